@@ -0,0 +1,128 @@
+//! Blank frame warning: notice when a parameter change leaves the view entirely
+//! black or NaN and suggest undoing it.
+//!
+//! The renderer's frame check runs a few times a second on the scene texture.
+//! Each measurement is tagged with the change generation it was encoded at, so a
+//! result is only acted on once the parameters it measured are still current.
+//! The last parameters that produced a visible frame are kept for auto-revert.
+
+use super::App;
+use crate::fractal::FractalParams;
+use crate::renderer::frame_check::FrameStats;
+
+/// Minimum time between frame checks
+const CHECK_INTERVAL_SECS: f32 = 0.25;
+
+pub(super) struct BlankFrameCheck {
+    /// Bumped on every parameter change
+    generation: u64,
+    /// Generation of the check in flight, if any
+    pending: Option<u64>,
+    /// Generation whose frame was last confirmed visible
+    good_generation: Option<u64>,
+    /// Parameters of that visible frame
+    last_good: Option<FractalParams>,
+    /// Generation already warned about, so each change warns once
+    warned_generation: Option<u64>,
+    last_check: web_time::Instant,
+}
+
+impl BlankFrameCheck {
+    pub(super) fn new() -> Self {
+        Self {
+            generation: 0,
+            pending: None,
+            good_generation: None,
+            last_good: None,
+            warned_generation: None,
+            last_check: web_time::Instant::now(),
+        }
+    }
+
+    /// Record a parameter change; results measured before it are ignored
+    pub(super) fn note_change(&mut self) {
+        self.generation += 1;
+    }
+}
+
+/// Blank frame warning methods
+impl App {
+    /// Check the scene texture this frame (throttled). Only meaningful for the
+    /// direct render path, where the scene texture holds the finished image.
+    pub(super) fn encode_frame_check(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if !self.ui.blank_frame_warning
+            || self.blank_frame.last_check.elapsed().as_secs_f32() < CHECK_INTERVAL_SECS
+        {
+            return;
+        }
+        if self.renderer.encode_frame_check(encoder) {
+            self.blank_frame.last_check = web_time::Instant::now();
+            self.blank_frame.pending = Some(self.blank_frame.generation);
+        }
+    }
+
+    /// Act on a finished frame check
+    pub(super) fn update_blank_frame_check(&mut self) {
+        let Some(stats) = self.renderer.poll_frame_check() else {
+            return;
+        };
+        let Some(generation) = self.blank_frame.pending.take() else {
+            return;
+        };
+        if !self.ui.blank_frame_warning || generation != self.blank_frame.generation {
+            return;
+        }
+
+        if !stats.is_blank() {
+            if self.blank_frame.good_generation != Some(generation) {
+                self.blank_frame.good_generation = Some(generation);
+                self.blank_frame.last_good = Some(self.fractal_params.clone());
+            }
+            return;
+        }
+
+        // Only a change away from a visible frame is worth a warning. A view that
+        // starts out blank, was already warned about, or went dark without a
+        // parameter change (e.g. zooming into the Mandelbrot interior) is left alone
+        if self
+            .blank_frame
+            .good_generation
+            .is_none_or(|good| good == generation)
+            || self.blank_frame.warned_generation == Some(generation)
+        {
+            return;
+        }
+        self.blank_frame.warned_generation = Some(generation);
+        log::warn!(
+            "Frame is blank after a parameter change ({:.0}% NaN samples)",
+            stats.invalid_fraction * 100.0
+        );
+
+        if self.ui.blank_frame_auto_revert {
+            if let Some(good) = self.blank_frame.last_good.clone() {
+                self.fractal_params = good;
+                self.camera.fovy = self.fractal_params.camera_fov;
+                self.blank_frame.note_change();
+                self.settings_last_changed = web_time::Instant::now();
+                self.settings_need_save = true;
+                self.ui
+                    .show_toast("⚠ The last change left the view blank - reverted".to_string());
+                return;
+            }
+        }
+        self.ui.show_toast(blank_frame_message(&stats));
+    }
+}
+
+/// Toast text suggesting a way back from a blank frame
+fn blank_frame_message(stats: &FrameStats) -> String {
+    let cause = if stats.invalid_fraction > 0.5 {
+        "produced invalid (NaN) pixels"
+    } else {
+        "left the view blank"
+    };
+    format!(
+        "⚠ The last change {} - undo with Ctrl+Z or reset the section",
+        cause
+    )
+}
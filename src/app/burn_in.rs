@@ -0,0 +1,239 @@
+//! Burn-in overlay for recorded video frames
+//!
+//! Draws the frame number, timecode, the current view and the parameters
+//! animated since the recording started into the captured RGBA pixels before
+//! they go to the encoder, so they end up in the file rather than only in the
+//! interactive UI. Uses a built-in 5x7 bitmap font to avoid pulling a font
+//! rasterizer into the capture path.
+
+use super::App;
+use crate::fractal::{FractalParams, RenderMode};
+
+/// Glyph cell size in font pixels, including one pixel of spacing
+const CELL_WIDTH: usize = 6;
+const CELL_HEIGHT: usize = 9;
+
+/// Recording burn-in methods
+impl App {
+    /// The overlay lines for the next recorded frame, empty when burn-in is off
+    fn burn_in_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let frame = self.video_recorder.frame_count();
+        let fps = self.video_recorder.fps().max(1);
+        if self.ui.burn_in_frame_number || self.ui.burn_in_timecode {
+            let mut line = Vec::new();
+            if self.ui.burn_in_frame_number {
+                line.push(format!("#{:05}", frame));
+            }
+            if self.ui.burn_in_timecode {
+                line.push(timecode(frame, fps));
+            }
+            lines.push(line.join("  "));
+        }
+        if self.ui.burn_in_parameters {
+            let params = &self.fractal_params;
+            lines.push(params.fractal_type.filename_safe_name().to_string());
+            lines.push(match params.render_mode {
+                RenderMode::TwoD => format!(
+                    "center ({:.10}, {:.10}) zoom {:.4e}",
+                    params.center_2d[0], params.center_2d[1], params.zoom_2d
+                ),
+                RenderMode::ThreeD => format!(
+                    "camera ({:.3}, {:.3}, {:.3}) fov {:.1}",
+                    self.camera.position.x,
+                    self.camera.position.y,
+                    self.camera.position.z,
+                    self.camera.fovy
+                ),
+            });
+            if let Some(start) = &self.recording_start_params {
+                let animated = animated_values(start, params);
+                if !animated.is_empty() {
+                    lines.push(animated);
+                }
+            }
+        }
+        lines
+    }
+
+    /// Draw the enabled overlay lines into a recorded RGBA8 frame
+    pub(super) fn burn_in_video_frame(&self, pixels: &mut [u8], width: u32, height: u32) {
+        let lines = self.burn_in_lines();
+        if !lines.is_empty() {
+            draw_burn_in(pixels, width, height, &lines);
+        }
+    }
+}
+
+/// The parameters that differ from the start of the recording, with their
+/// current values, e.g. `power 8.2500  hue_shift 0.1000`. The 2D zoom is
+/// already on the view line.
+fn animated_values(start: &FractalParams, current: &FractalParams) -> String {
+    let three_d = current.render_mode == RenderMode::ThreeD;
+    let mut values = Vec::new();
+    if current.power != start.power {
+        values.push(format!("power {:.4}", current.power));
+    }
+    if current.max_iterations != start.max_iterations {
+        values.push(format!("iterations {}", current.max_iterations));
+    }
+    // (name, value at the start, current value, whether it affects this mode)
+    let fields = [
+        ("julia_real", start.julia_c[0], current.julia_c[0], true),
+        ("julia_imag", start.julia_c[1], current.julia_c[1], true),
+        ("scale", start.fractal_scale, current.fractal_scale, three_d),
+        ("fold", start.fractal_fold, current.fractal_fold, three_d),
+        (
+            "min_radius",
+            start.fractal_min_radius,
+            current.fractal_min_radius,
+            three_d,
+        ),
+        ("hue_shift", start.hue_shift, current.hue_shift, true),
+        (
+            "light_azimuth",
+            start.light_azimuth,
+            current.light_azimuth,
+            three_d,
+        ),
+        (
+            "light_elevation",
+            start.light_elevation,
+            current.light_elevation,
+            three_d,
+        ),
+        ("roughness", start.roughness, current.roughness, three_d),
+        ("metallic", start.metallic, current.metallic, three_d),
+        (
+            "palette_offset",
+            start.palette_offset,
+            current.palette_offset,
+            true,
+        ),
+    ];
+    for (name, before, now, applies) in fields {
+        if applies && now != before {
+            values.push(format!("{} {:.4}", name, now));
+        }
+    }
+    values.join("  ")
+}
+
+/// Frame index as HH:MM:SS:FF
+fn timecode(frame: u32, fps: u32) -> String {
+    let seconds = frame / fps;
+    format!(
+        "{:02}:{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        frame % fps
+    )
+}
+
+/// Draw `lines` in the bottom-left corner of an RGBA8 image, white on a
+/// translucent dark box, scaled with the image height
+fn draw_burn_in(pixels: &mut [u8], width: u32, height: u32, lines: &[String]) {
+    let (width, height) = (width as usize, height as usize);
+    let longest = lines.iter().map(|line| line.chars().count()).max();
+    let Some(longest) = longest.filter(|&n| n > 0) else {
+        return;
+    };
+    let scale = (height / 360).max(1);
+    let margin = 4 * scale;
+    let box_width = (longest * CELL_WIDTH + 2) * scale;
+    let box_height = (lines.len() * CELL_HEIGHT + 1) * scale;
+    let left = margin;
+    let top = height.saturating_sub(margin + box_height);
+
+    // Darken the box behind the text
+    for y in top..(top + box_height).min(height) {
+        for x in left..(left + box_width).min(width) {
+            let i = (y * width + x) * 4;
+            for channel in &mut pixels[i..i + 3] {
+                *channel /= 3;
+            }
+        }
+    }
+
+    for (row, line) in lines.iter().enumerate() {
+        for (column, c) in line.chars().enumerate() {
+            let glyph = glyph(c);
+            let origin_x = left + (column * CELL_WIDTH + 1) * scale;
+            let origin_y = top + (row * CELL_HEIGHT + 1) * scale;
+            for (gy, bits) in glyph.iter().enumerate() {
+                for gx in 0..5 {
+                    if bits & (0x10 >> gx) == 0 {
+                        continue;
+                    }
+                    for sy in 0..scale {
+                        for sx in 0..scale {
+                            let x = origin_x + gx * scale + sx;
+                            let y = origin_y + gy * scale + sy;
+                            if x < width && y < height {
+                                let i = (y * width + x) * 4;
+                                pixels[i..i + 4].copy_from_slice(&[255, 255, 255, 255]);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 5x7 bitmap for a character, one byte per row with the leftmost pixel in
+/// bit 4. Lowercase letters use the uppercase glyphs.
+fn glyph(c: char) -> [u8; 7] {
+    match c.to_ascii_uppercase() {
+        ' ' => [0x00; 7],
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        'A' => [0x0E, 0x11, 0x11, 0x11, 0x1F, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '#' => [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '+' => [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00],
+        '=' => [0x00, 0x00, 0x1F, 0x00, 0x1F, 0x00, 0x00],
+        '/' => [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00],
+        '(' => [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02],
+        ')' => [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08],
+        '_' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F],
+        _ => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04],
+    }
+}
@@ -1,4 +1,5 @@
 use crate::camera::{Camera, CameraController};
+use crate::fractal::CameraEasing;
 use glam::Vec3;
 
 pub(super) struct CameraTransition {
@@ -11,6 +12,7 @@ pub(super) struct CameraTransition {
     end_fov: f32,
     start_time: web_time::Instant,
     duration: f32,
+    easing: CameraEasing,
 }
 
 impl CameraTransition {
@@ -25,6 +27,7 @@ impl CameraTransition {
             end_fov: 45.0,
             start_time: web_time::Instant::now(),
             duration: 1.0,
+            easing: CameraEasing::default(),
         }
     }
 
@@ -38,6 +41,7 @@ impl CameraTransition {
         end_tgt: Vec3,
         end_fov: f32,
         duration: f32,
+        easing: CameraEasing,
     ) {
         self.active = true;
         self.start_position = start_pos;
@@ -48,6 +52,7 @@ impl CameraTransition {
         self.end_fov = end_fov;
         self.start_time = web_time::Instant::now();
         self.duration = duration;
+        self.easing = easing;
     }
 
     pub(super) fn update(&self, camera: &mut Camera, controller: &mut CameraController) -> bool {
@@ -56,10 +61,8 @@ impl CameraTransition {
         }
 
         let elapsed = self.start_time.elapsed().as_secs_f32();
-        let t = (elapsed / self.duration).min(1.0);
-
-        // Smooth interpolation using smoothstep
-        let t_smooth = t * t * (3.0 - 2.0 * t);
+        let t = (elapsed / self.duration.max(1e-3)).min(1.0);
+        let t_smooth = self.easing.apply(t);
 
         camera.position = self.start_position.lerp(self.end_position, t_smooth);
         camera.target = self.start_target.lerp(self.end_target, t_smooth);
@@ -1,13 +1,82 @@
 use super::App;
+use crate::fractal::{
+    expand_filename_template, filename_safe, filename_template_uses_counter,
+    unique_filename_template, CaptureNameValues, CaptureSample, FractalParams, ProceduralPalette,
+    RenderMode,
+};
+use crate::renderer::surface_format;
+use crate::ui::UI;
 
 /// Capture and recording methods
 impl App {
-    pub(super) fn capture_screenshot(&mut self, texture: &wgpu::Texture) {
-        let width = self.renderer.config.width;
-        let height = self.renderer.config.height;
+    /// Full output path for a capture file in the given capture directory
+    /// setting, creating the directory if needed
+    pub(super) fn capture_path(directory: Option<&str>, filename: &str) -> std::path::PathBuf {
+        let dir = crate::fractal::AppPreferences::resolve_capture_directory(directory);
+        if let Err(e) = std::fs::create_dir_all(&dir) {
+            eprintln!(
+                "Failed to create capture directory {}: {}",
+                dir.display(),
+                e
+            );
+        }
+        dir.join(filename)
+    }
+
+    /// Output path for a capture of the current fractal, named from the
+    /// filename template with `suffix` appended (e.g. the size of a render).
+    /// An empty `extension` names a folder. `counter` is the last number
+    /// `{counter}` expanded to.
+    pub(super) fn capture_file_path(
+        ui: &UI,
+        params: &FractalParams,
+        counter: &mut u32,
+        (width, height): (u32, u32),
+        suffix: &str,
+        extension: &str,
+    ) -> std::path::PathBuf {
+        let now = chrono::Local::now();
+        let palette = if params.procedural_palette != ProceduralPalette::None {
+            params.procedural_palette.name()
+        } else {
+            params.palette.name
+        };
+        let mut values = CaptureNameValues {
+            fractal: params.fractal_type.filename_safe_name().to_string(),
+            date: now.format("%Y%m%d").to_string(),
+            time: now.format("%H%M%S").to_string(),
+            width,
+            height,
+            palette: filename_safe(palette),
+            counter: *counter,
+        };
+        let template = unique_filename_template(&ui.filename_template);
+        let uses_counter = filename_template_uses_counter(&template);
+        loop {
+            values.counter += 1;
+            let mut name = expand_filename_template(&template, &values) + suffix;
+            if !extension.is_empty() {
+                name = format!("{}.{}", name, extension);
+            }
+            let path = Self::capture_path(ui.capture_directory.as_deref(), &name);
+            // Number past captures already in the folder, e.g. from an earlier session
+            if !uses_counter || !path.exists() {
+                *counter = values.counter;
+                return path;
+            }
+        }
+    }
+
+    /// Read the render area of the surface texture back as tightly packed RGBA8 pixels
+    fn read_surface_pixels(&self, texture: &wgpu::Texture) -> Option<Vec<u8>> {
+        let width = self.renderer.size.width;
+        let height = self.renderer.size.height;
+        let (x, y) = self.renderer.render_origin;
+        let format = self.renderer.config.format;
+        let pixel_size = format.block_copy_size(None).unwrap_or(4);
 
         // Calculate buffer size with proper alignment
-        let bytes_per_row = (width * 4 + 255) & !255; // Align to 256 bytes
+        let bytes_per_row = (width * pixel_size + 255) & !255; // Align to 256 bytes
         let buffer_size = (bytes_per_row * height) as wgpu::BufferAddress;
 
         // Create buffer to copy texture to
@@ -31,7 +100,7 @@ impl App {
             wgpu::TexelCopyTextureInfo {
                 texture,
                 mip_level: 0,
-                origin: wgpu::Origin3d::ZERO,
+                origin: wgpu::Origin3d { x, y, z: 0 },
                 aspect: wgpu::TextureAspect::All,
             },
             wgpu::TexelCopyBufferInfo {
@@ -53,7 +122,7 @@ impl App {
             .queue
             .submit(std::iter::once(encoder.finish()));
 
-        // Map buffer and save to file
+        // Map buffer and read back
         let buffer_slice = buffer.slice(..);
         let (sender, receiver) = std::sync::mpsc::channel();
         buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
@@ -69,172 +138,226 @@ impl App {
             })
             .ok();
 
-        if receiver.recv().unwrap().is_ok() {
-            let data = buffer_slice.get_mapped_range();
+        if receiver.recv().unwrap().is_err() {
+            eprintln!("Failed to map screenshot buffer");
+            return None;
+        }
 
-            // Convert from padded buffer to image
-            let mut image_data = Vec::with_capacity((width * height * 4) as usize);
-            for row in 0..height {
-                let row_start = (row * bytes_per_row) as usize;
-                let row_data = &data[row_start..row_start + (width * 4) as usize];
-                image_data.extend_from_slice(row_data);
-            }
+        let data = buffer_slice.get_mapped_range();
 
-            drop(data);
-            buffer.unmap();
+        // Convert from padded buffer to image
+        let mut image_data = Vec::with_capacity((width * height * pixel_size) as usize);
+        for row in 0..height {
+            let row_start = (row * bytes_per_row) as usize;
+            let row_data = &data[row_start..row_start + (width * pixel_size) as usize];
+            image_data.extend_from_slice(row_data);
+        }
+
+        drop(data);
+        buffer.unmap();
+
+        // Convert BGRA (macOS/Windows typically use Bgra8UnormSrgb), 10-bit and
+        // float surfaces to RGBA8
+        Some(surface_format::surface_pixels_to_rgba8(format, &image_data))
+    }
 
-            // Convert BGRA to RGBA if surface format is Bgra
-            // (macOS/Windows typically use Bgra8UnormSrgb)
-            let format = self.renderer.config.format;
-            if format == wgpu::TextureFormat::Bgra8Unorm
-                || format == wgpu::TextureFormat::Bgra8UnormSrgb
-            {
-                for pixel in image_data.chunks_exact_mut(4) {
-                    pixel.swap(0, 2); // Swap B and R
+    /// Whether a screenshot of a `width`x`height` view is rendered offscreen at
+    /// twice the size and downscaled. Accumulating fractals are read from the
+    /// window as rendering them offscreen only resamples the accumulated image.
+    fn screenshot_supersampled(&self, width: u32, height: u32) -> bool {
+        let max_dimension = self.renderer.device.limits().max_texture_dimension_2d;
+        self.ui.screenshot_supersample
+            && !self.fractal_params.fractal_type.uses_accumulation()
+            && width.max(height) * 2 <= max_dimension
+    }
+
+    pub(super) fn capture_screenshot(&mut self, texture: &wgpu::Texture) {
+        let width = self.renderer.size.width;
+        let height = self.renderer.size.height;
+        let image_data = if self.screenshot_supersampled(width, height) {
+            let params = self.fractal_params.clone();
+            match self.render_linear(&params, width * 2, height * 2, CaptureSample::default()) {
+                Ok(linear) => {
+                    let linear = downsample_2x(&linear, width, height);
+                    linear_to_rgba8(&linear, width, height, self.ui.capture_gamma).into_raw()
+                }
+                Err(e) => {
+                    eprintln!("Failed to render screenshot: {}", e);
+                    return;
                 }
             }
+        } else {
+            let Some(mut image_data) = self.read_surface_pixels(texture) else {
+                return;
+            };
+            surface_format::apply_capture_gamma_rgba8(&mut image_data, self.ui.capture_gamma);
+            image_data
+        };
 
-            // Generate filename with fractal type and timestamp
-            let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-            let fractal_name = self.fractal_params.fractal_type.filename_safe_name();
-            let filename = format!("{}_{}.png", fractal_name, timestamp);
-
-            // Save as PNG
-            if let Some(img) = image::RgbaImage::from_raw(width, height, image_data) {
-                if let Err(e) = img.save(&filename) {
-                    eprintln!("Failed to save screenshot: {}", e);
-                } else {
-                    println!("Screenshot saved to {}", filename);
-                    // Convert to absolute path and show in toast
-                    let abs_path = std::path::Path::new(&filename)
-                        .canonicalize()
-                        .unwrap_or_else(|_| std::path::PathBuf::from(&filename));
-
-                    // Auto-open if enabled
-                    if self.ui.auto_open_captures {
-                        if let Err(e) = open::that(&abs_path) {
-                            eprintln!("Failed to open screenshot: {}", e);
-                        }
-                    }
+        let path = Self::capture_file_path(
+            &self.ui,
+            &self.fractal_params,
+            &mut self.capture_counter,
+            (width, height),
+            "",
+            "png",
+        );
 
-                    self.ui.show_toast_with_file(
-                        format!("📸 Screenshot saved: {} - Click to open", filename),
-                        abs_path.to_string_lossy().to_string(),
-                    );
-                }
+        // Save as PNG
+        if let Some(img) = image::RgbaImage::from_raw(width, height, image_data) {
+            if let Err(e) = img.save(&path) {
+                eprintln!("Failed to save screenshot: {}", e);
             } else {
-                eprintln!("Failed to create image from buffer");
+                // Convert to absolute path and show in toast
+                let abs_path = path.canonicalize().unwrap_or(path);
+                println!("Screenshot saved to {}", abs_path.display());
+
+                // Auto-open if enabled
+                if self.ui.auto_open_captures {
+                    if let Err(e) = open::that(&abs_path) {
+                        eprintln!("Failed to open screenshot: {}", e);
+                    }
+                }
+
+                self.ui.show_toast_with_file(
+                    format!(
+                        "📸 Screenshot saved: {} - Click to open",
+                        abs_path.display()
+                    ),
+                    abs_path.to_string_lossy().to_string(),
+                );
             }
         } else {
-            eprintln!("Failed to map screenshot buffer");
+            eprintln!("Failed to create image from buffer");
         }
     }
 
-    pub(super) fn capture_video_frame(&mut self, texture: &wgpu::Texture) {
-        let width = self.renderer.config.width;
-        let height = self.renderer.config.height;
-
-        // Calculate buffer size with proper alignment
-        let bytes_per_row = (width * 4 + 255) & !255; // Align to 256 bytes
-        let buffer_size = (bytes_per_row * height) as wgpu::BufferAddress;
-
-        // Create buffer to copy texture to
-        let buffer = self.renderer.device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Video Frame Buffer"),
-            size: buffer_size,
-            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
-            mapped_at_creation: false,
-        });
-
-        // Create encoder for copy operation
-        let mut encoder =
-            self.renderer
-                .device
-                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                    label: Some("Video Frame Encoder"),
-                });
+    /// Copy the current view to the system clipboard instead of writing a file
+    pub(super) fn capture_screenshot_to_clipboard(&mut self, texture: &wgpu::Texture) {
+        let width = self.renderer.size.width;
+        let height = self.renderer.size.height;
+        let Some(mut image_data) = self.read_surface_pixels(texture) else {
+            return;
+        };
+        surface_format::apply_capture_gamma_rgba8(&mut image_data, self.ui.capture_gamma);
 
-        // Copy texture to buffer
-        encoder.copy_texture_to_buffer(
-            wgpu::TexelCopyTextureInfo {
-                texture,
-                mip_level: 0,
-                origin: wgpu::Origin3d::ZERO,
-                aspect: wgpu::TextureAspect::All,
-            },
-            wgpu::TexelCopyBufferInfo {
-                buffer: &buffer,
-                layout: wgpu::TexelCopyBufferLayout {
-                    offset: 0,
-                    bytes_per_row: Some(bytes_per_row),
-                    rows_per_image: Some(height),
-                },
-            },
-            wgpu::Extent3d {
-                width,
-                height,
-                depth_or_array_layers: 1,
-            },
+        // egui's clipboard lives as long as the window, so X11/Wayland can
+        // still serve the image after this returns
+        let image = egui::ColorImage::from_rgba_unmultiplied(
+            [width as usize, height as usize],
+            &image_data,
         );
+        self.egui_state.egui_ctx().copy_image(image);
+        println!("Screenshot copied to clipboard ({}x{})", width, height);
+        self.ui.show_toast(format!(
+            "📋 Screenshot copied to clipboard ({}x{})",
+            width, height
+        ));
+    }
 
-        self.renderer
-            .queue
-            .submit(std::iter::once(encoder.finish()));
-
-        // Map buffer and add frame to video recorder
-        let buffer_slice = buffer.slice(..);
-        let (sender, receiver) = std::sync::mpsc::channel();
-        buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
-            sender.send(result).unwrap();
-        });
+    /// Size to record video at: the chosen recording resolution, or the size of
+    /// the view (the window minus any docked panel)
+    pub(super) fn video_size(ui: &UI, view_size: winit::dpi::PhysicalSize<u32>) -> (u32, u32) {
+        if ui.video_width > 0 && ui.video_height > 0 {
+            (ui.video_width, ui.video_height)
+        } else {
+            (view_size.width, view_size.height)
+        }
+    }
 
-        // Wait for GPU to finish
-        self.renderer
-            .device
-            .poll(wgpu::PollType::Wait {
-                submission_index: None,
-                timeout: None,
-            })
-            .ok();
+    /// Add a video frame when the recording size differs from the window: the
+    /// view is rendered offscreen at the recorder's size (without the UI)
+    pub(super) fn capture_video_frame_offscreen(&mut self) {
+        let (width, height) = self.video_recorder.size();
+        let params = self.fractal_params.clone();
+        match self.render_to_image(&params, width, height) {
+            Ok(img) => {
+                let mut frame_data = img.into_raw();
+                self.burn_in_video_frame(&mut frame_data, width, height);
+                if let Err(e) = self.video_recorder.add_frame(frame_data) {
+                    eprintln!("Failed to add frame to video: {}", e);
+                }
+            }
+            Err(e) => eprintln!("Failed to render video frame: {}", e),
+        }
+    }
 
-        if receiver.recv().unwrap().is_ok() {
-            let data = buffer_slice.get_mapped_range();
+    pub(super) fn capture_video_frame(&mut self, texture: &wgpu::Texture) {
+        let Some(mut frame_data) = self.read_surface_pixels(texture) else {
+            return;
+        };
+        let (width, height) = self.video_recorder.size();
+        self.burn_in_video_frame(&mut frame_data, width, height);
+        if let Err(e) = self.video_recorder.add_frame(frame_data) {
+            eprintln!("Failed to add frame to video: {}", e);
+        }
+    }
 
-            // Convert from padded buffer to unpadded RGBA data
-            let mut frame_data = Vec::with_capacity((width * height * 4) as usize);
-            for row in 0..height {
-                let row_start = (row * bytes_per_row) as usize;
-                let row_data = &data[row_start..row_start + (width * 4) as usize];
-                frame_data.extend_from_slice(row_data);
-            }
+    /// Render the current camera view with `params` offscreen at any resolution,
+    /// through the full post-processing chain, and read it back as an RGBA image
+    pub(super) fn render_to_image(
+        &mut self,
+        params: &FractalParams,
+        width: u32,
+        height: u32,
+    ) -> Result<image::RgbaImage, Box<dyn std::error::Error>> {
+        let linear = self.render_linear(params, width, height, CaptureSample::default())?;
+        Ok(linear_to_rgba8(&linear, width, height, 1.0))
+    }
 
-            drop(data);
-            buffer.unmap();
+    /// Render one image per sample, each jittered within the pixel (and on
+    /// the lens for depth of field), and average them in linear light. Color is
+    /// weighted by alpha so transparent samples don't darken the edges of a
+    /// transparent-background capture. Accumulating fractals already build up
+    /// their image over many frames, so they are captured with a single
+    /// sample. Returns linear RGBA like [`Self::render_linear`].
+    pub(super) fn render_supersampled(
+        &mut self,
+        params: &FractalParams,
+        width: u32,
+        height: u32,
+        samples: &[CaptureSample],
+    ) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+        if samples.len() < 2 || params.fractal_type.uses_accumulation() {
+            return self.render_linear(params, width, height, CaptureSample::default());
+        }
 
-            // Convert BGRA to RGBA if surface format is Bgra
-            let format = self.renderer.config.format;
-            if format == wgpu::TextureFormat::Bgra8Unorm
-                || format == wgpu::TextureFormat::Bgra8UnormSrgb
-            {
-                for pixel in frame_data.chunks_exact_mut(4) {
-                    pixel.swap(0, 2); // Swap B and R
+        // Per pixel: alpha-weighted linear RGB, then total alpha
+        let mut sums = vec![0.0f32; (width * height * 4) as usize];
+        for (index, capture_sample) in samples.iter().enumerate() {
+            log::debug!("Capture sample {}/{}", index + 1, samples.len());
+            let sample = self.render_linear(params, width, height, *capture_sample)?;
+            for (sum, pixel) in sums.chunks_exact_mut(4).zip(sample.chunks_exact(4)) {
+                let alpha = pixel[3].clamp(0.0, 1.0);
+                for channel in 0..3 {
+                    sum[channel] += pixel[channel] * alpha;
                 }
+                sum[3] += alpha;
             }
+        }
 
-            // Add frame to video recorder
-            if let Err(e) = self.video_recorder.add_frame(frame_data) {
-                eprintln!("Failed to add frame to video: {}", e);
+        let count = samples.len() as f32;
+        for sum in sums.chunks_exact_mut(4) {
+            let weight = if sum[3] > 0.0 { 1.0 / sum[3] } else { 0.0 };
+            for channel in &mut sum[..3] {
+                *channel *= weight;
             }
-        } else {
-            eprintln!("Failed to map video frame buffer");
+            sum[3] /= count;
         }
+        Ok(sums)
     }
 
-    pub(super) fn render_high_resolution(
+    /// Render the current camera view with `params` offscreen, jittered by
+    /// `sample`, and read the HDR composite back as linear RGBA floats.
+    /// Reading the composite rather than a copy in the surface format keeps
+    /// captures independent of the window's format and its precision.
+    fn render_linear(
         &mut self,
+        params: &FractalParams,
         width: u32,
         height: u32,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+        sample: CaptureSample,
+    ) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
         let size = wgpu::Extent3d {
             width,
             height,
@@ -254,7 +377,8 @@ impl App {
                     dimension: wgpu::TextureDimension::D2,
                     format: hdr_format,
                     usage: wgpu::TextureUsages::RENDER_ATTACHMENT
-                        | wgpu::TextureUsages::TEXTURE_BINDING,
+                        | wgpu::TextureUsages::TEXTURE_BINDING
+                        | wgpu::TextureUsages::COPY_SRC,
                     view_formats: &[],
                 })
         };
@@ -263,34 +387,13 @@ impl App {
         let scene_texture = create_hdr_texture("High-Res Scene");
         let scene_view = scene_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
-        let bright_texture = create_hdr_texture("High-Res Bright");
-        let bright_view = bright_texture.create_view(&wgpu::TextureViewDescriptor::default());
-
-        let blur_temp_texture = create_hdr_texture("High-Res Blur Temp");
-        let blur_temp_view = blur_temp_texture.create_view(&wgpu::TextureViewDescriptor::default());
-
-        let bloom_texture = create_hdr_texture("High-Res Bloom");
-        let bloom_view = bloom_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bloom = self
+            .renderer
+            .create_bloom_chain(width, height, params.bloom_quality);
 
         let composite_texture = create_hdr_texture("High-Res Composite");
         let composite_view = composite_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
-        // Create final output texture (8-bit for saving)
-        let output_texture = self
-            .renderer
-            .device
-            .create_texture(&wgpu::TextureDescriptor {
-                label: Some("High-Res Output"),
-                size,
-                mip_level_count: 1,
-                sample_count: 1,
-                dimension: wgpu::TextureDimension::D2,
-                format: self.renderer.config.format,
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
-                view_formats: &[],
-            });
-        let output_view = output_texture.create_view(&wgpu::TextureViewDescriptor::default());
-
         // Create bind groups for post-processing
         let texture_layout = self.renderer.copy_pipeline.get_bind_group_layout(0);
         let composite_layout = self.renderer.composite_pipeline.get_bind_group_layout(0);
@@ -313,42 +416,6 @@ impl App {
                 ],
             });
 
-        let bright_bind_group =
-            self.renderer
-                .device
-                .create_bind_group(&wgpu::BindGroupDescriptor {
-                    label: Some("HR Bright BG"),
-                    layout: &texture_layout,
-                    entries: &[
-                        wgpu::BindGroupEntry {
-                            binding: 0,
-                            resource: wgpu::BindingResource::TextureView(&bright_view),
-                        },
-                        wgpu::BindGroupEntry {
-                            binding: 1,
-                            resource: wgpu::BindingResource::Sampler(&self.renderer.sampler),
-                        },
-                    ],
-                });
-
-        let blur_temp_bind_group =
-            self.renderer
-                .device
-                .create_bind_group(&wgpu::BindGroupDescriptor {
-                    label: Some("HR Blur Temp BG"),
-                    layout: &texture_layout,
-                    entries: &[
-                        wgpu::BindGroupEntry {
-                            binding: 0,
-                            resource: wgpu::BindingResource::TextureView(&blur_temp_view),
-                        },
-                        wgpu::BindGroupEntry {
-                            binding: 1,
-                            resource: wgpu::BindingResource::Sampler(&self.renderer.sampler),
-                        },
-                    ],
-                });
-
         let composite_bind_group =
             self.renderer
                 .device
@@ -366,7 +433,7 @@ impl App {
                         },
                         wgpu::BindGroupEntry {
                             binding: 2,
-                            resource: wgpu::BindingResource::TextureView(&bloom_view),
+                            resource: wgpu::BindingResource::TextureView(bloom.result_view()),
                         },
                         wgpu::BindGroupEntry {
                             binding: 3,
@@ -375,28 +442,20 @@ impl App {
                     ],
                 });
 
-        let composite_final_bind_group =
-            self.renderer
-                .device
-                .create_bind_group(&wgpu::BindGroupDescriptor {
-                    label: Some("HR Final BG"),
-                    layout: &texture_layout,
-                    entries: &[
-                        wgpu::BindGroupEntry {
-                            binding: 0,
-                            resource: wgpu::BindingResource::TextureView(&composite_view),
-                        },
-                        wgpu::BindGroupEntry {
-                            binding: 1,
-                            resource: wgpu::BindingResource::Sampler(&self.renderer.sampler),
-                        },
-                    ],
-                });
-
         // Create temporary camera with correct aspect ratio
         let mut temp_camera = self.camera.clone();
         temp_camera.aspect = width as f32 / height as f32;
-        self.renderer.update(&temp_camera, &self.fractal_params);
+        self.renderer.set_pixel_jitter(sample.pixel);
+        self.renderer.set_lens_jitter(sample.lens);
+        self.renderer
+            .set_cvd_simulation(self.ui.cvd_simulation_for(true));
+        self.renderer
+            .update_for_size(&temp_camera, params, width, height);
+        // Captures beyond what the live view already warned about (e.g. Final Render)
+        let warnings = self.renderer.safe_mode_warnings();
+        if !warnings.is_empty() && warnings != self.safe_mode_warnings_shown.as_slice() {
+            self.ui.show_toast(warnings.join("\n"));
+        }
 
         let mut encoder =
             self.renderer
@@ -407,9 +466,16 @@ impl App {
 
         // Pass 1: Render fractal to scene texture
         // For strange attractors and Buddhabrot with accumulation, use the accumulation display pipeline
-        let use_accumulation = self.fractal_params.attractor_accumulation_enabled
-            && self.fractal_params.fractal_type.uses_accumulation()
-            && self.renderer.accumulation_display_bind_group.is_some();
+        let use_accumulation =
+            params.accumulation_active() && self.renderer.accumulation_display_bind_group.is_some();
+
+        // Double precision ray marching is a capture-only option for 3D fractals
+        let use_f64 = params.double_precision_3d
+            && self.renderer.supports_f64
+            && params.render_mode == RenderMode::ThreeD;
+        if use_f64 {
+            self.renderer.init_f64_pipeline();
+        }
 
         {
             let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -444,110 +510,16 @@ impl App {
                 }
             } else {
                 // Standard fractal rendering
-                pass.set_pipeline(&self.renderer.render_pipeline);
+                pass.set_pipeline(self.renderer.fractal_pipeline(use_f64));
                 pass.set_bind_group(0, &self.renderer.uniform_bind_group, &[]);
                 pass.set_vertex_buffer(0, self.renderer.vertex_buffer.slice(..));
                 pass.draw(0..4, 0..1);
             }
         }
 
-        // Pass 2: Extract bright pixels for bloom
-        {
-            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("HR Bloom Extract"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &bright_view,
-                    depth_slice: None,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                occlusion_query_set: None,
-                timestamp_writes: None,
-            });
-            pass.set_pipeline(&self.renderer.bloom_extract_pipeline);
-            pass.set_bind_group(0, &scene_bind_group, &[]);
-            pass.set_bind_group(1, &self.renderer.bloom_params_bind_group, &[]);
-            pass.set_vertex_buffer(0, self.renderer.postprocess_vertex_buffer.slice(..));
-            pass.draw(0..4, 0..1);
-        }
-
-        // Pass 3: Horizontal blur
-        {
-            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("HR Blur H"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &blur_temp_view,
-                    depth_slice: None,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                occlusion_query_set: None,
-                timestamp_writes: None,
-            });
-            pass.set_pipeline(&self.renderer.blur_pipeline);
-            pass.set_bind_group(0, &bright_bind_group, &[]);
-            pass.set_bind_group(1, &self.renderer.blur_h_params_bind_group, &[]);
-            pass.set_vertex_buffer(0, self.renderer.postprocess_vertex_buffer.slice(..));
-            pass.draw(0..4, 0..1);
-        }
-
-        // Update blur direction to vertical
-        #[repr(C)]
-        #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
-        struct BlurUniforms {
-            direction: [f32; 2],
-            _padding: [f32; 2],
-        }
-        self.renderer.queue.write_buffer(
-            &self.renderer.blur_uniform_buffer,
-            0,
-            bytemuck::cast_slice(&[BlurUniforms {
-                direction: [0.0, 1.0],
-                _padding: [0.0; 2],
-            }]),
-        );
-
-        // Pass 4: Vertical blur
-        {
-            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("HR Blur V"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &bloom_view,
-                    depth_slice: None,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                occlusion_query_set: None,
-                timestamp_writes: None,
-            });
-            pass.set_pipeline(&self.renderer.blur_pipeline);
-            pass.set_bind_group(0, &blur_temp_bind_group, &[]);
-            pass.set_bind_group(1, &self.renderer.blur_v_params_bind_group, &[]);
-            pass.set_vertex_buffer(0, self.renderer.postprocess_vertex_buffer.slice(..));
-            pass.draw(0..4, 0..1);
-        }
-
-        // Restore blur direction to horizontal for normal rendering
-        self.renderer.queue.write_buffer(
-            &self.renderer.blur_uniform_buffer,
-            0,
-            bytemuck::cast_slice(&[BlurUniforms {
-                direction: [1.0, 0.0],
-                _padding: [0.0; 2],
-            }]),
-        );
+        // Pass 2-4: Bloom mip chain
+        self.renderer
+            .encode_bloom(&mut encoder, &scene_bind_group, &bloom);
 
         // Pass 5: Composite (scene + bloom + color grading + vignette)
         {
@@ -573,31 +545,9 @@ impl App {
             pass.draw(0..4, 0..1);
         }
 
-        // Pass 6: Final copy to 8-bit output
-        {
-            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("HR Final Copy"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &output_view,
-                    depth_slice: None,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                occlusion_query_set: None,
-                timestamp_writes: None,
-            });
-            pass.set_pipeline(&self.renderer.copy_pipeline);
-            pass.set_bind_group(0, &composite_final_bind_group, &[]);
-            pass.set_vertex_buffer(0, self.renderer.postprocess_vertex_buffer.slice(..));
-            pass.draw(0..4, 0..1);
-        }
-
-        // Create buffer to copy texture to
-        let bytes_per_row = (width * 4 + 255) & !255; // Align to 256 bytes
+        // Create buffer to copy the composite to (8 bytes per Rgba16Float pixel)
+        let pixel_size = 8;
+        let bytes_per_row = (width * pixel_size + 255) & !255; // Align to 256 bytes
         let buffer_size = (bytes_per_row * height) as wgpu::BufferAddress;
 
         let buffer = self.renderer.device.create_buffer(&wgpu::BufferDescriptor {
@@ -610,7 +560,7 @@ impl App {
         // Copy texture to buffer
         encoder.copy_texture_to_buffer(
             wgpu::TexelCopyTextureInfo {
-                texture: &output_texture,
+                texture: &composite_texture,
                 mip_level: 0,
                 origin: wgpu::Origin3d::ZERO,
                 aspect: wgpu::TextureAspect::All,
@@ -635,9 +585,13 @@ impl App {
             .submit(std::iter::once(encoder.finish()));
 
         // Restore original camera uniforms
+        self.renderer.set_pixel_jitter([0.0, 0.0]);
+        self.renderer.set_lens_jitter([0.0, 0.0]);
+        self.renderer
+            .set_cvd_simulation(self.ui.cvd_simulation_for(false));
         self.renderer.update(&self.camera, &self.fractal_params);
 
-        // Map buffer and save to file
+        // Map buffer and read back the image
         let buffer_slice = buffer.slice(..);
         let (sender, receiver) = std::sync::mpsc::channel();
         buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
@@ -656,54 +610,330 @@ impl App {
         if receiver.recv()?.is_ok() {
             let data = buffer_slice.get_mapped_range();
 
-            // Convert from padded buffer to image
-            let mut image_data = Vec::with_capacity((width * height * 4) as usize);
+            // Convert from padded buffer to linear floats
+            let mut linear = Vec::with_capacity((width * height * 4) as usize);
             for row in 0..height {
                 let row_start = (row * bytes_per_row) as usize;
-                let row_data = &data[row_start..row_start + (width * 4) as usize];
-                image_data.extend_from_slice(row_data);
+                let row_data = &data[row_start..row_start + (width * pixel_size) as usize];
+                linear.extend(surface_format::rgba16f_to_f32(row_data));
             }
 
             drop(data);
             buffer.unmap();
+            Ok(linear)
+        } else {
+            Err("Failed to map buffer".into())
+        }
+    }
+
+    pub(super) fn render_high_resolution(
+        &mut self,
+        width: u32,
+        height: u32,
+    ) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+        let params = self.fractal_params.clone();
+        let samples = crate::fractal::capture_samples(
+            self.ui.capture_antialiasing,
+            self.ui.accumulation_samples,
+        );
+        self.render_capture(&params, width, height, &samples, "")
+    }
 
-            // Convert BGRA to RGBA (surface format is Bgra8UnormSrgb)
-            for pixel in image_data.chunks_exact_mut(4) {
-                pixel.swap(0, 2); // Swap B and R
+    /// Render the current view with the Final Render quality profile. The
+    /// interactive parameters aren't touched, so nothing needs restoring.
+    pub(super) fn render_final(
+        &mut self,
+        width: u32,
+        height: u32,
+    ) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+        let params = self.fractal_params.final_render_params();
+        let samples = crate::fractal::capture_samples(
+            crate::fractal::FINAL_RENDER_ANTIALIASING,
+            self.ui.accumulation_samples,
+        );
+        self.render_capture(&params, width, height, &samples, "final_")
+    }
+
+    /// Resolve the Final Render size from the capture panel and run it
+    pub(super) fn start_final_render(&mut self) {
+        let (mut width, mut height) = (self.renderer.size.width, self.renderer.size.height);
+        if self.ui.final_render_custom_size {
+            match (
+                self.ui.custom_width.trim().parse::<u32>(),
+                self.ui.custom_height.trim().parse::<u32>(),
+            ) {
+                (Ok(w), Ok(h)) if (1..=16384).contains(&w) && (1..=16384).contains(&h) => {
+                    (width, height) = (w, h);
+                }
+                _ => {
+                    self.ui.show_toast(format!(
+                        "⚠ Invalid custom resolution {}x{} (must be 1-16384)",
+                        self.ui.custom_width.trim(),
+                        self.ui.custom_height.trim()
+                    ));
+                    return;
+                }
+            }
+        }
+        if self.ui.safe_mode {
+            let (clamped, was_clamped) = crate::fractal::clamp_capture_resolution(width, height);
+            if was_clamped {
+                self.ui.show_toast(format!(
+                    "🛡 Safe mode: capture limited to {}x{} (requested {}x{})",
+                    clamped.0, clamped.1, width, height
+                ));
+                (width, height) = clamped;
             }
+        }
 
-            // Generate filename with fractal type, resolution, and timestamp
-            let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-            let fractal_name = self.fractal_params.fractal_type.filename_safe_name();
-            let filename = format!("{}_{}x{}_{}.png", fractal_name, width, height, timestamp);
+        println!("Starting final render at {}x{}...", width, height);
+        match self.render_final(width, height) {
+            Ok(_) => println!("Final render completed!"),
+            Err(e) => eprintln!("Failed to render final image: {}", e),
+        }
+    }
 
-            // Save as PNG
-            if let Some(img) = image::RgbaImage::from_raw(width, height, image_data) {
-                img.save(&filename)?;
-                println!("High-resolution image saved to {}", filename);
-                // Convert to absolute path and show in toast
-                let abs_path = std::path::Path::new(&filename)
-                    .canonicalize()
-                    .unwrap_or_else(|_| std::path::PathBuf::from(&filename));
+    /// Render the current view once per value of the parameter sweep set up in
+    /// the capture window, as one contact sheet or a folder of numbered images
+    pub(super) fn render_parameter_sweep(&mut self) {
+        let sweep = self.ui.parameter_sweep.clone();
+        let width = sweep.tile_width.max(1);
+        let size = self.renderer.size;
+        let height =
+            ((width as f64 * size.height as f64 / size.width.max(1) as f64).round() as u32).max(1);
+        let values = crate::fractal::sweep_values(sweep.min, sweep.max, sweep.count);
+        println!(
+            "Rendering {} sweep: {} images of {}x{}",
+            sweep.param.name(),
+            values.len(),
+            width,
+            height
+        );
 
-                // Auto-open if enabled
+        let mut tiles = Vec::with_capacity(values.len());
+        for (index, &value) in values.iter().enumerate() {
+            let mut params = self.fractal_params.clone();
+            sweep.param.set(&mut params, value);
+            println!(
+                "  {}/{}: {} = {}",
+                index + 1,
+                values.len(),
+                sweep.param.name(),
+                value
+            );
+            match self.render_to_image(&params, width, height) {
+                Ok(image) => tiles.push(image),
+                Err(e) => {
+                    eprintln!("Failed to render sweep image: {}", e);
+                    self.ui
+                        .show_toast(format!("❌ Parameter sweep failed: {}", e));
+                    return;
+                }
+            }
+        }
+
+        let suffix = format!("_sweep_{}", sweep.param.filename_safe_name());
+        let result = if sweep.contact_sheet {
+            let (columns, rows) = crate::fractal::contact_sheet_grid(sweep.count);
+            let path = Self::capture_file_path(
+                &self.ui,
+                &self.fractal_params,
+                &mut self.capture_counter,
+                (width * columns, height * rows),
+                &suffix,
+                "png",
+            );
+            save_contact_sheet(&tiles, &path)
+        } else {
+            let folder = Self::capture_file_path(
+                &self.ui,
+                &self.fractal_params,
+                &mut self.capture_counter,
+                (width, height),
+                &suffix,
+                "",
+            );
+            save_numbered_images(&tiles, &values, sweep.param.filename_safe_name(), &folder)
+        };
+
+        match result {
+            Ok(path) => {
+                let abs_path = path.canonicalize().unwrap_or(path);
+                println!("Parameter sweep saved to {}", abs_path.display());
                 if self.ui.auto_open_captures {
                     if let Err(e) = open::that(&abs_path) {
-                        eprintln!("Failed to open high-res image: {}", e);
+                        eprintln!("Failed to open parameter sweep: {}", e);
                     }
                 }
-
                 self.ui.show_toast_with_file(
-                    format!("🖼️  High-res image saved: {} - Click to open", filename),
+                    format!(
+                        "📊 {} sweep saved: {} - Click to open",
+                        sweep.param.name(),
+                        abs_path.display()
+                    ),
                     abs_path.to_string_lossy().to_string(),
                 );
-            } else {
-                return Err("Failed to create image from buffer".into());
             }
+            Err(e) => {
+                eprintln!("Failed to save parameter sweep: {}", e);
+                self.ui
+                    .show_toast(format!("❌ Failed to save parameter sweep: {}", e));
+            }
+        }
+    }
+
+    /// Render `params` offscreen and save it as a PNG named from the filename
+    /// template plus `_{tag}WxH` in the capture folder
+    fn render_capture(
+        &mut self,
+        params: &FractalParams,
+        width: u32,
+        height: u32,
+        samples: &[CaptureSample],
+        tag: &str,
+    ) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+        self.renderer
+            .set_transparent_background(self.ui.transparent_background);
+        let result = self.render_supersampled(params, width, height, samples);
+        self.renderer.set_transparent_background(false);
+        let linear = result?;
+        let gamma = self.ui.capture_gamma;
+        let img = if self.ui.capture_16bit {
+            image::DynamicImage::ImageRgba16(linear_to_rgba16(&linear, width, height, gamma))
         } else {
-            return Err("Failed to map buffer".into());
+            image::DynamicImage::ImageRgba8(linear_to_rgba8(&linear, width, height, gamma))
+        };
+
+        let path = Self::capture_file_path(
+            &self.ui,
+            &self.fractal_params,
+            &mut self.capture_counter,
+            (width, height),
+            &format!("_{}{}x{}", tag, width, height),
+            "png",
+        );
+
+        // Save as PNG
+        img.save(&path)?;
+        // Convert to absolute path and show in toast
+        let abs_path = path.canonicalize().unwrap_or(path);
+        println!("High-resolution image saved to {}", abs_path.display());
+
+        // Auto-open if enabled
+        if self.ui.auto_open_captures {
+            if let Err(e) = open::that(&abs_path) {
+                eprintln!("Failed to open high-res image: {}", e);
+            }
+        }
+
+        self.ui.show_toast_with_file(
+            format!(
+                "🖼️  High-res image saved: {} - Click to open",
+                abs_path.display()
+            ),
+            abs_path.to_string_lossy().to_string(),
+        );
+        Ok(abs_path)
+    }
+}
+
+/// Average each 2x2 block of a linear RGBA image twice `width`x`height` into
+/// one pixel
+fn downsample_2x(linear: &[f32], width: u32, height: u32) -> Vec<f32> {
+    let (width, height) = (width as usize, height as usize);
+    let source_width = width * 2;
+    let mut out = vec![0.0f32; width * height * 4];
+    for y in 0..height {
+        for x in 0..width {
+            let pixel = &mut out[(y * width + x) * 4..][..4];
+            for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                let source = ((y * 2 + dy) * source_width + x * 2 + dx) * 4;
+                for channel in 0..4 {
+                    pixel[channel] += linear[source + channel] * 0.25;
+                }
+            }
         }
+    }
+    out
+}
+
+/// Encode linear RGBA floats as an 8-bit sRGB image, with the capture gamma
+/// on top (1.0 for the same values the display shows)
+fn linear_to_rgba8(linear: &[f32], width: u32, height: u32, gamma: f32) -> image::RgbaImage {
+    let to_u8 = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let encode = |v: f32| {
+        to_u8(surface_format::apply_capture_gamma(
+            surface_format::linear_to_srgb(v),
+            gamma,
+        ))
+    };
+    let data = linear
+        .chunks_exact(4)
+        .flat_map(|p| [encode(p[0]), encode(p[1]), encode(p[2]), to_u8(p[3])])
+        .collect();
+    image::RgbaImage::from_raw(width, height, data).expect("linear buffer matches image size")
+}
+
+/// Encode linear RGBA floats as a 16-bit sRGB image, with the capture gamma
+fn linear_to_rgba16(
+    linear: &[f32],
+    width: u32,
+    height: u32,
+    gamma: f32,
+) -> image::ImageBuffer<image::Rgba<u16>, Vec<u16>> {
+    let to_u16 = |v: f32| (v.clamp(0.0, 1.0) * 65535.0).round() as u16;
+    let encode = |v: f32| {
+        to_u16(surface_format::apply_capture_gamma(
+            surface_format::linear_to_srgb(v),
+            gamma,
+        ))
+    };
+    let data = linear
+        .chunks_exact(4)
+        .flat_map(|p| [encode(p[0]), encode(p[1]), encode(p[2]), to_u16(p[3])])
+        .collect();
+    image::ImageBuffer::from_raw(width, height, data).expect("linear buffer matches image size")
+}
+
+/// Space between images on a contact sheet, in pixels
+const CONTACT_SHEET_GAP: u32 = 4;
+
+/// Arrange equally sized images in a grid, in order left to right and top to
+/// bottom, and save it to `path`
+fn save_contact_sheet(
+    tiles: &[image::RgbaImage],
+    path: &std::path::Path,
+) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let (tile_width, tile_height) = tiles.first().ok_or("No images to arrange")?.dimensions();
+    let (columns, rows) = crate::fractal::contact_sheet_grid(tiles.len() as u32);
+    let mut sheet = image::RgbaImage::from_pixel(
+        columns * (tile_width + CONTACT_SHEET_GAP) + CONTACT_SHEET_GAP,
+        rows * (tile_height + CONTACT_SHEET_GAP) + CONTACT_SHEET_GAP,
+        image::Rgba([0, 0, 0, 255]),
+    );
+    for (index, tile) in tiles.iter().enumerate() {
+        let (column, row) = (index as u32 % columns, index as u32 / columns);
+        image::imageops::replace(
+            &mut sheet,
+            tile,
+            (CONTACT_SHEET_GAP + column * (tile_width + CONTACT_SHEET_GAP)) as i64,
+            (CONTACT_SHEET_GAP + row * (tile_height + CONTACT_SHEET_GAP)) as i64,
+        );
+    }
+    sheet.save(path)?;
+    Ok(path.to_path_buf())
+}
 
-        Ok(())
+/// Save each image to `folder` as `NNN_{param}_{value}.png`. Returns the folder.
+fn save_numbered_images(
+    tiles: &[image::RgbaImage],
+    values: &[f32],
+    param: &str,
+    folder: &std::path::Path,
+) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    std::fs::create_dir_all(folder)?;
+    for (index, (tile, value)) in tiles.iter().zip(values).enumerate() {
+        tile.save(folder.join(format!("{:03}_{}_{}.png", index + 1, param, value)))?;
     }
+    Ok(folder.to_path_buf())
 }
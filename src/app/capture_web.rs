@@ -4,6 +4,7 @@ use crate::camera::Camera;
 use crate::fractal::FractalParams;
 use crate::platform::web::WebCapture;
 use crate::platform::Capture;
+use crate::renderer::surface_format;
 use crate::renderer::Renderer;
 use std::sync::{Arc, Mutex};
 
@@ -16,18 +17,23 @@ struct CaptureState {
 /// Captures a screenshot from the GPU texture on web.
 ///
 /// This creates a staging buffer, copies the texture data, and triggers
-/// an async download once the buffer is mapped.
+/// an async download (or a clipboard copy) once the buffer is mapped.
+#[allow(clippy::too_many_arguments)]
 pub fn capture_screenshot_web(
     device: &wgpu::Device,
     queue: &wgpu::Queue,
     texture: &wgpu::Texture,
+    format: wgpu::TextureFormat,
+    origin: (u32, u32),
     width: u32,
     height: u32,
     fractal_name: String,
+    copy_to_clipboard: bool,
     show_toast: Box<dyn Fn(String) + Send + 'static>,
 ) {
     // Calculate buffer size with proper alignment
-    let bytes_per_row = (width * 4 + 255) & !255; // Align to 256 bytes
+    let pixel_size = format.block_copy_size(None).unwrap_or(4);
+    let bytes_per_row = (width * pixel_size + 255) & !255; // Align to 256 bytes
     let buffer_size = (bytes_per_row * height) as wgpu::BufferAddress;
 
     // Create buffer to copy texture to
@@ -48,7 +54,11 @@ pub fn capture_screenshot_web(
         wgpu::TexelCopyTextureInfo {
             texture,
             mip_level: 0,
-            origin: wgpu::Origin3d::ZERO,
+            origin: wgpu::Origin3d {
+                x: origin.0,
+                y: origin.1,
+                z: 0,
+            },
             aspect: wgpu::TextureAspect::All,
         },
         wgpu::TexelCopyBufferInfo {
@@ -114,16 +124,33 @@ pub fn capture_screenshot_web(
                 let data = buffer_slice.get_mapped_range();
 
                 // Convert from padded buffer to image data
-                let mut image_data = Vec::with_capacity((width * height * 4) as usize);
+                let mut image_data = Vec::with_capacity((width * height * pixel_size) as usize);
                 for row in 0..height {
                     let row_start = (row * bytes_per_row) as usize;
-                    let row_data = &data[row_start..row_start + (width * 4) as usize];
+                    let row_data = &data[row_start..row_start + (width * pixel_size) as usize];
                     image_data.extend_from_slice(row_data);
                 }
 
                 drop(data);
                 buffer_for_async.unmap();
 
+                // Browsers usually give a BGRA canvas; convert like native captures
+                let image_data = surface_format::surface_pixels_to_rgba8(format, &image_data);
+
+                if copy_to_clipboard {
+                    match crate::platform::web::copy_png(width, height, &image_data).await {
+                        Ok(()) => show_toast_for_async(format!(
+                            "📋 Screenshot copied to clipboard ({}x{})",
+                            width, height
+                        )),
+                        Err(e) => {
+                            log::error!("Failed to copy screenshot to clipboard: {}", e);
+                            show_toast_for_async(format!("✗ Failed to copy to clipboard: {}", e));
+                        }
+                    }
+                    return;
+                }
+
                 // Use the platform capture to save the screenshot
                 let capture = WebCapture::new();
                 match capture.save_screenshot(width, height, &image_data, &fractal_name) {
@@ -194,14 +221,7 @@ pub fn render_high_resolution_web(
     let scene_texture = create_hdr_texture("High-Res Scene");
     let scene_view = scene_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
-    let bright_texture = create_hdr_texture("High-Res Bright");
-    let bright_view = bright_texture.create_view(&wgpu::TextureViewDescriptor::default());
-
-    let blur_temp_texture = create_hdr_texture("High-Res Blur Temp");
-    let blur_temp_view = blur_temp_texture.create_view(&wgpu::TextureViewDescriptor::default());
-
-    let bloom_texture = create_hdr_texture("High-Res Bloom");
-    let bloom_view = bloom_texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let bloom = renderer.create_bloom_chain(width, height, fractal_params.bloom_quality);
 
     let composite_texture = create_hdr_texture("High-Res Composite");
     let composite_view = composite_texture.create_view(&wgpu::TextureViewDescriptor::default());
@@ -241,40 +261,6 @@ pub fn render_high_resolution_web(
             ],
         });
 
-    let bright_bind_group = renderer
-        .device
-        .create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("HR Bright BG"),
-            layout: &texture_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: wgpu::BindingResource::TextureView(&bright_view),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::Sampler(&renderer.sampler),
-                },
-            ],
-        });
-
-    let blur_temp_bind_group = renderer
-        .device
-        .create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("HR Blur Temp BG"),
-            layout: &texture_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: wgpu::BindingResource::TextureView(&blur_temp_view),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::Sampler(&renderer.sampler),
-                },
-            ],
-        });
-
     let composite_bind_group = renderer
         .device
         .create_bind_group(&wgpu::BindGroupDescriptor {
@@ -291,7 +277,7 @@ pub fn render_high_resolution_web(
                 },
                 wgpu::BindGroupEntry {
                     binding: 2,
-                    resource: wgpu::BindingResource::TextureView(&bloom_view),
+                    resource: wgpu::BindingResource::TextureView(bloom.result_view()),
                 },
                 wgpu::BindGroupEntry {
                     binding: 3,
@@ -323,12 +309,20 @@ pub fn render_high_resolution_web(
     temp_camera.aspect = width as f32 / height as f32;
 
     // Update the main uniform buffer with the temporary camera
-    // (we'll restore it after rendering)
-    let uniforms = crate::renderer::uniforms::Uniforms::from_camera_and_params(
+    // (we'll restore it after rendering), capped like every other upload
+    let limited = if renderer.safe_mode {
+        fractal_params.safe_mode_limited()
+    } else {
+        None
+    };
+    let mut uniforms = crate::renderer::uniforms::Uniforms::from_camera_and_params(
         &temp_camera,
-        fractal_params,
+        limited
+            .as_ref()
+            .map_or(fractal_params, |(limited, _)| limited),
         renderer.start_time.elapsed().as_secs_f32(),
     );
+    uniforms.set_viewport_size(width, height);
     renderer.queue.write_buffer(
         &renderer.uniform_buffer,
         0,
@@ -364,103 +358,8 @@ pub fn render_high_resolution_web(
         pass.draw(0..4, 0..1);
     }
 
-    // Pass 2: Extract bright pixels for bloom
-    {
-        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("HR Bloom Extract"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &bright_view,
-                depth_slice: None,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                },
-            })],
-            depth_stencil_attachment: None,
-            occlusion_query_set: None,
-            timestamp_writes: None,
-        });
-        pass.set_pipeline(&renderer.bloom_extract_pipeline);
-        pass.set_bind_group(0, &scene_bind_group, &[]);
-        pass.set_bind_group(1, &renderer.bloom_params_bind_group, &[]);
-        pass.set_vertex_buffer(0, renderer.postprocess_vertex_buffer.slice(..));
-        pass.draw(0..4, 0..1);
-    }
-
-    // Pass 3: Horizontal blur
-    {
-        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("HR Blur H"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &blur_temp_view,
-                depth_slice: None,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                },
-            })],
-            depth_stencil_attachment: None,
-            occlusion_query_set: None,
-            timestamp_writes: None,
-        });
-        pass.set_pipeline(&renderer.blur_pipeline);
-        pass.set_bind_group(0, &bright_bind_group, &[]);
-        pass.set_bind_group(1, &renderer.blur_h_params_bind_group, &[]);
-        pass.set_vertex_buffer(0, renderer.postprocess_vertex_buffer.slice(..));
-        pass.draw(0..4, 0..1);
-    }
-
-    // Update blur direction to vertical
-    #[repr(C)]
-    #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
-    struct BlurUniforms {
-        direction: [f32; 2],
-        _padding: [f32; 2],
-    }
-    renderer.queue.write_buffer(
-        &renderer.blur_uniform_buffer,
-        0,
-        bytemuck::cast_slice(&[BlurUniforms {
-            direction: [0.0, 1.0],
-            _padding: [0.0; 2],
-        }]),
-    );
-
-    // Pass 4: Vertical blur
-    {
-        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("HR Blur V"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &bloom_view,
-                depth_slice: None,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                },
-            })],
-            depth_stencil_attachment: None,
-            occlusion_query_set: None,
-            timestamp_writes: None,
-        });
-        pass.set_pipeline(&renderer.blur_pipeline);
-        pass.set_bind_group(0, &blur_temp_bind_group, &[]);
-        pass.set_bind_group(1, &renderer.blur_v_params_bind_group, &[]);
-        pass.set_vertex_buffer(0, renderer.postprocess_vertex_buffer.slice(..));
-        pass.draw(0..4, 0..1);
-    }
-
-    // Restore blur direction to horizontal for normal rendering
-    renderer.queue.write_buffer(
-        &renderer.blur_uniform_buffer,
-        0,
-        bytemuck::cast_slice(&[BlurUniforms {
-            direction: [1.0, 0.0],
-            _padding: [0.0; 2],
-        }]),
-    );
+    // Pass 2-4: Bloom mip chain
+    renderer.encode_bloom(&mut encoder, &scene_bind_group, &bloom);
 
     // Pass 5: Composite (scene + bloom + color grading + vignette)
     {
@@ -0,0 +1,98 @@
+use super::App;
+use crate::fractal::{PresentModeSetting, RenderMode};
+
+/// Seconds without input or changes before the idle frame rate applies
+const IDLE_DELAY_SECS: f32 = 2.0;
+/// Update rate while the window is minimized and nothing is drawn
+const MINIMIZED_FPS: u32 = 2;
+
+fn to_wgpu_present_mode(mode: PresentModeSetting) -> wgpu::PresentMode {
+    match mode {
+        PresentModeSetting::Fifo => wgpu::PresentMode::Fifo,
+        PresentModeSetting::Mailbox => wgpu::PresentMode::Mailbox,
+        PresentModeSetting::Immediate => wgpu::PresentMode::Immediate,
+    }
+}
+
+/// Present modes selectable in the UI that the surface actually supports
+pub(super) fn supported_present_modes(modes: &[wgpu::PresentMode]) -> Vec<PresentModeSetting> {
+    [
+        PresentModeSetting::Fifo,
+        PresentModeSetting::Mailbox,
+        PresentModeSetting::Immediate,
+    ]
+    .into_iter()
+    .filter(|mode| modes.contains(&to_wgpu_present_mode(*mode)))
+    .collect()
+}
+
+/// Frame pacing and vsync methods
+impl App {
+    /// Record user input so the frame rate leaves idle throttling
+    pub(super) fn mark_activity(&mut self) {
+        self.last_activity = web_time::Instant::now();
+    }
+
+    /// Apply the present mode selected in the UI if it differs from the surface config
+    pub(super) fn apply_present_mode(&mut self) {
+        let requested = to_wgpu_present_mode(self.ui.present_mode);
+        if self.renderer.config.present_mode != requested {
+            let applied = self.renderer.set_present_mode(requested);
+            if applied != requested {
+                self.ui.present_mode = PresentModeSetting::Fifo;
+            }
+        }
+    }
+
+    /// Whether anything on screen changes without user input
+    fn is_animating(&self) -> bool {
+        #[cfg(feature = "native")]
+        if self.video_recorder.is_recording() {
+            return true;
+        }
+
+        #[cfg(feature = "scripting")]
+        if self.script.is_some() {
+            return true;
+        }
+
+        (self.fractal_params.auto_orbit && self.fractal_params.render_mode == RenderMode::ThreeD)
+            || self.camera_transition.active
+            || self.tour.active
+            || self.camera_controller.is_moving()
+            || self.momentum.is_moving()
+            || self.ui.palette_animation_enabled
+            || (self.fractal_params.fractal_type.uses_accumulation()
+                && !self.fractal_params.attractor_paused)
+            || (self.screenshot_delay.is_some() && !self.screenshot_taken)
+            || self.exit_delay.is_some()
+    }
+
+    /// Whether the scene is static long enough to drop to the idle frame rate
+    fn is_idle(&self) -> bool {
+        let idle_delay = std::time::Duration::from_secs_f32(IDLE_DELAY_SECS);
+        self.ui.idle_throttle_enabled
+            && !self.is_animating()
+            && self.last_activity.elapsed() >= idle_delay
+            && self.camera_last_moved.elapsed() >= idle_delay
+            && self.settings_last_changed.elapsed() >= idle_delay
+    }
+
+    /// When the next frame should be drawn according to the frame rate cap,
+    /// or None if it is already due
+    pub fn next_frame_deadline(&self) -> Option<web_time::Instant> {
+        let fps = if self.minimized {
+            MINIMIZED_FPS
+        } else if self.is_idle() {
+            self.ui.idle_fps
+        } else {
+            self.ui.max_fps
+        };
+        if fps == 0 {
+            return None;
+        }
+
+        let deadline = self.last_frame_time + std::time::Duration::from_secs_f32(1.0 / fps as f32);
+        (deadline > web_time::Instant::now()).then_some(deadline)
+    }
+}
@@ -3,15 +3,89 @@ use crate::fractal::{FractalType, RenderMode};
 use winit::event::*;
 use winit::keyboard::{KeyCode, PhysicalKey};
 
+/// Light rotation per pixel of modifier+drag
+const LIGHT_DRAG_DEGREES_PER_PIXEL: f32 = 0.4;
+/// Boxes smaller than this (in pixels on both sides) count as a click
+const MIN_BOX_ZOOM_PIXELS: f32 = 4.0;
+
+/// Whether a key press toggles between 2D and 3D: plain Tab, while no egui
+/// widget has keyboard focus. Ctrl/Alt/Shift+Tab are left to the OS and egui.
+fn toggles_render_mode(
+    key: PhysicalKey,
+    state: ElementState,
+    repeat: bool,
+    modifiers: egui::Modifiers,
+    egui_wants_keyboard: bool,
+) -> bool {
+    key == PhysicalKey::Code(KeyCode::Tab)
+        && state == ElementState::Pressed
+        && !repeat
+        && modifiers.is_none()
+        && !egui_wants_keyboard
+}
+
 /// Input handling methods
 impl App {
+    #[allow(clippy::collapsible_match)]
     pub fn input(&mut self, event: &WindowEvent) -> bool {
+        if !matches!(event, WindowEvent::RedrawRequested) {
+            self.mark_activity();
+        }
+
+        // egui-winit always reports Tab as consumed (it moves widget focus), so the
+        // 2D/3D toggle is handled before egui sees the key, unless a widget such as
+        // a text field has keyboard focus
+        if let WindowEvent::KeyboardInput {
+            event:
+                KeyEvent {
+                    physical_key,
+                    state,
+                    repeat,
+                    ..
+                },
+            ..
+        } = event
+        {
+            let ctx = self.egui_state.egui_ctx();
+            let modifiers = ctx.input(|i| i.modifiers);
+            let wants_keyboard = ctx.wants_keyboard_input();
+            if toggles_render_mode(*physical_key, *state, *repeat, modifiers, wants_keyboard) {
+                if self.tour.active {
+                    self.stop_tour();
+                } else {
+                    self.toggle_render_mode();
+                }
+                return true;
+            }
+        }
+
         // Let egui handle input first
         let response = self.egui_state.on_window_event(self.window.as_ref(), event);
         if response.consumed {
             return true;
         }
 
+        // Any direct interaction ends tour mode
+        if self.tour.active
+            && matches!(
+                event,
+                WindowEvent::KeyboardInput {
+                    event: KeyEvent {
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                    ..
+                } | WindowEvent::MouseInput {
+                    state: ElementState::Pressed,
+                    ..
+                } | WindowEvent::MouseWheel { .. }
+                    | WindowEvent::Touch(_)
+            )
+        {
+            self.stop_tour();
+            return true;
+        }
+
         // For touch events, we rely solely on egui's consumed flag (checked above)
         // Don't check egui_wants_pointer for touches because:
         // 1. egui-winit may not update pointer position from Touch events on web
@@ -78,104 +152,94 @@ impl App {
                 }
                 // 2D Fractals (1-7)
                 KeyCode::Digit1 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::Mandelbrot2D);
+                    self.switch_fractal(FractalType::Mandelbrot2D);
                     return true;
                 }
                 KeyCode::Digit2 => {
-                    self.fractal_params.switch_fractal(FractalType::Julia2D);
+                    self.switch_fractal(FractalType::Julia2D);
                     return true;
                 }
                 KeyCode::Digit3 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::Sierpinski2D);
+                    self.switch_fractal(FractalType::Sierpinski2D);
                     return true;
                 }
                 KeyCode::Digit4 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::BurningShip2D);
+                    self.switch_fractal(FractalType::BurningShip2D);
                     return true;
                 }
                 KeyCode::Digit5 => {
-                    self.fractal_params.switch_fractal(FractalType::Tricorn2D);
+                    self.switch_fractal(FractalType::Tricorn2D);
                     return true;
                 }
                 KeyCode::Digit6 => {
-                    self.fractal_params.switch_fractal(FractalType::Phoenix2D);
+                    self.switch_fractal(FractalType::Phoenix2D);
                     return true;
                 }
                 KeyCode::Digit7 => {
-                    self.fractal_params.switch_fractal(FractalType::Celtic2D);
+                    self.switch_fractal(FractalType::Celtic2D);
                     return true;
                 }
                 KeyCode::Digit8 => {
-                    self.fractal_params.switch_fractal(FractalType::Newton2D);
+                    self.switch_fractal(FractalType::Newton2D);
                     return true;
                 }
                 KeyCode::Digit9 => {
-                    self.fractal_params.switch_fractal(FractalType::Lyapunov2D);
+                    self.switch_fractal(FractalType::Lyapunov2D);
                     return true;
                 }
                 KeyCode::Digit0 => {
-                    self.fractal_params.switch_fractal(FractalType::Nova2D);
+                    self.switch_fractal(FractalType::Nova2D);
                     return true;
                 }
                 // Magnet and Collatz: use UI (no hotkey due to limited keys)
                 // 3D Fractals (F1-F9)
                 KeyCode::F1 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::Mandelbulb3D);
+                    self.switch_fractal(FractalType::Mandelbulb3D);
                     self.reset_view();
                     return true;
                 }
                 KeyCode::F2 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::MengerSponge3D);
+                    self.switch_fractal(FractalType::MengerSponge3D);
                     self.reset_view();
                     return true;
                 }
                 KeyCode::F3 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::SierpinskiPyramid3D);
+                    self.switch_fractal(FractalType::SierpinskiPyramid3D);
                     self.reset_view();
                     return true;
                 }
                 KeyCode::F4 => {
-                    self.fractal_params.switch_fractal(FractalType::JuliaSet3D);
+                    self.switch_fractal(FractalType::JuliaSet3D);
                     self.reset_view();
                     return true;
                 }
                 KeyCode::F5 => {
-                    self.fractal_params.switch_fractal(FractalType::Mandelbox3D);
+                    self.switch_fractal(FractalType::Mandelbox3D);
                     self.reset_view();
                     return true;
                 }
                 KeyCode::F6 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::OctahedralIFS3D);
+                    self.switch_fractal(FractalType::OctahedralIFS3D);
                     self.reset_view();
                     return true;
                 }
                 KeyCode::F7 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::IcosahedralIFS3D);
+                    self.switch_fractal(FractalType::IcosahedralIFS3D);
                     self.reset_view();
                     return true;
                 }
                 KeyCode::F8 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::ApollonianGasket3D);
+                    self.switch_fractal(FractalType::ApollonianGasket3D);
                     self.reset_view();
                     return true;
                 }
                 KeyCode::F9 => {
-                    self.fractal_params.switch_fractal(FractalType::Kleinian3D);
+                    self.switch_fractal(FractalType::Kleinian3D);
                     self.reset_view();
                     return true;
                 }
                 KeyCode::F10 => {
-                    self.fractal_params
-                        .switch_fractal(FractalType::HybridMandelbulbJulia3D);
+                    self.switch_fractal(FractalType::HybridMandelbulbJulia3D);
                     self.reset_view();
                     return true;
                 }
@@ -206,6 +270,19 @@ impl App {
                     }
                     return true;
                 }
+                KeyCode::KeyC => {
+                    // C: random palette, Shift+C: random palette and color mode
+                    let message = self
+                        .ui
+                        .shuffle_colors(&mut self.fractal_params, self.shift_pressed);
+                    self.ui.show_toast(message);
+                    return true;
+                }
+                KeyCode::F12 if self.shift_pressed => {
+                    self.copy_screenshot = true;
+                    println!("Clipboard screenshot queued...");
+                    return true;
+                }
                 KeyCode::F12 => {
                     self.save_screenshot = true;
                     println!("Screenshot queued...");
@@ -379,6 +456,23 @@ impl App {
             }
         }
 
+        // Ctrl+drag (Cmd+drag on macOS) relights the 3D scene. A drag in progress
+        // keeps its events even when the pointer passes over a UI panel.
+        if self.fractal_params.render_mode == RenderMode::ThreeD
+            && (self.light_drag.is_some() || !egui_blocks_mouse)
+            && self.handle_light_drag(event)
+        {
+            return true;
+        }
+
+        // Ctrl+drag (Cmd+drag on macOS) draws a box to zoom the 2D view into
+        if self.fractal_params.render_mode == RenderMode::TwoD
+            && (self.box_zoom.is_some() || !egui_blocks_mouse)
+            && self.handle_box_zoom(event)
+        {
+            return true;
+        }
+
         // Handle mode-specific input only if egui doesn't block it
         // For touch: always handle (egui consumed flag already checked)
         // For mouse: only if egui doesn't want pointer input
@@ -406,6 +500,108 @@ impl App {
         }
     }
 
+    /// Rotate the light direction with a modifier+drag: horizontal motion changes
+    /// the azimuth and vertical motion the elevation, like a lighting gizmo
+    fn handle_light_drag(&mut self, event: &WindowEvent) -> bool {
+        match event {
+            WindowEvent::MouseInput {
+                button: MouseButton::Left,
+                state: ElementState::Pressed,
+                ..
+            } => {
+                #[cfg(target_os = "macos")]
+                let modifier_pressed = self.egui_state.egui_ctx().input(|i| i.modifiers.command);
+                #[cfg(not(target_os = "macos"))]
+                let modifier_pressed = self.egui_state.egui_ctx().input(|i| i.modifiers.ctrl);
+
+                if modifier_pressed {
+                    self.light_drag = Some(self.cursor_pos);
+                }
+                modifier_pressed
+            }
+            WindowEvent::MouseInput {
+                button: MouseButton::Left,
+                state: ElementState::Released,
+                ..
+            } if self.light_drag.is_some() => {
+                self.light_drag = None;
+                self.settings_last_changed = web_time::Instant::now();
+                self.settings_need_save = true;
+                true
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                let current_pos = (position.x as f32, position.y as f32);
+                self.cursor_pos = current_pos;
+                let Some(last_pos) = self.light_drag else {
+                    return false;
+                };
+                let params = &mut self.fractal_params;
+                params.light_azimuth = (params.light_azimuth
+                    + (current_pos.0 - last_pos.0) * LIGHT_DRAG_DEGREES_PER_PIXEL)
+                    .rem_euclid(360.0);
+                params.light_elevation = (params.light_elevation
+                    - (current_pos.1 - last_pos.1) * LIGHT_DRAG_DEGREES_PER_PIXEL)
+                    .clamp(5.0, 90.0);
+                self.light_drag = Some(current_pos);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Draw a rubber-band box with a modifier+drag and frame the 2D view on it
+    /// when the button is released
+    fn handle_box_zoom(&mut self, event: &WindowEvent) -> bool {
+        match event {
+            WindowEvent::MouseInput {
+                button: MouseButton::Left,
+                state: ElementState::Pressed,
+                ..
+            } => {
+                #[cfg(target_os = "macos")]
+                let modifier_pressed = self.egui_state.egui_ctx().input(|i| i.modifiers.command);
+                #[cfg(not(target_os = "macos"))]
+                let modifier_pressed = self.egui_state.egui_ctx().input(|i| i.modifiers.ctrl);
+
+                if modifier_pressed {
+                    self.box_zoom = Some((self.cursor_pos, self.cursor_pos));
+                }
+                modifier_pressed
+            }
+            WindowEvent::MouseInput {
+                button: MouseButton::Left,
+                state: ElementState::Released,
+                ..
+            } => {
+                let Some((start, end)) = self.box_zoom.take() else {
+                    return false;
+                };
+                let size = (end.0 - start.0).abs().max((end.1 - start.1).abs());
+                if size >= MIN_BOX_ZOOM_PIXELS {
+                    let aspect = self.renderer.size.width.max(1) as f64
+                        / self.renderer.size.height.max(1) as f64;
+                    let (a, b) = (self.view_ndc(start), self.view_ndc(end));
+                    self.fractal_params.frame_2d_box(a, b, aspect);
+                    self.settings_last_changed = web_time::Instant::now();
+                    self.settings_need_save = true;
+                }
+                true
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                let current_pos = (position.x as f32, position.y as f32);
+                self.cursor_pos = current_pos;
+                match &mut self.box_zoom {
+                    Some((_, end)) => {
+                        *end = current_pos;
+                        true
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
     fn handle_2d_input(&mut self, event: &WindowEvent) -> bool {
         match event {
             WindowEvent::MouseInput {
@@ -417,8 +613,12 @@ impl App {
                 // (touch events set their own mouse_pressed state)
                 if self.active_touches.is_empty() {
                     self.mouse_pressed = *state == ElementState::Pressed;
-                    if !self.mouse_pressed {
+                    if self.mouse_pressed {
+                        // A new drag catches the view
+                        self.momentum.stop();
+                    } else {
                         self.last_mouse_pos = None;
+                        self.momentum.release();
                     }
                 }
                 true
@@ -447,6 +647,7 @@ impl App {
 
                         // If this is the first touch, enable panning
                         if self.active_touches.len() == 1 {
+                            self.momentum.stop();
                             self.mouse_pressed = true;
                             self.cursor_pos = current_pos;
                             self.last_mouse_pos = Some(current_pos);
@@ -484,34 +685,8 @@ impl App {
                                 let center_x = (touches[0].0 + touches[1].0) / 2.0;
                                 let center_y = (touches[0].1 + touches[1].1) / 2.0;
 
-                                // Convert pinch center from screen coords to fractal coords
-                                let screen_x =
-                                    (center_x / self.renderer.size.width as f32) * 2.0 - 1.0;
-                                let screen_y =
-                                    1.0 - (center_y / self.renderer.size.height as f32) * 2.0;
-                                let aspect = self.renderer.size.width as f64
-                                    / self.renderer.size.height as f64;
-
-                                // Calculate where the pinch center is in fractal coordinates
-                                let fractal_x = self.fractal_params.center_2d[0]
-                                    + (screen_x as f64) * aspect
-                                        / self.fractal_params.zoom_2d as f64;
-                                let fractal_y = self.fractal_params.center_2d[1]
-                                    + (screen_y as f64) / self.fractal_params.zoom_2d as f64;
-
-                                // Apply zoom
-                                let old_zoom = self.fractal_params.zoom_2d;
-                                self.fractal_params.zoom_2d *= zoom_factor;
-
-                                // Adjust center so the pinch point stays in the same place
-                                // new_center = old_center + (point - old_center) * (1 - old_zoom/new_zoom)
-                                let zoom_ratio = old_zoom / self.fractal_params.zoom_2d;
-                                self.fractal_params.center_2d[0] += (fractal_x
-                                    - self.fractal_params.center_2d[0])
-                                    * (1.0 - zoom_ratio as f64);
-                                self.fractal_params.center_2d[1] += (fractal_y
-                                    - self.fractal_params.center_2d[1])
-                                    * (1.0 - zoom_ratio as f64);
+                                // Keep the pinch point fixed while zooming
+                                self.zoom_2d_at(zoom_factor, (center_x, center_y));
 
                                 // Update initial distance for next frame
                                 self.initial_pinch_distance = Some(current_distance);
@@ -541,6 +716,7 @@ impl App {
                                     delta_x * 2.0 / self.fractal_params.zoom_2d as f64 * aspect;
                                 self.fractal_params.center_2d[1] +=
                                     delta_y * 2.0 / self.fractal_params.zoom_2d as f64;
+                                self.momentum.note_pan([delta_x * aspect, delta_y]);
                             }
                             self.last_mouse_pos = Some(current_pos);
                             true
@@ -561,6 +737,7 @@ impl App {
                             self.mouse_pressed = false;
                             self.last_mouse_pos = None;
                             self.last_touch_time = None; // Reset timing for next touch sequence
+                            self.momentum.release();
                         }
                         // If we're down to 1 touch, re-enable panning
                         else if self.active_touches.len() == 1 {
@@ -595,6 +772,7 @@ impl App {
                             delta_x * 2.0 / self.fractal_params.zoom_2d as f64 * aspect;
                         self.fractal_params.center_2d[1] +=
                             delta_y * 2.0 / self.fractal_params.zoom_2d as f64;
+                        self.momentum.note_pan([delta_x * aspect, delta_y]);
                     }
                     self.last_mouse_pos = Some(current_pos);
                     true
@@ -611,33 +789,13 @@ impl App {
 
                 let zoom_factor = 1.1f32.powf(zoom_delta);
 
-                // Zoom at cursor position (for 2D mode)
                 if self.fractal_params.render_mode == RenderMode::TwoD {
-                    let width = self.renderer.size.width as f64;
-                    let height = self.renderer.size.height as f64;
-                    let aspect = width / height;
-
-                    // Convert cursor position to normalized coordinates [-1, 1]
-                    let norm_x = (self.cursor_pos.0 as f64 / width) * 2.0 - 1.0;
-                    let norm_y = 1.0 - (self.cursor_pos.1 as f64 / height) * 2.0; // Flip Y
-
-                    // Convert to fractal coordinates
-                    let zoom = self.fractal_params.zoom_2d as f64;
-                    let fractal_x =
-                        self.fractal_params.center_2d[0] + (norm_x * 2.0 / zoom) * aspect;
-                    let fractal_y = self.fractal_params.center_2d[1] + norm_y * 2.0 / zoom;
-
-                    // Apply zoom
-                    self.fractal_params.zoom_2d *= zoom_factor;
-
-                    // Adjust center so the point under cursor stays in place
-                    let new_zoom = self.fractal_params.zoom_2d as f64;
-                    let new_fractal_x =
-                        self.fractal_params.center_2d[0] + (norm_x * 2.0 / new_zoom) * aspect;
-                    let new_fractal_y = self.fractal_params.center_2d[1] + norm_y * 2.0 / new_zoom;
-
-                    self.fractal_params.center_2d[0] += fractal_x - new_fractal_x;
-                    self.fractal_params.center_2d[1] += fractal_y - new_fractal_y;
+                    if self.ui.navigation_momentum {
+                        // Eased in over the next frames by update_momentum
+                        self.momentum.add_zoom(zoom_factor, self.cursor_pos);
+                    } else {
+                        self.zoom_2d_at(zoom_factor, self.cursor_pos);
+                    }
                 } else {
                     self.fractal_params.zoom_2d *= zoom_factor;
                 }
@@ -646,4 +804,99 @@ impl App {
             _ => false,
         }
     }
+
+    /// Window position relative to the top-left of the render area
+    pub(super) fn view_position(&self, screen_pos: (f32, f32)) -> (f32, f32) {
+        let (x, y) = self.renderer.render_origin;
+        (screen_pos.0 - x as f32, screen_pos.1 - y as f32)
+    }
+
+    /// Window position to NDC of the render area ([-1, 1], y up)
+    fn view_ndc(&self, screen_pos: (f32, f32)) -> [f64; 2] {
+        let width = self.renderer.size.width.max(1) as f64;
+        let height = self.renderer.size.height.max(1) as f64;
+        let (x, y) = self.view_position(screen_pos);
+        [
+            (x as f64 / width) * 2.0 - 1.0,
+            1.0 - (y as f64 / height) * 2.0,
+        ]
+    }
+
+    /// Zoom the 2D view, anchored at a screen position when zoom-to-cursor is on
+    pub(super) fn zoom_2d_at(&mut self, zoom_factor: f32, screen_pos: (f32, f32)) {
+        let width = self.renderer.size.width.max(1) as f64;
+        let height = self.renderer.size.height.max(1) as f64;
+        let anchor = if self.ui.zoom_to_cursor {
+            self.view_ndc(screen_pos)
+        } else {
+            [0.0, 0.0]
+        };
+        self.fractal_params
+            .zoom_2d_around(zoom_factor, anchor, width / height);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_toggles_render_mode() {
+        let tab = PhysicalKey::Code(KeyCode::Tab);
+        let none = egui::Modifiers::NONE;
+        assert!(toggles_render_mode(
+            tab,
+            ElementState::Pressed,
+            false,
+            none,
+            false
+        ));
+
+        // Not while a widget has focus, on release or on key repeat
+        assert!(!toggles_render_mode(
+            tab,
+            ElementState::Pressed,
+            false,
+            none,
+            true
+        ));
+        assert!(!toggles_render_mode(
+            tab,
+            ElementState::Released,
+            false,
+            none,
+            false
+        ));
+        assert!(!toggles_render_mode(
+            tab,
+            ElementState::Pressed,
+            true,
+            none,
+            false
+        ));
+
+        // Not with Ctrl, Alt or Shift held (window/tab switching, reverse focus)
+        for modifiers in [
+            egui::Modifiers::CTRL,
+            egui::Modifiers::ALT,
+            egui::Modifiers::SHIFT,
+        ] {
+            assert!(!toggles_render_mode(
+                tab,
+                ElementState::Pressed,
+                false,
+                modifiers,
+                false
+            ));
+        }
+
+        let other = PhysicalKey::Code(KeyCode::KeyT);
+        assert!(!toggles_render_mode(
+            other,
+            ElementState::Pressed,
+            false,
+            none,
+            false
+        ));
+    }
 }
@@ -1,20 +1,46 @@
 // Module declarations
+mod blank_frame;
 mod camera_transition;
+mod frame_pacing;
 mod input;
+mod mode_toggle;
+mod momentum;
+mod recovery;
 mod render;
+mod tour;
 mod update;
 
+#[cfg(feature = "native")]
+mod burn_in;
 #[cfg(feature = "native")]
 mod capture;
 #[cfg(target_arch = "wasm32")]
 mod capture_web;
 #[cfg(feature = "native")]
+mod palette_preview;
+#[cfg(feature = "native")]
 mod persistence;
+#[cfg(feature = "native")]
+mod randomize_thumbnails;
+#[cfg(feature = "scripting")]
+mod scripting;
+#[cfg(feature = "native")]
+mod wallpaper;
 
+use blank_frame::BlankFrameCheck;
 use camera_transition::CameraTransition;
+use mode_toggle::ModeSlots;
+use momentum::Momentum2D;
+use recovery::RenderRecovery;
+pub use recovery::RenderStatus;
+use tour::TourState;
+use update::AttractMode;
+
+#[cfg(feature = "native")]
+use palette_preview::PalettePreview;
 
 use crate::camera::{Camera, CameraController};
-use crate::fractal::{FractalParams, RenderMode};
+use crate::fractal::{FractalColoring, FractalParams, FractalType, RenderMode};
 use crate::renderer::Renderer;
 use crate::ui::UI;
 use std::sync::Arc;
@@ -30,14 +56,21 @@ pub struct App {
     camera: Camera,
     camera_controller: CameraController,
     fractal_params: FractalParams,
+    fractal_coloring: std::collections::HashMap<FractalType, FractalColoring>, // Coloring last used with each type
     ui: UI,
     egui_state: egui_winit::State,
     egui_renderer: egui_wgpu::Renderer,
     last_frame_time: web_time::Instant,
+    last_activity: web_time::Instant, // Last user input, for idle frame rate throttling
+    loaded_orbit_trap_image: Option<String>, // Trap image currently uploaded (None = built-in)
+    last_probe_time: web_time::Instant, // Last hover iteration probe, for throttling
     mouse_pressed: bool,
     last_mouse_pos: Option<(f32, f32)>,
     cursor_pos: (f32, f32), // Current cursor position for zoom-at-cursor
     shift_pressed: bool,    // Track shift key for continuous zoom
+    light_drag: Option<(f32, f32)>, // Last cursor position while Ctrl+dragging the 3D light
+    box_zoom: Option<((f32, f32), (f32, f32))>, // Start and current corner of a 2D Ctrl+drag box zoom
+    loaded_preset: Option<crate::fractal::Preset>, // Last preset loaded, for reverting parameters to it
     // Multi-touch pinch-to-zoom tracking
     active_touches: std::collections::HashMap<u64, (f32, f32)>, // touch_id -> (x, y)
     initial_pinch_distance: Option<f32>, // Distance between two fingers at pinch start
@@ -45,8 +78,14 @@ pub struct App {
     frame_count: u32,
     fps_timer: web_time::Instant,
     current_fps: f32,
+    minimized: bool, // Zero-size window: rendering and surface reconfiguration wait for a restore
     save_screenshot: bool,
+    copy_screenshot: bool, // Copy the next frame to the clipboard instead of saving a file
     save_hires_render: Option<(u32, u32)>, // Optional (width, height) for high-res render
+    #[cfg(feature = "native")]
+    apply_wallpaper_monitor: Option<usize>, // Set the next high-res render as wallpaper on this monitor
+    #[cfg(feature = "native")]
+    spanned_guides: Option<((u32, u32), Vec<crate::ui::SpanRect>)>, // Crop guides to save with the next high-res render
     camera_last_moved: web_time::Instant,
     camera_needs_save: bool,
     settings_last_changed: web_time::Instant,
@@ -54,13 +93,28 @@ pub struct App {
     was_auto_orbiting: bool, // Track if we were auto-orbiting in previous frame
     start_time: web_time::Instant, // Track elapsed time for palette animation
     camera_transition: CameraTransition,
+    recovery: RenderRecovery,
     smooth_transitions_enabled: bool,
+    tour: TourState,                       // Kiosk tour mode cycling through presets
+    mode_slots: ModeSlots, // Last 2D and 3D fractal, for toggling between them with Tab
+    momentum: Momentum2D,  // 2D glide after a flick and wheel zoom ease-out
+    attract_mode: Option<AttractMode>, // Idle auto-orbit/palette animation, with what to restore
+    blank_frame: BlankFrameCheck, // Warns when a change leaves the view blank
+    safe_mode_warnings_shown: Vec<String>, // Settings safe mode caps, last toasted
+    #[cfg(feature = "native")]
+    palette_preview: PalettePreview, // Live palette thumbnail in the Color section
     #[cfg(feature = "native")]
     video_recorder: VideoRecorder,
+    #[cfg(feature = "native")]
+    capture_counter: u32, // Last {counter} value used in a capture filename
+    #[cfg(feature = "native")]
+    recording_start_params: Option<FractalParams>, // Parameters when recording started, for the burn-in
     screenshot_delay: Option<f32>, // CLI option: take screenshot after N seconds
     exit_delay: Option<f32>,       // CLI option: exit after N seconds
     screenshot_taken: bool,        // Track if delayed screenshot was taken
     should_exit: bool,             // Track if app should exit
+    #[cfg(feature = "scripting")]
+    script: Option<scripting::ScriptHost>, // CLI option: script driving params each frame
 }
 
 impl App {
@@ -73,47 +127,71 @@ impl App {
         preset_name: Option<String>,
     ) -> Self {
         let window = Arc::new(window);
-        let size = window.inner_size();
+        let mut size = window.inner_size();
+
+        // A window created minimized has no size yet; the surface needs one
+        if size.width == 0 || size.height == 0 {
+            eprintln!(
+                "Window size is {}x{}, using fallback 800x600",
+                size.width, size.height
+            );
+            size = PhysicalSize::new(800, 600);
+        }
 
-        // Load GPU preferences
+        // Load GPU and surface format preferences
         let prefs = crate::fractal::AppPreferences::load();
-        let renderer = if let Some(gpu_index) = prefs.preferred_gpu_index {
+        if let Some(gpu_index) = prefs.preferred_gpu_index {
             println!("Using preferred GPU index: {}", gpu_index);
-            Renderer::new_with_gpu_preference(window.clone(), size, Some(gpu_index)).await
-        } else {
-            Renderer::new(window.clone(), size).await
-        };
+        }
+        let renderer = Renderer::new_with_gpu_preference(
+            window.clone(),
+            size,
+            prefs.preferred_gpu_index,
+            prefs.surface_format,
+        )
+        .await;
 
         // Load fractal params from preset if specified, otherwise from saved settings
-        let fractal_params = if let Some(preset) = preset_name {
+        let loaded_preset = preset_name.and_then(|preset| {
             // First try built-in presets
             if let Some(preset_data) = crate::fractal::PresetGallery::get_builtin_preset(&preset) {
                 println!("Loaded built-in preset: {}", preset);
-                FractalParams::from_settings(preset_data.settings.clone())
+                Some(preset_data.clone())
             } else {
                 // Try to load user preset from file
                 match crate::fractal::PresetGallery::load_preset(&preset) {
                     Ok(preset_data) => {
                         println!("Loaded user preset: {}", preset);
-                        FractalParams::from_settings(preset_data.settings)
+                        Some(preset_data)
                     }
                     Err(e) => {
                         eprintln!("Failed to load preset '{}': {}", preset, e);
                         eprintln!("Falling back to saved settings or defaults");
-                        FractalParams::load_from_file().unwrap_or_default()
+                        None
                     }
                 }
             }
-        } else {
-            FractalParams::load_from_file().unwrap_or_default()
+        });
+        let (fractal_params, adjustments) = match &loaded_preset {
+            Some(preset) => FractalParams::from_settings_checked(preset.settings.clone()),
+            None => (
+                FractalParams::load_from_file().unwrap_or_default(),
+                Vec::new(),
+            ),
         };
 
-        let mut camera = Camera::new(size.width, size.height);
+        let mut camera = Camera::new(size.width, size.height).with_up(fractal_params.up_axis.up());
         camera.fovy = fractal_params.camera_fov;
         let mut camera_controller = CameraController::new(fractal_params.camera_speed);
+        camera_controller.set_up(camera.up);
 
         // Load camera position and UI state from settings if available
         let mut ui = UI::new();
+        ui.loaded_preset_name = loaded_preset.as_ref().map(|preset| preset.name.clone());
+        for adjustment in adjustments {
+            log::warn!("Preset adjusted: {}", adjustment);
+            ui.show_toast(format!("⚠ {}", adjustment));
+        }
         if let Ok(content) = std::fs::read_to_string(
             directories::ProjectDirs::from("com", "fractal", "par-fractal")
                 .map(|dirs| dirs.config_dir().join("settings.yaml"))
@@ -127,9 +205,27 @@ impl App {
                 ui.load_ui_state(settings.ui_state);
                 ui.auto_open_captures = settings.auto_open_captures;
                 ui.custom_width = settings.custom_width;
+                ui.capture_antialiasing = settings.capture_antialiasing;
+                ui.accumulation_samples = settings
+                    .accumulation_samples
+                    .clamp(1, crate::fractal::MAX_ACCUMULATION_SAMPLES);
+                ui.transparent_background = settings.transparent_background;
+                ui.capture_16bit = settings.capture_16bit;
+                ui.capture_gamma = settings.capture_gamma.clamp(
+                    *crate::fractal::CAPTURE_GAMMA_RANGE.start(),
+                    *crate::fractal::CAPTURE_GAMMA_RANGE.end(),
+                );
                 ui.custom_height = settings.custom_height;
+                ui.lock_capture_aspect = settings.lock_capture_aspect;
+                ui.capture_aspect = settings.capture_aspect;
             }
         }
+        ui.gpu_supports_f64 = renderer.supports_f64;
+        ui.supported_present_modes =
+            frame_pacing::supported_present_modes(&renderer.supported_present_modes);
+        ui.supported_surface_formats = crate::renderer::surface_format::supported_surface_formats(
+            &renderer.supported_surface_formats,
+        );
 
         let egui_ctx = egui::Context::default();
         let egui_state =
@@ -137,7 +233,7 @@ impl App {
 
         let egui_renderer = egui_wgpu::Renderer::new(
             &renderer.device,
-            renderer.config.format,
+            renderer.ui_format(),
             egui_wgpu::RendererOptions {
                 msaa_samples: 1,
                 ..Default::default()
@@ -145,6 +241,7 @@ impl App {
         );
 
         let video_recorder = VideoRecorder::new(size.width, size.height, 60, VideoFormat::MP4);
+        let recovery = RenderRecovery::new(&renderer);
 
         Self {
             window,
@@ -152,22 +249,34 @@ impl App {
             camera,
             camera_controller,
             fractal_params,
+            fractal_coloring: std::collections::HashMap::new(),
             ui,
             egui_state,
             egui_renderer,
             last_frame_time: web_time::Instant::now(),
+            last_activity: web_time::Instant::now(),
+            loaded_orbit_trap_image: None,
+            last_probe_time: web_time::Instant::now(),
             mouse_pressed: false,
             last_mouse_pos: None,
             cursor_pos: (0.0, 0.0),
             shift_pressed: false,
+            light_drag: None,
+            box_zoom: None,
+            loaded_preset,
             active_touches: std::collections::HashMap::new(),
             initial_pinch_distance: None,
             last_touch_time: None,
             frame_count: 0,
             fps_timer: web_time::Instant::now(),
             current_fps: 0.0,
+            minimized: false,
             save_screenshot: false,
+            copy_screenshot: false,
             save_hires_render: None,
+            apply_wallpaper_monitor: None,
+            #[cfg(feature = "native")]
+            spanned_guides: None,
             camera_last_moved: web_time::Instant::now(),
             camera_needs_save: false,
             settings_last_changed: web_time::Instant::now(),
@@ -175,12 +284,24 @@ impl App {
             was_auto_orbiting: false,
             start_time: web_time::Instant::now(),
             camera_transition: CameraTransition::new(),
+            recovery,
             smooth_transitions_enabled: true,
+            tour: TourState::new(),
+            mode_slots: ModeSlots::default(),
+            momentum: Momentum2D::default(),
+            attract_mode: None,
+            blank_frame: BlankFrameCheck::new(),
+            safe_mode_warnings_shown: Vec::new(),
+            palette_preview: PalettePreview::new(),
             video_recorder,
+            capture_counter: 0,
+            recording_start_params: None,
             screenshot_delay,
             exit_delay,
             screenshot_taken: false,
             should_exit: false,
+            #[cfg(feature = "scripting")]
+            script: None,
         }
     }
 
@@ -216,26 +337,40 @@ impl App {
 
         // Use default fractal params for web (no persistent storage yet)
         // TODO: Load from localStorage via platform abstraction
-        let fractal_params = if let Some(preset) = preset_name {
-            match crate::fractal::PresetGallery::get_builtin_preset(&preset) {
-                Some(preset_data) => {
-                    log::info!("Loaded preset: {}", preset);
-                    FractalParams::from_settings(preset_data.settings.clone())
-                }
-                None => {
-                    log::warn!("Preset '{}' not found, using defaults", preset);
-                    FractalParams::default()
-                }
-            }
-        } else {
-            FractalParams::default()
+        let loaded_preset =
+            preset_name.and_then(
+                |preset| match crate::fractal::PresetGallery::get_builtin_preset(&preset) {
+                    Some(preset_data) => {
+                        log::info!("Loaded preset: {}", preset);
+                        Some(preset_data.clone())
+                    }
+                    None => {
+                        log::warn!("Preset '{}' not found, using defaults", preset);
+                        None
+                    }
+                },
+            );
+        let (fractal_params, adjustments) = match &loaded_preset {
+            Some(preset) => FractalParams::from_settings_checked(preset.settings.clone()),
+            None => (FractalParams::default(), Vec::new()),
         };
 
-        let mut camera = Camera::new(size.width, size.height);
+        let mut camera = Camera::new(size.width, size.height).with_up(fractal_params.up_axis.up());
         camera.fovy = fractal_params.camera_fov;
-        let camera_controller = CameraController::new(fractal_params.camera_speed);
+        let mut camera_controller = CameraController::new(fractal_params.camera_speed);
+        camera_controller.set_up(camera.up);
 
-        let ui = UI::new();
+        let mut ui = UI::new();
+        ui.loaded_preset_name = loaded_preset.as_ref().map(|preset| preset.name.clone());
+        for adjustment in adjustments {
+            log::warn!("Preset adjusted: {}", adjustment);
+            ui.show_toast(format!("⚠ {}", adjustment));
+        }
+        ui.supported_present_modes =
+            frame_pacing::supported_present_modes(&renderer.supported_present_modes);
+        ui.supported_surface_formats = crate::renderer::surface_format::supported_surface_formats(
+            &renderer.supported_surface_formats,
+        );
 
         let egui_ctx = egui::Context::default();
         let egui_state =
@@ -243,34 +378,45 @@ impl App {
 
         let egui_renderer = egui_wgpu::Renderer::new(
             &renderer.device,
-            renderer.config.format,
+            renderer.ui_format(),
             egui_wgpu::RendererOptions {
                 msaa_samples: 1,
                 ..Default::default()
             },
         );
 
+        let recovery = RenderRecovery::new(&renderer);
+
         Ok(Self {
             window,
             renderer,
             camera,
             camera_controller,
             fractal_params,
+            fractal_coloring: std::collections::HashMap::new(),
             ui,
             egui_state,
             egui_renderer,
             last_frame_time: web_time::Instant::now(),
+            last_activity: web_time::Instant::now(),
+            loaded_orbit_trap_image: None,
+            last_probe_time: web_time::Instant::now(),
             mouse_pressed: false,
             last_mouse_pos: None,
             cursor_pos: (0.0, 0.0),
             shift_pressed: false,
+            light_drag: None,
+            box_zoom: None,
+            loaded_preset,
             active_touches: std::collections::HashMap::new(),
             initial_pinch_distance: None,
             last_touch_time: None,
             frame_count: 0,
             fps_timer: web_time::Instant::now(),
             current_fps: 0.0,
+            minimized: false,
             save_screenshot: false,
+            copy_screenshot: false,
             save_hires_render: None,
             camera_last_moved: web_time::Instant::now(),
             camera_needs_save: false,
@@ -279,11 +425,20 @@ impl App {
             was_auto_orbiting: false,
             start_time: web_time::Instant::now(),
             camera_transition: CameraTransition::new(),
+            recovery,
             smooth_transitions_enabled: true,
+            tour: TourState::new(),
+            mode_slots: ModeSlots::default(),
+            momentum: Momentum2D::default(),
+            attract_mode: None,
+            blank_frame: BlankFrameCheck::new(),
+            safe_mode_warnings_shown: Vec::new(),
             screenshot_delay,
             exit_delay,
             screenshot_taken: false,
             should_exit: false,
+            #[cfg(feature = "scripting")]
+            script: None,
         })
     }
 
@@ -295,17 +450,24 @@ impl App {
         self.should_exit
     }
 
+    /// Window (surface) size; the fractal may render to a smaller area beside a docked panel
     pub fn size(&self) -> PhysicalSize<u32> {
-        self.renderer.size
+        PhysicalSize::new(self.renderer.config.width, self.renderer.config.height)
     }
 
     pub fn resize(&mut self, new_size: PhysicalSize<u32>) {
+        // A minimized window reports a zero size, which the surface can't be
+        // configured with. Keep the last configuration until it's restored.
+        self.minimized = new_size.width == 0 || new_size.height == 0;
+        if self.minimized {
+            return;
+        }
         self.renderer.resize(new_size);
         self.camera.resize(new_size.width, new_size.height);
 
         // Persist window size (native only)
         #[cfg(feature = "native")]
-        if new_size.width > 0 && new_size.height > 0 {
+        {
             let mut prefs = crate::fractal::AppPreferences::load();
             prefs.set_window_size(new_size.width, new_size.height);
             if let Err(e) = prefs.save() {
@@ -314,7 +476,29 @@ impl App {
         }
     }
 
+    /// Switch fractal type, restoring the coloring last used with the new type
+    fn switch_fractal(&mut self, fractal_type: FractalType) {
+        let previous = FractalColoring::capture(&self.fractal_params);
+        let previous_type = self.fractal_params.fractal_type;
+        self.fractal_params.switch_fractal(fractal_type);
+        self.restore_fractal_coloring(previous_type, previous);
+    }
+
+    /// After a fractal type switch, remember the coloring that was used with
+    /// `previous_type` and restore the one last used with the new type
+    fn restore_fractal_coloring(&mut self, previous_type: FractalType, previous: FractalColoring) {
+        let current_type = self.fractal_params.fractal_type;
+        if !self.ui.remember_fractal_coloring || current_type == previous_type {
+            return;
+        }
+        self.fractal_coloring.insert(previous_type, previous);
+        if let Some(coloring) = self.fractal_coloring.get(&current_type) {
+            coloring.apply(&mut self.fractal_params);
+        }
+    }
+
     fn reset_view(&mut self) {
+        self.momentum.stop();
         match self.fractal_params.render_mode {
             RenderMode::TwoD => {
                 // Re-apply fractal defaults (this sets the correct center and zoom for each fractal type)
@@ -333,9 +517,11 @@ impl App {
             }
             RenderMode::ThreeD => {
                 let size = self.renderer.size;
-                self.camera = Camera::new(size.width, size.height);
+                let up = self.fractal_params.up_axis.up();
+                self.camera = Camera::new(size.width, size.height).with_up(up);
                 self.camera.fovy = self.fractal_params.camera_fov;
                 self.camera_controller = CameraController::new(self.fractal_params.camera_speed);
+                self.camera_controller.set_up(up);
             }
         }
     }
@@ -0,0 +1,100 @@
+//! Tab flips between the last 2D and the last 3D fractal.
+//!
+//! Leaving a render mode with Tab keeps a snapshot of its parameters and view
+//! in that mode's slot, and coming back restores it. Fractals picked any other
+//! way are tracked by type, so the toggle always returns to the fractal last
+//! shown in the other mode, from its defaults when there's no snapshot of it.
+
+use super::App;
+use crate::fractal::{FractalParams, FractalType, RenderMode};
+
+/// The parameters and 3D camera of one render mode when it was left
+pub(super) struct ModeSnapshot {
+    params: FractalParams,
+    camera_position: glam::Vec3,
+    camera_target: glam::Vec3,
+    camera_fovy: f32,
+}
+
+/// The last fractal of each render mode, for toggling between them
+#[derive(Default)]
+pub(super) struct ModeSlots {
+    two_d: Option<ModeSnapshot>,
+    three_d: Option<ModeSnapshot>,
+    last_2d_type: Option<FractalType>,
+    last_3d_type: Option<FractalType>,
+}
+
+impl ModeSlots {
+    fn slot(&mut self, mode: RenderMode) -> &mut Option<ModeSnapshot> {
+        match mode {
+            RenderMode::TwoD => &mut self.two_d,
+            RenderMode::ThreeD => &mut self.three_d,
+        }
+    }
+
+    fn last_type(&mut self, mode: RenderMode) -> &mut Option<FractalType> {
+        match mode {
+            RenderMode::TwoD => &mut self.last_2d_type,
+            RenderMode::ThreeD => &mut self.last_3d_type,
+        }
+    }
+
+    /// Remember the fractal shown this frame as the last one of its mode
+    pub(super) fn note_fractal(&mut self, fractal_type: FractalType) {
+        *self.last_type(fractal_type.render_mode()) = Some(fractal_type);
+    }
+}
+
+/// Render mode toggle methods
+impl App {
+    /// Switch to the last fractal of the other render mode, restoring its
+    /// parameters and view if it was left with this toggle
+    pub(super) fn toggle_render_mode(&mut self) {
+        let current = self.fractal_params.render_mode;
+        let target = match current {
+            RenderMode::TwoD => RenderMode::ThreeD,
+            RenderMode::ThreeD => RenderMode::TwoD,
+        };
+
+        *self.mode_slots.slot(current) = Some(ModeSnapshot {
+            params: self.fractal_params.clone(),
+            camera_position: self.camera.position,
+            camera_target: self.camera.target,
+            camera_fovy: self.camera.fovy,
+        });
+
+        let fractal_type = self.mode_slots.last_type(target).unwrap_or(match target {
+            RenderMode::TwoD => FractalType::Mandelbrot2D,
+            RenderMode::ThreeD => FractalType::Mandelbulb3D,
+        });
+        match self.mode_slots.slot(target).take() {
+            // A snapshot is stale once another fractal of that mode was picked
+            Some(snapshot) if snapshot.params.fractal_type == fractal_type => {
+                self.fractal_params = snapshot.params;
+                if target == RenderMode::ThreeD {
+                    self.camera.position = snapshot.camera_position;
+                    self.camera.target = snapshot.camera_target;
+                    self.camera.fovy = snapshot.camera_fovy;
+                    self.camera_controller
+                        .point_at_target(self.camera.position, self.camera.target);
+                }
+                if self.fractal_params.attractor_accumulation_enabled {
+                    self.fractal_params.attractor_pending_clear = true;
+                    self.fractal_params.attractor_total_iterations = 0;
+                }
+            }
+            _ => {
+                self.switch_fractal(fractal_type);
+                self.reset_view();
+            }
+        }
+
+        self.settings_last_changed = web_time::Instant::now();
+        self.settings_need_save = true;
+        self.blank_frame.note_change();
+        self.ui
+            .show_toast(format!("⇆ Switched to {:?}", fractal_type));
+        println!("Toggled render mode: {:?}", fractal_type);
+    }
+}
@@ -0,0 +1,127 @@
+//! Momentum for 2D navigation.
+//!
+//! A flick keeps the view gliding after the drag is released, slowing to a
+//! stop, and wheel zoom eases out over a few frames instead of jumping.
+//! Velocity is kept in view heights per second and applied to the f64 center
+//! at the current zoom, so a glide keeps its precision at deep zoom.
+
+use super::App;
+use crate::fractal::RenderMode;
+
+/// The pan speed decays as e^(-PAN_FRICTION * seconds)
+const PAN_FRICTION: f64 = 4.0;
+/// Pan speed below which a glide stops, in view heights per second
+const MIN_PAN_SPEED: f64 = 0.01;
+/// A release longer than this after the last movement is a stop, not a flick
+const FLICK_WINDOW_SECS: f64 = 0.08;
+/// Time constant of the wheel zoom ease-out, in seconds
+const ZOOM_EASE_SECS: f32 = 0.08;
+/// Wheel zoom left to apply below which the ease-out ends (log zoom factor)
+const MIN_ZOOM_REMAINING: f32 = 1e-3;
+
+/// Pan velocity and pending wheel zoom of the 2D view
+#[derive(Default)]
+pub(super) struct Momentum2D {
+    /// View heights per second, in screen directions (x right, y down)
+    pan_velocity: [f64; 2],
+    /// Time of the last drag movement, while dragging
+    last_pan: Option<web_time::Instant>,
+    /// Wheel zoom still to apply, as a log zoom factor
+    zoom_remaining: f32,
+    /// Screen position the pending zoom is anchored at
+    zoom_anchor: (f32, f32),
+}
+
+impl Momentum2D {
+    /// Track a drag movement of `delta` view heights for the glide after release
+    pub(super) fn note_pan(&mut self, delta: [f64; 2]) {
+        let now = web_time::Instant::now();
+        if let Some(last) = self.last_pan {
+            let dt = (now - last).as_secs_f64().max(1e-3);
+            // Blend with the previous speed so one jittery event doesn't decide the flick
+            for (velocity, delta) in self.pan_velocity.iter_mut().zip(delta) {
+                *velocity = *velocity * 0.5 + delta / dt * 0.5;
+            }
+        } else {
+            self.pan_velocity = [0.0; 2];
+        }
+        self.last_pan = Some(now);
+    }
+
+    /// The drag was released: glide on unless the pointer had already stopped
+    pub(super) fn release(&mut self) {
+        let flicked = self
+            .last_pan
+            .is_some_and(|last| last.elapsed().as_secs_f64() < FLICK_WINDOW_SECS);
+        if !flicked {
+            self.pan_velocity = [0.0; 2];
+        }
+        self.last_pan = None;
+    }
+
+    /// Stop gliding and drop any pending zoom, e.g. when a new drag begins
+    pub(super) fn stop(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Queue a wheel zoom to ease in, anchored at a screen position
+    pub(super) fn add_zoom(&mut self, zoom_factor: f32, anchor: (f32, f32)) {
+        self.zoom_remaining += zoom_factor.ln();
+        self.zoom_anchor = anchor;
+    }
+
+    pub(super) fn is_moving(&self) -> bool {
+        self.pan_velocity != [0.0; 2] || self.zoom_remaining != 0.0
+    }
+
+    /// Advance the glide by `dt` seconds, returning the pan to apply this
+    /// frame in view heights
+    fn step_pan(&mut self, dt: f32) -> [f64; 2] {
+        let pan = self.pan_velocity.map(|velocity| velocity * dt as f64);
+        let decay = (-PAN_FRICTION * dt as f64).exp();
+        self.pan_velocity = self.pan_velocity.map(|velocity| velocity * decay);
+        if self.pan_velocity[0].hypot(self.pan_velocity[1]) < MIN_PAN_SPEED {
+            self.pan_velocity = [0.0; 2];
+        }
+        pan
+    }
+
+    /// Advance the zoom ease-out by `dt` seconds, returning the zoom factor
+    /// to apply this frame
+    fn step_zoom(&mut self, dt: f32) -> f32 {
+        let mut zoom = self.zoom_remaining * (1.0 - (-dt / ZOOM_EASE_SECS).exp());
+        if (self.zoom_remaining - zoom).abs() < MIN_ZOOM_REMAINING {
+            zoom = self.zoom_remaining;
+        }
+        self.zoom_remaining -= zoom;
+        zoom.exp()
+    }
+}
+
+/// 2D navigation momentum methods
+impl App {
+    /// Move the 2D view by the glide and pending zoom left from the last
+    /// drag and wheel input
+    pub(super) fn update_momentum(&mut self, dt: f32) {
+        if !self.ui.navigation_momentum || self.fractal_params.render_mode != RenderMode::TwoD {
+            self.momentum.stop();
+            return;
+        }
+        if !self.momentum.is_moving() {
+            return;
+        }
+
+        // While dragging the view follows the pointer; only the zoom eases
+        if !self.mouse_pressed {
+            let pan = self.momentum.step_pan(dt);
+            let zoom = self.fractal_params.zoom_2d as f64;
+            self.fractal_params.center_2d[0] -= pan[0] * 2.0 / zoom;
+            self.fractal_params.center_2d[1] += pan[1] * 2.0 / zoom;
+        }
+        let zoom_factor = self.momentum.step_zoom(dt);
+        if zoom_factor != 1.0 {
+            let anchor = self.momentum.zoom_anchor;
+            self.zoom_2d_at(zoom_factor, anchor);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Live palette preview thumbnail
+//!
+//! A small offscreen render of the current view shown in the Color &
+//! Visualization section. It is regenerated when the palette or color mode
+//! changes, or when a palette button or entry is hovered so a palette can be
+//! tried before it's applied. Renders are throttled to a few per second.
+
+use super::App;
+use crate::fractal::{ColorMode, FractalType, ProceduralPalette, RenderMode};
+
+/// Thumbnail width in pixels (height follows the window aspect ratio)
+const PREVIEW_WIDTH: u32 = 192;
+/// Minimum time between thumbnail renders
+const PREVIEW_INTERVAL_SECS: f32 = 0.25;
+
+/// Inputs the current thumbnail was rendered from
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PreviewKey {
+    fractal_type: FractalType,
+    render_mode: RenderMode,
+    color_mode: ColorMode,
+    palette_index: usize,
+    procedural_palette: ProceduralPalette,
+}
+
+pub(super) struct PalettePreview {
+    key: Option<PreviewKey>,
+    last_render: web_time::Instant,
+}
+
+impl PalettePreview {
+    pub(super) fn new() -> Self {
+        Self {
+            key: None,
+            last_render: web_time::Instant::now(),
+        }
+    }
+}
+
+/// Palette preview methods
+impl App {
+    /// Re-render the palette thumbnail if what it shows has changed (throttled).
+    /// Only runs while the Color & Visualization section is visible.
+    pub(super) fn update_palette_preview(&mut self) {
+        if !self.ui.show_ui || !self.ui.ui_state.color_viz_open {
+            return;
+        }
+
+        let mut params = self.fractal_params.clone();
+        if let Some(candidate) = self.ui.palette_preview_candidate {
+            candidate.apply(&mut params);
+        }
+        let key = PreviewKey {
+            fractal_type: params.fractal_type,
+            render_mode: params.render_mode,
+            color_mode: params.color_mode,
+            palette_index: params.palette_index,
+            procedural_palette: params.procedural_palette,
+        };
+        if self.palette_preview.key == Some(key)
+            || self.palette_preview.last_render.elapsed().as_secs_f32() < PREVIEW_INTERVAL_SECS
+        {
+            return;
+        }
+
+        let aspect =
+            self.renderer.size.width.max(1) as f32 / self.renderer.size.height.max(1) as f32;
+        let height = ((PREVIEW_WIDTH as f32 / aspect).round() as u32).clamp(16, PREVIEW_WIDTH);
+        match self.render_to_image(&params, PREVIEW_WIDTH, height) {
+            Ok(img) => {
+                let image = egui::ColorImage::from_rgba_unmultiplied(
+                    [img.width() as usize, img.height() as usize],
+                    img.as_raw(),
+                );
+                match self.ui.palette_preview_texture.as_mut() {
+                    Some(texture) => texture.set(image, egui::TextureOptions::LINEAR),
+                    None => {
+                        self.ui.palette_preview_texture =
+                            Some(self.egui_state.egui_ctx().load_texture(
+                                "palette_preview",
+                                image,
+                                egui::TextureOptions::LINEAR,
+                            ));
+                    }
+                }
+            }
+            Err(e) => eprintln!("Failed to render palette preview: {}", e),
+        }
+        self.palette_preview.key = Some(key);
+        self.palette_preview.last_render = web_time::Instant::now();
+    }
+}
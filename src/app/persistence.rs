@@ -9,7 +9,14 @@ impl App {
         settings.ui_state = self.ui.get_ui_state().clone();
         settings.auto_open_captures = self.ui.auto_open_captures;
         settings.custom_width = self.ui.custom_width.clone();
+        settings.capture_antialiasing = self.ui.capture_antialiasing;
+        settings.accumulation_samples = self.ui.accumulation_samples;
+        settings.transparent_background = self.ui.transparent_background;
+        settings.capture_16bit = self.ui.capture_16bit;
+        settings.capture_gamma = self.ui.capture_gamma;
         settings.custom_height = self.ui.custom_height.clone();
+        settings.lock_capture_aspect = self.ui.lock_capture_aspect;
+        settings.capture_aspect = self.ui.capture_aspect;
 
         if let Some(proj_dirs) = directories::ProjectDirs::from("com", "fractal", "par-fractal") {
             let config_dir = proj_dirs.config_dir();
@@ -35,4 +42,42 @@ impl App {
     pub(super) fn save_camera_settings(&self) {
         self.save_all_settings();
     }
+
+    /// Discard unsaved changes by reloading settings and camera from disk.
+    /// Returns false if there is no saved settings file to revert to.
+    pub(super) fn revert_to_saved_settings(&mut self) -> bool {
+        let Some(proj_dirs) = directories::ProjectDirs::from("com", "fractal", "par-fractal")
+        else {
+            return false;
+        };
+        let settings_path = proj_dirs.config_dir().join("settings.yaml");
+        let Ok(content) = std::fs::read_to_string(&settings_path) else {
+            return false;
+        };
+        let settings = match serde_yaml::from_str::<crate::fractal::Settings>(&content) {
+            Ok(settings) => settings,
+            Err(e) => {
+                eprintln!("Failed to parse saved settings: {}", e);
+                return false;
+            }
+        };
+
+        self.camera.position = glam::Vec3::from_array(settings.camera_position);
+        self.camera.target = glam::Vec3::from_array(settings.camera_target);
+        self.camera.fovy = settings.camera_fov;
+        self.camera_controller.set_speed(settings.camera_speed);
+        self.camera_controller
+            .point_at_target(self.camera.position, self.camera.target);
+        let (params, adjustments) = crate::fractal::FractalParams::from_settings_checked(settings);
+        self.fractal_params = params;
+        for adjustment in adjustments {
+            self.ui.show_toast(format!("⚠ {}", adjustment));
+        }
+
+        // Nothing left to persist after reverting
+        self.settings_need_save = false;
+        self.camera_needs_save = false;
+        println!("Settings reverted to {:?}", settings_path);
+        true
+    }
 }
@@ -0,0 +1,42 @@
+//! Thumbnails for the Randomize gallery
+//!
+//! Each Randomize result is rendered offscreen once, one per frame, so the
+//! gallery fills in over the frames after a roll.
+
+use super::App;
+
+/// Thumbnail width in pixels (height follows the window aspect ratio)
+const THUMBNAIL_WIDTH: u32 = 144;
+
+/// Randomize gallery methods
+impl App {
+    /// Render the next Randomize result still missing a thumbnail
+    pub(super) fn update_randomize_thumbnails(&mut self) {
+        let Some((index, params)) = self.ui.randomize_gallery.next_without_thumbnail() else {
+            return;
+        };
+        let params = params.clone();
+
+        let aspect =
+            self.renderer.size.width.max(1) as f32 / self.renderer.size.height.max(1) as f32;
+        let height = ((THUMBNAIL_WIDTH as f32 / aspect).round() as u32).clamp(16, THUMBNAIL_WIDTH);
+        match self.render_to_image(&params, THUMBNAIL_WIDTH, height) {
+            Ok(img) => {
+                let image = egui::ColorImage::from_rgba_unmultiplied(
+                    [img.width() as usize, img.height() as usize],
+                    img.as_raw(),
+                );
+                let texture = self.egui_state.egui_ctx().load_texture(
+                    format!("randomize_{}", index),
+                    image,
+                    egui::TextureOptions::LINEAR,
+                );
+                self.ui.randomize_gallery.set_thumbnail(index, texture);
+            }
+            Err(e) => {
+                eprintln!("Failed to render randomize thumbnail: {}", e);
+                self.ui.randomize_gallery.set_thumbnail_failed(index);
+            }
+        }
+    }
+}
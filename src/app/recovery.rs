@@ -0,0 +1,203 @@
+//! Recovery from lost GPU devices and surfaces
+//!
+//! A lost surface is usually fixed by reconfiguring it, but a lost device (a
+//! driver reset, a laptop switching GPUs) or a panic inside the frame needs a
+//! whole new `Renderer`. The params and camera live in `App`, so rebuilding the
+//! renderer keeps the current view. The event loop only gives up after several
+//! rebuilds in a row fail to produce a frame.
+
+use super::App;
+use crate::renderer::Renderer;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Consecutive lost surfaces tolerated before the renderer is rebuilt
+const LOST_FRAMES_BEFORE_REBUILD: u32 = 3;
+/// Renderer rebuilds tried without a successful frame before giving up
+const MAX_REBUILD_ATTEMPTS: u32 = 3;
+
+/// What the event loop should do after a frame
+pub enum RenderStatus {
+    Ok,
+    /// The GPU state is unusable: rebuild the renderer (`App::rebuild_renderer`
+    /// on native, `App::renderer_rebuild` + `App::install_renderer` on the web)
+    Rebuild,
+    /// Recovery failed; report the message and exit
+    Fatal(String),
+}
+
+pub(super) struct RenderRecovery {
+    lost_frames: u32,
+    rebuild_attempts: u32,
+    rebuild_pending: bool,
+    /// Set by wgpu's device lost callback for the current device
+    device_lost: Arc<AtomicBool>,
+}
+
+impl RenderRecovery {
+    pub(super) fn new(renderer: &Renderer) -> Self {
+        let mut recovery = Self {
+            lost_frames: 0,
+            rebuild_attempts: 0,
+            rebuild_pending: false,
+            device_lost: Arc::new(AtomicBool::new(false)),
+        };
+        recovery.watch(renderer);
+        recovery
+    }
+
+    /// Watch a new device for loss. Each device gets its own flag, so dropping
+    /// the old device can't flag the new one.
+    fn watch(&mut self, renderer: &Renderer) {
+        let device_lost = Arc::new(AtomicBool::new(false));
+        self.device_lost = device_lost.clone();
+        renderer
+            .device
+            .set_device_lost_callback(move |reason, message| {
+                if reason != wgpu::DeviceLostReason::Destroyed {
+                    log::error!("GPU device lost: {}", message);
+                    device_lost.store(true, Ordering::Relaxed);
+                }
+            });
+    }
+}
+
+/// Render recovery methods
+impl App {
+    /// Render a frame, tracking failures to decide whether the renderer has to
+    /// be rebuilt. On native, a panic during the frame counts as a failure.
+    /// Nothing is drawn while the window is minimized.
+    pub fn render_frame(&mut self) -> RenderStatus {
+        if self.minimized {
+            return RenderStatus::Ok;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.render()));
+        #[cfg(target_arch = "wasm32")]
+        let result: std::thread::Result<_> = Ok(self.render());
+
+        let device_lost = self.recovery.device_lost.load(Ordering::Relaxed);
+        match result {
+            Ok(Ok(())) if !device_lost => {
+                self.recovery.lost_frames = 0;
+                self.recovery.rebuild_attempts = 0;
+                return RenderStatus::Ok;
+            }
+            Ok(Ok(())) => {}
+            Ok(Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated)) => {
+                self.recovery.lost_frames += 1;
+                self.resize(self.size());
+            }
+            Ok(Err(wgpu::SurfaceError::OutOfMemory)) => {
+                return RenderStatus::Fatal("The GPU ran out of memory.".to_string());
+            }
+            Ok(Err(e)) => {
+                log::warn!("Render error: {}", e);
+                return RenderStatus::Ok;
+            }
+            Err(_) => {
+                log::error!("Rendering panicked, rebuilding the renderer");
+                self.recovery.lost_frames = LOST_FRAMES_BEFORE_REBUILD;
+            }
+        }
+
+        if self.recovery.rebuild_pending
+            || (!device_lost && self.recovery.lost_frames < LOST_FRAMES_BEFORE_REBUILD)
+        {
+            return RenderStatus::Ok;
+        }
+        if self.recovery.rebuild_attempts >= MAX_REBUILD_ATTEMPTS {
+            return RenderStatus::Fatal(format!(
+                "The GPU stopped responding and the renderer couldn't be restarted \
+                 after {} attempts.",
+                MAX_REBUILD_ATTEMPTS
+            ));
+        }
+        self.recovery.rebuild_attempts += 1;
+        self.recovery.rebuild_pending = true;
+        log::warn!(
+            "Rebuilding the renderer (attempt {}/{})",
+            self.recovery.rebuild_attempts,
+            MAX_REBUILD_ATTEMPTS
+        );
+        RenderStatus::Rebuild
+    }
+
+    /// Rebuild the renderer on the preferred GPU, keeping params and camera.
+    /// A failed rebuild is retried on a later frame.
+    #[cfg(feature = "native")]
+    pub fn rebuild_renderer(&mut self) {
+        let prefs = crate::fractal::AppPreferences::load();
+        let window = self.window.clone();
+        let size = self.size();
+        let renderer = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            pollster::block_on(Renderer::new_with_gpu_preference(
+                window,
+                size,
+                prefs.preferred_gpu_index,
+                prefs.surface_format,
+            ))
+        }));
+        match renderer {
+            Ok(renderer) => self.install_renderer(renderer),
+            Err(_) => {
+                log::error!("Failed to rebuild the renderer");
+                self.recovery.rebuild_pending = false;
+            }
+        }
+    }
+
+    /// A future that builds a replacement renderer, for the web where it can't
+    /// block; pass the result to `install_renderer`
+    #[cfg(target_arch = "wasm32")]
+    pub fn renderer_rebuild(&self) -> impl std::future::Future<Output = Renderer> {
+        let window = self.window.clone();
+        let size = self.size();
+        async move { Renderer::new(window, size).await }
+    }
+
+    /// Swap in a rebuilt renderer. Everything tied to the old device is
+    /// recreated: the egui renderer and context (so its textures are uploaded
+    /// again), cached UI textures and the orbit trap image.
+    pub fn install_renderer(&mut self, renderer: Renderer) {
+        self.egui_renderer = egui_wgpu::Renderer::new(
+            &renderer.device,
+            renderer.ui_format(),
+            egui_wgpu::RendererOptions {
+                msaa_samples: 1,
+                ..Default::default()
+            },
+        );
+        self.egui_state = egui_winit::State::new(
+            egui::Context::default(),
+            egui::ViewportId::ROOT,
+            &self.window,
+            None,
+            None,
+            None,
+        );
+        self.ui.reset_textures();
+        #[cfg(feature = "native")]
+        {
+            self.palette_preview = super::PalettePreview::new();
+        }
+
+        self.recovery.watch(&renderer);
+        self.renderer = renderer;
+        self.ui.gpu_supports_f64 = self.renderer.supports_f64;
+        self.ui.supported_present_modes =
+            super::frame_pacing::supported_present_modes(&self.renderer.supported_present_modes);
+        self.ui.supported_surface_formats =
+            crate::renderer::surface_format::supported_surface_formats(
+                &self.renderer.supported_surface_formats,
+            );
+        self.loaded_orbit_trap_image = None;
+        self.fractal_params.attractor_total_iterations = 0;
+
+        self.recovery.lost_frames = 0;
+        self.recovery.rebuild_pending = false;
+        self.ui
+            .show_toast("⚠ GPU reset, renderer restarted".to_string());
+        println!("Renderer rebuilt after a GPU failure");
+    }
+}
@@ -1,7 +1,8 @@
 use super::App;
-use crate::fractal::FractalParams;
+use crate::fractal::{FractalParams, RenderMode};
 use crate::renderer::compute::{
-    AccumulationDisplayUniforms, AttractorComputeUniforms, BuddhabrotComputeUniforms,
+    AccumulationDisplayUniforms, Attractor3DComputeUniforms, AttractorComputeUniforms,
+    BuddhabrotComputeUniforms, ATTRACTOR_3D_ORBITS,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -23,10 +24,9 @@ impl App {
                 });
 
         // Check if we should use accumulation mode for strange attractors or Buddhabrot
-        let is_attractor = self.fractal_params.fractal_type.is_2d_attractor();
+        let is_attractor_3d = self.fractal_params.fractal_type.is_3d_attractor();
         let is_buddhabrot = self.fractal_params.fractal_type.is_buddhabrot();
-        let use_accumulation =
-            self.fractal_params.attractor_accumulation_enabled && (is_attractor || is_buddhabrot);
+        let use_accumulation = self.fractal_params.accumulation_active();
 
         if use_accumulation {
             // Check if texture needs recreation (None or wrong size)
@@ -40,6 +40,8 @@ impl App {
             // Initialize compute infrastructure if needed (handles resize too)
             if is_buddhabrot {
                 self.renderer.init_buddhabrot_compute();
+            } else if is_attractor_3d {
+                self.renderer.init_attractor3d_compute();
             } else {
                 self.renderer.init_accumulation_compute();
             }
@@ -49,14 +51,41 @@ impl App {
                 self.fractal_params.attractor_total_iterations = 0;
             }
 
+            // 3D attractors project through the camera, so any camera move changes the view
+            let attractor_3d_uniforms = is_attractor_3d.then(|| self.attractor_3d_uniforms());
+
             // Auto-clear when view parameters change (zoom, pan, or attractor params)
-            let view_changed = self.fractal_params.center_2d
-                != self.fractal_params.attractor_last_center
-                || self.fractal_params.zoom_2d != self.fractal_params.attractor_last_zoom
-                || (!is_buddhabrot
-                    && self.fractal_params.julia_c != self.fractal_params.attractor_last_julia_c);
+            let view_changed = match (&attractor_3d_uniforms, &self.renderer.attractor3d_compute) {
+                (Some(uniforms), Some(compute)) => uniforms.view_differs(&compute.uniforms),
+                _ => {
+                    self.fractal_params.center_2d != self.fractal_params.attractor_last_center
+                        || self.fractal_params.zoom_2d != self.fractal_params.attractor_last_zoom
+                        || (!is_buddhabrot
+                            && self.fractal_params.julia_c
+                                != self.fractal_params.attractor_last_julia_c)
+                        // Hits gathered without the color mode's attribute can't be reused
+                        || (!is_buddhabrot
+                            && self.renderer.attractor_compute.as_ref().is_some_and(|c| {
+                                c.uniforms.color_mode
+                                    != self.fractal_params.attractor_color_mode_index()
+                            }))
+                }
+            };
+
+            // Recording can hold the accumulation through view changes
+            #[cfg(not(target_arch = "wasm32"))]
+            let recording_time = self.video_recorder.is_recording().then(|| {
+                self.video_recorder.frame_count() as f32 / self.video_recorder.fps().max(1) as f32
+            });
+            #[cfg(not(target_arch = "wasm32"))]
+            let clear_time = recording_time.unwrap_or(0.0);
+            #[cfg(target_arch = "wasm32")]
+            let recording_time = None;
+            #[cfg(target_arch = "wasm32")]
+            let clear_time = 0.0;
 
-            if view_changed {
+            if view_changed && self.fractal_params.attractor_auto_clear_due(recording_time) {
+                self.fractal_params.attractor_last_clear_time = clear_time;
                 self.fractal_params.attractor_pending_clear = true;
                 self.fractal_params.attractor_total_iterations = 0;
                 self.fractal_params.attractor_paused = false; // Resume accumulation on view change
@@ -143,6 +172,30 @@ impl App {
                     self.fractal_params.attractor_total_iterations +=
                         self.fractal_params.attractor_iterations_per_frame as u64;
 
+                    // Auto-pause when max iterations reached
+                    if self.fractal_params.attractor_total_iterations
+                        >= self.fractal_params.attractor_max_iterations
+                    {
+                        self.fractal_params.attractor_paused = true;
+                    }
+                }
+            } else if !self.fractal_params.attractor_paused && is_attractor_3d {
+                if let (Some(ref mut compute), Some(uniforms)) = (
+                    &mut self.renderer.attractor3d_compute,
+                    attractor_3d_uniforms,
+                ) {
+                    compute.set_uniforms(&self.renderer.queue, uniforms);
+
+                    // Every orbit advances steps_per_thread steps, so the
+                    // dispatch always covers all of them
+                    if let Some(ref accum_tex) = self.renderer.accumulation_texture {
+                        compute.dispatch(&mut encoder, &accum_tex.compute_bind_group);
+                    }
+
+                    // Update total iterations counter
+                    self.fractal_params.attractor_total_iterations +=
+                        (uniforms.steps_per_thread * ATTRACTOR_3D_ORBITS) as u64;
+
                     // Auto-pause when max iterations reached
                     if self.fractal_params.attractor_total_iterations
                         >= self.fractal_params.attractor_max_iterations
@@ -169,7 +222,8 @@ impl App {
                         attractor_type: self.fractal_params.fractal_type.attractor_index(),
                         total_iterations: self.fractal_params.attractor_total_iterations as u32,
                         clear_accumulation: 0,
-                        _padding: [0; 2],
+                        color_mode: self.fractal_params.attractor_color_mode_index(),
+                        _padding: 0,
                     };
                     compute.update_uniforms(&self.renderer.queue);
 
@@ -207,7 +261,7 @@ impl App {
                 log_scale: self.fractal_params.attractor_log_scale,
                 gamma: 0.6,
                 palette_offset: self.fractal_params.palette_offset,
-                _padding: 0.0,
+                color_mode: self.fractal_params.attractor_color_mode_index(),
                 palette: [
                     [
                         palette_colors[0].x,
@@ -329,116 +383,28 @@ impl App {
                 render_pass.set_vertex_buffer(0, self.renderer.vertex_buffer.slice(..));
                 render_pass.draw(0..4, 0..1);
             }
-        }
-
-        // Pass 2-4: Bloom pipeline (always run to keep texture valid)
-        if true {
-            // Always run bloom passes, composite will decide whether to use it
-            // Pass 2: Extract bright pixels
-            {
-                let render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("Bloom Extract Pass"),
-                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                        view: &self.renderer.bright_view,
-                        depth_slice: None,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                            store: wgpu::StoreOp::Store,
-                        },
-                    })],
-                    depth_stencil_attachment: None,
-                    occlusion_query_set: None,
-                    timestamp_writes: None,
-                });
-
-                // SAFETY: We drop the render_pass before using encoder again, so this is safe.
-                let mut render_pass: wgpu::RenderPass<'static> =
-                    unsafe { std::mem::transmute(render_pass) };
-
-                render_pass.set_pipeline(&self.renderer.bloom_extract_pipeline);
-                render_pass.set_bind_group(0, &self.renderer.scene_bind_group, &[]);
-                render_pass.set_bind_group(1, &self.renderer.bloom_params_bind_group, &[]);
-                render_pass.set_vertex_buffer(0, self.renderer.postprocess_vertex_buffer.slice(..));
-                render_pass.draw(0..4, 0..1);
-            }
 
-            // Pass 3: Horizontal blur
+            // Pass 1b: Re-render the 2D fractal boundary with extra samples
+            if self.fractal_params.render_mode == RenderMode::TwoD && self.fractal_params.edge_aa_2d
             {
-                let render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("Blur Horizontal Pass"),
-                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                        view: &self.renderer.blur_temp_view,
-                        depth_slice: None,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                            store: wgpu::StoreOp::Store,
-                        },
-                    })],
-                    depth_stencil_attachment: None,
-                    occlusion_query_set: None,
-                    timestamp_writes: None,
-                });
-
-                // SAFETY: We drop the render_pass before using encoder again, so this is safe.
-                let mut render_pass: wgpu::RenderPass<'static> =
-                    unsafe { std::mem::transmute(render_pass) };
-
-                render_pass.set_pipeline(&self.renderer.blur_pipeline);
-                render_pass.set_bind_group(0, &self.renderer.bright_bind_group, &[]);
-                render_pass.set_bind_group(1, &self.renderer.blur_h_params_bind_group, &[]);
-                render_pass.set_vertex_buffer(0, self.renderer.postprocess_vertex_buffer.slice(..));
-                render_pass.draw(0..4, 0..1);
+                self.renderer.encode_edge_supersampling(&mut encoder);
             }
 
-            // Update blur buffer to vertical direction for next pass
-            #[repr(C)]
-            #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
-            struct BlurUniforms {
-                direction: [f32; 2],
-                _padding: [f32; 2],
-            }
-            let blur_v_uniforms = BlurUniforms {
-                direction: [0.0, 1.0], // Vertical
-                _padding: [0.0; 2],
-            };
-            self.renderer.queue.write_buffer(
-                &self.renderer.blur_uniform_buffer,
-                0,
-                bytemuck::cast_slice(&[blur_v_uniforms]),
-            );
-
-            // Pass 4: Vertical blur
-            {
-                let render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("Blur Vertical Pass"),
-                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                        view: &self.renderer.bloom_view,
-                        depth_slice: None,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                            store: wgpu::StoreOp::Store,
-                        },
-                    })],
-                    depth_stencil_attachment: None,
-                    occlusion_query_set: None,
-                    timestamp_writes: None,
-                });
-
-                // SAFETY: We drop the render_pass before using encoder again, so this is safe.
-                let mut render_pass: wgpu::RenderPass<'static> =
-                    unsafe { std::mem::transmute(render_pass) };
-
-                render_pass.set_pipeline(&self.renderer.blur_pipeline);
-                render_pass.set_bind_group(0, &self.renderer.blur_temp_bind_group, &[]);
-                render_pass.set_bind_group(1, &self.renderer.blur_v_params_bind_group, &[]);
-                render_pass.set_vertex_buffer(0, self.renderer.postprocess_vertex_buffer.slice(..));
-                render_pass.draw(0..4, 0..1);
+            self.encode_iteration_probe(&mut encoder);
+            self.encode_frame_check(&mut encoder);
+            if self.uses_auto_exposure() {
+                self.renderer.encode_auto_exposure(&mut encoder);
             }
         }
 
+        // Pass 2-4: Bloom mip chain (always run to keep texture valid)
+        // Composite will decide whether to use it
+        self.renderer.encode_bloom(
+            &mut encoder,
+            &self.renderer.scene_bind_group,
+            &self.renderer.bloom,
+        );
+
         // Pass 5: Composite (scene + bloom + color grading + vignette)
         // For accumulation mode (attractors/Buddhabrot), skip composite since bloom wasn't rendered
         if !use_accumulation {
@@ -491,6 +457,17 @@ impl App {
             let mut render_pass: wgpu::RenderPass<'static> =
                 unsafe { std::mem::transmute(render_pass) };
 
+            // Draw into the render area, leaving the space under a docked panel black
+            let (x, y) = self.renderer.render_origin;
+            render_pass.set_viewport(
+                x as f32,
+                y as f32,
+                self.renderer.size.width as f32,
+                self.renderer.size.height as f32,
+                0.0,
+                1.0,
+            );
+
             if use_accumulation {
                 // For accumulation mode, copy directly from scene to screen (skip composite/bloom)
                 render_pass.set_pipeline(&self.renderer.copy_pipeline);
@@ -510,7 +487,7 @@ impl App {
         }
 
         // If screenshot requested or recording, capture fractal before UI is rendered
-        let should_screenshot = self.save_screenshot;
+        let should_screenshot = self.save_screenshot || self.copy_screenshot;
         #[cfg(not(target_arch = "wasm32"))]
         let is_recording = self.video_recorder.is_recording();
         #[cfg(target_arch = "wasm32")]
@@ -522,7 +499,19 @@ impl App {
                 .queue
                 .submit(std::iter::once(encoder.finish()));
 
-            if should_screenshot {
+            // Copy the view to the clipboard (fractal only)
+            #[cfg(not(target_arch = "wasm32"))]
+            if self.copy_screenshot {
+                self.capture_screenshot_to_clipboard(&output.texture);
+                self.copy_screenshot = false;
+            }
+
+            // On web a copy is read back like a screenshot, then written to the clipboard
+            #[cfg(not(target_arch = "wasm32"))]
+            let capture_requested = self.save_screenshot;
+            #[cfg(target_arch = "wasm32")]
+            let capture_requested = self.save_screenshot || self.copy_screenshot;
+            if capture_requested {
                 // Capture the screenshot (fractal only)
                 #[cfg(not(target_arch = "wasm32"))]
                 self.capture_screenshot(&output.texture);
@@ -533,8 +522,8 @@ impl App {
                         .fractal_type
                         .filename_safe_name()
                         .to_string();
-                    let width = self.renderer.config.width;
-                    let height = self.renderer.config.height;
+                    let width = self.renderer.size.width;
+                    let height = self.renderer.size.height;
                     // Create a closure that captures what we need for the toast
                     let show_toast: Box<dyn Fn(String) + Send + 'static> =
                         Box::new(move |msg: String| {
@@ -544,11 +533,15 @@ impl App {
                         &self.renderer.device,
                         &self.renderer.queue,
                         &output.texture,
+                        self.renderer.config.format,
+                        self.renderer.render_origin,
                         width,
                         height,
                         fractal_name,
+                        self.copy_screenshot,
                         show_toast,
                     );
+                    self.copy_screenshot = false;
                 }
                 self.save_screenshot = false;
             }
@@ -556,7 +549,12 @@ impl App {
             #[cfg(not(target_arch = "wasm32"))]
             if is_recording {
                 // Capture video frame (fractal only) - native only
-                self.capture_video_frame(&output.texture);
+                let view_size = (self.renderer.size.width, self.renderer.size.height);
+                if self.video_recorder.size() == view_size {
+                    self.capture_video_frame(&output.texture);
+                } else {
+                    self.capture_video_frame_offscreen();
+                }
             }
 
             // Create a new encoder for UI rendering
@@ -569,7 +567,11 @@ impl App {
         }
 
         // Render UI
+        let coloring_before = crate::fractal::FractalColoring::capture(&self.fractal_params);
+        let type_before = self.fractal_params.fractal_type;
+        self.apply_ui_scale();
         let raw_input = self.egui_state.take_egui_input(self.window.as_ref());
+        let mut render_area = egui::Rect::NOTHING;
         let full_output = self.egui_state.egui_ctx().run(raw_input, |ctx| {
             #[cfg(not(target_arch = "wasm32"))]
             let is_rec = self.video_recorder.is_recording();
@@ -595,16 +597,30 @@ impl App {
                 self.camera.target,
                 is_rec,
             );
+            // Space left for the fractal after a docked control panel
+            render_area = ctx.available_rect();
 
             // Render command palette overlay (always on top)
             if let Some(command_action) = self.ui.render_command_palette(ctx) {
+                let palette_preset = match &command_action {
+                    crate::command_palette::CommandAction::LoadPreset(name) => Some(name.clone()),
+                    _ => None,
+                };
                 let (changed, message) = self
                     .ui
                     .execute_command(command_action, &mut self.fractal_params);
 
+                if let (true, Some(name)) = (changed, palette_preset) {
+                    self.loaded_preset = crate::fractal::PresetGallery::load_preset(&name).ok();
+                    self.ui.loaded_preset_name = self
+                        .loaded_preset
+                        .as_ref()
+                        .map(|preset| preset.name.clone());
+                }
                 if changed {
                     self.settings_last_changed = web_time::Instant::now();
                     self.settings_need_save = true;
+                    self.blank_frame.note_change();
                 }
 
                 if let Some(msg) = message {
@@ -637,7 +653,12 @@ impl App {
             // Handle preset loading
             if let Some(preset) = preset_to_load {
                 println!("Loading preset: {}", preset.name);
-                self.fractal_params = FractalParams::from_settings(preset.settings.clone());
+                let (params, adjustments) =
+                    FractalParams::from_settings_checked(preset.settings.clone());
+                self.fractal_params = params;
+                for adjustment in adjustments {
+                    self.ui.show_toast(format!("⚠ {}", adjustment));
+                }
 
                 // Apply camera settings from preset
                 self.camera.position = glam::Vec3::from_array(preset.settings.camera_position);
@@ -650,39 +671,73 @@ impl App {
                 self.camera_controller
                     .point_at_target(self.camera.position, self.camera.target);
 
+                self.ui.loaded_preset_name = Some(preset.name.clone());
+                self.loaded_preset = Some(preset);
+
                 // Mark settings for save
                 self.settings_last_changed = web_time::Instant::now();
                 self.settings_need_save = true;
+                self.blank_frame.note_change();
+            }
+
+            // Restore the last loaded preset's parameters without moving the camera
+            if std::mem::take(&mut self.ui.revert_to_preset_requested) {
+                if let Some(preset) = &self.loaded_preset {
+                    let adjustments = self
+                        .fractal_params
+                        .restore_settings_keep_camera(preset.settings.clone());
+                    for adjustment in adjustments {
+                        self.ui.show_toast(format!("⚠ {}", adjustment));
+                    }
+                    self.ui
+                        .show_toast(format!("↺ Reverted to preset: {}", preset.name));
+                    self.settings_last_changed = web_time::Instant::now();
+                    self.settings_need_save = true;
+                    self.blank_frame.note_change();
+                }
             }
 
             // Handle camera bookmark loading
             if let Some(bookmark) = bookmark_to_load {
                 println!("Loading camera bookmark: {}", bookmark.name);
-                if self.smooth_transitions_enabled {
-                    // Start smooth transition
-                    self.camera_transition.start(
-                        self.camera.position,
-                        self.camera.target,
-                        self.camera.fovy,
-                        bookmark.get_position(),
-                        bookmark.get_target(),
-                        bookmark.fov,
-                        1.5, // 1.5 second transition
-                    );
+                if self.fractal_params.render_mode == crate::fractal::RenderMode::TwoD {
+                    // 2D fractals are framed by the view center and zoom, not the camera
+                    let (center, zoom) = bookmark.view_2d();
+                    self.fractal_params.center_2d = center;
+                    self.fractal_params.zoom_2d = zoom;
+                    self.momentum.stop();
                 } else {
-                    // Instant jump
-                    self.camera.position = bookmark.get_position();
-                    self.camera.target = bookmark.get_target();
-                    self.camera.fovy = bookmark.fov;
-                    self.camera_controller
-                        .point_at_target(self.camera.position, self.camera.target);
+                    if self.smooth_transitions_enabled {
+                        // Start smooth transition
+                        self.camera_transition.start(
+                            self.camera.position,
+                            self.camera.target,
+                            self.camera.fovy,
+                            bookmark.get_position(),
+                            bookmark.get_target(),
+                            bookmark.fov,
+                            self.ui.camera_transition_secs,
+                            self.ui.camera_transition_easing,
+                        );
+                    } else {
+                        // Instant jump
+                        self.camera.position = bookmark.get_position();
+                        self.camera.target = bookmark.get_target();
+                        self.camera.fovy = bookmark.fov;
+                        self.camera_controller
+                            .point_at_target(self.camera.position, self.camera.target);
+                    }
+                    self.fractal_params.camera_fov = bookmark.fov;
+                    // The saved position and target assume the bookmark's convention
+                    self.fractal_params.up_axis = bookmark.up_axis;
                 }
-                self.fractal_params.camera_fov = bookmark.fov;
             }
 
             if reset_requested {
                 self.fractal_params = FractalParams::default();
                 // Reset camera to default position and settings
+                self.camera.up = self.fractal_params.up_axis.up();
+                self.camera_controller.set_up(self.camera.up);
                 self.camera.reset_to_default();
                 self.camera.fovy = self.fractal_params.camera_fov;
                 self.camera_controller
@@ -712,8 +767,24 @@ impl App {
                 self.save_screenshot = true;
             }
 
-            if let Some(resolution) = hires_render_resolution {
+            if let Some(mut resolution) = hires_render_resolution {
+                if self.ui.safe_mode {
+                    let (clamped, was_clamped) =
+                        crate::fractal::clamp_capture_resolution(resolution.0, resolution.1);
+                    if was_clamped {
+                        self.ui.show_toast(format!(
+                            "🛡 Safe mode: capture limited to {}x{} (requested {}x{}); turn off Safe Mode in Settings for the full size",
+                            clamped.0, clamped.1, resolution.0, resolution.1
+                        ));
+                        resolution = clamped;
+                    }
+                }
                 self.save_hires_render = Some(resolution);
+                #[cfg(feature = "native")]
+                {
+                    self.apply_wallpaper_monitor = self.ui.wallpaper_monitor_request.take();
+                    self.spanned_guides = self.ui.spanned_guides_request.take();
+                }
                 println!(
                     "High-resolution render requested: {}x{}",
                     resolution.0, resolution.1
@@ -724,32 +795,34 @@ impl App {
             #[cfg(not(target_arch = "wasm32"))]
             {
                 if start_recording {
-                    // Generate filename with fractal type and timestamp
-                    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-                    let fractal_name = self.fractal_params.fractal_type.filename_safe_name();
-                    let filename = format!(
-                        "{}_{}.{}",
-                        fractal_name,
-                        timestamp,
-                        self.ui.video_format.extension()
-                    );
+                    let (width, height) = App::video_size(&self.ui, self.renderer.size);
+                    let filename = App::capture_file_path(
+                        &self.ui,
+                        &self.fractal_params,
+                        &mut self.capture_counter,
+                        (width, height),
+                        "",
+                        self.ui.video_format.extension(),
+                    )
+                    .to_string_lossy()
+                    .to_string();
 
                     // Update video recorder settings
-                    self.video_recorder = VideoRecorder::new(
-                        self.renderer.config.width,
-                        self.renderer.config.height,
-                        self.ui.video_fps,
-                        self.ui.video_format,
-                    );
+                    self.video_recorder =
+                        VideoRecorder::new(width, height, self.ui.video_fps, self.ui.video_format);
+                    self.video_recorder
+                        .set_frame_limit(self.ui.video_frame_limit());
 
                     if let Err(e) = self.video_recorder.start_recording(filename.clone()) {
                         eprintln!("Failed to start recording: {}", e);
                     } else {
                         println!("Started recording to {}", filename);
+                        self.recording_start_params = Some(self.fractal_params.clone());
                     }
                 }
 
-                if stop_recording {
+                // A limited recording stops by itself once its last frame is captured
+                if stop_recording || self.video_recorder.limit_reached() {
                     match self.video_recorder.stop_recording() {
                         Ok(filename) => {
                             // Convert to absolute path and show in toast
@@ -765,7 +838,7 @@ impl App {
                             }
 
                             self.ui.show_toast_with_file(
-                                format!("🎬 Video saved: {} - Click to open", filename),
+                                format!("🎬 Video saved: {} - Click to open", abs_path.display()),
                                 abs_path.to_string_lossy().to_string(),
                             );
                         }
@@ -781,6 +854,7 @@ impl App {
             if changed {
                 self.settings_last_changed = web_time::Instant::now();
                 self.settings_need_save = true;
+                self.blank_frame.note_change();
 
                 // Update camera parameters from fractal_params
                 self.camera.fovy = self.fractal_params.camera_fov;
@@ -788,6 +862,10 @@ impl App {
                     .set_speed(self.fractal_params.camera_speed);
             }
 
+            self.ui.render_console(ctx);
+            self.ui
+                .render_math_info(ctx, self.fractal_params.fractal_type);
+            self.ui.render_preset_diff(ctx);
             self.ui.render_fps(ctx, self.current_fps);
             self.ui.render_camera_info(
                 ctx,
@@ -796,17 +874,45 @@ impl App {
                 &self.fractal_params.lod_config.distance_zones,
             );
             self.ui.render_performance_overlay(ctx, self.current_fps);
+            self.ui.render_iteration_readout(ctx, &self.fractal_params);
+            self.ui
+                .render_light_indicator(ctx, &self.fractal_params, self.light_drag.is_some());
+            let (origin_x, origin_y) = self.renderer.render_origin;
+            self.ui.render_box_zoom(
+                ctx,
+                self.box_zoom,
+                (
+                    (origin_x as f32, origin_y as f32),
+                    (
+                        self.renderer.size.width as f32,
+                        self.renderer.size.height as f32,
+                    ),
+                ),
+            );
             #[cfg(not(target_arch = "wasm32"))]
             self.ui.render_recording_indicator(
                 ctx,
                 self.video_recorder.is_recording(),
                 self.video_recorder.frame_count(),
+                self.video_recorder.frame_limit(),
+                self.video_recorder.fps(),
                 self.video_recorder.filename(),
             );
             // No recording indicator on web - video recording not supported
             self.ui.render_lod_debug_overlay(ctx, &self.fractal_params);
         });
 
+        self.update_render_area(render_area, full_output.pixels_per_point);
+        if std::mem::take(&mut self.ui.fractal_switched) {
+            self.restore_fractal_coloring(type_before, coloring_before);
+        }
+        // egui-winit keeps copies in memory on web, so pass them to the browser
+        #[cfg(target_arch = "wasm32")]
+        for command in &full_output.platform_output.commands {
+            if let egui::OutputCommand::CopyText(text) = command {
+                crate::platform::web::copy_text(text);
+            }
+        }
         self.egui_state
             .handle_platform_output(self.window.as_ref(), full_output.platform_output);
 
@@ -826,7 +932,7 @@ impl App {
 
         let screen_descriptor = egui_wgpu::ScreenDescriptor {
             size_in_pixels: [self.renderer.config.width, self.renderer.config.height],
-            pixels_per_point: self.window.scale_factor() as f32,
+            pixels_per_point: full_output.pixels_per_point,
         };
 
         self.egui_renderer.update_buffers(
@@ -837,15 +943,21 @@ impl App {
             &screen_descriptor,
         );
 
+        // On float surfaces the UI is drawn into an sRGB overlay and blended on afterwards
+        let ui_overlay_view = self.renderer.ui_overlay_view();
         {
+            let (ui_view, ui_load) = match &ui_overlay_view {
+                Some(overlay) => (overlay, wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT)),
+                None => (&view, wgpu::LoadOp::Load),
+            };
             let render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("UI Render Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
+                    view: ui_view,
                     depth_slice: None,
                     resolve_target: None,
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Load,
+                        load: ui_load,
                         store: wgpu::StoreOp::Store,
                     },
                 })],
@@ -862,6 +974,9 @@ impl App {
                 .render(&mut render_pass, &tris, &screen_descriptor);
             drop(render_pass);
         }
+        if ui_overlay_view.is_some() {
+            self.renderer.encode_ui_overlay(&mut encoder, &view);
+        }
 
         for id in &full_output.textures_delta.free {
             self.egui_renderer.free_texture(id);
@@ -870,9 +985,107 @@ impl App {
         self.renderer
             .queue
             .submit(std::iter::once(encoder.finish()));
+        self.renderer.begin_iteration_probe_readback();
+        self.renderer.begin_auto_exposure_readback();
+        self.renderer.begin_frame_check_readback();
 
         output.present();
 
         Ok(())
     }
+
+    /// Apply the UI scale override. egui scales the system's pixels per point
+    /// by its zoom factor, and egui-winit maps the pointer with the same
+    /// product, so input stays aligned with the scaled controls.
+    fn apply_ui_scale(&self) {
+        if self.ui.ui_scale <= 0.0 {
+            return;
+        }
+        let ctx = self.egui_state.egui_ctx();
+        let zoom = self.ui.ui_scale / self.window.scale_factor() as f32;
+        if (ctx.zoom_factor() - zoom).abs() > f32::EPSILON {
+            ctx.set_zoom_factor(zoom);
+        }
+    }
+
+    /// Fit the fractal to the part of the window a docked control panel leaves
+    /// free (given in egui points). Takes effect from the next frame.
+    fn update_render_area(&mut self, area: egui::Rect, pixels_per_point: f32) {
+        if !area.is_finite() || !area.is_positive() {
+            return;
+        }
+        let (window_width, window_height) =
+            (self.renderer.config.width, self.renderer.config.height);
+        let to_pixels =
+            |points: f32, max: u32| ((points * pixels_per_point).round().max(0.0) as u32).min(max);
+        let (x0, x1) = (
+            to_pixels(area.min.x, window_width),
+            to_pixels(area.max.x, window_width),
+        );
+        let (y0, y1) = (
+            to_pixels(area.min.y, window_height),
+            to_pixels(area.max.y, window_height),
+        );
+        let size = winit::dpi::PhysicalSize::new(x1.saturating_sub(x0), y1.saturating_sub(y0));
+        if size.width == 0
+            || size.height == 0
+            || (size == self.renderer.size && (x0, y0) == self.renderer.render_origin)
+        {
+            return;
+        }
+        self.renderer.set_render_area((x0, y0), size);
+        self.camera.resize(size.width, size.height);
+    }
+
+    /// Compute uniforms for the 3D attractor point cloud as seen from the camera
+    fn attractor_3d_uniforms(&self) -> Attractor3DComputeUniforms {
+        let params = &self.fractal_params;
+        Attractor3DComputeUniforms {
+            view_proj: self
+                .camera
+                .build_view_projection_matrix()
+                .to_cols_array_2d(),
+            // Same parameter mapping as the ray marched attractors
+            params: [
+                params.julia_c[0],
+                params.julia_c[1],
+                params.power,
+                params.fractal_fold,
+            ],
+            width: self.renderer.size.width,
+            height: self.renderer.size.height,
+            steps_per_thread: (params.attractor_iterations_per_frame / ATTRACTOR_3D_ORBITS).max(1),
+            attractor_type: params.fractal_type.attractor_index(),
+            scale: params.fractal_scale,
+            seed: params.attractor_total_iterations as u32,
+            reseed: 0,
+            _padding: 0,
+        }
+    }
+
+    /// Whether the 3D auto-exposure measurement should run this frame
+    pub(super) fn uses_auto_exposure(&self) -> bool {
+        self.fractal_params.auto_exposure && self.fractal_params.render_mode == RenderMode::ThreeD
+    }
+
+    /// Probe the 2D fractal under the cursor for the iteration readout (throttled)
+    fn encode_iteration_probe(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        /// Minimum time between probe readbacks
+        const PROBE_INTERVAL_SECS: f32 = 0.1;
+
+        if !self.ui.show_iteration_readout
+            || self.fractal_params.render_mode != RenderMode::TwoD
+            || self.last_probe_time.elapsed().as_secs_f32() < PROBE_INTERVAL_SECS
+        {
+            return;
+        }
+        self.last_probe_time = web_time::Instant::now();
+
+        // Cursor position in NDC (y up), matching the vertex shader's uv
+        let width = self.renderer.size.width.max(1) as f32;
+        let height = self.renderer.size.height.max(1) as f32;
+        let (x, y) = self.view_position(self.cursor_pos);
+        let uv = [x / width * 2.0 - 1.0, 1.0 - y / height * 2.0];
+        self.renderer.encode_iteration_probe(encoder, uv);
+    }
 }
@@ -0,0 +1,171 @@
+//! Rhai scripting hook (enabled by the `scripting` feature)
+//!
+//! A script passed with `--script <file>` runs its top level once at load, then
+//! `fn tick(time, dt)` is called every frame if defined. Inside `tick`, `this` is
+//! the current parameters as an object map using the same field names as
+//! settings.yaml; any changes are applied back to the fractal.
+//!
+//! ```rhai
+//! fn tick(time, dt) {
+//!     this.julia_c[0] = 0.7885 * cos(time * 0.2);
+//!     this.julia_c[1] = 0.7885 * sin(time * 0.2);
+//! }
+//! ```
+//!
+//! `save_preset(params, name)` writes a user preset, so a script can also
+//! generate preset batches from its top level or from `tick`.
+
+use super::App;
+use crate::fractal::{FractalParams, Preset, PresetCategory, PresetGallery, Settings};
+use rhai::{CallFnOptions, Dynamic, Engine, Scope, AST};
+use std::error::Error;
+use std::path::Path;
+
+/// Name of the per-frame callback looked up in the script
+const TICK_FN: &str = "tick";
+
+/// A loaded script and the engine state that persists between frames
+pub struct ScriptHost {
+    engine: Engine,
+    ast: AST,
+    scope: Scope<'static>,
+    has_tick: bool,
+    // Values clamped on the last change, so each is reported once
+    adjustments: Vec<String>,
+}
+
+impl ScriptHost {
+    /// Compile a script file and run its top level once
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let mut engine = Engine::new();
+        engine.on_print(|text| println!("[script] {}", text));
+        engine.register_fn("save_preset", save_preset);
+
+        let ast = engine.compile_file(path.to_path_buf())?;
+        let mut scope = Scope::new();
+        engine.run_ast_with_scope(&mut scope, &ast)?;
+
+        let has_tick = ast
+            .iter_functions()
+            .any(|f| f.name == TICK_FN && f.params.len() == 2);
+        if !has_tick {
+            println!("Script has no tick(time, dt) function; it only ran once");
+        }
+
+        Ok(Self {
+            engine,
+            ast,
+            scope,
+            has_tick,
+            adjustments: Vec::new(),
+        })
+    }
+
+    /// Call the script's tick function with `this` bound to the params.
+    /// Returns values that were newly clamped to their valid range.
+    pub fn tick(
+        &mut self,
+        params: &mut FractalParams,
+        time: f32,
+        dt: f32,
+    ) -> Result<Vec<String>, Box<dyn Error>> {
+        if !self.has_tick {
+            return Ok(Vec::new());
+        }
+
+        let settings = params.to_settings();
+        let mut this = rhai::serde::to_dynamic(&settings)?;
+        let options = CallFnOptions::new()
+            .eval_ast(false)
+            .bind_this_ptr(&mut this);
+        let _: Dynamic = self.engine.call_fn_with_options(
+            options,
+            &mut self.scope,
+            &self.ast,
+            TICK_FN,
+            (time as rhai::FLOAT, dt as rhai::FLOAT),
+        )?;
+
+        // Round-trip through JSON so script floats and ints convert leniently to f32/u32
+        let updated: Settings = serde_json::from_value(serde_json::to_value(&this)?)?;
+        if serde_json::to_value(&updated)? == serde_json::to_value(&settings)? {
+            return Ok(Vec::new());
+        }
+
+        let (mut new_params, adjustments) = FractalParams::from_settings_checked(updated);
+        let new_adjustments = adjustments
+            .iter()
+            .filter(|adjustment| !self.adjustments.contains(adjustment))
+            .cloned()
+            .collect();
+        self.adjustments = adjustments;
+        // Keep runtime state that isn't part of the settings
+        std::mem::swap(&mut new_params.palette_offset, &mut params.palette_offset);
+        std::mem::swap(&mut new_params.lod_state, &mut params.lod_state);
+        new_params.attractor_total_iterations = params.attractor_total_iterations;
+        new_params.attractor_pending_clear = params.attractor_pending_clear;
+        new_params.attractor_paused = params.attractor_paused;
+        new_params.attractor_max_iterations = params.attractor_max_iterations;
+        new_params.attractor_last_center = params.attractor_last_center;
+        new_params.attractor_last_zoom = params.attractor_last_zoom;
+        new_params.attractor_last_julia_c = params.attractor_last_julia_c;
+        new_params.attractor_last_clear_time = params.attractor_last_clear_time;
+        *params = new_params;
+
+        Ok(new_adjustments)
+    }
+}
+
+/// Script function: save a params map as a user preset
+fn save_preset(params: Dynamic, name: &str) -> Result<(), Box<rhai::EvalAltResult>> {
+    let settings: Settings = serde_json::to_value(&params)
+        .and_then(serde_json::from_value)
+        .map_err(|e| e.to_string())?;
+    let preset = Preset {
+        name: name.to_string(),
+        description: "Generated by script".to_string(),
+        category: PresetCategory::default(),
+        settings,
+    };
+    PresetGallery::save_preset(&preset, &name.replace(' ', "_")).map_err(|e| e.to_string().into())
+}
+
+/// Scripting methods
+impl App {
+    /// Load a script to drive parameters every frame
+    pub fn load_script(&mut self, path: &Path) {
+        match ScriptHost::load(path) {
+            Ok(host) => {
+                println!("Loaded script: {}", path.display());
+                self.script = Some(host);
+            }
+            Err(e) => {
+                eprintln!("Failed to load script '{}': {}", path.display(), e);
+                self.ui.show_toast(format!("✗ Script error: {}", e));
+            }
+        }
+    }
+
+    /// Run the script's per-frame callback. Scripted changes are not auto-saved,
+    /// and a runtime error stops the script.
+    pub(super) fn tick_script(&mut self, dt: f32) {
+        let Some(host) = self.script.as_mut() else {
+            return;
+        };
+
+        let time = self.start_time.elapsed().as_secs_f32();
+        match host.tick(&mut self.fractal_params, time, dt) {
+            Ok(adjustments) => {
+                for adjustment in adjustments {
+                    eprintln!("Script value adjusted: {}", adjustment);
+                    self.ui.show_toast(format!("⚠ {}", adjustment));
+                }
+            }
+            Err(e) => {
+                eprintln!("Script error, stopping script: {}", e);
+                self.ui.show_toast(format!("✗ Script stopped: {}", e));
+                self.script = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Tour mode: cycle through the built-in presets like a screensaver.
+//!
+//! Each preset is held for the configured dwell time (auto-orbiting in 3D), then
+//! the next one is loaded, with a camera transition between 3D presets. The order
+//! is fixed so a tour is reproducible. Any key, click, scroll or touch stops it.
+
+use super::App;
+use crate::fractal::{FractalParams, PresetGallery, RenderMode};
+
+/// Camera transition duration between consecutive 3D presets
+const TOUR_TRANSITION_SECS: f32 = 2.0;
+
+pub(super) struct TourState {
+    pub(super) active: bool,
+    /// Index into the built-in preset list of the preset currently shown
+    index: usize,
+    last_switch: web_time::Instant,
+}
+
+impl TourState {
+    pub(super) fn new() -> Self {
+        Self {
+            active: false,
+            index: 0,
+            last_switch: web_time::Instant::now(),
+        }
+    }
+}
+
+/// Tour mode methods
+impl App {
+    /// Start touring from the first built-in preset
+    pub fn start_tour(&mut self) {
+        self.tour.active = true;
+        self.tour.index = 0;
+        self.load_tour_preset();
+        self.ui.tour_active = true;
+        println!("Tour started");
+    }
+
+    /// Stop the tour, keeping the current view. Nothing the tour loaded is auto-saved,
+    /// so the next launch restores the settings from before the tour.
+    pub(super) fn stop_tour(&mut self) {
+        if !self.tour.active {
+            return;
+        }
+        self.tour.active = false;
+        self.ui.tour_active = false;
+        self.settings_need_save = false;
+        self.camera_needs_save = false;
+        self.ui.show_toast("⏹ Tour stopped".to_string());
+        println!("Tour stopped");
+    }
+
+    /// Advance to the next preset once the dwell time has elapsed
+    pub(super) fn update_tour(&mut self) {
+        if std::mem::take(&mut self.ui.tour_toggle_requested) {
+            if self.tour.active {
+                self.stop_tour();
+            } else {
+                self.start_tour();
+            }
+        }
+
+        if !self.tour.active
+            || self.tour.last_switch.elapsed().as_secs_f32() < self.ui.tour_dwell_secs
+        {
+            return;
+        }
+
+        self.tour.index += 1;
+        self.load_tour_preset();
+    }
+
+    fn load_tour_preset(&mut self) {
+        let presets = PresetGallery::get_builtin_presets();
+        if presets.is_empty() {
+            self.tour.active = false;
+            self.ui.tour_active = false;
+            return;
+        }
+        self.tour.index %= presets.len();
+        self.tour.last_switch = web_time::Instant::now();
+
+        let preset = &presets[self.tour.index];
+        let was_3d = self.fractal_params.render_mode == RenderMode::ThreeD;
+        let (params, adjustments) = FractalParams::from_settings_checked(preset.settings.clone());
+        self.fractal_params = params;
+        for adjustment in adjustments {
+            self.ui.show_toast(format!("⚠ {}", adjustment));
+        }
+        self.ui.loaded_preset_name = Some(preset.name.clone());
+        self.loaded_preset = Some(preset.clone());
+
+        let position = glam::Vec3::from_array(preset.settings.camera_position);
+        let target = glam::Vec3::from_array(preset.settings.camera_target);
+        let fov = preset.settings.camera_fov;
+        let is_3d = self.fractal_params.render_mode == RenderMode::ThreeD;
+        if is_3d {
+            // Keep 3D presets moving while they're on screen
+            self.fractal_params.auto_orbit = true;
+        }
+        if is_3d && was_3d {
+            self.camera_transition.start(
+                self.camera.position,
+                self.camera.target,
+                self.camera.fovy,
+                position,
+                target,
+                fov,
+                TOUR_TRANSITION_SECS,
+                self.ui.camera_transition_easing,
+            );
+        } else {
+            self.camera_transition.active = false;
+            self.camera.position = position;
+            self.camera.target = target;
+            self.camera.fovy = fov;
+        }
+        self.camera_controller
+            .set_speed(preset.settings.camera_speed);
+        self.camera_controller
+            .point_at_target(self.camera.position, self.camera.target);
+
+        println!(
+            "Tour: {} ({}/{})",
+            preset.name,
+            self.tour.index + 1,
+            presets.len()
+        );
+    }
+}
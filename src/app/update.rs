@@ -1,6 +1,16 @@
 use super::App;
 use crate::fractal::RenderMode;
 
+/// Attract mode: after the idle timeout the current fractal auto-orbits and
+/// its palette animates until the next input. Holds the settings to restore.
+pub(super) struct AttractMode {
+    auto_orbit: bool,
+    palette_animation: bool,
+    camera_position: glam::Vec3,
+    camera_target: glam::Vec3,
+    started: web_time::Instant,
+}
+
 /// Update loop methods
 impl App {
     pub fn update(&mut self) {
@@ -8,6 +18,45 @@ impl App {
         let dt = (now - self.last_frame_time).as_secs_f32();
         self.last_frame_time = now;
 
+        self.apply_present_mode();
+        self.renderer.set_safe_mode(self.ui.safe_mode);
+        self.sync_orbit_trap_image();
+        self.update_tour();
+        self.mode_slots
+            .note_fractal(self.fractal_params.fractal_type);
+        self.update_camera_path_preview();
+        self.update_momentum(dt);
+        if std::mem::take(&mut self.ui.relief_view_requested) {
+            self.show_relief_overview();
+        }
+        self.update_attract_mode();
+
+        if std::mem::take(&mut self.ui.clipboard_screenshot_requested) {
+            self.copy_screenshot = true;
+        }
+        if let Some(format) = self.ui.shader_export_requested.take() {
+            // Copy the uniform values of the current frame as shader source
+            let text = self.renderer.shader_parameters(format);
+            self.egui_state.egui_ctx().copy_text(text);
+            self.ui.show_toast(format!(
+                "🧮 Shader parameters copied to clipboard ({})",
+                format.name()
+            ));
+        }
+
+        if let Some(sample) = self.renderer.poll_iteration_probe() {
+            self.ui.hover_probe = Some(sample);
+        }
+        self.renderer.update_auto_exposure(
+            self.uses_auto_exposure(),
+            dt,
+            self.fractal_params.auto_exposure_speed,
+        );
+        self.update_blank_frame_check();
+
+        #[cfg(feature = "scripting")]
+        self.tick_script(dt);
+
         // Update FPS counter
         self.frame_count += 1;
         let fps_elapsed = (now - self.fps_timer).as_secs_f32();
@@ -48,26 +97,7 @@ impl App {
             let zoom_speed = 2.0; // Zoom factor per second
             let zoom_factor = (zoom_speed * dt).exp();
 
-            // Zoom at cursor position
-            let width = self.renderer.size.width as f64;
-            let height = self.renderer.size.height as f64;
-            let aspect = width / height;
-            let norm_x = (self.cursor_pos.0 as f64 / width) * 2.0 - 1.0;
-            let norm_y = 1.0 - (self.cursor_pos.1 as f64 / height) * 2.0;
-
-            let zoom = self.fractal_params.zoom_2d as f64;
-            let fractal_x = self.fractal_params.center_2d[0] + (norm_x * 2.0 / zoom) * aspect;
-            let fractal_y = self.fractal_params.center_2d[1] + norm_y * 2.0 / zoom;
-
-            self.fractal_params.zoom_2d *= zoom_factor;
-
-            let new_zoom = self.fractal_params.zoom_2d as f64;
-            let new_fractal_x =
-                self.fractal_params.center_2d[0] + (norm_x * 2.0 / new_zoom) * aspect;
-            let new_fractal_y = self.fractal_params.center_2d[1] + norm_y * 2.0 / new_zoom;
-
-            self.fractal_params.center_2d[0] += fractal_x - new_fractal_x;
-            self.fractal_params.center_2d[1] += fractal_y - new_fractal_y;
+            self.zoom_2d_at(zoom_factor, self.cursor_pos);
         }
 
         // Update camera for 3D mode
@@ -75,6 +105,22 @@ impl App {
             let old_pos = self.camera.position;
             let old_target = self.camera.target;
 
+            // Follow the up axis convention (changed in the UI or by a loaded
+            // preset, bookmark or settings file), keeping the view direction
+            let up = self.fractal_params.up_axis.up();
+            if self.camera_controller.up() != up {
+                self.camera.up = up;
+                self.camera_controller.set_up(up);
+                self.camera_controller
+                    .point_at_target(self.camera.position, self.camera.target);
+            }
+
+            self.camera_controller.set_inertia(
+                self.fractal_params.camera_inertia,
+                self.fractal_params.camera_acceleration,
+                self.fractal_params.camera_damping,
+            );
+
             // Update camera transition if active
             if self
                 .camera_transition
@@ -82,6 +128,7 @@ impl App {
             {
                 // Transition is still running, don't allow other camera movements
                 self.fractal_params.camera_fov = self.camera.fovy;
+                self.camera_controller.stop();
             } else if self.camera_transition.active {
                 // Transition just finished
                 self.camera_transition.active = false;
@@ -93,8 +140,8 @@ impl App {
                 // Calculate orbit angle based on speed and delta time
                 let orbit_angle = self.fractal_params.orbit_speed * dt;
 
-                // Rotate around Y axis
-                let rotation = glam::Quat::from_axis_angle(glam::Vec3::Y, orbit_angle);
+                // Rotate around the up axis
+                let rotation = glam::Quat::from_axis_angle(self.camera.up, orbit_angle);
                 let new_offset = rotation * to_camera;
 
                 self.camera.position = orbit_center + new_offset;
@@ -103,6 +150,7 @@ impl App {
                 // Update controller to match the new orientation
                 self.camera_controller
                     .point_at_target(self.camera.position, self.camera.target);
+                self.camera_controller.stop();
 
                 self.was_auto_orbiting = true;
             } else {
@@ -126,19 +174,51 @@ impl App {
             }
         }
 
-        // Auto-save camera position after 1 second of inactivity (native only)
+        // Explicit save / revert from the Settings section (native only)
+        #[cfg(feature = "native")]
+        {
+            if std::mem::take(&mut self.ui.save_settings_requested) {
+                self.save_all_settings();
+                self.settings_need_save = false;
+                self.camera_needs_save = false;
+                self.ui.show_toast("💾 Settings saved".to_string());
+            }
+            if std::mem::take(&mut self.ui.revert_settings_requested) {
+                if self.revert_to_saved_settings() {
+                    self.ui
+                        .show_toast("↩ Reverted to last saved settings".to_string());
+                } else {
+                    self.ui
+                        .show_toast("No saved settings to revert to".to_string());
+                }
+            }
+        }
+
+        // Auto-save delay is configurable; when auto-save is off only explicit saves persist
         #[cfg(not(target_arch = "wasm32"))]
-        if self.camera_needs_save
-            && self.camera_last_moved.elapsed() >= std::time::Duration::from_secs(1)
+        let autosave_delay = std::time::Duration::from_secs_f32(self.ui.autosave_interval_secs);
+
+        // Auto-save camera position after the configured inactivity delay (native only).
+        // Nothing is auto-saved while touring or in attract mode, so neither overwrites
+        // the user's settings.
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.ui.autosave_enabled
+            && !self.tour.active
+            && self.attract_mode.is_none()
+            && self.camera_needs_save
+            && self.camera_last_moved.elapsed() >= autosave_delay
         {
             self.save_camera_settings();
             self.camera_needs_save = false;
         }
 
-        // Auto-save settings after 1 second of inactivity (native only)
+        // Auto-save settings after the configured inactivity delay (native only)
         #[cfg(not(target_arch = "wasm32"))]
-        if self.settings_need_save
-            && self.settings_last_changed.elapsed() >= std::time::Duration::from_secs(1)
+        if self.ui.autosave_enabled
+            && !self.tour.active
+            && self.attract_mode.is_none()
+            && self.settings_need_save
+            && self.settings_last_changed.elapsed() >= autosave_delay
         {
             self.save_all_settings();
             self.settings_need_save = false;
@@ -148,12 +228,42 @@ impl App {
         #[cfg(not(target_arch = "wasm32"))]
         if let Some((width, height)) = self.save_hires_render.take() {
             println!("Starting high-resolution render at {}x{}...", width, height);
-            if let Err(e) = self.render_high_resolution(width, height) {
-                eprintln!("Failed to render high-resolution image: {}", e);
-            } else {
-                println!("High-resolution render completed!");
+            let wallpaper_monitor = self.apply_wallpaper_monitor.take();
+            let spanned_guides = self.spanned_guides.take();
+            match self.render_high_resolution(width, height) {
+                Ok(path) => {
+                    println!("High-resolution render completed!");
+                    if let Some(monitor_index) = wallpaper_monitor {
+                        self.apply_wallpaper(&path, monitor_index);
+                    }
+                    if let Some((span_size, rects)) = spanned_guides {
+                        self.save_crop_guides(&path, span_size, &rects);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Failed to render high-resolution image: {}", e);
+                }
             }
         }
+
+        // Handle final render request (native only)
+        #[cfg(not(target_arch = "wasm32"))]
+        if std::mem::take(&mut self.ui.final_render_requested) {
+            self.start_final_render();
+        }
+
+        // Handle parameter sweep request (native only)
+        #[cfg(not(target_arch = "wasm32"))]
+        if std::mem::take(&mut self.ui.sweep_requested) {
+            self.render_parameter_sweep();
+        }
+
+        // Refresh the palette preview thumbnail (native only)
+        #[cfg(not(target_arch = "wasm32"))]
+        self.update_palette_preview();
+        #[cfg(not(target_arch = "wasm32"))]
+        self.update_randomize_thumbnails();
+
         #[cfg(target_arch = "wasm32")]
         if let Some((width, height)) = self.save_hires_render.take() {
             log::info!("Starting high-resolution render at {}x{}...", width, height);
@@ -181,10 +291,149 @@ impl App {
 
         // Update LOD system (must be done before renderer.update())
         let camera_forward = (self.camera.target - self.camera.position).normalize();
+        #[cfg(feature = "native")]
+        let recording = self.video_recorder.is_recording();
+        #[cfg(not(feature = "native"))]
+        let recording = false;
         self.fractal_params
-            .update_lod(self.camera.position, camera_forward, dt);
+            .update_lod(self.camera.position, camera_forward, dt, recording);
 
         // Update renderer uniforms
+        let capturing = self.save_screenshot || self.copy_screenshot || recording;
+        self.renderer
+            .set_cvd_simulation(self.ui.cvd_simulation_for(capturing));
         self.renderer.update(&self.camera, &self.fractal_params);
+        self.show_safe_mode_warnings();
+    }
+
+    /// Toast the settings safe mode caps in the live view whenever they change
+    fn show_safe_mode_warnings(&mut self) {
+        let warnings = self.renderer.safe_mode_warnings();
+        if warnings != self.safe_mode_warnings_shown.as_slice() {
+            if !warnings.is_empty() {
+                self.ui.show_toast(warnings.join("\n"));
+            }
+            self.safe_mode_warnings_shown = warnings.to_vec();
+        }
+    }
+
+    /// Move the camera to the time picked on the camera path scrubber
+    fn update_camera_path_preview(&mut self) {
+        let Some(time) = self.ui.camera_path_preview.take() else {
+            return;
+        };
+        let Some(pose) = self.ui.camera_path.sample(time) else {
+            return;
+        };
+        // The scrubber drives the camera, so nothing else may move it
+        self.camera_transition.active = false;
+        self.fractal_params.auto_orbit = false;
+        self.camera.position = pose.position;
+        self.camera.target = pose.target;
+        self.camera.fovy = pose.fov;
+        self.fractal_params.camera_fov = pose.fov;
+        self.camera_controller
+            .point_at_target(self.camera.position, self.camera.target);
+        self.camera_controller.stop();
+    }
+
+    /// Frame the 3D relief after it's turned on, as the 3D camera may be
+    /// anywhere (or still at its default, looking at the relief edge-on)
+    fn show_relief_overview(&mut self) {
+        self.camera_transition.active = false;
+        self.camera.relief_overview();
+        self.camera_controller
+            .point_at_target(self.camera.position, self.camera.target);
+        self.camera_controller.stop();
+    }
+
+    /// Upload the orbit trap image selected in the params whenever it changes
+    /// (image picker, preset load, settings revert). Falls back to the built-in image.
+    fn sync_orbit_trap_image(&mut self) {
+        if self.loaded_orbit_trap_image == self.fractal_params.orbit_trap_image_path {
+            return;
+        }
+        self.loaded_orbit_trap_image = self.fractal_params.orbit_trap_image_path.clone();
+
+        let Some(path) = self.loaded_orbit_trap_image.clone() else {
+            self.renderer.reset_orbit_trap_image();
+            return;
+        };
+
+        // Keep large images within the default texture size limit
+        const MAX_TRAP_IMAGE_DIMENSION: u32 = 4096;
+        match image::open(&path) {
+            Ok(image) => {
+                let image = if image.width() > MAX_TRAP_IMAGE_DIMENSION
+                    || image.height() > MAX_TRAP_IMAGE_DIMENSION
+                {
+                    image.thumbnail(MAX_TRAP_IMAGE_DIMENSION, MAX_TRAP_IMAGE_DIMENSION)
+                } else {
+                    image
+                };
+                let rgba = image.to_rgba8();
+                self.renderer
+                    .set_orbit_trap_image(rgba.width(), rgba.height(), rgba.as_raw());
+                println!("Loaded orbit trap image: {}", path);
+            }
+            Err(e) => {
+                eprintln!("Failed to load orbit trap image '{}': {}", path, e);
+                self.ui
+                    .show_toast(format!("✗ Could not load trap image, using default: {}", e));
+                self.renderer.reset_orbit_trap_image();
+            }
+        }
+    }
+
+    /// Start attract mode once nothing has happened for the idle timeout, and
+    /// stop it on the first input afterwards
+    fn update_attract_mode(&mut self) {
+        // Recording, touring and dragging a UI control all count as activity
+        #[cfg(feature = "native")]
+        let recording = self.video_recorder.is_recording();
+        #[cfg(not(feature = "native"))]
+        let recording = false;
+        let busy = recording || self.tour.active || self.egui_state.egui_ctx().is_using_pointer();
+
+        if let Some(attract) = &self.attract_mode {
+            if busy || self.last_activity > attract.started {
+                self.stop_attract_mode();
+            }
+            return;
+        }
+
+        let timeout = self.ui.idle_timeout_secs;
+        let idle = self
+            .last_activity
+            .elapsed()
+            .min(self.camera_last_moved.elapsed());
+        if timeout <= 0.0 || busy || idle.as_secs_f32() < timeout {
+            return;
+        }
+
+        self.attract_mode = Some(AttractMode {
+            auto_orbit: self.fractal_params.auto_orbit,
+            palette_animation: self.ui.palette_animation_enabled,
+            camera_position: self.camera.position,
+            camera_target: self.camera.target,
+            started: web_time::Instant::now(),
+        });
+        self.fractal_params.auto_orbit = true;
+        self.ui.palette_animation_enabled = true;
+        println!("Attract mode started after {:.0}s idle", timeout);
+    }
+
+    fn stop_attract_mode(&mut self) {
+        let Some(attract) = self.attract_mode.take() else {
+            return;
+        };
+        self.fractal_params.auto_orbit = attract.auto_orbit;
+        self.ui.palette_animation_enabled = attract.palette_animation;
+        // Put the orbited camera back, so the pose that gets autosaved is the user's
+        self.camera.position = attract.camera_position;
+        self.camera.target = attract.camera_target;
+        self.camera_controller
+            .point_at_target(self.camera.position, self.camera.target);
+        println!("Attract mode stopped");
     }
 }
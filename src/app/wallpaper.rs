@@ -0,0 +1,204 @@
+//! Native desktop wallpaper integration using each platform's own mechanism
+//!
+//! - macOS: System Events via `osascript` (per-monitor)
+//! - Windows: `SystemParametersInfo` via PowerShell (all monitors)
+//! - Linux: GNOME/Cinnamon `gsettings`, KDE `plasma-apply-wallpaperimage`, or `feh` (all monitors)
+
+use super::App;
+use crate::ui::SpanRect;
+use std::path::Path;
+
+type WallpaperResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+/// Apply an image as the desktop wallpaper.
+///
+/// `monitor_index` is the position in the UI monitor list (primary first). It is
+/// honoured where the platform supports per-monitor wallpapers; elsewhere the
+/// image is applied to every monitor. Returns a short description of what was done.
+fn set_desktop_wallpaper(path: &Path, monitor_index: usize) -> WallpaperResult<String> {
+    let path = path.canonicalize()?;
+    let path_str = path.to_string_lossy().to_string();
+    set_platform_wallpaper(&path_str, monitor_index)
+}
+
+/// Wallpaper methods
+impl App {
+    /// Apply a rendered image as the wallpaper and report the outcome via toast
+    pub(super) fn apply_wallpaper(&mut self, path: &Path, monitor_index: usize) {
+        match set_desktop_wallpaper(path, monitor_index) {
+            Ok(message) => {
+                println!("{}", message);
+                self.ui.show_toast(format!("🖥 {}", message));
+            }
+            Err(e) => {
+                eprintln!("Failed to set wallpaper: {}", e);
+                self.ui
+                    .show_toast(format!("❌ Failed to set wallpaper: {}", e));
+            }
+        }
+    }
+
+    /// Save a copy of a spanned wallpaper next to it (`*_guides.png`) with each
+    /// monitor's area outlined and the padding no monitor shows darkened.
+    /// `span_size` is the size the areas were laid out for; the render may be
+    /// smaller (safe mode), so the areas are scaled to the image.
+    pub(super) fn save_crop_guides(
+        &mut self,
+        path: &Path,
+        span_size: (u32, u32),
+        rects: &[SpanRect],
+    ) {
+        let result = image::open(path)
+            .map_err(|e| e.to_string())
+            .and_then(|img| {
+                let mut img = img.to_rgba8();
+                draw_crop_guides(&mut img, span_size, rects);
+                let guides_path = path.with_file_name(format!(
+                    "{}_guides.png",
+                    path.file_stem().unwrap_or_default().to_string_lossy()
+                ));
+                img.save(&guides_path).map_err(|e| e.to_string())?;
+                Ok(guides_path)
+            });
+        match result {
+            Ok(guides_path) => {
+                println!("Crop guides saved to {}", guides_path.display());
+                self.ui
+                    .show_toast(format!("📐 Crop guides saved: {}", guides_path.display()));
+            }
+            Err(e) => {
+                eprintln!("Failed to save crop guides: {}", e);
+                self.ui
+                    .show_toast(format!("❌ Failed to save crop guides: {}", e));
+            }
+        }
+    }
+}
+
+/// Darken the pixels outside every monitor area and outline each area
+fn draw_crop_guides(img: &mut image::RgbaImage, span_size: (u32, u32), rects: &[SpanRect]) {
+    let (width, height) = img.dimensions();
+    let scale_x = width as f64 / span_size.0.max(1) as f64;
+    let scale_y = height as f64 / span_size.1.max(1) as f64;
+    let scaled: Vec<[u32; 4]> = rects
+        .iter()
+        .map(|r| {
+            let x0 = (r.x as f64 * scale_x).round() as u32;
+            let y0 = (r.y as f64 * scale_y).round() as u32;
+            let x1 = (((r.x + r.width) as f64 * scale_x).round() as u32).min(width);
+            let y1 = (((r.y + r.height) as f64 * scale_y).round() as u32).min(height);
+            [x0, y0, x1, y1]
+        })
+        .collect();
+    // Thick enough to see on a downscaled preview of a very wide image
+    let line = (width.max(height) / 800).max(2);
+
+    for (x, y, pixel) in img.enumerate_pixels_mut() {
+        let inside = scaled
+            .iter()
+            .find(|[x0, y0, x1, y1]| x >= *x0 && x < *x1 && y >= *y0 && y < *y1);
+        match inside {
+            None => {
+                for channel in &mut pixel.0[..3] {
+                    *channel /= 4;
+                }
+            }
+            Some([x0, y0, x1, y1]) => {
+                let edge = x < x0 + line || x + line >= *x1 || y < y0 + line || y + line >= *y1;
+                if edge {
+                    pixel.0 = [255, 0, 255, 255];
+                }
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn set_platform_wallpaper(path: &str, monitor_index: usize) -> WallpaperResult<String> {
+    // System Events numbers desktops from 1, with the main display first
+    let escaped = path.replace('\\', "\\\\").replace('"', "\\\"");
+    let script = format!(
+        "tell application \"System Events\" to set picture of desktop {} to POSIX file \"{}\"",
+        monitor_index + 1,
+        escaped
+    );
+    run_command("osascript", &["-e", &script])?;
+    Ok(format!("Wallpaper set on monitor {}", monitor_index + 1))
+}
+
+#[cfg(target_os = "windows")]
+fn set_platform_wallpaper(path: &str, _monitor_index: usize) -> WallpaperResult<String> {
+    // SPI_SETDESKWALLPAPER (20) with SPIF_UPDATEINIFILE | SPIF_SENDWININICHANGE (3)
+    let escaped = path.replace('\'', "''");
+    let script = format!(
+        "Add-Type -TypeDefinition 'using System.Runtime.InteropServices; \
+         public class ParFractalWallpaper {{ \
+         [DllImport(\"user32.dll\", CharSet = CharSet.Unicode)] \
+         public static extern int SystemParametersInfo(int action, int param, string value, int flags); }}'; \
+         if ([ParFractalWallpaper]::SystemParametersInfo(20, 0, '{}', 3) -eq 0) {{ exit 1 }}",
+        escaped
+    );
+    run_command(
+        "powershell",
+        &["-NoProfile", "-NonInteractive", "-Command", &script],
+    )?;
+    Ok("Wallpaper set on all monitors".to_string())
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn set_platform_wallpaper(path: &str, _monitor_index: usize) -> WallpaperResult<String> {
+    let desktop = std::env::var("XDG_CURRENT_DESKTOP")
+        .unwrap_or_default()
+        .to_uppercase();
+
+    if desktop.contains("KDE") {
+        run_command("plasma-apply-wallpaperimage", &[path])?;
+    } else if desktop.contains("GNOME")
+        || desktop.contains("UNITY")
+        || desktop.contains("BUDGIE")
+        || desktop.contains("CINNAMON")
+    {
+        let schema = if desktop.contains("CINNAMON") {
+            "org.cinnamon.desktop.background"
+        } else {
+            "org.gnome.desktop.background"
+        };
+        let uri = format!("file://{}", path);
+        run_command("gsettings", &["set", schema, "picture-uri", &uri])?;
+        // Newer GNOME keeps a separate wallpaper for dark mode; older versions lack the key
+        let _ = run_command("gsettings", &["set", schema, "picture-uri-dark", &uri]);
+    } else {
+        run_command("feh", &["--bg-fill", path]).map_err(|_| {
+            format!(
+                "Unknown desktop environment '{}' (install feh or set the wallpaper manually)",
+                desktop
+            )
+        })?;
+    }
+
+    Ok("Wallpaper set on all monitors".to_string())
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn set_platform_wallpaper(_path: &str, _monitor_index: usize) -> WallpaperResult<String> {
+    Err("Setting the wallpaper is not supported on this platform".into())
+}
+
+#[cfg(any(unix, target_os = "windows"))]
+fn run_command(program: &str, args: &[&str]) -> WallpaperResult<()> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into())
+    }
+}
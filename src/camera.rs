@@ -26,10 +26,26 @@ impl Camera {
         }
     }
 
+    /// Use `up` as the world up axis, starting from its default viewpoint
+    pub fn with_up(mut self, up: Vec3) -> Self {
+        self.up = up;
+        self.reset_to_default();
+        self
+    }
+
+    /// Move back to the default viewpoint, keeping the up axis. With Z up the
+    /// default Y-up view is rotated to match, so the camera sits at -Y.
     pub fn reset_to_default(&mut self) {
-        self.position = Vec3::new(0.0, 0.0, 5.0);
+        let frame = glam::Quat::from_rotation_arc(Vec3::Y, self.up);
+        self.position = frame * Vec3::new(0.0, 0.0, 5.0);
+        self.target = Vec3::ZERO;
+    }
+
+    /// Look down on the 3D relief of a 2D fractal from above its near edge
+    pub fn relief_overview(&mut self) {
+        let frame = glam::Quat::from_rotation_arc(Vec3::Y, self.up);
+        self.position = frame * Vec3::new(0.0, 2.5, 3.5);
         self.target = Vec3::ZERO;
-        self.up = Vec3::Y;
     }
 
     pub fn build_view_projection_matrix(&self) -> Mat4 {
@@ -39,7 +55,26 @@ impl Camera {
     }
 
     pub fn resize(&mut self, width: u32, height: u32) {
-        self.aspect = width as f32 / height as f32;
+        // A minimized window reports a zero size; keep the last valid aspect
+        if width > 0 && height > 0 {
+            self.aspect = width as f32 / height as f32;
+        }
+    }
+
+    /// Build a camera for one eye of a stereo pair.
+    ///
+    /// The eye is shifted sideways by `offset` along the camera's right vector
+    /// (negative = left eye). Position and target move together so the two eyes
+    /// look along parallel axes.
+    pub fn stereo_eye(&self, offset: f32) -> Camera {
+        let forward = (self.target - self.position).normalize_or_zero();
+        let right = forward.cross(self.up).normalize_or_zero();
+        let shift = right * offset;
+        Camera {
+            position: self.position + shift,
+            target: self.target + shift,
+            ..self.clone()
+        }
     }
 }
 
@@ -56,8 +91,20 @@ pub struct CameraController {
     last_mouse_pos: Option<(f32, f32)>,
     yaw: f32,
     pitch: f32,
+    /// World up; yaw turns around it and pitch tilts toward it
+    up: Vec3,
+    /// Ease in and coast to a stop instead of moving at full speed instantly
+    inertia_enabled: bool,
+    /// How quickly movement reaches full speed (per second)
+    acceleration: f32,
+    /// How quickly movement coasts to a stop after release (per second)
+    damping: f32,
+    velocity: Vec3,
 }
 
+/// Below this fraction of the movement speed a coasting camera stops
+const INERTIA_STOP_FRACTION: f32 = 0.01;
+
 impl CameraController {
     pub fn new(speed: f32) -> Self {
         // Initialize yaw to point forward (along -Z axis)
@@ -75,9 +122,15 @@ impl CameraController {
             last_mouse_pos: None,
             yaw: 0.0,   // 0 means looking along -Z
             pitch: 0.0, // 0 means level (no up/down tilt)
+            up: Vec3::Y,
+            inertia_enabled: false,
+            acceleration: 8.0,
+            damping: 4.0,
+            velocity: Vec3::ZERO,
         }
     }
 
+    #[allow(clippy::collapsible_match)]
     pub fn process_events(&mut self, event: &WindowEvent) -> bool {
         match event {
             WindowEvent::KeyboardInput {
@@ -189,34 +242,56 @@ impl CameraController {
         }
     }
 
-    pub fn update_camera(&self, camera: &mut Camera, dt: f32) {
-        // Calculate orientation from yaw/pitch first
+    pub fn update_camera(&mut self, camera: &mut Camera, dt: f32) {
+        // Calculate orientation from yaw/pitch first, in the Y-up frame
         let yaw_quat = glam::Quat::from_axis_angle(Vec3::Y, self.yaw);
         let pitch_quat = glam::Quat::from_axis_angle(Vec3::X, self.pitch);
-        let rotation = yaw_quat * pitch_quat;
+        let rotation = self.frame() * yaw_quat * pitch_quat;
 
+        camera.up = self.up;
         let forward = rotation * Vec3::new(0.0, 0.0, -1.0);
         let right = forward.cross(camera.up).normalize();
 
-        // Move camera based on input
+        // Velocity requested by the movement keys
+        let mut input = Vec3::ZERO;
         if self.is_forward_pressed {
-            camera.position += forward * self.speed * dt;
+            input += forward;
         }
         if self.is_backward_pressed {
-            camera.position -= forward * self.speed * dt;
+            input -= forward;
         }
         if self.is_right_pressed {
-            camera.position += right * self.speed * dt;
+            input += right;
         }
         if self.is_left_pressed {
-            camera.position -= right * self.speed * dt;
+            input -= right;
         }
         if self.is_up_pressed {
-            camera.position += camera.up * self.speed * dt;
+            input += camera.up;
         }
         if self.is_down_pressed {
-            camera.position -= camera.up * self.speed * dt;
+            input -= camera.up;
+        }
+        let target_velocity = input * self.speed;
+
+        if self.inertia_enabled {
+            // Exponential approach keeps the easing independent of frame rate
+            let rate = if self.is_any_key_pressed() {
+                self.acceleration
+            } else {
+                self.damping
+            };
+            let blend = 1.0 - (-rate.max(0.0) * dt.max(0.0)).exp();
+            self.velocity += (target_velocity - self.velocity) * blend;
+            if !self.is_any_key_pressed()
+                && self.velocity.length() < self.speed * INERTIA_STOP_FRACTION
+            {
+                self.velocity = Vec3::ZERO;
+            }
+        } else {
+            self.velocity = target_velocity;
         }
+        camera.position += self.velocity * dt;
 
         // Update camera target to look in the direction defined by yaw/pitch
         camera.target = camera.position + forward;
@@ -233,9 +308,44 @@ impl CameraController {
         self.speed = speed;
     }
 
+    /// Configure velocity-based movement. Disabling it stops any coasting.
+    pub fn set_inertia(&mut self, enabled: bool, acceleration: f32, damping: f32) {
+        self.inertia_enabled = enabled;
+        self.acceleration = acceleration;
+        self.damping = damping;
+        if !enabled && !self.is_any_key_pressed() {
+            self.velocity = Vec3::ZERO;
+        }
+    }
+
+    /// Stop any coasting movement (e.g. when something else takes over the camera)
+    pub fn stop(&mut self) {
+        self.velocity = Vec3::ZERO;
+    }
+
+    /// Whether the camera is still moving from keyboard input (including coasting)
+    pub fn is_moving(&self) -> bool {
+        self.velocity != Vec3::ZERO
+    }
+
+    /// Change the world up axis. Call `point_at_target` afterwards to keep
+    /// looking the same way.
+    pub fn set_up(&mut self, up: Vec3) {
+        self.up = up;
+    }
+
+    pub fn up(&self) -> Vec3 {
+        self.up
+    }
+
+    /// Rotation from the Y-up frame yaw and pitch are measured in to the world
+    fn frame(&self) -> glam::Quat {
+        glam::Quat::from_rotation_arc(Vec3::Y, self.up)
+    }
+
     pub fn point_at_target(&mut self, camera_pos: Vec3, target: Vec3) {
-        // Calculate direction from camera to target
-        let direction = (target - camera_pos).normalize();
+        // Calculate direction from camera to target, in the Y-up frame
+        let direction = self.frame().inverse() * (target - camera_pos).normalize();
 
         // Calculate yaw (rotation around Y axis) from the horizontal components
         // Note: atan2(x, z) where default forward is -Z
@@ -252,7 +362,6 @@ impl CameraController {
             .clamp(-89.0f32.to_radians(), 89.0f32.to_radians());
     }
 
-    #[allow(dead_code)]
     pub fn is_any_key_pressed(&self) -> bool {
         self.is_forward_pressed
             || self.is_backward_pressed
@@ -365,6 +474,14 @@ mod tests {
         assert!((camera.aspect - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_camera_resize_ignores_zero_size() {
+        let mut camera = Camera::new(600, 900);
+        camera.resize(0, 0);
+        camera.resize(600, 0);
+        assert!((camera.aspect - 600.0 / 900.0).abs() < 0.001);
+    }
+
     #[test]
     fn test_camera_view_projection_matrix() {
         let camera = Camera::new(1280, 720);
@@ -451,7 +568,7 @@ mod tests {
 
     #[test]
     fn test_camera_no_movement_when_idle() {
-        let controller = CameraController::new(2.0);
+        let mut controller = CameraController::new(2.0);
         let mut camera = Camera::new(1280, 720);
         let original_pos = camera.position;
 
@@ -478,6 +595,48 @@ mod tests {
         assert!(dist2 > dist1);
     }
 
+    #[test]
+    fn test_camera_inertia_eases_in_and_coasts() {
+        let mut controller = CameraController::new(2.0);
+        controller.set_inertia(true, 8.0, 4.0);
+        let mut camera = Camera::new(1280, 720);
+
+        // First frame moves less than full speed would
+        controller.simulate_forward_press(true);
+        controller.update_camera(&mut camera, 0.1);
+        let first_step = (camera.position - Vec3::new(0.0, 0.0, 5.0)).length();
+        assert!(first_step > 0.0 && first_step < 2.0 * 0.1);
+
+        // After release the camera keeps moving, then comes to rest
+        controller.simulate_forward_press(false);
+        let released = camera.position;
+        controller.update_camera(&mut camera, 0.1);
+        assert_ne!(camera.position, released);
+        for _ in 0..100 {
+            controller.update_camera(&mut camera, 0.1);
+        }
+        assert!(!controller.is_moving());
+    }
+
+    #[test]
+    fn test_camera_z_up() {
+        let mut controller = CameraController::new(2.0);
+        controller.set_up(Vec3::Z);
+        let mut camera = Camera::new(1280, 720);
+        camera.up = Vec3::Z;
+
+        // The default view is rotated to look along +Y from -Y
+        camera.reset_to_default();
+        assert!((camera.position - Vec3::new(0.0, -5.0, 0.0)).length() < 1e-5);
+
+        // Pointing at the origin and flying up keeps looking at it and rises along Z
+        controller.point_at_target(camera.position, camera.target);
+        controller.simulate_up_press(true);
+        controller.update_camera(&mut camera, 1.0);
+        assert!((camera.position - Vec3::new(0.0, -5.0, 2.0)).length() < 1e-4);
+        assert!((camera.target - camera.position - Vec3::Y).length() < 1e-4);
+    }
+
     #[test]
     fn test_camera_target_updates_with_rotation() {
         let mut controller = CameraController::new(2.0);
@@ -0,0 +1,375 @@
+//! Camera paths: keyframed fly-throughs for the 3D camera
+//!
+//! A path is a list of keyframes, each holding a camera pose and the time it
+//! takes to travel to the next one. Positions and targets follow a
+//! Catmull-Rom spline through the keyframes so the motion doesn't kink at
+//! each one; the field of view is interpolated linearly.
+//!
+//! Paths can be exported and imported as CSV (one keyframe per row) or JSON
+//! (an array of keyframe objects), to edit them elsewhere or generate them
+//! with a script.
+
+use crate::fractal::CameraEasing;
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+
+/// Shortest segment duration, so a path always has a nonzero length
+pub const MIN_SEGMENT_SECS: f32 = 0.1;
+
+/// A camera pose on a path
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraKeyframe {
+    pub position: Vec3,
+    pub target: Vec3,
+    pub fov: f32,
+    /// Seconds to travel from this keyframe to the next (unused on the last)
+    pub duration: f32,
+    /// Easing of the travel to the next keyframe
+    pub easing: CameraEasing,
+}
+
+/// Camera pose sampled from a path
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraPose {
+    pub position: Vec3,
+    pub target: Vec3,
+    pub fov: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CameraPath {
+    pub keyframes: Vec<CameraKeyframe>,
+}
+
+impl CameraPath {
+    pub fn push(&mut self, keyframe: CameraKeyframe) {
+        self.keyframes.push(CameraKeyframe {
+            duration: keyframe.duration.max(MIN_SEGMENT_SECS),
+            ..keyframe
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.keyframes.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.keyframes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keyframes.is_empty()
+    }
+
+    /// Playback length in seconds
+    pub fn total_duration(&self) -> f32 {
+        match self.keyframes.split_last() {
+            Some((_, segments)) => segments.iter().map(|k| k.duration).sum(),
+            None => 0.0,
+        }
+    }
+
+    /// The pose at normalized time `t` (0 = first keyframe, 1 = last), or
+    /// `None` for an empty path
+    pub fn sample(&self, t: f32) -> Option<CameraPose> {
+        let keyframes = &self.keyframes;
+        let last = keyframes.len().checked_sub(1)?;
+        if last == 0 {
+            return Some(keyframes[0].pose());
+        }
+
+        // Find the segment containing the requested time
+        let mut time = t.clamp(0.0, 1.0) * self.total_duration();
+        let mut segment = 0;
+        while segment < last - 1 && time > keyframes[segment].duration {
+            time -= keyframes[segment].duration;
+            segment += 1;
+        }
+        let local = keyframes[segment]
+            .easing
+            .apply((time / keyframes[segment].duration).clamp(0.0, 1.0));
+
+        // Neighbouring keyframes, repeating the ends
+        let k0 = &keyframes[segment.saturating_sub(1)];
+        let k1 = &keyframes[segment];
+        let k2 = &keyframes[segment + 1];
+        let k3 = &keyframes[(segment + 2).min(last)];
+        Some(CameraPose {
+            position: catmull_rom(k0.position, k1.position, k2.position, k3.position, local),
+            target: catmull_rom(k0.target, k1.target, k2.target, k3.target, local),
+            fov: k1.fov + (k2.fov - k1.fov) * local,
+        })
+    }
+}
+
+/// File format of an exported camera path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraPathFormat {
+    Csv,
+    Json,
+}
+
+impl CameraPathFormat {
+    /// The format for a file extension, e.g. `csv`
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "csv" => Some(CameraPathFormat::Csv),
+            "json" => Some(CameraPathFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Column names of the CSV format
+const CSV_HEADER: &str =
+    "position_x,position_y,position_z,target_x,target_y,target_z,fov,duration,easing";
+
+/// A keyframe as written to a JSON file
+#[derive(Serialize, Deserialize)]
+struct KeyframeRecord {
+    position: [f32; 3],
+    target: [f32; 3],
+    fov: f32,
+    duration: f32,
+    #[serde(default = "linear")]
+    easing: CameraEasing,
+}
+
+fn linear() -> CameraEasing {
+    CameraEasing::Linear
+}
+
+impl CameraPath {
+    /// The path as CSV or JSON text
+    pub fn export(&self, format: CameraPathFormat) -> String {
+        match format {
+            CameraPathFormat::Csv => {
+                let mut csv = format!("{}\n", CSV_HEADER);
+                for k in &self.keyframes {
+                    csv.push_str(&format!(
+                        "{},{},{},{},{},{},{},{},{:?}\n",
+                        k.position.x,
+                        k.position.y,
+                        k.position.z,
+                        k.target.x,
+                        k.target.y,
+                        k.target.z,
+                        k.fov,
+                        k.duration,
+                        k.easing
+                    ));
+                }
+                csv
+            }
+            CameraPathFormat::Json => {
+                let records: Vec<KeyframeRecord> = self
+                    .keyframes
+                    .iter()
+                    .map(|k| KeyframeRecord {
+                        position: k.position.to_array(),
+                        target: k.target.to_array(),
+                        fov: k.fov,
+                        duration: k.duration,
+                        easing: k.easing,
+                    })
+                    .collect();
+                serde_json::to_string_pretty(&records).unwrap_or_default()
+            }
+        }
+    }
+
+    /// Read a path from CSV or JSON text. The path must have at least two
+    /// keyframes, each taking a positive time to reach the next so keyframe
+    /// times increase; the error says which keyframe is wrong.
+    pub fn import(text: &str, format: CameraPathFormat) -> Result<CameraPath, String> {
+        let records = match format {
+            CameraPathFormat::Csv => parse_csv(text)?,
+            CameraPathFormat::Json => serde_json::from_str::<Vec<KeyframeRecord>>(text)
+                .map_err(|e| format!("Invalid camera path JSON: {}", e))?,
+        };
+        if records.len() < 2 {
+            return Err(format!(
+                "A camera path needs at least two keyframes, found {}",
+                records.len()
+            ));
+        }
+
+        let last = records.len() - 1;
+        let mut path = CameraPath::default();
+        for (index, record) in records.into_iter().enumerate() {
+            let number = index + 1;
+            let values = record.position.iter().chain(&record.target);
+            if values
+                .chain([&record.fov, &record.duration])
+                .any(|v| !v.is_finite())
+            {
+                return Err(format!(
+                    "Keyframe {}: values must be finite numbers",
+                    number
+                ));
+            }
+            if !(1.0..=179.0).contains(&record.fov) {
+                return Err(format!(
+                    "Keyframe {}: fov {} is outside 1-179 degrees",
+                    number, record.fov
+                ));
+            }
+            if index < last && record.duration <= 0.0 {
+                return Err(format!(
+                    "Keyframe {}: duration must be positive so keyframe times increase",
+                    number
+                ));
+            }
+            path.push(CameraKeyframe {
+                position: Vec3::from_array(record.position),
+                target: Vec3::from_array(record.target),
+                fov: record.fov,
+                duration: record.duration,
+                easing: record.easing,
+            });
+        }
+        Ok(path)
+    }
+}
+
+/// Parse CSV keyframe rows, skipping the header, blank lines and `#` comments
+fn parse_csv(text: &str) -> Result<Vec<KeyframeRecord>, String> {
+    let mut records = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("position_x") {
+            continue;
+        }
+        let line_number = index + 1;
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if !(8..=9).contains(&fields.len()) {
+            return Err(format!(
+                "Line {}: expected 9 columns ({}), found {}",
+                line_number,
+                CSV_HEADER,
+                fields.len()
+            ));
+        }
+        let mut numbers = [0.0f32; 8];
+        for (number, field) in numbers.iter_mut().zip(&fields) {
+            *number = field
+                .parse()
+                .map_err(|_| format!("Line {}: '{}' is not a number", line_number, field))?;
+        }
+        let easing = match fields.get(8) {
+            None | Some(&"") => CameraEasing::Linear,
+            Some(name) => CameraEasing::ALL
+                .into_iter()
+                .find(|e| format!("{:?}", e).eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    format!(
+                        "Line {}: unknown easing '{}' (Linear, EaseIn, EaseOut, EaseInOut or Elastic)",
+                        line_number, name
+                    )
+                })?,
+        };
+        records.push(KeyframeRecord {
+            position: [numbers[0], numbers[1], numbers[2]],
+            target: [numbers[3], numbers[4], numbers[5]],
+            fov: numbers[6],
+            duration: numbers[7],
+            easing,
+        });
+    }
+    Ok(records)
+}
+
+impl CameraKeyframe {
+    pub fn pose(&self) -> CameraPose {
+        CameraPose {
+            position: self.position,
+            target: self.target,
+            fov: self.fov,
+        }
+    }
+}
+
+/// Uniform Catmull-Rom spline between `p1` and `p2`
+fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyframe(x: f32, fov: f32, duration: f32) -> CameraKeyframe {
+        CameraKeyframe {
+            position: Vec3::new(x, 0.0, 5.0),
+            target: Vec3::ZERO,
+            fov,
+            duration,
+            easing: CameraEasing::Linear,
+        }
+    }
+
+    #[test]
+    fn test_camera_path_sampling() {
+        let mut path = CameraPath::default();
+        assert!(path.sample(0.5).is_none());
+
+        path.push(keyframe(0.0, 40.0, 1.0));
+        assert_eq!(path.sample(0.7), Some(path.keyframes[0].pose()));
+
+        path.push(keyframe(1.0, 50.0, 3.0));
+        path.push(keyframe(2.0, 60.0, 1.0));
+        assert_eq!(path.total_duration(), 4.0);
+
+        // Passes through every keyframe
+        assert_eq!(path.sample(0.0), Some(path.keyframes[0].pose()));
+        let middle = path.sample(0.25).unwrap();
+        assert!((middle.position - path.keyframes[1].position).length() < 1e-5);
+        assert!((path.sample(1.0).unwrap().position.x - 2.0).abs() < 1e-5);
+
+        // Time is split by segment duration: 0.625 is halfway through the second
+        let pose = path.sample(0.625).unwrap();
+        assert!((pose.fov - 55.0).abs() < 1e-4);
+        assert!(pose.position.x > 1.0 && pose.position.x < 2.0);
+    }
+    #[test]
+    fn test_camera_path_import_export() {
+        let mut path = CameraPath::default();
+        path.push(keyframe(0.0, 40.0, 1.5));
+        path.push(CameraKeyframe {
+            easing: CameraEasing::EaseInOut,
+            ..keyframe(1.0, 50.0, 2.0)
+        });
+        path.push(keyframe(2.5, 60.0, 1.0));
+
+        for format in [CameraPathFormat::Csv, CameraPathFormat::Json] {
+            let text = path.export(format);
+            let loaded = CameraPath::import(&text, format).unwrap();
+            assert_eq!(loaded.keyframes, path.keyframes, "{:?}", format);
+        }
+
+        // Easing is optional in CSV and defaults to linear
+        let csv = "0,0,5,0,0,0,45,2\n1,0,5,0,0,0,45,0\n";
+        let loaded = CameraPath::import(csv, CameraPathFormat::Csv).unwrap();
+        assert_eq!(loaded.keyframes[0].easing, CameraEasing::Linear);
+
+        // Invalid paths are rejected with the offending keyframe
+        let one = "0,0,5,0,0,0,45,2\n";
+        assert!(CameraPath::import(one, CameraPathFormat::Csv)
+            .unwrap_err()
+            .contains("at least two"));
+        let stalled = "0,0,5,0,0,0,45,0\n1,0,5,0,0,0,45,1\n";
+        assert!(CameraPath::import(stalled, CameraPathFormat::Csv)
+            .unwrap_err()
+            .starts_with("Keyframe 1"));
+        let bad = "0,0,5,0,0,0,45,2\n1,x,5,0,0,0,45,1\n";
+        assert!(CameraPath::import(bad, CameraPathFormat::Csv)
+            .unwrap_err()
+            .starts_with("Line 2"));
+        assert!(CameraPath::import("{}", CameraPathFormat::Json).is_err());
+    }
+}
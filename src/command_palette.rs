@@ -72,6 +72,11 @@ pub enum CommandAction {
     ToggleUI,
     ToggleStats,
     ToggleFPS,
+    ToggleIterationReadout,
+    ToggleConsole,
+    ToggleMathInfo,
+    ScreenshotToClipboard,
+    ToggleTour,
     ResetView,
     ResetAll,
     SavePreset,
@@ -83,6 +88,7 @@ pub enum CommandAction {
     CycleTheme,
     CyclePalette,
     CycleProceduralPalette,
+    ShuffleColors,
     IncrementIterations,
     DecrementIterations,
     IncrementPower,
@@ -716,6 +722,11 @@ impl CommandPalette {
                 "Orbit Trap Radial",
                 vec!["orbit radial", "trap radial"],
             ),
+            (
+                ColorMode::OrbitTrapImage,
+                "Orbit Trap Image",
+                vec!["orbit image", "trap image", "image trap"],
+            ),
             (
                 ColorMode::WorldPosition,
                 "World Position",
@@ -1008,6 +1019,46 @@ impl CommandPalette {
             .with_shortcut("V"),
         );
 
+        commands.push(
+            Command::new(
+                "Toggle Iteration Readout",
+                CommandCategory::UI,
+                CommandAction::ToggleIterationReadout,
+                "Show/hide the 2D iteration count under the cursor",
+            )
+            .with_aliases(vec!["hover", "iterations", "escape count", "probe"]),
+        );
+
+        commands.push(
+            Command::new(
+                "Toggle Console",
+                CommandCategory::UI,
+                CommandAction::ToggleConsole,
+                "Show/hide the log console",
+            )
+            .with_aliases(vec!["log", "logs", "console", "diagnostics", "debug"]),
+        );
+
+        commands.push(
+            Command::new(
+                "Toggle Math Info",
+                CommandCategory::UI,
+                CommandAction::ToggleMathInfo,
+                "Show/hide the formula and parameter reference for the current fractal",
+            )
+            .with_aliases(vec!["math", "formula", "equation", "explain", "learn"]),
+        );
+
+        commands.push(
+            Command::new(
+                "Start/Stop Tour",
+                CommandCategory::UI,
+                CommandAction::ToggleTour,
+                "Cycle through the built-in presets like a screensaver",
+            )
+            .with_aliases(vec!["tour", "kiosk", "slideshow", "screensaver", "demo"]),
+        );
+
         commands.push(
             Command::new(
                 "Toggle Theme",
@@ -1046,6 +1097,21 @@ impl CommandPalette {
             .with_shortcut("Shift+P"),
         );
 
+        commands.push(
+            Command::new(
+                "Shuffle Colors",
+                CommandCategory::Color,
+                CommandAction::ShuffleColors,
+                "Switch to a random palette without changing the fractal",
+            )
+            .with_aliases(vec![
+                "random palette",
+                "randomize colors",
+                "shuffle palette",
+            ])
+            .with_shortcut("C"),
+        );
+
         commands.push(
             Command::new(
                 "Increase Iterations/Steps",
@@ -1176,6 +1242,17 @@ impl CommandPalette {
             .with_shortcut("F12"),
         );
 
+        commands.push(
+            Command::new(
+                "Copy Screenshot to Clipboard",
+                CommandCategory::Recording,
+                CommandAction::ScreenshotToClipboard,
+                "Copy current view to the clipboard instead of saving a file",
+            )
+            .with_aliases(vec!["clipboard", "copy image", "copy screenshot", "paste"])
+            .with_shortcut("Shift+F12"),
+        );
+
         // === Settings Commands ===
         commands.push(
             Command::new(
@@ -0,0 +1,63 @@
+//! Per-fractal coloring memory
+//!
+//! Switching fractal type applies that type's generic defaults. The app keeps
+//! the coloring and iteration settings last used with each type, so hopping to
+//! another fractal and back restores the tuning instead of starting over.
+
+use super::{ColorMode, ColorPalette, FractalParams, InteriorColorMode, ProceduralPalette};
+
+/// Coloring and iteration settings remembered for one fractal type
+#[derive(Debug, Clone, Copy)]
+pub struct FractalColoring {
+    pub color_mode: ColorMode,
+    pub palette: ColorPalette,
+    pub palette_index: usize,
+    pub palette_offset: f32,
+    pub procedural_palette: ProceduralPalette,
+    pub procedural_brightness: [f32; 3],
+    pub procedural_contrast: [f32; 3],
+    pub procedural_frequency: [f32; 3],
+    pub procedural_phase: [f32; 3],
+    pub interior_color_mode: InteriorColorMode,
+    pub interior_distance_lighting: bool,
+    pub max_iterations: u32,
+    pub power: f32,
+}
+
+impl FractalColoring {
+    /// Take the remembered settings from `params`
+    pub fn capture(params: &FractalParams) -> Self {
+        Self {
+            color_mode: params.color_mode,
+            palette: params.palette,
+            palette_index: params.palette_index,
+            palette_offset: params.palette_offset,
+            procedural_palette: params.procedural_palette,
+            procedural_brightness: params.procedural_brightness,
+            procedural_contrast: params.procedural_contrast,
+            procedural_frequency: params.procedural_frequency,
+            procedural_phase: params.procedural_phase,
+            interior_color_mode: params.interior_color_mode,
+            interior_distance_lighting: params.interior_distance_lighting,
+            max_iterations: params.max_iterations,
+            power: params.power,
+        }
+    }
+
+    /// Write the remembered settings back into `params`
+    pub fn apply(&self, params: &mut FractalParams) {
+        params.color_mode = self.color_mode;
+        params.palette = self.palette;
+        params.palette_index = self.palette_index;
+        params.palette_offset = self.palette_offset;
+        params.procedural_palette = self.procedural_palette;
+        params.procedural_brightness = self.procedural_brightness;
+        params.procedural_contrast = self.procedural_contrast;
+        params.procedural_frequency = self.procedural_frequency;
+        params.procedural_phase = self.procedural_phase;
+        params.interior_color_mode = self.interior_color_mode;
+        params.interior_distance_lighting = self.interior_distance_lighting;
+        params.max_iterations = self.max_iterations;
+        params.power = self.power;
+    }
+}
@@ -0,0 +1,65 @@
+//! A second 3D fractal composited into the scene
+//!
+//! The scene's distance estimate becomes the union (minimum) of the main
+//! fractal and a secondary one placed with its own position, rotation and
+//! scale, e.g. a Mandelbulb next to a Menger Sponge. Both share the shape
+//! parameters of the 3D Parameters section. Evaluating two distance
+//! estimators roughly doubles the cost of every ray step, so it's off by
+//! default.
+
+use super::{FractalParams, FractalType, RenderMode};
+use glam::{EulerRot, Mat3};
+use serde::{Deserialize, Serialize};
+
+/// The secondary fractal slot and its transform
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SecondaryFractal {
+    pub enabled: bool,
+    pub fractal_type: FractalType,
+    /// Center of the secondary fractal in world space
+    pub position: [f32; 3],
+    /// Rotation in degrees around X, then Y, then Z
+    pub rotation: [f32; 3],
+    /// Uniform scale, 1 = the size of the main fractal
+    pub scale: f32,
+    /// Color multiplied into the secondary fractal's albedo, to tell the two apart
+    pub tint: [f32; 3],
+}
+
+impl Default for SecondaryFractal {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fractal_type: FractalType::MengerSponge3D,
+            position: [2.5, 0.0, 0.0],
+            rotation: [0.0; 3],
+            scale: 1.0,
+            tint: [0.55, 0.75, 1.0],
+        }
+    }
+}
+
+impl SecondaryFractal {
+    /// Whether `fractal_type` can be composited: the ray-marched 3D fractals
+    pub fn supports(fractal_type: FractalType) -> bool {
+        fractal_type.render_mode() == RenderMode::ThreeD && !fractal_type.uses_accumulation()
+    }
+
+    /// Rotation taking world space into the secondary fractal's own space
+    pub fn inverse_rotation(&self) -> Mat3 {
+        let [x, y, z] = self.rotation.map(f32::to_radians);
+        Mat3::from_euler(EulerRot::ZYX, z, y, x).transpose()
+    }
+}
+
+impl FractalParams {
+    /// Whether the secondary fractal is rendered: it's enabled and both
+    /// fractals are ray-marched 3D fractals
+    pub fn secondary_fractal_active(&self) -> bool {
+        self.secondary_fractal.enabled
+            && self.render_mode == RenderMode::ThreeD
+            && SecondaryFractal::supports(self.fractal_type)
+            && SecondaryFractal::supports(self.secondary_fractal.fractal_type)
+    }
+}
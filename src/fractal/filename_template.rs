@@ -0,0 +1,151 @@
+//! Capture filename templates
+//!
+//! Screenshots, renders, sweeps and videos are named from a template in the
+//! preferences, e.g. `project_{fractal}_{counter}`. Tokens in braces are
+//! replaced with values of the capture; an invalid template falls back to
+//! the default `{fractal}_{date}_{time}`.
+
+/// Template used when none is set or the configured one is invalid
+pub const DEFAULT_FILENAME_TEMPLATE: &str = "{fractal}_{date}_{time}";
+
+/// Tokens a filename template may contain, with what they stand for
+pub const FILENAME_TOKENS: &[(&str, &str)] = &[
+    ("fractal", "Fractal type, e.g. mandelbrot"),
+    ("date", "Date as YYYYMMDD"),
+    ("time", "Time as HHMMSS"),
+    ("width", "Image width in pixels"),
+    ("height", "Image height in pixels"),
+    ("palette", "Palette name"),
+    (
+        "counter",
+        "Number counting up, 4 digits, skipping files that exist",
+    ),
+];
+
+/// Values substituted into a filename template
+#[derive(Debug, Clone, Default)]
+pub struct CaptureNameValues {
+    pub fractal: String,
+    pub date: String,
+    pub time: String,
+    pub width: u32,
+    pub height: u32,
+    pub palette: String,
+    pub counter: u32,
+}
+
+/// Lowercase a name and replace anything but letters and digits with `_`
+pub fn filename_safe(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Split a template into literal text and `{token}` names, checking that
+/// every token is known and the literal text is safe in a filename
+fn parse_filename_template(template: &str) -> Result<Vec<(bool, &str)>, String> {
+    if template.trim().is_empty() {
+        return Err("Template is empty".to_string());
+    }
+    let mut parts = Vec::new();
+    let mut rest = template;
+    while !rest.is_empty() {
+        match rest.find(['{', '}']) {
+            Some(i) if rest[i..].starts_with('}') => {
+                return Err("Unmatched '}'".to_string());
+            }
+            Some(i) => {
+                if i > 0 {
+                    parts.push((false, &rest[..i]));
+                }
+                let Some(end) = rest[i..].find('}') else {
+                    return Err("Unmatched '{'".to_string());
+                };
+                let token = &rest[i + 1..i + end];
+                if !FILENAME_TOKENS.iter().any(|(name, _)| *name == token) {
+                    return Err(format!("Unknown token {{{}}}", token));
+                }
+                parts.push((true, token));
+                rest = &rest[i + end + 1..];
+            }
+            None => {
+                parts.push((false, rest));
+                rest = "";
+            }
+        }
+    }
+    if let Some(c) = parts
+        .iter()
+        .filter(|(is_token, _)| !is_token)
+        .flat_map(|(_, text)| text.chars())
+        .find(|c| {
+            matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') || c.is_control()
+        })
+    {
+        return Err(format!("'{}' isn't allowed in a filename", c));
+    }
+    Ok(parts)
+}
+
+/// Check a template, returning why it can't be used
+pub fn validate_filename_template(template: &str) -> Result<(), String> {
+    parse_filename_template(template).map(|_| ())
+}
+
+/// Whether the template numbers captures with `{counter}`
+pub fn filename_template_uses_counter(template: &str) -> bool {
+    parse_filename_template(template).is_ok_and(|parts| parts.contains(&(true, "counter")))
+}
+
+/// Whether captures named from the template get distinct names, through
+/// `{time}` or `{counter}`. An invalid template counts, as it falls back to
+/// the default.
+pub fn filename_template_is_unique(template: &str) -> bool {
+    parse_filename_template(template).map_or(true, |parts| {
+        parts.contains(&(true, "time")) || parts.contains(&(true, "counter"))
+    })
+}
+
+/// The template to name captures with: one without `{time}` or `{counter}`
+/// gets `_{counter}` appended, so a capture never overwrites an earlier one
+pub fn unique_filename_template(template: &str) -> String {
+    if filename_template_is_unique(template) {
+        template.to_string()
+    } else {
+        format!("{}_{{counter}}", template)
+    }
+}
+
+/// The file name (without extension) for a capture. Falls back to
+/// `DEFAULT_FILENAME_TEMPLATE` when the template is invalid.
+pub fn expand_filename_template(template: &str, values: &CaptureNameValues) -> String {
+    let parts = parse_filename_template(template).unwrap_or_else(|e| {
+        log::warn!("Invalid filename template '{}': {}", template, e);
+        parse_filename_template(DEFAULT_FILENAME_TEMPLATE).unwrap_or_default()
+    });
+    parts
+        .into_iter()
+        .map(|(is_token, text)| {
+            if !is_token {
+                return text.to_string();
+            }
+            match text {
+                "fractal" => values.fractal.clone(),
+                "date" => values.date.clone(),
+                "time" => values.time.clone(),
+                "width" => values.width.to_string(),
+                "height" => values.height.to_string(),
+                "palette" => values.palette.clone(),
+                "counter" => format!("{:04}", values.counter),
+                _ => String::new(),
+            }
+        })
+        .collect()
+}
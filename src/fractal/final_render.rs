@@ -0,0 +1,50 @@
+use super::{CaptureAntialiasing, FractalParams};
+use crate::lod::QualityLevel;
+
+/// Supersampling used by Final Render (16 jittered frames)
+pub const FINAL_RENDER_ANTIALIASING: CaptureAntialiasing = CaptureAntialiasing::RotatedGrid4x4;
+
+/// Ray marching quality used by Final Render: the top of each slider's range.
+/// Settings already above this are kept.
+pub fn final_render_quality() -> QualityLevel {
+    QualityLevel {
+        max_steps: 512,
+        min_distance: 0.0002,
+        shadow_samples: 256,
+        shadow_step_factor: 0.4,
+        ao_step_size: QualityLevel::ultra().ao_step_size,
+        dof_samples: 16,
+        render_scale: 1.0,
+    }
+}
+
+impl FractalParams {
+    /// A copy of these parameters for Final Render: LOD off, quality raised to the
+    /// final render profile, and FXAA off since the capture is supersampled.
+    ///
+    /// LOD overwrites the quality settings while it's enabled, so its full-quality
+    /// level is used as the starting point instead of whatever level is active.
+    pub fn final_render_params(&self) -> Self {
+        let mut params = self.clone();
+        if params.lod_config.enabled {
+            let full = params.lod_config.quality_presets[0];
+            params.max_steps = full.max_steps;
+            params.min_distance = full.min_distance;
+            params.shadow_samples = full.shadow_samples;
+            params.shadow_step_factor = full.shadow_step_factor;
+            params.ao_step_size = full.ao_step_size;
+            params.dof_samples = full.dof_samples;
+        }
+        params.lod_config.enabled = false;
+        params.lod_config.debug_visualization = false;
+
+        let quality = final_render_quality();
+        params.max_steps = params.max_steps.max(quality.max_steps);
+        params.min_distance = params.min_distance.min(quality.min_distance);
+        params.shadow_samples = params.shadow_samples.max(quality.shadow_samples);
+        params.shadow_step_factor = params.shadow_step_factor.min(quality.shadow_step_factor);
+        params.dof_samples = params.dof_samples.max(quality.dof_samples);
+        params.fxaa_enabled = false;
+        params
+    }
+}
@@ -0,0 +1,52 @@
+use super::{ColorMode, FractalParams, RenderMode};
+
+/// Range of ray march steps the Max Steps control allows
+pub const MAX_STEPS_RANGE: std::ops::RangeInclusive<u32> = 32..=512;
+
+impl FractalParams {
+    /// Clamp the iteration count and ray march steps to the ranges the controls
+    /// allow for the current fractal type, and drop a color mode it can't render.
+    ///
+    /// Settings and presets are written with whatever values the previous fractal
+    /// type used, so a loaded file can carry e.g. 1024 iterations into a Menger
+    /// Sponge. Returns one message per adjusted value, suitable for a toast.
+    pub fn apply_fractal_limits(&mut self) -> Vec<String> {
+        let mut adjustments = Vec::new();
+
+        if let Some(range) = self.fractal_type.max_iterations_range() {
+            let clamped = self.max_iterations.clamp(*range.start(), *range.end());
+            if clamped != self.max_iterations {
+                adjustments.push(format!(
+                    "Iterations adjusted to {} for this fractal (was {})",
+                    clamped, self.max_iterations
+                ));
+                self.max_iterations = clamped;
+            }
+        }
+
+        if self.render_mode == RenderMode::ThreeD {
+            let clamped = self
+                .max_steps
+                .clamp(*MAX_STEPS_RANGE.start(), *MAX_STEPS_RANGE.end());
+            if clamped != self.max_steps {
+                adjustments.push(format!(
+                    "Max steps adjusted to {} (was {})",
+                    clamped, self.max_steps
+                ));
+                self.max_steps = clamped;
+            }
+        }
+
+        if self.color_mode == ColorMode::OrbitTrapImage
+            && !self.fractal_type.supports_orbit_trap_image()
+        {
+            adjustments.push(
+                "Orbit Trap Image works with Mandelbrot, Julia and Mandelbulb; using Palette"
+                    .to_string(),
+            );
+            self.color_mode = ColorMode::Palette;
+        }
+
+        adjustments
+    }
+}
@@ -0,0 +1,467 @@
+//! Formula reference for each fractal type
+//!
+//! The Math Info window shows the iteration rule, a short description and the
+//! meaning of each parameter for the current fractal. The formulas follow what
+//! the shaders compute, including the app's own parameter mappings (e.g. the
+//! Mandelbox scale derived from the power), rather than textbook variants.
+
+use super::FractalType;
+
+/// Formula, description and parameter meanings for one fractal type
+#[derive(Debug, Clone, PartialEq)]
+pub struct FractalMathInfo {
+    pub title: &'static str,
+    /// Iteration rule, one equation per line
+    pub formula: &'static [&'static str],
+    pub description: &'static str,
+    /// Symbol and what it stands for
+    pub parameters: &'static [(&'static str, &'static str)],
+}
+
+impl FractalMathInfo {
+    /// Plain-text version for the clipboard, e.g. for lesson notes
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "{}\n\n{}\n\n{}\n",
+            self.title,
+            self.formula.join("\n"),
+            self.description
+        );
+        if !self.parameters.is_empty() {
+            text.push('\n');
+            for (symbol, meaning) in self.parameters {
+                text.push_str(&format!("{}: {}\n", symbol, meaning));
+            }
+        }
+        text
+    }
+}
+
+impl FractalType {
+    /// Formula reference shown in the Math Info window
+    pub fn math_info(&self) -> FractalMathInfo {
+        match self {
+            FractalType::Mandelbrot2D => FractalMathInfo {
+                title: "Mandelbrot Set",
+                formula: &["z₀ = 0", "z → z^n + c", "escape when |z| > 4"],
+                description: "The set of points c whose orbit stays bounded. Points outside are colored by how many iterations the orbit takes to escape, smoothed with a fractional count; points still bounded at the iteration limit count as inside. n = 2 is the classic set, other powers give Multibrots with n − 1 fold symmetry.",
+                parameters: &[
+                    ("c", "The pixel's position in the complex plane"),
+                    ("n", "Power (2 = classic); the escape radius grows for |n| < 2"),
+                    ("N", "Max Iterations: orbits still bounded after N steps count as inside"),
+                ],
+            },
+            FractalType::Julia2D => FractalMathInfo {
+                title: "Julia Set",
+                formula: &["z₀ = pixel position", "z → z^n + c", "escape when |z| > 4"],
+                description: "For one fixed constant c, the set of starting points whose orbit stays bounded. For n = 2, every point of the Mandelbrot set gives a connected Julia set; constants outside it give a scattered dust of points. Colored by smoothed escape time like the Mandelbrot set.",
+                parameters: &[
+                    ("z₀", "The pixel's position in the complex plane"),
+                    ("c", "Julia Constant (real, imaginary)"),
+                    ("n", "Power (2 = classic)"),
+                ],
+            },
+            FractalType::Sierpinski2D => FractalMathInfo {
+                title: "Sierpinski Carpet",
+                formula: &[
+                    "d_k(t) = ⌊3^k·t⌋ mod 3",
+                    "removed if d_k(x) = d_k(y) = 1 for some k",
+                ],
+                description: "Divide the unit square into a 3 × 3 grid, remove the center square and repeat on the 8 squares left. A point is removed when, at some level, the base-3 digits of both coordinates are 1. Its dimension is log 8 / log 3 ≈ 1.893.",
+                parameters: &[
+                    ("x, y", "The pixel's position, mapped to the unit square"),
+                    ("k", "Subdivision level, up to Max Iterations"),
+                ],
+            },
+            FractalType::SierpinskiTriangle2D => FractalMathInfo {
+                title: "Sierpinski Triangle",
+                formula: &[
+                    "(u, v, w): barycentric coordinates, u + v + w = 1",
+                    "hole if u, v, w < ½",
+                    "else the weight t ≥ ½ → 2t − 1, the others → 2t",
+                ],
+                description: "Remove the middle triangle joining the edge midpoints and repeat on the three corner triangles. Each step zooms into the corner sub-triangle containing the point; holes are colored by the level at which they are found. Its dimension is log 3 / log 2 ≈ 1.585.",
+                parameters: &[
+                    ("u, v, w", "Weights of the three corners at the pixel's position"),
+                    ("k", "Subdivision level, up to Max Iterations"),
+                ],
+            },
+            FractalType::BurningShip2D => FractalMathInfo {
+                title: "Burning Ship",
+                formula: &[
+                    "z₀ = 0",
+                    "z → (|Re z| + i·|Im z|)^n + c",
+                    "escape when |z| > 4",
+                ],
+                description: "The Mandelbrot iteration with the absolute value of both parts of z taken before each power. Folding z into one quadrant breaks the symmetry and gives the flame-like 'ship' below the real axis.",
+                parameters: &[
+                    ("c", "The pixel's position in the complex plane"),
+                    ("n", "Power (2 = classic)"),
+                ],
+            },
+            FractalType::Tricorn2D => FractalMathInfo {
+                title: "Tricorn (Mandelbar)",
+                formula: &["z₀ = 0", "z → conj(z)^n + c", "escape when |z| > 4"],
+                description: "The Mandelbrot iteration on the complex conjugate conj(z) = Re z − i·Im z. Conjugating makes the map anti-holomorphic; for n = 2 the set has three-fold symmetry and three 'horns'.",
+                parameters: &[
+                    ("c", "The pixel's position in the complex plane"),
+                    ("n", "Power (2 = classic)"),
+                ],
+            },
+            FractalType::Phoenix2D => FractalMathInfo {
+                title: "Phoenix",
+                formula: &[
+                    "z₀ = z_prev = 0",
+                    "z → z^n + c + p·z_prev",
+                    "escape when |z| > 4",
+                ],
+                description: "A second-order iteration: each step also adds a multiple of the previous value, giving the map a memory. p = 0 gives back the Mandelbrot set; small values twist it into feathery shapes.",
+                parameters: &[
+                    ("c", "The pixel's position in the complex plane"),
+                    ("p", "Phoenix Distortion (real, imaginary)"),
+                    ("n", "Power (2 = classic)"),
+                ],
+            },
+            FractalType::Celtic2D => FractalMathInfo {
+                title: "Celtic",
+                formula: &[
+                    "z₀ = 0",
+                    "w = z^n",
+                    "z → |Re w| + i·Im w + c",
+                    "escape when |z| > 4",
+                ],
+                description: "The Mandelbrot iteration with the absolute value of the real part taken after the power. The fold gives knot-like, interlaced patterns.",
+                parameters: &[
+                    ("c", "The pixel's position in the complex plane"),
+                    ("n", "Power (2 = classic)"),
+                ],
+            },
+            FractalType::Newton2D => FractalMathInfo {
+                title: "Newton Fractal",
+                formula: &[
+                    "p(z) = (z − r₁)(z − r₂)…(z − r_m)",
+                    "z₀ = pixel position",
+                    "z → z − p(z) / p′(z)",
+                    "p′(z) / p(z) = Σ 1 / (z − r_j)",
+                ],
+                description: "Newton's method for the roots of a polynomial, started from every point of the plane. Each point is colored by the root its iteration converges to and how many steps it takes; the basin boundaries between roots are fractal.",
+                parameters: &[
+                    ("r_j", "Polynomial Roots (drag to reshape, default z^m − 1)"),
+                    ("m", "Degree: number of roots"),
+                ],
+            },
+            FractalType::Lyapunov2D => FractalMathInfo {
+                title: "Lyapunov Fractal",
+                formula: &[
+                    "x₀ = 0.5",
+                    "x → r·x·(1 − x)",
+                    "r cycles through a, a, a, a, a, a, b, b, b, b, b, b",
+                    "λ = (1/N)·Σ ln |r·(1 − 2x)|",
+                ],
+                description: "The Lyapunov exponent λ of the logistic population map whose growth rate alternates between a and b. λ < 0 means the population settles into a stable cycle; λ > 0 means chaos. Stable and chaotic regions are colored on opposite halves of the palette.",
+                parameters: &[
+                    ("a, b", "Growth rates from the pixel's x and y, in [0, 4]"),
+                    ("N", "Max Iterations"),
+                ],
+            },
+            FractalType::Nova2D => FractalMathInfo {
+                title: "Nova Fractal",
+                formula: &[
+                    "f(z) = z³ − 1",
+                    "z₀ = pixel position",
+                    "z → z − R·f(z) / f′(z) + c",
+                    "escape when |z| > 2",
+                ],
+                description: "A Newton-Mandelbrot hybrid: a relaxed Newton step for the cube roots of unity followed by adding a constant. The added constant lets orbits escape, so the Newton basins grow Mandelbrot-like filaments.",
+                parameters: &[
+                    ("R", "Relaxation, fixed at 1"),
+                    ("c", "Julia constant (real, imaginary)"),
+                ],
+            },
+            FractalType::Magnet2D => FractalMathInfo {
+                title: "Magnet Type 1",
+                formula: &[
+                    "z₀ = 0",
+                    "z → ((z² + c − 1) / (2z + c − 2))²",
+                    "escape when |z| > 100",
+                ],
+                description: "From the renormalization of magnetic phase transitions in the Ising model. Orbits either escape or converge to the fixed point z = 1; converged points are drawn as the inside.",
+                parameters: &[("c", "The pixel's position in the complex plane")],
+            },
+            FractalType::Collatz2D => FractalMathInfo {
+                title: "Collatz Fractal",
+                formula: &[
+                    "z₀ = pixel position",
+                    "z → ¼·((1 + 2m)z + 2d − ((2m − 1)z + 2d)·cos(πz))",
+                    "escape when |z| > 100",
+                ],
+                description: "The Collatz map (n/2 for even n, mn + d for odd n) extended to the complex plane by blending the two branches with cos(πz). On the integers it reproduces the original map; m = 3, d = 1 is the classic 3n + 1 problem.",
+                parameters: &[
+                    ("m", "Multiplier (m) of the odd branch"),
+                    ("d", "Offset (d) of the odd branch"),
+                ],
+            },
+            FractalType::Mandelbulb3D => FractalMathInfo {
+                title: "Mandelbulb",
+                formula: &[
+                    "(r, θ, φ) = spherical coordinates of z",
+                    "z^n = r^n·(sin nθ·cos nφ, sin nθ·sin nφ, cos nθ)",
+                    "z → z^n + c",
+                    "dr → n·r^(n−1)·dr + 1",
+                    "DE = ½·ln r · r / dr",
+                ],
+                description: "A 3D analogue of the Mandelbrot set: the power raises the radius and multiplies both angles, since 3D points can't be multiplied like complex numbers. Rendered by ray marching the distance estimate DE, built from the running derivative dr.",
+                parameters: &[
+                    ("c", "The point being tested, divided by Scale"),
+                    ("n", "Power (8 = classic)"),
+                ],
+            },
+            FractalType::MengerSponge3D => FractalMathInfo {
+                title: "Menger Sponge",
+                formula: &[
+                    "p → |p|, sorted so x ≥ y ≥ z",
+                    "p → 3p − 2o",
+                    "if z < −o_z: z → z + 2o_z",
+                    "DE = (max(|x|, |y|, |z|) − 1) / 3^N",
+                ],
+                description: "Divide a cube into 27 and remove the center cube and the six face centers, then repeat on the 20 cubes left. The folds map every point into one sub-cube so the whole sponge is built from one box. Its dimension is log 20 / log 3 ≈ 2.727.",
+                parameters: &[
+                    ("o", "IFS Offset (1, 1, 1 = classic)"),
+                    ("N", "Iterations: recursion depth"),
+                ],
+            },
+            FractalType::SierpinskiPyramid3D => FractalMathInfo {
+                title: "Sierpinski Pyramid",
+                formula: &[
+                    "if x + y < 0: (x, y) → (−y, −x), same for x + z, y + z",
+                    "p → 2p − o",
+                    "DE = (|p| − 1) / (s·2^N)",
+                ],
+                description: "The 3D Sierpinski tetrahedron: four half-size copies of a tetrahedron at its corners, repeated. The three reflections fold space onto one corner, then scaling by 2 zooms into it.",
+                parameters: &[
+                    ("o", "IFS Offset (1, 1, 1 = classic)"),
+                    ("s", "Scale"),
+                    ("N", "Iterations: recursion depth"),
+                ],
+            },
+            FractalType::JuliaSet3D => FractalMathInfo {
+                title: "Quaternion Julia Set",
+                formula: &[
+                    "q₀ = (x, y, z, 0)",
+                    "q → q² + c",
+                    "dq → 2·q·dq",
+                    "DE = ½·|q|·ln |q| / |dq|",
+                ],
+                description: "A Julia set in the four-dimensional quaternions, sliced by the 3D space w = 0. Quaternion squaring extends complex squaring, so each slice through the complex plane is a 2D Julia set.",
+                parameters: &[
+                    ("c", "Julia Constant as (re, im, 0, 0.2)"),
+                    ("x, y, z", "The point being tested, divided by Scale"),
+                ],
+            },
+            FractalType::Mandelbox3D => FractalMathInfo {
+                title: "Mandelbox",
+                formula: &[
+                    "box fold: p → clamp(p, −f, f)·2 − p",
+                    "sphere fold: p → p / r_min² if r² < r_min², p / r² if r² < 1",
+                    "p → s·p + c",
+                    "DE = |p| / |dr|",
+                ],
+                description: "Each step reflects points outside a box back inside, inverts points near the origin through a sphere, then scales and adds the starting point. Negative scales give the classic boxy, self-similar architecture.",
+                parameters: &[
+                    ("f", "Fold: box fold limit"),
+                    ("r_min", "Min Radius of the sphere fold"),
+                    ("s", "Scale factor −Power / 4 (−2 by default)"),
+                    ("c", "The point being tested"),
+                ],
+            },
+            FractalType::OctahedralIFS3D => FractalMathInfo {
+                title: "Octahedral IFS",
+                formula: &[
+                    "p → |p|, sorted so x ≥ y ≥ z",
+                    "p → s·p − o·(s − 1)",
+                    "DE = (|p| − 2) / s^N",
+                ],
+                description: "A kaleidoscopic IFS: folding by the mirror planes of the octahedron maps space into one of its 48 symmetric wedges, then scaling about the offset point adds detail at every level.",
+                parameters: &[
+                    ("s", "Fold + 1: scale factor"),
+                    ("o", "IFS Offset (1, 1, 1 = symmetric)"),
+                ],
+            },
+            FractalType::IcosahedralIFS3D => FractalMathInfo {
+                title: "Icosahedral IFS",
+                formula: &[
+                    "p → p − 2·min(p·n, 0)·n for the icosahedral planes n",
+                    "p → |p|, sorted so x ≥ y ≥ z",
+                    "p → s·p − o·(s − 1)",
+                    "DE = (|p| − 2) / s^N",
+                ],
+                description: "A kaleidoscopic IFS with the symmetry of the icosahedron. The mirror plane normals are built from the golden ratio φ = (1 + √5) / 2.",
+                parameters: &[
+                    ("s", "Fold + 1: scale factor"),
+                    ("o", "IFS Offset (1, 1, 1 = symmetric)"),
+                ],
+            },
+            FractalType::ApollonianGasket3D => FractalMathInfo {
+                title: "Apollonian Gasket",
+                formula: &[
+                    "p → |p|",
+                    "p → p / k² if r² < k², p / r² if r² < 1",
+                    "sort so x ≥ y ≥ z",
+                    "p → s·p − (k, k, k)",
+                    "DE = (|p| − 1) / |dr|",
+                ],
+                description: "A sphere packing: repeated inversions through a sphere fill the gaps between spheres with ever smaller spheres, as in Apollonius' problem of circles tangent to three others.",
+                parameters: &[
+                    ("k", "Min Radius: inversion radius and offset"),
+                    ("s", "(1 + k)·Fold: scale factor"),
+                ],
+            },
+            FractalType::Kleinian3D => FractalMathInfo {
+                title: "Pseudo-Kleinian",
+                formula: &[
+                    "p → |p|, sorted so x ≥ y ≥ z",
+                    "p → s·p − (1, 1, 1.3)·(s − 1)",
+                    "p → p·clamp(1 / r², 1, 3) + (0, 0, −r_min)",
+                    "DE = (|p| − 0.4) / |dr|",
+                ],
+                description: "Inspired by Kleinian groups, whose limit sets are built from repeated sphere inversions. Folds, scaling and a clamped inversion give cave-like, organic surfaces.",
+                parameters: &[
+                    ("s", "Fold + 1: scale factor"),
+                    ("r_min", "Min Radius: translation along z"),
+                ],
+            },
+            FractalType::HybridMandelbulbJulia3D => FractalMathInfo {
+                title: "Hybrid Mandelbulb-Julia",
+                formula: &[
+                    "z^n: the Mandelbulb power in spherical coordinates",
+                    "even steps: z → z^n + p",
+                    "odd steps: z → z^n + c",
+                    "DE = ½·ln r · r / |dz|",
+                ],
+                description: "Alternates Mandelbulb steps, which add the point being tested, with Julia steps, which add a fixed constant. Mixing the two formulas gives shapes neither produces alone.",
+                parameters: &[
+                    ("p", "The point being tested, divided by Scale"),
+                    ("c", "Julia constant as (re, im, 0.3)"),
+                    ("n", "Power"),
+                ],
+            },
+            FractalType::QuaternionCubic3D => FractalMathInfo {
+                title: "Quaternion Cubic Julia",
+                formula: &[
+                    "q₀ = (x, y, z, 0)",
+                    "q → q³ + c",
+                    "dq → 3·q²·dq",
+                    "DE = ½·|q|·ln |q| / |dq|",
+                ],
+                description: "A quaternion Julia set for the cubic map. The third power gives three-fold rather than two-fold symmetry around the real axis.",
+                parameters: &[
+                    ("c", "Julia constant as (re, im, 0.3, 0)"),
+                    ("x, y, z", "The point being tested, divided by Scale"),
+                ],
+            },
+            FractalType::SierpinskiGasket3D => FractalMathInfo {
+                title: "Sierpinski Gasket",
+                formula: &[
+                    "tetrahedral folds, as for the Sierpinski Pyramid",
+                    "p → p·r_min² / r² if r² < r_min²",
+                    "p → s·p − o·(s − 1)",
+                    "DE = (|p| − 1) / |dr|",
+                ],
+                description: "The Sierpinski tetrahedron with an added sphere inversion near the origin, which carves round holes into the gasket.",
+                parameters: &[
+                    ("s", "2 + Fold / 2: scale factor"),
+                    ("r_min", "Min Radius of the inversion"),
+                    ("o", "IFS Offset"),
+                ],
+            },
+            FractalType::Buddhabrot2D => FractalMathInfo {
+                title: "Buddhabrot",
+                formula: &[
+                    "z₀ = 0",
+                    "z → z² + c for random c",
+                    "if the orbit escapes |z| > 2, count a hit at every z",
+                ],
+                description: "A density image of the paths that escaping Mandelbrot orbits take, discovered by Melinda Green in 1993. Only orbits longer than a minimum length are plotted; the hit counts are log-scaled to brightness. The image accumulates over many frames.",
+                parameters: &[
+                    ("c", "Random sample points"),
+                    ("N", "Max Iterations; orbits shorter than N / 10 (at least 20) are skipped"),
+                ],
+            },
+            FractalType::Hopalong2D => FractalMathInfo {
+                title: "Hopalong Attractor",
+                formula: &["x′ = y − sign(x)·√|b·x − c|", "y′ = a − x"],
+                description: "Barry Martin's map from Scientific American (1986). A single orbit hops around the plane; plotting the density of its visits reveals intricate webs.",
+                parameters: &[("a", "Parameter a"), ("b", "Parameter b"), ("c", "Parameter c")],
+            },
+            FractalType::Martin2D => FractalMathInfo {
+                title: "Martin Attractor",
+                formula: &["x′ = y − sin(x)", "y′ = a − x"],
+                description: "A Hopalong variant with a sine in place of the square root. a = π gives the classic flower-like pattern.",
+                parameters: &[("a", "Parameter a")],
+            },
+            FractalType::Gingerbreadman2D => FractalMathInfo {
+                title: "Gingerbreadman Map",
+                formula: &["x′ = 1 − y + |x|", "y′ = x"],
+                description: "A piecewise-linear map with no parameters that is chaotic in some regions and stable in others, drawing the shape of a gingerbread man.",
+                parameters: &[],
+            },
+            FractalType::Chip2D => FractalMathInfo {
+                title: "Chip Attractor",
+                formula: &[
+                    "x′ = y − sign(x)·cos(ln²|b·x − c|)·atan(ln²|c·x − b|)",
+                    "y′ = a − x",
+                ],
+                description: "A Hopalong variant from Michael Peters' HOP program, replacing the square root with logarithms, a cosine and an arctangent.",
+                parameters: &[("a", "Parameter a"), ("b", "Parameter b"), ("c", "Parameter c")],
+            },
+            FractalType::Quadruptwo2D => FractalMathInfo {
+                title: "Quadruptwo Attractor",
+                formula: &[
+                    "x′ = y − sign(x)·sin(ln|b·x − c|)·atan((c·x − b)²)",
+                    "y′ = a − x",
+                ],
+                description: "A Hopalong variant from Michael Peters' HOP program that produces four-fold, clover-like patterns.",
+                parameters: &[("a", "Parameter a"), ("b", "Parameter b"), ("c", "Parameter c")],
+            },
+            FractalType::Threeply2D => FractalMathInfo {
+                title: "Threeply Attractor",
+                formula: &[
+                    "x′ = y − sign(x)·|sin(x)·cos(b) + c − x·sin(a + b + c)|",
+                    "y′ = a − x",
+                ],
+                description: "A Hopalong variant from Michael Peters' HOP program that produces three-fold, layered patterns.",
+                parameters: &[("a", "Parameter a"), ("b", "Parameter b"), ("c", "Parameter c")],
+            },
+            FractalType::Pickover3D => FractalMathInfo {
+                title: "Pickover Attractor",
+                formula: &[
+                    "x′ = sin(a·y) − z·cos(b·x)",
+                    "y′ = z·sin(c·x) − cos(d·y)",
+                    "z′ = sin(x)",
+                ],
+                description: "Clifford Pickover's 3D map. Many orbits are iterated on the GPU and their points projected through the camera and accumulated into a density image.",
+                parameters: &[("a, b", "Parameters a and b"), ("c", "Parameter c"), ("d", "Parameter d")],
+            },
+            FractalType::Lorenz3D => FractalMathInfo {
+                title: "Lorenz Attractor",
+                formula: &["dx/dt = σ·(y − x)", "dy/dt = x·(ρ − z) − y", "dz/dt = x·y − β·z"],
+                description: "Edward Lorenz's 1963 model of atmospheric convection, the classic example of deterministic chaos: nearby orbits diverge exponentially yet stay on the butterfly-shaped attractor. Integrated with 4th order Runge-Kutta steps of dt = 0.005.",
+                parameters: &[
+                    ("σ", "Sigma: Prandtl number (classic 10)"),
+                    ("ρ", "Rho: Rayleigh number (classic 28)"),
+                    ("β", "Beta: geometric factor (classic 8/3)"),
+                ],
+            },
+            FractalType::Rossler3D => FractalMathInfo {
+                title: "Rössler Attractor",
+                formula: &["dx/dt = −y − z", "dy/dt = x + a·y", "dz/dt = b + z·(x − c)"],
+                description: "Otto Rössler's 1976 system, designed as the simplest continuous flow with chaos: orbits spiral outward in the plane and are folded back by occasional spikes in z. Integrated with 4th order Runge-Kutta steps of dt = 0.01.",
+                parameters: &[
+                    ("a", "Parameter a (classic 0.2)"),
+                    ("b", "Parameter b (classic 0.2)"),
+                    ("c", "Parameter c (classic 5.7)"),
+                ],
+            },
+        }
+    }
+}
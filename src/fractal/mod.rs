@@ -1,14 +1,41 @@
 // Module declarations
+mod coloring_memory;
+mod composite;
+mod filename_template;
+mod final_render;
+mod limits;
+mod math_info;
+mod newton;
 mod palettes;
+mod preset_import;
 mod presets;
+mod profile;
+mod recipe;
+mod safe_mode;
+mod sections;
 mod settings;
+mod settings_diff;
+mod sweep;
 mod types;
 mod ui_state;
 
 // Re-exports
+pub use coloring_memory::*;
+pub use composite::*;
+pub use filename_template::*;
+pub use final_render::*;
+pub use limits::*;
+pub use math_info::*;
+pub use newton::*;
 pub use palettes::*;
+pub use preset_import::*;
 pub use presets::*;
+pub use profile::*;
+pub use safe_mode::*;
+pub use sections::*;
 pub use settings::*;
+pub use settings_diff::*;
+pub use sweep::*;
 pub use types::*;
 pub use ui_state::*;
 
@@ -16,6 +43,35 @@ use glam::Vec3;
 
 use crate::lod::{LODConfig, LODState};
 
+/// Classic Phoenix distortion coefficient (Ushiki's p = 0.5667)
+pub const DEFAULT_PHOENIX_P: [f32; 2] = [0.5667, 0.0];
+
+/// Classic Collatz map: odd branch 3n + 1, as (multiplier, offset)
+pub const DEFAULT_COLLATZ_PARAMS: [f32; 2] = [3.0, 1.0];
+
+/// Color modes Randomize picks from
+const RANDOM_COLOR_MODES: [ColorMode; 5] = [
+    ColorMode::Palette,
+    ColorMode::RaySteps,
+    ColorMode::Normals,
+    ColorMode::OrbitTrapXYZ,
+    ColorMode::OrbitTrapRadial,
+];
+
+/// Parameter groups that `FractalParams::randomize` leaves untouched
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RandomizeLocks {
+    pub fractal_type: bool,
+    pub palette: bool,
+    pub color_mode: bool,
+    /// 2D view center and zoom
+    pub camera: bool,
+    /// Light intensity, ambient light and material
+    pub lighting: bool,
+    /// Ambient occlusion, shadows, fog and floor
+    pub effects: bool,
+}
+
 #[derive(Clone)]
 pub struct FractalParams {
     pub fractal_type: FractalType,
@@ -26,6 +82,10 @@ pub struct FractalParams {
     pub palette_index: usize,
     pub palette_offset: f32,
     pub orbit_trap_scale: f32,
+    /// Point the orbit trap color modes measure from (2D uses x and y)
+    pub orbit_trap_offset: Vec3,
+    /// Image sampled by the Orbit Trap Image color mode (None = built-in default)
+    pub orbit_trap_image_path: Option<String>,
     pub channel_r: ChannelSource,
     pub channel_g: ChannelSource,
     pub channel_b: ChannelSource,
@@ -45,18 +105,57 @@ pub struct FractalParams {
     // 2D specific
     pub center_2d: [f64; 2],
     pub zoom_2d: f32,
+    /// Map pixels to complex coordinates in f64 on the CPU (steady panning at moderate zoom)
+    pub high_precision_2d: bool,
+    /// Re-render pixels on the fractal boundary with extra samples (second 2D pass)
+    pub edge_aa_2d: bool,
+    /// Boundary supersampling grid side: each boundary pixel takes grid² samples
+    pub edge_aa_grid: u32,
+    /// Color difference from a neighbouring pixel that marks a boundary pixel
+    pub edge_aa_threshold: f32,
+    /// Render whole periodic cells so the image tiles seamlessly (periodic 2D fractals only)
+    pub seamless_tile: bool,
+    /// Ray march 2D escape-time fractals as a height field in the 3D renderer,
+    /// with the iteration value as elevation (see `relief_active`)
+    pub relief_3d: bool,
+    /// Elevation of the relief's highest points (the interior of the set)
+    pub relief_height: f32,
+    /// Second 3D fractal composited into the scene with its own transform
+    pub secondary_fractal: SecondaryFractal,
+    /// Coloring for points that never escape (Mandelbrot-family fractals)
+    pub interior_color_mode: InteriorColorMode,
+    /// Light the Distance interior mode's field like a raised surface
+    pub interior_distance_lighting: bool,
+    /// Darken the exterior toward the boundary by distance estimate (Mandelbrot-family fractals)
+    pub distance_estimation_2d: bool,
+    /// Draw dark lines on 3D surfaces where the ray distance crosses multiples of the spacing
+    pub show_distance_contours: bool,
+    /// Ray distance between contour lines, in world units
+    pub distance_contour_spacing: f32,
     pub julia_c: [f32; 2],
+    /// Phoenix coefficient p on z(n-1) in z(n+1) = z(n)^power + c + p*z(n-1), as (re, im)
+    pub phoenix_p: [f32; 2],
+    /// Odd branch m*n + d of the continuous Collatz map, as (m, d)
+    pub collatz_params: [f32; 2],
+    /// Polynomial roots for the Newton fractal (up to NEWTON_MAX_ROOTS)
+    pub newton_roots: Vec<[f32; 2]>,
     pub max_iterations: u32,
 
     // 3D specific
     pub power: f32,
     pub max_steps: u32,
     pub min_distance: f32,
+    /// Grows the surface hit threshold with the size of a pixel at the hit
+    /// distance, 0 = fixed `min_distance`
+    pub detail_falloff: f32,
     pub ambient_occlusion: bool,
     pub ao_intensity: f32,
     pub ao_step_size: f32,
     pub shadow_mode: u32, // 0=off, 1=hard, 2=soft
+    /// Penumbra width multiplier on top of the light size (1.0 at the default of 8)
     pub shadow_softness: f32,
+    /// Angular diameter of the light in degrees, which sets the soft shadow penumbra
+    pub light_size: f32,
     pub shadow_max_distance: f32,
     pub shadow_samples: u32,
     pub shadow_step_factor: f32,
@@ -64,16 +163,25 @@ pub struct FractalParams {
     pub dof_focal_length: f32,
     pub dof_aperture: f32,
     pub dof_samples: u32,
+    /// Aperture blade count for bokeh shape: 0 = circular, 3-8 = polygon
+    pub bokeh_blades: u32,
 
     // 3D fractal parameters
     pub fractal_scale: f32,
     pub fractal_fold: f32,
     pub fractal_min_radius: f32,
+    /// Point the kaleidoscopic IFS fractals scale around after folding. The
+    /// default (1, 1, 1) gives the classic symmetric shapes.
+    pub ifs_offset: Vec3,
 
     // Material properties
     pub roughness: f32,
     pub metallic: f32,
     pub albedo: Vec3,
+    /// Tint of specular highlights in both shading models (white = uncolored)
+    pub specular_color: Vec3,
+    /// Specular highlight strength, 1 = physically based
+    pub specular_intensity: f32,
 
     // Lighting
     pub light_intensity: f32,
@@ -81,6 +189,9 @@ pub struct FractalParams {
     pub light_azimuth: f32,
     pub light_elevation: f32,
 
+    /// Which world axis points up for the camera, floor and light elevation
+    pub up_axis: UpAxis,
+
     // Floor
     pub show_floor: bool,
     pub floor_height: f32,
@@ -88,11 +199,16 @@ pub struct FractalParams {
     pub floor_color2: Vec3,
     pub floor_reflections: bool,
     pub floor_reflection_strength: f32,
+    pub floor_checker_scale: f32,    // Checker tile size in world units
+    pub floor_checker_rotation: f32, // Checker rotation around the up axis (degrees)
 
     // Fog
     pub fog_enabled: bool,
     pub fog_mode: FogMode,
     pub fog_density: f32,
+    // Distance where fog begins, and where linear fog becomes opaque
+    pub fog_start: f32,
+    pub fog_end: f32,
     pub fog_color: Vec3,
 
     // Ray marching
@@ -100,13 +216,24 @@ pub struct FractalParams {
     pub fixed_step_size: f32,
     pub step_multiplier: f32,
     pub max_distance: f32,
+    /// Ray march in double precision for high-resolution captures (requires SHADER_F64)
+    pub double_precision_3d: bool,
 
     // Camera (3D mode)
     pub camera_speed: f32,
     pub camera_fov: f32,
+    /// WASD movement eases in and coasts to a stop
+    pub camera_inertia: bool,
+    pub camera_acceleration: f32, // Per second, higher reaches full speed sooner
+    pub camera_damping: f32,      // Per second, higher stops sooner
     pub auto_orbit: bool,
     pub orbit_speed: f32,
 
+    // Stereoscopic 3D output
+    pub stereo_mode: StereoMode,
+    /// Distance between the left and right eye cameras (world units)
+    pub stereo_eye_separation: f32,
+
     // Post-processing
     // Color grading
     pub brightness: f32,
@@ -114,16 +241,35 @@ pub struct FractalParams {
     pub saturation: f32,
     pub hue_shift: f32,
 
+    // Auto-exposure (3D): adapt brightness to the measured scene luminance
+    pub auto_exposure: bool,
+    pub auto_exposure_speed: f32, // Adaptation rate per second
+
     // Vignette
     pub vignette_enabled: bool,
     pub vignette_intensity: f32,
     pub vignette_radius: f32,
 
+    // Porthole: clip the image to a feathered circle (or an oval filling the
+    // frame), with the color outside, or transparency in transparent captures
+    pub porthole_enabled: bool,
+    /// Radius relative to half the shorter side (half the frame when oval)
+    pub porthole_radius: f32,
+    pub porthole_feather: f32,
+    pub porthole_oval: bool,
+    pub porthole_color: Vec3,
+
+    // Kaleidoscope symmetry (screen-space UV fold)
+    pub symmetry_mode: SymmetryMode,
+    /// Mirrored wedges for `SymmetryMode::Radial`
+    pub symmetry_segments: u32,
+
     // Bloom
     pub bloom_enabled: bool,
     pub bloom_threshold: f32,
     pub bloom_intensity: f32,
     pub bloom_radius: f32,
+    pub bloom_quality: BloomQuality,
 
     // Anti-aliasing
     pub fxaa_enabled: bool,
@@ -141,6 +287,8 @@ pub struct FractalParams {
     pub attractor_total_iterations: u64,
     /// Log scale factor for density display
     pub attractor_log_scale: f32,
+    /// What 2D attractor pixels take their palette position from
+    pub attractor_color_mode: AttractorColorMode,
     /// Flag to clear accumulation on next frame
     pub attractor_pending_clear: bool,
     /// Flag to pause accumulation
@@ -153,6 +301,12 @@ pub struct FractalParams {
     pub attractor_last_zoom: f32,
     /// Last julia_c parameters (triggers auto-clear on change)
     pub attractor_last_julia_c: [f32; 2],
+    /// Keep accumulating through view changes while recording video
+    pub attractor_hold_while_recording: bool,
+    /// Seconds of recorded video between clears while holding (0 = never clear)
+    pub attractor_recording_clear_interval: f32,
+    /// Recorded video time of the last auto-clear, in seconds
+    pub attractor_last_clear_time: f32,
 }
 
 impl Default for FractalParams {
@@ -166,6 +320,8 @@ impl Default for FractalParams {
             palette_index: 0,
             palette_offset: 0.0,
             orbit_trap_scale: 1.0,
+            orbit_trap_offset: Vec3::ZERO,
+            orbit_trap_image_path: None,
             channel_r: ChannelSource::Iterations,
             channel_g: ChannelSource::Distance,
             channel_b: ChannelSource::PositionZ,
@@ -180,17 +336,35 @@ impl Default for FractalParams {
 
             center_2d: [0.0f64, 0.0f64],
             zoom_2d: 1.0,
+            high_precision_2d: false,
+            edge_aa_2d: false,
+            edge_aa_grid: 3,
+            edge_aa_threshold: 0.1,
+            seamless_tile: false,
+            relief_3d: false,
+            relief_height: 0.5,
+            secondary_fractal: SecondaryFractal::default(),
+            interior_color_mode: InteriorColorMode::default(),
+            interior_distance_lighting: false,
+            distance_estimation_2d: false,
+            show_distance_contours: false,
+            distance_contour_spacing: default_distance_contour_spacing(),
             julia_c: [-0.7, 0.27015],
+            phoenix_p: DEFAULT_PHOENIX_P,
+            collatz_params: DEFAULT_COLLATZ_PARAMS,
+            newton_roots: newton_unity_roots(3),
             max_iterations: 80,
 
             power: 2.0, // Default for Mandelbrot2D/Julia2D (z^2 + c)
             max_steps: 200,
             min_distance: 0.00035,
+            detail_falloff: 0.0,
             ambient_occlusion: true,
             ao_intensity: 1.0,
             ao_step_size: 0.12,
             shadow_mode: 2, // soft
             shadow_softness: 8.0,
+            light_size: 10.0,
             shadow_max_distance: 5.0,
             shadow_samples: 128,
             shadow_step_factor: 0.6,
@@ -198,56 +372,83 @@ impl Default for FractalParams {
             dof_focal_length: 6.0,
             dof_aperture: 0.01,
             dof_samples: 2,
+            bokeh_blades: 0,
 
             fractal_scale: 2.0,
             fractal_fold: 1.0,
             fractal_min_radius: 0.5,
+            ifs_offset: Vec3::ONE,
 
             roughness: 0.4,
             metallic: 0.20,
             albedo: Vec3::new(0.8, 0.8, 0.8),
+            specular_color: Vec3::ONE,
+            specular_intensity: 1.0,
 
             light_intensity: 3.0,
             ambient_light: 0.15,
             light_azimuth: 45.0,
             light_elevation: 35.0,
 
+            up_axis: UpAxis::Y,
+
             show_floor: true,
             floor_height: -2.0,
             floor_color1: Vec3::new(1.0, 1.0, 1.0), // White
             floor_color2: Vec3::new(0.0, 0.0, 0.0), // Black
             floor_reflections: false,
             floor_reflection_strength: 0.5,
+            floor_checker_scale: 1.0,
+            floor_checker_rotation: 0.0,
 
             fog_enabled: true,
             fog_mode: FogMode::Quadratic,
             fog_density: 0.005,
+            fog_start: 0.0,
+            fog_end: 200.0,
             fog_color: Vec3::new(0.0, 0.0, 0.0), // Black
 
             use_adaptive_step: true,
             fixed_step_size: 0.1,
             step_multiplier: 1.0,
             max_distance: 100.0,
+            double_precision_3d: false,
 
             camera_speed: 2.0,
             camera_fov: 45.0,
+            camera_inertia: false,
+            camera_acceleration: 8.0,
+            camera_damping: 4.0,
             auto_orbit: false,
             orbit_speed: 0.2,
 
+            stereo_mode: StereoMode::Off,
+            stereo_eye_separation: 0.1,
+
             // Post-processing defaults
             brightness: 1.0,
             contrast: 1.0,
             saturation: 1.0,
             hue_shift: 0.0,
+            auto_exposure: false,
+            auto_exposure_speed: 1.5,
 
             vignette_enabled: false,
             vignette_intensity: 0.5,
             vignette_radius: 0.8,
+            porthole_enabled: false,
+            porthole_radius: default_porthole_radius(),
+            porthole_feather: default_porthole_feather(),
+            porthole_oval: false,
+            porthole_color: Vec3::ZERO,
+            symmetry_mode: SymmetryMode::default(),
+            symmetry_segments: 6,
 
             bloom_enabled: false,
             bloom_threshold: 0.75,
             bloom_intensity: 0.1,
             bloom_radius: 0.005,
+            bloom_quality: BloomQuality::default(),
 
             fxaa_enabled: false,
 
@@ -260,12 +461,16 @@ impl Default for FractalParams {
             attractor_iterations_per_frame: 10_000,
             attractor_total_iterations: 0,
             attractor_log_scale: 4.0,
+            attractor_color_mode: AttractorColorMode::default(),
             attractor_pending_clear: false,
             attractor_paused: false,
             attractor_max_iterations: 8_000_000,
             attractor_last_center: [0.0, 0.0],
             attractor_last_zoom: 1.0,
             attractor_last_julia_c: [-0.7, 0.27015],
+            attractor_hold_while_recording: false,
+            attractor_recording_clear_interval: 0.0,
+            attractor_last_clear_time: 0.0,
         }
     }
 }
@@ -278,6 +483,8 @@ impl FractalParams {
             color_mode: self.color_mode,
             palette_index: self.palette_index,
             orbit_trap_scale: self.orbit_trap_scale,
+            orbit_trap_offset: self.orbit_trap_offset.to_array(),
+            orbit_trap_image_path: self.orbit_trap_image_path.clone(),
             channel_r: self.channel_r,
             channel_g: self.channel_g,
             channel_b: self.channel_b,
@@ -290,16 +497,34 @@ impl FractalParams {
             auto_open_captures: false,    // Will be overridden by App with UI state
             center_2d: self.center_2d,
             zoom_2d: self.zoom_2d,
+            high_precision_2d: self.high_precision_2d,
+            edge_aa_2d: self.edge_aa_2d,
+            edge_aa_grid: self.edge_aa_grid,
+            edge_aa_threshold: self.edge_aa_threshold,
+            seamless_tile: self.seamless_tile,
+            relief_3d: self.relief_3d,
+            relief_height: self.relief_height,
+            secondary_fractal: self.secondary_fractal,
+            interior_color_mode: self.interior_color_mode,
+            interior_distance_lighting: self.interior_distance_lighting,
+            distance_estimation_2d: self.distance_estimation_2d,
+            show_distance_contours: self.show_distance_contours,
+            distance_contour_spacing: self.distance_contour_spacing,
             julia_c: self.julia_c,
+            phoenix_p: self.phoenix_p,
+            collatz_params: self.collatz_params,
+            newton_roots: self.newton_roots.clone(),
             max_iterations: self.max_iterations,
             power: self.power,
             max_steps: self.max_steps,
             min_distance: self.min_distance,
+            detail_falloff: self.detail_falloff,
             ambient_occlusion: self.ambient_occlusion,
             ao_intensity: self.ao_intensity,
             ao_step_size: self.ao_step_size,
             shadow_mode: self.shadow_mode,
             shadow_softness: self.shadow_softness,
+            light_size: self.light_size,
             shadow_max_distance: self.shadow_max_distance,
             shadow_samples: self.shadow_samples,
             shadow_step_factor: self.shadow_step_factor,
@@ -307,100 +532,112 @@ impl FractalParams {
             dof_focal_length: self.dof_focal_length,
             dof_aperture: self.dof_aperture,
             dof_samples: self.dof_samples,
+            bokeh_blades: self.bokeh_blades,
             fractal_scale: self.fractal_scale,
             fractal_fold: self.fractal_fold,
             fractal_min_radius: self.fractal_min_radius,
+            ifs_offset: self.ifs_offset.to_array(),
             roughness: self.roughness,
             metallic: self.metallic,
             albedo: self.albedo.to_array(),
+            specular_color: self.specular_color.to_array(),
+            specular_intensity: self.specular_intensity,
             light_intensity: self.light_intensity,
             ambient_light: self.ambient_light,
             light_azimuth: self.light_azimuth,
             light_elevation: self.light_elevation,
+            up_axis: self.up_axis,
             show_floor: self.show_floor,
             floor_height: self.floor_height,
             floor_color1: self.floor_color1.to_array(),
             floor_color2: self.floor_color2.to_array(),
             floor_reflections: self.floor_reflections,
             floor_reflection_strength: self.floor_reflection_strength,
+            floor_checker_scale: self.floor_checker_scale,
+            floor_checker_rotation: self.floor_checker_rotation,
             fog_enabled: self.fog_enabled,
             fog_mode: self.fog_mode,
             fog_density: self.fog_density,
+            fog_start: self.fog_start,
+            fog_end: self.fog_end,
             fog_color: self.fog_color.to_array(),
             use_adaptive_step: self.use_adaptive_step,
             fixed_step_size: self.fixed_step_size,
             step_multiplier: self.step_multiplier,
             max_distance: self.max_distance,
+            double_precision_3d: self.double_precision_3d,
             camera_position: [0.0, 0.0, 3.0], // Will be overridden by App
             camera_target: [0.0, 0.0, 0.0],   // Will be overridden by App
             camera_speed: self.camera_speed,
+            camera_inertia: self.camera_inertia,
+            camera_acceleration: self.camera_acceleration,
+            camera_damping: self.camera_damping,
             camera_fov: self.camera_fov,
             auto_orbit: self.auto_orbit,
             orbit_speed: self.orbit_speed,
+            stereo_mode: self.stereo_mode,
+            stereo_eye_separation: self.stereo_eye_separation,
             brightness: self.brightness,
             contrast: self.contrast,
             saturation: self.saturation,
             hue_shift: self.hue_shift,
+            auto_exposure: self.auto_exposure,
+            auto_exposure_speed: self.auto_exposure_speed,
             vignette_enabled: self.vignette_enabled,
             vignette_intensity: self.vignette_intensity,
             vignette_radius: self.vignette_radius,
+            porthole_enabled: self.porthole_enabled,
+            porthole_radius: self.porthole_radius,
+            porthole_feather: self.porthole_feather,
+            porthole_oval: self.porthole_oval,
+            porthole_color: self.porthole_color.to_array(),
+            symmetry_mode: self.symmetry_mode,
+            symmetry_segments: self.symmetry_segments,
             bloom_enabled: self.bloom_enabled,
             bloom_threshold: self.bloom_threshold,
             bloom_intensity: self.bloom_intensity,
             bloom_radius: self.bloom_radius,
+            bloom_quality: self.bloom_quality,
             fxaa_enabled: self.fxaa_enabled,
             lod_config: self.lod_config.clone(),
             custom_width: default_custom_width(),
             custom_height: default_custom_height(),
+            lock_capture_aspect: false, // Will be overridden by App with UI state
+            capture_aspect: default_capture_aspect(),
+            capture_antialiasing: CaptureAntialiasing::default(), // Will be overridden by App with UI state
+            accumulation_samples: default_accumulation_samples(),
+            transparent_background: false, // Will be overridden by App with UI state
+            capture_16bit: false,          // Will be overridden by App with UI state
+            capture_gamma: default_capture_gamma(), // Will be overridden by App with UI state
             attractor_accumulation_enabled: self.attractor_accumulation_enabled,
             attractor_iterations_per_frame: self.attractor_iterations_per_frame,
             attractor_log_scale: self.attractor_log_scale,
+            attractor_color_mode: self.attractor_color_mode,
+            attractor_hold_while_recording: self.attractor_hold_while_recording,
+            attractor_recording_clear_interval: self.attractor_recording_clear_interval,
         }
     }
 
+    /// Convert settings without reporting adjustments; the app always uses
+    /// `from_settings_checked` so clamped values are shown
+    #[cfg(test)]
     pub fn from_settings(settings: Settings) -> Self {
+        Self::from_settings_checked(settings).0
+    }
+
+    /// Convert settings to parameters, clamping values that are out of range for
+    /// the loaded fractal type. Also returns a message for each adjusted value.
+    pub fn from_settings_checked(settings: Settings) -> (Self, Vec<String>) {
         let palette_index = settings.palette_index.min(ColorPalette::ALL.len() - 1);
         let palette = ColorPalette::ALL[palette_index];
 
-        let render_mode = match settings.fractal_type {
-            FractalType::Mandelbrot2D
-            | FractalType::Julia2D
-            | FractalType::Sierpinski2D
-            | FractalType::SierpinskiTriangle2D
-            | FractalType::BurningShip2D
-            | FractalType::Tricorn2D
-            | FractalType::Phoenix2D
-            | FractalType::Celtic2D
-            | FractalType::Newton2D
-            | FractalType::Lyapunov2D
-            | FractalType::Nova2D
-            | FractalType::Magnet2D
-            | FractalType::Collatz2D
-            | FractalType::Hopalong2D
-            | FractalType::Martin2D
-            | FractalType::Gingerbreadman2D
-            | FractalType::Chip2D
-            | FractalType::Quadruptwo2D
-            | FractalType::Threeply2D
-            | FractalType::Buddhabrot2D => RenderMode::TwoD,
-            FractalType::Mandelbulb3D
-            | FractalType::MengerSponge3D
-            | FractalType::SierpinskiPyramid3D
-            | FractalType::JuliaSet3D
-            | FractalType::Mandelbox3D
-            | FractalType::OctahedralIFS3D
-            | FractalType::IcosahedralIFS3D
-            | FractalType::ApollonianGasket3D
-            | FractalType::Kleinian3D
-            | FractalType::HybridMandelbulbJulia3D
-            | FractalType::QuaternionCubic3D
-            | FractalType::SierpinskiGasket3D
-            | FractalType::Pickover3D
-            | FractalType::Lorenz3D
-            | FractalType::Rossler3D => RenderMode::ThreeD,
+        let render_mode = if settings.relief_3d && settings.fractal_type.supports_relief() {
+            RenderMode::ThreeD
+        } else {
+            settings.fractal_type.render_mode()
         };
 
-        Self {
+        let mut params = Self {
             fractal_type: settings.fractal_type,
             render_mode,
             shading_model: settings.shading_model,
@@ -409,6 +646,8 @@ impl FractalParams {
             palette_index,
             palette_offset: 0.0,
             orbit_trap_scale: settings.orbit_trap_scale,
+            orbit_trap_offset: Vec3::from_array(settings.orbit_trap_offset),
+            orbit_trap_image_path: settings.orbit_trap_image_path,
             channel_r: settings.channel_r,
             channel_g: settings.channel_g,
             channel_b: settings.channel_b,
@@ -419,16 +658,34 @@ impl FractalParams {
             procedural_phase: settings.procedural_phase,
             center_2d: settings.center_2d,
             zoom_2d: settings.zoom_2d,
+            high_precision_2d: settings.high_precision_2d,
+            edge_aa_2d: settings.edge_aa_2d,
+            edge_aa_grid: settings.edge_aa_grid.clamp(2, 4),
+            edge_aa_threshold: settings.edge_aa_threshold,
+            seamless_tile: settings.seamless_tile,
+            relief_3d: settings.relief_3d,
+            relief_height: settings.relief_height,
+            secondary_fractal: settings.secondary_fractal,
+            interior_color_mode: settings.interior_color_mode,
+            interior_distance_lighting: settings.interior_distance_lighting,
+            distance_estimation_2d: settings.distance_estimation_2d,
+            show_distance_contours: settings.show_distance_contours,
+            distance_contour_spacing: settings.distance_contour_spacing,
             julia_c: settings.julia_c,
+            phoenix_p: settings.phoenix_p,
+            collatz_params: settings.collatz_params,
+            newton_roots: settings.newton_roots,
             max_iterations: settings.max_iterations,
             power: settings.power,
             max_steps: settings.max_steps,
             min_distance: settings.min_distance,
+            detail_falloff: settings.detail_falloff,
             ambient_occlusion: settings.ambient_occlusion,
             ao_intensity: settings.ao_intensity,
             ao_step_size: settings.ao_step_size,
             shadow_mode: settings.shadow_mode,
             shadow_softness: settings.shadow_softness,
+            light_size: settings.light_size,
             shadow_max_distance: settings.shadow_max_distance,
             shadow_samples: settings.shadow_samples,
             shadow_step_factor: settings.shadow_step_factor,
@@ -436,45 +693,76 @@ impl FractalParams {
             dof_focal_length: settings.dof_focal_length,
             dof_aperture: settings.dof_aperture,
             dof_samples: settings.dof_samples,
+            bokeh_blades: settings.bokeh_blades,
             fractal_scale: settings.fractal_scale,
             fractal_fold: settings.fractal_fold,
             fractal_min_radius: settings.fractal_min_radius,
+            ifs_offset: Vec3::from_array(settings.ifs_offset),
             roughness: settings.roughness,
             metallic: settings.metallic,
             albedo: Vec3::from_array(settings.albedo),
+            specular_color: Vec3::from_array(settings.specular_color),
+            specular_intensity: settings.specular_intensity,
             light_intensity: settings.light_intensity,
             ambient_light: settings.ambient_light,
             light_azimuth: settings.light_azimuth,
             light_elevation: settings.light_elevation,
+            up_axis: settings.up_axis,
             show_floor: settings.show_floor,
             floor_height: settings.floor_height,
             floor_color1: Vec3::from_array(settings.floor_color1),
             floor_color2: Vec3::from_array(settings.floor_color2),
             floor_reflections: settings.floor_reflections,
             floor_reflection_strength: settings.floor_reflection_strength,
+            floor_checker_scale: settings.floor_checker_scale,
+            floor_checker_rotation: settings.floor_checker_rotation,
             fog_enabled: settings.fog_enabled,
             fog_mode: settings.fog_mode,
             fog_density: settings.fog_density,
+            fog_start: settings.fog_start,
+            // Settings from before the end distance existed: linear fog used to
+            // be opaque at 1 / density
+            fog_end: if settings.fog_end > settings.fog_start {
+                settings.fog_end
+            } else {
+                settings.fog_start + 1.0 / settings.fog_density.max(1e-4)
+            },
             fog_color: Vec3::from_array(settings.fog_color),
             use_adaptive_step: settings.use_adaptive_step,
             fixed_step_size: settings.fixed_step_size,
             step_multiplier: settings.step_multiplier,
             max_distance: settings.max_distance,
+            double_precision_3d: settings.double_precision_3d,
             camera_speed: settings.camera_speed,
+            camera_inertia: settings.camera_inertia,
+            camera_acceleration: settings.camera_acceleration,
+            camera_damping: settings.camera_damping,
             camera_fov: settings.camera_fov,
             auto_orbit: settings.auto_orbit,
             orbit_speed: settings.orbit_speed,
+            stereo_mode: settings.stereo_mode,
+            stereo_eye_separation: settings.stereo_eye_separation,
             brightness: settings.brightness,
             contrast: settings.contrast,
             saturation: settings.saturation,
             hue_shift: settings.hue_shift,
+            auto_exposure: settings.auto_exposure,
+            auto_exposure_speed: settings.auto_exposure_speed,
             vignette_enabled: settings.vignette_enabled,
             vignette_intensity: settings.vignette_intensity,
             vignette_radius: settings.vignette_radius,
+            porthole_enabled: settings.porthole_enabled,
+            porthole_radius: settings.porthole_radius,
+            porthole_feather: settings.porthole_feather,
+            porthole_oval: settings.porthole_oval,
+            porthole_color: Vec3::from_array(settings.porthole_color),
+            symmetry_mode: settings.symmetry_mode,
+            symmetry_segments: settings.symmetry_segments,
             bloom_enabled: settings.bloom_enabled,
             bloom_threshold: settings.bloom_threshold,
             bloom_intensity: settings.bloom_intensity,
             bloom_radius: settings.bloom_radius,
+            bloom_quality: settings.bloom_quality,
             fxaa_enabled: settings.fxaa_enabled,
             lod_config: settings.lod_config,
             lod_state: LODState::default(),
@@ -482,37 +770,35 @@ impl FractalParams {
             attractor_iterations_per_frame: settings.attractor_iterations_per_frame,
             attractor_total_iterations: 0, // Always reset on load
             attractor_log_scale: settings.attractor_log_scale,
+            attractor_color_mode: settings.attractor_color_mode,
             attractor_pending_clear: false,
             attractor_paused: false,
             attractor_max_iterations: 8_000_000,
             attractor_last_center: settings.center_2d,
             attractor_last_zoom: settings.zoom_2d,
             attractor_last_julia_c: settings.julia_c,
-        }
-    }
-
-    #[cfg(not(target_arch = "wasm32"))]
-    pub fn save_to_file(&self) -> Result<(), Box<dyn std::error::Error>> {
-        if let Some(config_dir) = directories::ProjectDirs::from("com", "fractal", "par-fractal") {
-            let config_path = config_dir.config_dir();
-            std::fs::create_dir_all(config_path)?;
-
-            let settings_file = config_path.join("settings.yaml");
-            let settings = self.to_settings();
-            let yaml = serde_yaml::to_string(&settings)?;
-            std::fs::write(settings_file, yaml)?;
-
-            println!("Settings saved");
-            Ok(())
-        } else {
-            Err("Could not determine config directory".into())
-        }
+            attractor_hold_while_recording: settings.attractor_hold_while_recording,
+            attractor_recording_clear_interval: settings.attractor_recording_clear_interval,
+            attractor_last_clear_time: 0.0,
+        };
+        let adjustments = params.apply_fractal_limits();
+        (params, adjustments)
     }
 
-    #[cfg(target_arch = "wasm32")]
-    pub fn save_to_file(&self) -> Result<(), Box<dyn std::error::Error>> {
-        // Settings persistence not yet implemented for web
-        Ok(())
+    /// Re-apply `settings` while keeping the current view: the 2D center and
+    /// zoom, field of view, camera speed and up axis stay as they are. Returns
+    /// a message for each value clamped on load.
+    pub fn restore_settings_keep_camera(&mut self, settings: Settings) -> Vec<String> {
+        let (mut params, adjustments) = Self::from_settings_checked(settings);
+        params.center_2d = self.center_2d;
+        params.zoom_2d = self.zoom_2d;
+        params.attractor_last_center = self.center_2d;
+        params.attractor_last_zoom = self.zoom_2d;
+        params.camera_fov = self.camera_fov;
+        params.camera_speed = self.camera_speed;
+        params.up_axis = self.up_axis;
+        *self = params;
+        adjustments
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -523,7 +809,11 @@ impl FractalParams {
             if let Ok(yaml) = std::fs::read_to_string(settings_file) {
                 if let Ok(settings) = serde_yaml::from_str::<Settings>(&yaml) {
                     println!("Settings loaded");
-                    return Some(Self::from_settings(settings));
+                    let (params, adjustments) = Self::from_settings_checked(settings);
+                    for adjustment in adjustments {
+                        eprintln!("Saved settings adjusted: {}", adjustment);
+                    }
+                    return Some(params);
                 }
             }
         }
@@ -536,45 +826,29 @@ impl FractalParams {
         None
     }
 
+    /// Whether the 2D fractal is shown as a 3D relief: it then renders in the
+    /// 3D pipeline, with the 3D camera, lighting and shading
+    pub fn relief_active(&self) -> bool {
+        self.relief_3d && self.fractal_type.supports_relief()
+    }
+
+    /// Turn the 3D relief on or off, switching the render mode to match
+    pub fn set_relief(&mut self, enabled: bool) {
+        self.relief_3d = enabled;
+        self.render_mode = if self.relief_active() {
+            RenderMode::ThreeD
+        } else {
+            self.fractal_type.render_mode()
+        };
+    }
+
     pub fn switch_fractal(&mut self, fractal_type: FractalType) {
         self.fractal_type = fractal_type;
-        self.render_mode = match fractal_type {
-            FractalType::Mandelbrot2D
-            | FractalType::Julia2D
-            | FractalType::Sierpinski2D
-            | FractalType::SierpinskiTriangle2D
-            | FractalType::BurningShip2D
-            | FractalType::Tricorn2D
-            | FractalType::Phoenix2D
-            | FractalType::Celtic2D
-            | FractalType::Newton2D
-            | FractalType::Lyapunov2D
-            | FractalType::Nova2D
-            | FractalType::Magnet2D
-            | FractalType::Collatz2D
-            | FractalType::Hopalong2D
-            | FractalType::Martin2D
-            | FractalType::Gingerbreadman2D
-            | FractalType::Chip2D
-            | FractalType::Quadruptwo2D
-            | FractalType::Threeply2D
-            | FractalType::Buddhabrot2D => RenderMode::TwoD,
-            FractalType::Mandelbulb3D
-            | FractalType::MengerSponge3D
-            | FractalType::SierpinskiPyramid3D
-            | FractalType::JuliaSet3D
-            | FractalType::Mandelbox3D
-            | FractalType::OctahedralIFS3D
-            | FractalType::IcosahedralIFS3D
-            | FractalType::ApollonianGasket3D
-            | FractalType::Kleinian3D
-            | FractalType::HybridMandelbulbJulia3D
-            | FractalType::QuaternionCubic3D
-            | FractalType::SierpinskiGasket3D
-            | FractalType::Pickover3D
-            | FractalType::Lorenz3D
-            | FractalType::Rossler3D => RenderMode::ThreeD,
-        };
+        self.set_relief(self.relief_3d);
+        if !fractal_type.supports_orbit_trap_image() && self.color_mode == ColorMode::OrbitTrapImage
+        {
+            self.color_mode = ColorMode::Palette;
+        }
 
         // Set fractal-specific defaults
         match fractal_type {
@@ -602,6 +876,9 @@ impl FractalParams {
             FractalType::SierpinskiTriangle2D => {
                 self.max_iterations = 30;
             }
+            FractalType::Collatz2D => {
+                self.max_iterations = 80;
+            }
             FractalType::Mandelbox3D => {
                 self.fractal_scale = 1.0; // Double the apparent size
                 self.fractal_fold = 1.0;
@@ -679,7 +956,7 @@ impl FractalParams {
                 self.attractor_iterations_per_frame = 50_000; // More samples for faster accumulation
                 self.attractor_log_scale = 1.0; // Lower for better contrast
             }
-            // 3D Strange Attractors
+            // 3D Strange Attractors - GPU accumulated point clouds
             FractalType::Pickover3D => {
                 self.fractal_scale = 0.3;
                 self.max_iterations = 10000;
@@ -687,6 +964,9 @@ impl FractalParams {
                 self.julia_c = [2.24, 0.43];
                 self.power = -0.65;
                 self.fractal_fold = -2.43;
+                self.attractor_accumulation_enabled = true; // Requires accumulation
+                self.attractor_iterations_per_frame = 500_000;
+                self.attractor_log_scale = 1.5;
             }
             FractalType::Lorenz3D => {
                 self.fractal_scale = 0.05;
@@ -694,6 +974,9 @@ impl FractalParams {
                 // sigma, rho, beta via julia_c.x, julia_c.y, power
                 self.julia_c = [10.0, 28.0];
                 self.power = 2.666667;
+                self.attractor_accumulation_enabled = true; // Requires accumulation
+                self.attractor_iterations_per_frame = 500_000;
+                self.attractor_log_scale = 1.5;
             }
             FractalType::Rossler3D => {
                 self.fractal_scale = 0.1;
@@ -701,9 +984,52 @@ impl FractalParams {
                 // a, b, c via julia_c.x, julia_c.y, power
                 self.julia_c = [0.2, 0.2];
                 self.power = 5.7;
+                self.attractor_accumulation_enabled = true; // Requires accumulation
+                self.attractor_iterations_per_frame = 500_000;
+                self.attractor_log_scale = 1.5;
             }
             _ => {}
         }
+
+        // Start accumulating afresh; the texture may hold another type's density
+        if fractal_type.uses_accumulation() {
+            self.attractor_pending_clear = true;
+            self.attractor_total_iterations = 0;
+        }
+    }
+
+    /// Zoom the 2D view by `factor`, keeping the complex point at `anchor` fixed.
+    /// `anchor` is a screen position in NDC ([-1, 1], y up); [0, 0] zooms toward the
+    /// view center. Uses f64 throughout so the anchor holds at deep zoom levels.
+    pub fn zoom_2d_around(&mut self, factor: f32, anchor: [f64; 2], aspect: f64) {
+        let old_zoom = self.zoom_2d as f64;
+        self.zoom_2d *= factor;
+        let new_zoom = self.zoom_2d as f64;
+
+        // Screen offset maps to (uv * 2 / zoom) * (aspect, 1) in fractal space
+        let scale = 2.0 * (1.0 / old_zoom - 1.0 / new_zoom);
+        self.center_2d[0] += anchor[0] * aspect * scale;
+        self.center_2d[1] += anchor[1] * scale;
+    }
+
+    /// Frame the 2D view on the box between two corners given in NDC ([-1, 1],
+    /// y up). A box that doesn't match the view's aspect ratio is expanded on
+    /// its shorter side, so everything inside it stays visible.
+    pub fn frame_2d_box(&mut self, corner_a: [f64; 2], corner_b: [f64; 2], aspect: f64) {
+        let zoom = self.zoom_2d as f64;
+        let mid = [
+            (corner_a[0] + corner_b[0]) / 2.0,
+            (corner_a[1] + corner_b[1]) / 2.0,
+        ];
+        self.center_2d[0] += mid[0] * aspect * 2.0 / zoom;
+        self.center_2d[1] += mid[1] * 2.0 / zoom;
+
+        // Fraction of the view the box covers along its relatively longer side
+        let fraction =
+            ((corner_a[0] - corner_b[0]).abs() / 2.0).max((corner_a[1] - corner_b[1]).abs() / 2.0);
+        if fraction > 0.0 {
+            self.zoom_2d = (zoom / fraction) as f32;
+        }
     }
 
     pub fn next_palette(&mut self) {
@@ -720,11 +1046,39 @@ impl FractalParams {
         self.palette = ColorPalette::ALL[self.palette_index];
     }
 
-    /// Randomize fractal parameters for creative exploration
-    pub fn randomize(&mut self) {
+    /// Switch to a different random palette without touching the geometry. Shuffles
+    /// the procedural palette when one is active, otherwise the static palette.
+    pub fn randomize_palette(&mut self, include_color_mode: bool) {
         use rand::Rng;
         let mut rng = rand::rng();
 
+        if self.procedural_palette != ProceduralPalette::None {
+            let others: Vec<ProceduralPalette> = ProceduralPalette::ALL
+                .iter()
+                .copied()
+                .filter(|p| *p != self.procedural_palette)
+                .collect();
+            if !others.is_empty() {
+                self.procedural_palette = others[rng.random_range(0..others.len())];
+            }
+        } else {
+            let count = ColorPalette::ALL.len();
+            // Offset by 1..count so the palette always changes
+            self.palette_index = (self.palette_index + rng.random_range(1..count)) % count;
+            self.palette = ColorPalette::ALL[self.palette_index];
+        }
+
+        if include_color_mode {
+            self.color_mode = RANDOM_COLOR_MODES[rng.random_range(0..RANDOM_COLOR_MODES.len())];
+        }
+    }
+
+    /// Randomize fractal parameters for creative exploration, skipping locked groups
+    pub fn randomize(&mut self, locks: &RandomizeLocks) {
+        use rand::Rng;
+        let mut rng = rand::rng();
+        let view_2d = (self.center_2d, self.zoom_2d);
+
         // Randomly select a fractal type
         let fractal_types = [
             FractalType::Mandelbrot2D,
@@ -746,22 +1100,26 @@ impl FractalParams {
             FractalType::IcosahedralIFS3D,
             FractalType::ApollonianGasket3D,
         ];
-        let new_type = fractal_types[rng.random_range(0..fractal_types.len())];
-        self.switch_fractal(new_type);
+        if !locks.fractal_type {
+            let new_type = fractal_types[rng.random_range(0..fractal_types.len())];
+            self.switch_fractal(new_type);
+        }
+
+        // Switching fractals may move the 2D view
+        if locks.camera {
+            (self.center_2d, self.zoom_2d) = view_2d;
+        }
 
         // Randomize color palette
-        self.palette_index = rng.random_range(0..ColorPalette::ALL.len());
-        self.palette = ColorPalette::ALL[self.palette_index];
+        if !locks.palette {
+            self.palette_index = rng.random_range(0..ColorPalette::ALL.len());
+            self.palette = ColorPalette::ALL[self.palette_index];
+        }
 
         // Randomize color mode
-        let color_modes = [
-            ColorMode::Palette,
-            ColorMode::RaySteps,
-            ColorMode::Normals,
-            ColorMode::OrbitTrapXYZ,
-            ColorMode::OrbitTrapRadial,
-        ];
-        self.color_mode = color_modes[rng.random_range(0..color_modes.len())];
+        if !locks.color_mode {
+            self.color_mode = RANDOM_COLOR_MODES[rng.random_range(0..RANDOM_COLOR_MODES.len())];
+        }
 
         match self.render_mode {
             RenderMode::TwoD => {
@@ -780,48 +1138,70 @@ impl FractalParams {
                     self.fractal_min_radius = rng.random_range(0.2..1.5);
                 }
 
-                // Randomize lighting
-                self.light_intensity = rng.random_range(1.5..6.0);
-                self.ambient_light = rng.random_range(0.05..0.4);
-
-                // Randomize effects
-                self.ambient_occlusion = rng.random_bool(0.7); // 70% chance
-                if self.ambient_occlusion {
-                    self.ao_intensity = rng.random_range(1.0..6.0);
+                if self.fractal_type.uses_ifs_offset() {
+                    self.ifs_offset = Vec3::new(
+                        rng.random_range(0.7..1.3),
+                        rng.random_range(0.7..1.3),
+                        rng.random_range(0.7..1.3),
+                    );
                 }
 
-                // 0=off, 1=hard, 2=soft
-                self.shadow_mode = if rng.random_bool(0.6) {
-                    if rng.random_bool(0.7) {
-                        2
-                    } else {
-                        1
-                    } // 70% soft, 30% hard
-                } else {
-                    0
-                };
-                if self.shadow_mode == 2 {
-                    self.shadow_softness = rng.random_range(4.0..20.0);
-                }
+                if !locks.lighting {
+                    // Randomize lighting
+                    self.light_intensity = rng.random_range(1.5..6.0);
+                    self.ambient_light = rng.random_range(0.05..0.4);
 
-                self.fog_enabled = rng.random_bool(0.5); // 50% chance
-                if self.fog_enabled {
-                    self.fog_density = rng.random_range(0.001..0.05);
+                    // Randomize material for PBR
+                    if self.shading_model == ShadingModel::PBR {
+                        self.roughness = rng.random_range(0.1..0.9);
+                        self.metallic = rng.random_range(0.0..0.7);
+                    }
                 }
 
-                self.show_floor = rng.random_bool(0.5); // 50% chance
-
-                // Randomize material for PBR
-                if self.shading_model == ShadingModel::PBR {
-                    self.roughness = rng.random_range(0.1..0.9);
-                    self.metallic = rng.random_range(0.0..0.7);
+                if !locks.effects {
+                    // Randomize effects
+                    self.ambient_occlusion = rng.random_bool(0.7); // 70% chance
+                    if self.ambient_occlusion {
+                        self.ao_intensity = rng.random_range(1.0..6.0);
+                    }
+
+                    // 0=off, 1=hard, 2=soft
+                    self.shadow_mode = if rng.random_bool(0.6) {
+                        if rng.random_bool(0.7) {
+                            2
+                        } else {
+                            1
+                        } // 70% soft, 30% hard
+                    } else {
+                        0
+                    };
+                    if self.shadow_mode == 2 {
+                        self.shadow_softness = rng.random_range(4.0..20.0);
+                        self.light_size = rng.random_range(2.0..20.0);
+                    }
+
+                    self.fog_enabled = rng.random_bool(0.5); // 50% chance
+                    if self.fog_enabled {
+                        self.fog_density = rng.random_range(0.001..0.05);
+                    }
+
+                    self.show_floor = rng.random_bool(0.5); // 50% chance
                 }
             }
         }
     }
 
     /// Update LOD system state and apply quality adjustments
-    pub fn update_lod(&mut self, camera_pos: Vec3, camera_forward: Vec3, delta_time: f32) {
+    /// While `recording` a video, the level is held if the config freezes it
+    /// during recordings.
+    pub fn update_lod(
+        &mut self,
+        camera_pos: Vec3,
+        camera_forward: Vec3,
+        delta_time: f32,
+        recording: bool,
+    ) {
+        self.lod_state.frozen = false;
         if !self.lod_config.enabled {
             // LOD disabled, ensure we're at max quality
             if self.lod_state.current_level != 0 {
@@ -833,6 +1213,17 @@ impl FractalParams {
             return;
         }
 
+        // A frozen level overrides the adaptive logic entirely, with no transition
+        if let Some(level) = self.lod_config.frozen_level(recording) {
+            self.lod_state.frozen = true;
+            self.lod_state.current_level = level;
+            self.lod_state.target_level = level;
+            self.lod_state.transition_progress = 1.0;
+            self.lod_state.active_quality = self.lod_config.quality_presets[level];
+            self.apply_lod_quality();
+            return;
+        }
+
         // Update FPS tracking
         self.lod_state.update_fps(delta_time);
 
@@ -1012,8 +1403,55 @@ impl FractalParams {
         self.ao_step_size = quality.ao_step_size;
         self.dof_samples = quality.dof_samples;
 
-        // Render scale would require render target resizing, which we'll skip for now
-        // This could be added in a future phase
+        // The render scale is applied by the renderer, see `lod_render_scale`
+    }
+
+    /// Whether the frame is rendered from the accumulation texture rather than
+    /// by the fragment shader. 3D attractors have no usable ray marched form,
+    /// so they always accumulate.
+    pub fn accumulation_active(&self) -> bool {
+        self.fractal_type.is_3d_attractor()
+            || (self.attractor_accumulation_enabled && self.fractal_type.uses_accumulation())
+    }
+
+    /// Shader index of the attractor color mode in effect. Only 2D attractors
+    /// track the per-hit attribute; everything else colors by density.
+    pub fn attractor_color_mode_index(&self) -> u32 {
+        if self.fractal_type.is_2d_attractor() {
+            self.attractor_color_mode.shader_index()
+        } else {
+            AttractorColorMode::Density.shader_index()
+        }
+    }
+
+    /// Whether a view change should clear the accumulated density now.
+    /// `recording_time` is the length of the video being recorded, if any;
+    /// holding limits clears to one per `attractor_recording_clear_interval`
+    /// seconds of it so an animated attractor can still build up.
+    pub fn attractor_auto_clear_due(&self, recording_time: Option<f32>) -> bool {
+        match recording_time {
+            Some(time) if self.attractor_hold_while_recording => {
+                let interval = self.attractor_recording_clear_interval;
+                // A new recording restarts the clock
+                interval > 0.0
+                    && (time - self.attractor_last_clear_time >= interval
+                        || time < self.attractor_last_clear_time)
+            }
+            _ => true,
+        }
+    }
+
+    /// Resolution multiplier for the scene texture. Only 3D ray marching is
+    /// scaled; 2D views and accumulation fractals always render at native size.
+    pub fn lod_render_scale(&self) -> f32 {
+        if self.lod_config.enabled
+            && self.render_mode == RenderMode::ThreeD
+            && !self.fractal_type.uses_accumulation()
+        {
+            self.lod_state.active_quality.render_scale
+        } else {
+            1.0
+        }
     }
 }
 
@@ -0,0 +1,15 @@
+/// Maximum number of polynomial roots supported by the Newton fractal shader
+pub const NEWTON_MAX_ROOTS: usize = 8;
+
+/// Roots of z^n - 1 (the n-th roots of unity), the classic Newton fractal polynomials.
+///
+/// The degree is clamped to 2..=NEWTON_MAX_ROOTS.
+pub fn newton_unity_roots(degree: usize) -> Vec<[f32; 2]> {
+    let degree = degree.clamp(2, NEWTON_MAX_ROOTS);
+    (0..degree)
+        .map(|k| {
+            let angle = std::f32::consts::TAU * k as f32 / degree as f32;
+            [angle.cos(), angle.sin()]
+        })
+        .collect()
+}
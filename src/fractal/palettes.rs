@@ -900,6 +900,110 @@ impl CustomPalette {
         }
     }
 
+    /// Extract a representative 8-color palette from an image file
+    pub fn from_image_file(path: &std::path::Path) -> Result<Self, String> {
+        let image = image::open(path)
+            .map_err(|e| format!("Failed to decode image: {}", e))?
+            .to_rgb8();
+
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Extracted")
+            .to_string();
+
+        // Subsample large images so clustering stays fast
+        let pixel_count = (image.width() * image.height()) as usize;
+        let stride = (pixel_count / 20_000).max(1);
+        let pixels: Vec<Vec3> = image
+            .pixels()
+            .step_by(stride)
+            .map(|p| Vec3::new(p[0] as f32, p[1] as f32, p[2] as f32) / 255.0)
+            .collect();
+
+        if pixels.is_empty() {
+            return Err("Image contains no pixels".to_string());
+        }
+
+        Ok(Self::new(name, Self::extract_palette(&pixels)))
+    }
+
+    /// Build a palette from text listing hex colors (`#RRGGBB` or `RRGGBB`,
+    /// separated by commas, semicolons or whitespace), as copied from palette
+    /// websites. Other tokens are ignored. Any number of colors from 2 up is
+    /// resampled to 8. Returns the palette and how many colors were found.
+    pub fn from_hex_list(text: &str, name: String) -> Result<(Self, usize), String> {
+        let colors = parse_hex_colors(text);
+        if colors.len() < 2 {
+            return Err(format!(
+                "Need at least 2 hex colors like #1a2b3c (found {})",
+                colors.len()
+            ));
+        }
+        Ok((
+            Self::new(name, Self::sample_colors(&colors, 8)),
+            colors.len(),
+        ))
+    }
+
+    /// Cluster pixel colors into 8 representative colors using k-means.
+    ///
+    /// Centroids are seeded at luminance quantiles so the result is deterministic,
+    /// and the final colors are sorted dark to light to form a smooth gradient.
+    pub fn extract_palette(pixels: &[Vec3]) -> [Vec3; 8] {
+        const K: usize = 8;
+        const MAX_ITERATIONS: usize = 20;
+
+        let mut result = [Vec3::ZERO; K];
+        if pixels.is_empty() {
+            return result;
+        }
+
+        let mut by_luminance = pixels.to_vec();
+        by_luminance.sort_by(|a, b| luminance(*a).total_cmp(&luminance(*b)));
+        let mut centroids: Vec<Vec3> = (0..K)
+            .map(|i| by_luminance[(i * 2 + 1) * (by_luminance.len() - 1) / (K * 2)])
+            .collect();
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut sums = [Vec3::ZERO; K];
+            let mut counts = [0usize; K];
+            for pixel in pixels {
+                let nearest = centroids
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| {
+                        a.distance_squared(*pixel)
+                            .total_cmp(&b.distance_squared(*pixel))
+                    })
+                    .map(|(i, _)| i)
+                    .unwrap_or(0);
+                sums[nearest] += *pixel;
+                counts[nearest] += 1;
+            }
+
+            let mut moved = false;
+            for (i, centroid) in centroids.iter_mut().enumerate() {
+                // Empty clusters keep their previous centroid
+                if counts[i] > 0 {
+                    let updated = sums[i] / counts[i] as f32;
+                    moved |= updated.distance_squared(*centroid) > 1e-8;
+                    *centroid = updated;
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+
+        // Cluster order is arbitrary; order by luminance for a pleasing gradient
+        centroids.sort_by(|a, b| luminance(*a).total_cmp(&luminance(*b)));
+        for (slot, centroid) in result.iter_mut().zip(centroids) {
+            *slot = centroid.clamp(Vec3::ZERO, Vec3::ONE);
+        }
+        result
+    }
+
     /// Sample N colors evenly from a color list using linear interpolation
     fn sample_colors(colors: &[Vec3], n: usize) -> [Vec3; 8] {
         let mut result = [Vec3::ZERO; 8];
@@ -931,6 +1035,31 @@ impl CustomPalette {
     }
 }
 
+/// Every `#RRGGBB` / `RRGGBB` token in `text`, in order
+fn parse_hex_colors(text: &str) -> Vec<Vec3> {
+    text.split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+        .filter_map(|token| {
+            // Tolerate quotes and brackets from JSON or CSS snippets
+            let token = token.trim_matches(|c: char| !c.is_ascii_alphanumeric() && c != '#');
+            let hex = token.strip_prefix('#').unwrap_or(token);
+            if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            let value = u32::from_str_radix(hex, 16).ok()?;
+            Some(Vec3::new(
+                ((value >> 16) & 0xff) as f32 / 255.0,
+                ((value >> 8) & 0xff) as f32 / 255.0,
+                (value & 0xff) as f32 / 255.0,
+            ))
+        })
+        .collect()
+}
+
+/// Perceptual luminance (Rec. 601 weights) used to order palette colors
+fn luminance(color: Vec3) -> f32 {
+    color.dot(Vec3::new(0.299, 0.587, 0.114))
+}
+
 // Gallery for managing custom palettes
 pub struct CustomPaletteGallery;
 
@@ -0,0 +1,246 @@
+//! Validation for presets imported from JSON
+//!
+//! `Settings` gains fields with nearly every release, and a preset exported by
+//! an older version lacks them. Instead of deserializing the file directly,
+//! the import fills any missing settings from the defaults, reports fields it
+//! doesn't recognize, and names the field responsible when a value has the
+//! wrong type, so a bad file produces a message the user can act on.
+
+use super::{FractalParams, Preset};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Nested settings structs whose missing fields are also filled from the defaults
+const NESTED_SETTINGS: &[&str] = &["ui_state", "lod_config"];
+
+/// Why a preset file couldn't be imported
+#[derive(Debug)]
+pub enum PresetImportError {
+    /// The user closed the file dialog
+    Cancelled,
+    /// The file couldn't be read
+    Io(std::io::Error),
+    /// The file isn't valid JSON
+    Syntax {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The file is JSON but not a preset object
+    NotAPreset,
+    /// A required field is absent
+    MissingField(String),
+    /// A field has the wrong type or an unknown value
+    InvalidField { field: String, message: String },
+}
+
+impl fmt::Display for PresetImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PresetImportError::Cancelled => write!(f, "Import cancelled by user"),
+            PresetImportError::Io(e) => write!(f, "Couldn't read the file: {}", e),
+            PresetImportError::Syntax {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Invalid JSON at line {}, column {}: {}",
+                line, column, message
+            ),
+            PresetImportError::NotAPreset => write!(
+                f,
+                "Not a preset: expected an object with \"name\" and \"settings\""
+            ),
+            PresetImportError::MissingField(field) => {
+                write!(f, "Missing required field \"{}\"", field)
+            }
+            PresetImportError::InvalidField { field, message } => {
+                write!(f, "Invalid value for \"{}\": {}", field, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PresetImportError {}
+
+impl From<std::io::Error> for PresetImportError {
+    fn from(e: std::io::Error) -> Self {
+        PresetImportError::Io(e)
+    }
+}
+
+/// A successfully imported preset and anything the user should know about it
+#[derive(Debug)]
+pub struct PresetImport {
+    pub preset: Preset,
+    /// Fields that were filled with defaults or ignored
+    pub warnings: Vec<String>,
+}
+
+/// Parse and validate a preset exported as JSON. `fallback_name` names the
+/// preset if the file doesn't.
+pub fn parse_preset_json(
+    json: &str,
+    fallback_name: &str,
+) -> Result<PresetImport, PresetImportError> {
+    let mut value: Value = serde_json::from_str(json).map_err(|e| PresetImportError::Syntax {
+        line: e.line(),
+        column: e.column(),
+        message: strip_position(&e),
+    })?;
+    let preset = value.as_object_mut().ok_or(PresetImportError::NotAPreset)?;
+    let mut warnings = Vec::new();
+
+    if !preset.contains_key("name") {
+        warnings.push(format!("No preset name, using \"{}\"", fallback_name));
+        preset.insert("name".to_string(), Value::from(fallback_name));
+    }
+    if !preset.contains_key("description") {
+        preset.insert("description".to_string(), Value::from(""));
+    }
+    let settings = preset
+        .get_mut("settings")
+        .ok_or_else(|| PresetImportError::MissingField("settings".to_string()))?;
+    let Some(settings) = settings.as_object_mut() else {
+        return Err(PresetImportError::InvalidField {
+            field: "settings".to_string(),
+            message: "expected an object".to_string(),
+        });
+    };
+
+    // Fill in settings added since the preset was exported
+    let defaults = serde_json::to_value(FractalParams::default().to_settings())
+        .expect("default settings serialize");
+    let Value::Object(defaults) = defaults else {
+        unreachable!("settings serialize as an object");
+    };
+    let mut filled = Vec::new();
+    let mut unknown = Vec::new();
+    fill_missing(settings, &defaults, "", true, &mut filled, &mut unknown);
+    if !filled.is_empty() {
+        warnings.push(format!(
+            "{} missing setting(s) filled with defaults: {}",
+            filled.len(),
+            summarize(&filled)
+        ));
+    }
+    if !unknown.is_empty() {
+        warnings.push(format!(
+            "Ignored unknown setting(s): {}",
+            summarize(&unknown)
+        ));
+    }
+
+    match serde_json::from_value::<Preset>(value.clone()) {
+        Ok(preset) => Ok(PresetImport { preset, warnings }),
+        Err(e) => Err(find_invalid_field(&value, &defaults).unwrap_or_else(|| {
+            PresetImportError::InvalidField {
+                field: "preset".to_string(),
+                message: e.to_string(),
+            }
+        })),
+    }
+}
+
+/// Copy fields missing from `target` out of `defaults`, recording their paths,
+/// and record fields `defaults` doesn't have. At the top level, the nested
+/// settings structs are filled too.
+fn fill_missing(
+    target: &mut Map<String, Value>,
+    defaults: &Map<String, Value>,
+    prefix: &str,
+    top_level: bool,
+    filled: &mut Vec<String>,
+    unknown: &mut Vec<String>,
+) {
+    for (key, default) in defaults {
+        let path = format!("{}{}", prefix, key);
+        match target.get_mut(key) {
+            None => {
+                target.insert(key.clone(), default.clone());
+                filled.push(path);
+            }
+            Some(Value::Object(nested)) if top_level && NESTED_SETTINGS.contains(&key.as_str()) => {
+                if let Value::Object(nested_defaults) = default {
+                    let prefix = format!("{}.", path);
+                    fill_missing(nested, nested_defaults, &prefix, false, filled, unknown);
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    for key in target.keys() {
+        if !defaults.contains_key(key) {
+            unknown.push(format!("{}{}", prefix, key));
+        }
+    }
+}
+
+/// Find the field that stops the preset from deserializing: the first one
+/// that makes it valid when swapped for its default
+fn find_invalid_field(preset: &Value, defaults: &Map<String, Value>) -> Option<PresetImportError> {
+    let as_preset = |value: &Value| serde_json::from_value::<Preset>(value.clone());
+    let top_level = preset.as_object()?;
+
+    // The preset's own fields (name, description, category)
+    for key in top_level.keys().filter(|key| *key != "settings") {
+        let mut candidate = preset.clone();
+        candidate[key.as_str()] = match key.as_str() {
+            "category" => serde_json::to_value(super::PresetCategory::default()).ok()?,
+            _ => Value::from(""),
+        };
+        if as_preset(&candidate).is_ok() {
+            return Some(invalid_field(key, &preset[key.as_str()], as_preset(preset)));
+        }
+    }
+
+    let settings = top_level.get("settings")?.as_object()?;
+    for key in settings.keys() {
+        let Some(default) = defaults.get(key) else {
+            continue;
+        };
+        let mut candidate = preset.clone();
+        candidate["settings"][key.as_str()] = default.clone();
+        if as_preset(&candidate).is_ok() {
+            let field = format!("settings.{}", key);
+            return Some(invalid_field(&field, &settings[key], as_preset(preset)));
+        }
+    }
+    None
+}
+
+fn invalid_field<T>(
+    field: &str,
+    value: &Value,
+    result: Result<T, serde_json::Error>,
+) -> PresetImportError {
+    let reason = result.err().map(|e| e.to_string()).unwrap_or_default();
+    PresetImportError::InvalidField {
+        field: field.to_string(),
+        message: format!("{} (found {})", reason, value),
+    }
+}
+
+/// The error message without serde_json's "at line X column Y" suffix
+fn strip_position(e: &serde_json::Error) -> String {
+    let message = e.to_string();
+    match message.rfind(" at line ") {
+        Some(index) => message[..index].to_string(),
+        None => message,
+    }
+}
+
+/// A comma-separated list, shortened when long
+fn summarize(fields: &[String]) -> String {
+    const SHOWN: usize = 5;
+    if fields.len() <= SHOWN {
+        fields.join(", ")
+    } else {
+        format!(
+            "{} and {} more",
+            fields[..SHOWN].join(", "),
+            fields.len() - SHOWN
+        )
+    }
+}
@@ -1,4 +1,9 @@
-use super::{FractalParams, FractalType, Settings};
+#[cfg(not(target_arch = "wasm32"))]
+use super::parse_preset_json;
+use super::{
+    CameraEasing, FractalParams, FractalType, PresentModeSetting, PresetImport, PresetImportError,
+    Settings, SurfaceFormatSetting, UpAxis, DEFAULT_FILENAME_TEMPLATE,
+};
 use glam::Vec3;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -57,10 +62,20 @@ pub struct CameraBookmark {
     pub target: [f32; 3],
     pub fov: f32,
     pub timestamp: String,
+    /// Up axis convention the position and target were saved in
+    #[serde(default)]
+    pub up_axis: UpAxis,
+    /// 2D view center, for bookmarks of 2D fractals (None in 3D bookmarks
+    /// and files saved before 2D views were stored)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub center_2d: Option<[f64; 2]>,
+    /// 2D zoom, for bookmarks of 2D fractals
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zoom_2d: Option<f32>,
 }
 
 // Application preferences
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppPreferences {
     #[serde(default)]
     pub preferred_gpu_index: Option<usize>,
@@ -70,6 +85,165 @@ pub struct AppPreferences {
     pub window_width: Option<u32>,
     #[serde(default)]
     pub window_height: Option<u32>,
+    /// Clamp GPU-heavy settings to conservative limits to avoid driver resets
+    #[serde(default = "default_safe_mode")]
+    pub safe_mode: bool,
+    /// Persist settings and camera automatically after changes settle
+    #[serde(default = "default_autosave_enabled")]
+    pub autosave_enabled: bool,
+    /// Seconds of inactivity before an auto-save is written
+    #[serde(default = "default_autosave_interval_secs")]
+    pub autosave_interval_secs: f32,
+    /// Surface present mode (falls back to Fifo if unsupported by the adapter)
+    #[serde(default)]
+    pub present_mode: PresentModeSetting,
+    /// Window surface format, applied at startup (falls back to Auto if unsupported)
+    #[serde(default)]
+    pub surface_format: SurfaceFormatSetting,
+    /// Frame rate cap, 0 = unlimited
+    #[serde(default)]
+    pub max_fps: u32,
+    /// Drop to `idle_fps` while the scene is static and nothing is animating
+    #[serde(default)]
+    pub idle_throttle_enabled: bool,
+    #[serde(default = "default_idle_fps")]
+    pub idle_fps: u32,
+    /// Keep the 2D point under the cursor fixed while zooming (otherwise zoom toward the view center)
+    #[serde(default = "default_zoom_to_cursor")]
+    pub zoom_to_cursor: bool,
+    /// Let a flicked 2D pan glide to a stop and ease wheel zoom out
+    #[serde(default)]
+    pub navigation_momentum: bool,
+    /// Restore the coloring and iterations last used with a fractal type when
+    /// switching back to it (otherwise apply the type's defaults)
+    #[serde(default = "default_remember_fractal_coloring")]
+    pub remember_fractal_coloring: bool,
+    /// Seconds each preset is shown in tour mode
+    #[serde(default = "default_tour_dwell_secs")]
+    pub tour_dwell_secs: f32,
+    /// Easing curve of camera transitions to bookmarks and tour presets
+    #[serde(default)]
+    pub camera_transition_easing: CameraEasing,
+    /// Seconds a camera transition to a bookmark takes
+    #[serde(default = "default_camera_transition_secs")]
+    pub camera_transition_secs: f32,
+    /// Seconds without input before attract mode starts auto-orbiting and
+    /// animating the palette, 0 = never
+    #[serde(default)]
+    pub idle_timeout_secs: f32,
+    /// Warn when a parameter change leaves the view entirely black or NaN
+    #[serde(default = "default_blank_frame_warning")]
+    pub blank_frame_warning: bool,
+    /// Revert such a change automatically instead of only warning
+    #[serde(default)]
+    pub blank_frame_auto_revert: bool,
+    /// Folder screenshots, high-res renders and videos are written to
+    /// (None = the user's Pictures directory)
+    #[serde(default)]
+    pub capture_directory: Option<String>,
+    /// Render F12 screenshots at twice the window resolution and downscale
+    /// them, anti-aliasing the saved file
+    #[serde(default = "default_screenshot_supersample")]
+    pub screenshot_supersample: bool,
+    /// Text drawn into recorded video frames: frame number, timecode, and
+    /// the view and animated parameter values
+    #[serde(default)]
+    pub burn_in_frame_number: bool,
+    #[serde(default)]
+    pub burn_in_timecode: bool,
+    #[serde(default)]
+    pub burn_in_parameters: bool,
+    /// Name of capture files without the extension; see `FILENAME_TOKENS`
+    #[serde(default = "default_filename_template")]
+    pub filename_template: String,
+    /// Fractal types listed in the Fractal Type section (None = all). Hidden
+    /// types can still be loaded from presets
+    #[serde(default)]
+    pub visible_fractal_types: Option<Vec<FractalType>>,
+    /// egui pixels per point, overriding the scale the system reports for the
+    /// display (0 = follow the system)
+    #[serde(default)]
+    pub ui_scale: f32,
+}
+
+fn default_safe_mode() -> bool {
+    true
+}
+
+fn default_autosave_enabled() -> bool {
+    true
+}
+
+fn default_autosave_interval_secs() -> f32 {
+    1.0
+}
+
+fn default_idle_fps() -> u32 {
+    10
+}
+
+fn default_zoom_to_cursor() -> bool {
+    true
+}
+
+fn default_remember_fractal_coloring() -> bool {
+    true
+}
+
+fn default_tour_dwell_secs() -> f32 {
+    30.0
+}
+
+fn default_camera_transition_secs() -> f32 {
+    1.5
+}
+
+fn default_screenshot_supersample() -> bool {
+    true
+}
+
+fn default_filename_template() -> String {
+    DEFAULT_FILENAME_TEMPLATE.to_string()
+}
+
+fn default_blank_frame_warning() -> bool {
+    true
+}
+
+impl Default for AppPreferences {
+    fn default() -> Self {
+        Self {
+            preferred_gpu_index: None,
+            preferred_gpu_name: None,
+            window_width: None,
+            window_height: None,
+            safe_mode: default_safe_mode(),
+            autosave_enabled: default_autosave_enabled(),
+            autosave_interval_secs: default_autosave_interval_secs(),
+            present_mode: PresentModeSetting::default(),
+            surface_format: SurfaceFormatSetting::default(),
+            max_fps: 0,
+            idle_throttle_enabled: false,
+            idle_fps: default_idle_fps(),
+            zoom_to_cursor: default_zoom_to_cursor(),
+            navigation_momentum: false,
+            remember_fractal_coloring: default_remember_fractal_coloring(),
+            tour_dwell_secs: default_tour_dwell_secs(),
+            camera_transition_easing: CameraEasing::default(),
+            camera_transition_secs: default_camera_transition_secs(),
+            idle_timeout_secs: 0.0,
+            blank_frame_warning: default_blank_frame_warning(),
+            blank_frame_auto_revert: false,
+            capture_directory: None,
+            screenshot_supersample: default_screenshot_supersample(),
+            burn_in_frame_number: false,
+            burn_in_timecode: false,
+            burn_in_parameters: false,
+            filename_template: default_filename_template(),
+            visible_fractal_types: None,
+            ui_scale: 0.0,
+        }
+    }
 }
 
 impl AppPreferences {
@@ -105,6 +279,18 @@ impl AppPreferences {
         self.window_height = Some(height);
     }
 
+    /// Resolve the capture output folder: the configured directory, else the
+    /// user's Pictures directory, else the current directory
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn resolve_capture_directory(configured: Option<&str>) -> std::path::PathBuf {
+        if let Some(dir) = configured.filter(|dir| !dir.trim().is_empty()) {
+            return std::path::PathBuf::from(dir);
+        }
+        directories::UserDirs::new()
+            .and_then(|dirs| dirs.picture_dir().map(|dir| dir.to_path_buf()))
+            .unwrap_or_else(|| std::path::PathBuf::from("."))
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(config_dir) = directories::ProjectDirs::from("com", "fractal", "par-fractal") {
@@ -128,7 +314,7 @@ impl AppPreferences {
 
 impl CameraBookmark {
     #[cfg(not(target_arch = "wasm32"))]
-    pub fn new(name: String, position: Vec3, target: Vec3, fov: f32) -> Self {
+    pub fn new(name: String, position: Vec3, target: Vec3, fov: f32, up_axis: UpAxis) -> Self {
         use chrono::Local;
         let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
         Self {
@@ -137,11 +323,14 @@ impl CameraBookmark {
             target: target.to_array(),
             fov,
             timestamp,
+            up_axis,
+            center_2d: None,
+            zoom_2d: None,
         }
     }
 
     #[cfg(target_arch = "wasm32")]
-    pub fn new(name: String, position: Vec3, target: Vec3, fov: f32) -> Self {
+    pub fn new(name: String, position: Vec3, target: Vec3, fov: f32, up_axis: UpAxis) -> Self {
         // Web: use a simple timestamp placeholder
         let timestamp = "web".to_string();
         Self {
@@ -150,9 +339,56 @@ impl CameraBookmark {
             target: target.to_array(),
             fov,
             timestamp,
+            up_axis,
+            center_2d: None,
+            zoom_2d: None,
         }
     }
 
+    /// Also store a 2D view, for bookmarking 2D fractals
+    pub fn with_view_2d(mut self, center: [f64; 2], zoom: f32) -> Self {
+        self.center_2d = Some(center);
+        self.zoom_2d = Some(zoom);
+        self
+    }
+
+    /// Name for a quick bookmark of a 2D view, e.g.
+    /// `Mandelbrot_-0.7435_0.1314_z1e6`. Deeper zooms get more decimals so
+    /// nearby views keep distinct names.
+    pub fn quick_name_2d(fractal_type: FractalType, center: [f64; 2], zoom: f32) -> String {
+        let decimals = 4 + zoom.max(1.0).log10().ceil() as usize;
+        format!(
+            "{}_{:.*}_{:.*}_z{:.0e}",
+            quick_name_prefix(fractal_type),
+            decimals,
+            center[0],
+            decimals,
+            center[1],
+            zoom
+        )
+    }
+
+    /// Name for a quick bookmark of a 3D camera position, e.g.
+    /// `Mandelbulb_0.00_1.50_-3.00`
+    pub fn quick_name_3d(fractal_type: FractalType, position: Vec3) -> String {
+        format!(
+            "{}_{:.2}_{:.2}_{:.2}",
+            quick_name_prefix(fractal_type),
+            position.x,
+            position.y,
+            position.z
+        )
+    }
+
+    /// The 2D center and zoom to restore. Bookmarks without a 2D view load
+    /// the default view.
+    pub fn view_2d(&self) -> ([f64; 2], f32) {
+        (
+            self.center_2d.unwrap_or([0.0, 0.0]),
+            self.zoom_2d.unwrap_or(1.0),
+        )
+    }
+
     pub fn get_position(&self) -> Vec3 {
         Vec3::from_array(self.position)
     }
@@ -162,6 +398,14 @@ impl CameraBookmark {
     }
 }
 
+/// Fractal type without its 2D/3D suffix, e.g. `Mandelbrot`
+fn quick_name_prefix(fractal_type: FractalType) -> String {
+    let name = format!("{:?}", fractal_type);
+    name.trim_end_matches("2D")
+        .trim_end_matches("3D")
+        .to_string()
+}
+
 // Gallery of camera bookmarks
 pub struct BookmarkGallery;
 
@@ -179,7 +423,7 @@ impl BookmarkGallery {
             let yaml = serde_yaml::to_string(bookmark)?;
             fs::write(bookmark_file, yaml)?;
 
-            println!("Bookmark '{}' saved", bookmark.name);
+            log::info!("Bookmark '{}' saved", bookmark.name);
             Ok(())
         } else {
             Err("Could not determine config directory".into())
@@ -194,7 +438,7 @@ impl BookmarkGallery {
                 .join(format!("{}.yaml", filename));
             let yaml = fs::read_to_string(bookmark_file)?;
             let bookmark: CameraBookmark = serde_yaml::from_str(&yaml)?;
-            println!("Bookmark '{}' loaded", bookmark.name);
+            log::info!("Bookmark '{}' loaded", bookmark.name);
             Ok(bookmark)
         } else {
             Err("Could not determine config directory".into())
@@ -208,7 +452,7 @@ impl BookmarkGallery {
                 .join("bookmarks")
                 .join(format!("{}.yaml", filename));
             fs::remove_file(bookmark_file)?;
-            println!("Bookmark '{}' deleted", filename);
+            log::info!("Bookmark '{}' deleted", filename);
             Ok(())
         } else {
             Err("Could not determine config directory".into())
@@ -723,7 +967,7 @@ impl PresetGallery {
             let yaml = serde_yaml::to_string(preset)?;
             fs::write(preset_file, yaml)?;
 
-            println!("Preset '{}' saved", preset.name);
+            log::info!("Preset '{}' saved", preset.name);
             Ok(())
         } else {
             Err("Could not determine config directory".into())
@@ -739,7 +983,7 @@ impl PresetGallery {
                 .join(format!("{}.yaml", filename));
             let yaml = fs::read_to_string(preset_file)?;
             let preset: Preset = serde_yaml::from_str(&yaml)?;
-            println!("Preset '{}' loaded", preset.name);
+            log::info!("Preset '{}' loaded", preset.name);
             Ok(preset)
         } else {
             Err("Could not determine config directory".into())
@@ -755,7 +999,7 @@ impl PresetGallery {
                 .join(format!("{}.yaml", filename));
             if preset_file.exists() {
                 fs::remove_file(&preset_file)?;
-                println!("Preset '{}' deleted", filename);
+                log::info!("Preset '{}' deleted", filename);
                 Ok(())
             } else {
                 Err(format!("Preset '{}' not found", filename).into())
@@ -803,7 +1047,7 @@ impl PresetGallery {
         if let Some(path) = file_dialog.save_file() {
             let json = serde_json::to_string_pretty(preset)?;
             fs::write(&path, json)?;
-            println!("Preset '{}' exported to {}", preset.name, path.display());
+            log::info!("Preset '{}' exported to {}", preset.name, path.display());
             Ok(())
         } else {
             Err("Export cancelled by user".into())
@@ -836,28 +1080,34 @@ impl PresetGallery {
 
             let json = serde_json::to_string_pretty(&preset)?;
             fs::write(&path, json)?;
-            println!("Settings exported to {}", path.display());
+            log::info!("Settings exported to {}", path.display());
             Ok(())
         } else {
             Err("Export cancelled by user".into())
         }
     }
 
-    /// Import settings from a JSON file (user chooses file)
+    /// Import settings from a JSON file (user chooses file). Settings missing
+    /// from older exports are filled with defaults.
     #[cfg(not(target_arch = "wasm32"))]
-    pub fn import_from_json() -> Result<Preset, Box<dyn std::error::Error>> {
+    pub fn import_from_json() -> Result<PresetImport, PresetImportError> {
         let file_dialog = rfd::FileDialog::new()
             .add_filter("JSON", &["json"])
             .set_title("Import Fractal Settings");
 
-        if let Some(path) = file_dialog.pick_file() {
-            let json = fs::read_to_string(&path)?;
-            let preset: Preset = serde_json::from_str(&json)?;
-            println!("Settings imported from {}", path.display());
-            Ok(preset)
-        } else {
-            Err("Import cancelled by user".into())
+        let path = file_dialog
+            .pick_file()
+            .ok_or(PresetImportError::Cancelled)?;
+        let json = fs::read_to_string(&path)?;
+        let fallback_name = path
+            .file_stem()
+            .map_or("Imported Preset".into(), |stem| stem.to_string_lossy());
+        let import = parse_preset_json(&json, &fallback_name)?;
+        log::info!("Settings imported from {}", path.display());
+        for warning in &import.warnings {
+            log::warn!("{}: {}", path.display(), warning);
         }
+        Ok(import)
     }
 
     // Web implementations using localStorage
@@ -1050,9 +1300,10 @@ impl PresetGallery {
     }
 
     #[cfg(target_arch = "wasm32")]
-    pub fn import_from_json() -> Result<Preset, Box<dyn std::error::Error>> {
-        // This is a synchronous function but file reading is async on web
-        // We'll need to handle this through the UI with a callback
-        Err("Import should be handled through UI file input".into())
+    pub fn import_from_json() -> Result<PresetImport, PresetImportError> {
+        // The web build has no file input yet, so JSON import is native only
+        Err(PresetImportError::Io(std::io::Error::other(
+            "Importing from JSON is only available in the desktop app",
+        )))
     }
 }
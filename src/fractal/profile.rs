@@ -0,0 +1,87 @@
+//! Portable preference profiles
+//!
+//! A profile bundles the application preferences and the UI layout into one
+//! file that can be copied between machines, separate from fractal settings
+//! and presets. Preferences tied to one machine (the GPU, window size, UI
+//! scale and capture folder) are left out of an exported profile, and
+//! importing a profile keeps the local machine's values for them.
+
+use super::{AppPreferences, UIState};
+use serde::{Deserialize, Serialize};
+
+/// Default file name offered when exporting a profile
+pub const PROFILE_FILE_NAME: &str = "profile.yaml";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreferenceProfile {
+    #[serde(default)]
+    pub preferences: AppPreferences,
+    #[serde(default)]
+    pub ui_state: UIState,
+}
+
+impl PreferenceProfile {
+    /// A profile of the given preferences and layout, without the
+    /// machine-specific preferences
+    pub fn new(preferences: &AppPreferences, ui_state: &UIState) -> Self {
+        Self {
+            preferences: AppPreferences {
+                preferred_gpu_index: None,
+                preferred_gpu_name: None,
+                window_width: None,
+                window_height: None,
+                capture_directory: None,
+                ui_scale: 0.0,
+                ..preferences.clone()
+            },
+            ui_state: ui_state.clone(),
+        }
+    }
+
+    /// The profile's preferences with the machine-specific ones taken from `local`
+    pub fn merged_preferences(&self, local: &AppPreferences) -> AppPreferences {
+        AppPreferences {
+            preferred_gpu_index: local.preferred_gpu_index,
+            preferred_gpu_name: local.preferred_gpu_name.clone(),
+            window_width: local.window_width,
+            window_height: local.window_height,
+            capture_directory: local.capture_directory.clone(),
+            ui_scale: local.ui_scale,
+            ..self.preferences.clone()
+        }
+    }
+
+    /// Write the profile to a YAML file (user chooses location). Returns the
+    /// path written, or `None` if the dialog was cancelled.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn export_to_file(&self) -> Result<Option<std::path::PathBuf>, Box<dyn std::error::Error>> {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("YAML", &["yaml", "yml"])
+            .set_file_name(PROFILE_FILE_NAME)
+            .save_file()
+        else {
+            return Ok(None);
+        };
+        std::fs::write(&path, serde_yaml::to_string(self)?)?;
+        log::info!("Profile exported to {}", path.display());
+        Ok(Some(path))
+    }
+
+    /// Read a profile from a YAML file (user chooses file), or `None` if the
+    /// dialog was cancelled
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn import_from_file() -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("YAML", &["yaml", "yml"])
+            .set_title("Import Profile")
+            .pick_file()
+        else {
+            return Ok(None);
+        };
+        let yaml = std::fs::read_to_string(&path)?;
+        let profile = serde_yaml::from_str(&yaml)
+            .map_err(|e| format!("{} is not a valid profile: {}", path.display(), e))?;
+        log::info!("Profile imported from {}", path.display());
+        Ok(Some(profile))
+    }
+}
@@ -0,0 +1,246 @@
+//! Recipe card: a short human-readable summary of the current settings
+//!
+//! Lighter than the JSON export and meant for pasting into a forum post. Only
+//! the settings that matter for the current fractal type and the effects that
+//! are switched on are listed.
+
+use super::{
+    ColorMode, ColorPalette, FogMode, FractalType, InteriorColorMode, ProceduralPalette,
+    RenderMode, Settings, SymmetryMode, UpAxis,
+};
+use std::fmt::Write;
+
+impl Settings {
+    /// Format the settings as a plain-text recipe card
+    pub fn recipe_card(&self) -> String {
+        let mut card = String::new();
+        // Writing to a String can't fail
+        let _ = self.write_recipe_card(&mut card);
+        card
+    }
+
+    /// The pipeline the settings render in: 3D for a 2D fractal shown as a relief
+    fn recipe_render_mode(&self) -> RenderMode {
+        if self.relief_3d && self.fractal_type.supports_relief() {
+            RenderMode::ThreeD
+        } else {
+            self.fractal_type.render_mode()
+        }
+    }
+
+    fn write_recipe_card(&self, card: &mut String) -> std::fmt::Result {
+        let render_mode = self.recipe_render_mode();
+        let relief = render_mode != self.fractal_type.render_mode();
+        writeln!(card, "🌀 par-fractal {} recipe", env!("CARGO_PKG_VERSION"))?;
+        writeln!(
+            card,
+            "Fractal: {:?} ({})",
+            self.fractal_type,
+            match (render_mode, relief) {
+                (RenderMode::TwoD, _) => "2D",
+                (RenderMode::ThreeD, false) => "3D",
+                (RenderMode::ThreeD, true) => "2D as 3D relief",
+            }
+        )?;
+        writeln!(
+            card,
+            "Iterations: {} | Power: {}",
+            self.max_iterations, self.power
+        )?;
+        match self.fractal_type {
+            FractalType::Julia2D | FractalType::JuliaSet3D => {
+                writeln!(card, "Julia c: {} + {}i", self.julia_c[0], self.julia_c[1])?;
+            }
+            FractalType::Phoenix2D => {
+                writeln!(
+                    card,
+                    "Phoenix p: {} + {}i",
+                    self.phoenix_p[0], self.phoenix_p[1]
+                )?;
+            }
+            FractalType::Collatz2D => {
+                writeln!(
+                    card,
+                    "Collatz map: {}n + {}",
+                    self.collatz_params[0], self.collatz_params[1]
+                )?;
+            }
+            _ => {}
+        }
+
+        // Coloring
+        let palette = if self.procedural_palette != ProceduralPalette::None {
+            format!("{} (procedural)", self.procedural_palette.name())
+        } else {
+            ColorPalette::ALL
+                .get(self.palette_index)
+                .map_or("Custom", |p| p.name)
+                .to_string()
+        };
+        writeln!(card, "Color: {:?} | Palette: {}", self.color_mode, palette)?;
+        if self.color_mode == ColorMode::OrbitTrapRadial
+            || self.color_mode == ColorMode::OrbitTrapXYZ
+        {
+            writeln!(card, "Orbit trap scale: {}", self.orbit_trap_scale)?;
+            if self.orbit_trap_offset != [0.0; 3] {
+                writeln!(card, "Orbit trap offset: {:.3?}", self.orbit_trap_offset)?;
+            }
+        }
+
+        match render_mode {
+            RenderMode::TwoD => {
+                writeln!(
+                    card,
+                    "View: center ({}, {}) | zoom {}",
+                    self.center_2d[0], self.center_2d[1], self.zoom_2d
+                )?;
+                writeln!(card, "Interior: {}", self.interior_color_mode.name())?;
+                if self.interior_color_mode == InteriorColorMode::Distance
+                    && self.interior_distance_lighting
+                {
+                    writeln!(card, "Interior lighting: on")?;
+                }
+            }
+            RenderMode::ThreeD => {
+                writeln!(
+                    card,
+                    "Camera: position {:.3?} | target {:.3?} | FOV {}°",
+                    self.camera_position, self.camera_target, self.camera_fov
+                )?;
+                if self.up_axis != UpAxis::Y {
+                    writeln!(card, "Up axis: {}", self.up_axis.name())?;
+                }
+                if relief {
+                    writeln!(
+                        card,
+                        "Relief: center ({}, {}) | zoom {} | height {}",
+                        self.center_2d[0], self.center_2d[1], self.zoom_2d, self.relief_height
+                    )?;
+                } else {
+                    writeln!(
+                        card,
+                        "Shape: scale {} | fold {} | min radius {}",
+                        self.fractal_scale, self.fractal_fold, self.fractal_min_radius
+                    )?;
+                }
+                if self.fractal_type.uses_ifs_offset() && self.ifs_offset != [1.0; 3] {
+                    writeln!(card, "IFS offset: {:.3?}", self.ifs_offset)?;
+                }
+                let secondary = &self.secondary_fractal;
+                if secondary.enabled && !relief {
+                    writeln!(
+                        card,
+                        "Composited with: {:?} | position {:.3?} | rotation {:.1?}° | scale {}",
+                        secondary.fractal_type,
+                        secondary.position,
+                        secondary.rotation,
+                        secondary.scale
+                    )?;
+                }
+                writeln!(
+                    card,
+                    "Ray marching: {} steps | min distance {} | detail falloff {}",
+                    self.max_steps, self.min_distance, self.detail_falloff
+                )?;
+                writeln!(
+                    card,
+                    "Lighting: {:?} | intensity {} | ambient {} | azimuth {}° | elevation {}°",
+                    self.shading_model,
+                    self.light_intensity,
+                    self.ambient_light,
+                    self.light_azimuth,
+                    self.light_elevation
+                )?;
+                if self.specular_color != [1.0; 3] || self.specular_intensity != 1.0 {
+                    let [r, g, b] = self.specular_color;
+                    writeln!(
+                        card,
+                        "Specular: color ({}, {}, {}) | intensity {}",
+                        r, g, b, self.specular_intensity
+                    )?;
+                }
+            }
+        }
+
+        let effects = self.recipe_effects();
+        if effects.is_empty() {
+            writeln!(card, "Effects: none")?;
+        } else {
+            writeln!(card, "Effects: {}", effects.join(" | "))?;
+        }
+        Ok(())
+    }
+
+    /// Short descriptions of the effects that are switched on
+    fn recipe_effects(&self) -> Vec<String> {
+        let mut effects = Vec::new();
+        if self.recipe_render_mode() == RenderMode::ThreeD {
+            if self.ambient_occlusion {
+                effects.push(format!("AO {}", self.ao_intensity));
+            }
+            match self.shadow_mode {
+                1 => effects.push("hard shadows".to_string()),
+                2 => effects.push(format!(
+                    "soft shadows (light {}°, softness {})",
+                    self.light_size, self.shadow_softness
+                )),
+                _ => {}
+            }
+            if self.depth_of_field {
+                effects.push(format!(
+                    "DOF focal {} aperture {}",
+                    self.dof_focal_length, self.dof_aperture
+                ));
+            }
+            if self.fog_enabled {
+                effects.push(match self.fog_mode {
+                    FogMode::Linear => format!("Linear fog {} to {}", self.fog_start, self.fog_end),
+                    mode if self.fog_start > 0.0 => format!(
+                        "{:?} fog {} from {}",
+                        mode, self.fog_density, self.fog_start
+                    ),
+                    mode => format!("{:?} fog {}", mode, self.fog_density),
+                });
+            }
+            if self.show_floor {
+                effects.push("floor".to_string());
+            }
+            if self.show_distance_contours {
+                effects.push(format!("contours every {}", self.distance_contour_spacing));
+            }
+        }
+        if self.bloom_enabled {
+            effects.push(format!(
+                "bloom {} @ {}",
+                self.bloom_intensity, self.bloom_threshold
+            ));
+        }
+        if self.vignette_enabled {
+            effects.push(format!("vignette {}", self.vignette_intensity));
+        }
+        if self.porthole_enabled {
+            effects.push(format!(
+                "{} porthole {}",
+                if self.porthole_oval { "oval" } else { "round" },
+                self.porthole_radius
+            ));
+        }
+        if self.symmetry_mode != SymmetryMode::Off {
+            effects.push(format!("{} symmetry", self.symmetry_mode.name()));
+        }
+        let graded = self.brightness != 1.0
+            || self.contrast != 1.0
+            || self.saturation != 1.0
+            || self.hue_shift != 0.0;
+        if graded {
+            effects.push(format!(
+                "grading brightness {} contrast {} saturation {} hue {}",
+                self.brightness, self.contrast, self.saturation, self.hue_shift
+            ));
+        }
+        if self.fxaa_enabled {
+            effects.push("FXAA".to_string());
+        }
+        effects
+    }
+}
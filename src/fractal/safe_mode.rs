@@ -0,0 +1,70 @@
+use super::FractalParams;
+
+/// Maximum ray march steps allowed while safe mode is enabled
+pub const SAFE_MODE_MAX_STEPS: u32 = 300;
+/// Maximum soft shadow samples allowed while safe mode is enabled
+pub const SAFE_MODE_MAX_SHADOW_SAMPLES: u32 = 64;
+/// Maximum width or height of a high-resolution capture while safe mode is enabled
+pub const SAFE_MODE_MAX_CAPTURE_DIMENSION: u32 = 3840;
+
+impl FractalParams {
+    /// Clamp GPU-heavy quality settings to the safe mode limits.
+    ///
+    /// LOD quality presets are clamped as well so they cannot push the values back
+    /// over the limit on the next frame. Returns one warning message per setting
+    /// that had to be reduced, suitable for showing as a toast.
+    pub fn apply_safe_mode_limits(&mut self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if self.max_steps > SAFE_MODE_MAX_STEPS {
+            warnings.push(format!(
+                "🛡 Safe mode: max steps limited to {} (requested {})",
+                SAFE_MODE_MAX_STEPS, self.max_steps
+            ));
+            self.max_steps = SAFE_MODE_MAX_STEPS;
+        }
+
+        if self.shadow_samples > SAFE_MODE_MAX_SHADOW_SAMPLES {
+            warnings.push(format!(
+                "🛡 Safe mode: shadow samples limited to {} (requested {})",
+                SAFE_MODE_MAX_SHADOW_SAMPLES, self.shadow_samples
+            ));
+            self.shadow_samples = SAFE_MODE_MAX_SHADOW_SAMPLES;
+        }
+
+        for preset in self.lod_config.quality_presets.iter_mut() {
+            preset.max_steps = preset.max_steps.min(SAFE_MODE_MAX_STEPS);
+            preset.shadow_samples = preset.shadow_samples.min(SAFE_MODE_MAX_SHADOW_SAMPLES);
+        }
+
+        warnings
+    }
+
+    /// A copy with the safe mode limits applied and a warning per reduced
+    /// setting, or None when the settings are already within them
+    pub fn safe_mode_limited(&self) -> Option<(FractalParams, Vec<String>)> {
+        if self.max_steps <= SAFE_MODE_MAX_STEPS
+            && self.shadow_samples <= SAFE_MODE_MAX_SHADOW_SAMPLES
+        {
+            return None;
+        }
+        let mut limited = self.clone();
+        let warnings = limited.apply_safe_mode_limits();
+        Some((limited, warnings))
+    }
+}
+
+/// Clamp a capture resolution to the safe mode limit, preserving its aspect ratio.
+///
+/// Returns the (possibly reduced) resolution and whether it had to be clamped.
+pub fn clamp_capture_resolution(width: u32, height: u32) -> ((u32, u32), bool) {
+    let largest = width.max(height);
+    if largest <= SAFE_MODE_MAX_CAPTURE_DIMENSION {
+        return ((width, height), false);
+    }
+
+    let scale = SAFE_MODE_MAX_CAPTURE_DIMENSION as f64 / largest as f64;
+    let clamped_width = ((width as f64 * scale).round() as u32).max(1);
+    let clamped_height = ((height as f64 * scale).round() as u32).max(1);
+    ((clamped_width, clamped_height), true)
+}
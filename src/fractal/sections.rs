@@ -0,0 +1,180 @@
+use super::FractalParams;
+
+/// Control panel sections whose parameters can be reset on their own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamSection {
+    Color,
+    Params2D,
+    Params3D,
+    RayMarching,
+    Shading,
+    Lighting,
+    Effects,
+    Floor,
+}
+
+impl ParamSection {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ParamSection::Color => "Color & Visualization",
+            ParamSection::Params2D => "2D Parameters",
+            ParamSection::Params3D => "3D Parameters",
+            ParamSection::RayMarching => "Ray Marching",
+            ParamSection::Shading => "Shading",
+            ParamSection::Lighting => "Lighting",
+            ParamSection::Effects => "Effects",
+            ParamSection::Floor => "Floor",
+        }
+    }
+}
+
+impl FractalParams {
+    /// Default parameters for the current fractal type: `FractalParams::default()`
+    /// with the fractal-specific values `switch_fractal` applies
+    pub fn fractal_defaults(&self) -> FractalParams {
+        let mut defaults = FractalParams::default();
+        defaults.switch_fractal(self.fractal_type);
+        defaults
+    }
+
+    /// Reset the fields shown in one control panel section to the current
+    /// fractal's defaults, leaving the fractal type, view and other sections alone
+    pub fn reset_section(&mut self, section: ParamSection) {
+        let d = self.fractal_defaults();
+        match section {
+            ParamSection::Color => {
+                self.color_mode = d.color_mode;
+                self.palette = d.palette;
+                self.palette_index = d.palette_index;
+                self.palette_offset = d.palette_offset;
+                self.orbit_trap_scale = d.orbit_trap_scale;
+                self.orbit_trap_offset = d.orbit_trap_offset;
+                self.orbit_trap_image_path = d.orbit_trap_image_path;
+                self.channel_r = d.channel_r;
+                self.channel_g = d.channel_g;
+                self.channel_b = d.channel_b;
+                self.procedural_palette = d.procedural_palette;
+                self.procedural_brightness = d.procedural_brightness;
+                self.procedural_contrast = d.procedural_contrast;
+                self.procedural_frequency = d.procedural_frequency;
+                self.procedural_phase = d.procedural_phase;
+                self.interior_color_mode = d.interior_color_mode;
+                self.interior_distance_lighting = d.interior_distance_lighting;
+                self.distance_estimation_2d = d.distance_estimation_2d;
+                self.show_distance_contours = d.show_distance_contours;
+                self.distance_contour_spacing = d.distance_contour_spacing;
+            }
+            ParamSection::Params2D => {
+                self.max_iterations = d.max_iterations;
+                self.power = d.power;
+                self.julia_c = d.julia_c;
+                self.phoenix_p = d.phoenix_p;
+                self.collatz_params = d.collatz_params;
+                self.newton_roots = d.newton_roots;
+                self.high_precision_2d = d.high_precision_2d;
+                self.edge_aa_2d = d.edge_aa_2d;
+                self.edge_aa_grid = d.edge_aa_grid;
+                self.edge_aa_threshold = d.edge_aa_threshold;
+                self.seamless_tile = d.seamless_tile;
+                self.attractor_accumulation_enabled = d.attractor_accumulation_enabled;
+                self.attractor_iterations_per_frame = d.attractor_iterations_per_frame;
+                self.attractor_log_scale = d.attractor_log_scale;
+                self.attractor_color_mode = d.attractor_color_mode;
+                self.attractor_max_iterations = d.attractor_max_iterations;
+                self.attractor_hold_while_recording = d.attractor_hold_while_recording;
+                self.attractor_recording_clear_interval = d.attractor_recording_clear_interval;
+                // Accumulated density no longer matches the parameters
+                self.attractor_pending_clear = true;
+            }
+            ParamSection::Params3D => {
+                self.max_iterations = d.max_iterations;
+                self.power = d.power;
+                self.julia_c = d.julia_c;
+                self.fractal_scale = d.fractal_scale;
+                self.fractal_fold = d.fractal_fold;
+                self.fractal_min_radius = d.fractal_min_radius;
+                self.ifs_offset = d.ifs_offset;
+                self.relief_height = d.relief_height;
+                self.secondary_fractal = d.secondary_fractal;
+            }
+            ParamSection::RayMarching => {
+                self.use_adaptive_step = d.use_adaptive_step;
+                self.fixed_step_size = d.fixed_step_size;
+                self.step_multiplier = d.step_multiplier;
+                self.max_steps = d.max_steps;
+                self.min_distance = d.min_distance;
+                self.detail_falloff = d.detail_falloff;
+                self.max_distance = d.max_distance;
+                self.double_precision_3d = d.double_precision_3d;
+            }
+            ParamSection::Shading => {
+                self.shading_model = d.shading_model;
+                self.roughness = d.roughness;
+                self.metallic = d.metallic;
+                self.albedo = d.albedo;
+                self.specular_color = d.specular_color;
+                self.specular_intensity = d.specular_intensity;
+            }
+            ParamSection::Lighting => {
+                self.light_intensity = d.light_intensity;
+                self.ambient_light = d.ambient_light;
+                self.light_azimuth = d.light_azimuth;
+                self.light_elevation = d.light_elevation;
+                self.shadow_mode = d.shadow_mode;
+                self.shadow_softness = d.shadow_softness;
+                self.light_size = d.light_size;
+                self.shadow_max_distance = d.shadow_max_distance;
+                self.shadow_samples = d.shadow_samples;
+                self.shadow_step_factor = d.shadow_step_factor;
+                self.ambient_occlusion = d.ambient_occlusion;
+                self.ao_intensity = d.ao_intensity;
+                self.ao_step_size = d.ao_step_size;
+            }
+            ParamSection::Effects => {
+                self.depth_of_field = d.depth_of_field;
+                self.dof_focal_length = d.dof_focal_length;
+                self.dof_aperture = d.dof_aperture;
+                self.dof_samples = d.dof_samples;
+                self.bokeh_blades = d.bokeh_blades;
+                self.fog_enabled = d.fog_enabled;
+                self.fog_mode = d.fog_mode;
+                self.fog_density = d.fog_density;
+                self.fog_start = d.fog_start;
+                self.fog_end = d.fog_end;
+                self.fog_color = d.fog_color;
+                self.brightness = d.brightness;
+                self.contrast = d.contrast;
+                self.saturation = d.saturation;
+                self.hue_shift = d.hue_shift;
+                self.auto_exposure = d.auto_exposure;
+                self.auto_exposure_speed = d.auto_exposure_speed;
+                self.vignette_enabled = d.vignette_enabled;
+                self.vignette_intensity = d.vignette_intensity;
+                self.vignette_radius = d.vignette_radius;
+                self.porthole_enabled = d.porthole_enabled;
+                self.porthole_radius = d.porthole_radius;
+                self.porthole_feather = d.porthole_feather;
+                self.porthole_oval = d.porthole_oval;
+                self.porthole_color = d.porthole_color;
+                self.symmetry_mode = d.symmetry_mode;
+                self.symmetry_segments = d.symmetry_segments;
+                self.bloom_enabled = d.bloom_enabled;
+                self.bloom_threshold = d.bloom_threshold;
+                self.bloom_intensity = d.bloom_intensity;
+                self.bloom_radius = d.bloom_radius;
+                self.bloom_quality = d.bloom_quality;
+                self.fxaa_enabled = d.fxaa_enabled;
+            }
+            ParamSection::Floor => {
+                self.show_floor = d.show_floor;
+                self.floor_height = d.floor_height;
+                self.floor_color1 = d.floor_color1;
+                self.floor_color2 = d.floor_color2;
+                self.floor_reflections = d.floor_reflections;
+                self.floor_reflection_strength = d.floor_reflection_strength;
+                self.floor_checker_scale = d.floor_checker_scale;
+                self.floor_checker_rotation = d.floor_checker_rotation;
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use super::ui_state::*;
 use super::{
-    ChannelSource, ColorMode, FogMode, FractalType, ProceduralPalette, ShadingModel, UIState,
+    AttractorColorMode, BloomQuality, CaptureAntialiasing, ChannelSource, ColorMode, FogMode,
+    FractalType, InteriorColorMode, ProceduralPalette, SecondaryFractal, ShadingModel, StereoMode,
+    SymmetryMode, UIState, UpAxis,
 };
 use crate::lod::LODConfig;
 use serde::{Deserialize, Serialize};
@@ -12,6 +14,10 @@ pub struct Settings {
     pub color_mode: ColorMode,
     pub palette_index: usize,
     pub orbit_trap_scale: f32,
+    #[serde(default)]
+    pub orbit_trap_offset: [f32; 3],
+    #[serde(default)]
+    pub orbit_trap_image_path: Option<String>,
     pub channel_r: ChannelSource,
     pub channel_g: ChannelSource,
     pub channel_b: ChannelSource,
@@ -37,23 +43,73 @@ pub struct Settings {
     pub custom_width: String,
     #[serde(default = "default_custom_height")]
     pub custom_height: String,
+    #[serde(default)]
+    pub lock_capture_aspect: bool,
+    #[serde(default = "default_capture_aspect")]
+    pub capture_aspect: f32,
+    #[serde(default)]
+    pub capture_antialiasing: CaptureAntialiasing,
+    #[serde(default = "default_accumulation_samples")]
+    pub accumulation_samples: u32,
+    #[serde(default)]
+    pub transparent_background: bool,
+    #[serde(default)]
+    pub capture_16bit: bool,
+    #[serde(default = "default_capture_gamma")]
+    pub capture_gamma: f32,
 
     // 2D specific
     pub center_2d: [f64; 2],
     pub zoom_2d: f32,
+    #[serde(default)]
+    pub high_precision_2d: bool,
+    #[serde(default)]
+    pub edge_aa_2d: bool,
+    #[serde(default = "default_edge_aa_grid")]
+    pub edge_aa_grid: u32,
+    #[serde(default = "default_edge_aa_threshold")]
+    pub edge_aa_threshold: f32,
+    #[serde(default)]
+    pub seamless_tile: bool,
+    #[serde(default)]
+    pub relief_3d: bool,
+    #[serde(default = "default_relief_height")]
+    pub relief_height: f32,
+    #[serde(default)]
+    pub secondary_fractal: SecondaryFractal,
+    #[serde(default)]
+    pub interior_color_mode: InteriorColorMode,
+    #[serde(default)]
+    pub interior_distance_lighting: bool,
+    #[serde(default)]
+    pub distance_estimation_2d: bool,
+    #[serde(default)]
+    pub show_distance_contours: bool,
+    #[serde(default = "default_distance_contour_spacing")]
+    pub distance_contour_spacing: f32,
     pub julia_c: [f32; 2],
+    #[serde(default = "default_phoenix_p")]
+    pub phoenix_p: [f32; 2],
+    #[serde(default = "default_collatz_params")]
+    pub collatz_params: [f32; 2],
+    #[serde(default = "default_newton_roots")]
+    pub newton_roots: Vec<[f32; 2]>,
     pub max_iterations: u32,
 
     // 3D specific
     pub power: f32,
     pub max_steps: u32,
     pub min_distance: f32,
+    #[serde(default)]
+    pub detail_falloff: f32,
     pub ambient_occlusion: bool,
     pub ao_intensity: f32,
     pub ao_step_size: f32,
     #[serde(default = "default_shadow_mode", alias = "soft_shadows")]
     pub shadow_mode: u32, // 0=off, 1=hard, 2=soft; alias preserves old bool field
     pub shadow_softness: f32,
+    #[serde(default = "default_light_size")]
+    pub light_size: f32, // Angular diameter in degrees
     pub shadow_max_distance: f32,
     pub shadow_samples: u32,
     pub shadow_step_factor: f32,
@@ -62,16 +118,24 @@ pub struct Settings {
     pub dof_aperture: f32,
     #[serde(default = "default_dof_samples")]
     pub dof_samples: u32,
+    #[serde(default)]
+    pub bokeh_blades: u32,
 
     // 3D fractal parameters
     pub fractal_scale: f32,
     pub fractal_fold: f32,
     pub fractal_min_radius: f32,
+    #[serde(default = "default_ifs_offset")]
+    pub ifs_offset: [f32; 3],
 
     // Material properties
     pub roughness: f32,
     pub metallic: f32,
     pub albedo: [f32; 3],
+    #[serde(default = "default_specular_color")]
+    pub specular_color: [f32; 3],
+    #[serde(default = "default_specular_intensity")]
+    pub specular_intensity: f32,
 
     // Lighting
     pub light_intensity: f32,
@@ -80,6 +144,9 @@ pub struct Settings {
     pub light_azimuth: f32, // Horizontal angle in degrees (0-360)
     #[serde(default = "default_light_elevation")]
     pub light_elevation: f32, // Vertical angle in degrees (0-90)
+    /// Older settings and presets were made with the Y-up convention
+    #[serde(default)]
+    pub up_axis: UpAxis,
 
     // Floor
     pub show_floor: bool,
@@ -90,11 +157,19 @@ pub struct Settings {
     pub floor_reflections: bool,
     #[serde(default = "default_reflection_strength")]
     pub floor_reflection_strength: f32,
+    #[serde(default = "default_one")]
+    pub floor_checker_scale: f32,
+    #[serde(default)]
+    pub floor_checker_rotation: f32,
 
     // Fog
     pub fog_enabled: bool,
     pub fog_mode: FogMode,
     pub fog_density: f32,
+    #[serde(default)]
+    pub fog_start: f32,
+    #[serde(default)]
+    pub fog_end: f32,
     pub fog_color: [f32; 3],
 
     // Ray marching
@@ -102,11 +177,19 @@ pub struct Settings {
     pub fixed_step_size: f32,
     pub step_multiplier: f32,
     pub max_distance: f32,
+    #[serde(default)]
+    pub double_precision_3d: bool,
 
     // Camera (3D mode)
     pub camera_position: [f32; 3],
     pub camera_target: [f32; 3],
     pub camera_speed: f32,
+    #[serde(default)]
+    pub camera_inertia: bool,
+    #[serde(default = "default_camera_acceleration")]
+    pub camera_acceleration: f32,
+    #[serde(default = "default_camera_damping")]
+    pub camera_damping: f32,
     pub camera_fov: f32,
 
     // Camera orbit
@@ -115,6 +198,12 @@ pub struct Settings {
     #[serde(default = "default_orbit_speed")]
     pub orbit_speed: f32,
 
+    // Stereoscopic 3D output
+    #[serde(default)]
+    pub stereo_mode: StereoMode,
+    #[serde(default = "default_stereo_eye_separation")]
+    pub stereo_eye_separation: f32,
+
     // Post-processing
     #[serde(default = "default_one")]
     pub brightness: f32,
@@ -124,6 +213,10 @@ pub struct Settings {
     pub saturation: f32,
     #[serde(default)]
     pub hue_shift: f32,
+    #[serde(default)]
+    pub auto_exposure: bool,
+    #[serde(default = "default_auto_exposure_speed")]
+    pub auto_exposure_speed: f32,
 
     #[serde(default)]
     pub vignette_enabled: bool,
@@ -132,6 +225,22 @@ pub struct Settings {
     #[serde(default = "default_vignette_radius")]
     pub vignette_radius: f32,
 
+    #[serde(default)]
+    pub porthole_enabled: bool,
+    #[serde(default = "default_porthole_radius")]
+    pub porthole_radius: f32,
+    #[serde(default = "default_porthole_feather")]
+    pub porthole_feather: f32,
+    #[serde(default)]
+    pub porthole_oval: bool,
+    #[serde(default)]
+    pub porthole_color: [f32; 3],
+
+    #[serde(default)]
+    pub symmetry_mode: SymmetryMode,
+    #[serde(default = "default_symmetry_segments")]
+    pub symmetry_segments: u32,
+
     #[serde(default)]
     pub bloom_enabled: bool,
     #[serde(default = "default_bloom_threshold")]
@@ -140,6 +249,8 @@ pub struct Settings {
     pub bloom_intensity: f32,
     #[serde(default = "default_bloom_radius")]
     pub bloom_radius: f32,
+    #[serde(default)]
+    pub bloom_quality: BloomQuality,
 
     #[serde(default)]
     pub fxaa_enabled: bool,
@@ -155,6 +266,20 @@ pub struct Settings {
     pub attractor_iterations_per_frame: u32,
     #[serde(default = "default_attractor_log_scale")]
     pub attractor_log_scale: f32,
+    #[serde(default)]
+    pub attractor_color_mode: AttractorColorMode,
+    #[serde(default)]
+    pub attractor_hold_while_recording: bool,
+    #[serde(default)]
+    pub attractor_recording_clear_interval: f32,
+}
+
+fn default_specular_color() -> [f32; 3] {
+    [1.0, 1.0, 1.0]
+}
+
+fn default_specular_intensity() -> f32 {
+    1.0
 }
 
 fn default_attractor_iterations_per_frame() -> u32 {
@@ -165,6 +290,22 @@ fn default_attractor_log_scale() -> f32 {
     1.0
 }
 
+fn default_stereo_eye_separation() -> f32 {
+    0.1
+}
+
+fn default_phoenix_p() -> [f32; 2] {
+    super::DEFAULT_PHOENIX_P
+}
+
+fn default_collatz_params() -> [f32; 2] {
+    super::DEFAULT_COLLATZ_PARAMS
+}
+
+fn default_newton_roots() -> Vec<[f32; 2]> {
+    super::newton_unity_roots(3)
+}
+
 fn default_procedural_brightness() -> [f32; 3] {
     [0.5, 0.5, 0.5]
 }
@@ -0,0 +1,96 @@
+//! Field-by-field comparison of two sets of settings
+//!
+//! Used to show what loading or overwriting a preset would change. Settings
+//! are compared through their serialized form, so every field (including ones
+//! added later) is covered without a hand-written list. Nested structures are
+//! walked and reported as dotted paths such as `lod_config.enabled`.
+
+use super::Settings;
+use serde_yaml::Value;
+
+/// Fields left out of the comparison: the panel layout isn't part of a preset
+const IGNORED_FIELDS: &[&str] = &["ui_state"];
+
+/// One field whose value differs between two settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsChange {
+    /// Field name, dotted for nested fields
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+impl Settings {
+    /// The fields that differ in `other`, with this side's value as `old`
+    pub fn diff(&self, other: &Settings) -> Vec<SettingsChange> {
+        let mut changes = Vec::new();
+        // Settings only hold plain data, so serializing can't fail
+        if let (Ok(old), Ok(new)) = (serde_yaml::to_value(self), serde_yaml::to_value(other)) {
+            diff_values("", &old, &new, &mut changes);
+        }
+        changes
+    }
+}
+
+fn diff_values(path: &str, old: &Value, new: &Value, changes: &mut Vec<SettingsChange>) {
+    if old == new {
+        return;
+    }
+    if let (Value::Mapping(old_map), Value::Mapping(new_map)) = (old, new) {
+        let mut keys: Vec<&Value> = old_map.keys().collect();
+        keys.extend(new_map.keys().filter(|key| !old_map.contains_key(*key)));
+        for key in keys {
+            let name = format_value(key);
+            if path.is_empty() && IGNORED_FIELDS.contains(&name.as_str()) {
+                continue;
+            }
+            let field = if path.is_empty() {
+                name
+            } else {
+                format!("{}.{}", path, name)
+            };
+            diff_values(
+                &field,
+                old_map.get(key).unwrap_or(&Value::Null),
+                new_map.get(key).unwrap_or(&Value::Null),
+                changes,
+            );
+        }
+        return;
+    }
+    changes.push(SettingsChange {
+        field: path.to_string(),
+        old: format_value(old),
+        new: format_value(new),
+    });
+}
+
+/// A compact one-line rendering of a value
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::Null => "none".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => match n.as_f64() {
+            // f32 fields print without the noise of their f64 widening
+            Some(f) if n.is_f64() && (f as f32) as f64 == f => (f as f32).to_string(),
+            _ => n.to_string(),
+        },
+        Value::String(s) => s.clone(),
+        Value::Sequence(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(format_value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Mapping(map) => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(k, v)| format!("{}: {}", format_value(k), format_value(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Tagged(tagged) => format!("{} {}", tagged.tag, format_value(&tagged.value)),
+    }
+}
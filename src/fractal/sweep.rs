@@ -0,0 +1,165 @@
+//! Batch parameter sweeps: render one image per value of a parameter, for
+//! contact sheets comparing what the parameter does
+
+use super::{FractalParams, RenderMode};
+
+/// A parameter a batch sweep can vary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SweepParam {
+    #[default]
+    Power,
+    MaxIterations,
+    JuliaReal,
+    JuliaImag,
+    Scale,
+    Fold,
+    MinRadius,
+    Zoom2D,
+    HueShift,
+    LightAzimuth,
+    LightElevation,
+    Roughness,
+    Metallic,
+}
+
+impl SweepParam {
+    pub const ALL: &'static [SweepParam] = &[
+        SweepParam::Power,
+        SweepParam::MaxIterations,
+        SweepParam::JuliaReal,
+        SweepParam::JuliaImag,
+        SweepParam::Scale,
+        SweepParam::Fold,
+        SweepParam::MinRadius,
+        SweepParam::Zoom2D,
+        SweepParam::HueShift,
+        SweepParam::LightAzimuth,
+        SweepParam::LightElevation,
+        SweepParam::Roughness,
+        SweepParam::Metallic,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SweepParam::Power => "Power",
+            SweepParam::MaxIterations => "Max Iterations",
+            SweepParam::JuliaReal => "Julia C (Real)",
+            SweepParam::JuliaImag => "Julia C (Imag)",
+            SweepParam::Scale => "Scale",
+            SweepParam::Fold => "Fold",
+            SweepParam::MinRadius => "Min Radius",
+            SweepParam::Zoom2D => "Zoom",
+            SweepParam::HueShift => "Hue Shift",
+            SweepParam::LightAzimuth => "Light Azimuth",
+            SweepParam::LightElevation => "Light Elevation",
+            SweepParam::Roughness => "Roughness",
+            SweepParam::Metallic => "Metallic",
+        }
+    }
+
+    /// Short name used in sweep filenames
+    pub fn filename_safe_name(&self) -> &'static str {
+        match self {
+            SweepParam::Power => "power",
+            SweepParam::MaxIterations => "iterations",
+            SweepParam::JuliaReal => "julia_real",
+            SweepParam::JuliaImag => "julia_imag",
+            SweepParam::Scale => "scale",
+            SweepParam::Fold => "fold",
+            SweepParam::MinRadius => "min_radius",
+            SweepParam::Zoom2D => "zoom",
+            SweepParam::HueShift => "hue_shift",
+            SweepParam::LightAzimuth => "light_azimuth",
+            SweepParam::LightElevation => "light_elevation",
+            SweepParam::Roughness => "roughness",
+            SweepParam::Metallic => "metallic",
+        }
+    }
+
+    /// Whether varying this parameter changes the image in `mode`
+    pub fn applies_to(&self, mode: RenderMode) -> bool {
+        match self {
+            SweepParam::Zoom2D => mode == RenderMode::TwoD,
+            SweepParam::Scale
+            | SweepParam::Fold
+            | SweepParam::MinRadius
+            | SweepParam::LightAzimuth
+            | SweepParam::LightElevation
+            | SweepParam::Roughness
+            | SweepParam::Metallic => mode == RenderMode::ThreeD,
+            _ => true,
+        }
+    }
+
+    /// A useful range to start from, e.g. Mandelbulb power 2 to 16
+    pub fn default_range(&self) -> (f32, f32) {
+        match self {
+            SweepParam::Power => (2.0, 16.0),
+            SweepParam::MaxIterations => (16.0, 512.0),
+            SweepParam::JuliaReal | SweepParam::JuliaImag => (-1.0, 1.0),
+            SweepParam::Scale => (1.5, 3.0),
+            SweepParam::Fold => (0.5, 1.5),
+            SweepParam::MinRadius => (0.1, 1.0),
+            SweepParam::Zoom2D => (1.0, 100.0),
+            SweepParam::HueShift => (0.0, 1.0),
+            SweepParam::LightAzimuth => (0.0, 360.0),
+            SweepParam::LightElevation => (0.0, 90.0),
+            SweepParam::Roughness | SweepParam::Metallic => (0.0, 1.0),
+        }
+    }
+
+    pub fn get(&self, params: &FractalParams) -> f32 {
+        match self {
+            SweepParam::Power => params.power,
+            SweepParam::MaxIterations => params.max_iterations as f32,
+            SweepParam::JuliaReal => params.julia_c[0],
+            SweepParam::JuliaImag => params.julia_c[1],
+            SweepParam::Scale => params.fractal_scale,
+            SweepParam::Fold => params.fractal_fold,
+            SweepParam::MinRadius => params.fractal_min_radius,
+            SweepParam::Zoom2D => params.zoom_2d,
+            SweepParam::HueShift => params.hue_shift,
+            SweepParam::LightAzimuth => params.light_azimuth,
+            SweepParam::LightElevation => params.light_elevation,
+            SweepParam::Roughness => params.roughness,
+            SweepParam::Metallic => params.metallic,
+        }
+    }
+
+    pub fn set(&self, params: &mut FractalParams, value: f32) {
+        match self {
+            SweepParam::Power => params.power = value,
+            SweepParam::MaxIterations => params.max_iterations = value.round().max(1.0) as u32,
+            SweepParam::JuliaReal => params.julia_c[0] = value,
+            SweepParam::JuliaImag => params.julia_c[1] = value,
+            SweepParam::Scale => params.fractal_scale = value,
+            SweepParam::Fold => params.fractal_fold = value,
+            SweepParam::MinRadius => params.fractal_min_radius = value,
+            SweepParam::Zoom2D => params.zoom_2d = value,
+            SweepParam::HueShift => params.hue_shift = value,
+            SweepParam::LightAzimuth => params.light_azimuth = value,
+            SweepParam::LightElevation => params.light_elevation = value,
+            SweepParam::Roughness => params.roughness = value,
+            SweepParam::Metallic => params.metallic = value,
+        }
+    }
+}
+
+/// `count` values spaced evenly from `min` to `max`, both included
+pub fn sweep_values(min: f32, max: f32, count: u32) -> Vec<f32> {
+    if count < 2 {
+        return vec![min];
+    }
+    (0..count)
+        .map(|i| min + (max - min) * i as f32 / (count - 1) as f32)
+        .collect()
+}
+
+/// Columns and rows of a contact sheet holding `count` images: as square as
+/// possible, wider rather than taller
+pub fn contact_sheet_grid(count: u32) -> (u32, u32) {
+    let count = count.max(1);
+    let columns = (count as f32).sqrt().ceil() as u32;
+    let rows = count.div_ceil(columns);
+    (columns, rows)
+}
@@ -139,3 +139,1211 @@ fn test_3d_parameters_valid() {
     assert!(params.dof_focal_length > 0.0);
     assert!(params.dof_aperture > 0.0);
 }
+
+#[test]
+fn test_safe_mode_clamps_quality() {
+    let mut params = FractalParams {
+        max_steps: 512,
+        shadow_samples: 256,
+        ..Default::default()
+    };
+    let warnings = params.apply_safe_mode_limits();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(params.max_steps, SAFE_MODE_MAX_STEPS);
+    assert_eq!(params.shadow_samples, SAFE_MODE_MAX_SHADOW_SAMPLES);
+
+    // Already within limits: nothing to report
+    assert!(params.apply_safe_mode_limits().is_empty());
+}
+
+#[test]
+fn test_safe_mode_limits_a_copy() {
+    let params = FractalParams {
+        shadow_samples: 128,
+        ..Default::default()
+    };
+    let (limited, warnings) = params.safe_mode_limited().unwrap();
+    assert_eq!(limited.shadow_samples, SAFE_MODE_MAX_SHADOW_SAMPLES);
+    assert_eq!(warnings.len(), 1);
+    // The user's settings and LOD presets are left alone
+    assert_eq!(params.shadow_samples, 128);
+
+    let within = FractalParams {
+        max_steps: SAFE_MODE_MAX_STEPS,
+        shadow_samples: SAFE_MODE_MAX_SHADOW_SAMPLES,
+        ..Default::default()
+    };
+    assert!(within.safe_mode_limited().is_none());
+}
+
+#[test]
+fn test_safe_mode_capture_resolution() {
+    assert_eq!(clamp_capture_resolution(1920, 1080), ((1920, 1080), false));
+    assert_eq!(clamp_capture_resolution(7680, 4320), ((3840, 2160), true));
+}
+
+#[test]
+fn test_stereo_settings_round_trip() {
+    let params = FractalParams {
+        stereo_mode: StereoMode::Anaglyph,
+        stereo_eye_separation: 0.25,
+        ..Default::default()
+    };
+    let restored = FractalParams::from_settings(params.to_settings());
+    assert_eq!(restored.stereo_mode, StereoMode::Anaglyph);
+    assert_eq!(restored.stereo_eye_separation, 0.25);
+}
+
+#[test]
+fn test_extract_palette_sorted_by_luminance() {
+    // Two distinct color clusters: dark blue and bright yellow
+    let mut pixels = vec![Vec3::new(0.0, 0.0, 0.3); 500];
+    pixels.extend(vec![Vec3::new(1.0, 0.9, 0.1); 500]);
+
+    let colors = CustomPalette::extract_palette(&pixels);
+    let luma = |c: Vec3| c.dot(Vec3::new(0.299, 0.587, 0.114));
+    for pair in colors.windows(2) {
+        assert!(luma(pair[0]) <= luma(pair[1]));
+    }
+    assert!(colors[0].abs_diff_eq(Vec3::new(0.0, 0.0, 0.3), 1e-4));
+    assert!(colors[7].abs_diff_eq(Vec3::new(1.0, 0.9, 0.1), 1e-4));
+}
+
+#[test]
+fn test_custom_palette_from_hex_list() {
+    // Mixed separators, optional '#', quotes and junk tokens
+    let text = "\"#000000\", ffffff;\n#FF0000 not-a-color 12345";
+    let (palette, count) = CustomPalette::from_hex_list(text, "Pasted".to_string()).unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(palette.colors[0], [0.0, 0.0, 0.0]);
+    assert_eq!(palette.colors[7], [1.0, 0.0, 0.0]);
+
+    // Longer lists are resampled to 8, keeping both ends
+    let many: Vec<String> = (0..16).map(|i| format!("#{:02x}0000", i * 17)).collect();
+    let (palette, count) = CustomPalette::from_hex_list(&many.join(","), String::new()).unwrap();
+    assert_eq!(count, 16);
+    assert_eq!(palette.colors[0], [0.0, 0.0, 0.0]);
+    assert_eq!(palette.colors[7], [1.0, 0.0, 0.0]);
+
+    assert!(CustomPalette::from_hex_list("#abcdef", String::new()).is_err());
+}
+
+#[test]
+fn test_preferences_autosave_defaults_for_old_files() {
+    // Preference files written before auto-save options existed keep auto-saving
+    let prefs: AppPreferences = serde_yaml::from_str("safe_mode: false\n").unwrap();
+    assert!(prefs.autosave_enabled);
+    assert_eq!(prefs.autosave_interval_secs, 1.0);
+}
+
+#[test]
+fn test_newton_unity_roots() {
+    let roots = newton_unity_roots(3);
+    assert_eq!(roots.len(), 3);
+    // Every root of z^3 - 1 lies on the unit circle, starting at z = 1
+    assert!((roots[0][0] - 1.0).abs() < 1e-6 && roots[0][1].abs() < 1e-6);
+    for root in &roots {
+        assert!((root[0].hypot(root[1]) - 1.0).abs() < 1e-6);
+    }
+
+    assert_eq!(newton_unity_roots(1).len(), 2);
+    assert_eq!(newton_unity_roots(20).len(), NEWTON_MAX_ROOTS);
+}
+
+#[test]
+fn test_preferences_frame_pacing_defaults() {
+    // Older preference files keep the previous behavior: vsync, uncapped, no idle throttle
+    let prefs: AppPreferences = serde_yaml::from_str("safe_mode: true\n").unwrap();
+    assert_eq!(prefs.present_mode, PresentModeSetting::Fifo);
+    assert_eq!(prefs.surface_format, SurfaceFormatSetting::Auto);
+    assert_eq!(prefs.max_fps, 0);
+    assert!(!prefs.idle_throttle_enabled);
+    assert_eq!(prefs.idle_fps, 10);
+    assert!(prefs.zoom_to_cursor);
+    assert!(!prefs.navigation_momentum);
+    assert!(prefs.remember_fractal_coloring);
+    assert_eq!(prefs.tour_dwell_secs, 30.0);
+    assert_eq!(prefs.camera_transition_easing, CameraEasing::EaseInOut);
+    assert_eq!(prefs.camera_transition_secs, 1.5);
+    assert!(prefs.screenshot_supersample);
+    assert_eq!(prefs.filename_template, DEFAULT_FILENAME_TEMPLATE);
+    assert_eq!(prefs.visible_fractal_types, None);
+    assert_eq!(prefs.idle_timeout_secs, 0.0);
+    assert_eq!(prefs.capture_directory, None);
+    assert!(!prefs.burn_in_frame_number && !prefs.burn_in_timecode && !prefs.burn_in_parameters);
+}
+
+#[test]
+fn test_camera_easing_curves() {
+    for easing in CameraEasing::ALL {
+        assert_eq!(easing.apply(0.0), 0.0, "{}", easing.name());
+        assert!((easing.apply(1.0) - 1.0).abs() < 1e-6, "{}", easing.name());
+    }
+    // The default keeps the previous smoothstep curve
+    assert_eq!(CameraEasing::default().apply(0.25), 0.15625);
+    assert!(CameraEasing::EaseIn.apply(0.5) < 0.5);
+    assert!(CameraEasing::EaseOut.apply(0.5) > 0.5);
+    // Elastic overshoots before settling
+    assert!((0..100).any(|i| CameraEasing::Elastic.apply(i as f32 / 100.0) > 1.0));
+}
+
+#[test]
+fn test_preferences_blank_frame_defaults() {
+    // Warn about blank frames by default, but never change parameters unasked
+    let prefs: AppPreferences = serde_yaml::from_str("safe_mode: true\n").unwrap();
+    assert!(prefs.blank_frame_warning);
+    assert!(!prefs.blank_frame_auto_revert);
+}
+
+#[test]
+fn test_capture_directory_prefers_configured_folder() {
+    let configured = AppPreferences::resolve_capture_directory(Some("/tmp/fractal-captures"));
+    assert_eq!(
+        configured,
+        std::path::PathBuf::from("/tmp/fractal-captures")
+    );
+    // A blank setting falls back to the default location
+    let blank = AppPreferences::resolve_capture_directory(Some("  "));
+    assert_eq!(blank, AppPreferences::resolve_capture_directory(None));
+}
+
+#[test]
+fn test_zoom_2d_around_keeps_anchor_fixed() {
+    let mut params = FractalParams {
+        center_2d: [-0.743643887037151, 0.131825904205330],
+        zoom_2d: 1.0e5,
+        ..Default::default()
+    };
+    let aspect = 16.0 / 9.0;
+    let anchor = [0.6, -0.3];
+    let point_at = |p: &FractalParams| {
+        [
+            p.center_2d[0] + anchor[0] * 2.0 / p.zoom_2d as f64 * aspect,
+            p.center_2d[1] + anchor[1] * 2.0 / p.zoom_2d as f64,
+        ]
+    };
+
+    let before = point_at(&params);
+    params.zoom_2d_around(1.1, anchor, aspect);
+    let after = point_at(&params);
+    assert!((before[0] - after[0]).abs() < 1e-15);
+    assert!((before[1] - after[1]).abs() < 1e-15);
+
+    // A centered anchor leaves the center untouched
+    let center = params.center_2d;
+    params.zoom_2d_around(2.0, [0.0, 0.0], aspect);
+    assert_eq!(params.center_2d, center);
+}
+
+#[test]
+fn test_orbit_trap_image_limited_to_orbit_tracing_types() {
+    let mut params = FractalParams {
+        fractal_type: FractalType::Mandelbrot2D,
+        color_mode: ColorMode::OrbitTrapImage,
+        ..Default::default()
+    };
+    assert!(params.apply_fractal_limits().is_empty());
+
+    // Switching to a type whose shader doesn't trace the trap drops the mode
+    params.switch_fractal(FractalType::BurningShip2D);
+    assert_eq!(params.color_mode, ColorMode::Palette);
+
+    // So does loading one from a file
+    let mut settings = params.to_settings();
+    settings.color_mode = ColorMode::OrbitTrapImage;
+    let (loaded, adjustments) = FractalParams::from_settings_checked(settings);
+    assert_eq!(loaded.color_mode, ColorMode::Palette);
+    assert_eq!(adjustments.len(), 1);
+}
+
+#[test]
+fn test_randomize_palette_keeps_geometry() {
+    let mut params = FractalParams::default();
+    params.switch_fractal(FractalType::Mandelbulb3D);
+    params.power = 7.5;
+    params.color_mode = ColorMode::Normals;
+
+    for _ in 0..20 {
+        let previous = params.palette_index;
+        params.randomize_palette(false);
+        assert_ne!(params.palette_index, previous);
+        assert_eq!(
+            params.palette.name,
+            ColorPalette::ALL[params.palette_index].name
+        );
+        assert_eq!(params.fractal_type, FractalType::Mandelbulb3D);
+        assert_eq!(params.power, 7.5);
+        assert_eq!(params.color_mode, ColorMode::Normals);
+    }
+
+    params.procedural_palette = ProceduralPalette::Firestrm;
+    let index = params.palette_index;
+    params.randomize_palette(false);
+    assert_ne!(params.procedural_palette, ProceduralPalette::Firestrm);
+    assert_ne!(params.procedural_palette, ProceduralPalette::None);
+    assert_eq!(params.palette_index, index);
+}
+
+#[test]
+fn test_randomize_respects_locks() {
+    let locks = RandomizeLocks {
+        fractal_type: true,
+        palette: true,
+        color_mode: true,
+        camera: true,
+        lighting: true,
+        effects: true,
+    };
+    let mut params = FractalParams::default();
+    params.switch_fractal(FractalType::Mandelbulb3D);
+    params.palette_index = 3;
+    params.palette = ColorPalette::ALL[3];
+    params.color_mode = ColorMode::Normals;
+    params.center_2d = [0.25, -0.5];
+    params.zoom_2d = 42.0;
+
+    for _ in 0..20 {
+        params.randomize(&locks);
+        assert_eq!(params.fractal_type, FractalType::Mandelbulb3D);
+        assert_eq!(params.palette_index, 3);
+        assert_eq!(params.color_mode, ColorMode::Normals);
+        assert_eq!(params.center_2d, [0.25, -0.5]);
+        assert_eq!(params.zoom_2d, 42.0);
+        assert_eq!(
+            params.light_intensity,
+            FractalParams::default().light_intensity
+        );
+        assert_eq!(params.show_floor, FractalParams::default().show_floor);
+    }
+}
+
+#[test]
+fn test_from_settings_clamps_iterations_to_fractal_range() {
+    let mut settings = FractalParams::default().to_settings();
+    settings.fractal_type = FractalType::MengerSponge3D;
+    settings.max_iterations = 1024;
+    settings.max_steps = 5000;
+
+    let (params, adjustments) = FractalParams::from_settings_checked(settings.clone());
+    assert_eq!(params.max_iterations, 20);
+    assert_eq!(params.max_steps, *MAX_STEPS_RANGE.end());
+    assert_eq!(adjustments.len(), 2);
+
+    // In-range values load unchanged
+    settings.max_iterations = 7;
+    settings.max_steps = 200;
+    let (params, adjustments) = FractalParams::from_settings_checked(settings);
+    assert_eq!(params.max_iterations, 7);
+    assert!(adjustments.is_empty());
+}
+
+#[test]
+fn test_builtin_presets_are_within_fractal_limits() {
+    for preset in PresetGallery::get_builtin_presets() {
+        let (_, adjustments) = FractalParams::from_settings_checked(preset.settings);
+        assert!(adjustments.is_empty(), "{}: {:?}", preset.name, adjustments);
+    }
+}
+
+#[test]
+fn test_capture_antialiasing_sample_offsets() {
+    assert_eq!(CaptureAntialiasing::None.sample_offsets(), vec![[0.0, 0.0]]);
+    assert_eq!(CaptureAntialiasing::Grid2x2.sample_offsets().len(), 4);
+    assert_eq!(CaptureAntialiasing::Halton8.sample_offsets().len(), 8);
+
+    for aa in CaptureAntialiasing::ALL {
+        for [x, y] in aa.sample_offsets() {
+            assert!(
+                x.abs() <= 0.5 && y.abs() <= 0.5,
+                "{:?} offset outside pixel",
+                aa
+            );
+        }
+    }
+
+    // Rotated grid: every sample has its own column and row
+    let offsets = CaptureAntialiasing::RotatedGrid4x4.sample_offsets();
+    assert_eq!(offsets.len(), 16);
+    for axis in 0..2 {
+        let mut values: Vec<f32> = offsets.iter().map(|o| o[axis]).collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!(values.windows(2).all(|w| w[1] - w[0] > 0.01));
+    }
+}
+
+#[test]
+fn test_seamless_tile_only_for_periodic_fractals() {
+    let (origin, size) = FractalType::Sierpinski2D.tile_cell().unwrap();
+    assert_eq!(origin, [-1.0, -1.0]);
+    assert_eq!(size, [2.0, 2.0]);
+    assert!(FractalType::SierpinskiTriangle2D.tile_cell().is_some());
+    assert!(FractalType::Mandelbrot2D.tile_cell().is_none());
+    assert!(FractalType::MengerSponge3D.tile_cell().is_none());
+
+    let params = FractalParams {
+        seamless_tile: true,
+        ..Default::default()
+    };
+    assert!(FractalParams::from_settings(params.to_settings()).seamless_tile);
+}
+
+#[test]
+fn test_interior_coloring_for_escape_time_fractals() {
+    assert_eq!(
+        FractalParams::default().interior_color_mode,
+        InteriorColorMode::Solid
+    );
+    assert!(FractalType::Mandelbrot2D.has_interior_coloring());
+    assert!(FractalType::Celtic2D.has_interior_coloring());
+    assert!(!FractalType::Newton2D.has_interior_coloring());
+    assert!(!FractalType::Mandelbulb3D.has_interior_coloring());
+
+    let params = FractalParams {
+        interior_color_mode: InteriorColorMode::Period,
+        ..Default::default()
+    };
+    assert_eq!(
+        FractalParams::from_settings(params.to_settings()).interior_color_mode,
+        InteriorColorMode::Period
+    );
+
+    // The interior distance estimate only applies to the Mandelbrot set
+    assert!(InteriorColorMode::Distance.applies_to(FractalType::Mandelbrot2D));
+    assert!(!InteriorColorMode::Distance.applies_to(FractalType::Julia2D));
+    assert!(InteriorColorMode::Period.applies_to(FractalType::Julia2D));
+    let params = FractalParams {
+        interior_color_mode: InteriorColorMode::Distance,
+        interior_distance_lighting: true,
+        ..Default::default()
+    };
+    assert!(FractalParams::from_settings(params.to_settings()).interior_distance_lighting);
+}
+
+#[test]
+fn test_attractor_color_mode_only_for_2d_attractors() {
+    let mut params = FractalParams {
+        fractal_type: FractalType::Hopalong2D,
+        attractor_color_mode: AttractorColorMode::IterationIndex,
+        ..Default::default()
+    };
+    assert_eq!(params.attractor_color_mode_index(), 1);
+    assert_eq!(
+        FractalParams::from_settings(params.to_settings()).attractor_color_mode,
+        AttractorColorMode::IterationIndex
+    );
+
+    // Buddhabrot and the 3D attractors always color by density
+    params.fractal_type = FractalType::Buddhabrot2D;
+    assert_eq!(params.attractor_color_mode_index(), 0);
+    params.fractal_type = FractalType::Lorenz3D;
+    assert_eq!(params.attractor_color_mode_index(), 0);
+}
+
+#[test]
+fn test_distance_estimation_2d_round_trip() {
+    assert!(!FractalParams::default().distance_estimation_2d);
+
+    let params = FractalParams {
+        distance_estimation_2d: true,
+        ..Default::default()
+    };
+    assert!(FractalParams::from_settings(params.to_settings()).distance_estimation_2d);
+}
+
+#[test]
+fn test_symmetry_round_trip() {
+    assert_eq!(FractalParams::default().symmetry_mode, SymmetryMode::Off);
+
+    let params = FractalParams {
+        symmetry_mode: SymmetryMode::Radial,
+        symmetry_segments: 8,
+        ..Default::default()
+    };
+    let restored = FractalParams::from_settings(params.to_settings());
+    assert_eq!(restored.symmetry_mode, SymmetryMode::Radial);
+    assert_eq!(restored.symmetry_segments, 8);
+}
+
+#[test]
+fn test_collatz_params_round_trip() {
+    assert_eq!(
+        FractalParams::default().collatz_params,
+        DEFAULT_COLLATZ_PARAMS
+    );
+    assert!(FractalType::Collatz2D.max_iterations_range().is_some());
+
+    let params = FractalParams {
+        collatz_params: [5.0, -0.5],
+        ..Default::default()
+    };
+    assert_eq!(
+        FractalParams::from_settings(params.to_settings()).collatz_params,
+        [5.0, -0.5]
+    );
+}
+
+#[test]
+fn test_phoenix_p_round_trip() {
+    let params = FractalParams::default();
+    assert_eq!(params.phoenix_p, DEFAULT_PHOENIX_P);
+
+    let params = FractalParams {
+        phoenix_p: [-0.5, 0.25],
+        ..Default::default()
+    };
+    assert_eq!(
+        FractalParams::from_settings(params.to_settings()).phoenix_p,
+        [-0.5, 0.25]
+    );
+}
+
+#[test]
+fn test_reset_section_only_touches_its_fields() {
+    let mut params = FractalParams::default();
+    params.switch_fractal(FractalType::Mandelbox3D);
+    params.light_intensity = 9.0;
+    params.shadow_mode = 0;
+    params.fog_density = 0.9;
+    params.roughness = 0.9;
+
+    params.reset_section(ParamSection::Lighting);
+    let defaults = params.fractal_defaults();
+    assert_eq!(params.light_intensity, defaults.light_intensity);
+    assert_eq!(params.shadow_mode, defaults.shadow_mode);
+    assert_eq!(params.fog_density, 0.9);
+    assert_eq!(params.fractal_type, FractalType::Mandelbox3D);
+
+    // Shading defaults follow the fractal (Mandelbox sets its own material)
+    params.reset_section(ParamSection::Shading);
+    assert_eq!(params.roughness, 0.21);
+}
+
+#[test]
+fn test_panel_dock_defaults_to_floating() {
+    assert_eq!(UIState::default().panel_dock, PanelDock::Floating);
+
+    // Settings saved before docking existed still load
+    let mut yaml = serde_yaml::to_value(UIState::default()).unwrap();
+    yaml.as_mapping_mut().unwrap().remove("panel_dock");
+    let state: UIState = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(state.panel_dock, PanelDock::Floating);
+
+    let state = UIState {
+        panel_dock: PanelDock::Right,
+        ..Default::default()
+    };
+    let yaml = serde_yaml::to_string(&state).unwrap();
+    let loaded: UIState = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(loaded.panel_dock, PanelDock::Right);
+}
+
+#[test]
+fn test_bokeh_blades_round_trip() {
+    assert_eq!(FractalParams::default().bokeh_blades, 0);
+
+    let params = FractalParams {
+        bokeh_blades: 6,
+        ..Default::default()
+    };
+    assert_eq!(
+        FractalParams::from_settings(params.to_settings()).bokeh_blades,
+        6
+    );
+}
+
+#[test]
+fn test_final_render_params_raise_quality() {
+    let mut params = FractalParams {
+        fxaa_enabled: true,
+        shadow_samples: 32,
+        dof_samples: 2,
+        ..Default::default()
+    };
+    params.lod_config.enabled = true;
+    params.max_steps = 100; // LOD-reduced
+
+    let final_params = params.final_render_params();
+    let quality = final_render_quality();
+    assert!(!final_params.lod_config.enabled);
+    assert!(!final_params.fxaa_enabled);
+    assert_eq!(final_params.max_steps, quality.max_steps);
+    assert_eq!(final_params.shadow_samples, quality.shadow_samples);
+    assert_eq!(final_params.dof_samples, quality.dof_samples);
+    assert!(final_params.min_distance <= quality.min_distance);
+    // The interactive parameters are untouched
+    assert!(params.lod_config.enabled && params.fxaa_enabled);
+    assert_eq!(params.max_steps, 100);
+}
+
+#[test]
+fn test_fractal_coloring_survives_switch() {
+    let mut params = FractalParams::default();
+    params.switch_fractal(FractalType::Mandelbrot2D);
+    params.palette_offset = 0.4;
+    params.max_iterations = 900;
+    params.color_mode = ColorMode::OrbitTrapRadial;
+    let tuned = FractalColoring::capture(&params);
+
+    // Switching applies the new type's defaults...
+    params.switch_fractal(FractalType::Collatz2D);
+    assert_eq!(params.max_iterations, 80);
+
+    // ...and applying the remembered coloring brings the tuning back
+    params.switch_fractal(FractalType::Mandelbrot2D);
+    tuned.apply(&mut params);
+    assert_eq!(params.palette_offset, 0.4);
+    assert_eq!(params.max_iterations, 900);
+    assert_eq!(params.color_mode, ColorMode::OrbitTrapRadial);
+}
+
+#[test]
+fn test_recipe_card_lists_key_settings() {
+    let mut params = FractalParams::default();
+    params.switch_fractal(FractalType::Julia2D);
+    params.julia_c = [-0.8, 0.156];
+    params.bloom_enabled = true;
+    let card = params.to_settings().recipe_card();
+
+    assert!(card.contains(env!("CARGO_PKG_VERSION")));
+    assert!(card.contains("Fractal: Julia2D (2D)"));
+    assert!(card.contains("Julia c: -0.8 + 0.156i"));
+    assert!(card.contains("bloom"));
+    // 3D-only settings are left out of a 2D recipe
+    assert!(!card.contains("Ray marching"));
+}
+
+#[test]
+fn test_lod_render_scale_only_scales_3d() {
+    let mut params = FractalParams::default();
+    params.switch_fractal(FractalType::Mandelbulb3D);
+    params.lod_state.active_quality.render_scale = 0.5;
+    assert_eq!(params.lod_render_scale(), 1.0); // LOD disabled
+
+    params.lod_config.enabled = true;
+    assert_eq!(params.lod_render_scale(), 0.5);
+
+    params.switch_fractal(FractalType::Mandelbrot2D);
+    assert_eq!(params.lod_render_scale(), 1.0);
+}
+
+#[test]
+fn test_3d_attractors_always_accumulate() {
+    for fractal_type in [
+        FractalType::Pickover3D,
+        FractalType::Lorenz3D,
+        FractalType::Rossler3D,
+    ] {
+        let mut params = FractalParams::default();
+        params.switch_fractal(fractal_type);
+        assert_eq!(params.render_mode, RenderMode::ThreeD);
+        assert!(params.attractor_pending_clear);
+        assert!(params.accumulation_active());
+
+        // Even with accumulation switched off there's no ray marched fallback
+        params.attractor_accumulation_enabled = false;
+        assert!(params.accumulation_active());
+
+        // The point cloud is splatted at native resolution
+        params.lod_config.enabled = true;
+        params.lod_state.active_quality.render_scale = 0.5;
+        assert_eq!(params.lod_render_scale(), 1.0);
+    }
+
+    let mut params = FractalParams::default();
+    params.switch_fractal(FractalType::Hopalong2D);
+    params.attractor_accumulation_enabled = false;
+    assert!(!params.accumulation_active());
+}
+
+#[test]
+fn test_up_axis_round_trip() {
+    // Settings and presets saved before the convention existed are Y-up
+    let mut yaml = serde_yaml::to_value(FractalParams::default().to_settings()).unwrap();
+    yaml.as_mapping_mut().unwrap().remove("up_axis");
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(settings.up_axis, UpAxis::Y);
+
+    let params = FractalParams {
+        up_axis: UpAxis::Z,
+        ..Default::default()
+    };
+    let settings = params.to_settings();
+    assert_eq!(FractalParams::from_settings(settings).up_axis, UpAxis::Z);
+    assert_eq!(UpAxis::Z.up(), glam::Vec3::Z);
+}
+
+#[test]
+fn test_accumulation_capture_samples() {
+    // Without accumulation the antialiasing pattern is used with a fixed lens
+    let samples = capture_samples(CaptureAntialiasing::Grid2x2, 1);
+    assert_eq!(samples.len(), 4);
+    assert!(samples.iter().all(|s| s.lens == [0.0, 0.0]));
+
+    // Accumulation replaces it with a reproducible Halton sequence
+    let samples = capture_samples(CaptureAntialiasing::Grid2x2, 64);
+    assert_eq!(samples.len(), 64);
+    assert_eq!(samples, capture_samples(CaptureAntialiasing::None, 64));
+    for s in &samples {
+        for v in s.pixel.iter().chain(&s.lens) {
+            assert!((-0.5..0.5).contains(v));
+        }
+    }
+    assert_eq!(samples[0].pixel, [0.0, 1.0 / 3.0 - 0.5]);
+    assert_eq!(samples[0].lens, [0.2 - 0.5, 1.0 / 7.0 - 0.5]);
+}
+
+#[test]
+fn test_ifs_offset_round_trip() {
+    let defaults = FractalParams::default();
+    assert_eq!(defaults.ifs_offset, Vec3::ONE);
+    assert!(FractalType::MengerSponge3D.uses_ifs_offset());
+    assert!(!FractalType::Mandelbulb3D.uses_ifs_offset());
+
+    let params = FractalParams {
+        ifs_offset: Vec3::new(1.2, 0.8, 1.0),
+        ..Default::default()
+    };
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert_eq!(loaded.ifs_offset, params.ifs_offset);
+
+    // Presets saved before the offset existed keep the symmetric shape
+    let mut yaml = serde_yaml::to_value(defaults.to_settings()).unwrap();
+    yaml.as_mapping_mut().unwrap().remove("ifs_offset");
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(settings.ifs_offset, [1.0; 3]);
+}
+
+#[test]
+fn test_orbit_trap_offset_round_trip() {
+    let defaults = FractalParams::default();
+    assert_eq!(defaults.orbit_trap_offset, Vec3::ZERO);
+
+    let params = FractalParams {
+        orbit_trap_offset: Vec3::new(0.5, -0.25, 1.0),
+        ..Default::default()
+    };
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert_eq!(loaded.orbit_trap_offset, params.orbit_trap_offset);
+
+    // Presets saved before the offset existed keep the trap at the origin
+    let mut yaml = serde_yaml::to_value(defaults.to_settings()).unwrap();
+    yaml.as_mapping_mut().unwrap().remove("orbit_trap_offset");
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(settings.orbit_trap_offset, [0.0; 3]);
+}
+
+#[test]
+fn test_preference_profile_keeps_machine_specific_preferences() {
+    let exporting = AppPreferences {
+        preferred_gpu_index: Some(1),
+        preferred_gpu_name: Some("Discrete GPU".to_string()),
+        window_width: Some(2560),
+        capture_directory: Some("/home/me/Pictures".to_string()),
+        max_fps: 60,
+        zoom_to_cursor: false,
+        ui_scale: 2.0,
+        ..Default::default()
+    };
+    let ui_state = UIState {
+        panel_dock: PanelDock::Right,
+        ..Default::default()
+    };
+    let profile = PreferenceProfile::new(&exporting, &ui_state);
+    assert_eq!(profile.preferences.preferred_gpu_index, None);
+    assert_eq!(profile.preferences.capture_directory, None);
+    assert_eq!(profile.preferences.ui_scale, 0.0);
+
+    let yaml = serde_yaml::to_string(&profile).unwrap();
+    let imported: PreferenceProfile = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(imported.ui_state.panel_dock, PanelDock::Right);
+
+    let local = AppPreferences {
+        preferred_gpu_index: Some(0),
+        window_width: Some(1280),
+        capture_directory: Some("D:\\Renders".to_string()),
+        ui_scale: 1.25,
+        ..Default::default()
+    };
+    let merged = imported.merged_preferences(&local);
+    assert_eq!(merged.max_fps, 60);
+    assert!(!merged.zoom_to_cursor);
+    assert_eq!(merged.preferred_gpu_index, Some(0));
+    assert_eq!(merged.window_width, Some(1280));
+    assert_eq!(merged.capture_directory, local.capture_directory);
+    assert_eq!(merged.ui_scale, 1.25);
+}
+
+#[test]
+fn test_light_size_round_trip() {
+    let params = FractalParams {
+        light_size: 0.5,
+        ..Default::default()
+    };
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert_eq!(loaded.light_size, 0.5);
+
+    // Older settings files get the default light size
+    let defaults = FractalParams::default();
+    let mut yaml = serde_yaml::to_value(defaults.to_settings()).unwrap();
+    yaml.as_mapping_mut().unwrap().remove("light_size");
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(settings.light_size, defaults.light_size);
+}
+
+#[test]
+fn test_relief_switches_render_mode() {
+    let mut params = FractalParams::default();
+    assert!(FractalType::Mandelbrot2D.supports_relief());
+    assert!(!FractalType::Hopalong2D.supports_relief());
+    assert!(!FractalType::Mandelbulb3D.supports_relief());
+
+    params.set_relief(true);
+    assert!(params.relief_active());
+    assert_eq!(params.render_mode, RenderMode::ThreeD);
+
+    // The mode follows the fractal type while the relief stays enabled
+    params.switch_fractal(FractalType::Hopalong2D);
+    assert_eq!(params.render_mode, RenderMode::TwoD);
+    params.switch_fractal(FractalType::Julia2D);
+    assert_eq!(params.render_mode, RenderMode::ThreeD);
+
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert!(loaded.relief_active());
+    assert_eq!(loaded.render_mode, RenderMode::ThreeD);
+    assert!(params.to_settings().recipe_card().contains("3D relief"));
+
+    params.set_relief(false);
+    assert_eq!(params.render_mode, RenderMode::TwoD);
+}
+
+#[test]
+fn test_edge_aa_round_trip() {
+    let defaults = FractalParams::default();
+    assert!(!defaults.edge_aa_2d);
+
+    let params = FractalParams {
+        edge_aa_2d: true,
+        edge_aa_grid: 4,
+        edge_aa_threshold: 0.05,
+        ..Default::default()
+    };
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert!(loaded.edge_aa_2d);
+    assert_eq!(loaded.edge_aa_grid, 4);
+    assert_eq!(loaded.edge_aa_threshold, 0.05);
+
+    // Out-of-range grids from hand-edited files are clamped
+    let mut settings = params.to_settings();
+    settings.edge_aa_grid = 16;
+    assert_eq!(FractalParams::from_settings(settings).edge_aa_grid, 4);
+
+    // Older settings files get the default grid and threshold
+    let mut yaml = serde_yaml::to_value(defaults.to_settings()).unwrap();
+    let map = yaml.as_mapping_mut().unwrap();
+    map.remove("edge_aa_grid");
+    map.remove("edge_aa_threshold");
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(settings.edge_aa_grid, defaults.edge_aa_grid);
+    assert_eq!(settings.edge_aa_threshold, defaults.edge_aa_threshold);
+}
+
+#[test]
+fn test_distance_contours_round_trip() {
+    let defaults = FractalParams::default();
+    assert!(!defaults.show_distance_contours);
+
+    let params = FractalParams {
+        show_distance_contours: true,
+        distance_contour_spacing: 0.5,
+        ..Default::default()
+    };
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert!(loaded.show_distance_contours);
+    assert_eq!(loaded.distance_contour_spacing, 0.5);
+
+    // Older settings files get the default spacing
+    let mut yaml = serde_yaml::to_value(defaults.to_settings()).unwrap();
+    yaml.as_mapping_mut()
+        .unwrap()
+        .remove("distance_contour_spacing");
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(
+        settings.distance_contour_spacing,
+        defaults.distance_contour_spacing
+    );
+}
+
+#[test]
+fn test_preset_import_fills_defaults_and_names_bad_fields() {
+    // An export from an older version lacks newer settings
+    let mut settings = serde_json::to_value(FractalParams::default().to_settings()).unwrap();
+    let map = settings.as_object_mut().unwrap();
+    map.remove("up_axis");
+    map.remove("distance_contour_spacing");
+    map.insert("retired_setting".to_string(), serde_json::json!(1));
+    let json = serde_json::json!({ "name": "Old", "settings": settings }).to_string();
+    let import = parse_preset_json(&json, "file").unwrap();
+    assert_eq!(import.preset.name, "Old");
+    assert_eq!(import.preset.settings.up_axis, UpAxis::Y);
+    assert!(import.warnings[0].contains("distance_contour_spacing"));
+    assert!(import.warnings[1].contains("retired_setting"));
+
+    // A wrong type is reported against its field
+    settings["max_iterations"] = serde_json::json!("lots");
+    let json = serde_json::json!({ "settings": settings }).to_string();
+    match parse_preset_json(&json, "file") {
+        Err(PresetImportError::InvalidField { field, .. }) => {
+            assert_eq!(field, "settings.max_iterations")
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    assert!(matches!(
+        parse_preset_json("{\"name\": \"x\"}", "file"),
+        Err(PresetImportError::MissingField(field)) if field == "settings"
+    ));
+    assert!(matches!(
+        parse_preset_json("{\n  \"name\": ", "file"),
+        Err(PresetImportError::Syntax { line: 2, .. })
+    ));
+}
+
+#[test]
+fn test_settings_diff_lists_changed_fields() {
+    let preset = FractalParams::default().to_settings();
+    let mut current = preset.clone();
+    assert!(preset.diff(&current).is_empty());
+
+    current.bloom_intensity = 0.25;
+    current.camera_position = [1.0, 2.0, 3.5];
+    current.lod_config.enabled = !preset.lod_config.enabled;
+    // The panel layout isn't part of a preset
+    current.ui_state.presets_open = !preset.ui_state.presets_open;
+
+    let changes = preset.diff(&current);
+    let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+    assert_eq!(
+        fields,
+        ["camera_position", "bloom_intensity", "lod_config.enabled"]
+    );
+    let bloom = &changes[1];
+    assert_eq!(bloom.old, preset.bloom_intensity.to_string());
+    assert_eq!(bloom.new, "0.25");
+    assert_eq!(changes[0].new, "[1, 2, 3.5]");
+}
+
+#[test]
+fn test_frame_2d_box() {
+    let mut params = FractalParams {
+        center_2d: [-0.5, 0.0],
+        zoom_2d: 1.0,
+        ..Default::default()
+    };
+    // The top-right quarter of a 2:1 view: centered there at twice the zoom.
+    // NDC 1 is 2 / zoom in the plane, times the aspect horizontally.
+    params.frame_2d_box([0.0, 0.0], [1.0, 1.0], 2.0);
+    assert_eq!(params.center_2d, [-0.5 + 2.0, 1.0]);
+    assert_eq!(params.zoom_2d, 2.0);
+
+    // A flat box is expanded to the view's shape: its width decides the zoom
+    params.frame_2d_box([-0.5, 0.1], [0.5, 0.0], 2.0);
+    assert_eq!(params.zoom_2d, 4.0);
+    assert!((params.center_2d[1] - (1.0 + 0.05)).abs() < 1e-12);
+
+    // A zero-size box keeps the zoom
+    params.frame_2d_box([0.2, 0.2], [0.2, 0.2], 2.0);
+    assert_eq!(params.zoom_2d, 4.0);
+}
+
+#[test]
+fn test_restore_settings_keep_camera() {
+    let preset = FractalParams {
+        fractal_type: FractalType::Mandelbrot2D,
+        max_iterations: 300,
+        center_2d: [-0.5, 0.0],
+        zoom_2d: 1.0,
+        camera_fov: 45.0,
+        ..Default::default()
+    }
+    .to_settings();
+
+    let mut params = FractalParams::from_settings(preset.clone());
+    params.max_iterations = 900;
+    params.center_2d = [-0.75, 0.1];
+    params.zoom_2d = 40.0;
+    params.camera_fov = 70.0;
+
+    let adjustments = params.restore_settings_keep_camera(preset);
+    assert!(adjustments.is_empty());
+    assert_eq!(params.max_iterations, 300);
+    assert_eq!(params.center_2d, [-0.75, 0.1]);
+    assert_eq!(params.zoom_2d, 40.0);
+    assert_eq!(params.camera_fov, 70.0);
+}
+
+#[test]
+fn test_fog_end_from_older_settings() {
+    // Settings saved before fog_end existed deserialize it as 0; linear fog
+    // then keeps its old falloff, opaque at 1 / density
+    let mut settings = FractalParams {
+        fog_mode: FogMode::Linear,
+        fog_density: 0.02,
+        ..Default::default()
+    }
+    .to_settings();
+    settings.fog_end = 0.0;
+    let params = FractalParams::from_settings(settings.clone());
+    assert_eq!(params.fog_end, 50.0);
+
+    settings.fog_start = 10.0;
+    settings.fog_end = 30.0;
+    let params = FractalParams::from_settings(settings);
+    assert_eq!((params.fog_start, params.fog_end), (10.0, 30.0));
+}
+
+#[test]
+fn test_parameter_sweep_values_and_grid() {
+    assert_eq!(
+        sweep_values(2.0, 16.0, 8),
+        vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0]
+    );
+    assert_eq!(sweep_values(1.0, 5.0, 1), vec![1.0]);
+
+    assert_eq!(contact_sheet_grid(9), (3, 3));
+    assert_eq!(contact_sheet_grid(8), (3, 3));
+    assert_eq!(contact_sheet_grid(10), (4, 3));
+    assert_eq!(contact_sheet_grid(2), (2, 1));
+
+    let mut params = FractalParams::default();
+    SweepParam::MaxIterations.set(&mut params, 99.6);
+    assert_eq!(params.max_iterations, 100);
+    assert_eq!(SweepParam::MaxIterations.get(&params), 100.0);
+    assert!(!SweepParam::Zoom2D.applies_to(RenderMode::ThreeD));
+}
+
+#[test]
+fn test_secondary_fractal_composite() {
+    let mut params = FractalParams::default();
+    params.switch_fractal(FractalType::Mandelbulb3D);
+    assert!(!params.secondary_fractal_active(), "off by default");
+
+    params.secondary_fractal.enabled = true;
+    params.secondary_fractal.rotation = [0.0, 90.0, 0.0];
+    params.secondary_fractal.scale = 0.5;
+    assert!(params.secondary_fractal_active());
+
+    // The inverse rotation undoes the fractal's own rotation
+    let rotation = params.secondary_fractal.inverse_rotation();
+    let local = rotation * Vec3::Z;
+    assert!((local - Vec3::new(-1.0, 0.0, 0.0)).length() < 1e-5);
+
+    // Only ray-marched 3D fractals composite
+    params.secondary_fractal.fractal_type = FractalType::Lorenz3D;
+    assert!(!params.secondary_fractal_active());
+    params.secondary_fractal.fractal_type = FractalType::MengerSponge3D;
+    params.switch_fractal(FractalType::Mandelbrot2D);
+    assert!(!params.secondary_fractal_active());
+
+    // The slot round-trips through settings, and older files load it disabled
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert_eq!(loaded.secondary_fractal, params.secondary_fractal);
+    let mut yaml = serde_yaml::to_value(params.to_settings()).unwrap();
+    yaml.as_mapping_mut().unwrap().remove("secondary_fractal");
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(settings.secondary_fractal, SecondaryFractal::default());
+}
+
+#[test]
+fn test_filename_template_expansion() {
+    let values = CaptureNameValues {
+        fractal: "mandelbrot".to_string(),
+        date: "20260101".to_string(),
+        time: "120000".to_string(),
+        width: 1920,
+        height: 1080,
+        palette: filename_safe("Fire Storm"),
+        counter: 7,
+    };
+    assert_eq!(
+        expand_filename_template(DEFAULT_FILENAME_TEMPLATE, &values),
+        "mandelbrot_20260101_120000"
+    );
+    assert_eq!(
+        expand_filename_template("shot_{palette}_{width}x{height}_{counter}", &values),
+        "shot_fire_storm_1920x1080_0007"
+    );
+    assert!(filename_template_uses_counter("project_{counter}"));
+    assert!(!filename_template_uses_counter(DEFAULT_FILENAME_TEMPLATE));
+
+    // Templates that would give every capture the same name get a counter
+    assert!(filename_template_is_unique(DEFAULT_FILENAME_TEMPLATE));
+    assert!(filename_template_is_unique("project_{counter}"));
+    assert!(!filename_template_is_unique("{fractal}_{date}"));
+    assert_eq!(
+        unique_filename_template("{fractal}_{date}"),
+        "{fractal}_{date}_{counter}"
+    );
+    assert_eq!(
+        unique_filename_template(DEFAULT_FILENAME_TEMPLATE),
+        DEFAULT_FILENAME_TEMPLATE
+    );
+
+    // Bad templates are rejected and fall back to the default when expanded
+    for bad in ["", "{nope}", "a{fractal", "a}b", "dir/{fractal}", "x:y"] {
+        assert!(validate_filename_template(bad).is_err(), "{:?}", bad);
+    }
+    assert_eq!(
+        expand_filename_template("{nope}", &values),
+        "mandelbrot_20260101_120000"
+    );
+}
+
+#[test]
+fn test_quick_bookmark_names() {
+    assert_eq!(
+        CameraBookmark::quick_name_2d(FractalType::Mandelbrot2D, [-0.7435, 0.1314], 1.0),
+        "Mandelbrot_-0.7435_0.1314_z1e0"
+    );
+    // Deeper zooms keep more decimals
+    assert_eq!(
+        CameraBookmark::quick_name_2d(FractalType::Mandelbrot2D, [-0.74351234, 0.1314], 1e6),
+        "Mandelbrot_-0.7435123400_0.1314000000_z1e6"
+    );
+    assert_eq!(
+        CameraBookmark::quick_name_3d(FractalType::Mandelbulb3D, Vec3::new(0.0, 1.5, -3.0)),
+        "Mandelbulb_0.00_1.50_-3.00"
+    );
+
+    let bookmark = CameraBookmark::new(
+        "view".to_string(),
+        Vec3::ZERO,
+        Vec3::Z,
+        45.0,
+        UpAxis::default(),
+    )
+    .with_view_2d([-0.5, 0.25], 8.0);
+    assert_eq!(bookmark.center_2d, Some([-0.5, 0.25]));
+    assert_eq!(bookmark.zoom_2d, Some(8.0));
+}
+
+#[test]
+fn test_bookmark_2d_view_round_trip() {
+    let bookmark = CameraBookmark::new(
+        "deep".to_string(),
+        Vec3::ZERO,
+        Vec3::Z,
+        45.0,
+        UpAxis::default(),
+    )
+    .with_view_2d([-0.743643887037, 0.131825904205], 2.5e5);
+    let yaml = serde_yaml::to_string(&bookmark).unwrap();
+    let loaded: CameraBookmark = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(loaded.view_2d(), ([-0.743643887037, 0.131825904205], 2.5e5));
+
+    // Bookmark files from before 2D views were stored load the default view
+    let old = "name: old\nposition: [0.0, 0.0, 3.0]\ntarget: [0.0, 0.0, 0.0]\nfov: 45.0\ntimestamp: '2024-01-01 00:00:00'\n";
+    let loaded: CameraBookmark = serde_yaml::from_str(old).unwrap();
+    assert_eq!(loaded.center_2d, None);
+    assert_eq!(loaded.view_2d(), ([0.0, 0.0], 1.0));
+    // and 3D bookmarks don't write the 2D fields
+    assert!(!serde_yaml::to_string(&loaded)
+        .unwrap()
+        .contains("center_2d"));
+}
+
+#[test]
+fn test_detail_falloff_defaults_to_fixed_epsilon() {
+    let mut params = FractalParams::default();
+    assert_eq!(params.detail_falloff, 0.0);
+
+    params.detail_falloff = 1.5;
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert_eq!(loaded.detail_falloff, 1.5);
+
+    // Settings saved before the parameter existed keep the fixed epsilon
+    let mut yaml = serde_yaml::to_value(params.to_settings()).unwrap();
+    yaml.as_mapping_mut().unwrap().remove("detail_falloff");
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(settings.detail_falloff, 0.0);
+
+    params.reset_section(ParamSection::RayMarching);
+    assert_eq!(params.detail_falloff, 0.0);
+}
+
+#[test]
+fn test_specular_color_and_intensity() {
+    let mut params = FractalParams::default();
+    assert_eq!(params.specular_color, Vec3::ONE);
+    assert_eq!(params.specular_intensity, 1.0);
+
+    params.specular_color = Vec3::new(1.0, 0.8, 0.3);
+    params.specular_intensity = 2.5;
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert_eq!(loaded.specular_color, Vec3::new(1.0, 0.8, 0.3));
+    assert_eq!(loaded.specular_intensity, 2.5);
+
+    // Settings saved before the parameters existed keep white highlights
+    let mut yaml = serde_yaml::to_value(params.to_settings()).unwrap();
+    let mapping = yaml.as_mapping_mut().unwrap();
+    mapping.remove("specular_color");
+    mapping.remove("specular_intensity");
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(settings.specular_color, [1.0, 1.0, 1.0]);
+    assert_eq!(settings.specular_intensity, 1.0);
+
+    params.reset_section(ParamSection::Shading);
+    assert_eq!(params.specular_color, Vec3::ONE);
+    assert_eq!(params.specular_intensity, 1.0);
+}
+
+#[test]
+fn test_attractor_hold_while_recording() {
+    let mut params = FractalParams::default();
+    // View changes always clear unless holding during a recording
+    assert!(params.attractor_auto_clear_due(None));
+    assert!(params.attractor_auto_clear_due(Some(3.0)));
+
+    params.attractor_hold_while_recording = true;
+    assert!(params.attractor_auto_clear_due(None));
+    assert!(!params.attractor_auto_clear_due(Some(3.0)));
+
+    params.attractor_recording_clear_interval = 2.0;
+    params.attractor_last_clear_time = 3.0;
+    assert!(!params.attractor_auto_clear_due(Some(4.5)));
+    assert!(params.attractor_auto_clear_due(Some(5.0)));
+    // A new recording starts before the last clear
+    assert!(params.attractor_auto_clear_due(Some(0.5)));
+
+    let loaded = FractalParams::from_settings(params.to_settings());
+    assert!(loaded.attractor_hold_while_recording);
+    assert_eq!(loaded.attractor_recording_clear_interval, 2.0);
+}
+
+#[test]
+fn test_fractal_math_info() {
+    let mandelbrot = FractalType::Mandelbrot2D.math_info();
+    assert_eq!(mandelbrot.title, "Mandelbrot Set");
+    assert!(mandelbrot.formula.contains(&"z → z^n + c"));
+
+    let lorenz = FractalType::Lorenz3D.math_info();
+    assert!(lorenz.parameters.iter().any(|(symbol, _)| *symbol == "σ"));
+
+    // Every family has a formula and description; the copied text holds them all
+    for fractal_type in [
+        FractalType::Newton2D,
+        FractalType::Buddhabrot2D,
+        FractalType::Gingerbreadman2D,
+        FractalType::Mandelbox3D,
+        FractalType::Rossler3D,
+    ] {
+        let info = fractal_type.math_info();
+        assert!(!info.formula.is_empty() && !info.description.is_empty());
+        let text = info.to_text();
+        assert!(text.starts_with(info.title));
+        assert!(info.formula.iter().all(|line| text.contains(line)));
+        assert!(info
+            .parameters
+            .iter()
+            .all(|(symbol, meaning)| text.contains(&format!("{}: {}", symbol, meaning))));
+    }
+}
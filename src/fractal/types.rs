@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum FractalType {
     // 2D Fractals - Escape Time
     Mandelbrot2D,
@@ -62,6 +62,14 @@ impl FractalType {
         )
     }
 
+    /// Returns true if this is a 3D strange attractor type
+    pub fn is_3d_attractor(&self) -> bool {
+        matches!(
+            self,
+            FractalType::Pickover3D | FractalType::Lorenz3D | FractalType::Rossler3D
+        )
+    }
+
     /// Returns true if this is the Buddhabrot fractal type
     pub fn is_buddhabrot(&self) -> bool {
         matches!(self, FractalType::Buddhabrot2D)
@@ -69,11 +77,90 @@ impl FractalType {
 
     /// Returns true if this fractal type uses accumulation rendering
     pub fn uses_accumulation(&self) -> bool {
-        self.is_2d_attractor() || self.is_buddhabrot()
+        self.is_2d_attractor() || self.is_3d_attractor() || self.is_buddhabrot()
+    }
+
+    /// Range of iteration counts the controls allow for this type, or None where
+    /// the iteration count isn't user-adjustable (attractors, most 3D types)
+    pub fn max_iterations_range(&self) -> Option<std::ops::RangeInclusive<u32>> {
+        match self {
+            FractalType::Mandelbrot2D
+            | FractalType::Julia2D
+            | FractalType::Sierpinski2D
+            | FractalType::SierpinskiTriangle2D
+            | FractalType::BurningShip2D
+            | FractalType::Tricorn2D
+            | FractalType::Phoenix2D
+            | FractalType::Celtic2D
+            | FractalType::Newton2D
+            | FractalType::Lyapunov2D
+            | FractalType::Nova2D
+            | FractalType::Magnet2D
+            | FractalType::Collatz2D => Some(1..=1024),
+            // Buddhabrot needs many more iterations for detail
+            FractalType::Buddhabrot2D => Some(1..=10000),
+            // Recursion depth
+            FractalType::MengerSponge3D | FractalType::SierpinskiPyramid3D => Some(1..=20),
+            FractalType::QuaternionCubic3D => Some(1..=64),
+            _ => None,
+        }
+    }
+
+    /// Returns true for the 2D escape-time fractals, which can also be rendered
+    /// as a 3D relief (see `FractalParams::relief_3d`)
+    pub fn supports_relief(&self) -> bool {
+        self.render_mode() == RenderMode::TwoD && !self.uses_accumulation()
+    }
+
+    /// Returns true for the types whose shader traces orbits through the trap
+    /// image (`ColorMode::OrbitTrapImage`)
+    pub fn supports_orbit_trap_image(&self) -> bool {
+        matches!(
+            self,
+            FractalType::Mandelbrot2D | FractalType::Julia2D | FractalType::Mandelbulb3D
+        )
+    }
+
+    /// Returns true for the kaleidoscopic IFS fractals that fold and then scale
+    /// around an offset point (see `FractalParams::ifs_offset`)
+    pub fn uses_ifs_offset(&self) -> bool {
+        matches!(
+            self,
+            FractalType::MengerSponge3D
+                | FractalType::SierpinskiPyramid3D
+                | FractalType::OctahedralIFS3D
+                | FractalType::IcosahedralIFS3D
+                | FractalType::SierpinskiGasket3D
+        )
+    }
+
+    /// Returns true for the z^n + c escape-time fractals whose interior can be
+    /// colored from the orbit (see `InteriorColorMode`)
+    pub fn has_interior_coloring(&self) -> bool {
+        matches!(
+            self,
+            FractalType::Mandelbrot2D
+                | FractalType::Julia2D
+                | FractalType::BurningShip2D
+                | FractalType::Tricorn2D
+                | FractalType::Celtic2D
+        )
     }
 
-    /// Returns the index of the 2D attractor type for the compute shader.
-    /// Returns 0 if not a 2D attractor.
+    /// Periodic cell (origin, size) in the complex plane for fractals whose pattern
+    /// tiles without seams, or None for non-periodic fractals
+    pub fn tile_cell(&self) -> Option<([f32; 2], [f32; 2])> {
+        match self {
+            // The carpet fills the square [-1, 1]^2 with solid edges
+            FractalType::Sierpinski2D => Some(([-1.0, -1.0], [2.0, 2.0])),
+            // The triangle's bounding box; rows of triangles meet at their corners
+            FractalType::SierpinskiTriangle2D => Some(([-1.0, -1.0], [2.0, 3f32.sqrt()])),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the attractor type for its compute shader (2D and
+    /// 3D attractors have separate shaders). Returns 0 if not an attractor.
     pub fn attractor_index(&self) -> u32 {
         match self {
             FractalType::Hopalong2D => 0,
@@ -82,10 +169,54 @@ impl FractalType {
             FractalType::Chip2D => 3,
             FractalType::Quadruptwo2D => 4,
             FractalType::Threeply2D => 5,
+            FractalType::Pickover3D => 0,
+            FractalType::Lorenz3D => 1,
+            FractalType::Rossler3D => 2,
             _ => 0,
         }
     }
 
+    /// Whether this type is rendered in the 2D or 3D pipeline
+    pub fn render_mode(&self) -> RenderMode {
+        match self {
+            Self::Mandelbrot2D
+            | Self::Julia2D
+            | Self::Sierpinski2D
+            | Self::SierpinskiTriangle2D
+            | Self::BurningShip2D
+            | Self::Tricorn2D
+            | Self::Phoenix2D
+            | Self::Celtic2D
+            | Self::Newton2D
+            | Self::Lyapunov2D
+            | Self::Nova2D
+            | Self::Magnet2D
+            | Self::Collatz2D
+            | Self::Hopalong2D
+            | Self::Martin2D
+            | Self::Gingerbreadman2D
+            | Self::Chip2D
+            | Self::Quadruptwo2D
+            | Self::Threeply2D
+            | Self::Buddhabrot2D => RenderMode::TwoD,
+            Self::Mandelbulb3D
+            | Self::MengerSponge3D
+            | Self::SierpinskiPyramid3D
+            | Self::JuliaSet3D
+            | Self::Mandelbox3D
+            | Self::OctahedralIFS3D
+            | Self::IcosahedralIFS3D
+            | Self::ApollonianGasket3D
+            | Self::Kleinian3D
+            | Self::HybridMandelbulbJulia3D
+            | Self::QuaternionCubic3D
+            | Self::SierpinskiGasket3D
+            | Self::Pickover3D
+            | Self::Lorenz3D
+            | Self::Rossler3D => RenderMode::ThreeD,
+        }
+    }
+
     /// Returns a filename-safe name for this fractal type
     pub fn filename_safe_name(&self) -> &'static str {
         match self {
@@ -151,6 +282,7 @@ pub enum ColorMode {
     Normals,          // Visualize surface normals
     OrbitTrapXYZ,     // Color based on XYZ coordinates during iteration
     OrbitTrapRadial,  // Color based on radial distance during iteration
+    OrbitTrapImage,   // Sample an image at the orbit's trap coordinates
     WorldPosition,    // Color based on world position
     LocalPosition,    // Color based on local/fractal-space position
     AmbientOcclusion, // Visualize AO only
@@ -184,6 +316,463 @@ pub enum FogMode {
     Quadratic,   // Quadratic (exponential squared) fog falloff
 }
 
+/// World axis that points up in 3D mode.
+///
+/// The fractals themselves don't move; the convention decides which way the
+/// camera treats as up, which plane the floor lies in and what the light
+/// elevation is measured from. Z-up is the same world rotated +90° about X, so
+/// the default camera looks along +Y from -Y, as in Blender's front view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum UpAxis {
+    #[default]
+    Y,
+    Z,
+}
+
+impl UpAxis {
+    pub fn name(&self) -> &'static str {
+        match self {
+            UpAxis::Y => "Y-up",
+            UpAxis::Z => "Z-up (Blender)",
+        }
+    }
+
+    /// The world up vector
+    pub fn up(&self) -> glam::Vec3 {
+        match self {
+            UpAxis::Y => glam::Vec3::Y,
+            UpAxis::Z => glam::Vec3::Z,
+        }
+    }
+}
+
+/// Stereoscopic output mode for 3D fractals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StereoMode {
+    /// Regular mono rendering
+    #[default]
+    Off,
+    /// Left eye in the left half, right eye in the right half (cross-free viewing, VR viewers, 3D TVs)
+    SideBySide,
+    /// Red/cyan anaglyph (left eye red, right eye cyan)
+    Anaglyph,
+}
+
+impl StereoMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StereoMode::Off => "Off",
+            StereoMode::SideBySide => "Side-by-Side",
+            StereoMode::Anaglyph => "Anaglyph (Red/Cyan)",
+        }
+    }
+}
+
+/// How the interior of escape-time 2D fractals (points that never escape) is colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum InteriorColorMode {
+    /// Flat black
+    #[default]
+    Solid,
+    /// |z| after the last iteration
+    FinalMagnitude,
+    /// Mean |z| over the whole orbit
+    AverageMagnitude,
+    /// Period of the cycle the orbit settles into
+    Period,
+    /// Interior distance estimate to the boundary (Mandelbrot only)
+    Distance,
+}
+
+impl InteriorColorMode {
+    pub const ALL: &'static [InteriorColorMode] = &[
+        InteriorColorMode::Solid,
+        InteriorColorMode::FinalMagnitude,
+        InteriorColorMode::AverageMagnitude,
+        InteriorColorMode::Period,
+        InteriorColorMode::Distance,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            InteriorColorMode::Solid => "Solid",
+            InteriorColorMode::FinalMagnitude => "Final |z|",
+            InteriorColorMode::AverageMagnitude => "Average |z|",
+            InteriorColorMode::Period => "Period",
+            InteriorColorMode::Distance => "Distance",
+        }
+    }
+
+    /// Whether this mode colors the interior of `fractal_type`; the distance
+    /// estimate needs the attracting cycles of the Mandelbrot set
+    pub fn applies_to(&self, fractal_type: FractalType) -> bool {
+        match self {
+            InteriorColorMode::Distance => fractal_type == FractalType::Mandelbrot2D,
+            _ => fractal_type.has_interior_coloring(),
+        }
+    }
+}
+
+/// What the palette position of a 2D strange attractor pixel is taken from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AttractorColorMode {
+    /// Number of hits, log scaled
+    #[default]
+    Density,
+    /// Mean position along the orbit at which the pixel was hit
+    IterationIndex,
+    /// Mean distance the orbit jumped on its way into the pixel
+    Velocity,
+}
+
+impl AttractorColorMode {
+    pub const ALL: &'static [AttractorColorMode] = &[
+        AttractorColorMode::Density,
+        AttractorColorMode::IterationIndex,
+        AttractorColorMode::Velocity,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AttractorColorMode::Density => "Density",
+            AttractorColorMode::IterationIndex => "Iteration Index",
+            AttractorColorMode::Velocity => "Velocity",
+        }
+    }
+
+    /// Index passed to the attractor shaders
+    pub fn shader_index(&self) -> u32 {
+        match self {
+            AttractorColorMode::Density => 0,
+            AttractorColorMode::IterationIndex => 1,
+            AttractorColorMode::Velocity => 2,
+        }
+    }
+}
+
+/// Screen-space symmetry applied in the composite pass by folding the image UVs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SymmetryMode {
+    #[default]
+    Off,
+    /// Left half mirrored onto the right
+    MirrorX,
+    /// Top half mirrored onto the bottom
+    MirrorY,
+    /// Top-left quadrant mirrored into all four
+    Quad,
+    /// Kaleidoscope with `symmetry_segments` mirrored wedges around the center
+    Radial,
+}
+
+impl SymmetryMode {
+    pub const ALL: &'static [SymmetryMode] = &[
+        SymmetryMode::Off,
+        SymmetryMode::MirrorX,
+        SymmetryMode::MirrorY,
+        SymmetryMode::Quad,
+        SymmetryMode::Radial,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SymmetryMode::Off => "Off",
+            SymmetryMode::MirrorX => "Mirror X",
+            SymmetryMode::MirrorY => "Mirror Y",
+            SymmetryMode::Quad => "Quad",
+            SymmetryMode::Radial => "Radial (Kaleidoscope)",
+        }
+    }
+}
+
+/// Color vision deficiency simulated in the composite pass, to check that a
+/// palette stays legible to color-blind viewers. A preview overlay, not a
+/// fractal setting, so it isn't saved with presets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CvdSimulation {
+    #[default]
+    None,
+    /// No long-wavelength (red) cones
+    Protanopia,
+    /// No medium-wavelength (green) cones
+    Deuteranopia,
+    /// No short-wavelength (blue) cones
+    Tritanopia,
+}
+
+impl CvdSimulation {
+    pub const ALL: &'static [CvdSimulation] = &[
+        CvdSimulation::None,
+        CvdSimulation::Protanopia,
+        CvdSimulation::Deuteranopia,
+        CvdSimulation::Tritanopia,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CvdSimulation::None => "None",
+            CvdSimulation::Protanopia => "Protanopia (red-blind)",
+            CvdSimulation::Deuteranopia => "Deuteranopia (green-blind)",
+            CvdSimulation::Tritanopia => "Tritanopia (blue-blind)",
+        }
+    }
+}
+
+/// Bloom quality: how many half-resolution levels the bloom mip chain uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BloomQuality {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl BloomQuality {
+    pub const ALL: &'static [BloomQuality] =
+        &[BloomQuality::Low, BloomQuality::Medium, BloomQuality::High];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BloomQuality::Low => "Low",
+            BloomQuality::Medium => "Medium",
+            BloomQuality::High => "High",
+        }
+    }
+
+    /// Number of mip levels; more levels give a wider, smoother glow
+    pub fn mip_count(&self) -> u32 {
+        match self {
+            BloomQuality::Low => 3,
+            BloomQuality::Medium => 5,
+            BloomQuality::High => 7,
+        }
+    }
+}
+
+/// Supersampling pattern for high-resolution captures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CaptureAntialiasing {
+    /// One sample per pixel
+    #[default]
+    None,
+    /// Regular 2x2 grid (4 samples)
+    Grid2x2,
+    /// 4x4 grid rotated so every sample has its own row and column (16 samples)
+    RotatedGrid4x4,
+    /// First 8 points of the Halton (2, 3) sequence
+    Halton8,
+}
+
+impl CaptureAntialiasing {
+    pub const ALL: &'static [CaptureAntialiasing] = &[
+        CaptureAntialiasing::None,
+        CaptureAntialiasing::Grid2x2,
+        CaptureAntialiasing::RotatedGrid4x4,
+        CaptureAntialiasing::Halton8,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CaptureAntialiasing::None => "None",
+            CaptureAntialiasing::Grid2x2 => "2x2 Grid",
+            CaptureAntialiasing::RotatedGrid4x4 => "4x4 Rotated Grid",
+            CaptureAntialiasing::Halton8 => "8x Halton",
+        }
+    }
+
+    /// Sample positions within a pixel, as offsets from its center in pixels
+    pub fn sample_offsets(&self) -> Vec<[f32; 2]> {
+        match self {
+            CaptureAntialiasing::None => vec![[0.0, 0.0]],
+            CaptureAntialiasing::Grid2x2 => {
+                vec![[-0.25, -0.25], [0.25, -0.25], [-0.25, 0.25], [0.25, 0.25]]
+            }
+            CaptureAntialiasing::RotatedGrid4x4 => {
+                // Rotating by atan(1/4) gives 16 distinct, evenly spaced x and y
+                // projections, so near-horizontal and near-vertical edges get 16
+                // coverage levels instead of 4. The rotated grid fits inside the pixel.
+                let angle = 0.25f32.atan();
+                let (sin, cos) = angle.sin_cos();
+                (0..16)
+                    .map(|i| {
+                        let u = ((i % 4) as f32 - 1.5) / 4.0;
+                        let v = ((i / 4) as f32 - 1.5) / 4.0;
+                        [u * cos - v * sin, u * sin + v * cos]
+                    })
+                    .collect()
+            }
+            CaptureAntialiasing::Halton8 => (1..=8)
+                .map(|i| [halton(i, 2) - 0.5, halton(i, 3) - 0.5])
+                .collect(),
+        }
+    }
+}
+
+/// Maximum passes for an accumulated capture
+pub const MAX_ACCUMULATION_SAMPLES: u32 = 1024;
+
+/// Allowed capture gamma; 1.0 saves exactly what the view shows
+pub const CAPTURE_GAMMA_RANGE: std::ops::RangeInclusive<f32> = 0.5..=2.5;
+
+/// Allowed UI scale override, in egui points per physical pixel
+pub const UI_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.5..=4.0;
+
+/// One frame of a supersampled or accumulated capture
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CaptureSample {
+    /// Sub-pixel offset from the pixel center, in pixels
+    pub pixel: [f32; 2],
+    /// Depth of field aperture sampling offset in [-0.5, 0.5); rotates and
+    /// shifts the lens pattern so successive frames sample different points
+    pub lens: [f32; 2],
+}
+
+/// The frames to average for a capture. With more than one accumulation
+/// sample, frames follow the Halton (2, 3, 5, 7) sequence in place of the
+/// antialiasing pattern: bases 2 and 3 jitter the pixel, 5 and 7 the lens.
+/// The sequence is fixed, so accumulated captures are reproducible.
+pub fn capture_samples(antialiasing: CaptureAntialiasing, accumulation: u32) -> Vec<CaptureSample> {
+    if accumulation > 1 {
+        return (1..=accumulation.min(MAX_ACCUMULATION_SAMPLES))
+            .map(|i| CaptureSample {
+                pixel: [halton(i, 2) - 0.5, halton(i, 3) - 0.5],
+                lens: [halton(i, 5) - 0.5, halton(i, 7) - 0.5],
+            })
+            .collect();
+    }
+    antialiasing
+        .sample_offsets()
+        .into_iter()
+        .map(|pixel| CaptureSample {
+            pixel,
+            lens: [0.0, 0.0],
+        })
+        .collect()
+}
+
+/// Element `index` of the van der Corput sequence in `base` (one Halton dimension)
+fn halton(mut index: u32, base: u32) -> f32 {
+    let mut result = 0.0;
+    let mut fraction = 1.0;
+    while index > 0 {
+        fraction /= base as f32;
+        result += fraction * (index % base) as f32;
+        index /= base;
+    }
+    result
+}
+
+/// Surface present mode (vsync behavior)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PresentModeSetting {
+    /// Vsync, always supported
+    #[default]
+    Fifo,
+    /// Low-latency vsync without tearing
+    Mailbox,
+    /// No vsync, may tear
+    Immediate,
+}
+
+impl PresentModeSetting {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PresentModeSetting::Fifo => "VSync (Fifo)",
+            PresentModeSetting::Mailbox => "Mailbox",
+            PresentModeSetting::Immediate => "Immediate (No VSync)",
+        }
+    }
+}
+
+/// Window surface format. Wider formats keep more of the HDR composite's
+/// precision; unsupported choices fall back to `Auto`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SurfaceFormatSetting {
+    /// 8-bit sRGB, encoded by the hardware
+    #[default]
+    Auto,
+    /// 8-bit linear-coded format, sRGB encoded in the final pass
+    Unorm8,
+    /// 10 bits per color channel, sRGB encoded in the final pass
+    Rgb10a2,
+    /// 16-bit float extended linear sRGB (values above 1.0 reach HDR displays)
+    Float16,
+}
+
+impl SurfaceFormatSetting {
+    pub const ALL: [SurfaceFormatSetting; 4] = [
+        SurfaceFormatSetting::Auto,
+        SurfaceFormatSetting::Unorm8,
+        SurfaceFormatSetting::Rgb10a2,
+        SurfaceFormatSetting::Float16,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SurfaceFormatSetting::Auto => "Auto (8-bit sRGB)",
+            SurfaceFormatSetting::Unorm8 => "8-bit Unorm",
+            SurfaceFormatSetting::Rgb10a2 => "10-bit (Rgb10a2)",
+            SurfaceFormatSetting::Float16 => "16-bit Float (HDR)",
+        }
+    }
+}
+
+/// Easing curve of camera transitions (bookmarks and tour presets)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CameraEasing {
+    /// Constant speed
+    Linear,
+    /// Start slowly, arrive at full speed
+    EaseIn,
+    /// Start at full speed, settle into place
+    EaseOut,
+    /// Smoothstep: slow at both ends
+    #[default]
+    EaseInOut,
+    /// Overshoot and spring back into place
+    Elastic,
+}
+
+impl CameraEasing {
+    pub const ALL: [CameraEasing; 5] = [
+        CameraEasing::Linear,
+        CameraEasing::EaseIn,
+        CameraEasing::EaseOut,
+        CameraEasing::EaseInOut,
+        CameraEasing::Elastic,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CameraEasing::Linear => "Linear",
+            CameraEasing::EaseIn => "Ease In",
+            CameraEasing::EaseOut => "Ease Out",
+            CameraEasing::EaseInOut => "Ease In-Out",
+            CameraEasing::Elastic => "Elastic",
+        }
+    }
+
+    /// Eased progress for linear progress `t` in 0..=1. Every curve maps 0 to
+    /// 0 and 1 to 1; Elastic overshoots 1 on the way.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            CameraEasing::Linear => t,
+            CameraEasing::EaseIn => t * t * t,
+            CameraEasing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            CameraEasing::EaseInOut => t * t * (3.0 - 2.0 * t),
+            CameraEasing::Elastic => {
+                if t <= 0.0 || t >= 1.0 {
+                    t
+                } else {
+                    let period = 2.0 * std::f32::consts::PI / 3.0;
+                    2f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * period).sin() + 1.0
+                }
+            }
+        }
+    }
+}
+
 /// Procedural palette types that generate colors mathematically
 /// These use cosine-based formulas for smooth, continuous color gradients
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
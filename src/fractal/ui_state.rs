@@ -1,5 +1,29 @@
 use serde::{Deserialize, Serialize};
 
+/// Where the Fractal Controls panel is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PanelDock {
+    /// Movable window over the fractal
+    #[default]
+    Floating,
+    /// Fixed side panel on the left; the fractal renders to its right
+    Left,
+    /// Fixed side panel on the right; the fractal renders to its left
+    Right,
+}
+
+impl PanelDock {
+    pub const ALL: &'static [PanelDock] = &[PanelDock::Floating, PanelDock::Left, PanelDock::Right];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PanelDock::Floating => "Floating",
+            PanelDock::Left => "Docked Left",
+            PanelDock::Right => "Docked Right",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UIState {
     pub fractal_type_open: bool,
@@ -20,6 +44,14 @@ pub struct UIState {
     pub about_window_open: bool,
     pub show_fps: bool,
     pub show_camera_info: bool,
+    #[serde(default)]
+    pub show_iteration_readout: bool,
+    #[serde(default)]
+    pub panel_dock: PanelDock,
+    #[serde(default)]
+    pub console_window_open: bool,
+    #[serde(default)]
+    pub math_info_window_open: bool,
 }
 
 pub(super) fn default_dof_samples() -> u32 {
@@ -30,6 +62,18 @@ pub(super) fn default_shadow_mode() -> u32 {
     2 // Soft shadows
 }
 
+pub(super) fn default_edge_aa_grid() -> u32 {
+    3
+}
+
+pub(super) fn default_edge_aa_threshold() -> f32 {
+    0.1
+}
+
+pub(super) fn default_relief_height() -> f32 {
+    0.5
+}
+
 pub(super) fn default_one() -> f32 {
     1.0
 }
@@ -46,10 +90,18 @@ pub(super) fn default_light_azimuth() -> f32 {
     45.0 // degrees
 }
 
+pub(super) fn default_light_size() -> f32 {
+    10.0 // degrees
+}
+
 pub(super) fn default_light_elevation() -> f32 {
     60.0 // degrees
 }
 
+pub(super) fn default_auto_exposure_speed() -> f32 {
+    1.5
+}
+
 pub(super) fn default_vignette_intensity() -> f32 {
     0.5
 }
@@ -58,6 +110,18 @@ pub(super) fn default_vignette_radius() -> f32 {
     0.8
 }
 
+pub(super) fn default_porthole_radius() -> f32 {
+    0.9
+}
+
+pub(super) fn default_porthole_feather() -> f32 {
+    0.05
+}
+
+pub(super) fn default_symmetry_segments() -> u32 {
+    6
+}
+
 pub(super) fn default_bloom_threshold() -> f32 {
     0.75
 }
@@ -78,6 +142,34 @@ pub(super) fn default_custom_height() -> String {
     "1080".to_string()
 }
 
+pub(super) fn default_capture_aspect() -> f32 {
+    16.0 / 9.0
+}
+
+pub(super) fn default_capture_gamma() -> f32 {
+    1.0
+}
+
+pub(super) fn default_ifs_offset() -> [f32; 3] {
+    [1.0, 1.0, 1.0]
+}
+
+pub(super) fn default_distance_contour_spacing() -> f32 {
+    0.1
+}
+
+pub(super) fn default_accumulation_samples() -> u32 {
+    1
+}
+
+pub(super) fn default_camera_acceleration() -> f32 {
+    8.0
+}
+
+pub(super) fn default_camera_damping() -> f32 {
+    4.0
+}
+
 pub(super) fn default_orbit_speed() -> f32 {
     0.2
 }
@@ -103,6 +195,10 @@ impl Default for UIState {
             about_window_open: false,
             show_fps: false,
             show_camera_info: false,
+            show_iteration_readout: false,
+            panel_dock: PanelDock::default(),
+            console_window_open: false,
+            math_info_window_open: false,
         }
     }
 }
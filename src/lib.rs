@@ -1,6 +1,7 @@
 // Library exports for testing and external use
 pub mod app;
 pub mod camera;
+pub mod camera_path;
 pub mod command_palette;
 pub mod fractal;
 pub mod lod;
@@ -6,6 +6,9 @@ use glam::Vec3;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
+/// Display names of the LOD levels, indexed by level
+pub const LOD_LEVEL_NAMES: [&str; 4] = ["Ultra", "High", "Medium", "Low"];
+
 /// LOD strategy for quality adjustment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum LODStrategy {
@@ -175,6 +178,23 @@ pub struct LODConfig {
 
     /// Aggressive mode - more aggressive quality reduction
     pub aggressive_mode: bool,
+
+    /// Pin the quality to `freeze_level` instead of adapting it
+    #[serde(default)]
+    pub freeze_enabled: bool,
+
+    /// Level held while frozen (0=ultra .. 3=low)
+    #[serde(default)]
+    pub freeze_level: usize,
+
+    /// Also hold `freeze_level` while a video is recording, so quality
+    /// doesn't change mid-capture
+    #[serde(default = "default_freeze_during_recording")]
+    pub freeze_during_recording: bool,
+}
+
+fn default_freeze_during_recording() -> bool {
+    true
 }
 
 impl Default for LODConfig {
@@ -199,6 +219,9 @@ impl Default for LODConfig {
             motion_sensitivity: 1.0,
             min_quality_level: 0,
             aggressive_mode: false,
+            freeze_enabled: false,
+            freeze_level: 0,
+            freeze_during_recording: default_freeze_during_recording(),
         }
     }
 }
@@ -275,6 +298,13 @@ impl LODConfig {
         }
     }
 
+    /// The level quality is pinned to, if frozen (explicitly, or because a
+    /// video is `recording`)
+    pub fn frozen_level(&self, recording: bool) -> Option<usize> {
+        (self.freeze_enabled || (self.freeze_during_recording && recording))
+            .then_some(self.freeze_level.min(3))
+    }
+
     /// Get the name of the current profile
     pub fn profile_name(&self) -> &'static str {
         match self.profile {
@@ -329,6 +359,9 @@ pub struct LODState {
 
     /// Last calculated performance LOD level (for detecting changes)
     pub last_performance_level: usize,
+
+    /// Whether the level is currently pinned by the freeze settings
+    pub frozen: bool,
 }
 
 impl LODState {
@@ -347,6 +380,7 @@ impl LODState {
             active_quality: QualityLevel::ultra(),
             fps_stable_time: 0.0,
             last_performance_level: 0,
+            frozen: false,
         }
     }
 
@@ -522,4 +556,22 @@ mod tests {
         assert_eq!(state.transition_progress, 1.0);
         assert_eq!(state.current_level, 2);
     }
+
+    #[test]
+    fn test_frozen_level() {
+        let mut config = LODConfig {
+            freeze_level: 2,
+            ..Default::default()
+        };
+        // Auto-freeze only applies while recording
+        assert_eq!(config.frozen_level(false), None);
+        assert_eq!(config.frozen_level(true), Some(2));
+
+        config.freeze_during_recording = false;
+        assert_eq!(config.frozen_level(true), None);
+
+        config.freeze_enabled = true;
+        config.freeze_level = 7;
+        assert_eq!(config.frozen_level(false), Some(3));
+    }
 }
@@ -1,5 +1,6 @@
 mod app;
 mod camera;
+mod camera_path;
 mod command_palette;
 mod fractal;
 mod lod;
@@ -7,9 +8,12 @@ mod renderer;
 mod ui;
 mod video_recorder;
 
-use app::App;
+use app::{App, RenderStatus};
 use std::env;
-use winit::{event::*, event_loop::EventLoop};
+use winit::{
+    event::*,
+    event_loop::{ControlFlow, EventLoop},
+};
 
 fn print_help() {
     println!("Par Fractal - GPU Accelerated Fractal Renderer");
@@ -22,6 +26,9 @@ fn print_help() {
     println!("  --list-presets           List all available presets and exit");
     println!("  --screenshot-delay <s>   Take a screenshot after N seconds");
     println!("  --exit-delay <s>         Exit application after N seconds");
+    println!("  --tour                   Cycle through the built-in presets (kiosk mode)");
+    #[cfg(feature = "scripting")]
+    println!("  --script <file>          Drive parameters from a Rhai script");
     println!("  --help, -h               Show this help message");
 }
 
@@ -120,6 +127,9 @@ fn main() {
     let mut screenshot_delay: Option<f32> = None;
     let mut exit_delay: Option<f32> = None;
     let mut preset_name: Option<String> = None;
+    let mut tour = false;
+    #[cfg(feature = "scripting")]
+    let mut script_path: Option<std::path::PathBuf> = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -179,6 +189,21 @@ fn main() {
                     return;
                 }
             }
+            #[cfg(feature = "scripting")]
+            "--script" => {
+                if i + 1 < args.len() {
+                    script_path = Some(std::path::PathBuf::from(&args[i + 1]));
+                    i += 2;
+                } else {
+                    eprintln!("--script requires a file path");
+                    print_help();
+                    return;
+                }
+            }
+            "--tour" => {
+                tour = true;
+                i += 1;
+            }
             "--list-presets" => {
                 list_presets();
                 return;
@@ -195,7 +220,17 @@ fn main() {
         }
     }
 
-    env_logger::init();
+    // Log to the terminal (RUST_LOG) and to the in-app console
+    let terminal_logger = env_logger::Builder::from_env(
+        env_logger::Env::default().default_filter_or("warn,par_fractal=info"),
+    )
+    .build();
+    let terminal_level = terminal_logger.filter();
+    if let Err(e) =
+        ui::console::ConsoleLogger::init(Some(Box::new(terminal_logger)), terminal_level)
+    {
+        eprintln!("Failed to initialize logging: {}", e);
+    }
 
     let event_loop = EventLoop::new().unwrap();
 
@@ -210,8 +245,15 @@ fn main() {
     let window = event_loop.create_window(window_attributes).unwrap();
 
     let mut app = pollster::block_on(App::new(window, screenshot_delay, exit_delay, preset_name));
+    #[cfg(feature = "scripting")]
+    if let Some(path) = script_path {
+        app.load_script(&path);
+    }
+    if tour {
+        app.start_tour();
+    }
 
-    #[allow(deprecated)]
+    #[allow(deprecated, clippy::collapsible_match)]
     event_loop
         .run(move |event, target| match event {
             Event::WindowEvent {
@@ -226,11 +268,22 @@ fn main() {
                         }
                         WindowEvent::RedrawRequested => {
                             app.update();
-                            match app.render() {
-                                Ok(_) => {}
-                                Err(wgpu::SurfaceError::Lost) => app.resize(app.size()),
-                                Err(wgpu::SurfaceError::OutOfMemory) => target.exit(),
-                                Err(e) => eprintln!("{:?}", e),
+                            match app.render_frame() {
+                                RenderStatus::Ok => {}
+                                RenderStatus::Rebuild => app.rebuild_renderer(),
+                                RenderStatus::Fatal(message) => {
+                                    eprintln!("Fatal GPU error: {}", message);
+                                    rfd::MessageDialog::new()
+                                        .set_level(rfd::MessageLevel::Error)
+                                        .set_title("Par Fractal - GPU error")
+                                        .set_description(format!(
+                                            "{}\n\nTry restarting Par Fractal, or select another GPU under \
+                                             GPU Selection.",
+                                            message
+                                        ))
+                                        .show();
+                                    target.exit();
+                                }
                             }
                         }
                         _ => {}
@@ -242,7 +295,14 @@ fn main() {
                 if app.should_exit() {
                     target.exit();
                 }
-                app.window().request_redraw();
+                // Pace redraws to the frame rate cap (or idle rate when the scene is static)
+                match app.next_frame_deadline() {
+                    Some(deadline) => target.set_control_flow(ControlFlow::WaitUntil(deadline)),
+                    None => {
+                        target.set_control_flow(ControlFlow::Wait);
+                        app.window().request_redraw();
+                    }
+                }
             }
             _ => {}
         })
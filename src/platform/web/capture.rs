@@ -20,6 +20,33 @@ impl Default for WebCapture {
     }
 }
 
+/// Encode raw RGBA data as a PNG blob
+pub(super) fn png_blob(
+    width: u32,
+    height: u32,
+    data: &[u8],
+) -> Result<web_sys::Blob, PlatformError> {
+    // Create image from raw RGBA data
+    let img: ImageBuffer<Rgba<u8>, Vec<u8>> =
+        ImageBuffer::from_raw(width, height, data.to_vec())
+            .ok_or_else(|| PlatformError::IoError("Failed to create image buffer".into()))?;
+
+    // Encode as PNG
+    let mut png_bytes = Vec::new();
+    img.write_to(&mut Cursor::new(&mut png_bytes), image::ImageFormat::Png)
+        .map_err(|e| PlatformError::IoError(e.to_string()))?;
+
+    let array = js_sys::Uint8Array::from(&png_bytes[..]);
+    let blob_parts = js_sys::Array::new();
+    blob_parts.push(&array);
+
+    let mut options = web_sys::BlobPropertyBag::new();
+    options.type_("image/png");
+
+    web_sys::Blob::new_with_u8_array_sequence_and_options(&blob_parts, &options)
+        .map_err(|_| PlatformError::IoError("Failed to create blob".into()))
+}
+
 impl Capture for WebCapture {
     fn save_screenshot(
         &self,
@@ -33,16 +60,6 @@ impl Capture for WebCapture {
             .document()
             .ok_or(PlatformError::NotSupported("no document".into()))?;
 
-        // Create image from raw RGBA data
-        let img: ImageBuffer<Rgba<u8>, Vec<u8>> =
-            ImageBuffer::from_raw(width, height, data.to_vec())
-                .ok_or_else(|| PlatformError::IoError("Failed to create image buffer".into()))?;
-
-        // Encode as PNG
-        let mut png_bytes = Vec::new();
-        img.write_to(&mut Cursor::new(&mut png_bytes), image::ImageFormat::Png)
-            .map_err(|e| PlatformError::IoError(e.to_string()))?;
-
         // Generate filename with timestamp
         let date = js_sys::Date::new_0();
         let timestamp = format!(
@@ -57,15 +74,7 @@ impl Capture for WebCapture {
         let filename = format!("{}_{}.png", filename_prefix, timestamp);
 
         // Create blob and trigger download
-        let array = js_sys::Uint8Array::from(&png_bytes[..]);
-        let blob_parts = js_sys::Array::new();
-        blob_parts.push(&array);
-
-        let mut options = web_sys::BlobPropertyBag::new();
-        options.type_("image/png");
-
-        let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&blob_parts, &options)
-            .map_err(|_| PlatformError::IoError("Failed to create blob".into()))?;
+        let blob = png_blob(width, height, data)?;
 
         let url = web_sys::Url::create_object_url_with_blob(&blob)
             .map_err(|_| PlatformError::IoError("Failed to create URL".into()))?;
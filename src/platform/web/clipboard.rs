@@ -0,0 +1,41 @@
+//! Web clipboard access through the async Clipboard API
+
+use super::capture::png_blob;
+use crate::platform::PlatformError;
+
+fn clipboard() -> Result<web_sys::Clipboard, PlatformError> {
+    let window = web_sys::window().ok_or(PlatformError::NotSupported("no window".into()))?;
+    Ok(window.navigator().clipboard())
+}
+
+/// Copy text to the system clipboard; egui only keeps its own copy on web
+pub fn copy_text(text: &str) {
+    let promise = match clipboard() {
+        Ok(clipboard) => clipboard.write_text(text),
+        Err(e) => {
+            log::error!("Failed to copy text: {}", e);
+            return;
+        }
+    };
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Err(e) = wasm_bindgen_futures::JsFuture::from(promise).await {
+            log::error!("Failed to copy text: {:?}", e);
+        }
+    });
+}
+
+/// Copy raw RGBA data to the system clipboard as a PNG image
+pub async fn copy_png(width: u32, height: u32, data: &[u8]) -> Result<(), PlatformError> {
+    let blob = png_blob(width, height, data)?;
+    let record = js_sys::Object::new();
+    js_sys::Reflect::set(&record, &"image/png".into(), &blob)
+        .map_err(|_| PlatformError::IoError("Failed to build clipboard item".into()))?;
+    let item = web_sys::ClipboardItem::new_with_record_from_str_to_blob_promise(&record)
+        .map_err(|_| PlatformError::NotSupported("ClipboardItem".into()))?;
+
+    let promise = clipboard()?.write(&js_sys::Array::of1(&item));
+    wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .map_err(|e| PlatformError::IoError(format!("{:?}", e)))?;
+    Ok(())
+}
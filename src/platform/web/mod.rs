@@ -1,9 +1,11 @@
 //! Web platform implementations using browser APIs
 
 mod capture;
+mod clipboard;
 mod file_dialog;
 mod storage;
 
 pub use capture::WebCapture;
+pub use clipboard::{copy_png, copy_text};
 pub use file_dialog::WebFileDialog;
 pub use storage::WebStorage;
@@ -0,0 +1,269 @@
+//! Mip-chain bloom
+//!
+//! The bright pass is downsampled through a chain of half-resolution levels with
+//! a 13-tap filter, then upsampled back with a tent filter, each level added into
+//! the next larger one. The small levels give the wide, soft part of the glow and
+//! the large levels keep it tight around highlights, so bright areas glow
+//! smoothly instead of getting the blocky halo of a single blur. The quality
+//! setting chooses how many levels are used.
+
+use super::Renderer;
+use crate::fractal::BloomQuality;
+
+/// Smallest mip dimension worth filtering
+const MIN_MIP_SIZE: u32 = 2;
+
+/// Number of mip levels used for a target size, limited so the smallest level
+/// is still at least `MIN_MIP_SIZE` pixels on each side
+pub fn bloom_level_count(width: u32, height: u32, quality: BloomQuality) -> u32 {
+    let mut levels = 0;
+    let (mut w, mut h) = (width, height);
+    while levels < quality.mip_count() && w / 2 >= MIN_MIP_SIZE && h / 2 >= MIN_MIP_SIZE {
+        w /= 2;
+        h /= 2;
+        levels += 1;
+    }
+    levels.max(1)
+}
+
+/// Pipelines for the downsample, upsample and final resolve passes
+pub struct BloomPipelines {
+    downsample: wgpu::RenderPipeline,
+    /// Additive blend into the next larger level
+    upsample: wgpu::RenderPipeline,
+    resolve: wgpu::RenderPipeline,
+}
+
+impl BloomPipelines {
+    pub(super) fn new(
+        device: &wgpu::Device,
+        shader: &wgpu::ShaderModule,
+        layout: &wgpu::PipelineLayout,
+        vertex_buffer_layout: &wgpu::VertexBufferLayout,
+    ) -> Self {
+        let create = |label: &str, entry_point: &str, blend: wgpu::BlendState| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                cache: None,
+                label: Some(label),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: shader,
+                    entry_point: Some("vs_main"),
+                    buffers: std::slice::from_ref(vertex_buffer_layout),
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: shader,
+                    entry_point: Some(entry_point),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: wgpu::TextureFormat::Rgba16Float,
+                        blend: Some(blend),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleStrip,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        };
+
+        let additive = wgpu::BlendState {
+            color: wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha: wgpu::BlendComponent::REPLACE,
+        };
+
+        Self {
+            downsample: create(
+                "Bloom Downsample Pipeline",
+                "fs_bloom_downsample",
+                wgpu::BlendState::REPLACE,
+            ),
+            upsample: create("Bloom Upsample Pipeline", "fs_bloom_upsample", additive),
+            resolve: create(
+                "Bloom Resolve Pipeline",
+                "fs_bloom_resolve",
+                wgpu::BlendState::REPLACE,
+            ),
+        }
+    }
+}
+
+/// A render target in the chain and the bind group that samples it
+struct BloomLevel {
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+/// Textures for one bloom chain: the full-resolution bright pass, the mip levels
+/// and the full-resolution result the composite pass samples
+pub struct BloomChain {
+    bright: BloomLevel,
+    mips: Vec<BloomLevel>,
+    result_view: wgpu::TextureView,
+    quality: BloomQuality,
+}
+
+impl BloomChain {
+    pub(super) fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+        width: u32,
+        height: u32,
+        quality: BloomQuality,
+    ) -> Self {
+        let level = |width: u32, height: u32, label: &str| {
+            let (_, view) = Renderer::create_render_texture(device, width, height, label);
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some(label),
+                layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(sampler),
+                    },
+                ],
+            });
+            BloomLevel { view, bind_group }
+        };
+
+        let (width, height) = (width.max(1), height.max(1));
+        let mips = (1..=bloom_level_count(width, height, quality))
+            .map(|i| {
+                level(
+                    (width >> i).max(1),
+                    (height >> i).max(1),
+                    &format!("Bloom Mip {}", i),
+                )
+            })
+            .collect();
+        let (_, result_view) =
+            Renderer::create_render_texture(device, width, height, "Bloom Texture");
+
+        Self {
+            bright: level(width, height, "Bright Texture"),
+            mips,
+            result_view,
+            quality,
+        }
+    }
+
+    /// Final bloom texture (bind this in the composite pass)
+    pub fn result_view(&self) -> &wgpu::TextureView {
+        &self.result_view
+    }
+
+    pub fn quality(&self) -> BloomQuality {
+        self.quality
+    }
+}
+
+/// Bloom methods
+impl Renderer {
+    /// Create the bloom textures for a render target of the given size
+    pub fn create_bloom_chain(&self, width: u32, height: u32, quality: BloomQuality) -> BloomChain {
+        BloomChain::new(
+            &self.device,
+            &self.bloom_extract_pipeline.get_bind_group_layout(0),
+            &self.sampler,
+            width,
+            height,
+            quality,
+        )
+    }
+
+    /// Encode the bloom passes: bright extract from the scene, downsample through
+    /// the chain, then upsample back and resolve into the chain's result texture
+    pub fn encode_bloom(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        scene_bind_group: &wgpu::BindGroup,
+        chain: &BloomChain,
+    ) {
+        let draw = |encoder: &mut wgpu::CommandEncoder,
+                    label: &str,
+                    target: &wgpu::TextureView,
+                    load: wgpu::LoadOp<wgpu::Color>,
+                    pipeline: &wgpu::RenderPipeline,
+                    source: &wgpu::BindGroup| {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some(label),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, source, &[]);
+            pass.set_bind_group(1, &self.bloom_params_bind_group, &[]);
+            pass.set_vertex_buffer(0, self.postprocess_vertex_buffer.slice(..));
+            pass.draw(0..4, 0..1);
+        };
+        let clear = wgpu::LoadOp::Clear(wgpu::Color::BLACK);
+        let pipelines = &self.bloom_pipelines;
+
+        draw(
+            encoder,
+            "Bloom Extract Pass",
+            &chain.bright.view,
+            clear,
+            &self.bloom_extract_pipeline,
+            scene_bind_group,
+        );
+
+        let mut source = &chain.bright;
+        for mip in &chain.mips {
+            draw(
+                encoder,
+                "Bloom Downsample Pass",
+                &mip.view,
+                clear,
+                &pipelines.downsample,
+                &source.bind_group,
+            );
+            source = mip;
+        }
+
+        for pair in chain.mips.windows(2).rev() {
+            draw(
+                encoder,
+                "Bloom Upsample Pass",
+                &pair[0].view,
+                wgpu::LoadOp::Load,
+                &pipelines.upsample,
+                &pair[1].bind_group,
+            );
+        }
+
+        let top = chain.mips.first().unwrap_or(&chain.bright);
+        draw(
+            encoder,
+            "Bloom Resolve Pass",
+            &chain.result_view,
+            clear,
+            &pipelines.resolve,
+            &top.bind_group,
+        );
+    }
+}
@@ -30,7 +30,8 @@ pub struct AccumulationDisplayUniforms {
     pub log_scale: f32,
     pub gamma: f32,
     pub palette_offset: f32,
-    pub _padding: f32,
+    /// AttractorColorMode shader index; 0 colors by density
+    pub color_mode: u32,
     /// 8 palette colors, each as [r, g, b, a] (alpha unused but needed for alignment)
     pub palette: [[f32; 4]; 8],
 }
@@ -41,7 +42,7 @@ impl Default for AccumulationDisplayUniforms {
             log_scale: 1.0,
             gamma: 0.6,
             palette_offset: 0.0,
-            _padding: 0.0,
+            color_mode: 0,
             // Default fire palette (8 colors)
             palette: [
                 [0.0, 0.0, 0.0, 1.0],   // Black
@@ -82,7 +83,9 @@ pub struct AttractorComputeUniforms {
     // Accumulation control
     pub total_iterations: u32,
     pub clear_accumulation: u32,
-    pub _padding: [u32; 2],
+    /// AttractorColorMode shader index; 0 records hits only
+    pub color_mode: u32,
+    pub _padding: u32,
 }
 
 /// Uniforms for the Buddhabrot compute shader
@@ -144,7 +147,8 @@ impl Default for AttractorComputeUniforms {
             attractor_type: 0, // Hopalong
             total_iterations: 0,
             clear_accumulation: 1,
-            _padding: [0; 2],
+            color_mode: 0,
+            _padding: 0,
         }
     }
 }
@@ -161,6 +165,10 @@ pub struct AccumulationTexture {
     pub texture: wgpu::Texture,
     /// View for binding to shaders
     pub view: wgpu::TextureView,
+    /// Per-pixel sum of the value the attractor color mode tracks, alongside
+    /// the hit count (unused when coloring by density)
+    pub attribute_texture: wgpu::Texture,
+    pub attribute_view: wgpu::TextureView,
     /// Bind group for compute shader access (read-write)
     pub compute_bind_group: wgpu::BindGroup,
     /// Texture dimensions
@@ -184,51 +192,53 @@ impl AccumulationTexture {
         compute_bind_group_layout: &wgpu::BindGroupLayout,
         label: &str,
     ) -> Self {
-        let texture = device.create_texture(&wgpu::TextureDescriptor {
-            label: Some(label),
-            size: wgpu::Extent3d {
-                width,
-                height,
-                depth_or_array_layers: 1,
-            },
-            mip_level_count: 1,
-            sample_count: 1,
-            dimension: wgpu::TextureDimension::D2,
-            // Use R32Uint for atomic accumulation - widely supported for read-write storage
-            // We only need hit count in R channel, other channels unused
-            format: wgpu::TextureFormat::R32Uint,
-            // STORAGE_BINDING for compute write, TEXTURE_BINDING for fragment read
-            usage: wgpu::TextureUsages::STORAGE_BINDING
-                | wgpu::TextureUsages::TEXTURE_BINDING
-                | wgpu::TextureUsages::COPY_DST, // For clearing
-            view_formats: &[],
-        });
+        let texture = create_accumulation_storage_texture(device, width, height, label);
+        let attribute_texture = create_accumulation_storage_texture(
+            device,
+            width,
+            height,
+            &format!("{} Attribute", label),
+        );
 
         let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let attribute_view = attribute_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
-        // Compute bind group (read-write storage texture)
+        // Compute bind group (read-write storage textures)
         let compute_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some(&format!("{} Compute Bind Group", label)),
             layout: compute_bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: wgpu::BindingResource::TextureView(&view),
-            }],
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&attribute_view),
+                },
+            ],
         });
 
         Self {
             texture,
             view,
+            attribute_texture,
+            attribute_view,
             compute_bind_group,
             width,
             height,
         }
     }
 
-    /// Clear the accumulation texture to zeros.
+    /// Clear the accumulation and attribute textures to zeros.
     ///
-    /// This queues a buffer copy to zero out the texture.
+    /// This queues a buffer copy to zero out the textures.
     pub fn clear(&self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.clear_texture(device, queue, &self.texture);
+        self.clear_texture(device, queue, &self.attribute_texture);
+    }
+
+    fn clear_texture(&self, device: &wgpu::Device, queue: &wgpu::Queue, texture: &wgpu::Texture) {
         // bytes_per_row must be aligned to COPY_BYTES_PER_ROW_ALIGNMENT (256 bytes)
         const COPY_BYTES_PER_ROW_ALIGNMENT: u32 = 256;
         let unpadded_bytes_per_row = self.width * 4; // 1 u32 * 4 bytes (R32Uint)
@@ -265,7 +275,7 @@ impl AccumulationTexture {
                 },
             },
             wgpu::TexelCopyTextureInfo {
-                texture: &self.texture,
+                texture,
                 mip_level: 0,
                 origin: wgpu::Origin3d::ZERO,
                 aspect: wgpu::TextureAspect::All,
@@ -304,20 +314,51 @@ impl AccumulationTexture {
     }
 }
 
-/// Creates the bind group layout for compute shader storage texture access.
+/// Creates an R32Uint texture the compute shaders accumulate into and the
+/// display pass reads.
+pub fn create_accumulation_storage_texture(
+    device: &wgpu::Device,
+    width: u32,
+    height: u32,
+    label: &str,
+) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        // Use R32Uint for atomic accumulation - widely supported for read-write storage
+        // We only need hit count in R channel, other channels unused
+        format: wgpu::TextureFormat::R32Uint,
+        // STORAGE_BINDING for compute write, TEXTURE_BINDING for fragment read
+        usage: wgpu::TextureUsages::STORAGE_BINDING
+            | wgpu::TextureUsages::TEXTURE_BINDING
+            | wgpu::TextureUsages::COPY_DST, // For clearing
+        view_formats: &[],
+    })
+}
+
+/// Creates the bind group layout for compute shader storage texture access:
+/// the hit counts at binding 0 and the color mode attribute at binding 1.
 pub fn create_compute_storage_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    let storage_entry = |binding| wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::COMPUTE,
+        ty: wgpu::BindingType::StorageTexture {
+            access: wgpu::StorageTextureAccess::ReadWrite,
+            format: wgpu::TextureFormat::R32Uint, // Widely supported for read-write
+            view_dimension: wgpu::TextureViewDimension::D2,
+        },
+        count: None,
+    };
     device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
         label: Some("Compute Storage Texture Layout"),
-        entries: &[wgpu::BindGroupLayoutEntry {
-            binding: 0,
-            visibility: wgpu::ShaderStages::COMPUTE,
-            ty: wgpu::BindingType::StorageTexture {
-                access: wgpu::StorageTextureAccess::ReadWrite,
-                format: wgpu::TextureFormat::R32Uint, // Widely supported for read-write
-                view_dimension: wgpu::TextureViewDimension::D2,
-            },
-            count: None,
-        }],
+        entries: &[storage_entry(0), storage_entry(1)],
     })
 }
 
@@ -654,6 +695,221 @@ impl BuddhabrotComputePipeline {
     }
 }
 
+/// Number of persistent orbits the 3D attractor shader advances each frame
+pub const ATTRACTOR_3D_ORBITS: u32 = 16_384;
+
+/// Uniforms for the 3D attractor compute shader
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct Attractor3DComputeUniforms {
+    // Camera view-projection matrix (column-major)
+    pub view_proj: [[f32; 4]; 4],
+
+    // Attractor parameters (from julia_c, power, fractal_fold)
+    pub params: [f32; 4],
+
+    // Rendering parameters
+    pub width: u32,
+    pub height: u32,
+    pub steps_per_thread: u32,
+    pub attractor_type: u32,
+
+    // World scale applied to attractor coordinates
+    pub scale: f32,
+    // Varies the random starting points between reseeds
+    pub seed: u32,
+    // 1 to start every orbit from a new random point
+    pub reseed: u32,
+    pub _padding: u32,
+}
+
+impl Attractor3DComputeUniforms {
+    /// Whether the orbits themselves differ (type or parameters), as opposed to
+    /// only the way they're projected
+    pub fn orbits_differ(&self, other: &Self) -> bool {
+        self.attractor_type != other.attractor_type || self.params != other.params
+    }
+
+    /// Whether points accumulated under `other` would land on different pixels
+    pub fn view_differs(&self, other: &Self) -> bool {
+        self.orbits_differ(other)
+            || self.view_proj != other.view_proj
+            || self.scale != other.scale
+            || self.width != other.width
+            || self.height != other.height
+    }
+}
+
+impl Default for Attractor3DComputeUniforms {
+    fn default() -> Self {
+        Self {
+            view_proj: glam::Mat4::IDENTITY.to_cols_array_2d(),
+            params: [0.0; 4],
+            width: 1920,
+            height: 1080,
+            steps_per_thread: 16,
+            // No attractor yet, so the first real uniforms always reseed
+            attractor_type: u32::MAX,
+            scale: 1.0,
+            seed: 0,
+            reseed: 1,
+            _padding: 0,
+        }
+    }
+}
+
+/// Creates the bind group layout for the 3D attractor uniforms and orbit state.
+pub fn create_attractor3d_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("Attractor 3D Compute Layout"),
+        entries: &[
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: Some(
+                        std::num::NonZeroU64::new(
+                            std::mem::size_of::<Attractor3DComputeUniforms>() as u64,
+                        )
+                        .unwrap(),
+                    ),
+                },
+                count: None,
+            },
+            // Orbit positions that persist between frames
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only: false },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+        ],
+    })
+}
+
+/// Manages the compute pipeline for 3D strange attractor accumulation.
+///
+/// Orbits are projected through the camera and splatted into the same
+/// accumulation texture as the 2D attractors, so the texture's compute bind
+/// group (built from the 2D pipeline's storage layout) is used as group 0.
+pub struct Attractor3DComputePipeline {
+    /// The compute pipeline
+    pub pipeline: wgpu::ComputePipeline,
+    /// Uniform buffer for compute parameters
+    pub uniform_buffer: wgpu::Buffer,
+    /// Persistent orbit positions, one vec4 per orbit
+    pub orbit_buffer: wgpu::Buffer,
+    /// Bind group for the uniforms and orbit buffer
+    pub bind_group: wgpu::BindGroup,
+    /// Current uniform values
+    pub uniforms: Attractor3DComputeUniforms,
+}
+
+impl Attractor3DComputePipeline {
+    /// Create a new 3D attractor compute pipeline. `storage_layout` is the
+    /// accumulation texture layout shared with the 2D attractor pipeline.
+    pub fn new(device: &wgpu::Device, storage_layout: &wgpu::BindGroupLayout) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Attractor 3D Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(
+                include_str!("../shaders/attractor3d_compute.wgsl").into(),
+            ),
+        });
+
+        let layout = create_attractor3d_layout(device);
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Attractor 3D Compute Pipeline Layout"),
+            bind_group_layouts: &[storage_layout, &layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Attractor 3D Compute Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        let uniforms = Attractor3DComputeUniforms::default();
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Attractor 3D Compute Uniform Buffer"),
+            size: std::mem::size_of::<Attractor3DComputeUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let orbit_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Attractor 3D Orbit Buffer"),
+            size: (ATTRACTOR_3D_ORBITS as usize * std::mem::size_of::<[f32; 4]>()) as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Attractor 3D Compute Bind Group"),
+            layout: &layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: orbit_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        Self {
+            pipeline,
+            uniform_buffer,
+            orbit_buffer,
+            bind_group,
+            uniforms,
+        }
+    }
+
+    /// Set the uniforms for the next dispatch and upload them. Orbits are
+    /// reseeded when the attractor type or its parameters changed.
+    pub fn set_uniforms(&mut self, queue: &wgpu::Queue, mut uniforms: Attractor3DComputeUniforms) {
+        uniforms.reseed = uniforms.orbits_differ(&self.uniforms) as u32;
+        self.uniforms = uniforms;
+        queue.write_buffer(
+            &self.uniform_buffer,
+            0,
+            bytemuck::cast_slice(&[self.uniforms]),
+        );
+    }
+
+    /// Dispatch the compute shader to advance every orbit by `steps_per_thread`
+    /// steps and accumulate the projected points.
+    pub fn dispatch(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        accumulation_bind_group: &wgpu::BindGroup,
+    ) {
+        let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Attractor 3D Compute Pass"),
+            timestamp_writes: None,
+        });
+
+        compute_pass.set_pipeline(&self.pipeline);
+        compute_pass.set_bind_group(0, accumulation_bind_group, &[]);
+        compute_pass.set_bind_group(1, &self.bind_group, &[]);
+        // One thread per orbit
+        compute_pass.dispatch_workgroups(ATTRACTOR_3D_ORBITS.div_ceil(256), 1, 1);
+    }
+}
+
 /// Configuration for attractor accumulation rendering
 #[derive(Debug, Clone)]
 pub struct AttractorAccumulationConfig {
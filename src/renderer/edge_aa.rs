@@ -0,0 +1,169 @@
+//! Adaptive supersampling of the 2D fractal boundary
+//!
+//! Only pixels on the boundary of the set alias badly, and they are a small
+//! fraction of the image. After the scene pass, the frame is copied and the
+//! shader's `fs_edge_aa` entry point compares each pixel with its neighbours:
+//! pixels that contrast sharply are re-rendered with a grid of sub-pixel
+//! samples, the rest keep the first pass's color. The samples are offsets from
+//! the same per-pixel coordinate the first pass used (including the f64
+//! coordinate table), so the refined pixels line up with their neighbours.
+
+use super::Renderer;
+
+/// GPU resources for the boundary pass, sized to the scene texture
+pub struct EdgeSupersampling {
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    /// Copy of the first pass, read by the edge detection
+    source: wgpu::Texture,
+    bind_group: wgpu::BindGroup,
+}
+
+impl EdgeSupersampling {
+    fn new(
+        device: &wgpu::Device,
+        uniform_layout: &wgpu::BindGroupLayout,
+        shader: &wgpu::ShaderModule,
+        size: wgpu::Extent3d,
+    ) -> Self {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Edge Supersampling Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Edge Supersampling Pipeline Layout"),
+            bind_group_layouts: &[uniform_layout, &bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            cache: None,
+            label: Some("Edge Supersampling Pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: Some("vs_main"),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array![0 => Float32x2],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: Some("fs_edge_aa"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba16Float, // Writes back to scene_texture
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        let (source, bind_group) = Self::create_source(device, &bind_group_layout, size);
+        Self {
+            pipeline,
+            bind_group_layout,
+            source,
+            bind_group,
+        }
+    }
+
+    fn create_source(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        size: wgpu::Extent3d,
+    ) -> (wgpu::Texture, wgpu::BindGroup) {
+        let source = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Edge Supersampling Source"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba16Float,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let view = source.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Edge Supersampling Bind Group"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+        (source, bind_group)
+    }
+}
+
+/// Boundary supersampling methods
+impl Renderer {
+    /// Encode the boundary pass over the rendered 2D scene texture
+    pub fn encode_edge_supersampling(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        let size = self.scene_texture.size();
+        match self.edge_supersampling.as_mut() {
+            None => {
+                self.edge_supersampling = Some(EdgeSupersampling::new(
+                    &self.device,
+                    &self.uniform_bind_group_layout,
+                    &self.fractal_shader,
+                    size,
+                ));
+            }
+            Some(edge) if edge.source.size() != size => {
+                (edge.source, edge.bind_group) =
+                    EdgeSupersampling::create_source(&self.device, &edge.bind_group_layout, size);
+            }
+            Some(_) => {}
+        }
+        let Some(edge) = self.edge_supersampling.as_ref() else {
+            return;
+        };
+
+        encoder.copy_texture_to_texture(
+            self.scene_texture.as_image_copy(),
+            edge.source.as_image_copy(),
+            size,
+        );
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Edge Supersampling Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &self.scene_view,
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+        render_pass.set_pipeline(&edge.pipeline);
+        render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+        render_pass.set_bind_group(1, &edge.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.draw(0..4, 0..1);
+    }
+}
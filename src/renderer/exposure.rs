@@ -0,0 +1,201 @@
+//! Histogram-based auto-exposure for 3D
+//!
+//! A compute pass measures the average log luminance of the scene texture
+//! (before bloom and color grading) and the result is read back asynchronously.
+//! The exposure scalar then adapts toward that measurement with exponential
+//! smoothing, like a camera's auto-exposure, and multiplies the user's brightness
+//! in the composite pass. Because the measurement ignores the brightest samples
+//! and is taken before bloom, small highlights don't make the exposure pump.
+
+use super::readback::AsyncReadback;
+use super::Renderer;
+
+/// Size of the reduction result (log2 luminance, coverage, padding)
+const RESULT_SIZE: u64 = 16;
+/// Average luminance the exposure adapts the scene toward
+const TARGET_LUMINANCE: f32 = 0.35;
+/// Exposure range in stops either side of neutral
+const MAX_EXPOSURE_STOPS: f32 = 3.0;
+/// Below this fraction of non-background samples there's nothing to meter
+const MIN_COVERAGE: f32 = 0.01;
+
+/// GPU resources and adaptation state for auto-exposure
+pub struct AutoExposure {
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    result_buffer: wgpu::Buffer,
+    readback: AsyncReadback,
+    /// Exposure the scene is adapting toward (stops)
+    target_stops: f32,
+    /// Current smoothed exposure (stops)
+    current_stops: f32,
+}
+
+impl AutoExposure {
+    fn new(device: &wgpu::Device) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Luminance Histogram Shader"),
+            source: wgpu::ShaderSource::Wgsl(
+                include_str!("../shaders/luminance_histogram.wgsl").into(),
+            ),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Luminance Histogram Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Luminance Histogram Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Luminance Histogram Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        let result_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Luminance Histogram Result"),
+            size: RESULT_SIZE,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            pipeline,
+            bind_group_layout,
+            result_buffer,
+            readback: AsyncReadback::new(
+                device,
+                "Luminance Histogram Readback",
+                RESULT_SIZE,
+                RESULT_SIZE,
+            ),
+            target_stops: 0.0,
+            current_stops: 0.0,
+        }
+    }
+
+    /// Exposure in stops that brings the measured log2 luminance to the target
+    pub fn exposure_for_luminance(log2_luminance: f32) -> f32 {
+        (TARGET_LUMINANCE.log2() - log2_luminance).clamp(-MAX_EXPOSURE_STOPS, MAX_EXPOSURE_STOPS)
+    }
+
+    /// Move the current exposure toward the target. `speed` is the adaptation rate
+    /// per second; the step is frame-rate independent.
+    fn adapt(&mut self, dt: f32, speed: f32) {
+        let blend = 1.0 - (-dt.max(0.0) * speed.max(0.0)).exp();
+        self.current_stops += (self.target_stops - self.current_stops) * blend;
+    }
+}
+
+/// Auto-exposure methods
+impl Renderer {
+    /// Encode the luminance measurement of the scene texture.
+    /// Skipped while the previous readback is still in flight.
+    pub fn encode_auto_exposure(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        let exposure = self
+            .auto_exposure
+            .get_or_insert_with(|| AutoExposure::new(&self.device));
+        if !exposure.readback.is_ready() {
+            return;
+        }
+
+        // Recreated each time so it always tracks the current (resized) scene texture
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Luminance Histogram Bind Group"),
+            layout: &exposure.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&self.scene_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: exposure.result_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        {
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Luminance Histogram Pass"),
+                timestamp_writes: None,
+            });
+            compute_pass.set_pipeline(&exposure.pipeline);
+            compute_pass.set_bind_group(0, &bind_group, &[]);
+            compute_pass.dispatch_workgroups(1, 1, 1);
+        }
+
+        encoder.copy_buffer_to_buffer(
+            &exposure.result_buffer,
+            0,
+            exposure.readback.buffer(),
+            0,
+            RESULT_SIZE,
+        );
+        exposure.readback.mark_copied();
+    }
+
+    /// Start mapping the luminance result. Call after the frame's commands are submitted.
+    pub fn begin_auto_exposure_readback(&mut self) {
+        if let Some(exposure) = self.auto_exposure.as_mut() {
+            exposure.readback.begin();
+        }
+    }
+
+    /// Collect new measurements and adapt the exposure. When disabled the
+    /// exposure resets to neutral so re-enabling adapts from the user's brightness.
+    pub fn update_auto_exposure(&mut self, enabled: bool, dt: f32, speed: f32) {
+        let Some(exposure) = self.auto_exposure.as_mut() else {
+            return;
+        };
+        if !enabled {
+            exposure.target_stops = 0.0;
+            exposure.current_stops = 0.0;
+            return;
+        }
+
+        if let Some(values) = exposure.readback.poll(&self.device) {
+            let (log2_luminance, coverage) = (values[0], values[1]);
+            if coverage >= MIN_COVERAGE {
+                exposure.target_stops = AutoExposure::exposure_for_luminance(log2_luminance);
+            }
+        }
+        exposure.adapt(dt, speed);
+    }
+
+    /// Current auto-exposure multiplier (1.0 when auto-exposure hasn't run)
+    pub fn auto_exposure_scale(&self) -> f32 {
+        self.auto_exposure
+            .as_ref()
+            .map_or(1.0, |exposure| exposure.current_stops.exp2())
+    }
+}
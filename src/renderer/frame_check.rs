@@ -0,0 +1,166 @@
+//! Blank frame detection
+//!
+//! A compute pass samples the scene texture on a coarse grid and counts NaN and
+//! visible samples. The result is read back asynchronously like the auto-exposure
+//! measurement, so checking costs one tiny dispatch and never stalls the frame.
+
+use super::readback::AsyncReadback;
+use super::Renderer;
+
+/// Size of the check result (invalid fraction, visible fraction, padding)
+const RESULT_SIZE: u64 = 16;
+
+/// What the frame check found in the scene texture
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameStats {
+    /// Fraction of samples with a NaN or infinite channel
+    pub invalid_fraction: f32,
+    /// Fraction of finite samples that aren't black
+    pub visible_fraction: f32,
+}
+
+impl FrameStats {
+    /// Nothing but black and/or NaN was rendered
+    pub fn is_blank(&self) -> bool {
+        self.visible_fraction == 0.0
+    }
+}
+
+/// GPU resources for the frame check
+pub struct FrameCheck {
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    result_buffer: wgpu::Buffer,
+    readback: AsyncReadback,
+}
+
+impl FrameCheck {
+    fn new(device: &wgpu::Device) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Frame Check Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/frame_check.wgsl").into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Frame Check Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Frame Check Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Frame Check Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        let result_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Frame Check Result"),
+            size: RESULT_SIZE,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            pipeline,
+            bind_group_layout,
+            result_buffer,
+            readback: AsyncReadback::new(device, "Frame Check Readback", RESULT_SIZE, RESULT_SIZE),
+        }
+    }
+}
+
+/// Frame check methods
+impl Renderer {
+    /// Encode a check of the scene texture. Returns false (and encodes nothing)
+    /// while the previous readback is still in flight.
+    pub fn encode_frame_check(&mut self, encoder: &mut wgpu::CommandEncoder) -> bool {
+        let check = self
+            .frame_check
+            .get_or_insert_with(|| FrameCheck::new(&self.device));
+        if !check.readback.is_ready() {
+            return false;
+        }
+
+        // Recreated each time so it always tracks the current (resized) scene texture
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Frame Check Bind Group"),
+            layout: &check.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&self.scene_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: check.result_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        {
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Frame Check Pass"),
+                timestamp_writes: None,
+            });
+            compute_pass.set_pipeline(&check.pipeline);
+            compute_pass.set_bind_group(0, &bind_group, &[]);
+            compute_pass.dispatch_workgroups(1, 1, 1);
+        }
+
+        encoder.copy_buffer_to_buffer(
+            &check.result_buffer,
+            0,
+            check.readback.buffer(),
+            0,
+            RESULT_SIZE,
+        );
+        check.readback.mark_copied();
+        true
+    }
+
+    /// Start mapping the check result. Call after the frame's commands are submitted.
+    pub fn begin_frame_check_readback(&mut self) {
+        if let Some(check) = self.frame_check.as_mut() {
+            check.readback.begin();
+        }
+    }
+
+    /// Collect a finished frame check without blocking
+    pub fn poll_frame_check(&mut self) -> Option<FrameStats> {
+        let check = self.frame_check.as_mut()?;
+        let values = check.readback.poll(&self.device)?;
+        Some(FrameStats {
+            invalid_fraction: values[0],
+            visible_fraction: values[1],
+        })
+    }
+}
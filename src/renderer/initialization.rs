@@ -1,10 +1,17 @@
+use super::compute::create_accumulation_storage_texture;
+use super::{bloom, orbit_trap, precise_2d, surface_format};
 use super::{
-    AccumulationDisplayUniforms, AccumulationTexture, AttractorComputePipeline, BloomUniforms,
-    BlurUniforms, BuddhabrotAccumulationBuffer, BuddhabrotComputePipeline, GpuInfo,
-    PostProcessUniforms, Renderer, Uniforms,
+    AccumulationDisplayUniforms, AccumulationTexture, Attractor3DComputePipeline,
+    AttractorComputePipeline, BloomUniforms, BuddhabrotAccumulationBuffer,
+    BuddhabrotComputePipeline, GpuInfo, PostProcessUniforms, Renderer, Uniforms,
 };
+use crate::fractal::{CvdSimulation, SurfaceFormatSetting};
 use wgpu::util::DeviceExt;
 
+/// Primary ray march call in fractal.wgsl, swapped out for the f64 pipeline
+const F32_RAY_MARCH_CALL: &str = "let result = ray_march(ray_origin, ray_dir);";
+const F64_RAY_MARCH_CALL: &str = "let result = ray_march_f64(ray_origin, ray_dir);";
+
 /// GPU initialization and setup methods
 impl Renderer {
     #[cfg(not(target_arch = "wasm32"))]
@@ -34,11 +41,12 @@ impl Renderer {
         Vec::new()
     }
 
+    #[allow(dead_code)] // The native app always passes its GPU and format preferences
     pub async fn new(
         window: std::sync::Arc<winit::window::Window>,
         size: winit::dpi::PhysicalSize<u32>,
     ) -> Self {
-        Self::new_with_gpu_preference(window, size, None).await
+        Self::new_with_gpu_preference(window, size, None, SurfaceFormatSetting::Auto).await
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -46,6 +54,7 @@ impl Renderer {
         window: std::sync::Arc<winit::window::Window>,
         size: winit::dpi::PhysicalSize<u32>,
         preferred_gpu_index: Option<usize>,
+        surface_format: SurfaceFormatSetting,
     ) -> Self {
         let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
             backends: wgpu::Backends::all(),
@@ -93,7 +102,7 @@ impl Renderer {
                 .unwrap()
         };
 
-        Self::initialize_with_adapter(surface, adapter, size).await
+        Self::initialize_with_adapter(surface, adapter, size, surface_format).await
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -101,6 +110,7 @@ impl Renderer {
         window: std::sync::Arc<winit::window::Window>,
         size: winit::dpi::PhysicalSize<u32>,
         _preferred_gpu_index: Option<usize>,
+        surface_format: SurfaceFormatSetting,
     ) -> Self {
         // On web, browser handles GPU selection - ignore preference
         let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
@@ -119,18 +129,27 @@ impl Renderer {
             .await
             .expect("Failed to find an appropriate adapter");
 
-        Self::initialize_with_adapter(surface, adapter, size).await
+        Self::initialize_with_adapter(surface, adapter, size, surface_format).await
     }
 
     async fn initialize_with_adapter(
         surface: wgpu::Surface<'static>,
         adapter: wgpu::Adapter,
         size: winit::dpi::PhysicalSize<u32>,
+        surface_format_setting: SurfaceFormatSetting,
     ) -> Self {
+        // Double precision ray marching is optional, so only request f64 where available
+        let supports_f64 = adapter.features().contains(wgpu::Features::SHADER_F64);
+        let required_features = if supports_f64 {
+            wgpu::Features::SHADER_F64
+        } else {
+            wgpu::Features::empty()
+        };
+
         let (device, queue) = adapter
             .request_device(&wgpu::DeviceDescriptor {
                 label: None,
-                required_features: wgpu::Features::empty(),
+                required_features,
                 required_limits: wgpu::Limits::default(),
                 memory_hints: Default::default(),
                 experimental_features: Default::default(),
@@ -140,12 +159,20 @@ impl Renderer {
             .unwrap();
 
         let surface_caps = surface.get_capabilities(&adapter);
-        let surface_format = surface_caps
-            .formats
-            .iter()
-            .copied()
-            .find(|f| f.is_srgb())
-            .unwrap_or(surface_caps.formats[0]);
+        let surface_format =
+            surface_format::surface_format_for(surface_format_setting, &surface_caps.formats)
+                .unwrap_or_else(|| {
+                    log::warn!(
+                        "Surface format {} is not supported by this adapter, using Auto",
+                        surface_format_setting.name()
+                    );
+                    surface_format::surface_format_for(
+                        SurfaceFormatSetting::Auto,
+                        &surface_caps.formats,
+                    )
+                    .unwrap_or(surface_caps.formats[0])
+                });
+        log::info!("Surface format: {:?}", surface_format);
 
         let config = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
@@ -176,31 +203,82 @@ impl Renderer {
 
         let uniform_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-                entries: &[wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: Some(
-                            std::num::NonZeroU64::new(std::mem::size_of::<Uniforms>() as u64)
-                                .unwrap(),
-                        ),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: Some(
+                                std::num::NonZeroU64::new(std::mem::size_of::<Uniforms>() as u64)
+                                    .unwrap(),
+                            ),
+                        },
+                        count: None,
                     },
-                    count: None,
-                }],
+                    // Orbit trap image texture
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    // Precise 2D coordinate table
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
                 label: Some("uniform_bind_group_layout"),
             });
 
-        let uniform_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            layout: &uniform_bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: uniform_buffer.as_entire_binding(),
-            }],
-            label: Some("uniform_bind_group"),
+        // Orbit trap image starts as the procedural default; repeat so traps tile
+        let (trap_width, trap_height, trap_pixels) = orbit_trap::default_orbit_trap_image();
+        let orbit_trap_view = orbit_trap::create_orbit_trap_texture(
+            &device,
+            &queue,
+            trap_width,
+            trap_height,
+            &trap_pixels,
+        );
+        let orbit_trap_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Orbit Trap Sampler"),
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::Repeat,
+            address_mode_w: wgpu::AddressMode::Repeat,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
         });
 
+        let coord_table_buffer = precise_2d::create_coord_table_buffer(&device);
+        let uniform_bind_group = orbit_trap::create_uniform_bind_group(
+            &device,
+            &uniform_bind_group_layout,
+            &uniform_buffer,
+            &orbit_trap_view,
+            &orbit_trap_sampler,
+            &coord_table_buffer,
+        );
+
         let render_pipeline_layout =
             device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
                 label: Some("Render Pipeline Layout"),
@@ -208,47 +286,12 @@ impl Renderer {
                 push_constant_ranges: &[],
             });
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            cache: None,
-            label: Some("Render Pipeline"),
-            layout: Some(&render_pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                buffers: &[wgpu::VertexBufferLayout {
-                    array_stride: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
-                    step_mode: wgpu::VertexStepMode::Vertex,
-                    attributes: &wgpu::vertex_attr_array![0 => Float32x2],
-                }],
-                compilation_options: Default::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: wgpu::TextureFormat::Rgba16Float, // Render to HDR intermediate texture
-                    blend: Some(wgpu::BlendState::REPLACE),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-                compilation_options: Default::default(),
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleStrip,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: None,
-                polygon_mode: wgpu::PolygonMode::Fill,
-                unclipped_depth: false,
-                conservative: false,
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState {
-                count: 1,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
-            multiview: None,
-        });
+        let render_pipeline = Self::create_fractal_pipeline(
+            &device,
+            &render_pipeline_layout,
+            &shader,
+            "Render Pipeline",
+        );
 
         // Fullscreen quad vertices
         let vertices: &[f32] = &[-1.0, -1.0, 1.0, -1.0, -1.0, 1.0, 1.0, 1.0];
@@ -284,12 +327,6 @@ impl Renderer {
         // Create intermediate render textures
         let (scene_texture, scene_view) =
             Self::create_render_texture(&device, size.width, size.height, "Scene Texture");
-        let (bright_texture, bright_view) =
-            Self::create_render_texture(&device, size.width, size.height, "Bright Texture");
-        let (blur_temp_texture, blur_temp_view) =
-            Self::create_render_texture(&device, size.width, size.height, "Blur Temp Texture");
-        let (bloom_texture, bloom_view) =
-            Self::create_render_texture(&device, size.width, size.height, "Bloom Texture");
         let (composite_texture, composite_view) =
             Self::create_render_texture(&device, size.width, size.height, "Composite Texture");
 
@@ -312,7 +349,8 @@ impl Renderer {
         let bloom_uniforms = BloomUniforms {
             threshold: 0.7,
             intensity: 0.5,
-            _padding: [0.0; 2],
+            radius: 0.005,
+            level_weight: 1.0,
         };
         let bloom_uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Bloom Uniform Buffer"),
@@ -320,20 +358,6 @@ impl Renderer {
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
-        let blur_h_uniforms = BlurUniforms {
-            direction: [1.0, 0.0], // Horizontal
-            _padding: [0.0; 2],
-        };
-        let _blur_v_uniforms = BlurUniforms {
-            direction: [0.0, 1.0], // Vertical
-            _padding: [0.0; 2],
-        };
-        let blur_uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some("Blur Uniform Buffer"),
-            contents: bytemuck::cast_slice(&[blur_h_uniforms]),
-            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-        });
-
         let composite_uniforms = PostProcessUniforms {
             brightness: 1.0,
             contrast: 1.0,
@@ -346,7 +370,15 @@ impl Renderer {
             bloom_enabled: 0,
             bloom_intensity: 0.5,
             _padding2: [0.0; 2],
-            _padding3: [0.0; 4],
+            symmetry_mode: 0,
+            symmetry_segments: 6,
+            _padding3: [0.0; 2],
+            porthole_color: [0.0; 3],
+            porthole_enabled: 0,
+            porthole_radius: 0.9,
+            porthole_feather: 0.05,
+            porthole_oval: 0,
+            cvd_simulation: 0,
         };
         let composite_uniform_buffer =
             device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -482,41 +514,13 @@ impl Renderer {
                 multiview: None,
             });
 
-        // Blur pipeline
-        let blur_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-            label: Some("Blur Layout"),
-            bind_group_layouts: &[&texture_bind_group_layout, &uniform_layout],
-            push_constant_ranges: &[],
-        });
-
-        let blur_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            cache: None,
-            label: Some("Blur Pipeline"),
-            layout: Some(&blur_layout),
-            vertex: wgpu::VertexState {
-                module: &postprocess_shader,
-                entry_point: Some("vs_main"),
-                buffers: std::slice::from_ref(&vertex_buffer_layout),
-                compilation_options: Default::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &postprocess_shader,
-                entry_point: Some("fs_blur"),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: wgpu::TextureFormat::Rgba16Float,
-                    blend: Some(wgpu::BlendState::REPLACE),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-                compilation_options: Default::default(),
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleStrip,
-                ..Default::default()
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
-            multiview: None,
-        });
+        // Bloom mip chain pipelines (same bindings as the extract pass)
+        let bloom_pipelines = bloom::BloomPipelines::new(
+            &device,
+            &postprocess_shader,
+            &bloom_extract_layout,
+            &vertex_buffer_layout,
+        );
 
         // Composite pipeline
         let composite_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -554,6 +558,20 @@ impl Renderer {
             multiview: None,
         });
 
+        // The final pass sRGB-encodes itself on non-sRGB unorm surfaces
+        let encode_srgb = [(
+            "ENCODE_SRGB",
+            if surface_format::needs_shader_srgb_encode(config.format) {
+                1.0
+            } else {
+                0.0
+            },
+        )];
+        let output_compilation_options = wgpu::PipelineCompilationOptions {
+            constants: &encode_srgb,
+            ..Default::default()
+        };
+
         // FXAA pipeline
         let fxaa_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("FXAA Layout"),
@@ -579,7 +597,7 @@ impl Renderer {
                     blend: Some(wgpu::BlendState::REPLACE),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
-                compilation_options: Default::default(),
+                compilation_options: output_compilation_options.clone(),
             }),
             primitive: wgpu::PrimitiveState {
                 topology: wgpu::PrimitiveTopology::TriangleStrip,
@@ -615,7 +633,7 @@ impl Renderer {
                     blend: Some(wgpu::BlendState::REPLACE),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
-                compilation_options: Default::default(),
+                compilation_options: output_compilation_options.clone(),
             }),
             primitive: wgpu::PrimitiveState {
                 topology: wgpu::PrimitiveTopology::TriangleStrip,
@@ -626,21 +644,54 @@ impl Renderer {
             multiview: None,
         });
 
+        // UI overlay pipeline: blends the premultiplied sRGB UI texture onto float surfaces
+        let ui_overlay_pipeline = surface_format::is_float_format(config.format).then(|| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                cache: None,
+                label: Some("UI Overlay Pipeline"),
+                layout: Some(&copy_layout),
+                vertex: wgpu::VertexState {
+                    module: &postprocess_shader,
+                    entry_point: Some("vs_main"),
+                    buffers: std::slice::from_ref(&vertex_buffer_layout),
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &postprocess_shader,
+                    entry_point: Some("fs_copy"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: config.format,
+                        blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleStrip,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        });
+
         // Accumulation display pipeline (for visualizing accumulated attractor data)
-        // Bind group 0: uint accumulation texture
+        // Bind group 0: uint accumulation and attribute textures
+        let accumulation_texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Uint,
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
         let accumulation_texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("Accumulation Display Texture Layout"),
-                entries: &[wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Texture {
-                        sample_type: wgpu::TextureSampleType::Uint,
-                        view_dimension: wgpu::TextureViewDimension::D2,
-                        multisampled: false,
-                    },
-                    count: None,
-                }],
+                entries: &[accumulation_texture_entry(0), accumulation_texture_entry(1)],
             });
 
         // Bind group 1: display uniforms (log_scale, gamma)
@@ -731,35 +782,14 @@ impl Renderer {
             ],
         });
 
-        let bright_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Bright Bind Group"),
-            layout: &texture_bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: wgpu::BindingResource::TextureView(&bright_view),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::Sampler(&sampler),
-                },
-            ],
-        });
-
-        let blur_temp_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Blur Temp Bind Group"),
-            layout: &texture_bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: wgpu::BindingResource::TextureView(&blur_temp_view),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::Sampler(&sampler),
-                },
-            ],
-        });
+        let bloom_chain = bloom::BloomChain::new(
+            &device,
+            &texture_bind_group_layout,
+            &sampler,
+            size.width,
+            size.height,
+            Default::default(),
+        );
 
         let composite_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Composite Bind Group"),
@@ -775,7 +805,7 @@ impl Renderer {
                 },
                 wgpu::BindGroupEntry {
                     binding: 2,
-                    resource: wgpu::BindingResource::TextureView(&bloom_view),
+                    resource: wgpu::BindingResource::TextureView(bloom_chain.result_view()),
                 },
                 wgpu::BindGroupEntry {
                     binding: 3,
@@ -794,25 +824,6 @@ impl Renderer {
             }],
         });
 
-        let blur_h_params_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Blur H Params Bind Group"),
-            layout: &uniform_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: blur_uniform_buffer.as_entire_binding(),
-            }],
-        });
-
-        // For vertical blur, we'll need to update the buffer before use
-        let blur_v_params_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Blur V Params Bind Group"),
-            layout: &uniform_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: blur_uniform_buffer.as_entire_binding(),
-            }],
-        });
-
         let composite_params_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Composite Params Bind Group"),
             layout: &uniform_layout,
@@ -843,23 +854,37 @@ impl Renderer {
             device,
             queue,
             config,
+            supported_present_modes: surface_caps.present_modes.clone(),
+            supported_surface_formats: surface_caps.formats.clone(),
             size,
+            render_origin: (0, 0),
             render_pipeline,
+            render_pipeline_layout,
+            render_pipeline_f64: None,
+            supports_f64,
+            fractal_shader: shader,
+            iteration_probe: None,
+            edge_supersampling: None,
+            auto_exposure: None,
+            frame_check: None,
+            coord_table_buffer,
+            coord_table_key: None,
             vertex_buffer,
             uniform_buffer,
             uniform_bind_group,
+            uniform_bind_group_layout,
+            orbit_trap_sampler,
             uniforms,
             start_time: web_time::Instant::now(),
 
             // Multi-pass post-processing
             scene_texture,
             scene_view,
-            bright_texture,
-            bright_view,
-            blur_temp_texture,
-            blur_temp_view,
-            bloom_texture,
-            bloom_view,
+            scene_scale: 1.0,
+            cvd_simulation: CvdSimulation::None,
+            safe_mode: true,
+            safe_mode_warnings: Vec::new(),
+            bloom: bloom_chain,
             composite_texture,
             composite_view,
 
@@ -867,27 +892,25 @@ impl Renderer {
             postprocess_vertex_buffer,
 
             bloom_extract_pipeline,
-            blur_pipeline,
+            bloom_pipelines,
             composite_pipeline,
             fxaa_pipeline,
             copy_pipeline,
+            ui_overlay_pipeline,
+            ui_overlay: None,
 
             bloom_uniform_buffer,
-            blur_uniform_buffer,
             composite_uniform_buffer,
 
             scene_bind_group,
-            bright_bind_group,
-            blur_temp_bind_group,
             composite_bind_group,
             composite_final_bind_group,
             bloom_params_bind_group,
-            blur_h_params_bind_group,
-            blur_v_params_bind_group,
             composite_params_bind_group,
 
             // Compute shader infrastructure (initialized lazily when needed)
             attractor_compute: None,
+            attractor3d_compute: None,
             buddhabrot_compute: None,
             accumulation_texture: None,
             buddhabrot_accumulation_buffer: None,
@@ -900,6 +923,92 @@ impl Renderer {
         }
     }
 
+    /// Create the main fractal render pipeline for the given shader module
+    fn create_fractal_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        shader: &wgpu::ShaderModule,
+        label: &str,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            cache: None,
+            label: Some(label),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: Some("vs_main"),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array![0 => Float32x2],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba16Float, // Render to HDR intermediate texture
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+        })
+    }
+
+    /// Initialize the double precision fractal pipeline.
+    /// This is called lazily when a capture requests f64 ray marching, since the
+    /// shader is large and most sessions never need it.
+    pub fn init_f64_pipeline(&mut self) {
+        if !self.supports_f64 || self.render_pipeline_f64.is_some() {
+            return;
+        }
+
+        // Swap the primary ray march for the f64 variant and append its implementation
+        let source = include_str!("../shaders/fractal.wgsl")
+            .replace(F32_RAY_MARCH_CALL, F64_RAY_MARCH_CALL)
+            + include_str!("../shaders/fractal_f64.wgsl");
+
+        let shader = self
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("Shader (f64)"),
+                source: wgpu::ShaderSource::Wgsl(source.into()),
+            });
+
+        self.render_pipeline_f64 = Some(Self::create_fractal_pipeline(
+            &self.device,
+            &self.render_pipeline_layout,
+            &shader,
+            "Render Pipeline (f64)",
+        ));
+    }
+
+    /// Fractal pipeline to use, preferring f64 when requested and initialized
+    pub fn fractal_pipeline(&self, double_precision: bool) -> &wgpu::RenderPipeline {
+        match &self.render_pipeline_f64 {
+            Some(pipeline) if double_precision => pipeline,
+            _ => &self.render_pipeline,
+        }
+    }
+
     /// Initialize the compute shader infrastructure for strange attractor accumulation.
     /// This is called lazily when accumulation mode is first enabled.
     /// Also handles recreation of textures when window is resized.
@@ -912,6 +1021,21 @@ impl Renderer {
         self.ensure_accumulation_texture();
     }
 
+    /// Initialize the 3D strange attractor compute pipeline.
+    /// 3D attractors accumulate into the same texture as the 2D ones, so this
+    /// sets up the shared accumulation infrastructure first.
+    pub fn init_attractor3d_compute(&mut self) {
+        self.init_accumulation_compute();
+        if self.attractor3d_compute.is_none() {
+            if let Some(ref attractor) = self.attractor_compute {
+                self.attractor3d_compute = Some(Attractor3DComputePipeline::new(
+                    &self.device,
+                    &attractor.storage_layout,
+                ));
+            }
+        }
+    }
+
     /// Initialize the Buddhabrot compute shader infrastructure.
     /// This is called lazily when Buddhabrot fractal type is selected.
     ///
@@ -1052,24 +1176,23 @@ impl Renderer {
             );
 
             // Create a simple R32Uint texture for display (no compute bind group needed)
-            let texture = self.device.create_texture(&wgpu::TextureDescriptor {
-                label: Some("Buddhabrot Display Texture"),
-                size: wgpu::Extent3d {
-                    width: self.size.width,
-                    height: self.size.height,
-                    depth_or_array_layers: 1,
-                },
-                mip_level_count: 1,
-                sample_count: 1,
-                dimension: wgpu::TextureDimension::D2,
-                format: wgpu::TextureFormat::R32Uint,
-                usage: wgpu::TextureUsages::STORAGE_BINDING
-                    | wgpu::TextureUsages::TEXTURE_BINDING
-                    | wgpu::TextureUsages::COPY_DST,
-                view_formats: &[],
-            });
+            let texture = create_accumulation_storage_texture(
+                &self.device,
+                self.size.width,
+                self.size.height,
+                "Buddhabrot Display Texture",
+            );
+            // Buddhabrot only colors by density, so this stays zero
+            let attribute_texture = create_accumulation_storage_texture(
+                &self.device,
+                self.size.width,
+                self.size.height,
+                "Buddhabrot Display Attribute Texture",
+            );
 
             let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let attribute_view =
+                attribute_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
             // Create the copy bind group (buffer -> texture)
             if let (Some(ref buffer), Some(ref copy_pipeline)) = (
@@ -1116,10 +1239,16 @@ impl Renderer {
                 self.device.create_bind_group(&wgpu::BindGroupDescriptor {
                     label: Some("Buddhabrot Display Bind Group"),
                     layout: &self.accumulation_display_pipeline.get_bind_group_layout(0),
-                    entries: &[wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(&view),
-                    }],
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::TextureView(&attribute_view),
+                        },
+                    ],
                 });
 
             // Create a placeholder AccumulationTexture (we only need the view for display)
@@ -1144,6 +1273,8 @@ impl Renderer {
                 self.accumulation_texture = Some(AccumulationTexture {
                     texture,
                     view,
+                    attribute_texture,
+                    attribute_view,
                     compute_bind_group,
                     width: self.size.width,
                     height: self.size.height,
@@ -1190,15 +1321,25 @@ impl Renderer {
 
             // Create bind group for sampling the accumulation texture
             // Use the layout from the accumulation_display_pipeline which was created in initialization
-            // The layout has only 1 binding: the uint accumulation texture
+            // The layout has 2 bindings: the uint accumulation and attribute textures
             let accumulation_display_bind_group =
                 self.device.create_bind_group(&wgpu::BindGroupDescriptor {
                     label: Some("Accumulation Display Bind Group"),
                     layout: &self.accumulation_display_pipeline.get_bind_group_layout(0),
-                    entries: &[wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(&accumulation_texture.view),
-                    }],
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(
+                                &accumulation_texture.view,
+                            ),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::TextureView(
+                                &accumulation_texture.attribute_view,
+                            ),
+                        },
+                    ],
                 });
 
             // Clear the texture immediately to avoid garbage data
@@ -1211,3 +1352,17 @@ impl Renderer {
 
     // Helper: Create a render texture
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f64_ray_march_marker() {
+        // The f64 pipeline is built by string replacement, so the call it swaps
+        // must stay in fractal.wgsl exactly once
+        let source = include_str!("../shaders/fractal.wgsl");
+        assert_eq!(source.matches(F32_RAY_MARCH_CALL).count(), 1);
+        assert!(include_str!("../shaders/fractal_f64.wgsl").contains("fn ray_march_f64("));
+    }
+}
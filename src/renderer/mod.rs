@@ -1,12 +1,22 @@
 // Module declarations
+pub mod bloom;
 pub mod compute;
+pub mod edge_aa;
+pub mod exposure;
+pub mod frame_check;
 mod initialization;
+pub mod orbit_trap;
+pub mod precise_2d;
+pub mod probe;
+mod readback;
+pub mod shader_export;
+pub mod surface_format;
 pub mod uniforms;
 mod update;
 
 use compute::{
-    AccumulationDisplayUniforms, AccumulationTexture, AttractorComputePipeline,
-    BuddhabrotAccumulationBuffer, BuddhabrotComputePipeline,
+    AccumulationDisplayUniforms, AccumulationTexture, Attractor3DComputePipeline,
+    AttractorComputePipeline, BuddhabrotAccumulationBuffer, BuddhabrotComputePipeline,
 };
 use uniforms::*;
 
@@ -22,25 +32,58 @@ pub struct Renderer {
     pub device: wgpu::Device,
     pub queue: wgpu::Queue,
     pub config: wgpu::SurfaceConfiguration,
+    /// Present modes the surface supports on this adapter
+    pub supported_present_modes: Vec<wgpu::PresentMode>,
+    /// Formats the surface supports on this adapter
+    pub supported_surface_formats: Vec<wgpu::TextureFormat>,
+    /// Size of the area the fractal is rendered to (the window minus any docked panel)
     pub size: winit::dpi::PhysicalSize<u32>,
+    /// Top-left corner of the render area within the window, in pixels
+    pub render_origin: (u32, u32),
 
     // Main fractal rendering
     pub render_pipeline: wgpu::RenderPipeline,
+    pub render_pipeline_layout: wgpu::PipelineLayout,
+    /// Double precision ray marching variant (created lazily for captures)
+    pub render_pipeline_f64: Option<wgpu::RenderPipeline>,
+    /// Whether the adapter supports SHADER_F64
+    pub supports_f64: bool,
+    /// Main fractal shader, kept for pipelines created on demand
+    pub fractal_shader: wgpu::ShaderModule,
+    /// Single-pixel probe for the 2D hover iteration readout (created on demand)
+    pub iteration_probe: Option<probe::IterationProbe>,
+    /// Second pass that supersamples the 2D fractal boundary (created on demand)
+    pub edge_supersampling: Option<edge_aa::EdgeSupersampling>,
+    /// Luminance histogram and adaptation state for 3D auto-exposure (created on demand)
+    pub auto_exposure: Option<exposure::AutoExposure>,
+    /// Blank/NaN frame detection (created on demand)
+    pub frame_check: Option<frame_check::FrameCheck>,
     pub vertex_buffer: wgpu::Buffer,
     pub uniform_buffer: wgpu::Buffer,
     pub uniform_bind_group: wgpu::BindGroup,
+    pub uniform_bind_group_layout: wgpu::BindGroupLayout,
+    // Image orbit trap sampler (texture is bound alongside the uniforms)
+    pub orbit_trap_sampler: wgpu::Sampler,
+    // Precise 2D mapping: per-column/row f64 coordinates (bound alongside the uniforms)
+    pub coord_table_buffer: wgpu::Buffer,
+    coord_table_key: Option<precise_2d::CoordTableKey>,
     uniforms: Uniforms,
     pub start_time: web_time::Instant,
 
     // Multi-pass post-processing
+    /// Fractal render target, `scene_scale` times the render area size
     pub scene_texture: wgpu::Texture,
     pub scene_view: wgpu::TextureView,
-    pub bright_texture: wgpu::Texture,
-    pub bright_view: wgpu::TextureView,
-    pub blur_temp_texture: wgpu::Texture,
-    pub blur_temp_view: wgpu::TextureView,
-    pub bloom_texture: wgpu::Texture,
-    pub bloom_view: wgpu::TextureView,
+    /// LOD render scale the scene texture is allocated at (1.0 = native)
+    pub scene_scale: f32,
+    /// Color vision deficiency simulated by the composite pass
+    pub cvd_simulation: crate::fractal::CvdSimulation,
+    /// Cap GPU-heavy settings to the safe mode limits on every uniform upload
+    pub safe_mode: bool,
+    /// Settings the last upload had to cap, one message each
+    safe_mode_warnings: Vec<String>,
+    /// Bright pass, mip chain and result textures for the window's bloom
+    pub bloom: bloom::BloomChain,
     pub composite_texture: wgpu::Texture,
     pub composite_view: wgpu::TextureView,
 
@@ -49,29 +92,29 @@ pub struct Renderer {
 
     // Post-processing pipelines
     pub bloom_extract_pipeline: wgpu::RenderPipeline,
-    pub blur_pipeline: wgpu::RenderPipeline,
+    pub bloom_pipelines: bloom::BloomPipelines,
     pub composite_pipeline: wgpu::RenderPipeline,
     pub fxaa_pipeline: wgpu::RenderPipeline,
     pub copy_pipeline: wgpu::RenderPipeline,
+    /// Blends the sRGB UI overlay onto a float surface (None on other surfaces)
+    pub ui_overlay_pipeline: Option<wgpu::RenderPipeline>,
+    ui_overlay: Option<surface_format::UiOverlay>,
 
     // Post-processing uniforms
     pub bloom_uniform_buffer: wgpu::Buffer,
-    pub blur_uniform_buffer: wgpu::Buffer,
     pub composite_uniform_buffer: wgpu::Buffer,
 
     // Bind groups
     pub scene_bind_group: wgpu::BindGroup,
-    pub bright_bind_group: wgpu::BindGroup,
-    pub blur_temp_bind_group: wgpu::BindGroup,
     pub composite_bind_group: wgpu::BindGroup,
     pub composite_final_bind_group: wgpu::BindGroup, // For final pass (FXAA or copy)
     pub bloom_params_bind_group: wgpu::BindGroup,
-    pub blur_h_params_bind_group: wgpu::BindGroup,
-    pub blur_v_params_bind_group: wgpu::BindGroup,
     pub composite_params_bind_group: wgpu::BindGroup,
 
     // Compute shader infrastructure for accumulation-based fractals
     pub attractor_compute: Option<AttractorComputePipeline>,
+    /// 3D attractors share the 2D attractors' accumulation texture
+    pub attractor3d_compute: Option<Attractor3DComputePipeline>,
     pub buddhabrot_compute: Option<BuddhabrotComputePipeline>,
     pub accumulation_texture: Option<AccumulationTexture>,
     /// Atomic storage buffer for Buddhabrot accumulation (separate from texture-based attractors)
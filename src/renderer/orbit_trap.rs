@@ -0,0 +1,124 @@
+//! Image orbit trap texture
+//!
+//! The fractal shader samples this texture at the orbit's trap coordinates when
+//! the Orbit Trap Image color mode is active. A procedural default image is used
+//! until the user loads one (and always on web).
+
+use super::Renderer;
+use wgpu::util::DeviceExt;
+
+/// Size of the procedural default trap image
+const DEFAULT_TRAP_IMAGE_SIZE: u32 = 256;
+
+/// Generate the default trap image: a hue wheel disk on a transparent background.
+/// Returns RGBA8 pixels.
+pub fn default_orbit_trap_image() -> (u32, u32, Vec<u8>) {
+    let size = DEFAULT_TRAP_IMAGE_SIZE;
+    let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+
+    for y in 0..size {
+        for x in 0..size {
+            // Map to [-1, 1] with y up
+            let u = (x as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+            let v = 1.0 - (y as f32 + 0.5) / size as f32 * 2.0;
+            let radius = (u * u + v * v).sqrt();
+
+            let hue = (v.atan2(u) / std::f32::consts::TAU).rem_euclid(1.0);
+            let value = 1.0 - 0.6 * radius.min(1.0);
+            let rgb: egui::Color32 = egui::epaint::Hsva::new(hue, 0.85, value, 1.0).into();
+            // Soft edge so sampled boundaries are antialiased
+            let alpha = ((0.95 - radius) / 0.05).clamp(0.0, 1.0);
+
+            pixels.extend_from_slice(&[rgb.r(), rgb.g(), rgb.b(), (alpha * 255.0) as u8]);
+        }
+    }
+
+    (size, size, pixels)
+}
+
+/// Upload an RGBA8 image as a sampled texture for the orbit trap
+pub(super) fn create_orbit_trap_texture(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    width: u32,
+    height: u32,
+    rgba: &[u8],
+) -> wgpu::TextureView {
+    let texture = device.create_texture_with_data(
+        queue,
+        &wgpu::TextureDescriptor {
+            label: Some("Orbit Trap Texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        },
+        wgpu::util::TextureDataOrder::LayerMajor,
+        rgba,
+    );
+    // The view keeps the texture alive for as long as the bind group uses it
+    texture.create_view(&wgpu::TextureViewDescriptor::default())
+}
+
+/// Bind group for the fractal shader: uniforms, the orbit trap texture and the
+/// precise 2D coordinate table
+pub(super) fn create_uniform_bind_group(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    uniform_buffer: &wgpu::Buffer,
+    orbit_trap_view: &wgpu::TextureView,
+    orbit_trap_sampler: &wgpu::Sampler,
+    coord_table_buffer: &wgpu::Buffer,
+) -> wgpu::BindGroup {
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::TextureView(orbit_trap_view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 2,
+                resource: wgpu::BindingResource::Sampler(orbit_trap_sampler),
+            },
+            wgpu::BindGroupEntry {
+                binding: 3,
+                resource: coord_table_buffer.as_entire_binding(),
+            },
+        ],
+        label: Some("uniform_bind_group"),
+    })
+}
+
+/// Orbit trap image methods
+impl Renderer {
+    /// Replace the orbit trap image with RGBA8 pixels
+    pub fn set_orbit_trap_image(&mut self, width: u32, height: u32, rgba: &[u8]) {
+        let view = create_orbit_trap_texture(&self.device, &self.queue, width, height, rgba);
+        self.uniform_bind_group = create_uniform_bind_group(
+            &self.device,
+            &self.uniform_bind_group_layout,
+            &self.uniform_buffer,
+            &view,
+            &self.orbit_trap_sampler,
+            &self.coord_table_buffer,
+        );
+    }
+
+    /// Restore the procedural default orbit trap image
+    pub fn reset_orbit_trap_image(&mut self) {
+        let (width, height, rgba) = default_orbit_trap_image();
+        self.set_orbit_trap_image(width, height, &rgba);
+    }
+}
@@ -0,0 +1,193 @@
+//! Single-pixel iteration probe for the 2D hover readout
+//!
+//! The fractal shader's `fs_probe` entry point evaluates the 2D fractal at the
+//! cursor position and writes the normalized iteration value and complex
+//! coordinate into a 1x1 float texture. That pixel is read back asynchronously,
+//! so a probe never stalls the frame.
+
+use super::readback::AsyncReadback;
+use super::Renderer;
+
+/// Bytes per texel of the probe target (Rgba32Float)
+const PROBE_TEXEL_SIZE: u64 = 16;
+/// Buffer copies require rows aligned to 256 bytes
+const PROBE_BUFFER_SIZE: u64 = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as u64;
+
+/// Result of a hover probe
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProbeSample {
+    /// Normalized smooth iteration value (0 = did not escape)
+    pub t: f32,
+    /// Complex-plane coordinate of the probed pixel
+    pub coord: [f32; 2],
+}
+
+/// GPU resources and readback state for the hover probe
+pub struct IterationProbe {
+    pipeline: wgpu::RenderPipeline,
+    view: wgpu::TextureView,
+    texture: wgpu::Texture,
+    readback: AsyncReadback,
+}
+
+impl IterationProbe {
+    fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        shader: &wgpu::ShaderModule,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Iteration Probe Texture"),
+            size: wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba32Float,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let readback = AsyncReadback::new(
+            device,
+            "Iteration Probe Buffer",
+            PROBE_TEXEL_SIZE,
+            PROBE_BUFFER_SIZE,
+        );
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            cache: None,
+            label: Some("Iteration Probe Pipeline"),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: Some("vs_main"),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array![0 => Float32x2],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: Some("fs_probe"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba32Float,
+                    blend: None, // 32-bit float targets aren't blendable
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        Self {
+            pipeline,
+            view,
+            texture,
+            readback,
+        }
+    }
+}
+
+/// Hover probe methods
+impl Renderer {
+    /// Encode a probe of the 2D fractal at a screen position (NDC).
+    /// Skipped while a previous readback is still in flight.
+    pub fn encode_iteration_probe(&mut self, encoder: &mut wgpu::CommandEncoder, uv: [f32; 2]) {
+        if self.iteration_probe.is_none() {
+            self.iteration_probe = Some(IterationProbe::new(
+                &self.device,
+                &self.render_pipeline_layout,
+                &self.fractal_shader,
+            ));
+        }
+        let Some(probe) = self.iteration_probe.as_mut() else {
+            return;
+        };
+        if !probe.readback.is_ready() {
+            return;
+        }
+
+        // The probe position rides along with the frame's uniform upload
+        self.uniforms.set_probe_uv(uv);
+        self.queue.write_buffer(
+            &self.uniform_buffer,
+            0,
+            bytemuck::cast_slice(&[self.uniforms]),
+        );
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Iteration Probe Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &probe.view,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            render_pass.set_pipeline(&probe.pipeline);
+            render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+            render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            render_pass.draw(0..4, 0..1);
+        }
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &probe.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: probe.readback.buffer(),
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(PROBE_BUFFER_SIZE as u32),
+                    rows_per_image: Some(1),
+                },
+            },
+            wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+        );
+        probe.readback.mark_copied();
+    }
+
+    /// Start mapping the probe buffer. Call after the frame's commands are submitted.
+    pub fn begin_iteration_probe_readback(&mut self) {
+        if let Some(probe) = self.iteration_probe.as_mut() {
+            probe.readback.begin();
+        }
+    }
+
+    /// Collect a finished probe readback without blocking
+    pub fn poll_iteration_probe(&mut self) -> Option<ProbeSample> {
+        let probe = self.iteration_probe.as_mut()?;
+        let values = probe.readback.poll(&self.device)?;
+        Some(ProbeSample {
+            t: values[0],
+            coord: [values[1], values[2]],
+        })
+    }
+}
@@ -0,0 +1,95 @@
+//! Non-blocking GPU readback of small buffers
+//!
+//! Used by features that need a few values back from the GPU every frame
+//! (the hover iteration probe, auto-exposure). A copy into the readback buffer
+//! is encoded with the frame, mapping starts after submit, and the result is
+//! collected on a later frame once the map completes, so nothing stalls.
+
+use std::sync::{Arc, Mutex};
+
+/// A mappable buffer with its asynchronous map state
+pub struct AsyncReadback {
+    buffer: wgpu::Buffer,
+    size: u64,
+    /// Set by the map_async callback once the buffer is mapped
+    map_result: Arc<Mutex<Option<Result<(), wgpu::BufferAsyncError>>>>,
+    /// A copy was encoded this frame and the buffer still needs mapping
+    copy_pending: bool,
+    /// The buffer is being mapped (no new copy until it completes)
+    in_flight: bool,
+}
+
+impl AsyncReadback {
+    /// Create a readback buffer. `size` is the number of bytes read back,
+    /// `buffer_size` the allocation (at least `size`, for copy alignment).
+    pub fn new(device: &wgpu::Device, label: &str, size: u64, buffer_size: u64) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: buffer_size.max(size),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            buffer,
+            size,
+            map_result: Arc::new(Mutex::new(None)),
+            copy_pending: false,
+            in_flight: false,
+        }
+    }
+
+    /// Destination for the copy encoded this frame
+    pub fn buffer(&self) -> &wgpu::Buffer {
+        &self.buffer
+    }
+
+    /// Whether a new copy can be encoded (the previous readback has finished)
+    pub fn is_ready(&self) -> bool {
+        !self.copy_pending && !self.in_flight
+    }
+
+    /// Record that a copy into the buffer was encoded this frame
+    pub fn mark_copied(&mut self) {
+        self.copy_pending = true;
+    }
+
+    /// Start mapping the buffer. Call after the frame's commands are submitted.
+    pub fn begin(&mut self) {
+        if !self.copy_pending {
+            return;
+        }
+        self.copy_pending = false;
+        self.in_flight = true;
+
+        let map_result = Arc::clone(&self.map_result);
+        self.buffer
+            .slice(..self.size)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                if let Ok(mut slot) = map_result.lock() {
+                    *slot = Some(result);
+                }
+            });
+    }
+
+    /// Collect the mapped values without blocking
+    pub fn poll(&mut self, device: &wgpu::Device) -> Option<Vec<f32>> {
+        if !self.in_flight {
+            return None;
+        }
+
+        let _ = device.poll(wgpu::PollType::Poll);
+        let result = self.map_result.lock().ok()?.take()?;
+        self.in_flight = false;
+        if result.is_err() {
+            return None;
+        }
+
+        let values = {
+            let data = self.buffer.slice(..self.size).get_mapped_range();
+            bytemuck::cast_slice::<u8, f32>(&data).to_vec()
+        };
+        self.buffer.unmap();
+        Some(values)
+    }
+}
@@ -0,0 +1,187 @@
+//! Shader-ready parameter export
+//!
+//! Dumps the uniform values the renderer feeds the fractal shader as WGSL or
+//! GLSL constants, or a flat name = value list, for porting a view to
+//! ShaderToy or a custom shader. The names match the `Uniforms` struct in
+//! `fractal.wgsl`, so the export also documents the uniform layout.
+
+/// A uniform value as the shader sees it
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShaderValue {
+    F32(f32),
+    U32(u32),
+    Vec2([f32; 2]),
+    Vec3([f32; 3]),
+    Vec4([f32; 4]),
+    Vec4Array(Vec<[f32; 4]>),
+}
+
+/// One uniform field with a short description of what it does
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShaderParam {
+    pub name: &'static str,
+    pub value: ShaderValue,
+    pub doc: &'static str,
+}
+
+impl ShaderParam {
+    pub fn new(name: &'static str, value: ShaderValue, doc: &'static str) -> Self {
+        Self { name, value, doc }
+    }
+}
+
+/// Syntax of the exported parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShaderExportFormat {
+    #[default]
+    Wgsl,
+    Glsl,
+    /// One `name = value` line per field
+    List,
+}
+
+impl ShaderExportFormat {
+    pub const ALL: &'static [ShaderExportFormat] = &[
+        ShaderExportFormat::Wgsl,
+        ShaderExportFormat::Glsl,
+        ShaderExportFormat::List,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShaderExportFormat::Wgsl => "WGSL",
+            ShaderExportFormat::Glsl => "GLSL",
+            ShaderExportFormat::List => "Name = value list",
+        }
+    }
+
+    /// Format `params` as constants (or a list), one per line with its
+    /// description as a trailing comment
+    pub fn format(&self, params: &[ShaderParam]) -> String {
+        let mut out = String::from("// par-fractal shader parameters\n");
+        for param in params {
+            let line = match self {
+                ShaderExportFormat::Wgsl => format!(
+                    "const {}: {} = {};",
+                    param.name,
+                    wgsl_type(&param.value),
+                    wgsl_value(&param.value)
+                ),
+                ShaderExportFormat::Glsl => match &param.value {
+                    ShaderValue::Vec4Array(items) => format!(
+                        "const vec4 {}[{}] = {};",
+                        param.name,
+                        items.len(),
+                        glsl_value(&param.value)
+                    ),
+                    value => format!(
+                        "const {} {} = {};",
+                        glsl_type(value),
+                        param.name,
+                        glsl_value(value)
+                    ),
+                },
+                ShaderExportFormat::List => {
+                    format!("{} = {}", param.name, list_value(&param.value))
+                }
+            };
+            out.push_str(&format!("{} // {}\n", line, param.doc));
+        }
+        out
+    }
+}
+
+/// A float literal that always reads as a float, e.g. `1.0` rather than `1`
+fn float(value: f32) -> String {
+    let text = format!("{:?}", value);
+    if text.contains(['.', 'e', 'N', 'i']) {
+        text
+    } else {
+        format!("{}.0", text)
+    }
+}
+
+fn floats(values: &[f32]) -> String {
+    values
+        .iter()
+        .map(|&v| float(v))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn wgsl_type(value: &ShaderValue) -> String {
+    match value {
+        ShaderValue::F32(_) => "f32".to_string(),
+        ShaderValue::U32(_) => "u32".to_string(),
+        ShaderValue::Vec2(_) => "vec2<f32>".to_string(),
+        ShaderValue::Vec3(_) => "vec3<f32>".to_string(),
+        ShaderValue::Vec4(_) => "vec4<f32>".to_string(),
+        ShaderValue::Vec4Array(items) => format!("array<vec4<f32>, {}>", items.len()),
+    }
+}
+
+fn wgsl_value(value: &ShaderValue) -> String {
+    match value {
+        ShaderValue::F32(v) => float(*v),
+        ShaderValue::U32(v) => format!("{}u", v),
+        ShaderValue::Vec2(v) => format!("vec2<f32>({})", floats(v)),
+        ShaderValue::Vec3(v) => format!("vec3<f32>({})", floats(v)),
+        ShaderValue::Vec4(v) => format!("vec4<f32>({})", floats(v)),
+        ShaderValue::Vec4Array(items) => format!(
+            "{}({})",
+            wgsl_type(value),
+            items
+                .iter()
+                .map(|v| format!("vec4<f32>({})", floats(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn glsl_type(value: &ShaderValue) -> &'static str {
+    match value {
+        ShaderValue::F32(_) => "float",
+        ShaderValue::U32(_) => "uint",
+        ShaderValue::Vec2(_) => "vec2",
+        ShaderValue::Vec3(_) => "vec3",
+        ShaderValue::Vec4(_) | ShaderValue::Vec4Array(_) => "vec4",
+    }
+}
+
+fn glsl_value(value: &ShaderValue) -> String {
+    match value {
+        ShaderValue::F32(v) => float(*v),
+        ShaderValue::U32(v) => format!("{}u", v),
+        ShaderValue::Vec2(v) => format!("vec2({})", floats(v)),
+        ShaderValue::Vec3(v) => format!("vec3({})", floats(v)),
+        ShaderValue::Vec4(v) => format!("vec4({})", floats(v)),
+        ShaderValue::Vec4Array(items) => format!(
+            "vec4[{}]({})",
+            items.len(),
+            items
+                .iter()
+                .map(|v| format!("vec4({})", floats(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn list_value(value: &ShaderValue) -> String {
+    match value {
+        ShaderValue::F32(v) => float(*v),
+        ShaderValue::U32(v) => v.to_string(),
+        ShaderValue::Vec2(v) => format!("[{}]", floats(v)),
+        ShaderValue::Vec3(v) => format!("[{}]", floats(v)),
+        ShaderValue::Vec4(v) => format!("[{}]", floats(v)),
+        ShaderValue::Vec4Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(|v| format!("[{}]", floats(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
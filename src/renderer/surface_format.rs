@@ -0,0 +1,254 @@
+//! Window surface format selection
+//!
+//! By default the surface uses an 8-bit sRGB format, so the hardware encodes the
+//! linear HDR composite on write. A non-sRGB 8-bit or 10-bit format can be chosen
+//! instead, in which case the final pass sRGB-encodes in the shader. A 16-bit
+//! float surface is extended linear sRGB: the composite is written unchanged,
+//! SDR displays clip it at 1.0 and HDR displays show the brighter values.
+//!
+//! egui only draws correctly into sRGB targets (where blending happens in linear
+//! light) or gamma-coded unorm targets. On a float surface the UI is therefore
+//! drawn into an sRGB overlay texture and blended onto the surface afterwards.
+//!
+//! Captures are sRGB-coded the same way whichever path they take: screenshots
+//! read back the surface and undo only its channel layout, high-res renders
+//! encode the linear composite with [`linear_to_srgb`]. A capture gamma of 1
+//! keeps both identical to the display.
+
+use super::Renderer;
+use crate::fractal::SurfaceFormatSetting;
+
+/// Format the UI overlay is drawn in on float surfaces
+const UI_OVERLAY_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+/// The surface format for `setting` among the `available` ones, or None if
+/// the surface doesn't support it
+pub fn surface_format_for(
+    setting: SurfaceFormatSetting,
+    available: &[wgpu::TextureFormat],
+) -> Option<wgpu::TextureFormat> {
+    use wgpu::TextureFormat as F;
+    let candidates: &[F] = match setting {
+        SurfaceFormatSetting::Auto => {
+            return available
+                .iter()
+                .copied()
+                .find(|f| f.is_srgb())
+                .or(available.first().copied());
+        }
+        SurfaceFormatSetting::Unorm8 => &[F::Rgba8Unorm, F::Bgra8Unorm],
+        SurfaceFormatSetting::Rgb10a2 => &[F::Rgb10a2Unorm],
+        SurfaceFormatSetting::Float16 => &[F::Rgba16Float],
+    };
+    candidates.iter().copied().find(|f| available.contains(f))
+}
+
+/// Surface format settings the surface supports, for the UI
+pub fn supported_surface_formats(available: &[wgpu::TextureFormat]) -> Vec<SurfaceFormatSetting> {
+    SurfaceFormatSetting::ALL
+        .into_iter()
+        .filter(|setting| surface_format_for(*setting, available).is_some())
+        .collect()
+}
+
+/// Whether the surface stores linear floating point color
+pub fn is_float_format(format: wgpu::TextureFormat) -> bool {
+    format == wgpu::TextureFormat::Rgba16Float
+}
+
+/// Whether the final pass has to sRGB-encode itself: the format expects
+/// sRGB-coded values but the hardware doesn't encode on write
+pub fn needs_shader_srgb_encode(format: wgpu::TextureFormat) -> bool {
+    !format.is_srgb() && !is_float_format(format)
+}
+
+/// Convert tightly packed surface pixels to 8-bit sRGB RGBA
+pub fn surface_pixels_to_rgba8(format: wgpu::TextureFormat, data: &[u8]) -> Vec<u8> {
+    use wgpu::TextureFormat as F;
+    match format {
+        F::Bgra8Unorm | F::Bgra8UnormSrgb => data
+            .chunks_exact(4)
+            .flat_map(|p| [p[2], p[1], p[0], p[3]])
+            .collect(),
+        F::Rgb10a2Unorm => data
+            .chunks_exact(4)
+            .flat_map(|p| {
+                let bits = u32::from_le_bytes([p[0], p[1], p[2], p[3]]);
+                let channel =
+                    |shift: u32| (((bits >> shift) & 0x3ff) as f32 / 1023.0 * 255.0).round() as u8;
+                [
+                    channel(0),
+                    channel(10),
+                    channel(20),
+                    ((bits >> 30) as f32 / 3.0 * 255.0).round() as u8,
+                ]
+            })
+            .collect(),
+        F::Rgba16Float => rgba16f_to_f32(data)
+            .chunks_exact(4)
+            .flat_map(|p| {
+                [
+                    unit_to_u8(linear_to_srgb(p[0])),
+                    unit_to_u8(linear_to_srgb(p[1])),
+                    unit_to_u8(linear_to_srgb(p[2])),
+                    unit_to_u8(p[3]),
+                ]
+            })
+            .collect(),
+        _ => data.to_vec(),
+    }
+}
+
+/// Decode little-endian half floats (e.g. an Rgba16Float readback)
+pub fn rgba16f_to_f32(data: &[u8]) -> Vec<f32> {
+    data.chunks_exact(2)
+        .map(|h| f16_to_f32(u16::from_le_bytes([h[0], h[1]])))
+        .collect()
+}
+
+/// IEEE 754 half to single precision
+pub fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((bits >> 10) & 0x1f) as i32;
+    let mantissa = (bits & 0x3ff) as f32;
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-24),
+        31 if mantissa == 0.0 => sign * f32::INFINITY,
+        31 => f32::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+/// Linear light to the sRGB transfer curve, clamped to 0..=1
+pub fn linear_to_srgb(linear: f32) -> f32 {
+    let c = linear.clamp(0.0, 1.0);
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Apply the capture gamma to an sRGB-coded value in 0..=1. A gamma of 1
+/// leaves it unchanged, so captures match the display exactly; higher values
+/// brighten the midtones.
+pub fn apply_capture_gamma(encoded: f32, gamma: f32) -> f32 {
+    if gamma == 1.0 {
+        return encoded;
+    }
+    encoded.clamp(0.0, 1.0).powf(1.0 / gamma)
+}
+
+/// Apply [`apply_capture_gamma`] to the color channels of RGBA8 pixels
+pub fn apply_capture_gamma_rgba8(pixels: &mut [u8], gamma: f32) {
+    if gamma == 1.0 {
+        return;
+    }
+    let table: Vec<u8> = (0..=255)
+        .map(|v| unit_to_u8(apply_capture_gamma(v as f32 / 255.0, gamma)))
+        .collect();
+    for pixel in pixels.chunks_exact_mut(4) {
+        for channel in &mut pixel[..3] {
+            *channel = table[*channel as usize];
+        }
+    }
+}
+
+fn unit_to_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Window-sized sRGB texture the UI is drawn into on float surfaces
+pub struct UiOverlay {
+    size: (u32, u32),
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+/// UI overlay methods
+impl Renderer {
+    /// Format the egui renderer has to target
+    pub fn ui_format(&self) -> wgpu::TextureFormat {
+        if self.ui_overlay_pipeline.is_some() {
+            UI_OVERLAY_FORMAT
+        } else {
+            self.config.format
+        }
+    }
+
+    /// The texture the UI should be drawn into instead of the surface, if any.
+    /// Clear it, draw the UI, then call [`Self::encode_ui_overlay`].
+    pub fn ui_overlay_view(&mut self) -> Option<wgpu::TextureView> {
+        let pipeline = self.ui_overlay_pipeline.as_ref()?;
+        let size = (self.config.width, self.config.height);
+        if self.ui_overlay.as_ref().is_none_or(|o| o.size != size) {
+            let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("UI Overlay Texture"),
+                size: wgpu::Extent3d {
+                    width: size.0,
+                    height: size.1,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: UI_OVERLAY_FORMAT,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("UI Overlay Bind Group"),
+                layout: &pipeline.get_bind_group_layout(0),
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(&self.sampler),
+                    },
+                ],
+            });
+            self.ui_overlay = Some(UiOverlay {
+                size,
+                view,
+                bind_group,
+            });
+        }
+        self.ui_overlay.as_ref().map(|o| o.view.clone())
+    }
+
+    /// Blend the UI overlay onto the surface. Sampling the sRGB overlay yields
+    /// linear premultiplied color, which is what the float surface stores.
+    pub fn encode_ui_overlay(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+    ) {
+        let (Some(pipeline), Some(overlay)) = (&self.ui_overlay_pipeline, &self.ui_overlay) else {
+            return;
+        };
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("UI Overlay Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target,
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(pipeline);
+        pass.set_bind_group(0, &overlay.bind_group, &[]);
+        pass.set_vertex_buffer(0, self.postprocess_vertex_buffer.slice(..));
+        pass.draw(0..4, 0..1);
+    }
+}
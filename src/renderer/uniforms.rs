@@ -1,5 +1,6 @@
+use super::shader_export::{ShaderParam, ShaderValue};
 use crate::camera::Camera;
-use crate::fractal::{FractalParams, RenderMode};
+use crate::fractal::{FractalParams, FractalType, RenderMode, NEWTON_MAX_ROOTS};
 use bytemuck::{Pod, Zeroable};
 use glam::Mat4;
 
@@ -29,7 +30,8 @@ pub struct Uniforms {
     fractal_scale: f32,
     fractal_fold: f32,
     fractal_min_radius: f32,
-    _padding2: [f32; 2], // Adjusted for alignment
+    contour_spacing: f32, // Ray distance between contour lines, 0 = off
+    _padding2: f32,       // Adjusted for alignment
 
     // Color palette
     palette: [[f32; 4]; 8], // 8 colors with padding
@@ -71,21 +73,23 @@ pub struct Uniforms {
     shadow_step_factor: f32,
 
     // Light direction
-    light_azimuth: f32,       // Horizontal angle in degrees (0-360)
-    light_elevation: f32,     // Vertical angle in degrees (5-90)
-    _padding_light: [f32; 2], // Maintain 16-byte alignment
+    light_azimuth: f32,   // Horizontal angle in degrees (0-360)
+    light_elevation: f32, // Vertical angle in degrees (5-90)
+    up_axis: u32,         // 0: Y-up, 1: Z-up
+    shadow_penumbra: f32, // Soft shadow penumbra slope: tan(light half-angle) * softness multiplier
 
     // Floor
     show_floor: u32,
     floor_height: f32,
-    _padding_floor: [f32; 2], // Padding for vec3 alignment
+    floor_checker_scale: f32, // Checker tile size (also pads floor_color1 to 16 bytes)
+    floor_checker_rotation: f32, // Degrees around the up axis
     floor_color1: [f32; 3],
     _padding_floor1: f32,
     floor_color2: [f32; 3],
     floor_reflections: u32,
     floor_reflection_strength: f32,
     _padding_floor3_align: [f32; 3], // Explicit padding to match WGSL implicit vec3 alignment to 16-byte boundary
-    _padding_floor3: [f32; 3],
+    orbit_trap_offset: [f32; 3],     // vec3 in WGSL, 16-byte aligned after the floor padding
 
     // Ray marching
     use_adaptive_step: u32,
@@ -97,8 +101,8 @@ pub struct Uniforms {
     fog_enabled: u32,
     fog_mode: u32, // 0: Linear, 1: Exponential, 2: Quadratic
     fog_density: f32,
-    _padding_fog: f32,            // Align to 8-byte boundary
-    _padding_fog_vec3_align: f32, // Align fog_color to 16-byte boundary (WGSL requirement)
+    fog_start: f32,
+    fog_end: f32,
     fog_color: [f32; 3],
     _padding_fog_color: f32,
 
@@ -121,7 +125,8 @@ pub struct Uniforms {
     center_lo: [f32; 2],         // Low part of center (x, y)
     high_precision: u32,         // Flag: 1 = use high precision
     _hp_padding_align: [f32; 3], // WGSL adds 12 bytes implicit padding before vec3 to align to 16-byte boundary
-    _hp_padding: [f32; 4],       // vec3 in WGSL (16 bytes with padding)
+    ifs_offset: [f32; 3],        // IFS fold offset (vec3 in WGSL)
+    _hp_padding: f32,
 
     // LOD debug visualization
     lod_debug_enabled: u32, // Flag: 1 = show LOD zones as colors
@@ -130,11 +135,13 @@ pub struct Uniforms {
     lod_zone3: f32,         // Distance threshold: Medium -> Low
 
     // Aspect ratio stored in a vec4 slot to guarantee 16-byte alignment
-    aspect_ratio: [f32; 4], // .x = width/height, others unused
+    aspect_ratio: [f32; 4], // .x = width/height, .yz = viewport size in pixels, .w unused
 
     // Procedural palette parameters
     procedural_palette_type: u32, // 0=None (use static), 1=Firestrm, 2=Rainbow, etc.
-    _padding_proc_pal: [u32; 3],  // Align to 16 bytes
+    edge_aa_grid: u32,            // Boundary supersampling grid side (0 = off)
+    edge_aa_threshold: f32,       // Neighbour color difference that marks a boundary pixel
+    _padding_proc_pal: u32,       // Align to 16 bytes
     /// Custom procedural palette: brightness (a), contrast (b), frequency (c), phase (d)
     /// color(t) = a + b * cos(2π * (c * t + d))
     procedural_brightness: [f32; 4], // [r, g, b, _]
@@ -142,8 +149,66 @@ pub struct Uniforms {
     procedural_frequency: [f32; 4], // [r, g, b, _]
     procedural_phase: [f32; 4],   // [r, g, b, _]
 
-    // Padding for 16-byte alignment (reduced to accommodate procedural palette)
-    _padding_end: [f32; 8], // 32 bytes
+    // Stereoscopic 3D: per-eye camera matrices and positions
+    inv_view_proj_left: [[f32; 4]; 4],
+    inv_view_proj_right: [[f32; 4]; 4],
+    camera_pos_left: [f32; 3],
+    stereo_mode: u32, // 0: Off, 1: Side-by-side, 2: Anaglyph
+    camera_pos_right: [f32; 3],
+    relief_height: f32, // Elevation of the 2D relief's interior (3D relief of 2D fractals)
+
+    // Newton fractal polynomial roots, two complex roots packed per vec4
+    newton_root_count: u32,
+    _padding_newton: [u32; 3],
+    newton_roots: [[f32; 4]; 4],
+
+    // Hover iteration probe position (NDC), read by fs_probe
+    probe_uv: [f32; 2],
+    // Precise 2D coordinate table size (columns, rows), 0 = standard mapping
+    coord_table_size: [u32; 2],
+
+    // Seamless tile cell: origin (xy) and size (zw) in the complex plane, size 0 = off
+    tile_rect: [f32; 4],
+
+    // Sub-pixel offset of every sample in pixels (capture supersampling), 0 = centered
+    pixel_jitter: [f32; 2],
+
+    // Background pixels get alpha 0 (transparent captures), 0 = opaque
+    transparent_background: u32,
+
+    // 2D interior coloring: 0 = solid, 1 = final |z|, 2 = average |z|, 3 = period
+    interior_color_mode: u32,
+
+    // Phoenix coefficient on z(n-1) (complex)
+    phoenix_p: [f32; 2],
+
+    // Darken 2D exteriors toward the boundary by distance estimate (0 = off)
+    distance_estimation_2d: u32,
+
+    // DoF aperture blade count (0 = circular, 3+ = polygon)
+    bokeh_blades: u32,
+
+    // Collatz odd branch m*n + d as (m, d)
+    collatz_params: [f32; 2],
+    lens_jitter: [f32; 2], // DoF aperture pattern offset (accumulated captures), 0 = fixed pattern
+
+    // Secondary composited fractal: world-to-local rotation (mat3x3 columns),
+    // position and scale, albedo tint, and shader fractal index (0 = off)
+    secondary_inv_rotation: [[f32; 4]; 3],
+    secondary_position: [f32; 3],
+    secondary_scale: f32,
+    secondary_tint: [f32; 3],
+    secondary_fractal_type: u32,
+
+    // Surface hit threshold growth with pixel size (0 = fixed min_distance),
+    // and tan(fov / 2) to size a pixel at a distance
+    detail_falloff: f32,
+    tan_half_fov: f32,
+    _padding_detail: [f32; 2],
+
+    // Specular highlight tint and strength (white and 1 = untinted)
+    specular_color: [f32; 3],
+    specular_intensity: f32,
 }
 
 impl Default for Uniforms {
@@ -171,7 +236,8 @@ impl Uniforms {
             fractal_scale: 2.0,
             fractal_fold: 1.0,
             fractal_min_radius: 0.5,
-            _padding2: [0.0; 2],
+            contour_spacing: 0.0,
+            _padding2: 0.0,
             palette: [[0.0; 4]; 8],
             ambient_occlusion: 1,
             soft_shadows: 1,
@@ -206,18 +272,20 @@ impl Uniforms {
 
             light_azimuth: 45.0,
             light_elevation: 60.0,
-            _padding_light: [0.0; 2],
+            up_axis: 0,
+            shadow_penumbra: 0.0875,
 
             show_floor: 1,
             floor_height: -2.0,
-            _padding_floor: [0.0; 2],
+            floor_checker_scale: 1.0,
+            floor_checker_rotation: 0.0,
             floor_color1: [1.0, 1.0, 1.0], // White
             _padding_floor1: 0.0,
             floor_color2: [0.0, 0.0, 0.0], // Black
             floor_reflections: 0,
             floor_reflection_strength: 0.7,
             _padding_floor3_align: [0.0; 3],
-            _padding_floor3: [0.0; 3],
+            orbit_trap_offset: [0.0; 3],
 
             use_adaptive_step: 1,
             fixed_step_size: 0.1,
@@ -227,8 +295,8 @@ impl Uniforms {
             fog_enabled: 0,
             fog_mode: 1, // Exponential
             fog_density: 0.001,
-            _padding_fog: 0.0,
-            _padding_fog_vec3_align: 0.0,
+            fog_start: 0.0,
+            fog_end: 200.0,
             fog_color: [0.2, 0.2, 0.2], // Dark grey
             _padding_fog_color: 0.0,
 
@@ -249,7 +317,8 @@ impl Uniforms {
             center_lo: [0.0, 0.0],
             high_precision: 0,
             _hp_padding_align: [0.0; 3],
-            _hp_padding: [0.0; 4],
+            ifs_offset: [1.0; 3],
+            _hp_padding: 0.0,
 
             lod_debug_enabled: 0,
             lod_zone1: 10.0, // Default LOD thresholds
@@ -260,13 +329,50 @@ impl Uniforms {
 
             // Procedural palette defaults
             procedural_palette_type: 0, // None (use static palette)
-            _padding_proc_pal: [0; 3],
+            edge_aa_grid: 0,
+            edge_aa_threshold: 0.1,
+            _padding_proc_pal: 0,
             procedural_brightness: [0.5, 0.5, 0.5, 0.0],
             procedural_contrast: [0.5, 0.5, 0.5, 0.0],
             procedural_frequency: [1.0, 1.0, 1.0, 0.0],
             procedural_phase: [0.0, 0.333, 0.667, 0.0],
 
-            _padding_end: [0.0; 8],
+            inv_view_proj_left: Mat4::IDENTITY.to_cols_array_2d(),
+            inv_view_proj_right: Mat4::IDENTITY.to_cols_array_2d(),
+            camera_pos_left: [0.0, 0.0, 3.0],
+            stereo_mode: 0,
+            camera_pos_right: [0.0, 0.0, 3.0],
+            relief_height: 0.5,
+
+            newton_root_count: 3,
+            _padding_newton: [0; 3],
+            newton_roots: [[0.0; 4]; 4],
+
+            probe_uv: [0.0; 2],
+            coord_table_size: [0; 2],
+            tile_rect: [0.0; 4],
+            pixel_jitter: [0.0; 2],
+            transparent_background: 0,
+            interior_color_mode: 0,
+            phoenix_p: crate::fractal::DEFAULT_PHOENIX_P,
+            distance_estimation_2d: 0,
+            bokeh_blades: 0,
+            collatz_params: crate::fractal::DEFAULT_COLLATZ_PARAMS,
+            lens_jitter: [0.0; 2],
+            secondary_inv_rotation: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+            ],
+            secondary_position: [0.0; 3],
+            secondary_scale: 1.0,
+            secondary_tint: [1.0; 3],
+            secondary_fractal_type: 0,
+            detail_falloff: 0.0,
+            tan_half_fov: (22.5f32).to_radians().tan(),
+            _padding_detail: [0.0; 2],
+            specular_color: [1.0; 3],
+            specular_intensity: 1.0,
         }
     }
 
@@ -276,8 +382,48 @@ impl Uniforms {
         self.inv_view_proj = view_proj.inverse().to_cols_array_2d();
         self.camera_pos = camera.position.into();
 
+        // Stereo eye cameras: each half of a side-by-side frame is half as wide
+        self.stereo_mode = match params.stereo_mode {
+            crate::fractal::StereoMode::Off => 0,
+            crate::fractal::StereoMode::SideBySide => 1,
+            crate::fractal::StereoMode::Anaglyph => 2,
+        };
+        if params.stereo_mode != crate::fractal::StereoMode::Off {
+            let half_separation = params.stereo_eye_separation * 0.5;
+            let mut left_eye = camera.stereo_eye(-half_separation);
+            let mut right_eye = camera.stereo_eye(half_separation);
+            if params.stereo_mode == crate::fractal::StereoMode::SideBySide {
+                left_eye.aspect *= 0.5;
+                right_eye.aspect *= 0.5;
+            }
+            self.inv_view_proj_left = left_eye
+                .build_view_projection_matrix()
+                .inverse()
+                .to_cols_array_2d();
+            self.inv_view_proj_right = right_eye
+                .build_view_projection_matrix()
+                .inverse()
+                .to_cols_array_2d();
+            self.camera_pos_left = left_eye.position.into();
+            self.camera_pos_right = right_eye.position.into();
+        }
+
         self.center = [params.center_2d[0] as f32, params.center_2d[1] as f32];
         self.zoom = params.zoom_2d;
+        self.tile_rect = match params.fractal_type.tile_cell() {
+            Some((origin, size)) if params.seamless_tile => {
+                [origin[0], origin[1], size[0], size[1]]
+            }
+            _ => [0.0; 4],
+        };
+        self.interior_color_mode = match params.interior_color_mode {
+            crate::fractal::InteriorColorMode::Solid => 0,
+            crate::fractal::InteriorColorMode::FinalMagnitude => 1,
+            crate::fractal::InteriorColorMode::AverageMagnitude => 2,
+            crate::fractal::InteriorColorMode::Period => 3,
+            crate::fractal::InteriorColorMode::Distance if params.interior_distance_lighting => 5,
+            crate::fractal::InteriorColorMode::Distance => 4,
+        };
         self.aspect_ratio[0] = camera.aspect;
 
         // High-precision center: split f64 into (hi, lo) pair
@@ -303,49 +449,42 @@ impl Uniforms {
             self.max_iterations = params.max_iterations;
         }
         self.julia_c = params.julia_c;
+        self.phoenix_p = params.phoenix_p;
+        self.collatz_params = params.collatz_params;
+        self.distance_estimation_2d = params.distance_estimation_2d as u32;
+        self.contour_spacing = if params.show_distance_contours {
+            params.distance_contour_spacing.max(1e-4)
+        } else {
+            0.0
+        };
+
+        // Pack Newton roots two per vec4: (re0, im0, re1, im1)
+        let newton_roots = &params.newton_roots[..params.newton_roots.len().min(NEWTON_MAX_ROOTS)];
+        self.newton_root_count = newton_roots.len() as u32;
+        self.newton_roots = [[0.0; 4]; 4];
+        for (i, root) in newton_roots.iter().enumerate() {
+            let slot = &mut self.newton_roots[i / 2];
+            slot[(i % 2) * 2] = root[0];
+            slot[(i % 2) * 2 + 1] = root[1];
+        }
 
-        self.fractal_type = match params.fractal_type {
-            // 2D fractals (0-12)
-            crate::fractal::FractalType::Mandelbrot2D => 0,
-            crate::fractal::FractalType::Julia2D => 1,
-            crate::fractal::FractalType::Sierpinski2D => 2,
-            crate::fractal::FractalType::SierpinskiTriangle2D => 3,
-            crate::fractal::FractalType::BurningShip2D => 4,
-            crate::fractal::FractalType::Tricorn2D => 5,
-            crate::fractal::FractalType::Phoenix2D => 6,
-            crate::fractal::FractalType::Celtic2D => 7,
-            crate::fractal::FractalType::Newton2D => 8,
-            crate::fractal::FractalType::Lyapunov2D => 9,
-            crate::fractal::FractalType::Nova2D => 10,
-            crate::fractal::FractalType::Magnet2D => 11,
-            crate::fractal::FractalType::Collatz2D => 12,
-            // 2D Density fractals
-            crate::fractal::FractalType::Buddhabrot2D => 25, // Rendered via compute shader, not main shader
-            // 3D fractals (13-25)
-            crate::fractal::FractalType::Mandelbulb3D => 13,
-            crate::fractal::FractalType::MengerSponge3D => 14,
-            crate::fractal::FractalType::SierpinskiPyramid3D => 15,
-            crate::fractal::FractalType::JuliaSet3D => 16,
-            crate::fractal::FractalType::Mandelbox3D => 17,
-            crate::fractal::FractalType::OctahedralIFS3D => 18,
-            crate::fractal::FractalType::IcosahedralIFS3D => 19,
-            crate::fractal::FractalType::ApollonianGasket3D => 20,
-            crate::fractal::FractalType::Kleinian3D => 21,
-            crate::fractal::FractalType::HybridMandelbulbJulia3D => 22,
-            crate::fractal::FractalType::QuaternionCubic3D => 23,
-            crate::fractal::FractalType::SierpinskiGasket3D => 24,
-            // 2D Strange Attractors (26-32)
-            crate::fractal::FractalType::Hopalong2D => 26,
-            crate::fractal::FractalType::Martin2D => 27,
-            crate::fractal::FractalType::Gingerbreadman2D => 28,
-            crate::fractal::FractalType::Chip2D => 29,
-            crate::fractal::FractalType::Quadruptwo2D => 30,
-            crate::fractal::FractalType::Threeply2D => 31,
-            // 3D Strange Attractors (35-37)
-            crate::fractal::FractalType::Pickover3D => 35,
-            crate::fractal::FractalType::Lorenz3D => 36,
-            crate::fractal::FractalType::Rossler3D => 37,
+        self.fractal_type = shader_fractal_index(params.fractal_type);
+
+        let secondary = &params.secondary_fractal;
+        self.secondary_fractal_type = if params.secondary_fractal_active() {
+            shader_fractal_index(secondary.fractal_type)
+        } else {
+            0
         };
+        let rotation = secondary.inverse_rotation();
+        self.secondary_inv_rotation = [rotation.x_axis, rotation.y_axis, rotation.z_axis]
+            .map(|column| column.extend(0.0).to_array());
+        self.secondary_position = secondary.position;
+        self.secondary_scale = secondary.scale.max(1e-3);
+        self.secondary_tint = secondary.tint;
+
+        self.detail_falloff = params.detail_falloff;
+        self.tan_half_fov = (camera.fovy.to_radians() * 0.5).tan();
 
         self.render_mode = match params.render_mode {
             RenderMode::TwoD => 0,
@@ -359,6 +498,14 @@ impl Uniforms {
         self.fractal_scale = params.fractal_scale;
         self.fractal_fold = params.fractal_fold;
         self.fractal_min_radius = params.fractal_min_radius;
+        self.ifs_offset = params.ifs_offset.to_array();
+        self.relief_height = params.relief_height;
+        self.edge_aa_grid = if params.edge_aa_2d {
+            params.edge_aa_grid
+        } else {
+            0
+        };
+        self.edge_aa_threshold = params.edge_aa_threshold;
 
         // Update palette
         for (i, color) in params.palette.colors.iter().enumerate() {
@@ -418,9 +565,11 @@ impl Uniforms {
             crate::fractal::ColorMode::ShadowMap => 13,
             crate::fractal::ColorMode::CameraDistanceLOD => 14,
             crate::fractal::ColorMode::DistanceGrayscale => 15,
+            crate::fractal::ColorMode::OrbitTrapImage => 16,
         };
 
         self.orbit_trap_scale = params.orbit_trap_scale;
+        self.orbit_trap_offset = params.orbit_trap_offset.to_array();
         self.palette_offset = params.palette_offset;
 
         // Convert channel sources to shader-compatible values
@@ -457,11 +606,14 @@ impl Uniforms {
 
         self.roughness = params.roughness;
         self.metallic = params.metallic;
+        self.specular_color = params.specular_color.to_array();
+        self.specular_intensity = params.specular_intensity;
         self.albedo = params.albedo.into();
 
         self.dof_focal_length = params.dof_focal_length;
         self.dof_aperture = params.dof_aperture;
         self.dof_samples = params.dof_samples;
+        self.bokeh_blades = params.bokeh_blades;
         self.time = time;
         self.light_intensity = params.light_intensity;
         self.ambient_light = params.ambient_light;
@@ -471,12 +623,21 @@ impl Uniforms {
         self.shadow_max_distance = params.shadow_max_distance;
         self.shadow_samples = params.shadow_samples;
         self.shadow_step_factor = params.shadow_step_factor;
+        // Softness scales the physical penumbra, relative to its default of 8
+        self.shadow_penumbra =
+            (params.light_size.to_radians() * 0.5).tan() * params.shadow_softness / 8.0;
 
         self.light_azimuth = params.light_azimuth;
         self.light_elevation = params.light_elevation;
+        self.up_axis = match params.up_axis {
+            crate::fractal::UpAxis::Y => 0,
+            crate::fractal::UpAxis::Z => 1,
+        };
 
         self.show_floor = if params.show_floor { 1 } else { 0 };
         self.floor_height = params.floor_height;
+        self.floor_checker_scale = params.floor_checker_scale;
+        self.floor_checker_rotation = params.floor_checker_rotation;
         self.floor_color1 = params.floor_color1.into();
         self.floor_color2 = params.floor_color2.into();
         self.floor_reflections = if params.floor_reflections { 1 } else { 0 };
@@ -494,6 +655,8 @@ impl Uniforms {
             crate::fractal::FogMode::Quadratic => 2,
         };
         self.fog_density = params.fog_density;
+        self.fog_start = params.fog_start;
+        self.fog_end = params.fog_end;
         self.fog_color = params.fog_color.into();
 
         // Post-processing
@@ -518,6 +681,43 @@ impl Uniforms {
         self.lod_zone3 = params.lod_config.distance_zones[2];
     }
 
+    /// Set the screen position (NDC) sampled by the hover iteration probe
+    pub fn set_probe_uv(&mut self, uv: [f32; 2]) {
+        self.probe_uv = uv;
+    }
+
+    /// Set the render target size the 2D pixel-to-complex mapping uses for its aspect ratio
+    pub fn set_viewport_size(&mut self, width: u32, height: u32) {
+        if width > 0 && height > 0 {
+            self.aspect_ratio = [
+                width as f32 / height as f32,
+                width as f32,
+                height as f32,
+                0.0,
+            ];
+        }
+    }
+
+    /// Set the sub-pixel offset (in pixels) applied to every sample
+    pub fn set_pixel_jitter(&mut self, jitter: [f32; 2]) {
+        self.pixel_jitter = jitter;
+    }
+
+    /// Set the offset of the depth of field aperture sampling pattern
+    pub fn set_lens_jitter(&mut self, jitter: [f32; 2]) {
+        self.lens_jitter = jitter;
+    }
+
+    /// Give background pixels zero alpha instead of drawing the background
+    pub fn set_transparent_background(&mut self, transparent: bool) {
+        self.transparent_background = transparent as u32;
+    }
+
+    /// Set the size of the precise 2D coordinate table (0 disables it)
+    pub fn set_coord_table_size(&mut self, size: [u32; 2]) {
+        self.coord_table_size = size;
+    }
+
     /// Creates a new Uniforms struct populated from camera and fractal parameters.
     /// This is useful for high-resolution rendering where we need immutable access to the renderer.
     #[cfg(target_arch = "wasm32")]
@@ -526,12 +726,406 @@ impl Uniforms {
         uniforms.update(camera, params, time);
         uniforms
     }
+
+    /// The values the fractal shader reads, in uniform layout order and under
+    /// the WGSL field names, for the shader-ready parameter export. Padding,
+    /// camera matrices and per-frame capture state are left out.
+    pub fn shader_params(&self) -> Vec<ShaderParam> {
+        use ShaderValue::*;
+        let p = ShaderParam::new;
+        vec![
+            p("camera_pos", Vec3(self.camera_pos), "3D camera position"),
+            p("center", Vec2(self.center), "2D view center (f32)"),
+            p("zoom", F32(self.zoom), "2D zoom, view height = 4 / zoom"),
+            p(
+                "max_iterations",
+                U32(self.max_iterations),
+                "Iteration limit",
+            ),
+            p("julia_c", Vec2(self.julia_c), "Julia constant"),
+            p(
+                "fractal_type",
+                U32(self.fractal_type),
+                "Shader fractal index",
+            ),
+            p("render_mode", U32(self.render_mode), "0: 2D, 1: 3D"),
+            p("power", F32(self.power), "Power / exponent"),
+            p("max_steps", U32(self.max_steps), "Ray march step limit"),
+            p(
+                "min_distance",
+                F32(self.min_distance),
+                "Ray march hit distance",
+            ),
+            p(
+                "detail_falloff",
+                F32(self.detail_falloff),
+                "Hit distance growth with pixel size, 0 = fixed",
+            ),
+            p(
+                "tan_half_fov",
+                F32(self.tan_half_fov),
+                "tan(fov / 2), sizes a pixel at a distance",
+            ),
+            p("fractal_scale", F32(self.fractal_scale), "IFS scale"),
+            p("fractal_fold", F32(self.fractal_fold), "IFS fold limit"),
+            p(
+                "fractal_min_radius",
+                F32(self.fractal_min_radius),
+                "IFS minimum radius",
+            ),
+            p(
+                "contour_spacing",
+                F32(self.contour_spacing),
+                "Ray distance between contour lines, 0 = off",
+            ),
+            p(
+                "palette",
+                Vec4Array(self.palette.to_vec()),
+                "Palette stops (rgb, w unused)",
+            ),
+            p("ambient_occlusion", U32(self.ambient_occlusion), "1 = on"),
+            p("soft_shadows", U32(self.soft_shadows), "1 = on"),
+            p("depth_of_field", U32(self.depth_of_field), "1 = on"),
+            p(
+                "shading_model",
+                U32(self.shading_model),
+                "0: Blinn-Phong, 1: PBR",
+            ),
+            p(
+                "color_mode",
+                U32(self.color_mode),
+                "Color visualization mode",
+            ),
+            p(
+                "orbit_trap_scale",
+                F32(self.orbit_trap_scale),
+                "Orbit trap color scale",
+            ),
+            p(
+                "palette_offset",
+                F32(self.palette_offset),
+                "Palette animation offset",
+            ),
+            p("channel_r", U32(self.channel_r), "Red channel source"),
+            p("channel_g", U32(self.channel_g), "Green channel source"),
+            p("channel_b", U32(self.channel_b), "Blue channel source"),
+            p("roughness", F32(self.roughness), "PBR roughness"),
+            p("metallic", F32(self.metallic), "PBR metallic"),
+            p(
+                "specular_color",
+                Vec3(self.specular_color),
+                "Specular highlight tint",
+            ),
+            p(
+                "specular_intensity",
+                F32(self.specular_intensity),
+                "Specular highlight strength",
+            ),
+            p("albedo", Vec3(self.albedo), "Base color"),
+            p(
+                "dof_focal_length",
+                F32(self.dof_focal_length),
+                "Focus distance",
+            ),
+            p("dof_aperture", F32(self.dof_aperture), "Aperture size"),
+            p(
+                "dof_samples",
+                U32(self.dof_samples),
+                "DoF samples per pixel",
+            ),
+            p("time", F32(self.time), "Seconds since start"),
+            p(
+                "light_intensity",
+                F32(self.light_intensity),
+                "Key light intensity",
+            ),
+            p(
+                "ambient_light",
+                F32(self.ambient_light),
+                "Ambient light level",
+            ),
+            p(
+                "ao_intensity",
+                F32(self.ao_intensity),
+                "Ambient occlusion strength",
+            ),
+            p(
+                "ao_step_size",
+                F32(self.ao_step_size),
+                "Ambient occlusion step",
+            ),
+            p(
+                "shadow_softness",
+                F32(self.shadow_softness),
+                "Soft shadow softness",
+            ),
+            p(
+                "shadow_max_distance",
+                F32(self.shadow_max_distance),
+                "Shadow ray length",
+            ),
+            p(
+                "shadow_samples",
+                U32(self.shadow_samples),
+                "Shadow ray step limit",
+            ),
+            p(
+                "shadow_step_factor",
+                F32(self.shadow_step_factor),
+                "Shadow ray step multiplier",
+            ),
+            p("light_azimuth", F32(self.light_azimuth), "Degrees (0-360)"),
+            p(
+                "light_elevation",
+                F32(self.light_elevation),
+                "Degrees (5-90)",
+            ),
+            p("up_axis", U32(self.up_axis), "0: Y-up, 1: Z-up"),
+            p(
+                "shadow_penumbra",
+                F32(self.shadow_penumbra),
+                "tan(light half-angle) * softness",
+            ),
+            p("show_floor", U32(self.show_floor), "1 = on"),
+            p("floor_height", F32(self.floor_height), "Floor plane height"),
+            p(
+                "floor_checker_scale",
+                F32(self.floor_checker_scale),
+                "Checker tile size",
+            ),
+            p(
+                "floor_checker_rotation",
+                F32(self.floor_checker_rotation),
+                "Degrees around the up axis",
+            ),
+            p("floor_color1", Vec3(self.floor_color1), "Checker color 1"),
+            p("floor_color2", Vec3(self.floor_color2), "Checker color 2"),
+            p("floor_reflections", U32(self.floor_reflections), "1 = on"),
+            p(
+                "floor_reflection_strength",
+                F32(self.floor_reflection_strength),
+                "Reflection mix",
+            ),
+            p(
+                "orbit_trap_offset",
+                Vec3(self.orbit_trap_offset),
+                "Orbit trap point",
+            ),
+            p(
+                "use_adaptive_step",
+                U32(self.use_adaptive_step),
+                "1 = adaptive ray steps",
+            ),
+            p(
+                "fixed_step_size",
+                F32(self.fixed_step_size),
+                "Step size when not adaptive",
+            ),
+            p(
+                "step_multiplier",
+                F32(self.step_multiplier),
+                "Ray step multiplier",
+            ),
+            p("max_distance", F32(self.max_distance), "Ray length"),
+            p("fog_enabled", U32(self.fog_enabled), "1 = on"),
+            p(
+                "fog_mode",
+                U32(self.fog_mode),
+                "0: Linear, 1: Exponential, 2: Quadratic",
+            ),
+            p("fog_density", F32(self.fog_density), "Fog density"),
+            p("fog_start", F32(self.fog_start), "Linear fog start"),
+            p("fog_end", F32(self.fog_end), "Linear fog end"),
+            p("fog_color", Vec3(self.fog_color), "Fog color"),
+            p(
+                "brightness",
+                F32(self.brightness),
+                "Post-processing brightness",
+            ),
+            p("contrast", F32(self.contrast), "Post-processing contrast"),
+            p(
+                "saturation",
+                F32(self.saturation),
+                "Post-processing saturation",
+            ),
+            p(
+                "hue_shift",
+                F32(self.hue_shift),
+                "Post-processing hue shift",
+            ),
+            p(
+                "center_hi",
+                Vec2(self.center_hi),
+                "High part of the f64 center",
+            ),
+            p(
+                "center_lo",
+                Vec2(self.center_lo),
+                "Low part of the f64 center",
+            ),
+            p(
+                "high_precision",
+                U32(self.high_precision),
+                "1 = double-float center",
+            ),
+            p("ifs_offset", Vec3(self.ifs_offset), "IFS fold offset"),
+            p(
+                "aspect_ratio",
+                Vec4(self.aspect_ratio),
+                ".x = width/height, .yz = viewport size",
+            ),
+            p(
+                "procedural_palette_type",
+                U32(self.procedural_palette_type),
+                "0 = static palette",
+            ),
+            p(
+                "edge_aa_grid",
+                U32(self.edge_aa_grid),
+                "Boundary supersampling grid side, 0 = off",
+            ),
+            p(
+                "edge_aa_threshold",
+                F32(self.edge_aa_threshold),
+                "Boundary color difference",
+            ),
+            p(
+                "procedural_brightness",
+                Vec4(self.procedural_brightness),
+                "a in a + b * cos(2π(c * t + d))",
+            ),
+            p("procedural_contrast", Vec4(self.procedural_contrast), "b"),
+            p("procedural_frequency", Vec4(self.procedural_frequency), "c"),
+            p("procedural_phase", Vec4(self.procedural_phase), "d"),
+            p(
+                "stereo_mode",
+                U32(self.stereo_mode),
+                "0: Off, 1: Side-by-side, 2: Anaglyph",
+            ),
+            p(
+                "relief_height",
+                F32(self.relief_height),
+                "2D relief interior elevation",
+            ),
+            p(
+                "newton_root_count",
+                U32(self.newton_root_count),
+                "Newton polynomial degree",
+            ),
+            p(
+                "newton_roots",
+                Vec4Array(self.newton_roots.to_vec()),
+                "Two complex roots per vec4",
+            ),
+            p(
+                "tile_rect",
+                Vec4(self.tile_rect),
+                "Seamless tile origin (xy) and size (zw), 0 = off",
+            ),
+            p(
+                "transparent_background",
+                U32(self.transparent_background),
+                "1 = background alpha 0",
+            ),
+            p(
+                "interior_color_mode",
+                U32(self.interior_color_mode),
+                "2D interior coloring",
+            ),
+            p(
+                "phoenix_p",
+                Vec2(self.phoenix_p),
+                "Phoenix coefficient on z(n-1)",
+            ),
+            p(
+                "distance_estimation_2d",
+                U32(self.distance_estimation_2d),
+                "1 = darken toward the boundary",
+            ),
+            p(
+                "bokeh_blades",
+                U32(self.bokeh_blades),
+                "0 = circular aperture",
+            ),
+            p(
+                "collatz_params",
+                Vec2(self.collatz_params),
+                "Odd branch m * n + d as (m, d)",
+            ),
+            p(
+                "secondary_fractal_type",
+                U32(self.secondary_fractal_type),
+                "Composited fractal index, 0 = off",
+            ),
+            p(
+                "secondary_position",
+                Vec3(self.secondary_position),
+                "Composited fractal center",
+            ),
+            p(
+                "secondary_scale",
+                F32(self.secondary_scale),
+                "Composited fractal scale",
+            ),
+            p(
+                "secondary_tint",
+                Vec3(self.secondary_tint),
+                "Composited fractal albedo tint",
+            ),
+        ]
+    }
+}
+
+/// Index the fractal shader dispatches on for a fractal type
+fn shader_fractal_index(fractal_type: FractalType) -> u32 {
+    match fractal_type {
+        // 2D fractals (0-12)
+        FractalType::Mandelbrot2D => 0,
+        FractalType::Julia2D => 1,
+        FractalType::Sierpinski2D => 2,
+        FractalType::SierpinskiTriangle2D => 3,
+        FractalType::BurningShip2D => 4,
+        FractalType::Tricorn2D => 5,
+        FractalType::Phoenix2D => 6,
+        FractalType::Celtic2D => 7,
+        FractalType::Newton2D => 8,
+        FractalType::Lyapunov2D => 9,
+        FractalType::Nova2D => 10,
+        FractalType::Magnet2D => 11,
+        FractalType::Collatz2D => 12,
+        // 2D Density fractals
+        FractalType::Buddhabrot2D => 25, // Rendered via compute shader, not main shader
+        // 3D fractals (13-25)
+        FractalType::Mandelbulb3D => 13,
+        FractalType::MengerSponge3D => 14,
+        FractalType::SierpinskiPyramid3D => 15,
+        FractalType::JuliaSet3D => 16,
+        FractalType::Mandelbox3D => 17,
+        FractalType::OctahedralIFS3D => 18,
+        FractalType::IcosahedralIFS3D => 19,
+        FractalType::ApollonianGasket3D => 20,
+        FractalType::Kleinian3D => 21,
+        FractalType::HybridMandelbulbJulia3D => 22,
+        FractalType::QuaternionCubic3D => 23,
+        FractalType::SierpinskiGasket3D => 24,
+        // 2D Strange Attractors (26-32)
+        FractalType::Hopalong2D => 26,
+        FractalType::Martin2D => 27,
+        FractalType::Gingerbreadman2D => 28,
+        FractalType::Chip2D => 29,
+        FractalType::Quadruptwo2D => 30,
+        FractalType::Threeply2D => 31,
+        // 3D Strange Attractors (35-37)
+        FractalType::Pickover3D => 35,
+        FractalType::Lorenz3D => 36,
+        FractalType::Rossler3D => 37,
+    }
 }
 
 // Compile-time assertion to ensure struct size matches WGSL expectations
 const _: () = assert!(
-    std::mem::size_of::<Uniforms>() == 864,
-    "Uniforms struct must be exactly 864 bytes"
+    std::mem::size_of::<Uniforms>() == 1264,
+    "Uniforms struct must be exactly 1264 bytes"
 );
 
 // Post-processing uniform structs
@@ -540,14 +1134,8 @@ const _: () = assert!(
 pub(super) struct BloomUniforms {
     pub(super) threshold: f32,
     pub(super) intensity: f32,
-    pub(super) _padding: [f32; 2],
-}
-
-#[repr(C)]
-#[derive(Copy, Clone, Debug, Pod, Zeroable)]
-pub(super) struct BlurUniforms {
-    pub(super) direction: [f32; 2], // (1,0) for horizontal, (0,1) for vertical
-    pub(super) _padding: [f32; 2],
+    pub(super) radius: f32,       // Upsample filter radius (UV units)
+    pub(super) level_weight: f32, // 1 / mip level count
 }
 
 #[repr(C)]
@@ -567,6 +1155,15 @@ pub(super) struct PostProcessUniforms {
     pub(super) bloom_intensity: f32, // offset 36
     pub(super) _padding2: [f32; 2],  // offset 40 (pad to 48)
 
-    pub(super) _padding3: [f32; 4], // offset 48 (vec3 + alignment = 16 bytes)
-                                    // Total: 64 bytes
+    pub(super) symmetry_mode: u32,     // offset 48
+    pub(super) symmetry_segments: u32, // offset 52
+    pub(super) _padding3: [f32; 2],    // offset 56 (pad to 64)
+
+    pub(super) porthole_color: [f32; 3], // offset 64
+    pub(super) porthole_enabled: u32,    // offset 76
+    pub(super) porthole_radius: f32,     // offset 80
+    pub(super) porthole_feather: f32,    // offset 84
+    pub(super) porthole_oval: u32,       // offset 88
+    pub(super) cvd_simulation: u32,      // offset 92
+                                         // Total: 96 bytes
 }
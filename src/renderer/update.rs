@@ -1,6 +1,19 @@
+use super::bloom::bloom_level_count;
+use super::shader_export::ShaderExportFormat;
 use super::{BloomUniforms, PostProcessUniforms, Renderer};
 use crate::camera::Camera;
-use crate::fractal::FractalParams;
+use crate::fractal::{BloomQuality, CvdSimulation, FractalParams, RenderMode, SymmetryMode};
+
+/// Steps the scene render scale is snapped to
+const RENDER_SCALE_STEP: f32 = 0.05;
+
+/// Lowest scene render scale
+const MIN_RENDER_SCALE: f32 = 0.25;
+
+/// Snap a render scale to the steps the scene texture is allocated at
+fn snap_render_scale(scale: f32) -> f32 {
+    ((scale / RENDER_SCALE_STEP).round() * RENDER_SCALE_STEP).clamp(MIN_RENDER_SCALE, 1.0)
+}
 
 /// Update and helper methods
 impl Renderer {
@@ -33,41 +46,8 @@ impl Renderer {
 
     // Helper: Recreate all intermediate textures (for resize)
     fn recreate_textures(&mut self) {
-        let (scene_texture, scene_view) = Self::create_render_texture(
-            &self.device,
-            self.size.width,
-            self.size.height,
-            "Scene Texture",
-        );
-        self.scene_texture = scene_texture;
-        self.scene_view = scene_view;
-
-        let (bright_texture, bright_view) = Self::create_render_texture(
-            &self.device,
-            self.size.width,
-            self.size.height,
-            "Bright Texture",
-        );
-        self.bright_texture = bright_texture;
-        self.bright_view = bright_view;
-
-        let (blur_temp_texture, blur_temp_view) = Self::create_render_texture(
-            &self.device,
-            self.size.width,
-            self.size.height,
-            "Blur Temp Texture",
-        );
-        self.blur_temp_texture = blur_temp_texture;
-        self.blur_temp_view = blur_temp_view;
-
-        let (bloom_texture, bloom_view) = Self::create_render_texture(
-            &self.device,
-            self.size.width,
-            self.size.height,
-            "Bloom Texture",
-        );
-        self.bloom_texture = bloom_texture;
-        self.bloom_view = bloom_view;
+        self.bloom =
+            self.create_bloom_chain(self.size.width, self.size.height, self.bloom.quality());
 
         let (composite_texture, composite_view) = Self::create_render_texture(
             &self.device,
@@ -78,11 +58,43 @@ impl Renderer {
         self.composite_texture = composite_texture;
         self.composite_view = composite_view;
 
+        // Also recreates the composite bind group, which needs the new bloom chain
+        self.recreate_scene_texture();
+
         // Recreate bind groups that use these textures
         // We need to get the bind group layouts from the pipelines
         let texture_bind_group_layout = self.bloom_extract_pipeline.get_bind_group_layout(0);
-        let composite_texture_layout = self.composite_pipeline.get_bind_group_layout(0);
+        self.composite_final_bind_group =
+            self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Composite Final Bind Group"),
+                layout: &texture_bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&self.composite_view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(&self.sampler),
+                    },
+                ],
+            });
+    }
 
+    // Helper: Recreate the scene texture at the current render scale, and the
+    // bind groups that read it
+    fn recreate_scene_texture(&mut self) {
+        let scene_size = self.scene_size();
+        let (scene_texture, scene_view) = Self::create_render_texture(
+            &self.device,
+            scene_size.width,
+            scene_size.height,
+            "Scene Texture",
+        );
+        self.scene_texture = scene_texture;
+        self.scene_view = scene_view;
+
+        let texture_bind_group_layout = self.bloom_extract_pipeline.get_bind_group_layout(0);
         self.scene_bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Scene Bind Group"),
             layout: &texture_bind_group_layout,
@@ -98,36 +110,32 @@ impl Renderer {
             ],
         });
 
-        self.bright_bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Bright Bind Group"),
-            layout: &texture_bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: wgpu::BindingResource::TextureView(&self.bright_view),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::Sampler(&self.sampler),
-                },
-            ],
-        });
+        self.recreate_composite_bind_group();
+    }
 
-        self.blur_temp_bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Blur Temp Bind Group"),
-            layout: &texture_bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: wgpu::BindingResource::TextureView(&self.blur_temp_view),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::Sampler(&self.sampler),
-                },
-            ],
-        });
+    /// Size of the scene texture the fractal is rendered into: the render area
+    /// scaled by the LOD render scale. Post-processing upscales it to the window.
+    pub fn scene_size(&self) -> winit::dpi::PhysicalSize<u32> {
+        winit::dpi::PhysicalSize::new(
+            ((self.size.width as f32 * self.scene_scale).round() as u32).max(1),
+            ((self.size.height as f32 * self.scene_scale).round() as u32).max(1),
+        )
+    }
+
+    /// Render the fractal at a fraction of the render area's resolution. The
+    /// scale is snapped to discrete steps, so a smooth LOD transition only
+    /// reallocates the scene texture a few times.
+    pub fn set_scene_scale(&mut self, scale: f32) {
+        let scale = snap_render_scale(scale);
+        if scale != self.scene_scale {
+            self.scene_scale = scale;
+            self.recreate_scene_texture();
+        }
+    }
 
+    // Helper: Recreate the composite bind group (scene + bloom result)
+    fn recreate_composite_bind_group(&mut self) {
+        let composite_texture_layout = self.composite_pipeline.get_bind_group_layout(0);
         self.composite_bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Composite Bind Group"),
             layout: &composite_texture_layout,
@@ -142,7 +150,7 @@ impl Renderer {
                 },
                 wgpu::BindGroupEntry {
                     binding: 2,
-                    resource: wgpu::BindingResource::TextureView(&self.bloom_view),
+                    resource: wgpu::BindingResource::TextureView(self.bloom.result_view()),
                 },
                 wgpu::BindGroupEntry {
                     binding: 3,
@@ -150,47 +158,137 @@ impl Renderer {
                 },
             ],
         });
+    }
 
-        self.composite_final_bind_group =
-            self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("Composite Final Bind Group"),
-                layout: &texture_bind_group_layout,
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(&self.composite_view),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&self.sampler),
-                    },
-                ],
-            });
+    /// Rebuild the window's bloom chain when the quality (mip count) changes
+    pub fn set_bloom_quality(&mut self, quality: BloomQuality) {
+        if self.bloom.quality() != quality {
+            self.bloom = self.create_bloom_chain(self.size.width, self.size.height, quality);
+            self.recreate_composite_bind_group();
+        }
+    }
+
+    /// Switch the surface present mode, falling back to Fifo when the mode is unsupported.
+    /// Returns the mode actually applied.
+    pub fn set_present_mode(&mut self, mode: wgpu::PresentMode) -> wgpu::PresentMode {
+        let mode = if self.supported_present_modes.contains(&mode) {
+            mode
+        } else {
+            wgpu::PresentMode::Fifo
+        };
+        if self.config.present_mode != mode {
+            self.config.present_mode = mode;
+            self.surface.configure(&self.device, &self.config);
+        }
+        mode
     }
 
     pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 {
-            self.size = new_size;
             self.config.width = new_size.width;
             self.config.height = new_size.height;
             self.surface.configure(&self.device, &self.config);
+            self.set_render_area((0, 0), new_size);
+        }
+    }
+
+    /// Render the fractal into part of the window (e.g. beside a docked panel).
+    /// Intermediate textures are recreated when the size changes.
+    pub fn set_render_area(&mut self, origin: (u32, u32), size: winit::dpi::PhysicalSize<u32>) {
+        if size.width == 0 || size.height == 0 {
+            return;
+        }
+        self.render_origin = origin;
+        if size == self.size {
+            return;
+        }
+        self.size = size;
 
-            // Recreate intermediate textures for multi-pass rendering
-            self.recreate_textures();
+        // Recreate intermediate textures for multi-pass rendering
+        self.recreate_textures();
 
-            // Recreate accumulation texture if it exists (for strange attractors)
-            // This ensures the accumulation matches the new window size
-            if self.accumulation_texture.is_some() {
-                // Clear the existing texture and let it be recreated on next frame
-                self.accumulation_texture = None;
-                self.accumulation_display_bind_group = None;
-            }
+        // Recreate accumulation texture if it exists (for strange attractors)
+        // This ensures the accumulation matches the new render size
+        if self.accumulation_texture.is_some() {
+            // Clear the existing texture and let it be recreated on next frame
+            self.accumulation_texture = None;
+            self.accumulation_display_bind_group = None;
         }
     }
 
+    /// Offset every sample by a sub-pixel amount (in pixels) on the next uniform
+    /// update. Used to jitter supersampled captures; reset to zero afterwards.
+    pub fn set_pixel_jitter(&mut self, jitter: [f32; 2]) {
+        self.uniforms.set_pixel_jitter(jitter);
+    }
+
+    /// Simulate a color vision deficiency from the next uniform update on
+    pub fn set_cvd_simulation(&mut self, simulation: CvdSimulation) {
+        self.cvd_simulation = simulation;
+    }
+
+    /// Cap GPU-heavy settings to the safe mode limits from the next uniform update on
+    pub fn set_safe_mode(&mut self, enabled: bool) {
+        self.safe_mode = enabled;
+    }
+
+    /// Settings the last uniform update capped for safe mode, one message each
+    pub fn safe_mode_warnings(&self) -> &[String] {
+        &self.safe_mode_warnings
+    }
+
+    /// The uniform values of the last update as shader source, for the
+    /// shader-ready parameter export
+    pub fn shader_parameters(&self, format: ShaderExportFormat) -> String {
+        format.format(&self.uniforms.shader_params())
+    }
+
+    /// Offset the depth of field aperture pattern on the next uniform update.
+    /// Used to vary lens samples between accumulated capture frames.
+    pub fn set_lens_jitter(&mut self, jitter: [f32; 2]) {
+        self.uniforms.set_lens_jitter(jitter);
+    }
+
+    /// Render the background (escaped rays, 2D exterior) with zero alpha on the
+    /// next uniform update. Used for transparent captures; reset afterwards.
+    pub fn set_transparent_background(&mut self, transparent: bool) {
+        self.uniforms.set_transparent_background(transparent);
+    }
+
     pub fn update(&mut self, camera: &Camera, params: &FractalParams) {
+        self.set_bloom_quality(params.bloom_quality);
+        self.set_scene_scale(params.lod_render_scale());
+        let scene_size = self.scene_size();
+        self.update_for_size(camera, params, scene_size.width, scene_size.height);
+    }
+
+    /// Update uniforms for a render target of the given size (e.g. a high-res capture)
+    pub fn update_for_size(
+        &mut self,
+        camera: &Camera,
+        params: &FractalParams,
+        width: u32,
+        height: u32,
+    ) {
+        // Safe mode caps a copy of the GPU-heavy settings on their way to the
+        // shader, leaving the caller's values (and LOD presets) as they are
+        let limited = if self.safe_mode {
+            params.safe_mode_limited()
+        } else {
+            None
+        };
+        self.safe_mode_warnings = limited
+            .as_ref()
+            .map(|(_, warnings)| warnings.clone())
+            .unwrap_or_default();
+        let params = limited.as_ref().map_or(params, |(limited, _)| limited);
+
         let time = self.start_time.elapsed().as_secs_f32();
         self.uniforms.update(camera, params, time);
+        self.uniforms.set_viewport_size(width, height);
+        let aspect = width.max(1) as f32 / height.max(1) as f32;
+        let table_size = self.update_coord_table(params, aspect, width, height);
+        self.uniforms.set_coord_table_size(table_size);
         self.queue.write_buffer(
             &self.uniform_buffer,
             0,
@@ -201,7 +299,8 @@ impl Renderer {
         let bloom_uniforms = BloomUniforms {
             threshold: params.bloom_threshold,
             intensity: params.bloom_intensity,
-            _padding: [0.0; 2],
+            radius: params.bloom_radius,
+            level_weight: 1.0 / bloom_level_count(width, height, params.bloom_quality) as f32,
         };
         self.queue.write_buffer(
             &self.bloom_uniform_buffer,
@@ -212,8 +311,14 @@ impl Renderer {
         // Blur uniforms don't change (direction is fixed)
         // We use the same buffer for both H and V passes, just different bind groups
 
+        // Auto-exposure scales the user's brightness, which acts as exposure compensation
+        let exposure = if params.auto_exposure && params.render_mode == RenderMode::ThreeD {
+            self.auto_exposure_scale()
+        } else {
+            1.0
+        };
         let composite_uniforms = PostProcessUniforms {
-            brightness: params.brightness,
+            brightness: params.brightness * exposure,
             contrast: params.contrast,
             saturation: params.saturation,
             hue_shift: params.hue_shift,
@@ -224,7 +329,26 @@ impl Renderer {
             bloom_enabled: if params.bloom_enabled { 1 } else { 0 },
             bloom_intensity: params.bloom_intensity,
             _padding2: [0.0; 2],
-            _padding3: [0.0; 4],
+            symmetry_mode: match params.symmetry_mode {
+                SymmetryMode::Off => 0,
+                SymmetryMode::MirrorX => 1,
+                SymmetryMode::MirrorY => 2,
+                SymmetryMode::Quad => 3,
+                SymmetryMode::Radial => 4,
+            },
+            symmetry_segments: params.symmetry_segments.max(2),
+            _padding3: [0.0; 2],
+            porthole_color: params.porthole_color.to_array(),
+            porthole_enabled: if params.porthole_enabled { 1 } else { 0 },
+            porthole_radius: params.porthole_radius,
+            porthole_feather: params.porthole_feather,
+            porthole_oval: if params.porthole_oval { 1 } else { 0 },
+            cvd_simulation: match self.cvd_simulation {
+                CvdSimulation::None => 0,
+                CvdSimulation::Protanopia => 1,
+                CvdSimulation::Deuteranopia => 2,
+                CvdSimulation::Tritanopia => 3,
+            },
         };
         self.queue.write_buffer(
             &self.composite_uniform_buffer,
@@ -0,0 +1,219 @@
+// 3D Strange Attractor Compute Shader
+//
+// Iterates Pickover, Lorenz and Rossler orbits in 3D, projects every point
+// through the camera's view-projection matrix and accumulates hit counts into
+// the same storage texture the 2D attractors use. The existing accumulation
+// display pass then resolves the counts with log-scale palette coloring.
+//
+// Each thread owns one orbit whose position persists in a storage buffer, so
+// orbits stay on the attractor between frames and only need a transient skip
+// when they are (re)seeded.
+
+struct Uniforms {
+    // Camera view-projection matrix
+    view_proj: mat4x4<f32>,
+
+    // Attractor parameters (from julia_c, power, fractal_fold)
+    params: vec4<f32>,
+
+    // Rendering parameters
+    width: u32,
+    height: u32,
+    steps_per_thread: u32,
+    attractor_type: u32,
+
+    // World scale applied to attractor coordinates
+    scale: f32,
+    // Varies the random starting points between reseeds
+    seed: u32,
+    // 1 to start every orbit from a new random point
+    reseed: u32,
+    _padding: u32,
+}
+
+@group(0) @binding(0)
+var accumulation_texture: texture_storage_2d<r32uint, read_write>;
+
+@group(1) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+@group(1) @binding(1)
+var<storage, read_write> orbits: array<vec4<f32>>;
+
+// Steps skipped after seeding so orbits settle onto the attractor
+const TRANSIENT_STEPS: u32 = 500u;
+
+// Integration time steps for the continuous systems (match the ray marched versions)
+const LORENZ_DT: f32 = 0.005;
+const ROSSLER_DT: f32 = 0.01;
+
+// Simple hash function for generating pseudo-random numbers
+fn hash(seed: u32) -> u32 {
+    var s = seed;
+    s = s ^ (s >> 16u);
+    s = s * 0x85ebca6bu;
+    s = s ^ (s >> 13u);
+    s = s * 0xc2b2ae35u;
+    s = s ^ (s >> 16u);
+    return s;
+}
+
+// Convert hash to float in [0, 1)
+fn hash_to_float(h: u32) -> f32 {
+    return f32(h) / 4294967296.0;
+}
+
+// Random starting point in [-1, 1]^3
+fn random_start(seed: u32) -> vec3<f32> {
+    return vec3<f32>(
+        hash_to_float(hash(seed)),
+        hash_to_float(hash(seed ^ 0x12345678u)),
+        hash_to_float(hash(seed ^ 0x87654321u))
+    ) * 2.0 - 1.0;
+}
+
+// Pickover attractor (a discrete map):
+// x' = sin(a*y) - z*cos(b*x), y' = z*sin(c*x) - cos(d*y), z' = sin(x)
+fn pickover_step(p: vec3<f32>) -> vec3<f32> {
+    let a = uniforms.params.x;
+    let b = uniforms.params.y;
+    let c = uniforms.params.z;
+    let d = uniforms.params.w;
+    return vec3<f32>(
+        sin(a * p.y) - p.z * cos(b * p.x),
+        p.z * sin(c * p.x) - cos(d * p.y),
+        sin(p.x)
+    );
+}
+
+// Lorenz system: dx = sigma*(y - x), dy = x*(rho - z) - y, dz = x*y - beta*z
+fn lorenz_derivative(p: vec3<f32>) -> vec3<f32> {
+    let sigma = uniforms.params.x;
+    let rho = uniforms.params.y;
+    let beta = uniforms.params.z;
+    return vec3<f32>(
+        sigma * (p.y - p.x),
+        p.x * (rho - p.z) - p.y,
+        p.x * p.y - beta * p.z
+    );
+}
+
+// Rossler system: dx = -y - z, dy = x + a*y, dz = b + z*(x - c)
+fn rossler_derivative(p: vec3<f32>) -> vec3<f32> {
+    let a = uniforms.params.x;
+    let b = uniforms.params.y;
+    let c = uniforms.params.z;
+    return vec3<f32>(
+        -p.y - p.z,
+        p.x + a * p.y,
+        b + p.z * (p.x - c)
+    );
+}
+
+// One classic Runge-Kutta step of the selected continuous system
+fn rk4_step(p: vec3<f32>, dt: f32) -> vec3<f32> {
+    var k1: vec3<f32>;
+    var k2: vec3<f32>;
+    var k3: vec3<f32>;
+    var k4: vec3<f32>;
+    if (uniforms.attractor_type == 1u) {
+        k1 = lorenz_derivative(p);
+        k2 = lorenz_derivative(p + k1 * (dt * 0.5));
+        k3 = lorenz_derivative(p + k2 * (dt * 0.5));
+        k4 = lorenz_derivative(p + k3 * dt);
+    } else {
+        k1 = rossler_derivative(p);
+        k2 = rossler_derivative(p + k1 * (dt * 0.5));
+        k3 = rossler_derivative(p + k2 * (dt * 0.5));
+        k4 = rossler_derivative(p + k3 * dt);
+    }
+    return p + (k1 + 2.0 * k2 + 2.0 * k3 + k4) * (dt / 6.0);
+}
+
+// Advance an orbit by one step based on type
+fn attractor_step(p: vec3<f32>) -> vec3<f32> {
+    switch uniforms.attractor_type {
+        case 1u: { // Lorenz
+            return rk4_step(p, LORENZ_DT);
+        }
+        case 2u: { // Rossler
+            return rk4_step(p, ROSSLER_DT);
+        }
+        default: { // Pickover
+            return pickover_step(p);
+        }
+    }
+}
+
+// Attractor coordinates to world space, matching the ray marched placement
+fn to_world(p: vec3<f32>) -> vec3<f32> {
+    if (uniforms.attractor_type == 1u) {
+        // The Lorenz butterfly sits around z = 25
+        return vec3<f32>(p.x, p.y, p.z - 25.0) * uniforms.scale;
+    }
+    return p * uniforms.scale;
+}
+
+// Whether an orbit escaped or became invalid and needs a new start
+fn is_diverged(p: vec3<f32>) -> bool {
+    let m = max(abs(p.x), max(abs(p.y), abs(p.z)));
+    // Written as !(m < limit) so NaN also counts as diverged
+    return !(m < 100000.0);
+}
+
+// Start an orbit from a random point and let it settle onto the attractor
+fn seed_orbit(seed: u32) -> vec3<f32> {
+    var p = random_start(seed);
+    for (var i = 0u; i < TRANSIENT_STEPS; i = i + 1u) {
+        p = attractor_step(p);
+        if (is_diverged(p)) {
+            p = random_start(hash(seed ^ i));
+        }
+    }
+    return p;
+}
+
+// Workgroup size: 256 threads per workgroup
+// Each thread advances its own persistent orbit
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let thread_id = global_id.x;
+    if (thread_id >= arrayLength(&orbits)) {
+        return;
+    }
+
+    let seed = hash(thread_id ^ (uniforms.seed * 0x9E3779B9u));
+    var p = orbits[thread_id].xyz;
+    if (uniforms.reseed != 0u || is_diverged(p)) {
+        p = seed_orbit(seed);
+    }
+
+    let size = vec2<f32>(f32(uniforms.width), f32(uniforms.height));
+    for (var i = 0u; i < uniforms.steps_per_thread; i = i + 1u) {
+        p = attractor_step(p);
+        if (is_diverged(p)) {
+            p = seed_orbit(hash(seed ^ (i * 0xDEADBEEFu)));
+            continue;
+        }
+
+        // Project through the camera; skip points behind it or outside the frustum
+        let clip = uniforms.view_proj * vec4<f32>(to_world(p), 1.0);
+        if (clip.w <= 0.0) {
+            continue;
+        }
+        let ndc = clip.xyz / clip.w;
+        if (abs(ndc.x) > 1.0 || abs(ndc.y) > 1.0 || ndc.z < 0.0 || ndc.z > 1.0) {
+            continue;
+        }
+
+        // NDC to pixels (screen Y increases downward)
+        let screen = vec2<f32>(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5) * size;
+        let coord = min(vec2<u32>(screen), vec2<u32>(uniforms.width - 1u, uniforms.height - 1u));
+
+        // Increment hit count (R32Uint - single u32 channel)
+        let current = textureLoad(accumulation_texture, coord);
+        textureStore(accumulation_texture, coord, vec4<u32>(current.r + 1u, 0u, 0u, 0u));
+    }
+
+    orbits[thread_id] = vec4<f32>(p, 0.0);
+}
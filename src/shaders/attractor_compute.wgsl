@@ -9,6 +9,10 @@
 // - G: minimum distance to orbit (for anti-aliasing)
 // - B: reserved for future use (e.g., color from orbit position)
 // - A: reserved
+//
+// When coloring by iteration index or velocity, a second texture sums that
+// value (scaled to 0..ATTRIBUTE_SCALE) over every hit, so the display pass can
+// map the per-pixel mean to the palette.
 
 struct Uniforms {
     // Attractor parameters
@@ -32,12 +36,22 @@ struct Uniforms {
     // Accumulation control
     total_iterations: u32,
     clear_accumulation: u32,
-    _padding: vec2<u32>,
+    // 0 = density only, 1 = iteration index, 2 = velocity
+    color_mode: u32,
+    _padding: u32,
 }
 
+// Fixed-point scale of one hit's attribute (must match postprocess.wgsl)
+const ATTRIBUTE_SCALE: f32 = 255.0;
+// Hits after which the attribute sum stops growing, so it can't overflow
+const ATTRIBUTE_MAX_HITS: u32 = 16777216u;
+
 @group(0) @binding(0)
 var accumulation_texture: texture_storage_2d<r32uint, read_write>;
 
+@group(0) @binding(1)
+var attribute_texture: texture_storage_2d<r32uint, read_write>;
+
 @group(1) @binding(0)
 var<uniform> uniforms: Uniforms;
 
@@ -226,6 +240,7 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
     // Main accumulation loop
     for (var i = 0u; i < iterations_per_thread; i = i + 1u) {
+        let prev = pos;
         pos = attractor_step(pos);
 
         // Check for divergence and reset if needed
@@ -253,6 +268,21 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
             // Write back
             textureStore(accumulation_texture, coord, new_value);
+
+            if (uniforms.color_mode != 0u && current.r < ATTRIBUTE_MAX_HITS) {
+                var value: f32;
+                if (uniforms.color_mode == 1u) {
+                    // Position along this orbit, so its temporal structure shows
+                    value = f32(i) / f32(max(iterations_per_thread - 1u, 1u));
+                } else {
+                    // Jump length in screen heights, softly saturated
+                    let jump = length(pos - prev) * uniforms.zoom * 0.5;
+                    value = 1.0 - exp(-2.0 * jump);
+                }
+                let sum = textureLoad(attribute_texture, coord).r;
+                let scaled = u32(clamp(value, 0.0, 1.0) * ATTRIBUTE_SCALE + 0.5);
+                textureStore(attribute_texture, coord, vec4<u32>(sum + scaled, 0u, 0u, 0u));
+            }
         }
     }
 }
@@ -18,7 +18,8 @@ struct Uniforms {
     fractal_scale: f32,
     fractal_fold: f32,
     fractal_min_radius: f32,
-    _padding2: vec2<f32>,
+    contour_spacing: f32,   // Ray distance between contour lines, 0 = off
+    _padding2: f32,
 
     palette: array<vec4<f32>, 8>,
 
@@ -58,11 +59,13 @@ struct Uniforms {
     // Light direction
     light_azimuth: f32,     // Horizontal angle in degrees (0-360)
     light_elevation: f32,   // Vertical angle in degrees (5-90)
-    _padding_light: vec2<f32>, // Maintain 16-byte alignment
+    up_axis: u32,           // 0: Y-up, 1: Z-up
+    shadow_penumbra: f32,   // Soft shadow penumbra slope: tan(light half-angle) * softness multiplier
 
     show_floor: u32,
     floor_height: f32,
-    _padding_floor: vec2<f32>,
+    floor_checker_scale: f32,     // Checker tile size in world units
+    floor_checker_rotation: f32,  // Degrees around the up axis
     floor_color1: vec3<f32>,
     _padding_floor1: f32,
     floor_color2: vec3<f32>,
@@ -71,9 +74,8 @@ struct Uniforms {
     _padding_floor3_align_0: f32,
     _padding_floor3_align_1: f32,
     _padding_floor3_align_2: f32,
-    _padding_floor3_0: f32,
-    _padding_floor3_1: f32,
-    _padding_floor3_2: f32,
+    // Point the orbit trap color modes measure from (2D uses .xy)
+    orbit_trap_offset: vec3<f32>,
 
     use_adaptive_step: u32,
     fixed_step_size: f32,
@@ -83,8 +85,8 @@ struct Uniforms {
     fog_enabled: u32,
     fog_mode: u32,  // 0: Linear, 1: Exponential, 2: Quadratic
     fog_density: f32,
-    _padding_fog: f32,
-    _padding_fog_vec3_align: f32,  // Align fog_color to 16-byte boundary
+    fog_start: f32,
+    fog_end: f32,
     fog_color: vec3<f32>,
     _padding_fog_color: f32,
 
@@ -107,7 +109,8 @@ struct Uniforms {
     center_hi: vec2<f32>,   // High part of center (x, y)
     center_lo: vec2<f32>,   // Low part of center (x, y)
     high_precision: u32,    // Flag: 1 = use high precision
-    _hp_padding: vec3<f32>, // Padding to maintain alignment
+    // Point the kaleidoscopic IFS fractals scale around, (1, 1, 1) = symmetric
+    ifs_offset: vec3<f32>,
 
     // LOD debug visualization
     lod_debug_enabled: u32,  // Flag: 1 = show LOD zones as colors
@@ -116,12 +119,14 @@ struct Uniforms {
     lod_zone3: f32,          // Distance threshold: Medium -> Low
 
     // Aspect ratio stored in a vec4 slot to guarantee 16-byte alignment
-    aspect_ratio: vec4<f32>, // .x = width/height, others unused
+    aspect_ratio: vec4<f32>, // .x = width/height, .yz = viewport size in pixels, .w unused
 
     // Procedural palette parameters
     procedural_palette_type: u32, // 0=None (use static), 1=Firestrm, 2=Rainbow, etc.
-    _padding_proc_pal_0: u32,
-    _padding_proc_pal_1: u32,
+    // 2D boundary supersampling: grid side per pixel (0 = off) and the color
+    // contrast with a neighbour that marks a pixel as boundary
+    edge_aa_grid: u32,
+    edge_aa_threshold: f32,
     _padding_proc_pal_2: u32,
     // Custom procedural palette: color(t) = brightness + contrast * cos(2π * (frequency * t + phase))
     procedural_brightness: vec4<f32>, // [r, g, b, _]
@@ -129,13 +134,88 @@ struct Uniforms {
     procedural_frequency: vec4<f32>,  // [r, g, b, _]
     procedural_phase: vec4<f32>,      // [r, g, b, _]
 
-    // Padding to align struct to 864 bytes (54 × 16)
-    _padding_end: array<vec4<f32>, 2>,  // 32 bytes
+    // Stereoscopic 3D: per-eye camera matrices and positions
+    inv_view_proj_left: mat4x4<f32>,
+    inv_view_proj_right: mat4x4<f32>,
+    camera_pos_left: vec3<f32>,
+    stereo_mode: u32,       // 0: Off, 1: Side-by-side, 2: Anaglyph
+    camera_pos_right: vec3<f32>,
+    relief_height: f32,     // Elevation of the 2D relief's interior (3D relief of 2D fractals)
+
+    // Newton fractal polynomial roots, two complex roots packed per vec4
+    newton_root_count: u32,
+    _padding_newton_0: u32,
+    _padding_newton_1: u32,
+    _padding_newton_2: u32,
+    newton_roots: array<vec4<f32>, 4>,
+
+    // Hover iteration probe position (NDC), read by fs_probe
+    probe_uv: vec2<f32>,
+    // Precise 2D coordinate table size (columns, rows), 0 = standard mapping
+    coord_table_size: vec2<u32>,
+
+    // Seamless tile cell: origin (xy) and size (zw) in the complex plane, size 0 = off
+    tile_rect: vec4<f32>,
+
+    // Sub-pixel offset of every sample in pixels (capture supersampling), 0 = centered
+    pixel_jitter: vec2<f32>,
+
+    // Background pixels get alpha 0 (transparent captures), 0 = opaque
+    transparent_background: u32,
+
+    // 2D interior coloring: 0 = solid, 1 = final |z|, 2 = average |z|, 3 = period
+    interior_color_mode: u32,
+
+    // Phoenix coefficient on z(n-1) (complex)
+    phoenix_p: vec2<f32>,
+
+    // Darken 2D exteriors toward the boundary by distance estimate (0 = off)
+    distance_estimation_2d: u32,
+
+    // DoF aperture blade count (0 = circular, 3+ = polygon)
+    bokeh_blades: u32,
+
+    // Collatz odd branch m*n + d as (m, d)
+    collatz_params: vec2<f32>,
+
+    // DoF aperture pattern offset in [-0.5, 0.5): x rotates the Vogel spiral
+    // by a fraction of a turn, y shifts samples radially (accumulated captures).
+    lens_jitter: vec2<f32>,
+
+    // Secondary composited fractal: world-to-local rotation, position and
+    // scale, albedo tint, and fractal index (0 = off)
+    secondary_inv_rotation: mat3x3<f32>,
+    secondary_position: vec3<f32>,
+    secondary_scale: f32,
+    secondary_tint: vec3<f32>,
+    secondary_fractal_type: u32,
+
+    // Surface hit threshold growth with pixel size (0 = fixed min_distance),
+    // and tan(fov / 2) to size a pixel at a distance
+    detail_falloff: f32,
+    tan_half_fov: f32,
+    _padding_detail: vec2<f32>,
+
+    // Specular highlight tint and strength (white and 1 = untinted)
+    specular_color: vec3<f32>,
+    specular_intensity: f32,
+    // Struct is 1264 bytes (79 × 16)
 }
 
 @group(0) @binding(0)
 var<uniform> uniforms: Uniforms;
 
+// Image orbit trap (procedural default until the user loads one)
+@group(0) @binding(1)
+var orbit_trap_texture: texture_2d<f32>;
+@group(0) @binding(2)
+var orbit_trap_sampler: sampler;
+
+// Precise 2D mapping: per-column real parts then per-row imaginary parts,
+// computed in f64 on the CPU and stored as double-float (hi, lo) pairs
+@group(0) @binding(3)
+var<storage, read> coord_table: array<vec2<f32>>;
+
 struct VertexInput {
     @location(0) position: vec2<f32>,
 }
@@ -149,7 +229,9 @@ struct VertexOutput {
 fn vs_main(input: VertexInput) -> VertexOutput {
     var output: VertexOutput;
     output.clip_position = vec4<f32>(input.position, 0.0, 1.0);
-    output.uv = input.position;
+    // Shift the sample position by the capture jitter (pixels to NDC)
+    let viewport = max(uniforms.aspect_ratio.yz, vec2<f32>(1.0));
+    output.uv = input.position + uniforms.pixel_jitter * 2.0 / viewport;
     return output;
 }
 
@@ -564,6 +646,275 @@ fn complex_pow(z: vec2<f32>, n: f32) -> vec2<f32> {
     return vec2<f32>(r_n * cos(n_theta), r_n * sin(n_theta));
 }
 
+// ============================================================================
+// Image Orbit Traps
+// ============================================================================
+
+// Map trap-space coordinates, relative to the trap offset, onto the image:
+// [-1, 1] / orbit_trap_scale covers it once
+fn orbit_trap_uv(p: vec2<f32>) -> vec2<f32> {
+    return vec2<f32>(p.x, -p.y) * uniforms.orbit_trap_scale * 0.5 + 0.5;
+}
+
+// 2D image orbit trap: the first orbit point landing on an opaque image pixel
+// decides the color. Mandelbrot and Julia trace their own orbit; other types map
+// the pixel coordinate onto the (tiled) image like the other orbit trap modes.
+fn orbit_trap_image_2d(coord: vec2<f32>, t: f32) -> vec3<f32> {
+    var fallback = vec3<f32>(0.0);
+    if (t > 0.0) {
+        fallback = get_palette_color(t);
+    }
+
+    if (uniforms.fractal_type > 1u) {
+        let texel = textureSampleLevel(orbit_trap_texture, orbit_trap_sampler, orbit_trap_uv(coord - uniforms.orbit_trap_offset.xy), 0.0);
+        return mix(fallback, texel.rgb, texel.a);
+    }
+
+    var z = coord;
+    var c = uniforms.julia_c;
+    if (uniforms.fractal_type == 0u) {
+        z = vec2<f32>(0.0, 0.0);
+        c = coord;
+    }
+    let n = uniforms.power;
+    let escape_radius = select(4.0, pow(2.0, 2.0 / abs(n)), abs(n) < 2.0);
+
+    for (var i = 0u; i < uniforms.max_iterations; i = i + 1u) {
+        if (dot(z, z) > escape_radius * escape_radius) {
+            break;
+        }
+        z = complex_pow(z, n) + c;
+
+        // Only a single copy of the image acts as the trap
+        let uv = orbit_trap_uv(z - uniforms.orbit_trap_offset.xy);
+        if (all(uv >= vec2<f32>(0.0)) && all(uv <= vec2<f32>(1.0))) {
+            let texel = textureSampleLevel(orbit_trap_texture, orbit_trap_sampler, uv, 0.0);
+            if (texel.a > 0.5) {
+                return texel.rgb;
+            }
+        }
+    }
+
+    return fallback;
+}
+
+// ============================================================================
+// 2D Interior Coloring
+// ============================================================================
+
+// Longest cycle the period interior mode looks for
+const INTERIOR_MAX_PERIOD: u32 = 64u;
+
+// One iteration of the z^n + c family (Mandelbrot, Julia, Burning Ship, Tricorn, Celtic)
+fn escape_step_2d(z: vec2<f32>, c: vec2<f32>) -> vec2<f32> {
+    let n = uniforms.power;
+    if (uniforms.fractal_type == 4u) {
+        return complex_pow(abs(z), n) + c;
+    } else if (uniforms.fractal_type == 5u) {
+        return complex_pow(vec2<f32>(z.x, -z.y), n) + c;
+    } else if (uniforms.fractal_type == 7u) {
+        let z_pow = complex_pow(z, n);
+        return vec2<f32>(abs(z_pow.x) + c.x, z_pow.y + c.y);
+    }
+    return complex_pow(z, n) + c;
+}
+
+fn complex_mul(a: vec2<f32>, b: vec2<f32>) -> vec2<f32> {
+    return vec2<f32>(a.x * b.x - a.y * b.y, a.x * b.y + a.y * b.x);
+}
+
+fn complex_div(a: vec2<f32>, b: vec2<f32>) -> vec2<f32> {
+    return complex_mul(a, vec2<f32>(b.x, -b.y)) / max(dot(b, b), 1e-30);
+}
+
+// Distance from an interior Mandelbrot point to the boundary, from the attracting
+// cycle its orbit settles into. With the derivatives taken over one trip around
+// a cycle of period p: DE = (1 - |dz|^2) / |dcdz + dzdz dc / (1 - dz)|.
+// 0 = no cycle found (not settled yet near the boundary, or a longer cycle).
+fn interior_distance_2d(c: vec2<f32>) -> f32 {
+    let n = uniforms.power;
+    var z = vec2<f32>(0.0, 0.0);
+    for (var i = 0u; i < uniforms.max_iterations; i = i + 1u) {
+        z = complex_pow(z, n) + c;
+    }
+
+    let reference = z;
+    let tolerance = 1e-4 * max(length(reference), 1.0);
+    var period = 0u;
+    for (var p = 1u; p <= INTERIOR_MAX_PERIOD; p = p + 1u) {
+        z = complex_pow(z, n) + c;
+        if (distance(z, reference) < tolerance) {
+            period = p;
+            break;
+        }
+    }
+    if (period == 0u) {
+        return 0.0;
+    }
+
+    // First and second derivatives of f(z) = z^n + c along the cycle
+    z = reference;
+    var dz = vec2<f32>(1.0, 0.0);
+    var dc = vec2<f32>(0.0, 0.0);
+    var dzdz = vec2<f32>(0.0, 0.0);
+    var dcdz = vec2<f32>(0.0, 0.0);
+    for (var i = 0u; i < period; i = i + 1u) {
+        let f1 = n * complex_pow(z, n - 1.0);
+        // z^0 is 1 even at z = 0, which complex_pow doesn't give
+        var f2 = vec2<f32>(n * (n - 1.0), 0.0);
+        if (abs(n - 2.0) > 1e-3) {
+            f2 = f2 * complex_pow(z, n - 2.0);
+        }
+        dcdz = complex_mul(f2, complex_mul(dz, dc)) + complex_mul(f1, dcdz);
+        dzdz = complex_mul(f2, complex_mul(dz, dz)) + complex_mul(f1, dzdz);
+        dz = complex_mul(f1, dz);
+        dc = complex_mul(f1, dc) + vec2<f32>(1.0, 0.0);
+        z = complex_pow(z, n) + c;
+    }
+
+    let dz2 = dot(dz, dz);
+    if (dz2 >= 1.0) {
+        return 0.0;
+    }
+    let denominator = length(dcdz + complex_div(complex_mul(dzdz, dc), vec2<f32>(1.0, 0.0) - dz));
+    return (1.0 - dz2) / max(denominator, 1e-30);
+}
+
+// Interior distance as a palette position: 0 on the boundary, approaching 1 deep
+// inside a component. Scaled by the view so the gradient looks the same at any zoom.
+fn interior_distance_t(coord: vec2<f32>) -> f32 {
+    return 1.0 - exp(-2.0 * interior_distance_2d(coord) * uniforms.zoom);
+}
+
+// Interior distance field, optionally lit as a raised surface: its slope from
+// two neighboring pixels gives a normal, lit from the upper left
+fn interior_distance_color(coord: vec2<f32>, lit: bool) -> vec3<f32> {
+    let t = interior_distance_t(coord);
+    let color = get_palette_color(t);
+    if (!lit) {
+        return color;
+    }
+
+    let viewport_height = max(uniforms.aspect_ratio.z, 1.0);
+    let pixel_size = 4.0 / (uniforms.zoom * viewport_height);
+    // Slope per view height, flattened so the shading stays subtle
+    let slope_x = (interior_distance_t(coord + vec2<f32>(pixel_size, 0.0)) - t) * viewport_height;
+    let slope_y = (interior_distance_t(coord + vec2<f32>(0.0, pixel_size)) - t) * viewport_height;
+    let normal = normalize(vec3<f32>(-slope_x * 0.25, -slope_y * 0.25, 1.0));
+    let light = normalize(vec3<f32>(-1.0, 1.0, 1.5));
+    // Scaled so flat areas keep their palette color
+    let diffuse = max(dot(normal, light), 0.0) / light.z;
+    let specular = pow(max(reflect(-light, normal).z, 0.0), 24.0) * 0.4;
+    return color * (0.25 + 0.75 * diffuse) + vec3<f32>(specular);
+}
+
+// Color for a point that never escaped. Solid mode (and fractals without a z^n + c
+// orbit) stay black; otherwise the orbit is traced again and its final |z|,
+// average |z| or the period of the cycle it settles into picks a palette color.
+// The distance modes (Mandelbrot only) color by the interior distance estimate.
+fn interior_color_2d(coord: vec2<f32>) -> vec3<f32> {
+    let ft = uniforms.fractal_type;
+    let has_orbit = ft == 0u || ft == 1u || ft == 4u || ft == 5u || ft == 7u;
+    if (uniforms.interior_color_mode == 0u || !has_orbit) {
+        return vec3<f32>(0.0);
+    }
+    if (uniforms.interior_color_mode >= 4u) {
+        if (ft != 0u) {
+            return vec3<f32>(0.0);
+        }
+        return interior_distance_color(coord, uniforms.interior_color_mode == 5u);
+    }
+
+    var z = vec2<f32>(0.0, 0.0);
+    var c = coord;
+    if (ft == 1u) {
+        z = coord;
+        c = uniforms.julia_c;
+    }
+    var magnitude_sum = 0.0;
+    for (var i = 0u; i < uniforms.max_iterations; i = i + 1u) {
+        z = escape_step_2d(z, c);
+        magnitude_sum = magnitude_sum + length(z);
+    }
+
+    if (uniforms.interior_color_mode == 1u) {
+        // Bounded orbits stay within |z| <= 2
+        return get_palette_color(clamp(length(z) * 0.5, 0.0, 1.0));
+    } else if (uniforms.interior_color_mode == 2u) {
+        let average = magnitude_sum / f32(max(uniforms.max_iterations, 1u));
+        return get_palette_color(clamp(average * 0.5, 0.0, 1.0));
+    }
+
+    // Period: step from the settled point until the orbit returns to it
+    let reference = z;
+    let tolerance = 1e-4 * max(length(reference), 1.0);
+    for (var period = 1u; period <= INTERIOR_MAX_PERIOD; period = period + 1u) {
+        z = escape_step_2d(z, c);
+        if (distance(z, reference) < tolerance) {
+            // Golden-ratio spacing keeps neighboring periods apart on the palette
+            return get_palette_color(fract(f32(period) * 0.618034));
+        }
+    }
+    // Not settled yet (near the boundary) or a longer cycle
+    return vec3<f32>(0.0);
+}
+
+// ============================================================================
+// 2D Distance Estimation
+// ============================================================================
+
+// Large bailout so log|z| dominates and the estimate is accurate
+const DE_BAILOUT: f32 = 1000.0;
+// Width of the dark band along the boundary, in pixels
+const DE_BAND_PIXELS: f32 = 2.0;
+
+// Distance from an escaping point to the set boundary, from the orbit's running
+// derivative: DE = |z| ln|z| / (2 |dz|). Tracks |dz| as a magnitude so it also
+// applies to the folded variants (Burning Ship, Tricorn, Celtic). 0 = didn't escape.
+fn distance_estimate_2d(coord: vec2<f32>) -> f32 {
+    let n = abs(uniforms.power);
+    var z = vec2<f32>(0.0, 0.0);
+    var c = coord;
+    var dz = 0.0;
+    // Mandelbrot-style fractals differentiate with respect to c, Julia with respect to z0
+    var dc = 1.0;
+    if (uniforms.fractal_type == 1u) {
+        z = coord;
+        c = uniforms.julia_c;
+        dz = 1.0;
+        dc = 0.0;
+    }
+
+    for (var i = 0u; i < uniforms.max_iterations; i = i + 1u) {
+        let r = length(z);
+        if (r > DE_BAILOUT) {
+            break;
+        }
+        dz = min(n * pow(r, n - 1.0) * dz + dc, 1e30);
+        z = escape_step_2d(z, c);
+    }
+
+    let r = length(z);
+    if (r <= DE_BAILOUT) {
+        return 0.0;
+    }
+    return 0.5 * r * log(r) / max(dz, 1e-30);
+}
+
+// Brightness for an exterior pixel: 0 on the boundary rising to 1 a couple of
+// pixels away, so filaments stay crisp at one sample per pixel
+fn distance_shade_2d(coord: vec2<f32>) -> f32 {
+    let ft = uniforms.fractal_type;
+    let has_orbit = ft == 0u || ft == 1u || ft == 4u || ft == 5u || ft == 7u;
+    if (uniforms.distance_estimation_2d == 0u || !has_orbit) {
+        return 1.0;
+    }
+    // The view spans 4 / zoom vertically across the viewport height
+    let pixel_size = 4.0 / (uniforms.zoom * max(uniforms.aspect_ratio.z, 1.0));
+    let de = distance_estimate_2d(coord);
+    return smoothstep(0.0, 1.0, de / (pixel_size * DE_BAND_PIXELS));
+}
+
 fn mandelbrot(c: vec2<f32>) -> f32 {
     var z = vec2<f32>(0.0, 0.0);
     var iteration = 0u;
@@ -791,8 +1142,8 @@ fn phoenix(c: vec2<f32>) -> f32 {
     let n = uniforms.power;
     let escape_radius = select(4.0, pow(2.0, 2.0 / abs(n)), abs(n) < 2.0);
 
-    // Phoenix parameters
-    let p = vec2<f32>(0.5667, 0.0);
+    // Phoenix distortion coefficient on the previous z
+    let p = uniforms.phoenix_p;
 
     for (var i = 0u; i < uniforms.max_iterations; i = i + 1u) {
         if (dot(z, z) > escape_radius * escape_radius) {
@@ -846,51 +1197,60 @@ fn celtic(c: vec2<f32>) -> f32 {
     return (f32(iteration) + 1.0 - nu) / f32(uniforms.max_iterations);
 }
 
-// Newton fractal - finds roots of z^3 - 1
+// Newton polynomial root k, unpacked from the (re0, im0, re1, im1) uniform layout
+fn newton_root(k: u32) -> vec2<f32> {
+    let packed = uniforms.newton_roots[k / 2u];
+    if (k % 2u == 0u) {
+        return packed.xy;
+    }
+    return packed.zw;
+}
+
+// Newton fractal - finds roots of p(z) = (z - r0)(z - r1)...(z - rn)
+// Roots come from uniforms (defaults to z^3 - 1)
 fn newton_fractal(c: vec2<f32>) -> f32 {
     var z = c;
     var iteration = 0u;
     let tolerance = 0.000001;
-
-    // Roots of z^3 - 1
-    let root1 = vec2<f32>(1.0, 0.0);
-    let root2 = vec2<f32>(-0.5, 0.866025);
-    let root3 = vec2<f32>(-0.5, -0.866025);
+    let root_count = max(uniforms.newton_root_count, 1u);
 
     for (var i = 0u; i < uniforms.max_iterations; i = i + 1u) {
         // Check convergence to roots
-        if (distance(z, root1) < tolerance ||
-            distance(z, root2) < tolerance ||
-            distance(z, root3) < tolerance) {
-            break;
+        var converged = false;
+        for (var k = 0u; k < root_count; k = k + 1u) {
+            if (distance(z, newton_root(k)) < tolerance) {
+                converged = true;
+            }
         }
+        if (converged) { break; }
 
-        // Newton iteration: z = z - f(z)/f'(z) where f(z) = z^3 - 1
-        // f'(z) = 3z^2
-        let z2 = vec2<f32>(z.x * z.x - z.y * z.y, 2.0 * z.x * z.y);
-        let z3 = vec2<f32>(z2.x * z.x - z2.y * z.y, z2.x * z.y + z2.y * z.x);
-        let f_z = vec2<f32>(z3.x - 1.0, z3.y);
-        let f_prime = vec2<f32>(3.0 * z2.x, 3.0 * z2.y);
+        // Newton iteration: z = z - p(z)/p'(z)
+        // For a polynomial given by its roots, p'(z)/p(z) = sum of 1/(z - r_k)
+        var sum = vec2<f32>(0.0, 0.0);
+        for (var k = 0u; k < root_count; k = k + 1u) {
+            let d = z - newton_root(k);
+            sum = sum + vec2<f32>(d.x, -d.y) / dot(d, d);
+        }
 
-        let denom = dot(f_prime, f_prime);
-        if (denom < 0.0001) { break; }
+        let denom = dot(sum, sum);
+        if (denom < 1e-12) { break; }
 
-        // Complex division: f_z / f_prime
-        let div = vec2<f32>(
-            (f_z.x * f_prime.x + f_z.y * f_prime.y) / denom,
-            (f_z.y * f_prime.x - f_z.x * f_prime.y) / denom
-        );
-        z = z - div;
+        // Complex reciprocal: 1 / sum = conj(sum) / |sum|^2
+        z = z - vec2<f32>(sum.x, -sum.y) / denom;
         iteration = i;
     }
 
     // Color based on which root and iteration count
-    let d1 = distance(z, root1);
-    let d2 = distance(z, root2);
-    let d3 = distance(z, root3);
-    var root_offset = 0.0;
-    if (d2 < d1 && d2 < d3) { root_offset = 0.33; }
-    else if (d3 < d1 && d3 < d2) { root_offset = 0.66; }
+    var nearest = 0u;
+    var nearest_dist = distance(z, newton_root(0u));
+    for (var k = 1u; k < root_count; k = k + 1u) {
+        let d = distance(z, newton_root(k));
+        if (d < nearest_dist) {
+            nearest_dist = d;
+            nearest = k;
+        }
+    }
+    let root_offset = f32(nearest) / f32(root_count);
 
     return (f32(iteration) / f32(uniforms.max_iterations)) * 0.5 + root_offset * 0.5;
 }
@@ -1020,8 +1380,12 @@ fn magnet_fractal(c: vec2<f32>) -> f32 {
     return (f32(iteration) + 1.0 - nu) / f32(uniforms.max_iterations);
 }
 
-// Collatz fractal - based on Collatz conjecture generalized to complex numbers
+// Collatz fractal - based on Collatz conjecture generalized to complex numbers.
+// Blends the even branch n/2 and the odd branch m*n + d with cos²/sin²(πz/2):
+// z = 0.25 * ((1 + 2m)z + 2d - ((2m - 1)z + 2d) * cos(πz)); m = 3, d = 1 is the classic map
 fn collatz_fractal(c: vec2<f32>) -> f32 {
+    let m = uniforms.collatz_params.x;
+    let d = uniforms.collatz_params.y;
     var z = c;
     var iteration = 0u;
     var min_dist = 1000.0;  // Track minimum distance to origin for coloring
@@ -1037,7 +1401,7 @@ fn collatz_fractal(c: vec2<f32>) -> f32 {
 
         min_dist = min(min_dist, sqrt(mag_sq));
 
-        // Collatz map generalized to complex: z = 0.25 * (2 + 7z - (2 + 5z) * cos(π * z))
+        // Collatz map generalized to complex (classic: 0.25 * (2 + 7z - (2 + 5z) * cos(π * z)))
         let pi = 3.14159265359;
 
         // cos(π * z) for complex z
@@ -1047,17 +1411,18 @@ fn collatz_fractal(c: vec2<f32>) -> f32 {
         let cos_imag = -sin(piz_real) * sinh(piz_imag);
         let cos_piz = vec2<f32>(cos_real, cos_imag);
 
-        // (2 + 5z)
-        let term1 = vec2<f32>(2.0 + 5.0 * z.x, 5.0 * z.y);
+        // (2d + (2m - 1)z)
+        let term1 = vec2<f32>(2.0 * d + (2.0 * m - 1.0) * z.x, (2.0 * m - 1.0) * z.y);
 
-        // (2 + 5z) * cos(π * z)
+        // (2d + (2m - 1)z) * cos(π * z)
         let product = vec2<f32>(
             term1.x * cos_piz.x - term1.y * cos_piz.y,
             term1.x * cos_piz.y + term1.y * cos_piz.x
         );
 
-        // 2 + 7z - product, then multiply by 0.25
-        z = vec2<f32>(2.0 + 7.0 * z.x - product.x, 7.0 * z.y - product.y) * 0.25;
+        // 2d + (1 + 2m)z - product, then multiply by 0.25
+        let linear = 1.0 + 2.0 * m;
+        z = vec2<f32>(2.0 * d + linear * z.x - product.x, linear * z.y - product.y) * 0.25;
         iteration = i;
     }
 
@@ -1505,6 +1870,48 @@ fn mandelbulb_de(pos: vec3<f32>) -> f32 {
     return 0.5 * log(r) * r / (dr * scale_inv);
 }
 
+// Closest approach of the Mandelbulb orbit to the trap offset (point trap),
+// returned relative to the offset
+fn mandelbulb_orbit_trap(pos: vec3<f32>) -> vec3<f32> {
+    let scale_inv = 1.0 / uniforms.fractal_scale;
+    var z = pos * scale_inv;
+    var trap = z - uniforms.orbit_trap_offset;
+    let power = uniforms.power;
+
+    for (var i = 0u; i < 16u; i = i + 1u) {
+        let r = length(z);
+        if (r > 2.0) {
+            break;
+        }
+        if (length(z - uniforms.orbit_trap_offset) < length(trap)) {
+            trap = z - uniforms.orbit_trap_offset;
+        }
+
+        let theta = acos(z.z / r) * power;
+        let phi = atan2(z.y, z.x) * power;
+        z = pow(r, power) * vec3<f32>(
+            sin(theta) * cos(phi),
+            sin(phi) * sin(theta),
+            cos(theta)
+        );
+        z = z + pos * scale_inv;
+    }
+
+    return trap;
+}
+
+// 3D image orbit trap: the Mandelbulb uses its orbit's closest approach,
+// other types project the hit position onto the tiled image
+fn orbit_trap_image_3d(pos: vec3<f32>) -> vec3<f32> {
+    var trap = pos - uniforms.orbit_trap_offset;
+    if (uniforms.fractal_type == 13u) {
+        trap = mandelbulb_orbit_trap(pos);
+    }
+    let texel = textureSampleLevel(orbit_trap_texture, orbit_trap_sampler, orbit_trap_uv(trap.xy), 0.0);
+    let fallback = get_palette_color(fract(length(trap) * uniforms.orbit_trap_scale));
+    return mix(fallback, texel.rgb, texel.a);
+}
+
 fn box_fold(p: vec3<f32>, fold_limit: f32) -> vec3<f32> {
     return clamp(p, vec3<f32>(-fold_limit), vec3<f32>(fold_limit)) * 2.0 - p;
 }
@@ -1538,12 +1945,15 @@ fn menger_sponge_de(pos: vec3<f32>) -> f32 {
             p = p.xzy;
         }
 
+        // Scale by 3 around the offset corner; the z fold keeps the middle
+        // layer of cubes
+        let offset = uniforms.ifs_offset;
         p = p * 3.0;
         scale = scale * 3.0;
-        p = p - vec3<f32>(2.0, 2.0, 2.0);
+        p = p - offset * 2.0;
 
-        if (p.z < -1.0) {
-            p.z = p.z + 2.0;
+        if (p.z < -offset.z) {
+            p.z = p.z + offset.z * 2.0;
         }
     }
 
@@ -1569,7 +1979,7 @@ fn sierpinski_pyramid_de(pos: vec3<f32>) -> f32 {
         }
 
         // Scale and translate
-        p = p * 2.0 - vec3<f32>(1.0, 1.0, 1.0);
+        p = p * 2.0 - uniforms.ifs_offset;
         scale = scale * 2.0;
     }
 
@@ -1682,7 +2092,7 @@ fn octahedral_ifs_de(pos: vec3<f32>) -> f32 {
         }
 
         // Apply scaling and translation
-        p = p * scale - vec3<f32>(1.0) * (scale - 1.0);
+        p = p * scale - uniforms.ifs_offset * (scale - 1.0);
         dr = dr * scale;
 
         // Bailout
@@ -1753,7 +2163,7 @@ fn icosahedral_ifs_de(pos: vec3<f32>) -> f32 {
         }
 
         // Apply scaling and translation
-        p = p * scale - vec3<f32>(1.0) * (scale - 1.0);
+        p = p * scale - uniforms.ifs_offset * (scale - 1.0);
         dr = dr * scale;
 
         if (length(p) > 100.0) {
@@ -1962,7 +2372,7 @@ fn sierpinski_gasket_de(pos: vec3<f32>) -> f32 {
         }
 
         // Scale and translate to create the gasket structure
-        p = p * scale_factor - vec3<f32>(1.0, 1.0, 1.0) * (scale_factor - 1.0);
+        p = p * scale_factor - uniforms.ifs_offset * (scale_factor - 1.0);
         scale = scale * scale_factor;
     }
 
@@ -2107,60 +2517,161 @@ fn quat_mul(a: vec4<f32>, b: vec4<f32>) -> vec4<f32> {
     );
 }
 
+// World up vector for the floor and light (see up_axis)
+fn world_up() -> vec3<f32> {
+    if (uniforms.up_axis == 1u) {
+        return vec3<f32>(0.0, 0.0, 1.0);
+    }
+    return vec3<f32>(0.0, 1.0, 0.0);
+}
+
+// Map a direction from the Y-up frame into the world frame. Z-up is the Y-up
+// world rotated +90 degrees about X: (x, y, z) -> (x, -z, y).
+fn from_y_up(v: vec3<f32>) -> vec3<f32> {
+    if (uniforms.up_axis == 1u) {
+        return vec3<f32>(v.x, -v.z, v.y);
+    }
+    return v;
+}
+
+// Inverse of from_y_up: map a world position into the Y-up frame
+fn to_y_up(v: vec3<f32>) -> vec3<f32> {
+    if (uniforms.up_axis == 1u) {
+        return vec3<f32>(v.x, v.z, -v.y);
+    }
+    return v;
+}
+
+// ============================================================================
+// 3D Relief of 2D Fractals
+// ============================================================================
+
+// Half-width of the relief's square footprint; it spans the same region of
+// the complex plane as the 2D view's height at the current center and zoom
+const RELIEF_EXTENT: f32 = 2.0;
+
+// A 2D fractal rendered by the 3D pipeline (types 0-12 with render_mode 3D)
+fn relief_active() -> bool {
+    return uniforms.render_mode == 1u && uniforms.fractal_type <= 12u;
+}
+
+// Complex-plane coordinate under a relief position (ground plane is Y-up XZ)
+fn relief_coord(pos: vec3<f32>) -> vec2<f32> {
+    let q = to_y_up(pos);
+    return uniforms.center_hi + vec2<f32>(q.x, -q.z) / uniforms.zoom;
+}
+
+// Elevation of the relief: the smooth iteration value, with the interior
+// (points that never escape) as the top plateau
+fn relief_elevation(coord: vec2<f32>) -> f32 {
+    let t = evaluate_2d(coord);
+    return select(t, 1.0, t == 0.0) * uniforms.relief_height;
+}
+
+// Height-field distance estimate. The vertical distance to the surface
+// overestimates the true distance on steep slopes, so it is scaled down; the
+// bounding slab lets rays outside the footprint skip ahead without iterating.
+fn relief_de(pos: vec3<f32>) -> f32 {
+    let q = to_y_up(pos);
+    let height = max(uniforms.relief_height, 1e-3);
+    let half_size = vec3<f32>(RELIEF_EXTENT, height * 0.5, RELIEF_EXTENT);
+    let b = abs(q - vec3<f32>(0.0, height * 0.5, 0.0)) - half_size;
+    let slab = length(max(b, vec3<f32>(0.0))) + min(max(b.x, max(b.y, b.z)), 0.0);
+    if (slab > 0.05) {
+        return slab;
+    }
+    let surface = relief_elevation(relief_coord(pos));
+    return max(slab, (q.y - surface) * 0.25);
+}
+
 // Floor distance estimation
 fn floor_de(pos: vec3<f32>) -> f32 {
-    return pos.y - uniforms.floor_height;
+    return dot(pos, world_up()) - uniforms.floor_height;
 }
 
 // Checkered pattern for floor
 fn checkered(pos: vec3<f32>) -> vec3<f32> {
-    let scale = 1.0;
-    let ix = floor(pos.x / scale);
-    let iz = floor(pos.z / scale);
+    let scale = max(uniforms.floor_checker_scale, 0.001);
+    let angle = radians(uniforms.floor_checker_rotation);
+    let c = cos(angle);
+    let s = sin(angle);
+    // Floor plane coordinates, matching the Y-up layout under from_y_up
+    var plane = pos.xz;
+    if (uniforms.up_axis == 1u) {
+        plane = vec2<f32>(pos.x, -pos.y);
+    }
+    // Rotate the floor plane around the up axis before tiling
+    let rotated = vec2<f32>(c * plane.x - s * plane.y, s * plane.x + c * plane.y);
+    let ix = floor(rotated.x / scale);
+    let iz = floor(rotated.y / scale);
     return select(uniforms.floor_color2, uniforms.floor_color1, (i32(ix) + i32(iz)) % 2 == 0);
 }
 
 struct SceneResult {
     distance: f32,
-    material_id: u32, // 0 = fractal, 1 = floor
+    material_id: u32, // 0 = fractal, 1 = floor, 2 = secondary fractal
+}
+
+// Distance estimate of one fractal type; the shape parameters are shared
+fn fractal_de(fractal_type: u32, pos: vec3<f32>) -> f32 {
+    // 2D fractals (types 0-12) reach the 3D pipeline only as a relief;
+    // 3D fractals start at type 13
+    if (fractal_type <= 12u) {
+        return relief_de(pos);
+    } else if (fractal_type == 13u) {
+        return mandelbulb_de(pos);
+    } else if (fractal_type == 14u) {
+        return menger_sponge_de(pos);
+    } else if (fractal_type == 15u) {
+        return sierpinski_pyramid_de(pos);
+    } else if (fractal_type == 16u) {
+        return julia_set_3d_de(pos);
+    } else if (fractal_type == 17u) {
+        return mandelbox_de(pos);
+    } else if (fractal_type == 18u) {
+        return octahedral_ifs_de(pos);
+    } else if (fractal_type == 19u) {
+        return icosahedral_ifs_de(pos);
+    } else if (fractal_type == 20u) {
+        return apollonian_gasket_de(pos);
+    } else if (fractal_type == 21u) {
+        return kleinian_de(pos);
+    } else if (fractal_type == 22u) {
+        return hybrid_mandelbulb_julia_de(pos);
+    } else if (fractal_type == 23u) {
+        return quaternion_cubic_de(pos);
+    } else if (fractal_type == 24u) {
+        return sierpinski_gasket_de(pos);
+    // 3D Strange Attractors (types 35-37)
+    } else if (fractal_type == 35u) {
+        return pickover_attractor_de(pos);
+    } else if (fractal_type == 36u) {
+        return lorenz_attractor_de(pos);
+    } else if (fractal_type == 37u) {
+        return rossler_attractor_de(pos);
+    }
+    return 1000.0;
+}
+
+// Distance estimate of the secondary composited fractal, evaluated in its own
+// rotated and scaled space (a uniform scale keeps the estimate conservative)
+fn secondary_fractal_de(pos: vec3<f32>) -> f32 {
+    let local = uniforms.secondary_inv_rotation * (pos - uniforms.secondary_position) / uniforms.secondary_scale;
+    return fractal_de(uniforms.secondary_fractal_type, local) * uniforms.secondary_scale;
 }
 
 fn scene_de_with_material(pos: vec3<f32>) -> SceneResult {
     var result: SceneResult;
-    var fractal_dist = 1000.0;
+    var fractal_dist = fractal_de(uniforms.fractal_type, pos);
+    var fractal_material = 0u;
 
-    // 3D fractals start at type 13 (after 13 2D types: 0-12)
-    if (uniforms.fractal_type == 13u) {
-        fractal_dist = mandelbulb_de(pos);
-    } else if (uniforms.fractal_type == 14u) {
-        fractal_dist = menger_sponge_de(pos);
-    } else if (uniforms.fractal_type == 15u) {
-        fractal_dist = sierpinski_pyramid_de(pos);
-    } else if (uniforms.fractal_type == 16u) {
-        fractal_dist = julia_set_3d_de(pos);
-    } else if (uniforms.fractal_type == 17u) {
-        fractal_dist = mandelbox_de(pos);
-    } else if (uniforms.fractal_type == 18u) {
-        fractal_dist = octahedral_ifs_de(pos);
-    } else if (uniforms.fractal_type == 19u) {
-        fractal_dist = icosahedral_ifs_de(pos);
-    } else if (uniforms.fractal_type == 20u) {
-        fractal_dist = apollonian_gasket_de(pos);
-    } else if (uniforms.fractal_type == 21u) {
-        fractal_dist = kleinian_de(pos);
-    } else if (uniforms.fractal_type == 22u) {
-        fractal_dist = hybrid_mandelbulb_julia_de(pos);
-    } else if (uniforms.fractal_type == 23u) {
-        fractal_dist = quaternion_cubic_de(pos);
-    } else if (uniforms.fractal_type == 24u) {
-        fractal_dist = sierpinski_gasket_de(pos);
-    // 3D Strange Attractors (types 35-37)
-    } else if (uniforms.fractal_type == 35u) {
-        fractal_dist = pickover_attractor_de(pos);
-    } else if (uniforms.fractal_type == 36u) {
-        fractal_dist = lorenz_attractor_de(pos);
-    } else if (uniforms.fractal_type == 37u) {
-        fractal_dist = rossler_attractor_de(pos);
+    // Union with the secondary fractal: the nearer surface wins
+    if (uniforms.secondary_fractal_type != 0u) {
+        let secondary_dist = secondary_fractal_de(pos);
+        if (secondary_dist < fractal_dist) {
+            fractal_dist = secondary_dist;
+            fractal_material = 2u;
+        }
     }
 
     var floor_dist = 1000.0;
@@ -2173,7 +2684,7 @@ fn scene_de_with_material(pos: vec3<f32>) -> SceneResult {
         result.material_id = 1u;
     } else {
         result.distance = fractal_dist;
-        result.material_id = 0u;
+        result.material_id = fractal_material;
     }
 
     return result;
@@ -2222,14 +2733,9 @@ struct RayMarchResult {
     material_id: u32,
 }
 
-fn ray_march(origin: vec3<f32>, direction: vec3<f32>) -> RayMarchResult {
-    var result: RayMarchResult;
-    result.hit = false;
-    result.distance = 0.0;
-    result.steps = 0u;
-    result.material_id = 0u;
-
-    // Bounding sphere acceleration: skip empty space when camera is outside sphere
+// Returns the distance along the ray where marching should start, using the
+// bounding sphere to skip empty space when the camera is outside it
+fn ray_march_start_distance(origin: vec3<f32>, direction: vec3<f32>) -> f32 {
     var total_distance = 0.0;
     // Conservative bounding radius to account for all fractal types and parameters
     // Different fractals grow at different rates with iterations:
@@ -2238,8 +2744,12 @@ fn ray_march(origin: vec3<f32>, direction: vec3<f32>) -> RayMarchResult {
     // - Apollonian: complex growth with min_radius
     // Use very conservative multiplier to ensure we never clip the fractal
     let iteration_factor = f32(max(uniforms.max_iterations, uniforms.max_steps)) * 0.1;
-    let bounding_radius = 50.0 * uniforms.fractal_scale * max(1.0, uniforms.fractal_fold) * max(1.0, iteration_factor);
+    var bounding_radius = 50.0 * uniforms.fractal_scale * max(1.0, uniforms.fractal_fold) * max(1.0, iteration_factor);
     let bounding_center = vec3<f32>(0.0);
+    // Grow the sphere to also enclose the secondary fractal
+    if (uniforms.secondary_fractal_type != 0u) {
+        bounding_radius = max(bounding_radius, length(uniforms.secondary_position) + bounding_radius * uniforms.secondary_scale);
+    }
 
     // Check if camera is outside the bounding sphere
     let camera_to_center = length(origin - bounding_center);
@@ -2249,8 +2759,10 @@ fn ray_march(origin: vec3<f32>, direction: vec3<f32>) -> RayMarchResult {
         if (sphere_hit > 0.0) {
             // Check if we would hit the floor before the sphere
             var floor_hit = -1.0;
-            if (uniforms.show_floor == 1u && abs(direction.y) > 0.0001) {
-                let t = (uniforms.floor_height - origin.y) / direction.y;
+            let up = world_up();
+            let direction_up = dot(direction, up);
+            if (uniforms.show_floor == 1u && abs(direction_up) > 0.0001) {
+                let t = (uniforms.floor_height - dot(origin, up)) / direction_up;
                 if (t > 0.0) {
                     floor_hit = t;
                 }
@@ -2263,7 +2775,27 @@ fn ray_march(origin: vec3<f32>, direction: vec3<f32>) -> RayMarchResult {
         }
         // If ray misses the sphere, continue with normal ray marching (for floor, etc.)
     }
-    // If camera is inside sphere, start ray marching from camera position (total_distance = 0.0)
+
+    // If camera is inside sphere, start ray marching from camera position
+    return total_distance;
+}
+
+// Surface hit threshold at `distance` along a ray: min_distance, or with
+// detail falloff, that many times the width of a pixel there if larger
+fn hit_epsilon(distance: f32) -> f32 {
+    let pixel_width = 2.0 * distance * uniforms.tan_half_fov / max(uniforms.aspect_ratio.z, 1.0);
+    return max(uniforms.min_distance, uniforms.detail_falloff * pixel_width);
+}
+
+fn ray_march(origin: vec3<f32>, direction: vec3<f32>) -> RayMarchResult {
+    var result: RayMarchResult;
+    result.hit = false;
+    result.distance = 0.0;
+    result.steps = 0u;
+    result.material_id = 0u;
+
+    // Bounding sphere acceleration: skip empty space when camera is outside sphere
+    var total_distance = ray_march_start_distance(origin, direction);
 
     for (var i = 0u; i < uniforms.max_steps; i = i + 1u) {
         result.steps = i;
@@ -2281,9 +2813,10 @@ fn ray_march(origin: vec3<f32>, direction: vec3<f32>) -> RayMarchResult {
             step_size = uniforms.fixed_step_size;
         }
 
+        let epsilon = hit_epsilon(total_distance);
         total_distance = total_distance + step_size;
 
-        if (dist < uniforms.min_distance) {
+        if (dist < epsilon) {
             result.hit = true;
             result.distance = total_distance;
             result.position = pos;
@@ -2306,6 +2839,7 @@ fn ray_march(origin: vec3<f32>, direction: vec3<f32>) -> RayMarchResult {
 // Convert azimuth and elevation angles to a 3D direction vector
 // Azimuth: horizontal angle in degrees (0-360), 0=+Z, 90=+X
 // Elevation: vertical angle in degrees (5-90), 90=straight up
+// Both are measured in the Y-up frame, then rotated to the up axis convention
 fn calculate_light_direction() -> vec3<f32> {
     let PI = 3.14159265359;
     let azimuth_rad = uniforms.light_azimuth * PI / 180.0;
@@ -2318,11 +2852,11 @@ fn calculate_light_direction() -> vec3<f32> {
     let sin_azim = sin(azimuth_rad);
 
     // Build direction vector
-    return normalize(vec3<f32>(
+    return normalize(from_y_up(vec3<f32>(
         cos_elev * sin_azim,  // X component
         sin_elev,             // Y component (up)
         cos_elev * cos_azim   // Z component
-    ));
+    )));
 }
 
 // ============================================================================
@@ -2373,7 +2907,10 @@ fn calculate_soft_shadow(origin: vec3<f32>, direction: vec3<f32>, mint: f32) ->
 
     var result = 1.0;
     var t = mint;
+    var prev_h = 0.0;
+    var prev_step = 0.0;
     let maxt = uniforms.shadow_max_distance;
+    let penumbra = max(uniforms.shadow_penumbra, 1e-4);
     // Use the same precision as main ray marching for consistent shadow detection
     let shadow_threshold = uniforms.min_distance * 2.0;
 
@@ -2390,7 +2927,7 @@ fn calculate_soft_shadow(origin: vec3<f32>, direction: vec3<f32>, mint: f32) ->
         // Check if shadow ray has exited the bounding sphere
         // If so, there's no fractal to occlude, so return full light
         if (length(pos - bounding_center) > bounding_radius) {
-            return result;
+            break;
         }
 
         let h = scene_de(pos);
@@ -2404,7 +2941,17 @@ fn calculate_soft_shadow(origin: vec3<f32>, direction: vec3<f32>, mint: f32) ->
             break;
         }
         if (uniforms.soft_shadows == 2u) {
-            result = min(result, uniforms.shadow_softness * h / t);
+            // Fraction of the light's disc visible past the occluder: the ray's
+            // closest approach to it over the penumbra cone's width at that
+            // point. The closest approach is where the distance spheres of this
+            // and the previous sample intersect, so shadows are hard at contact
+            // and widen with distance from the occluder.
+            var y = 0.0;
+            if (prev_step > 0.0) {
+                y = clamp((prev_step * prev_step - prev_h * prev_h + h * h) / (2.0 * prev_step), 0.0, h);
+            }
+            let d = sqrt(max(h * h - y * y, 0.0));
+            result = min(result, d / (penumbra * max(t - y, 1e-6)));
         }
 
         // Conservative stepping to prevent missing thin features:
@@ -2414,12 +2961,19 @@ fn calculate_soft_shadow(origin: vec3<f32>, direction: vec3<f32>, mint: f32) ->
         let max_step = 0.05 * uniforms.fractal_scale;
         let step = min(conservative_step, max_step);
         t = t + step;
+        prev_h = h;
+        prev_step = step;
 
         if (t > maxt) {
             break;
         }
     }
 
+    if (uniforms.soft_shadows == 2u) {
+        // Smooth the penumbra's falloff across the light's disc
+        let visible = clamp(result, 0.0, 1.0);
+        return visible * visible * (3.0 - 2.0 * visible);
+    }
     return result;
 }
 
@@ -2434,7 +2988,12 @@ fn blinn_phong(normal: vec3<f32>, view_dir: vec3<f32>, light_dir: vec3<f32>, alb
     let half_dir = normalize(light_dir + view_dir);
     let specular = pow(max(dot(normal, half_dir), 0.0), 32.0) * uniforms.light_intensity;
 
-    return albedo * (ambient + diffuse) + vec3<f32>(specular);
+    return albedo * (ambient + diffuse) + specular * specular_tint();
+}
+
+// Color and strength of specular highlights
+fn specular_tint() -> vec3<f32> {
+    return uniforms.specular_color * uniforms.specular_intensity;
 }
 
 // PBR Functions
@@ -2479,7 +3038,7 @@ fn pbr(normal: vec3<f32>, view_dir: vec3<f32>, light_dir: vec3<f32>, albedo: vec
 
     let numerator = ndf * g * f;
     let denominator = 4.0 * n_dot_v * n_dot_l + 0.001;
-    let specular = numerator / denominator;
+    let specular = numerator / denominator * specular_tint();
 
     let k_s = f;
     var k_d = vec3<f32>(1.0) - k_s;
@@ -2512,12 +3071,26 @@ fn sample_disk_indexed(uv: vec2<f32>, sample_index: u32, num_samples: u32) -> ve
     // Use deterministic Vogel disk distribution (golden angle spiral)
     // This creates evenly distributed samples without noise
     let golden_angle = 2.39996322972; // Golden angle in radians
-    let angle = f32(sample_index) * golden_angle;
-    let radius = sqrt((f32(sample_index) + 0.5) / f32(num_samples)); // +0.5 for better center coverage
+    let angle = f32(sample_index) * golden_angle + uniforms.lens_jitter.x * 6.28318530718;
+    // +0.5 for better center coverage; the jitter moves samples within their ring
+    let radius = sqrt((f32(sample_index) + 0.5 + uniforms.lens_jitter.y) / f32(num_samples));
 
     return vec2<f32>(cos(angle), sin(angle)) * radius;
 }
 
+// Radius of a regular polygon aperture (circumradius 1) in the direction `angle`.
+// Scaling disk samples by this squeezes them into the polygon, giving polygonal bokeh.
+fn aperture_radius(angle: f32, blades: u32) -> f32 {
+    if (blades < 3u) {
+        return 1.0;
+    }
+    let sector = 6.28318530718 / f32(blades);
+    let half_sector = sector * 0.5;
+    // Angle from the middle of the edge this direction points at
+    let local = angle - floor(angle / sector) * sector - half_sector;
+    return cos(half_sector) / cos(local);
+}
+
 // Sample a point on a disk (for aperture) - single sample version
 fn sample_disk(uv: vec2<f32>) -> vec2<f32> {
     // Use pixel position for stable sampling (not time-based to avoid jitter)
@@ -2531,9 +3104,40 @@ fn sample_disk(uv: vec2<f32>) -> vec2<f32> {
 // Ray Rendering Helper (for DOF multi-sampling)
 // ============================================================================
 
+// Primary rays traced for the current pixel and how many of them hit a surface,
+// for the transparent background alpha (DOF and anaglyph trace several per pixel)
+var<private> rays_traced: f32 = 0.0;
+var<private> rays_hit: f32 = 0.0;
+
+// Pixel alpha: the fraction of its rays that hit a surface with a transparent
+// background, otherwise opaque
+fn surface_coverage() -> f32 {
+    if (uniforms.transparent_background == 0u || rays_traced == 0.0) {
+        return 1.0;
+    }
+    return rays_hit / rays_traced;
+}
+
+// Darkening factor for iso-distance contour lines: 1 away from a line, down
+// to 0.2 on the lines where the ray distance crosses a multiple of the spacing.
+// Lines are a fixed fraction of the spacing wide with soft edges.
+fn distance_contour(distance: f32) -> f32 {
+    if (uniforms.contour_spacing <= 0.0) {
+        return 1.0;
+    }
+    let phase = distance / uniforms.contour_spacing;
+    let from_line = abs(fract(phase + 0.5) - 0.5);
+    let line = 1.0 - smoothstep(0.03, 0.06, from_line);
+    return 1.0 - 0.8 * line;
+}
+
 fn render_ray(ray_origin: vec3<f32>, ray_dir: vec3<f32>, uv: vec2<f32>) -> vec3<f32> {
     // Ray march
     let result = ray_march(ray_origin, ray_dir);
+    rays_traced = rays_traced + 1.0;
+    if (result.hit) {
+        rays_hit = rays_hit + 1.0;
+    }
 
     if (!result.hit) {
         // Background - use fog color if fog is enabled, otherwise gradient
@@ -2582,14 +3186,18 @@ fn render_ray(ray_origin: vec3<f32>, ray_dir: vec3<f32>, uv: vec2<f32>) -> vec3<
             apply_shading = false;
         } else if (uniforms.color_mode == 3u) {
             // Orbit Trap XYZ - color based on position components using palette
-            let xyz_sum = abs(fract(pos.x * uniforms.orbit_trap_scale * 1.5)) + abs(fract(pos.y * uniforms.orbit_trap_scale * 1.5)) + abs(fract(pos.z * uniforms.orbit_trap_scale * 1.5));
+            let trap_pos = pos - uniforms.orbit_trap_offset;
+            let xyz_sum = abs(fract(trap_pos.x * uniforms.orbit_trap_scale * 1.5)) + abs(fract(trap_pos.y * uniforms.orbit_trap_scale * 1.5)) + abs(fract(trap_pos.z * uniforms.orbit_trap_scale * 1.5));
             let trap_t = fract(xyz_sum / 3.0);
             albedo = get_palette_color(trap_t);
         } else if (uniforms.color_mode == 4u) {
             // Orbit Trap Radial - color based on distance patterns using palette
-            let dist = length(pos);
+            let dist = length(pos - uniforms.orbit_trap_offset);
             let radial_t = fract(dist * uniforms.orbit_trap_scale * 2.0);
             albedo = get_palette_color(radial_t);
+        } else if (uniforms.color_mode == 16u) {
+            // Orbit Trap Image - sample the trap image at the orbit's trap point
+            albedo = orbit_trap_image_3d(pos);
         } else if (uniforms.color_mode == 5u) {
             // World Position visualization
             albedo = abs(fract(pos * 0.5));
@@ -2677,6 +3285,12 @@ fn render_ray(ray_origin: vec3<f32>, ray_dir: vec3<f32>, uv: vec2<f32>) -> vec3<
             let gray = clamp(distance / 100.0, 0.0, 1.0);
             final_color = vec3<f32>(gray);
             apply_shading = false;
+        } else if (relief_active()) {
+            // Standard palette mode (0) on a relief: color by the 2D fractal's
+            // own iteration value, as in the flat view
+            let coord = relief_coord(pos);
+            let t = evaluate_2d(coord);
+            albedo = select(get_palette_color(t), interior_color_2d(coord), t == 0.0);
         } else {
             // Standard palette mode (0)
             let color_t = f32(result.steps) / f32(uniforms.max_steps);
@@ -2684,6 +3298,11 @@ fn render_ray(ray_origin: vec3<f32>, ray_dir: vec3<f32>, uv: vec2<f32>) -> vec3<
         }
     }
 
+    // Tell the secondary composited fractal apart by its tint
+    if (result.material_id == 2u) {
+        albedo = albedo * uniforms.secondary_tint;
+    }
+
     // Apply shading to modes that need it
     if (apply_shading) {
         if (uniforms.shading_model == 0u) {
@@ -2703,22 +3322,24 @@ fn render_ray(ray_origin: vec3<f32>, ray_dir: vec3<f32>, uv: vec2<f32>) -> vec3<
         }
     }
 
+    final_color = final_color * distance_contour(result.distance);
+
     // Apply screen-space reflections for floor
     if (result.material_id == 1u && uniforms.floor_reflections != 0u) {
         // Reflect the view direction about the floor normal (upward)
-        let floor_normal = vec3<f32>(0.0, 1.0, 0.0);
+        let floor_normal = world_up();
         let reflect_dir = reflect(-view_dir, floor_normal);
 
         // Cast a reflection ray from the floor position
         let reflect_origin = pos + floor_normal * uniforms.min_distance * 3.0;
         let reflect_result = ray_march(reflect_origin, reflect_dir);
 
-        if (reflect_result.hit && reflect_result.material_id == 0u) {
+        if (reflect_result.hit && reflect_result.material_id != 1u) {
             // We hit the fractal - calculate its color
             let reflect_pos = reflect_result.position;
             let reflect_normal = calculate_normal(reflect_pos);
             let reflect_view_dir = normalize(reflect_origin - reflect_pos);
-            let light_dir = normalize(vec3<f32>(0.5, 1.0, 0.3));
+            let light_dir = normalize(from_y_up(vec3<f32>(0.5, 1.0, 0.3)));
 
             // Get fractal albedo based on color mode
             var reflect_albedo: vec3<f32>;
@@ -2729,6 +3350,9 @@ fn render_ray(ray_origin: vec3<f32>, ray_dir: vec3<f32>, uv: vec2<f32>) -> vec3<
                 let color_t = f32(reflect_result.steps) / f32(uniforms.max_steps);
                 reflect_albedo = get_palette_color(color_t);
             }
+            if (reflect_result.material_id == 2u) {
+                reflect_albedo = reflect_albedo * uniforms.secondary_tint;
+            }
 
             // Apply lighting to reflection
             var reflect_color: vec3<f32>;
@@ -2763,12 +3387,13 @@ fn render_ray(ray_origin: vec3<f32>, ray_dir: vec3<f32>, uv: vec2<f32>) -> vec3<
 
     // Apply fog if enabled
     if (uniforms.fog_enabled != 0u) {
-        let dist = length(pos - ray_origin);
+        // Fog begins at fog_start
+        let dist = max(length(pos - ray_origin) - uniforms.fog_start, 0.0);
         var fog_factor: f32;
 
         if (uniforms.fog_mode == 0u) {
-            // Linear fog
-            fog_factor = clamp(dist * uniforms.fog_density, 0.0, 1.0);
+            // Linear fog, opaque at fog_end
+            fog_factor = dist / max(uniforms.fog_end - uniforms.fog_start, 1e-4);
         } else if (uniforms.fog_mode == 1u) {
             // Exponential fog
             fog_factor = 1.0 - exp(-uniforms.fog_density * dist);
@@ -2932,188 +3557,368 @@ fn apply_post_processing(color: vec3<f32>, uv: vec2<f32>) -> vec3<f32> {
 // Main Fragment Shader
 // ============================================================================
 
-@fragment
-fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    if (uniforms.render_mode == 0u) {
-        // 2D Mode
-        // Use the aspect ratio supplied by the host (window or capture target)
-        let aspect = uniforms.aspect_ratio.x;
+// Evaluate the active 2D fractal at a screen position (NDC uv)
+struct Sample2D {
+    t: f32,
+    coord: vec2<f32>,
+}
+
+// Standard precision 2D fractal evaluation
+fn evaluate_2d(coord: vec2<f32>) -> f32 {
+    if (uniforms.fractal_type == 0u) {
+        return mandelbrot(coord);
+    } else if (uniforms.fractal_type == 1u) {
+        return julia(coord);
+    } else if (uniforms.fractal_type == 2u) {
+        return sierpinski(coord);
+    } else if (uniforms.fractal_type == 3u) {
+        return sierpinski_triangle(coord);
+    } else if (uniforms.fractal_type == 4u) {
+        return burning_ship(coord);
+    } else if (uniforms.fractal_type == 5u) {
+        return tricorn(coord);
+    } else if (uniforms.fractal_type == 6u) {
+        return phoenix(coord);
+    } else if (uniforms.fractal_type == 7u) {
+        return celtic(coord);
+    } else if (uniforms.fractal_type == 8u) {
+        return newton_fractal(coord);
+    } else if (uniforms.fractal_type == 9u) {
+        return lyapunov_fractal(coord);
+    } else if (uniforms.fractal_type == 10u) {
+        return nova_fractal(coord);
+    } else if (uniforms.fractal_type == 11u) {
+        return magnet_fractal(coord);
+    } else if (uniforms.fractal_type == 12u) {
+        return collatz_fractal(coord);
+    // Strange Attractors (types 26-34, indices after 3D fractals)
+    } else if (uniforms.fractal_type == 26u) {
+        return hopalong_attractor(coord);
+    } else if (uniforms.fractal_type == 27u) {
+        return henon_attractor(coord);
+    } else if (uniforms.fractal_type == 28u) {
+        return martin_attractor(coord);
+    } else if (uniforms.fractal_type == 29u) {
+        return gingerbreadman_attractor(coord);
+    } else if (uniforms.fractal_type == 30u) {
+        return latoocarfian_attractor(coord);
+    } else if (uniforms.fractal_type == 31u) {
+        return chip_attractor(coord);
+    } else if (uniforms.fractal_type == 32u) {
+        return quadruptwo_attractor(coord);
+    } else if (uniforms.fractal_type == 33u) {
+        return threeply_attractor(coord);
+    } else if (uniforms.fractal_type == 34u) {
+        return icon_attractor(coord);
+    } else {
+        return collatz_fractal(coord);
+    }
+}
 
-        var t: f32;
-        var coord: vec2<f32>;
+// Precise 2D mapping: look up this pixel's column/row coordinate as (hi, lo) pairs.
+// Returns vec4(hi.x, hi.y, lo.x, lo.y).
+fn precise_2d_coord(uv: vec2<f32>) -> vec4<f32> {
+    let size = uniforms.coord_table_size;
+    let cell = min(vec2<u32>((uv * 0.5 + 0.5) * vec2<f32>(size)), size - 1u);
+    let re = coord_table[cell.x];
+    let im = coord_table[size.x + cell.y];
+    return vec4<f32>(re.x, im.x, re.y, im.y);
+}
 
-        // Check if high-precision mode is enabled and fractal supports it
-        if (uniforms.high_precision == 1u && uniforms.fractal_type <= 4u) {
+// Width/height of the render target, so 2D pixels map to square cells of the
+// complex plane at any window shape (falls back to the camera aspect)
+fn viewport_aspect() -> f32 {
+    let viewport = uniforms.aspect_ratio.yz;
+    if (viewport.x > 0.0 && viewport.y > 0.0) {
+        return viewport.x / viewport.y;
+    }
+    return uniforms.aspect_ratio.x;
+}
+
+// Seamless tile mode: map the view onto a whole number of periodic cells so the
+// image wraps at its edges. Cells repeat along the longer axis, as many as keep
+// them closest to their true shape.
+fn seamless_tile_coord(uv: vec2<f32>) -> vec2<f32> {
+    let origin = uniforms.tile_rect.xy;
+    let size = uniforms.tile_rect.zw;
+    let ratio = viewport_aspect() * size.y / size.x;
+    var cells = vec2<f32>(1.0);
+    if (ratio >= 1.0) {
+        cells.x = round(ratio);
+    } else {
+        cells.y = round(1.0 / ratio);
+    }
+    let local = fract((uv * 0.5 + 0.5) * cells);
+    return origin + local * size;
+}
+
+fn sample_2d(uv: vec2<f32>) -> Sample2D {
+    return sample_2d_offset(uv, vec2<f32>(0.0));
+}
+
+// Sample the 2D fractal `offset` pixels from the pixel at `uv`. With the precise
+// coordinate table the offset is added to the pixel's double-float coordinate,
+// so supersamples line up exactly with the base pass.
+fn sample_2d_offset(pixel_uv: vec2<f32>, offset: vec2<f32>) -> Sample2D {
+    let viewport = max(uniforms.aspect_ratio.yz, vec2<f32>(1.0));
+    let uv = pixel_uv + offset * 2.0 / viewport;
+    if (uniforms.tile_rect.z > 0.0) {
+        let tile_coord = seamless_tile_coord(uv);
+        return Sample2D(evaluate_2d(tile_coord), tile_coord);
+    }
+
+    let aspect = viewport_aspect();
+
+    var t: f32;
+    var coord: vec2<f32>;
+
+    let use_table = uniforms.coord_table_size.x > 0u && uniforms.coord_table_size.y > 0u;
+    // Check if high-precision mode is enabled and fractal supports it
+    if (use_table || (uniforms.high_precision == 1u && uniforms.fractal_type <= 4u)) {
+        var coord_hi: vec2<f32>;
+        var coord_lo: vec2<f32>;
+        if (use_table) {
+            // Coordinates computed in f64 on the CPU. The table holds pixel
+            // centers, so look up the unjittered pixel and add the capture
+            // jitter back as a sub-pixel step.
+            let base_uv = pixel_uv - uniforms.pixel_jitter * 2.0 / viewport;
+            let cell = precise_2d_coord(base_uv);
+            coord_hi = cell.xy;
+            coord_lo = cell.zw;
+            let sub_pixel = offset + uniforms.pixel_jitter;
+            if (any(sub_pixel != vec2<f32>(0.0))) {
+                let pixel_step = 4.0 / uniforms.zoom * vec2<f32>(aspect, 1.0)
+                    / vec2<f32>(uniforms.coord_table_size);
+                let delta = sub_pixel * pixel_step;
+                let coord_x = df_add_full(coord_hi.x, coord_lo.x, delta.x, 0.0);
+                let coord_y = df_add_full(coord_hi.y, coord_lo.y, delta.y, 0.0);
+                coord_hi = vec2<f32>(coord_x.x, coord_y.x);
+                coord_lo = vec2<f32>(coord_x.y, coord_y.y);
+            }
+        } else {
             // High-precision coordinate calculation
             // offset = uv * 2.0 / zoom * aspect (for x) or uv * 2.0 / zoom (for y)
-            let offset_x = input.uv.x * 2.0 / uniforms.zoom * aspect;
-            let offset_y = input.uv.y * 2.0 / uniforms.zoom;
+            let offset_x = uv.x * 2.0 / uniforms.zoom * aspect;
+            let offset_y = uv.y * 2.0 / uniforms.zoom;
 
             // Add offset to high-precision center using double-float arithmetic
             let coord_x = df_add_full(uniforms.center_hi.x, uniforms.center_lo.x, offset_x, 0.0);
             let coord_y = df_add_full(uniforms.center_hi.y, uniforms.center_lo.y, offset_y, 0.0);
-            let coord_hi = vec2<f32>(coord_x.x, coord_y.x);
-            let coord_lo = vec2<f32>(coord_x.y, coord_y.y);
-            coord = coord_hi; // Use high part for color modes
-
-            // Use high-precision fractal functions
-            if (uniforms.fractal_type == 0u) {
-                t = mandelbrot_hp(coord_hi, coord_lo);
-            } else if (uniforms.fractal_type == 1u) {
-                t = julia_hp(coord_hi, coord_lo);
-            } else if (uniforms.fractal_type == 2u) {
-                t = sierpinski_hp(coord_hi, coord_lo);
-            } else if (uniforms.fractal_type == 3u) {
-                t = sierpinski_triangle_hp(coord_hi, coord_lo);
-            } else if (uniforms.fractal_type == 4u) {
-                t = burning_ship_hp(coord_hi, coord_lo);
-            } else {
-                t = tricorn_hp(coord_hi, coord_lo);
-            }
+            coord_hi = vec2<f32>(coord_x.x, coord_y.x);
+            coord_lo = vec2<f32>(coord_x.y, coord_y.y);
+        }
+        coord = coord_hi; // Use high part for color modes
+
+        // Use high-precision fractal functions where available
+        if (uniforms.fractal_type == 0u) {
+            t = mandelbrot_hp(coord_hi, coord_lo);
+        } else if (uniforms.fractal_type == 1u) {
+            t = julia_hp(coord_hi, coord_lo);
+        } else if (uniforms.fractal_type == 2u) {
+            t = sierpinski_hp(coord_hi, coord_lo);
+        } else if (uniforms.fractal_type == 3u) {
+            t = sierpinski_triangle_hp(coord_hi, coord_lo);
+        } else if (uniforms.fractal_type == 4u) {
+            t = burning_ship_hp(coord_hi, coord_lo);
         } else {
-            // Standard precision coordinate
-            coord = vec2<f32>(
-                uniforms.center.x + (input.uv.x * 2.0 / uniforms.zoom) * aspect,
-                uniforms.center.y + (input.uv.y * 2.0 / uniforms.zoom)
-            );
-
-            if (uniforms.fractal_type == 0u) {
-                t = mandelbrot(coord);
-            } else if (uniforms.fractal_type == 1u) {
-                t = julia(coord);
-            } else if (uniforms.fractal_type == 2u) {
-                t = sierpinski(coord);
-            } else if (uniforms.fractal_type == 3u) {
-                t = sierpinski_triangle(coord);
-            } else if (uniforms.fractal_type == 4u) {
-                t = burning_ship(coord);
-            } else if (uniforms.fractal_type == 5u) {
-                t = tricorn(coord);
-            } else if (uniforms.fractal_type == 6u) {
-                t = phoenix(coord);
-            } else if (uniforms.fractal_type == 7u) {
-                t = celtic(coord);
-            } else if (uniforms.fractal_type == 8u) {
-                t = newton_fractal(coord);
-            } else if (uniforms.fractal_type == 9u) {
-                t = lyapunov_fractal(coord);
-            } else if (uniforms.fractal_type == 10u) {
-                t = nova_fractal(coord);
-            } else if (uniforms.fractal_type == 11u) {
-                t = magnet_fractal(coord);
-            } else if (uniforms.fractal_type == 12u) {
-                t = collatz_fractal(coord);
-            // Strange Attractors (types 26-34, indices after 3D fractals)
-            } else if (uniforms.fractal_type == 26u) {
-                t = hopalong_attractor(coord);
-            } else if (uniforms.fractal_type == 27u) {
-                t = henon_attractor(coord);
-            } else if (uniforms.fractal_type == 28u) {
-                t = martin_attractor(coord);
-            } else if (uniforms.fractal_type == 29u) {
-                t = gingerbreadman_attractor(coord);
-            } else if (uniforms.fractal_type == 30u) {
-                t = latoocarfian_attractor(coord);
-            } else if (uniforms.fractal_type == 31u) {
-                t = chip_attractor(coord);
-            } else if (uniforms.fractal_type == 32u) {
-                t = quadruptwo_attractor(coord);
-            } else if (uniforms.fractal_type == 33u) {
-                t = threeply_attractor(coord);
-            } else if (uniforms.fractal_type == 34u) {
-                t = icon_attractor(coord);
-            } else {
-                t = collatz_fractal(coord);
-            }
+            t = evaluate_2d(coord);
         }
+    } else {
+        // Standard precision coordinate
+        coord = vec2<f32>(
+            uniforms.center.x + (uv.x * 2.0 / uniforms.zoom) * aspect,
+            uniforms.center.y + (uv.y * 2.0 / uniforms.zoom)
+        );
+        t = evaluate_2d(coord);
+    }
 
-        if (t == 0.0) {
-            // No post-processing - render raw fractal (post-FX done in multi-pass pipeline)
-            return vec4<f32>(0.0, 0.0, 0.0, 1.0);
-        }
+    return Sample2D(t, coord);
+}
 
-        var color: vec3<f32>;
-        if (uniforms.color_mode == 1u) {
-            // Iteration visualization (similar to ray steps)
-            color = vec3<f32>(t, t * 0.5, 1.0 - t);
-        } else if (uniforms.color_mode == 2u) {
-            // Grayscale iteration count
-            color = vec3<f32>(t);
-        } else if (uniforms.color_mode == 3u) {
-            // Orbit Trap XYZ - color based on coordinate components using palette
-            let xy_sum = abs(fract(coord.x * uniforms.orbit_trap_scale * 2.0)) + abs(fract(coord.y * uniforms.orbit_trap_scale * 2.0));
-            let trap_t = fract(xy_sum / 2.0);
-            color = get_palette_color(trap_t);
-        } else if (uniforms.color_mode == 4u) {
-            // Orbit Trap Radial - color based on distance from origin using palette
-            let dist = length(coord);
-            let radial_t = fract(dist * uniforms.orbit_trap_scale * 3.0);
-            color = get_palette_color(radial_t);
-        } else if (uniforms.color_mode == 5u || uniforms.color_mode == 6u) {
-            // Position-based coloring for 2D
-            color = vec3<f32>(abs(fract(coord.x)), abs(fract(coord.y)), abs(fract(coord.x + coord.y)));
-        } else {
-            // Palette mode (default)
-            color = get_palette_color(t);
+// Hover probe: evaluate the 2D fractal at the cursor for the iteration readout.
+// Output is (normalized smooth iteration t, coord.x, coord.y, 1).
+@fragment
+fn fs_probe(input: VertexOutput) -> @location(0) vec4<f32> {
+    let sample = sample_2d(uniforms.probe_uv);
+    return vec4<f32>(sample.t, sample.coord, 1.0);
+}
+
+// 2D boundary supersampling: the first pass's output, read by fs_edge_aa
+@group(1) @binding(0)
+var edge_aa_source: texture_2d<f32>;
+
+// Second 2D pass: pixels whose color contrasts with a neighbour are on the
+// fractal boundary and get re-rendered with a grid of sub-pixel samples; all
+// others keep the first pass's color
+@fragment
+fn fs_edge_aa(input: VertexOutput) -> @location(0) vec4<f32> {
+    let pixel = vec2<i32>(input.clip_position.xy);
+    let max_pixel = vec2<i32>(textureDimensions(edge_aa_source)) - 1;
+    let center = textureLoad(edge_aa_source, pixel, 0);
+
+    var contrast = 0.0;
+    let neighbours = array<vec2<i32>, 4>(
+        vec2<i32>(1, 0), vec2<i32>(-1, 0), vec2<i32>(0, 1), vec2<i32>(0, -1)
+    );
+    for (var i = 0; i < 4; i = i + 1) {
+        let neighbour = textureLoad(edge_aa_source, clamp(pixel + neighbours[i], vec2<i32>(0), max_pixel), 0);
+        let difference = abs(neighbour.rgb - center.rgb);
+        contrast = max(contrast, max(difference.r, max(difference.g, difference.b)));
+    }
+    if (contrast < uniforms.edge_aa_threshold) {
+        return center;
+    }
+
+    let grid = uniforms.edge_aa_grid;
+    var sum = vec4<f32>(0.0);
+    for (var y = 0u; y < grid; y = y + 1u) {
+        for (var x = 0u; x < grid; x = x + 1u) {
+            let offset = (vec2<f32>(f32(x), f32(y)) + 0.5) / f32(grid) - 0.5;
+            sum = sum + shade_2d(sample_2d_offset(input.uv, offset));
         }
+    }
+    return sum / f32(grid * grid);
+}
+
+// Whether a 2D sample belongs to the fractal itself. Escape-time fractals
+// return 0 for points that never escaped, while the Sierpinski shapes and the
+// strange attractors return 0 for the empty space around them.
+fn in_fractal_2d(t: f32) -> bool {
+    let t_marks_shape = uniforms.fractal_type == 2u || uniforms.fractal_type == 3u
+        || (uniforms.fractal_type >= 26u && uniforms.fractal_type <= 34u);
+    return select(t == 0.0, t != 0.0, t_marks_shape);
+}
+
+// Color of a 2D sample, with alpha 0 for the exterior of transparent captures
+fn shade_2d(sample: Sample2D) -> vec4<f32> {
+    let t = sample.t;
+    let coord = sample.coord;
 
+    // Everything outside the fractal is background, which a transparent capture hides
+    var alpha = 1.0;
+    if (uniforms.transparent_background != 0u && !in_fractal_2d(t)) {
+        alpha = 0.0;
+    }
+
+    // Image orbit traps also color the interior, so they skip the black early-out
+    if (uniforms.color_mode == 16u) {
+        return vec4<f32>(orbit_trap_image_2d(coord, t), alpha);
+    }
+
+    if (t == 0.0) {
         // No post-processing - render raw fractal (post-FX done in multi-pass pipeline)
-        return vec4<f32>(color, 1.0);
+        return vec4<f32>(interior_color_2d(coord), alpha);
+    }
+
+    var color: vec3<f32>;
+    if (uniforms.color_mode == 1u) {
+        // Iteration visualization (similar to ray steps)
+        color = vec3<f32>(t, t * 0.5, 1.0 - t);
+    } else if (uniforms.color_mode == 2u) {
+        // Grayscale iteration count
+        color = vec3<f32>(t);
+    } else if (uniforms.color_mode == 3u) {
+        // Orbit Trap XYZ - color based on coordinate components using palette
+        let trap_coord = coord - uniforms.orbit_trap_offset.xy;
+        let xy_sum = abs(fract(trap_coord.x * uniforms.orbit_trap_scale * 2.0)) + abs(fract(trap_coord.y * uniforms.orbit_trap_scale * 2.0));
+        let trap_t = fract(xy_sum / 2.0);
+        color = get_palette_color(trap_t);
+    } else if (uniforms.color_mode == 4u) {
+        // Orbit Trap Radial - color based on distance from the trap point using palette
+        let dist = length(coord - uniforms.orbit_trap_offset.xy);
+        let radial_t = fract(dist * uniforms.orbit_trap_scale * 3.0);
+        color = get_palette_color(radial_t);
+    } else if (uniforms.color_mode == 5u || uniforms.color_mode == 6u) {
+        // Position-based coloring for 2D
+        color = vec3<f32>(abs(fract(coord.x)), abs(fract(coord.y)), abs(fract(coord.x + coord.y)));
+    } else {
+        // Palette mode (default)
+        color = get_palette_color(t);
+    }
+
+    color = color * distance_shade_2d(coord);
+
+    // No post-processing - render raw fractal (post-FX done in multi-pass pipeline)
+    return vec4<f32>(color, alpha);
+}
 
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    if (uniforms.render_mode == 0u) {
+        // 2D Mode
+        return shade_2d(sample_2d(input.uv));
     } else {
         // 3D Mode
-        // UV coordinates are already in NDC space (-1 to 1)
-        let ndc_x = input.uv.x;
-        let ndc_y = input.uv.y;
-
-        // Unproject a point on the far plane to get initial ray direction
-        let far_point = vec4<f32>(ndc_x, ndc_y, 1.0, 1.0);
-        var far_world = uniforms.inv_view_proj * far_point;
-        far_world = far_world / far_world.w;
-
-        // Initial ray from camera
-        let base_ray_origin = uniforms.camera_pos;
-        let base_ray_dir = normalize(far_world.xyz - base_ray_origin);
-
-        var final_color: vec3<f32>;
-
-        // Apply depth of field if enabled with multi-sampling
-        if (uniforms.depth_of_field == 1u) {
-            // Multi-sample DOF - configurable quality vs performance
-            let num_samples = uniforms.dof_samples;
-            var accumulated_color = vec3<f32>(0.0);
-
-            // Calculate focal point
-            let focal_point = base_ray_origin + base_ray_dir * uniforms.dof_focal_length;
-
-            // Calculate camera right and up vectors
-            let camera_forward = base_ray_dir;
-            let world_up = vec3<f32>(0.0, 1.0, 0.0);
-            let camera_right = normalize(cross(camera_forward, world_up));
-            let camera_up = cross(camera_right, camera_forward);
-
-            // Take multiple samples
-            for (var i = 0u; i < num_samples; i = i + 1u) {
-                // Sample aperture with indexed pattern
-                let aperture_sample = sample_disk_indexed(input.clip_position.xy, i, num_samples);
-                let aperture_offset = (camera_right * aperture_sample.x + camera_up * aperture_sample.y) * uniforms.dof_aperture;
-
-                // Offset ray origin and recalculate direction to focal point
-                let ray_origin = base_ray_origin + aperture_offset;
-                let ray_dir = normalize(focal_point - ray_origin);
-
-                // Render this ray and accumulate
-                accumulated_color = accumulated_color + render_ray(ray_origin, ray_dir, input.uv);
+        if (uniforms.stereo_mode == 1u) {
+            // Side-by-side stereo: each half of the screen is a full NDC viewport for one eye
+            if (input.uv.x < 0.0) {
+                let eye_ndc = vec2<f32>(input.uv.x * 2.0 + 1.0, input.uv.y);
+                let left = render_camera_view(eye_ndc, uniforms.inv_view_proj_left, uniforms.camera_pos_left, input.clip_position.xy, input.uv);
+                return vec4<f32>(left, surface_coverage());
             }
-
-            // Average the samples
-            final_color = accumulated_color / f32(num_samples);
-        } else {
-            // No DOF - single sample
-            final_color = render_ray(base_ray_origin, base_ray_dir, input.uv);
+            let eye_ndc = vec2<f32>(input.uv.x * 2.0 - 1.0, input.uv.y);
+            let right = render_camera_view(eye_ndc, uniforms.inv_view_proj_right, uniforms.camera_pos_right, input.clip_position.xy, input.uv);
+            return vec4<f32>(right, surface_coverage());
+        } else if (uniforms.stereo_mode == 2u) {
+            // Red/cyan anaglyph: render both eyes and mix channels
+            let left = render_camera_view(input.uv, uniforms.inv_view_proj_left, uniforms.camera_pos_left, input.clip_position.xy, input.uv);
+            let right = render_camera_view(input.uv, uniforms.inv_view_proj_right, uniforms.camera_pos_right, input.clip_position.xy, input.uv);
+            // Half-color anaglyph: left eye luminance in red reduces retinal rivalry
+            let left_luma = dot(left, vec3<f32>(0.299, 0.587, 0.114));
+            return vec4<f32>(left_luma, right.g, right.b, surface_coverage());
         }
 
         // No post-processing - render raw fractal (post-FX done in multi-pass pipeline)
-        return vec4<f32>(final_color, 1.0);
+        let color = render_camera_view(input.uv, uniforms.inv_view_proj, uniforms.camera_pos, input.clip_position.xy, input.uv);
+        return vec4<f32>(color, surface_coverage());
     }
 }
+
+// Render one camera view: generate the primary ray for an NDC position and apply depth of field
+fn render_camera_view(ndc: vec2<f32>, inv_view_proj: mat4x4<f32>, camera_pos: vec3<f32>, frag_coord: vec2<f32>, uv: vec2<f32>) -> vec3<f32> {
+    // Unproject a point on the far plane to get initial ray direction
+    let far_point = vec4<f32>(ndc.x, ndc.y, 1.0, 1.0);
+    var far_world = inv_view_proj * far_point;
+    far_world = far_world / far_world.w;
+
+    // Initial ray from camera
+    let base_ray_origin = camera_pos;
+    let base_ray_dir = normalize(far_world.xyz - base_ray_origin);
+
+    // Apply depth of field if enabled with multi-sampling
+    if (uniforms.depth_of_field == 1u) {
+        // Multi-sample DOF - configurable quality vs performance
+        let num_samples = uniforms.dof_samples;
+        var accumulated_color = vec3<f32>(0.0);
+
+        // Calculate focal point
+        let focal_point = base_ray_origin + base_ray_dir * uniforms.dof_focal_length;
+
+        // Calculate camera right and up vectors
+        let camera_forward = base_ray_dir;
+        let camera_right = normalize(cross(camera_forward, world_up()));
+        let camera_up = cross(camera_right, camera_forward);
+
+        // Take multiple samples
+        for (var i = 0u; i < num_samples; i = i + 1u) {
+            // Sample aperture with indexed pattern
+            var aperture_sample = sample_disk_indexed(frag_coord, i, num_samples);
+            let sample_angle = atan2(aperture_sample.y, aperture_sample.x);
+            aperture_sample = aperture_sample * aperture_radius(sample_angle, uniforms.bokeh_blades);
+            let aperture_offset = (camera_right * aperture_sample.x + camera_up * aperture_sample.y) * uniforms.dof_aperture;
+
+            // Offset ray origin and recalculate direction to focal point
+            let ray_origin = base_ray_origin + aperture_offset;
+            let ray_dir = normalize(focal_point - ray_origin);
+
+            // Render this ray and accumulate
+            accumulated_color = accumulated_color + render_ray(ray_origin, ray_dir, uv);
+        }
+
+        // Average the samples
+        return accumulated_color / f32(num_samples);
+    }
+
+    // No DOF - single sample
+    return render_ray(base_ray_origin, base_ray_dir, uv);
+}
@@ -0,0 +1,144 @@
+// ============================================================================
+// Double-Precision Ray Marching
+// ============================================================================
+// Appended to fractal.wgsl when the adapter supports SHADER_F64. The march
+// position and distance are accumulated in f64 so deep close-ups keep their
+// detail. Most backends lack f64 transcendentals, so only arithmetic is used:
+// the Mandelbulb is iterated with integer powers via complex multiplication
+// and other fractal types evaluate their f32 DE at the f64 position.
+
+// Square root refined from an f32 estimate with Newton iterations
+fn sqrt_f64(x: f64) -> f64 {
+    if (x <= 0.0lf) {
+        return 0.0lf;
+    }
+    var s = f64(sqrt(f32(x)));
+    s = 0.5lf * (s + x / s);
+    s = 0.5lf * (s + x / s);
+    return s;
+}
+
+// Raise a complex number to an integer power
+fn complex_pow_f64(c: vec2<f64>, n: u32) -> vec2<f64> {
+    var result = vec2<f64>(1.0lf, 0.0lf);
+    for (var i = 0u; i < n; i = i + 1u) {
+        result = vec2<f64>(
+            result.x * c.x - result.y * c.y,
+            result.x * c.y + result.y * c.x
+        );
+    }
+    return result;
+}
+
+fn mandelbulb_de_f64(pos: vec3<f64>) -> f32 {
+    let scale_inv = 1.0lf / f64(uniforms.fractal_scale);
+    let c = pos * scale_inv;
+    var z = c;
+    var dr = 1.0lf;
+    var r = 0.0lf;
+    let power = u32(clamp(round(uniforms.power), 2.0, 16.0));
+
+    for (var i = 0u; i < 16u; i = i + 1u) {
+        r = sqrt_f64(dot(z, z));
+        if (r > 2.0lf) {
+            break;
+        }
+
+        // r^(power - 1) for the running derivative
+        var r_pow = 1.0lf;
+        for (var j = 1u; j < power; j = j + 1u) {
+            r_pow = r_pow * r;
+        }
+        dr = r_pow * f64(power) * dr + 1.0lf;
+
+        // (z + i*rho)^n = r^n * (cos(n*theta) + i*sin(n*theta))
+        let rho = sqrt_f64(z.x * z.x + z.y * z.y);
+        let theta_n = complex_pow_f64(vec2<f64>(z.z, rho), power);
+
+        if (rho > 0.0lf) {
+            // (x + i*y)^n / rho^n = cos(n*phi) + i*sin(n*phi)
+            let phi_n = complex_pow_f64(vec2<f64>(z.x, z.y) / rho, power);
+            z = vec3<f64>(theta_n.y * phi_n.x, theta_n.y * phi_n.y, theta_n.x);
+        } else {
+            z = vec3<f64>(0.0lf, 0.0lf, theta_n.x);
+        }
+        z = z + c;
+    }
+
+    let rf = f32(r);
+    return 0.5 * log(rf) * rf / f32(dr * scale_inv);
+}
+
+fn scene_de_with_material_f64(pos: vec3<f64>) -> SceneResult {
+    // The f64 Mandelbulb only handles integer powers; fractional powers keep
+    // the f32 estimator rather than silently rounding the shape
+    if (uniforms.fractal_type != 13u || fract(uniforms.power) != 0.0) {
+        return scene_de_with_material(vec3<f32>(pos));
+    }
+
+    var result: SceneResult;
+    result.distance = mandelbulb_de_f64(pos);
+    result.material_id = 0u;
+
+    // Union with the secondary fractal, which stays in f32
+    if (uniforms.secondary_fractal_type != 0u) {
+        let secondary_dist = secondary_fractal_de(vec3<f32>(pos));
+        if (secondary_dist < result.distance) {
+            result.distance = secondary_dist;
+            result.material_id = 2u;
+        }
+    }
+
+    if (uniforms.show_floor == 1u) {
+        let floor_dist = floor_de(vec3<f32>(pos));
+        if (floor_dist < result.distance) {
+            result.distance = floor_dist;
+            result.material_id = 1u;
+        }
+    }
+
+    return result;
+}
+
+fn ray_march_f64(origin: vec3<f32>, direction: vec3<f32>) -> RayMarchResult {
+    var result: RayMarchResult;
+    result.hit = false;
+    result.distance = 0.0;
+    result.steps = 0u;
+    result.material_id = 0u;
+
+    let origin_f64 = vec3<f64>(origin);
+    let direction_f64 = vec3<f64>(direction);
+    var total_distance = f64(ray_march_start_distance(origin, direction));
+
+    for (var i = 0u; i < uniforms.max_steps; i = i + 1u) {
+        result.steps = i;
+        let pos = origin_f64 + direction_f64 * total_distance;
+        let scene_result = scene_de_with_material_f64(pos);
+        let dist = scene_result.distance;
+
+        var step_size: f64;
+        if (uniforms.use_adaptive_step == 1u) {
+            step_size = f64(dist * uniforms.step_multiplier);
+        } else {
+            step_size = f64(uniforms.fixed_step_size);
+        }
+
+        let epsilon = hit_epsilon(f32(total_distance));
+        total_distance = total_distance + step_size;
+
+        if (dist < epsilon) {
+            result.hit = true;
+            result.distance = f32(total_distance);
+            result.position = vec3<f32>(pos);
+            result.material_id = scene_result.material_id;
+            break;
+        }
+
+        if (total_distance > f64(uniforms.max_distance)) {
+            break;
+        }
+    }
+
+    return result;
+}
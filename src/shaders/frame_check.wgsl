@@ -0,0 +1,75 @@
+// Blank Frame Check Shader
+//
+// A single 16x16 workgroup samples the scene texture on a 32x32 grid and counts
+// the samples that are NaN/infinite and the ones that show anything at all. The
+// app uses the fractions to notice when a parameter change leaves the screen
+// entirely black or invalid.
+
+const SAMPLE_GRID: u32 = 32u;
+const SAMPLES_PER_THREAD: u32 = 2u; // SAMPLE_GRID / workgroup size, per axis
+
+// Any channel above this counts as visible (about 1/255)
+const VISIBLE_THRESHOLD: f32 = 0.004;
+
+struct FrameCheckResult {
+    // Fraction of samples with a NaN or infinite channel
+    invalid_fraction: f32,
+    // Fraction of finite samples brighter than the threshold
+    visible_fraction: f32,
+    _padding: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var t_scene: texture_2d<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> result: FrameCheckResult;
+
+var<workgroup> invalid_count: atomic<u32>;
+var<workgroup> visible_count: atomic<u32>;
+
+// NaN and infinity have every exponent bit set; comparisons like x != x can be
+// optimized away, so test the bits
+fn is_invalid(color: vec3<f32>) -> bool {
+    let exponent = vec3<u32>(0x7f800000u);
+    return any((bitcast<vec3<u32>>(color) & exponent) == exponent);
+}
+
+@compute @workgroup_size(16, 16)
+fn main(
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32,
+) {
+    if (local_index == 0u) {
+        atomicStore(&invalid_count, 0u);
+        atomicStore(&visible_count, 0u);
+    }
+    workgroupBarrier();
+
+    let size = vec2<f32>(textureDimensions(t_scene));
+    for (var j = 0u; j < SAMPLES_PER_THREAD; j = j + 1u) {
+        for (var i = 0u; i < SAMPLES_PER_THREAD; i = i + 1u) {
+            let grid = vec2<f32>(
+                f32(local_id.x * SAMPLES_PER_THREAD + i),
+                f32(local_id.y * SAMPLES_PER_THREAD + j)
+            );
+            let coord = vec2<i32>((grid + 0.5) / f32(SAMPLE_GRID) * size);
+            let color = textureLoad(t_scene, coord, 0).rgb;
+            if (is_invalid(color)) {
+                atomicAdd(&invalid_count, 1u);
+            } else if (max(max(color.r, color.g), color.b) > VISIBLE_THRESHOLD) {
+                atomicAdd(&visible_count, 1u);
+            }
+        }
+    }
+    workgroupBarrier();
+
+    if (local_index != 0u) {
+        return;
+    }
+
+    let total = f32(SAMPLE_GRID * SAMPLE_GRID);
+    result.invalid_fraction = f32(atomicLoad(&invalid_count)) / total;
+    result.visible_fraction = f32(atomicLoad(&visible_count)) / total;
+    result._padding = vec2<f32>(0.0);
+}
@@ -0,0 +1,95 @@
+// Luminance Histogram Shader (3D auto-exposure)
+//
+// A single 16x16 workgroup samples the scene texture on a 64x64 grid and bins
+// each sample's log2 luminance into a shared histogram. The average log2
+// luminance is then taken over the bins between two percentiles, so small bright
+// highlights (specular hot spots, glowing edges) and deep shadows don't pull the
+// exposure around. Near-black pixels are treated as background and ignored.
+
+const BIN_COUNT: u32 = 64u;
+const MIN_LOG2: f32 = -10.0;
+const MAX_LOG2: f32 = 4.0;
+const SAMPLE_GRID: u32 = 64u;
+const SAMPLES_PER_THREAD: u32 = 4u; // SAMPLE_GRID / workgroup size, per axis
+
+// Fraction of the (non-background) samples excluded at each end
+const LOW_PERCENTILE: f32 = 0.4;
+const HIGH_PERCENTILE: f32 = 0.95;
+
+struct ExposureResult {
+    // Average log2 luminance of the measured range
+    log2_luminance: f32,
+    // Fraction of samples that were not background (0 = nothing to measure)
+    coverage: f32,
+    _padding: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var t_scene: texture_2d<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> result: ExposureResult;
+
+var<workgroup> histogram: array<atomic<u32>, BIN_COUNT>;
+
+fn bin_center_log2(bin: u32) -> f32 {
+    return MIN_LOG2 + (f32(bin) + 0.5) / f32(BIN_COUNT) * (MAX_LOG2 - MIN_LOG2);
+}
+
+@compute @workgroup_size(16, 16)
+fn main(
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32,
+) {
+    if (local_index < BIN_COUNT) {
+        atomicStore(&histogram[local_index], 0u);
+    }
+    workgroupBarrier();
+
+    let size = vec2<f32>(textureDimensions(t_scene));
+    for (var j = 0u; j < SAMPLES_PER_THREAD; j = j + 1u) {
+        for (var i = 0u; i < SAMPLES_PER_THREAD; i = i + 1u) {
+            let grid = vec2<f32>(
+                f32(local_id.x * SAMPLES_PER_THREAD + i),
+                f32(local_id.y * SAMPLES_PER_THREAD + j)
+            );
+            let coord = vec2<i32>((grid + 0.5) / f32(SAMPLE_GRID) * size);
+            let color = textureLoad(t_scene, coord, 0).rgb;
+            let luminance = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+            let log_lum = log2(max(luminance, 1e-6));
+            if (log_lum > MIN_LOG2) {
+                let scaled = (log_lum - MIN_LOG2) / (MAX_LOG2 - MIN_LOG2) * f32(BIN_COUNT);
+                let bin = u32(clamp(scaled, 0.0, f32(BIN_COUNT - 1u)));
+                atomicAdd(&histogram[bin], 1u);
+            }
+        }
+    }
+    workgroupBarrier();
+
+    if (local_index != 0u) {
+        return;
+    }
+
+    var total = 0.0;
+    for (var bin = 0u; bin < BIN_COUNT; bin = bin + 1u) {
+        total = total + f32(atomicLoad(&histogram[bin]));
+    }
+
+    // Average over the part of each bin that falls inside the percentile window
+    let low = total * LOW_PERCENTILE;
+    let high = total * HIGH_PERCENTILE;
+    var cumulative = 0.0;
+    var sum = 0.0;
+    var weight = 0.0;
+    for (var bin = 0u; bin < BIN_COUNT; bin = bin + 1u) {
+        let count = f32(atomicLoad(&histogram[bin]));
+        let inside = max(min(cumulative + count, high) - max(cumulative, low), 0.0);
+        sum = sum + inside * bin_center_log2(bin);
+        weight = weight + inside;
+        cumulative = cumulative + count;
+    }
+
+    result.log2_luminance = select(0.0, sum / weight, weight > 0.0);
+    result.coverage = total / f32(SAMPLE_GRID * SAMPLE_GRID);
+    result._padding = vec2<f32>(0.0);
+}
@@ -1,5 +1,5 @@
 // Post-processing shaders for multi-pass rendering
-// Handles: bloom extraction, Gaussian blur, compositing, FXAA
+// Handles: bloom extraction, mip-chain bloom, compositing, FXAA
 
 struct VertexInput {
     @location(0) position: vec2<f32>,
@@ -32,7 +32,10 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 struct BloomUniforms {
     threshold: f32,
     intensity: f32,
-    _padding: vec2<f32>,
+    // Upsample filter radius in UV units
+    radius: f32,
+    // 1 / number of mip levels, to normalize the combined chain
+    level_weight: f32,
 }
 
 @group(1) @binding(0)
@@ -54,48 +57,68 @@ fn fs_bloom_extract(input: VertexOutput) -> @location(0) vec4<f32> {
 }
 
 // ============================================================================
-// Gaussian Blur Pass - Separable blur (horizontal or vertical)
+// Bloom Mip Chain - Downsample the bright pass, then upsample and combine
 // ============================================================================
 
-struct BlurUniforms {
-    direction: vec2<f32>,  // (1,0) for horizontal, (0,1) for vertical
-    _padding: vec2<f32>,
+// 13-tap downsample (source is twice the target size). Overlapping 4x4 box
+// filters weighted toward the center, which avoids the flicker and blockiness
+// of a plain 2x2 average.
+@fragment
+fn fs_bloom_downsample(input: VertexOutput) -> @location(0) vec4<f32> {
+    let texel = 1.0 / vec2<f32>(textureDimensions(t_scene));
+    let uv = input.tex_coords;
+
+    let a = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(-2.0, 2.0)).rgb;
+    let b = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(0.0, 2.0)).rgb;
+    let c = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(2.0, 2.0)).rgb;
+    let d = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(-2.0, 0.0)).rgb;
+    let e = textureSample(t_scene, s_scene, uv).rgb;
+    let f = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(2.0, 0.0)).rgb;
+    let g = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(-2.0, -2.0)).rgb;
+    let h = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(0.0, -2.0)).rgb;
+    let i = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(2.0, -2.0)).rgb;
+    let j = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(-1.0, 1.0)).rgb;
+    let k = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(1.0, 1.0)).rgb;
+    let l = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(-1.0, -1.0)).rgb;
+    let m = textureSample(t_scene, s_scene, uv + texel * vec2<f32>(1.0, -1.0)).rgb;
+
+    var result = e * 0.125;
+    result += (a + c + g + i) * 0.03125;
+    result += (b + d + f + h) * 0.0625;
+    result += (j + k + l + m) * 0.125;
+    return vec4<f32>(result, 1.0);
 }
 
-@group(1) @binding(0)
-var<uniform> blur_params: BlurUniforms;
-
-// 9-tap Gaussian blur weights
-const BLUR_WEIGHTS = array<f32, 5>(
-    0.227027, 0.1945946, 0.1216216, 0.054054, 0.016216
-);
+// 3x3 tent filter with a radius in UV units (aspect corrected), so the glow
+// widens at every smaller mip level
+fn bloom_tent(uv: vec2<f32>) -> vec3<f32> {
+    let size = vec2<f32>(textureDimensions(t_scene));
+    let r = bloom_params.radius * vec2<f32>(size.y / size.x, 1.0);
+
+    let a = textureSample(t_scene, s_scene, uv + vec2<f32>(-r.x, r.y)).rgb;
+    let b = textureSample(t_scene, s_scene, uv + vec2<f32>(0.0, r.y)).rgb;
+    let c = textureSample(t_scene, s_scene, uv + vec2<f32>(r.x, r.y)).rgb;
+    let d = textureSample(t_scene, s_scene, uv + vec2<f32>(-r.x, 0.0)).rgb;
+    let e = textureSample(t_scene, s_scene, uv).rgb;
+    let f = textureSample(t_scene, s_scene, uv + vec2<f32>(r.x, 0.0)).rgb;
+    let g = textureSample(t_scene, s_scene, uv + vec2<f32>(-r.x, -r.y)).rgb;
+    let h = textureSample(t_scene, s_scene, uv + vec2<f32>(0.0, -r.y)).rgb;
+    let i = textureSample(t_scene, s_scene, uv + vec2<f32>(r.x, -r.y)).rgb;
+
+    return (e * 4.0 + (b + d + f + h) * 2.0 + (a + c + g + i)) / 16.0;
+}
 
+// Upsample a level and add it to the next larger one (additive blending)
 @fragment
-fn fs_blur(input: VertexOutput) -> @location(0) vec4<f32> {
-    let tex_size = vec2<f32>(textureDimensions(t_scene));
-    let tex_offset = 1.0 / tex_size;
-
-    var result = textureSample(t_scene, s_scene, input.tex_coords).rgb * BLUR_WEIGHTS[0];
-
-    // Manually unroll the loop to avoid dynamic array indexing
-    // Use 2x spread for wider bloom
-    let offset1 = blur_params.direction * tex_offset * 2.0;
-    result += textureSample(t_scene, s_scene, input.tex_coords + offset1).rgb * BLUR_WEIGHTS[1];
-    result += textureSample(t_scene, s_scene, input.tex_coords - offset1).rgb * BLUR_WEIGHTS[1];
-
-    let offset2 = blur_params.direction * tex_offset * 4.0;
-    result += textureSample(t_scene, s_scene, input.tex_coords + offset2).rgb * BLUR_WEIGHTS[2];
-    result += textureSample(t_scene, s_scene, input.tex_coords - offset2).rgb * BLUR_WEIGHTS[2];
-
-    let offset3 = blur_params.direction * tex_offset * 6.0;
-    result += textureSample(t_scene, s_scene, input.tex_coords + offset3).rgb * BLUR_WEIGHTS[3];
-    result += textureSample(t_scene, s_scene, input.tex_coords - offset3).rgb * BLUR_WEIGHTS[3];
-
-    let offset4 = blur_params.direction * tex_offset * 8.0;
-    result += textureSample(t_scene, s_scene, input.tex_coords + offset4).rgb * BLUR_WEIGHTS[4];
-    result += textureSample(t_scene, s_scene, input.tex_coords - offset4).rgb * BLUR_WEIGHTS[4];
+fn fs_bloom_upsample(input: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(bloom_tent(input.tex_coords), 1.0);
+}
 
-    return vec4<f32>(result, 1.0);
+// Upsample the combined chain to full resolution, averaging the levels so the
+// glow strength doesn't depend on the quality setting
+@fragment
+fn fs_bloom_resolve(input: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(bloom_tent(input.tex_coords) * bloom_params.level_weight, 1.0);
 }
 
 // ============================================================================
@@ -125,7 +148,20 @@ struct PostProcessUniforms {
     bloom_intensity: f32,       // offset 36
     _padding2: vec2<f32>,       // offset 40
 
-    _padding3: vec4<f32>,       // offset 48
+    // Symmetry (0 = off, 1 = mirror X, 2 = mirror Y, 3 = quad, 4 = radial)
+    symmetry_mode: u32,         // offset 48
+    symmetry_segments: u32,     // offset 52
+    _padding3: vec2<f32>,       // offset 56
+
+    // Porthole mask
+    porthole_color: vec3<f32>,  // offset 64
+    porthole_enabled: u32,      // offset 76
+    porthole_radius: f32,       // offset 80
+    porthole_feather: f32,      // offset 84
+    porthole_oval: u32,         // offset 88
+
+    // Color vision deficiency simulation (0 = off, 1 = protanopia, 2 = deuteranopia, 3 = tritanopia)
+    cvd_simulation: u32,        // offset 92
 }
 
 @group(1) @binding(0)
@@ -160,6 +196,33 @@ fn rgb_to_hsv(rgb: vec3<f32>) -> vec3<f32> {
     return vec3<f32>(h, s, cmax);
 }
 
+// Color vision deficiency simulation with the full-severity matrices of
+// Machado, Oliveira & Fernandes (2009), applied to linear RGB
+fn simulate_cvd(color: vec3<f32>, mode: u32) -> vec3<f32> {
+    var m: mat3x3<f32>;
+    if (mode == 1u) {
+        m = mat3x3<f32>(
+            vec3<f32>(0.152286, 1.052583, -0.204868),
+            vec3<f32>(0.114503, 0.786281, 0.099216),
+            vec3<f32>(-0.003882, -0.048116, 1.051998),
+        );
+    } else if (mode == 2u) {
+        m = mat3x3<f32>(
+            vec3<f32>(0.367322, 0.860646, -0.227968),
+            vec3<f32>(0.280085, 0.672501, 0.047413),
+            vec3<f32>(-0.011820, 0.042940, 0.968881),
+        );
+    } else {
+        m = mat3x3<f32>(
+            vec3<f32>(1.255528, -0.076749, -0.178779),
+            vec3<f32>(-0.078411, 0.930809, 0.147602),
+            vec3<f32>(0.004733, 0.691367, 0.303900),
+        );
+    }
+    // The matrices are given row by row, and WGSL builds from columns
+    return max(color * m, vec3<f32>(0.0));
+}
+
 // HSV to RGB conversion
 fn hsv_to_rgb(hsv: vec3<f32>) -> vec3<f32> {
     let h = hsv.x * 6.0;
@@ -188,9 +251,42 @@ fn hsv_to_rgb(hsv: vec3<f32>) -> vec3<f32> {
     return rgb + m;
 }
 
+const PI: f32 = 3.14159265359;
+
+// Fold UVs so one part of the image is mirrored across the rest
+fn symmetry_uv(uv: vec2<f32>) -> vec2<f32> {
+    switch postfx.symmetry_mode {
+        case 1u: {
+            return vec2<f32>(0.5 - abs(uv.x - 0.5), uv.y);
+        }
+        case 2u: {
+            return vec2<f32>(uv.x, 0.5 - abs(uv.y - 0.5));
+        }
+        case 3u: {
+            return 0.5 - abs(uv - 0.5);
+        }
+        case 4u: {
+            // Measure the angle in pixels so wedges aren't stretched by the aspect ratio
+            let size = vec2<f32>(textureDimensions(t_scene));
+            let p = (uv - 0.5) * size;
+            let wedge = 2.0 * PI / f32(postfx.symmetry_segments);
+            // Angle from straight up, folded into the first half-wedge
+            var angle = atan2(p.x, -p.y);
+            angle = abs(angle - wedge * round(angle / wedge));
+            let folded = length(p) * vec2<f32>(sin(angle), -cos(angle));
+            return clamp(folded / size + 0.5, vec2<f32>(0.0), vec2<f32>(1.0));
+        }
+        default: {
+            return uv;
+        }
+    }
+}
+
 @fragment
 fn fs_composite(input: VertexOutput) -> @location(0) vec4<f32> {
-    var color = textureSample(t_scene, s_scene, input.tex_coords).rgb;
+    let uv = symmetry_uv(input.tex_coords);
+    let scene = textureSample(t_scene, s_scene, uv);
+    var color = scene.rgb;
 
     // Apply color grading FIRST (before bloom, so we don't clamp it)
     // Brightness
@@ -212,7 +308,7 @@ fn fs_composite(input: VertexOutput) -> @location(0) vec4<f32> {
 
     // Add bloom AFTER color grading and clamping (so bloom can exceed 1.0)
     if (postfx.bloom_enabled == 1u) {
-        let bloom = textureSample(t_bloom, s_bloom, input.tex_coords).rgb;
+        let bloom = textureSample(t_bloom, s_bloom, uv).rgb;
         color = color + bloom * postfx.bloom_intensity;
     }
 
@@ -225,7 +321,50 @@ fn fs_composite(input: VertexOutput) -> @location(0) vec4<f32> {
         color = color * factor;
     }
 
-    return vec4<f32>(color, 1.0);
+    // Keep the scene's alpha so transparent captures survive compositing
+    var alpha = scene.a;
+
+    // Porthole: clip to a feathered circle, or an oval filling the frame.
+    // Outside shows the porthole color, and is transparent in transparent captures.
+    if (postfx.porthole_enabled == 1u) {
+        var p = (input.tex_coords - 0.5) * 2.0;
+        if (postfx.porthole_oval == 0u) {
+            // Measure in pixels relative to the shorter side so the circle stays round
+            let size = vec2<f32>(textureDimensions(t_scene));
+            p = p * size / min(size.x, size.y);
+        }
+        let radius = postfx.porthole_radius;
+        // smoothstep needs edge0 < edge1, so a zero feather becomes a hard edge
+        let feather = max(postfx.porthole_feather, 1e-4);
+        let mask = 1.0 - smoothstep(radius - feather, radius, length(p));
+        color = mix(postfx.porthole_color, color, mask);
+        alpha = alpha * mask;
+    }
+
+    // Last, so the simulation shows the finished image
+    if (postfx.cvd_simulation != 0u) {
+        color = simulate_cvd(color, postfx.cvd_simulation);
+    }
+
+    return vec4<f32>(color, alpha);
+}
+
+// ============================================================================
+// Output Encoding - Final pass color encoding for the surface format
+// ============================================================================
+
+// Set for surfaces that expect sRGB-coded values but don't encode on write
+// (8/10-bit unorm), so the final pass applies the sRGB curve itself
+override ENCODE_SRGB: bool = false;
+
+fn encode_output(color: vec3<f32>) -> vec3<f32> {
+    if (!ENCODE_SRGB) {
+        return color;
+    }
+    let c = clamp(color, vec3<f32>(0.0), vec3<f32>(1.0));
+    let low = c * 12.92;
+    let high = 1.055 * pow(c, vec3<f32>(1.0 / 2.4)) - 0.055;
+    return select(high, low, c <= vec3<f32>(0.0031308));
 }
 
 // ============================================================================
@@ -279,9 +418,9 @@ fn fs_fxaa(input: VertexOutput) -> @location(0) vec4<f32> {
     let lumaB = dot(rgbB, luma);
 
     if ((lumaB < lumaMin) || (lumaB > lumaMax)) {
-        return vec4<f32>(rgbA, 1.0);
+        return vec4<f32>(encode_output(rgbA), 1.0);
     } else {
-        return vec4<f32>(rgbB, 1.0);
+        return vec4<f32>(encode_output(rgbB), 1.0);
     }
 }
 
@@ -291,7 +430,8 @@ fn fs_fxaa(input: VertexOutput) -> @location(0) vec4<f32> {
 
 @fragment
 fn fs_copy(input: VertexOutput) -> @location(0) vec4<f32> {
-    return textureSample(t_scene, s_scene, input.tex_coords);
+    let color = textureSample(t_scene, s_scene, input.tex_coords);
+    return vec4<f32>(encode_output(color.rgb), color.a);
 }
 
 // ============================================================================
@@ -302,14 +442,23 @@ struct AccumulationDisplayUniforms {
     log_scale: f32,
     gamma: f32,
     palette_offset: f32,
-    _padding: f32,
+    // 0 = density, 1 = iteration index, 2 = velocity
+    color_mode: u32,
     palette: array<vec4<f32>, 8>,
 }
 
-// This shader uses a separate bind group with only the uint accumulation texture
+// Fixed-point scale of one hit's attribute (must match attractor_compute.wgsl)
+const ACCUM_ATTRIBUTE_SCALE: f32 = 255.0;
+const ACCUM_ATTRIBUTE_MAX_HITS: f32 = 16777216.0;
+
+// This shader uses a separate bind group with only the uint accumulation textures
 @group(0) @binding(0)
 var t_accum: texture_2d<u32>;
 
+// Per-pixel attribute sum for the non-density color modes
+@group(0) @binding(1)
+var t_accum_attribute: texture_2d<u32>;
+
 @group(1) @binding(0)
 var<uniform> accum_uniforms: AccumulationDisplayUniforms;
 
@@ -365,8 +514,15 @@ fn fs_accumulation_display(input: VertexOutput) -> @location(0) vec4<f32> {
     // Apply gamma correction for fine-tuning contrast
     let adjusted = pow(clamp(normalized, 0.0, 1.0), accum_uniforms.gamma);
 
-    // Sample from the user-selected palette
-    let color = sample_accum_palette(adjusted);
+    if (accum_uniforms.color_mode == 0u) {
+        // Sample from the user-selected palette
+        let color = sample_accum_palette(adjusted);
+        return vec4<f32>(color, 1.0);
+    }
 
-    return vec4<f32>(color, 1.0);
+    // Palette position from the mean attribute, brightness from the density
+    let attribute_sum = f32(textureLoad(t_accum_attribute, coord, 0).r);
+    let mean = attribute_sum / (min(hit_count, ACCUM_ATTRIBUTE_MAX_HITS) * ACCUM_ATTRIBUTE_SCALE);
+    let color = sample_accum_palette(clamp(mean, 0.0, 1.0));
+    return vec4<f32>(color * adjusted, 1.0);
 }
@@ -0,0 +1,225 @@
+//! Accumulation controls shared by the density fractals
+//!
+//! 2D strange attractors, the Buddhabrot and the 3D strange attractors all
+//! accumulate hit counts over many frames, so they share the same settings for
+//! iteration rate, density scaling and pausing or clearing the accumulation.
+
+use crate::fractal::{AttractorColorMode, FractalParams, FractalType};
+
+/// Format a count with thousands separators
+fn format_with_commas(n: u64) -> String {
+    let s = n.to_string();
+    let mut result = String::new();
+    for (i, c) in s.chars().rev().enumerate() {
+        if i > 0 && i % 3 == 0 {
+            result.push(',');
+        }
+        result.push(c);
+    }
+    result.chars().rev().collect()
+}
+
+/// Show the accumulation settings. Returns true if a setting changed.
+pub(super) fn accumulation_settings(ui: &mut egui::Ui, params: &mut FractalParams) -> bool {
+    let mut changed = false;
+
+    ui.separator();
+    ui.label("🎯 Accumulation Settings");
+
+    changed |= ui
+        .add(
+            egui::Slider::new(&mut params.attractor_iterations_per_frame, 1_000..=500_000)
+                .text("Iterations/Frame")
+                .logarithmic(true),
+        )
+        .on_hover_text(
+            "Number of orbit iterations per frame\nLower = better FPS, Higher = faster accumulation",
+        )
+        .changed();
+
+    changed |= ui
+        .add(egui::Slider::new(&mut params.attractor_log_scale, 0.5..=6.0).text("Density Scale"))
+        .on_hover_text("Controls saturation point (hits needed for white)\n0.5 = ~30 hits, 1.0 = ~100, 2.0 = ~1000, 3.0 = ~10k, 4.0 = ~100k")
+        .changed();
+
+    if params.fractal_type.is_2d_attractor() {
+        ui.horizontal(|ui| {
+            ui.label("Color by:");
+            egui::ComboBox::from_id_salt("attractor_color_mode")
+                .selected_text(params.attractor_color_mode.name())
+                .show_ui(ui, |ui| {
+                    for &mode in AttractorColorMode::ALL {
+                        changed |= ui
+                            .selectable_value(&mut params.attractor_color_mode, mode, mode.name())
+                            .changed();
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "Density: palette by hit count\nIteration Index: palette by position along the orbit, showing its path\nVelocity: palette by how far the orbit jumped\nBrightness follows density in every mode",
+                );
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        changed |= ui
+            .checkbox(
+                &mut params.attractor_hold_while_recording,
+                "Hold While Recording",
+            )
+            .on_hover_text(
+                "Keep accumulating when the view or parameters change during video recording,\nso a panning, zooming or evolving attractor can build up",
+            )
+            .changed();
+        if params.attractor_hold_while_recording {
+            ui.horizontal(|ui| {
+                ui.label("Re-clear Every:");
+                changed |= ui
+                    .add(
+                        egui::DragValue::new(&mut params.attractor_recording_clear_interval)
+                            .range(0.0..=60.0)
+                            .speed(0.1)
+                            .suffix(" s"),
+                    )
+                    .on_hover_text(
+                        "Seconds of recorded video between clears while the view keeps changing\n0 = never clear while recording",
+                    )
+                    .changed();
+            });
+        }
+    }
+
+    ui.label(format!(
+        "Total: {} / {}",
+        format_with_commas(params.attractor_total_iterations),
+        format_with_commas(params.attractor_max_iterations)
+    ));
+
+    ui.horizontal(|ui| {
+        ui.label("Max:");
+        let mut max_millions = (params.attractor_max_iterations / 1_000_000) as u32;
+        if ui
+            .add(
+                egui::DragValue::new(&mut max_millions)
+                    .range(1..=100)
+                    .suffix("M"),
+            )
+            .on_hover_text("Maximum iterations before auto-pause (in millions)")
+            .changed()
+        {
+            params.attractor_max_iterations = max_millions as u64 * 1_000_000;
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let pause_text = if params.attractor_paused {
+            "▶ Resume"
+        } else {
+            "⏸ Pause"
+        };
+        if ui
+            .button(pause_text)
+            .on_hover_text("Pause/resume accumulation")
+            .clicked()
+        {
+            params.attractor_paused = !params.attractor_paused;
+        }
+        if ui
+            .button("Clear")
+            .on_hover_text("Reset accumulated density")
+            .clicked()
+        {
+            params.attractor_pending_clear = true;
+            params.attractor_total_iterations = 0;
+            changed = true;
+        }
+    });
+
+    changed
+}
+
+/// Show the 3D strange attractor parameters and accumulation settings.
+/// Returns true if a setting changed.
+pub(super) fn attractor_3d_controls(ui: &mut egui::Ui, params: &mut FractalParams) -> bool {
+    // Ensure accumulation is always enabled for these fractal types
+    params.attractor_accumulation_enabled = true;
+    let mut changed = false;
+
+    ui.label("Fractal Shape:")
+        .on_hover_text("Control the size of the point cloud");
+    changed |= ui
+        .add(
+            egui::Slider::new(&mut params.fractal_scale, 0.01..=1.0)
+                .text("Scale")
+                .logarithmic(true),
+        )
+        .on_hover_text("World units per attractor unit")
+        .changed();
+
+    ui.separator();
+    ui.label("🔧 Attractor Parameters");
+    match params.fractal_type {
+        FractalType::Lorenz3D => {
+            changed |= ui
+                .add(egui::Slider::new(&mut params.julia_c[0], 0.0..=30.0).text("σ (sigma)"))
+                .on_hover_text("Lorenz sigma (default: 10)")
+                .changed();
+            changed |= ui
+                .add(egui::Slider::new(&mut params.julia_c[1], 0.0..=100.0).text("ρ (rho)"))
+                .on_hover_text("Lorenz rho (default: 28)")
+                .changed();
+            changed |= ui
+                .add(egui::Slider::new(&mut params.power, 0.0..=10.0).text("β (beta)"))
+                .on_hover_text("Lorenz beta (default: 8/3)")
+                .changed();
+        }
+        FractalType::Rossler3D => {
+            changed |= ui
+                .add(egui::Slider::new(&mut params.julia_c[0], -1.0..=1.0).text("a"))
+                .on_hover_text("Rossler parameter a (default: 0.2)")
+                .changed();
+            changed |= ui
+                .add(egui::Slider::new(&mut params.julia_c[1], -1.0..=2.0).text("b"))
+                .on_hover_text("Rossler parameter b (default: 0.2)")
+                .changed();
+            changed |= ui
+                .add(egui::Slider::new(&mut params.power, 0.0..=20.0).text("c"))
+                .on_hover_text("Rossler parameter c (default: 5.7)")
+                .changed();
+        }
+        FractalType::Pickover3D => {
+            changed |= ui
+                .add(egui::Slider::new(&mut params.julia_c[0], -3.0..=3.0).text("a"))
+                .on_hover_text("Pickover parameter a (default: 2.24)")
+                .changed();
+            changed |= ui
+                .add(egui::Slider::new(&mut params.julia_c[1], -3.0..=3.0).text("b"))
+                .on_hover_text("Pickover parameter b (default: 0.43)")
+                .changed();
+            changed |= ui
+                .add(egui::Slider::new(&mut params.power, -3.0..=3.0).text("c"))
+                .on_hover_text("Pickover parameter c (default: -0.65)")
+                .changed();
+            changed |= ui
+                .add(egui::Slider::new(&mut params.fractal_fold, -3.0..=3.0).text("d"))
+                .on_hover_text("Pickover parameter d (default: -2.43)")
+                .changed();
+        }
+        _ => {}
+    }
+
+    changed |= accumulation_settings(ui, params);
+
+    // Reset to defaults button
+    if ui
+        .button("Reset Parameters")
+        .on_hover_text("Reset attractor parameters to defaults")
+        .clicked()
+    {
+        params.switch_fractal(params.fractal_type);
+        changed = true;
+    }
+
+    changed
+}
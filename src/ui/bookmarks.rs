@@ -0,0 +1,171 @@
+//! Camera bookmark controls
+//!
+//! Bookmarks save the camera position, target and field of view; for 2D
+//! fractals they also store the view center and zoom, which is what a 2D
+//! bookmark restores. A quick bookmark saves the current view at once under a
+//! name made from the fractal and its coordinates, so deep-zoom exploration
+//! doesn't stop to type names.
+
+use super::UI;
+use crate::fractal::{BookmarkGallery, CameraBookmark, FractalParams, RenderMode};
+use glam::Vec3;
+
+/// A bookmark of the current view: the camera, plus the 2D view for 2D fractals
+fn current_bookmark(
+    name: String,
+    params: &FractalParams,
+    camera_pos: Vec3,
+    camera_target: Vec3,
+) -> CameraBookmark {
+    let bookmark = CameraBookmark::new(
+        name,
+        camera_pos,
+        camera_target,
+        params.camera_fov,
+        params.up_axis,
+    );
+    match params.render_mode {
+        RenderMode::TwoD => bookmark.with_view_2d(params.center_2d, params.zoom_2d),
+        RenderMode::ThreeD => bookmark,
+    }
+}
+
+impl UI {
+    /// Save the current view as an auto-named bookmark
+    pub(super) fn save_quick_bookmark(
+        &mut self,
+        params: &FractalParams,
+        camera_pos: Vec3,
+        camera_target: Vec3,
+    ) {
+        let name = match params.render_mode {
+            RenderMode::TwoD => {
+                CameraBookmark::quick_name_2d(params.fractal_type, params.center_2d, params.zoom_2d)
+            }
+            RenderMode::ThreeD => CameraBookmark::quick_name_3d(params.fractal_type, camera_pos),
+        };
+        let bookmark = current_bookmark(name.clone(), params, camera_pos, camera_target);
+        match BookmarkGallery::save_bookmark(&bookmark, &name) {
+            Ok(()) => {
+                self.bookmarks = BookmarkGallery::list_bookmarks().unwrap_or_default();
+                self.show_toast(format!("📌 Bookmark saved: {}", name));
+            }
+            Err(e) => {
+                eprintln!("Failed to save bookmark: {}", e);
+                self.show_toast(format!("✗ Failed to save bookmark: {}", e));
+            }
+        }
+    }
+
+    /// Name field, save buttons and the list of saved bookmarks. Returns a
+    /// bookmark the user clicked to load.
+    pub(super) fn bookmark_controls(
+        &mut self,
+        ui: &mut egui::Ui,
+        params: &FractalParams,
+        camera_pos: Vec3,
+        camera_target: Vec3,
+    ) -> Option<CameraBookmark> {
+        let view = match params.render_mode {
+            RenderMode::TwoD => "view",
+            RenderMode::ThreeD => "camera position",
+        };
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            ui.text_edit_singleline(&mut self.bookmark_name);
+        });
+
+        let (save_clicked, quick_clicked) = ui
+            .horizontal(|ui| {
+                let save = ui
+                    .button("📌 Save Bookmark")
+                    .on_hover_text(format!("Save current {}", view))
+                    .clicked();
+                let quick = ui
+                    .button("⚡ Quick Bookmark")
+                    .on_hover_text(format!(
+                        "Save current {} at once, named from the fractal and its coordinates\n\
+                         e.g. Mandelbrot_-0.7435_0.1314_z1e6",
+                        view
+                    ))
+                    .clicked();
+                (save, quick)
+            })
+            .inner;
+        if quick_clicked {
+            self.save_quick_bookmark(params, camera_pos, camera_target);
+        }
+        if save_clicked && !self.bookmark_name.is_empty() {
+            let bookmark = current_bookmark(
+                self.bookmark_name.clone(),
+                params,
+                camera_pos,
+                camera_target,
+            );
+
+            // Sanitize filename
+            let filename = self
+                .bookmark_name
+                .replace(|c: char| !c.is_alphanumeric() && c != '_' && c != '-', "_");
+
+            if let Err(e) = BookmarkGallery::save_bookmark(&bookmark, &filename) {
+                eprintln!("Failed to save bookmark: {}", e);
+            } else {
+                // Refresh bookmark list
+                self.bookmarks = BookmarkGallery::list_bookmarks().unwrap_or_default();
+                self.bookmark_name.clear();
+            }
+        }
+
+        // Refresh bookmark list periodically
+        if self.last_bookmark_list_update.elapsed().as_secs() > 2 {
+            self.bookmarks = BookmarkGallery::list_bookmarks().unwrap_or_default();
+            self.last_bookmark_list_update = web_time::Instant::now();
+        }
+
+        let mut bookmark_to_load = None;
+        if !self.bookmarks.is_empty() {
+            ui.separator();
+            ui.label("Saved Bookmarks:")
+                .on_hover_text("Click to load, right-click to delete");
+
+            egui::ScrollArea::vertical()
+                .id_salt("bookmarks_scroll")
+                .max_height(120.0)
+                .show(ui, |ui| {
+                    let bookmarks_clone = self.bookmarks.clone();
+                    for bookmark_name in bookmarks_clone.iter() {
+                        ui.horizontal(|ui| {
+                            if ui
+                                .button(bookmark_name)
+                                .on_hover_text(format!("Click to restore this {}", view))
+                                .clicked()
+                            {
+                                if let Ok(bookmark) = BookmarkGallery::load_bookmark(bookmark_name)
+                                {
+                                    bookmark_to_load = Some(bookmark);
+                                }
+                            }
+                            if ui
+                                .small_button("🗑")
+                                .on_hover_text("Delete this bookmark")
+                                .clicked()
+                            {
+                                self.bookmark_to_delete = Some(bookmark_name.clone());
+                            }
+                        });
+                    }
+                });
+        }
+
+        // Handle bookmark deletion
+        if let Some(ref bookmark_name) = self.bookmark_to_delete {
+            if let Err(e) = BookmarkGallery::delete_bookmark(bookmark_name) {
+                eprintln!("Failed to delete bookmark: {}", e);
+            }
+            self.bookmarks = BookmarkGallery::list_bookmarks().unwrap_or_default();
+            self.bookmark_to_delete = None;
+        }
+        bookmark_to_load
+    }
+}
@@ -0,0 +1,87 @@
+//! Camera path export and import buttons
+
+use super::UI;
+use crate::camera_path::{CameraPath, CameraPathFormat};
+
+impl UI {
+    /// Buttons to save the camera path to a CSV or JSON file and to replace
+    /// it with one loaded from a file. Problems are reported as toasts.
+    pub(super) fn camera_path_file_buttons(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(self.camera_path.len() >= 2, egui::Button::new("💾 Export Path"))
+                .on_hover_text("Save the keyframes as CSV or JSON, to edit them elsewhere")
+                .clicked()
+            {
+                self.export_camera_path();
+            }
+            if ui
+                .button("📂 Import Path")
+                .on_hover_text(
+                    "Load keyframes from a CSV or JSON file, e.g. one generated by a script\n\
+                     CSV columns: position_x,position_y,position_z,target_x,target_y,target_z,fov,duration,easing",
+                )
+                .clicked()
+            {
+                self.import_camera_path();
+            }
+        });
+    }
+
+    fn export_camera_path(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .add_filter("JSON", &["json"])
+            .set_file_name("camera_path.csv")
+            .set_title("Export Camera Path")
+            .save_file()
+        else {
+            return;
+        };
+        let format = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .and_then(CameraPathFormat::from_extension)
+            .unwrap_or(CameraPathFormat::Csv);
+        match std::fs::write(&path, self.camera_path.export(format)) {
+            Ok(()) => self.show_toast(format!("🎥 Camera path exported to {}", path.display())),
+            Err(e) => {
+                log::error!("Failed to export camera path: {}", e);
+                self.show_toast(format!("✗ Camera path export failed: {}", e));
+            }
+        }
+    }
+
+    fn import_camera_path(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Camera path", &["csv", "json"])
+            .set_title("Import Camera Path")
+            .pick_file()
+        else {
+            return;
+        };
+        let format = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .and_then(CameraPathFormat::from_extension)
+            .unwrap_or(CameraPathFormat::Csv);
+        let result = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| CameraPath::import(&text, format));
+        match result {
+            Ok(camera_path) => {
+                self.show_toast(format!(
+                    "🎥 Imported camera path: {} keyframes, {:.1}s",
+                    camera_path.len(),
+                    camera_path.total_duration()
+                ));
+                self.camera_path = camera_path;
+                self.camera_path_time = 0.0;
+            }
+            Err(e) => {
+                log::error!("Failed to import camera path: {}", e);
+                self.show_toast(format!("✗ Camera path import failed: {}", e));
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Custom capture resolution helpers
+//!
+//! With the aspect ratio locked, typing one dimension of the custom resolution
+//! fills in the other so the pair keeps the chosen aspect ratio.
+
+/// Largest width or height a high-res capture can have
+pub const MAX_CAPTURE_DIMENSION: u32 = 16384;
+
+/// The dimension paired with `value` at `aspect` (width / height). Pass the
+/// aspect for a width and its reciprocal for a height. Rounded and clamped to
+/// the valid capture range.
+pub fn matching_dimension(value: u32, aspect: f32) -> u32 {
+    let other = (value as f64 / aspect as f64).round();
+    if other.is_finite() {
+        (other as u32).clamp(1, MAX_CAPTURE_DIMENSION)
+    } else {
+        1
+    }
+}
+
+/// Parse a custom width/height pair, or None unless both are valid numbers
+pub fn parse_custom_size(width: &str, height: &str) -> Option<(u32, u32)> {
+    let width = width.trim().parse::<u32>().ok()?;
+    let height = height.trim().parse::<u32>().ok()?;
+    (width > 0 && height > 0).then_some((width, height))
+}
@@ -162,9 +162,17 @@ impl UI {
         match action {
             CommandAction::SetFractalType(ftype) => {
                 params.switch_fractal(ftype);
+                self.fractal_switched = true;
                 changed = true;
                 message = Some(format!("Switched to {:?}", ftype));
             }
+            CommandAction::SetColorMode(crate::fractal::ColorMode::OrbitTrapImage)
+                if !params.fractal_type.supports_orbit_trap_image() =>
+            {
+                message = Some(
+                    "Orbit Trap Image works with Mandelbrot, Julia and Mandelbulb".to_string(),
+                );
+            }
             CommandAction::SetColorMode(mode) => {
                 params.color_mode = mode;
                 changed = true;
@@ -293,11 +301,33 @@ impl UI {
                     if self.show_fps { "ON" } else { "OFF" }
                 ));
             }
+            CommandAction::ToggleConsole => {
+                self.ui_state.console_window_open = !self.ui_state.console_window_open;
+            }
+            CommandAction::ToggleMathInfo => {
+                self.ui_state.math_info_window_open = !self.ui_state.math_info_window_open;
+            }
+            CommandAction::ToggleIterationReadout => {
+                self.show_iteration_readout = !self.show_iteration_readout;
+                self.ui_state.show_iteration_readout = self.show_iteration_readout;
+                message = Some(format!(
+                    "Iteration Readout: {}",
+                    if self.show_iteration_readout {
+                        "ON"
+                    } else {
+                        "OFF"
+                    }
+                ));
+            }
             CommandAction::CyclePalette => {
                 params.next_palette();
                 changed = true;
                 message = Some(format!("Static Palette: {}", params.palette.name));
             }
+            CommandAction::ShuffleColors => {
+                message = Some(self.shuffle_colors(params, false));
+                changed = true;
+            }
             CommandAction::CycleProceduralPalette => {
                 use crate::fractal::ProceduralPalette;
                 // Cycle through procedural palettes including None
@@ -383,9 +413,15 @@ impl UI {
             }
             CommandAction::LoadPreset(name) => match PresetGallery::load_preset(&name) {
                 Ok(preset) => {
-                    *params = FractalParams::from_settings(preset.settings);
+                    let (loaded, adjustments) =
+                        FractalParams::from_settings_checked(preset.settings);
+                    *params = loaded;
                     changed = true;
-                    message = Some(format!("Loaded preset: {}", name));
+                    message = Some(if adjustments.is_empty() {
+                        format!("Loaded preset: {}", name)
+                    } else {
+                        format!("Loaded preset: {} ({})", name, adjustments.join(", "))
+                    });
                 }
                 Err(e) => {
                     message = Some(format!("Failed to load preset: {}", e));
@@ -410,6 +446,12 @@ impl UI {
             CommandAction::Screenshot => {
                 message = Some("Screenshot feature not exposed to command palette yet".to_string());
             }
+            CommandAction::ScreenshotToClipboard => {
+                self.clipboard_screenshot_requested = true;
+            }
+            CommandAction::ToggleTour => {
+                self.tour_toggle_requested = true;
+            }
             _ => {}
         }
 
@@ -0,0 +1,100 @@
+//! Controls for the secondary fractal composited into a 3D scene
+
+use crate::fractal::{FractalParams, FractalType, SecondaryFractal};
+
+/// Ray-marched 3D fractals the secondary slot can hold
+const SECONDARY_TYPES: &[(FractalType, &str)] = &[
+    (FractalType::Mandelbulb3D, "Mandelbulb"),
+    (FractalType::MengerSponge3D, "Menger Sponge"),
+    (FractalType::SierpinskiPyramid3D, "Sierpinski Pyramid"),
+    (FractalType::SierpinskiGasket3D, "Sierpinski Gasket"),
+    (FractalType::JuliaSet3D, "Julia 3D"),
+    (FractalType::Mandelbox3D, "Mandelbox"),
+    (FractalType::OctahedralIFS3D, "Octahedron IFS"),
+    (FractalType::IcosahedralIFS3D, "Icosahedron IFS"),
+    (FractalType::ApollonianGasket3D, "Apollonian Gasket"),
+    (FractalType::Kleinian3D, "Kleinian"),
+    (FractalType::HybridMandelbulbJulia3D, "Hybrid Bulb-Julia"),
+    (FractalType::QuaternionCubic3D, "Quaternion Cubic"),
+];
+
+fn secondary_type_name(fractal_type: FractalType) -> &'static str {
+    SECONDARY_TYPES
+        .iter()
+        .find(|(t, _)| *t == fractal_type)
+        .map_or("?", |(_, name)| name)
+}
+
+/// Show the secondary fractal controls in the 3D Parameters section. Returns
+/// true when a parameter changed.
+pub(super) fn secondary_fractal_controls(ui: &mut egui::Ui, params: &mut FractalParams) -> bool {
+    if !SecondaryFractal::supports(params.fractal_type) {
+        return false;
+    }
+    let mut changed = false;
+    let secondary = &mut params.secondary_fractal;
+
+    ui.separator();
+    changed |= ui
+        .checkbox(&mut secondary.enabled, "Composite Second Fractal")
+        .on_hover_text(
+            "Render a second 3D fractal in the same scene, placed with its own transform\n\
+             Both distance estimates are evaluated at every ray step, roughly doubling the cost",
+        )
+        .changed();
+    if !secondary.enabled {
+        return changed;
+    }
+
+    ui.horizontal(|ui| {
+        ui.label("Fractal:");
+        egui::ComboBox::from_id_salt("secondary_fractal_type")
+            .selected_text(secondary_type_name(secondary.fractal_type))
+            .show_ui(ui, |ui| {
+                for &(fractal_type, name) in SECONDARY_TYPES {
+                    changed |= ui
+                        .selectable_value(&mut secondary.fractal_type, fractal_type, name)
+                        .changed();
+                }
+            })
+            .response
+            .on_hover_text("Shares the shape parameters above with the main fractal");
+    });
+
+    for (axis, value) in ["X", "Y", "Z"]
+        .into_iter()
+        .zip(secondary.position.iter_mut())
+    {
+        changed |= ui
+            .add(egui::Slider::new(value, -10.0..=10.0).text(format!("Position {}", axis)))
+            .changed();
+    }
+    for (axis, value) in ["X", "Y", "Z"]
+        .into_iter()
+        .zip(secondary.rotation.iter_mut())
+    {
+        changed |= ui
+            .add(
+                egui::Slider::new(value, -180.0..=180.0)
+                    .suffix("°")
+                    .text(format!("Rotation {}", axis)),
+            )
+            .changed();
+    }
+    changed |= ui
+        .add(
+            egui::Slider::new(&mut secondary.scale, 0.1..=5.0)
+                .logarithmic(true)
+                .text("Size"),
+        )
+        .on_hover_text("Scale relative to the main fractal")
+        .changed();
+    ui.horizontal(|ui| {
+        ui.label("Tint:");
+        changed |= ui
+            .color_edit_button_rgb(&mut secondary.tint)
+            .on_hover_text("Multiplied into the second fractal's color to tell the two apart")
+            .changed();
+    });
+    changed
+}
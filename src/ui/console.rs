@@ -0,0 +1,145 @@
+//! In-app log console
+//!
+//! `ConsoleLogger` is installed as the `log` backend. It forwards every record to
+//! the platform logger (env_logger on native, the browser console on web) and
+//! keeps the most recent ones in a ring buffer that the Console window shows, so
+//! diagnostics are visible without a terminal or browser devtools.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+/// Records kept for the console window; older ones are dropped
+const MAX_ENTRIES: usize = 1000;
+
+static ENTRIES: Mutex<VecDeque<LogEntry>> = Mutex::new(VecDeque::new());
+static START: OnceLock<web_time::Instant> = OnceLock::new();
+
+/// One captured log record
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub level: log::Level,
+    pub target: String,
+    pub message: String,
+    /// Seconds since the logger was installed
+    pub time_secs: f32,
+}
+
+/// `log` backend feeding the console ring buffer
+pub struct ConsoleLogger {
+    /// Platform logger records are also passed to (None = console only)
+    forward: Option<Box<dyn log::Log>>,
+}
+
+impl ConsoleLogger {
+    /// Install as the global logger. Records are forwarded to `forward` when it
+    /// accepts them; the console keeps this crate's debug and above plus
+    /// warnings and errors from dependencies.
+    pub fn init(
+        forward: Option<Box<dyn log::Log>>,
+        forward_level: log::LevelFilter,
+    ) -> Result<(), log::SetLoggerError> {
+        START.get_or_init(web_time::Instant::now);
+        log::set_boxed_logger(Box::new(Self { forward }))?;
+        log::set_max_level(forward_level.max(log::LevelFilter::Debug));
+        Ok(())
+    }
+}
+
+/// Whether a record is kept for the console
+fn is_captured(metadata: &log::Metadata) -> bool {
+    let own_crate = metadata.target().starts_with(env!("CARGO_CRATE_NAME"));
+    let max_level = if own_crate {
+        log::Level::Debug
+    } else {
+        log::Level::Warn
+    };
+    metadata.level() <= max_level
+}
+
+impl log::Log for ConsoleLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        is_captured(metadata)
+            || self
+                .forward
+                .as_ref()
+                .is_some_and(|forward| forward.enabled(metadata))
+    }
+
+    fn log(&self, record: &log::Record) {
+        if let Some(forward) = &self.forward {
+            if forward.enabled(record.metadata()) {
+                forward.log(record);
+            }
+        }
+        if !is_captured(record.metadata()) {
+            return;
+        }
+
+        let entry = LogEntry {
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+            time_secs: START
+                .get()
+                .map_or(0.0, |start| start.elapsed().as_secs_f32()),
+        };
+        if let Ok(mut entries) = ENTRIES.lock() {
+            if entries.len() >= MAX_ENTRIES {
+                entries.pop_front();
+            }
+            entries.push_back(entry);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(forward) = &self.forward {
+            forward.flush();
+        }
+    }
+}
+
+/// Captured records at or above `level`, oldest first
+pub fn entries(level: log::LevelFilter) -> Vec<LogEntry> {
+    ENTRIES
+        .lock()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter(|entry| entry.level <= level)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Number of captured warnings and errors
+pub fn problem_count() -> usize {
+    ENTRIES
+        .lock()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter(|entry| entry.level <= log::Level::Warn)
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Discard all captured records
+pub fn clear() {
+    if let Ok(mut entries) = ENTRIES.lock() {
+        entries.clear();
+    }
+}
+
+/// One line of console text for a record (module paths shown relative to this crate)
+pub fn format_entry(entry: &LogEntry) -> String {
+    let target = entry
+        .target
+        .strip_prefix(concat!(env!("CARGO_CRATE_NAME"), "::"))
+        .unwrap_or(&entry.target);
+    format!(
+        "[{:>8.2}] {:<5} {}: {}",
+        entry.time_secs, entry.level, target, entry.message
+    )
+}
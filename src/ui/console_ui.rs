@@ -0,0 +1,81 @@
+use super::{console, UI};
+use egui::Context;
+
+/// Levels offered by the console's severity filter
+const CONSOLE_LEVELS: &[log::LevelFilter] = &[
+    log::LevelFilter::Error,
+    log::LevelFilter::Warn,
+    log::LevelFilter::Info,
+    log::LevelFilter::Debug,
+];
+
+/// Log console window methods
+impl UI {
+    pub fn render_console(&mut self, ctx: &Context) {
+        if !self.ui_state.console_window_open {
+            return;
+        }
+
+        let mut open = true;
+        egui::Window::new("🖥 Console")
+            .open(&mut open)
+            .default_size([600.0, 300.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                let entries = console::entries(self.console_level);
+                ui.horizontal(|ui| {
+                    ui.label("Show:");
+                    egui::ComboBox::from_id_salt("console_level")
+                        .selected_text(self.console_level.as_str())
+                        .show_ui(ui, |ui| {
+                            for &level in CONSOLE_LEVELS {
+                                ui.selectable_value(&mut self.console_level, level, level.as_str());
+                            }
+                        })
+                        .response
+                        .on_hover_text("Lowest severity to list");
+                    if ui
+                        .button("📋 Copy")
+                        .on_hover_text("Copy the listed messages, e.g. for a bug report")
+                        .clicked()
+                    {
+                        let text: Vec<String> = entries.iter().map(console::format_entry).collect();
+                        ctx.copy_text(text.join("\n"));
+                        self.show_toast("📋 Console copied to clipboard".to_string());
+                    }
+                    if ui.button("🗑 Clear").clicked() {
+                        console::clear();
+                    }
+                    ui.label(format!("{} messages", entries.len()));
+                });
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        let visuals = ui.visuals();
+                        let (error, warn, info, debug) = (
+                            visuals.error_fg_color,
+                            visuals.warn_fg_color,
+                            visuals.text_color(),
+                            visuals.weak_text_color(),
+                        );
+                        for entry in &entries {
+                            let color = match entry.level {
+                                log::Level::Error => error,
+                                log::Level::Warn => warn,
+                                log::Level::Info => info,
+                                log::Level::Debug | log::Level::Trace => debug,
+                            };
+                            ui.label(
+                                egui::RichText::new(console::format_entry(entry))
+                                    .monospace()
+                                    .color(color),
+                            );
+                        }
+                    });
+            });
+        self.ui_state.console_window_open = open;
+    }
+}
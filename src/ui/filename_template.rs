@@ -0,0 +1,67 @@
+//! Editor for the capture filename template
+
+use crate::fractal::{
+    filename_template_is_unique, validate_filename_template, DEFAULT_FILENAME_TEMPLATE,
+    FILENAME_TOKENS,
+};
+
+/// Show the filename template editor under the capture folder controls.
+/// `edit` holds the text being typed; a valid edit is applied to `template`
+/// and saved to preferences right away.
+pub(super) fn filename_template_controls(
+    ui: &mut egui::Ui,
+    template: &mut String,
+    edit: &mut String,
+) {
+    let tokens = FILENAME_TOKENS
+        .iter()
+        .map(|(name, doc)| format!("{{{}}} - {}", name, doc))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut template_changed = false;
+    ui.horizontal(|ui| {
+        ui.label("Filename:");
+        let edited = ui
+            .add(
+                egui::TextEdit::singleline(edit)
+                    .desired_width(180.0)
+                    .font(egui::TextStyle::Monospace),
+            )
+            .on_hover_text(format!(
+                "Name of saved captures, without extension\n\n{}",
+                tokens
+            ))
+            .changed();
+        if edited && validate_filename_template(edit).is_ok() {
+            *template = edit.clone();
+            template_changed = true;
+        }
+        if ui
+            .add_enabled(
+                edit != DEFAULT_FILENAME_TEMPLATE,
+                egui::Button::new("Default"),
+            )
+            .on_hover_text(format!("Name captures {}", DEFAULT_FILENAME_TEMPLATE))
+            .clicked()
+        {
+            *template = DEFAULT_FILENAME_TEMPLATE.to_string();
+            *edit = template.clone();
+            template_changed = true;
+        }
+    });
+    if let Err(e) = validate_filename_template(edit) {
+        ui.colored_label(
+            egui::Color32::from_rgb(255, 120, 80),
+            format!("⚠ {} - using {}", e, template),
+        );
+    } else if !filename_template_is_unique(edit) {
+        ui.weak("No {time} or {counter}, so _{counter} is added to keep captures from overwriting each other");
+    }
+    if template_changed {
+        let mut prefs = crate::fractal::AppPreferences::load();
+        prefs.filename_template = template.clone();
+        if let Err(e) = prefs.save() {
+            eprintln!("Failed to save preferences: {}", e);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Fractal type selection buttons
+//!
+//! The buttons of the Fractal Type section, grouped as in the panel. Users can
+//! hide the types they don't use from the section; the visible set is kept in
+//! the preferences. Hidden types can still be loaded from presets and hotkeys.
+
+use super::UI;
+use crate::fractal::{FractalParams, FractalType};
+
+/// One labelled group of fractal type buttons
+struct FractalTypeGroup {
+    label: &'static str,
+    /// Buttons per row
+    columns: usize,
+    /// Selecting one of these turns on accumulation and clears it
+    accumulate: bool,
+    /// Type, button label and hover text
+    types: &'static [(FractalType, &'static str, &'static str)],
+}
+
+const FRACTAL_TYPE_GROUPS: &[FractalTypeGroup] = &[
+    FractalTypeGroup {
+        label: "2D Fractals",
+        columns: 2,
+        accumulate: false,
+        types: &[
+            (FractalType::Mandelbrot2D, "Mandelbrot", "Classic Mandelbrot set - infinite detail fractal [1]"),
+            (FractalType::Julia2D, "Julia", "Julia set - beautiful variations with complex parameter [2]"),
+            (FractalType::Sierpinski2D, "Sierpinski Carpet", "Sierpinski carpet - recursive square pattern [3]"),
+            (FractalType::SierpinskiTriangle2D, "Sierpinski Triangle", "Sierpinski triangle - classic recursive triangle pattern"),
+            (FractalType::BurningShip2D, "Burning Ship", "Burning Ship fractal - variant with absolute values [4]"),
+            (FractalType::Tricorn2D, "Tricorn", "Tricorn - Mandelbrot with conjugate iteration [5]"),
+            (FractalType::Phoenix2D, "Phoenix", "Phoenix fractal - dynamic iteration algorithm [6]"),
+            (FractalType::Celtic2D, "Celtic", "Celtic fractal - alternative complex iteration [7]"),
+            (FractalType::Newton2D, "Newton", "Newton fractal - polynomial root-finding visualization [8]"),
+            (FractalType::Lyapunov2D, "Lyapunov", "Lyapunov fractal - stability diagram patterns [9]"),
+            (FractalType::Nova2D, "Nova", "Nova fractal - Newton-Mandelbrot hybrid [0]"),
+            (FractalType::Magnet2D, "Magnet", "Magnet Type 1 - physics-inspired fractal"),
+            (FractalType::Collatz2D, "Collatz", "Collatz fractal - based on Collatz conjecture"),
+        ],
+    },
+    FractalTypeGroup {
+        label: "2D Density Fractals",
+        columns: 1,
+        accumulate: true,
+        types: &[(
+            FractalType::Buddhabrot2D,
+            "Buddhabrot",
+            "Buddhabrot - Mandelbrot escape trajectory density visualization (discovered by Melinda Green, 1993)",
+        )],
+    },
+    FractalTypeGroup {
+        label: "2D Strange Attractors",
+        columns: 2,
+        accumulate: true,
+        types: &[
+            (FractalType::Hopalong2D, "Hopalong", "Hopalong attractor - intricate web patterns"),
+            (FractalType::Martin2D, "Martin", "Martin attractor - spiral/flower patterns"),
+            (FractalType::Gingerbreadman2D, "Gingerbread", "Gingerbreadman - simple formula, complex output"),
+            (FractalType::Chip2D, "Chip", "Chip - log/cos/atan hopalong variant"),
+            (FractalType::Quadruptwo2D, "Quadruptwo", "Quadruptwo - log/sin/atan hopalong variant"),
+            (FractalType::Threeply2D, "Threeply", "Threeply - trigonometric hopalong variant"),
+        ],
+    },
+    FractalTypeGroup {
+        label: "3D Fractals",
+        columns: 2,
+        accumulate: false,
+        types: &[
+            (FractalType::Mandelbulb3D, "Mandelbulb", "3D Mandelbrot with adjustable power [F1]"),
+            (FractalType::MengerSponge3D, "Menger Sponge", "Recursive cubic structure with infinite holes [F2]"),
+            (FractalType::SierpinskiPyramid3D, "Sierpinski Pyramid", "3D Sierpinski pyramid - recursive tetrahedron [F3]"),
+            (FractalType::SierpinskiGasket3D, "Sierpinski Gasket", "3D Sierpinski gasket - sphere packing structure"),
+            (FractalType::JuliaSet3D, "Julia 3D", "3D Julia set with quaternion math [F4]"),
+            (FractalType::Mandelbox3D, "Mandelbox", "Cubic folding fractal with sharp edges [F5]"),
+            (FractalType::OctahedralIFS3D, "Octahedron IFS", "Kaleidoscopic IFS with 8-fold symmetry [F6]"),
+            (FractalType::IcosahedralIFS3D, "Icosahedron IFS", "Kaleidoscopic IFS with 20-fold symmetry [F7]"),
+            (FractalType::ApollonianGasket3D, "Apollonian Gasket", "Beautiful sphere-packing fractal [F8]"),
+            (FractalType::Kleinian3D, "Kleinian", "Kleinian group fractal [F9]"),
+            (FractalType::HybridMandelbulbJulia3D, "Hybrid Bulb-Julia", "Mandelbulb and Julia set hybrid [F10]"),
+            (FractalType::QuaternionCubic3D, "Quaternion Cubic", "Cubic quaternion Julia set (z³+c)"),
+        ],
+    },
+    // Rendered as GPU-accumulated point clouds projected through the camera
+    FractalTypeGroup {
+        label: "3D Strange Attractors",
+        columns: 3,
+        accumulate: false,
+        types: &[
+            (FractalType::Lorenz3D, "Lorenz", "Lorenz attractor - the classic chaotic butterfly"),
+            (FractalType::Rossler3D, "Rossler", "Rossler attractor - a folded band spiralling outward"),
+            (FractalType::Pickover3D, "Pickover", "Pickover attractor - Clifford Pickover's chaotic 3D map"),
+        ],
+    },
+];
+
+/// Whether `fractal_type` is listed in the Fractal Type section, given the
+/// visible set from the preferences (None = all types)
+pub(super) fn fractal_type_visible(
+    visible: Option<&[FractalType]>,
+    fractal_type: FractalType,
+) -> bool {
+    visible.is_none_or(|types| types.contains(&fractal_type))
+}
+
+impl UI {
+    /// Show the buttons of the visible fractal types, always including the
+    /// current one. Returns true when a button set up accumulation (the type
+    /// switch itself is picked up by the caller).
+    pub(super) fn fractal_type_buttons(
+        &mut self,
+        ui: &mut egui::Ui,
+        params: &mut FractalParams,
+    ) -> bool {
+        let mut changed = false;
+        let visible = self.visible_fractal_types.as_deref();
+        let mut first_group = true;
+        for group in FRACTAL_TYPE_GROUPS {
+            let types: Vec<_> = group
+                .types
+                .iter()
+                .filter(|(t, _, _)| *t == params.fractal_type || fractal_type_visible(visible, *t))
+                .collect();
+            if types.is_empty() {
+                continue;
+            }
+            if !first_group {
+                ui.separator();
+            }
+            first_group = false;
+            ui.label(format!("{}:", group.label));
+            for row in types.chunks(group.columns) {
+                ui.horizontal(|ui| {
+                    for &&(fractal_type, label, hover) in row {
+                        let selected = params.fractal_type == fractal_type;
+                        if ui
+                            .selectable_label(selected, label)
+                            .on_hover_text(hover)
+                            .clicked()
+                        {
+                            params.fractal_type = fractal_type;
+                            if group.accumulate {
+                                params.attractor_accumulation_enabled = true;
+                                params.attractor_pending_clear = true;
+                                params.attractor_total_iterations = 0;
+                                changed = true;
+                            }
+                        }
+                    }
+                });
+            }
+        }
+
+        ui.separator();
+        let hidden = FRACTAL_TYPE_GROUPS
+            .iter()
+            .flat_map(|group| group.types)
+            .filter(|(t, _, _)| !fractal_type_visible(visible, *t))
+            .count();
+        let title = if hidden > 0 {
+            format!("👁 Shown Types ({} hidden)", hidden)
+        } else {
+            "👁 Shown Types".to_string()
+        };
+        ui.menu_button(title, |ui| self.fractal_type_visibility_menu(ui))
+            .response
+            .on_hover_text("Hide the fractal types you don't use from this list\nHidden types can still be loaded from presets");
+        changed
+    }
+
+    /// Checkboxes for each fractal type, saved to preferences as they change
+    fn fractal_type_visibility_menu(&mut self, ui: &mut egui::Ui) {
+        let mut visibility_changed = false;
+        if ui
+            .add_enabled(
+                self.visible_fractal_types.is_some(),
+                egui::Button::new("Show All"),
+            )
+            .clicked()
+        {
+            self.visible_fractal_types = None;
+            visibility_changed = true;
+        }
+        egui::ScrollArea::vertical()
+            .max_height(400.0)
+            .show(ui, |ui| {
+                for group in FRACTAL_TYPE_GROUPS {
+                    ui.separator();
+                    ui.label(group.label);
+                    for &(fractal_type, label, _) in group.types {
+                        let mut shown = fractal_type_visible(
+                            self.visible_fractal_types.as_deref(),
+                            fractal_type,
+                        );
+                        if ui.checkbox(&mut shown, label).changed() {
+                            let visible = self.visible_fractal_types.get_or_insert_with(|| {
+                                FRACTAL_TYPE_GROUPS
+                                    .iter()
+                                    .flat_map(|group| group.types.iter().map(|(t, _, _)| *t))
+                                    .collect()
+                            });
+                            if shown {
+                                visible.push(fractal_type);
+                            } else {
+                                visible.retain(|t| *t != fractal_type);
+                            }
+                            visibility_changed = true;
+                        }
+                    }
+                }
+            });
+        // Once everything is shown again, go back to showing all, including
+        // types added in later versions
+        if self.visible_fractal_types.as_ref().is_some_and(|visible| {
+            visible.len() >= FRACTAL_TYPE_GROUPS.iter().map(|g| g.types.len()).sum()
+        }) {
+            self.visible_fractal_types = None;
+        }
+        if visibility_changed {
+            let mut prefs = crate::fractal::AppPreferences::load();
+            prefs.visible_fractal_types = self.visible_fractal_types.clone();
+            if let Err(e) = prefs.save() {
+                eprintln!("Failed to save preferences: {}", e);
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Shaded color swatches for the floor and fog color pickers
+//!
+//! A flat color button says little about how a color looks once it's lit, and
+//! dark colors vanish against the dark theme. These swatches are tiny CPU
+//! renders drawn over a checkerboard: the floor colors on a sphere shaded with
+//! the same Blinn-Phong / PBR models and light parameters as the ray marcher,
+//! and the fog color fading in over the checkerboard as it would with distance.
+
+use crate::fractal::{FractalParams, ShadingModel};
+use crate::renderer::surface_format::linear_to_srgb;
+use glam::Vec3;
+
+/// Swatch size in pixels
+pub const SWATCH_SIZE: usize = 48;
+
+/// Checkerboard cell size in pixels
+const CHECKER_CELL: usize = 6;
+
+/// Checker cells across the sphere's circumference
+const SPHERE_CHECKERS: f32 = 8.0;
+
+/// Everything a swatch is rendered from; swatches are re-rendered only when it changes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SwatchKey {
+    colors: [Vec3; 2],
+    lighting: SwatchLighting,
+}
+
+/// Light and material parameters, mirroring the shader uniforms
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SwatchLighting {
+    pub shading_model: ShadingModel,
+    pub light_dir: Vec3,
+    pub light_intensity: f32,
+    pub ambient_light: f32,
+    pub metallic: f32,
+    pub roughness: f32,
+    /// Specular color already scaled by intensity
+    pub specular: Vec3,
+}
+
+impl SwatchLighting {
+    pub fn from_params(params: &FractalParams) -> Self {
+        Self {
+            shading_model: params.shading_model,
+            light_dir: light_direction(params.light_azimuth, params.light_elevation),
+            light_intensity: params.light_intensity,
+            ambient_light: params.ambient_light,
+            metallic: params.metallic,
+            roughness: params.roughness,
+            specular: params.specular_color * params.specular_intensity,
+        }
+    }
+}
+
+/// The shader's light direction: azimuth 0 = +Z, 90 = +X; elevation 90 = straight up
+pub fn light_direction(azimuth_degrees: f32, elevation_degrees: f32) -> Vec3 {
+    let (sin_azim, cos_azim) = azimuth_degrees.to_radians().sin_cos();
+    let (sin_elev, cos_elev) = elevation_degrees.to_radians().sin_cos();
+    Vec3::new(cos_elev * sin_azim, sin_elev, cos_elev * cos_azim).normalize()
+}
+
+/// Shade a surface point in linear light, as `blinn_phong` / `pbr` in fractal.wgsl do
+pub fn shade(normal: Vec3, view_dir: Vec3, albedo: Vec3, lighting: &SwatchLighting) -> Vec3 {
+    let light_dir = lighting.light_dir;
+    let intensity = lighting.light_intensity;
+    match lighting.shading_model {
+        ShadingModel::BlinnPhong => {
+            let diffuse = normal.dot(light_dir).max(0.0) * intensity;
+            let half_dir = (light_dir + view_dir).normalize();
+            let specular = normal.dot(half_dir).max(0.0).powf(32.0) * intensity;
+            albedo * (lighting.ambient_light + diffuse) + specular * lighting.specular
+        }
+        ShadingModel::PBR => {
+            let n_dot_v = normal.dot(view_dir).max(0.0);
+            let n_dot_l = normal.dot(light_dir).max(0.0);
+            let half_dir = (view_dir + light_dir).normalize();
+            let n_dot_h = normal.dot(half_dir).max(0.0);
+            let h_dot_v = half_dir.dot(view_dir).max(0.0);
+
+            let f0 = Vec3::splat(0.04).lerp(albedo, lighting.metallic);
+
+            // Cook-Torrance BRDF
+            let a = lighting.roughness * lighting.roughness;
+            let a2 = a * a;
+            let denom = n_dot_h * n_dot_h * (a2 - 1.0) + 1.0;
+            let ndf = a2 / (std::f32::consts::PI * denom * denom);
+            let r = lighting.roughness + 1.0;
+            let k = r * r / 8.0;
+            let ggx = |n_dot_x: f32| n_dot_x / (n_dot_x * (1.0 - k) + k);
+            let g = ggx(n_dot_v) * ggx(n_dot_l);
+            let f = f0 + (Vec3::ONE - f0) * (1.0 - h_dot_v).powi(5);
+
+            let specular = ndf * g * f / (4.0 * n_dot_v * n_dot_l + 0.001) * lighting.specular;
+            let k_d = (Vec3::ONE - f) * (1.0 - lighting.metallic);
+            let ambient = albedo * lighting.ambient_light;
+            (k_d * albedo / std::f32::consts::PI + specular) * intensity * n_dot_l + ambient
+        }
+    }
+}
+
+/// Light and dark gray checkerboard behind the swatch, in linear light
+fn checker(x: usize, y: usize) -> Vec3 {
+    if (x / CHECKER_CELL + y / CHECKER_CELL).is_multiple_of(2) {
+        Vec3::splat(0.6)
+    } else {
+        Vec3::splat(0.2)
+    }
+}
+
+fn to_color32(linear: Vec3) -> egui::Color32 {
+    let channel = |c: f32| (linear_to_srgb(c) * 255.0).round() as u8;
+    egui::Color32::from_rgb(channel(linear.x), channel(linear.y), channel(linear.z))
+}
+
+/// A sphere checkered with `colors`, lit as the floor would be, over the checkerboard.
+/// The sphere is seen from +Z, like the default camera.
+pub fn sphere_swatch(colors: [Vec3; 2], lighting: &SwatchLighting) -> egui::ColorImage {
+    let view_dir = Vec3::Z;
+    let half = SWATCH_SIZE as f32 / 2.0;
+    let radius = half - 2.0;
+    let mut pixels = Vec::with_capacity(SWATCH_SIZE * SWATCH_SIZE);
+    for y in 0..SWATCH_SIZE {
+        for x in 0..SWATCH_SIZE {
+            let px = (x as f32 + 0.5 - half) / radius;
+            let py = (half - y as f32 - 0.5) / radius;
+            let r2 = px * px + py * py;
+            let color = if r2 <= 1.0 {
+                let normal = Vec3::new(px, py, (1.0 - r2).sqrt());
+                // Checker the sphere in longitude/latitude cells
+                let u = normal.x.atan2(normal.z) / std::f32::consts::TAU + 0.5;
+                let v = normal.y.clamp(-1.0, 1.0).asin() / std::f32::consts::PI + 0.5;
+                let cell = (u * SPHERE_CHECKERS).floor() as i32
+                    + (v * SPHERE_CHECKERS / 2.0).floor() as i32;
+                let albedo = colors[cell.rem_euclid(2) as usize];
+                shade(normal, view_dir, albedo, lighting)
+            } else {
+                checker(x, y)
+            };
+            pixels.push(to_color32(color));
+        }
+    }
+    egui::ColorImage::new([SWATCH_SIZE, SWATCH_SIZE], pixels)
+}
+
+/// The fog color fading in from left to right over the checkerboard, as
+/// distant geometry fades into fog
+pub fn fog_swatch(color: Vec3) -> egui::ColorImage {
+    let mut pixels = Vec::with_capacity(SWATCH_SIZE * SWATCH_SIZE);
+    for y in 0..SWATCH_SIZE {
+        for x in 0..SWATCH_SIZE {
+            let amount = x as f32 / (SWATCH_SIZE - 1) as f32;
+            pixels.push(to_color32(checker(x, y).lerp(color, amount)));
+        }
+    }
+    egui::ColorImage::new([SWATCH_SIZE, SWATCH_SIZE], pixels)
+}
+
+/// A swatch texture that is re-rendered when its key changes
+#[derive(Default)]
+pub struct CachedSwatch {
+    key: Option<SwatchKey>,
+    texture: Option<egui::TextureHandle>,
+}
+
+impl CachedSwatch {
+    /// The swatch texture for `colors` under `lighting`, rendering it with
+    /// `render` if either changed since the last call
+    pub fn texture(
+        &mut self,
+        ctx: &egui::Context,
+        name: &str,
+        colors: [Vec3; 2],
+        lighting: SwatchLighting,
+        render: impl FnOnce() -> egui::ColorImage,
+    ) -> &egui::TextureHandle {
+        let key = SwatchKey { colors, lighting };
+        let changed = self.key != Some(key);
+        self.key = Some(key);
+        let texture = match self.texture.take() {
+            Some(mut texture) => {
+                if changed {
+                    texture.set(render(), egui::TextureOptions::LINEAR);
+                }
+                texture
+            }
+            None => ctx.load_texture(name, render(), egui::TextureOptions::LINEAR),
+        };
+        self.texture.insert(texture)
+    }
+}
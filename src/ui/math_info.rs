@@ -0,0 +1,64 @@
+use super::UI;
+use crate::fractal::{FractalMathInfo, FractalType};
+use egui::{Context, RichText};
+
+/// Math Info window: the formula reference for the current fractal type
+impl UI {
+    pub fn render_math_info(&mut self, ctx: &Context, fractal_type: FractalType) {
+        if !self.ui_state.math_info_window_open {
+            return;
+        }
+
+        let info = fractal_type.math_info();
+        let mut open = true;
+        egui::Window::new("∑ Math Info")
+            .open(&mut open)
+            .default_width(440.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.heading(info.title);
+                ui.add_space(4.0);
+
+                egui::Frame::group(ui.style()).show(ui, |ui| {
+                    ui.set_width(ui.available_width());
+                    for line in info.formula {
+                        ui.label(RichText::new(*line).monospace().size(15.0));
+                    }
+                });
+
+                ui.add_space(4.0);
+                ui.label(info.description);
+
+                if !info.parameters.is_empty() {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new("Parameters").strong());
+                    parameter_grid(ui, &info);
+                }
+
+                ui.add_space(4.0);
+                if ui
+                    .button("📋 Copy")
+                    .on_hover_text("Copy the formula, description and parameters as text")
+                    .clicked()
+                {
+                    ctx.copy_text(info.to_text());
+                    self.show_toast("📋 Formula copied to clipboard".to_string());
+                }
+            });
+        self.ui_state.math_info_window_open = open;
+    }
+}
+
+/// Symbols and their meanings, one per row
+fn parameter_grid(ui: &mut egui::Ui, info: &FractalMathInfo) {
+    egui::Grid::new("math_info_parameters")
+        .num_columns(2)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            for (symbol, meaning) in info.parameters {
+                ui.label(RichText::new(*symbol).monospace());
+                ui.label(*meaning);
+                ui.end_row();
+            }
+        });
+}
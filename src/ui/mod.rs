@@ -1,19 +1,40 @@
 // Module declarations
+mod accumulation;
+mod bookmarks;
+#[cfg(not(target_arch = "wasm32"))]
+mod camera_path_io;
+mod capture_size;
 mod command;
+mod composite;
+pub mod console;
+mod console_ui;
+#[cfg(not(target_arch = "wasm32"))]
+mod filename_template;
+mod fractal_types;
 mod history;
+mod material_swatch;
+mod math_info;
 mod monitor;
 mod overlays;
+mod preset_diff;
+mod preset_nav;
+mod randomize_gallery;
+mod search;
+mod sweep;
 mod toast;
 mod toast_ui;
 
 // Re-exports
-pub use monitor::MonitorInfo;
+pub use monitor::{spanned_layout, MonitorInfo, SpanRect};
+pub use randomize_gallery::RandomizeGallery;
+pub use sweep::ParameterSweep;
 pub use toast::Toast;
 
 use crate::command_palette::CommandPalette;
 use crate::fractal::{
-    BookmarkGallery, CameraBookmark, CustomPalette, CustomPaletteGallery, FractalParams,
-    FractalType, Preset, PresetCategory, PresetGallery, ShadingModel, UIState,
+    BookmarkGallery, CameraBookmark, ColorPalette, CustomPalette, CustomPaletteGallery,
+    FractalParams, FractalType, PanelDock, ParamSection, Preset, PresetCategory, PresetGallery,
+    ShadingModel, UIState, UpAxis,
 };
 use egui::Context;
 use glam::Vec3;
@@ -135,10 +156,178 @@ fn get_procedural_preview_color(
     }
 }
 
+/// Complex-plane mini widget for dragging Newton fractal roots.
+/// Shows the region [-2, 2] x [-2, 2] with the unit circle for reference.
+fn newton_root_editor(ui: &mut egui::Ui, roots: &mut [[f32; 2]]) -> bool {
+    const EXTENT: f32 = 2.0;
+    const ROOT_RADIUS: f32 = 5.0;
+
+    let size = egui::vec2(180.0, 180.0);
+    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let scale = rect.width() / (2.0 * EXTENT);
+    let to_screen = |p: [f32; 2]| rect.center() + egui::vec2(p[0] * scale, -p[1] * scale);
+    let from_screen = |pos: egui::Pos2| {
+        let v = (pos - rect.center()) / scale;
+        [v.x.clamp(-EXTENT, EXTENT), (-v.y).clamp(-EXTENT, EXTENT)]
+    };
+
+    // Background, axes and unit circle
+    let axis_stroke = egui::Stroke::new(1.0, egui::Color32::from_gray(80));
+    painter.rect_filled(rect, 4.0, egui::Color32::from_gray(20));
+    painter.line_segment([rect.left_center(), rect.right_center()], axis_stroke);
+    painter.line_segment([rect.center_top(), rect.center_bottom()], axis_stroke);
+    painter.circle_stroke(rect.center(), scale, axis_stroke);
+
+    let mut changed = false;
+    let root_count = roots.len();
+    for (i, root) in roots.iter_mut().enumerate() {
+        let center = to_screen(*root);
+        let hit_rect =
+            egui::Rect::from_center_size(center, egui::vec2(ROOT_RADIUS * 3.0, ROOT_RADIUS * 3.0));
+        let response = ui.interact(
+            hit_rect,
+            ui.id().with(("newton_root", i)),
+            egui::Sense::drag(),
+        );
+        if response.dragged() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                *root = from_screen(pos);
+                changed = true;
+            }
+        }
+
+        // Color each root like its basin (hue spread evenly across roots)
+        let hue = i as f32 / root_count as f32;
+        let color: egui::Color32 = egui::epaint::Hsva::new(hue, 0.8, 1.0, 1.0).into();
+        let radius = if response.hovered() || response.dragged() {
+            ROOT_RADIUS * 1.4
+        } else {
+            ROOT_RADIUS
+        };
+        painter.circle_filled(to_screen(*root), radius, color);
+        response.on_hover_text(format!("Root {}: {:.3} {:+.3}i", i + 1, root[0], root[1]));
+    }
+
+    changed
+}
+
+/// Slider for a parameter that needs more precision than dragging gives. The
+/// value box shows `decimals` places and takes typed values: click it, or
+/// double-click the slider track.
+fn precise_slider(
+    ui: &mut egui::Ui,
+    value: &mut f32,
+    range: std::ops::RangeInclusive<f32>,
+    text: &str,
+    decimals: usize,
+    logarithmic: bool,
+) -> egui::Response {
+    ui.horizontal(|ui| {
+        let slider = ui.add(
+            egui::Slider::new(value, range.clone())
+                .logarithmic(logarithmic)
+                .show_value(false),
+        );
+        // The value box takes the next id; focusing it opens its text field
+        let value_id = ui.next_auto_id();
+        if slider.double_clicked() {
+            ui.memory_mut(|mem| mem.request_focus(value_id));
+        }
+        let value_box = ui.add(
+            egui::DragValue::new(value)
+                .range(range.clone())
+                .speed((range.end() - range.start()) / 500.0)
+                .min_decimals(decimals)
+                .max_decimals(decimals + 3),
+        );
+        ui.label(text);
+        slider | value_box
+    })
+    .inner
+}
+
+/// Julia constant as (radius, angle in degrees)
+fn julia_c_to_polar(c: [f32; 2]) -> (f32, f32) {
+    (c[0].hypot(c[1]), c[1].atan2(c[0]).to_degrees())
+}
+
+/// Julia constant from (radius, angle in degrees)
+fn julia_c_from_polar(radius: f32, degrees: f32) -> [f32; 2] {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    [radius * cos, radius * sin]
+}
+
+/// Sliders for a Julia constant, either as real/imaginary parts or as a
+/// radius and angle. The constant is always stored in Cartesian form.
+fn julia_constant_editor(
+    ui: &mut egui::Ui,
+    c: &mut [f32; 2],
+    polar: &mut bool,
+    constant_name: &str,
+) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label("Input:");
+        ui.selectable_value(polar, false, "Cartesian")
+            .on_hover_text("Edit the real and imaginary parts");
+        ui.selectable_value(polar, true, "Polar").on_hover_text(
+            "Edit the radius and angle - sweep the angle at a radius near 0.7-1.0\n\
+             to trace the rim of the Mandelbrot set where the richest Julia sets live",
+        );
+    });
+    if *polar {
+        let (mut radius, mut angle) = julia_c_to_polar(*c);
+        let mut polar_changed = precise_slider(ui, &mut radius, 0.0..=2.0, "Radius", 6, false)
+            .on_hover_text(format!("Distance of the {} from the origin", constant_name))
+            .changed();
+        polar_changed |= precise_slider(ui, &mut angle, -180.0..=180.0, "Angle (°)", 4, false)
+            .on_hover_text(format!("Argument of the {}", constant_name))
+            .changed();
+        if polar_changed {
+            *c = julia_c_from_polar(radius, angle);
+            changed = true;
+        }
+    } else {
+        changed |= precise_slider(ui, &mut c[0], -2.0..=2.0, "Real", 6, false)
+            .on_hover_text(format!("Real component of {}", constant_name))
+            .changed();
+        changed |= precise_slider(ui, &mut c[1], -2.0..=2.0, "Imaginary", 6, false)
+            .on_hover_text(format!("Imaginary component of {}", constant_name))
+            .changed();
+    }
+    changed
+}
+
+/// Label for a DoF aperture blade count
+fn bokeh_shape_name(blades: u32) -> &'static str {
+    match blades {
+        3 => "Triangle",
+        4 => "Square",
+        5 => "Pentagon",
+        6 => "Hexagon",
+        7 => "Heptagon",
+        8 => "Octagon",
+        _ => "Circular",
+    }
+}
+
 // Video format - use actual type on native, stub on web
 #[cfg(not(target_arch = "wasm32"))]
 use crate::video_recorder::VideoFormat;
 
+/// Video recording size presets (label, width, height); 0x0 follows the window
+#[cfg(not(target_arch = "wasm32"))]
+const VIDEO_RESOLUTIONS: &[(&str, u32, u32)] = &[
+    ("Window size", 0, 0),
+    ("1280x720 (HD)", 1280, 720),
+    ("1920x1080 (Full HD)", 1920, 1080),
+    ("2560x1440 (2K)", 2560, 1440),
+    ("3840x2160 (4K)", 3840, 2160),
+    ("1080x1920 (Portrait)", 1080, 1920),
+    ("1080x1080 (Square)", 1080, 1080),
+];
+
 /// Stub video format for web builds
 #[cfg(target_arch = "wasm32")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -160,11 +349,33 @@ impl VideoFormat {
     }
 }
 
+/// A palette the user is hovering in the Color section, shown in the live
+/// preview thumbnail before it's applied
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PalettePreviewCandidate {
+    pub palette_index: usize,
+    pub procedural_palette: ProceduralPalette,
+}
+
+impl PalettePreviewCandidate {
+    /// Apply the candidate palette to a copy of the parameters
+    pub fn apply(&self, params: &mut FractalParams) {
+        params.palette_index = self.palette_index.min(ColorPalette::ALL.len() - 1);
+        params.palette = ColorPalette::ALL[params.palette_index];
+        params.procedural_palette = self.procedural_palette;
+    }
+}
+
 pub struct UI {
     pub show_ui: bool,
     pub show_fps: bool,
     pub show_camera_info: bool,
     pub show_performance_overlay: bool,
+    pub show_iteration_readout: bool,
+    /// Lowest severity listed in the log console
+    pub console_level: log::LevelFilter,
+    /// Latest 2D hover probe readback (iteration value and coordinate under the cursor)
+    pub hover_probe: Option<crate::renderer::probe::ProbeSample>,
     pub ui_state: UIState,
     // Command palette
     pub command_palette: CommandPalette,
@@ -174,8 +385,18 @@ pub struct UI {
     // Preset UI state
     preset_name: String,
     preset_description: String,
+    /// Open preset comparison window, if any
+    preset_diff: Option<preset_diff::PresetDiff>,
     preset_category: PresetCategory,
     preset_search: String,
+    // Keyboard selection in the built-in preset list and its type-to-search buffer
+    preset_selected: usize,
+    preset_typeahead: String,
+    preset_typeahead_time: web_time::Instant,
+    // Control panel search: filters sections by control label
+    settings_search: String,
+    // The search was active last frame, so sections need their open state restored
+    settings_search_was_active: bool,
     preset_category_filter: PresetCategory,
     user_presets: Vec<String>,
     last_preset_list_update: web_time::Instant,
@@ -204,6 +425,7 @@ pub struct UI {
     pub palette_animation_speed: f32,
     pub palette_animation_reverse: bool,
     palette_animation_offset: f32, // Current accumulated offset
+    palette_animation_rate: f32,   // Current (eased) signed rate in cycles per second
     // GPU selection
     pub available_gpus: Vec<super::renderer::GpuInfo>,
     #[allow(dead_code)]
@@ -212,34 +434,162 @@ pub struct UI {
     // Video recording
     pub video_format: VideoFormat,
     pub video_fps: u32,
+    // Recording resolution, 0x0 = window size
+    pub video_width: u32,
+    pub video_height: u32,
+    // Text burned into recorded frames
+    pub burn_in_frame_number: bool,
+    pub burn_in_timecode: bool,
+    pub burn_in_parameters: bool,
+    // Stop recording by itself after a number of seconds or frames
+    pub video_auto_stop: bool,
+    pub video_stop_after: u32,
+    pub video_stop_in_frames: bool,
+    // Color blindness preview, left out of captures unless included
+    pub cvd_simulation: crate::fractal::CvdSimulation,
+    pub cvd_in_captures: bool,
     // Monitor/wallpaper support
     pub available_monitors: Vec<MonitorInfo>,
     // Toast notifications
     toasts: Vec<Toast>,
     pub selected_monitor_index: usize,
+    /// Monitor to apply the next wallpaper render to (consumed by the app)
+    pub wallpaper_monitor_request: Option<usize>,
+    // Monitors included in a spanned wallpaper (parallel to available_monitors),
+    // and the crop guides to save alongside the next render: (spanned size, monitor areas)
+    pub span_monitors: Vec<bool>,
+    pub span_crop_guides: bool,
+    pub spanned_guides_request: Option<((u32, u32), Vec<SpanRect>)>,
     pub last_monitor_scan: web_time::Instant,
     // Custom resolution input
     pub custom_width: String,
     pub custom_height: String,
+    // Keep custom_width / custom_height at capture_aspect (width / height) while typing
+    pub lock_capture_aspect: bool,
+    pub capture_aspect: f32,
+    // Supersampling pattern for high-res renders
+    pub capture_antialiasing: crate::fractal::CaptureAntialiasing,
+    /// Frames averaged for a noise-free high-res capture (1 = off)
+    pub accumulation_samples: u32,
+    // High-res renders with a transparent background (alpha mask PNG)
+    pub transparent_background: bool,
+    pub capture_16bit: bool,
+    /// Gamma applied to saved screenshots and renders (1.0 = as displayed)
+    pub capture_gamma: f32,
     // Auto-open captured images
     pub auto_open_captures: bool,
+    // Render F12 screenshots at 2x and downscale (persisted in preferences)
+    pub screenshot_supersample: bool,
+    // Safe mode: clamp GPU-heavy settings (persisted in preferences)
+    pub safe_mode: bool,
+    // Auto-save behavior (persisted in preferences)
+    pub autosave_enabled: bool,
+    pub autosave_interval_secs: f32,
+    // Explicit save / revert requests, handled by the app
+    pub save_settings_requested: bool,
+    pub revert_settings_requested: bool,
+    // Copy the current view to the clipboard, handled by the app
+    pub clipboard_screenshot_requested: bool,
+    // Copy the uniform values as shader source, handled by the app
+    pub shader_export_requested: Option<crate::renderer::shader_export::ShaderExportFormat>,
+    // Maximum-quality capture, handled by the app (custom size = the Width/Height fields)
+    pub final_render_requested: bool,
+    pub final_render_custom_size: bool,
+    // Batch parameter sweep settings, and a request for the app to render it
+    pub parameter_sweep: ParameterSweep,
+    pub sweep_requested: bool,
+    // Frame pacing (persisted in preferences)
+    pub present_mode: crate::fractal::PresentModeSetting,
+    pub supported_present_modes: Vec<crate::fractal::PresentModeSetting>,
+    pub max_fps: u32,
+    pub idle_throttle_enabled: bool,
+    pub idle_fps: u32,
+    // Window surface format (persisted in preferences, applied on restart)
+    pub surface_format: crate::fractal::SurfaceFormatSetting,
+    pub supported_surface_formats: Vec<crate::fractal::SurfaceFormatSetting>,
+    pub surface_format_changed: bool,
+    // 2D wheel/drag/pinch zoom keeps the point under the cursor fixed (persisted in preferences)
+    pub zoom_to_cursor: bool,
+    /// Glide after a flicked 2D pan and ease wheel zoom out
+    pub navigation_momentum: bool,
+    // Restore per-fractal coloring on type switches (persisted in preferences, applied by the app)
+    pub remember_fractal_coloring: bool,
+    // The fractal type was switched this frame, for the app's coloring memory
+    pub fractal_switched: bool,
+    // Blank frame warning and auto-revert (persisted in preferences, acted on by the app)
+    pub blank_frame_warning: bool,
+    pub blank_frame_auto_revert: bool,
+    // Parameter groups Randomize leaves unchanged
+    pub randomize_locks: crate::fractal::RandomizeLocks,
+    // Recent Randomize results; thumbnails are rendered by the app
+    pub randomize_gallery: RandomizeGallery,
+    // Tour mode: dwell time is persisted in preferences, the toggle is handled by the app
+    pub tour_dwell_secs: f32,
+    // Camera transitions to bookmarks and tour presets (persisted in preferences)
+    pub camera_transition_easing: crate::fractal::CameraEasing,
+    pub camera_transition_secs: f32,
+    pub tour_toggle_requested: bool,
+    pub tour_active: bool,
+    // Name of the last loaded preset (set by the app) and a request to revert to it
+    pub loaded_preset_name: Option<String>,
+    pub revert_to_preset_requested: bool,
+    // Camera path keyframes and the scrubber time the app should move the camera to
+    pub camera_path: crate::camera_path::CameraPath,
+    pub camera_path_preview: Option<f32>,
+    /// Set when the 3D relief is turned on, so the app can frame it
+    pub relief_view_requested: bool,
+    camera_path_time: f32,
+    camera_path_segment_secs: f32,
+    // Edit the Julia constant as radius and angle instead of real/imaginary
+    julia_polar_input: bool,
+    // Attract mode idle timeout in seconds, 0 = off (persisted in preferences)
+    pub idle_timeout_secs: f32,
+    // UI scale override in pixels per point, 0 = system DPI (persisted in preferences,
+    // applied by the app). The slider edits a copy so the panel doesn't rescale mid-drag.
+    pub ui_scale: f32,
+    ui_scale_edit: f32,
+    // Live palette preview: thumbnail rendered by the app, and the hovered palette to show in it
+    pub palette_preview_texture: Option<egui::TextureHandle>,
+    // Shaded swatches next to the floor and fog color pickers
+    floor_swatch: material_swatch::CachedSwatch,
+    fog_swatch: material_swatch::CachedSwatch,
+    pub palette_preview_candidate: Option<PalettePreviewCandidate>,
+    // Capture output folder (persisted in preferences, None = Pictures directory)
+    pub capture_directory: Option<String>,
+    // Capture filename template (persisted in preferences) and the text being edited
+    pub filename_template: String,
+    filename_template_edit: String,
+    // Fractal types listed in the Fractal Type section (persisted, None = all)
+    visible_fractal_types: Option<Vec<FractalType>>,
+    // Whether the GPU supports f64 shaders (set by the app after renderer init)
+    pub gpu_supports_f64: bool,
 }
 
 impl UI {
     pub fn new() -> Self {
+        let prefs = crate::fractal::AppPreferences::load();
         Self {
             show_ui: cfg!(not(target_arch = "wasm32")), // Hidden by default on web for mobile testing
             show_fps: false,
             show_camera_info: false,
             show_performance_overlay: false,
+            show_iteration_readout: false,
+            console_level: log::LevelFilter::Info,
+            hover_probe: None,
             ui_state: UIState::default(),
             command_palette: CommandPalette::new(),
             frame_times: Vec::with_capacity(120),
             max_frame_history: 120,
             preset_name: String::new(),
             preset_description: String::new(),
+            preset_diff: None,
             preset_category: PresetCategory::All,
             preset_search: String::new(),
+            preset_selected: 0,
+            preset_typeahead: String::new(),
+            preset_typeahead_time: web_time::Instant::now(),
+            settings_search: String::new(),
+            settings_search_was_active: false,
             preset_category_filter: PresetCategory::All,
             user_presets: PresetGallery::list_user_presets().unwrap_or_default(),
             last_preset_list_update: web_time::Instant::now(),
@@ -272,30 +622,105 @@ impl UI {
             palette_animation_speed: 0.1,
             palette_animation_reverse: false,
             palette_animation_offset: 0.0,
+            palette_animation_rate: 0.0,
             available_gpus: Vec::new(),
             selected_gpu_index: None,
             gpu_selection_message: None,
             video_format: VideoFormat::MP4,
             video_fps: 60,
+            video_width: 0,
+            video_height: 0,
+            burn_in_frame_number: prefs.burn_in_frame_number,
+            burn_in_timecode: prefs.burn_in_timecode,
+            burn_in_parameters: prefs.burn_in_parameters,
+            video_auto_stop: false,
+            video_stop_after: 10,
+            video_stop_in_frames: false,
+            cvd_simulation: crate::fractal::CvdSimulation::None,
+            cvd_in_captures: false,
             available_monitors: Vec::new(),
             toasts: Vec::new(),
             selected_monitor_index: 0,
+            wallpaper_monitor_request: None,
+            span_monitors: Vec::new(),
+            span_crop_guides: false,
+            spanned_guides_request: None,
             last_monitor_scan: web_time::Instant::now(),
             custom_width: String::from("1920"),
             custom_height: String::from("1080"),
+            lock_capture_aspect: false,
+            capture_aspect: 16.0 / 9.0,
+            capture_antialiasing: crate::fractal::CaptureAntialiasing::default(),
+            accumulation_samples: 1,
+            transparent_background: false,
+            capture_16bit: false,
+            capture_gamma: 1.0,
             auto_open_captures: false,
+            screenshot_supersample: prefs.screenshot_supersample,
+            safe_mode: prefs.safe_mode,
+            autosave_enabled: prefs.autosave_enabled,
+            autosave_interval_secs: prefs.autosave_interval_secs,
+            save_settings_requested: false,
+            revert_settings_requested: false,
+            clipboard_screenshot_requested: false,
+            shader_export_requested: None,
+            final_render_requested: false,
+            parameter_sweep: ParameterSweep::default(),
+            sweep_requested: false,
+            final_render_custom_size: false,
+            present_mode: prefs.present_mode,
+            supported_present_modes: vec![crate::fractal::PresentModeSetting::Fifo],
+            max_fps: prefs.max_fps,
+            idle_throttle_enabled: prefs.idle_throttle_enabled,
+            idle_fps: prefs.idle_fps,
+            surface_format: prefs.surface_format,
+            supported_surface_formats: vec![crate::fractal::SurfaceFormatSetting::Auto],
+            surface_format_changed: false,
+            zoom_to_cursor: prefs.zoom_to_cursor,
+            navigation_momentum: prefs.navigation_momentum,
+            remember_fractal_coloring: prefs.remember_fractal_coloring,
+            fractal_switched: false,
+            blank_frame_warning: prefs.blank_frame_warning,
+            blank_frame_auto_revert: prefs.blank_frame_auto_revert,
+            randomize_locks: crate::fractal::RandomizeLocks::default(),
+            randomize_gallery: RandomizeGallery::default(),
+            tour_dwell_secs: prefs.tour_dwell_secs,
+            camera_transition_easing: prefs.camera_transition_easing,
+            camera_transition_secs: prefs.camera_transition_secs,
+            idle_timeout_secs: prefs.idle_timeout_secs,
+            ui_scale: prefs.ui_scale,
+            ui_scale_edit: prefs.ui_scale,
+            tour_toggle_requested: false,
+            tour_active: false,
+            loaded_preset_name: None,
+            revert_to_preset_requested: false,
+            camera_path: Default::default(),
+            camera_path_preview: None,
+            relief_view_requested: false,
+            camera_path_time: 0.0,
+            camera_path_segment_secs: 2.0,
+            julia_polar_input: false,
+            palette_preview_texture: None,
+            floor_swatch: Default::default(),
+            fog_swatch: Default::default(),
+            palette_preview_candidate: None,
+            capture_directory: prefs.capture_directory.clone(),
+            filename_template: prefs.filename_template.clone(),
+            filename_template_edit: prefs.filename_template.clone(),
+            visible_fractal_types: prefs.visible_fractal_types.clone(),
+            gpu_supports_f64: false,
         }
     }
 
     /// Scan for available monitors and populate the list
     pub fn scan_monitors(&mut self, window: &winit::window::Window) {
-        eprintln!("DEBUG: Scanning for monitors...");
+        log::debug!("Scanning for monitors...");
         self.available_monitors.clear();
 
         // Get primary monitor
         let primary_monitor = window.primary_monitor();
-        eprintln!(
-            "DEBUG: Primary monitor: {:?}",
+        log::debug!(
+            "Primary monitor: {:?}",
             primary_monitor.as_ref().and_then(|m| m.name())
         );
 
@@ -303,7 +728,7 @@ impl UI {
         let mut count = 0;
         for (index, monitor) in window.available_monitors().enumerate() {
             count += 1;
-            eprintln!("DEBUG: Found monitor {}: {:?}", index, monitor.name());
+            log::debug!("Found monitor {}: {:?}", index, monitor.name());
             let is_primary = if let Some(ref primary) = primary_monitor {
                 monitor::monitors_equal(&monitor, primary)
             } else {
@@ -317,6 +742,7 @@ impl UI {
 
             // Prefer the monitor's reported current resolution. If unavailable (e.g., web),
             // fall back to the largest advertised video mode to avoid tiny default modes.
+            let position = monitor.position();
             let size = monitor.size();
             let (width, height) = if size.width > 0 && size.height > 0 {
                 (size.width, size.height)
@@ -338,6 +764,8 @@ impl UI {
                 name,
                 width,
                 height,
+                x: position.x,
+                y: position.y,
                 is_primary,
             });
         }
@@ -350,8 +778,10 @@ impl UI {
                 _ => std::cmp::Ordering::Equal,
             });
 
-        eprintln!("DEBUG: Total monitors found: {}", count);
-        eprintln!("DEBUG: Monitors in list: {}", self.available_monitors.len());
+        self.span_monitors = vec![true; self.available_monitors.len()];
+
+        log::debug!("Total monitors found: {}", count);
+        log::debug!("Monitors in list: {}", self.available_monitors.len());
 
         // Update scan time
         self.last_monitor_scan = web_time::Instant::now();
@@ -359,16 +789,285 @@ impl UI {
         log::info!("Scanned {} monitor(s)", self.available_monitors.len());
     }
 
+    /// Drop cached textures after the egui context is recreated (GPU reset),
+    /// so they are uploaded again
+    pub fn reset_textures(&mut self) {
+        self.palette_preview_texture = None;
+        self.floor_swatch = Default::default();
+        self.fog_swatch = Default::default();
+    }
+
     pub fn load_ui_state(&mut self, ui_state: UIState) {
         self.show_fps = ui_state.show_fps;
         self.show_camera_info = ui_state.show_camera_info;
+        self.show_iteration_readout = ui_state.show_iteration_readout;
         self.ui_state = ui_state;
     }
 
+    /// 3D Parameters for a 2D fractal shown as a relief
+    fn relief_controls(&mut self, ui: &mut egui::Ui, params: &mut FractalParams) -> bool {
+        let mut changed = false;
+        if ui.checkbox(&mut params.relief_3d, "3D Relief")
+            .on_hover_text("Turn off to return to the flat 2D view, where pan and zoom choose the region the relief covers")
+            .changed()
+        {
+            params.set_relief(params.relief_3d);
+            changed = true;
+        }
+        changed |= ui.add(egui::Slider::new(&mut params.relief_height, 0.05..=2.0)
+            .text("Height")
+            .logarithmic(true))
+            .on_hover_text("Elevation of the highest points (the set's interior); iteration values scale linearly below it")
+            .changed();
+        if let Some(max_iter_range) = params.fractal_type.max_iterations_range() {
+            changed |= ui.add(egui::Slider::new(&mut params.max_iterations, max_iter_range)
+                .text("Max Iterations")
+                .logarithmic(true))
+                .on_hover_text("Iterations per height sample - every ray step evaluates the fractal, so keep this modest")
+                .changed();
+        }
+        if matches!(params.fractal_type, FractalType::Julia2D) {
+            ui.label("Julia Constant (C):")
+                .on_hover_text("The complex constant used in Julia set formula");
+            changed |= julia_constant_editor(
+                ui,
+                &mut params.julia_c,
+                &mut self.julia_polar_input,
+                "Julia constant",
+            );
+        }
+        changed
+    }
+
+    /// Take on preferences changed outside the Settings controls (an imported profile)
+    pub fn apply_preferences(&mut self, prefs: &crate::fractal::AppPreferences) {
+        self.safe_mode = prefs.safe_mode;
+        self.autosave_enabled = prefs.autosave_enabled;
+        self.autosave_interval_secs = prefs.autosave_interval_secs;
+        self.present_mode = prefs.present_mode;
+        self.max_fps = prefs.max_fps;
+        self.idle_throttle_enabled = prefs.idle_throttle_enabled;
+        self.idle_fps = prefs.idle_fps;
+        if self.surface_format != prefs.surface_format {
+            self.surface_format = prefs.surface_format;
+            self.surface_format_changed = true;
+        }
+        self.zoom_to_cursor = prefs.zoom_to_cursor;
+        self.screenshot_supersample = prefs.screenshot_supersample;
+        self.burn_in_frame_number = prefs.burn_in_frame_number;
+        self.burn_in_timecode = prefs.burn_in_timecode;
+        self.burn_in_parameters = prefs.burn_in_parameters;
+        self.navigation_momentum = prefs.navigation_momentum;
+        self.remember_fractal_coloring = prefs.remember_fractal_coloring;
+        self.blank_frame_warning = prefs.blank_frame_warning;
+        self.blank_frame_auto_revert = prefs.blank_frame_auto_revert;
+        self.tour_dwell_secs = prefs.tour_dwell_secs;
+        self.camera_transition_easing = prefs.camera_transition_easing;
+        self.camera_transition_secs = prefs.camera_transition_secs;
+        self.idle_timeout_secs = prefs.idle_timeout_secs;
+        self.ui_scale = prefs.ui_scale;
+        self.ui_scale_edit = prefs.ui_scale;
+        self.capture_directory = prefs.capture_directory.clone();
+        self.filename_template = prefs.filename_template.clone();
+        self.filename_template_edit = prefs.filename_template.clone();
+        self.visible_fractal_types = prefs.visible_fractal_types.clone();
+    }
+
     pub fn get_ui_state(&self) -> &UIState {
         &self.ui_state
     }
 
+    /// Capture folder display with Choose/Default buttons, saved to preferences on change
+    #[cfg(not(target_arch = "wasm32"))]
+    fn capture_directory_ui(ui: &mut egui::Ui, capture_directory: &mut Option<String>) {
+        let resolved =
+            crate::fractal::AppPreferences::resolve_capture_directory(capture_directory.as_deref());
+        ui.horizontal(|ui| {
+            ui.label("Save to:");
+            ui.label(egui::RichText::new(resolved.display().to_string()).monospace())
+                .on_hover_text("Screenshots, high-res renders and videos are written here");
+        });
+        ui.horizontal(|ui| {
+            let mut directory_changed = false;
+            if ui
+                .button("📁 Choose…")
+                .on_hover_text("Pick the folder captures are saved to")
+                .clicked()
+            {
+                if let Some(dir) = rfd::FileDialog::new()
+                    .set_title("Capture Folder")
+                    .set_directory(&resolved)
+                    .pick_folder()
+                {
+                    *capture_directory = Some(dir.to_string_lossy().to_string());
+                    directory_changed = true;
+                }
+            }
+            if ui
+                .add_enabled(capture_directory.is_some(), egui::Button::new("Default"))
+                .on_hover_text("Save captures to your Pictures folder")
+                .clicked()
+            {
+                *capture_directory = None;
+                directory_changed = true;
+            }
+            if directory_changed {
+                let mut prefs = crate::fractal::AppPreferences::load();
+                prefs.capture_directory = capture_directory.clone();
+                if let Err(e) = prefs.save() {
+                    eprintln!("Failed to save preferences: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Switch to a random palette (and optionally color mode) as a single undoable
+    /// step, leaving the geometry alone. Returns a message naming the new palette.
+    pub fn shuffle_colors(
+        &mut self,
+        params: &mut FractalParams,
+        include_color_mode: bool,
+    ) -> String {
+        // Record the starting point if nothing has been saved yet, so undo can return to it
+        if self.history.is_empty() {
+            self.save_to_history(params);
+        }
+        params.randomize_palette(include_color_mode);
+        self.save_to_history(params);
+
+        let palette = if params.procedural_palette == crate::fractal::ProceduralPalette::None {
+            params.palette.name
+        } else {
+            params.procedural_palette.name()
+        };
+        if include_color_mode {
+            format!("🎲 Colors: {} ({:?})", palette, params.color_mode)
+        } else {
+            format!("🎲 Colors: {}", palette)
+        }
+    }
+
+    /// Collapsing header for a control panel section. While the settings search
+    /// has a query, sections with matches are forced open (with the match count
+    /// highlighted) and the rest collapsed; returns the matching control labels.
+    fn section_header(
+        &self,
+        title: &'static str,
+        open: bool,
+    ) -> (egui::CollapsingHeader, Vec<&'static str>) {
+        if self.settings_search.trim().is_empty() {
+            let header = egui::CollapsingHeader::new(title).default_open(open);
+            // Put sections back the way they were before the search
+            let header = if self.settings_search_was_active {
+                header.open(Some(open))
+            } else {
+                header
+            };
+            return (header, Vec::new());
+        }
+
+        let found = search::search_section(title, &self.settings_search);
+        if !found.is_match() {
+            let text = egui::RichText::new(title).weak();
+            return (
+                egui::CollapsingHeader::new(text)
+                    .id_salt(title)
+                    .open(Some(false)),
+                Vec::new(),
+            );
+        }
+
+        let label = if found.controls.is_empty() {
+            title.to_string()
+        } else {
+            format!("{} ({})", title, found.controls.len())
+        };
+        let text = egui::RichText::new(label)
+            .strong()
+            .color(egui::Color32::from_rgb(255, 200, 80));
+        (
+            egui::CollapsingHeader::new(text)
+                .id_salt(title)
+                .open(Some(true)),
+            found.controls,
+        )
+    }
+
+    /// Open state to remember for a section. Openness forced by the settings
+    /// search isn't saved, so clearing the search restores the user's layout.
+    fn section_open_state(&self, current: bool, openness: f32) -> bool {
+        if self.settings_search.trim().is_empty() {
+            openness > 0.0
+        } else {
+            current
+        }
+    }
+
+    /// Small ↺ button over the right end of a section header that resets the
+    /// section's parameters to the current fractal's defaults. Returns true
+    /// when clicked; the change goes into history like any other edit.
+    fn section_reset_button(
+        &mut self,
+        ui: &egui::Ui,
+        header: &egui::Response,
+        params: &mut FractalParams,
+        section: ParamSection,
+    ) -> bool {
+        let side = header.rect.height();
+        let rect = egui::Rect::from_min_size(
+            egui::pos2(header.rect.right() - side, header.rect.top()),
+            egui::vec2(side, side),
+        );
+        // Registered after the header, so it takes the click instead of toggling it
+        let response = ui
+            .interact(rect, header.id.with("reset_section"), egui::Sense::click())
+            .on_hover_text(format!("Reset {} to defaults", section.name()));
+        let color = if response.hovered() {
+            ui.visuals().strong_text_color()
+        } else {
+            ui.visuals().weak_text_color()
+        };
+        ui.painter().text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            "↺",
+            egui::TextStyle::Button.resolve(ui.style()),
+            color,
+        );
+
+        if !response.clicked() {
+            return false;
+        }
+        params.reset_section(section);
+        self.show_toast(format!("↺ {} reset to defaults", section.name()));
+        true
+    }
+
+    /// Frames to record before stopping by itself, or None to record until
+    /// stopped. Seconds count video time, so a limit is an exact clip length.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn video_frame_limit(&self) -> Option<u32> {
+        if !self.video_auto_stop {
+            return None;
+        }
+        let frames = if self.video_stop_in_frames {
+            self.video_stop_after
+        } else {
+            self.video_stop_after.saturating_mul(self.video_fps)
+        };
+        Some(frames.max(1))
+    }
+
+    /// Color vision deficiency to simulate in a frame, which is a capture
+    /// (screenshot, recording or offscreen render) when `capture` is set
+    pub fn cvd_simulation_for(&self, capture: bool) -> crate::fractal::CvdSimulation {
+        if capture && !self.cvd_in_captures {
+            crate::fractal::CvdSimulation::None
+        } else {
+            self.cvd_simulation
+        }
+    }
+
     #[allow(clippy::type_complexity)]
     pub fn render(
         &mut self,
@@ -425,215 +1124,151 @@ impl UI {
         let mut preset_to_load: Option<Preset> = None;
         let mut hires_render_resolution: Option<(u32, u32)> = None;
         let mut randomize_requested = false;
+        let mut shuffle_colors_requested = false;
         let mut start_recording = false;
         let mut stop_recording = false;
         let mut bookmark_to_load: Option<CameraBookmark> = None;
         let mut gpu_scan_requested = false;
         let mut from_history = false; // Don't save to history if change came from undo/redo
 
-        egui::Window::new("Fractal Controls")
-            .default_width(320.0)
-            .default_height(600.0)
-            .resizable(true)
-            .vscroll(true)
-            .show(ctx, |ui| {
-                // Quick Actions at the top
-                ui.horizontal(|ui| {
-                    if ui.add_enabled(self.can_undo(), egui::Button::new("↶ Undo"))
-                        .on_hover_text("Undo last parameter change (Ctrl+Z)")
-                        .clicked() {
-                        if let Some(prev_params) = self.undo() {
-                            *params = prev_params;
-                            changed = true;
-                            from_history = true;  // Don't save to history
-                        }
-                    }
-                    if ui.add_enabled(self.can_redo(), egui::Button::new("↷ Redo"))
-                        .on_hover_text("Redo parameter change (Ctrl+Y)")
-                        .clicked() {
-                        if let Some(next_params) = self.redo() {
-                            *params = next_params;
-                            changed = true;
-                            from_history = true;  // Don't save to history
-                        }
-                    }
-                    if ui.button("🎲 Randomize")
-                        .on_hover_text("Generate random fractal settings for creative exploration")
-                        .clicked() {
-                        randomize_requested = true;
-                    }
-                });
-
-                // UI Control Actions
-                ui.horizontal(|ui| {
-                    if ui.button("⏏ Hide UI")
-                        .on_hover_text("Hide the control panel [H]")
-                        .clicked() {
-                        self.show_ui = false;
-                    }
-
-                    let theme_icon = if self.dark_theme { "☀" } else { "🌙" };
-                    let theme_text = format!("{} Theme", theme_icon);
-                    if ui.button(theme_text)
-                        .on_hover_text("Toggle between dark and light themes")
-                        .clicked() {
-                        self.dark_theme = !self.dark_theme;
-                    }
-
-                    if ui.button("📷 Capture")
-                        .on_hover_text("Open capture & recording panel")
-                        .clicked() {
-                        self.ui_state.capture_window_open = !self.ui_state.capture_window_open;
+        let panel_dock = self.ui_state.panel_dock;
+        let panel_contents = |ui: &mut egui::Ui| {
+            // Quick Actions at the top
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(self.can_undo(), egui::Button::new("↶ Undo"))
+                    .on_hover_text("Undo last parameter change (Ctrl+Z)")
+                    .clicked()
+                {
+                    if let Some(prev_params) = self.undo() {
+                        *params = prev_params;
+                        changed = true;
+                        from_history = true; // Don't save to history
                     }
-
-                    if ui.button("ℹ About")
-                        .on_hover_text("About Par Fractal")
-                        .clicked() {
-                        self.ui_state.about_window_open = !self.ui_state.about_window_open;
+                }
+                if ui
+                    .add_enabled(self.can_redo(), egui::Button::new("↷ Redo"))
+                    .on_hover_text("Redo parameter change (Ctrl+Y)")
+                    .clicked()
+                {
+                    if let Some(next_params) = self.redo() {
+                        *params = next_params;
+                        changed = true;
+                        from_history = true; // Don't save to history
                     }
-                });
-                ui.separator();
-
-                let response = egui::CollapsingHeader::new("Fractal Type")
-                    .default_open(self.ui_state.fractal_type_open)
-                    .show(ui, |ui| {
-                        let old_type = params.fractal_type;
-                        ui.label("2D Fractals:");
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Mandelbrot2D, "Mandelbrot")
-                                .on_hover_text("Classic Mandelbrot set - infinite detail fractal [1]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Julia2D, "Julia")
-                                .on_hover_text("Julia set - beautiful variations with complex parameter [2]");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Sierpinski2D, "Sierpinski Carpet")
-                                .on_hover_text("Sierpinski carpet - recursive square pattern [3]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::SierpinskiTriangle2D, "Sierpinski Triangle")
-                                .on_hover_text("Sierpinski triangle - classic recursive triangle pattern");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::BurningShip2D, "Burning Ship")
-                                .on_hover_text("Burning Ship fractal - variant with absolute values [4]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Tricorn2D, "Tricorn")
-                                .on_hover_text("Tricorn - Mandelbrot with conjugate iteration [5]");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Phoenix2D, "Phoenix")
-                                .on_hover_text("Phoenix fractal - dynamic iteration algorithm [6]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Celtic2D, "Celtic")
-                                .on_hover_text("Celtic fractal - alternative complex iteration [7]");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Newton2D, "Newton")
-                                .on_hover_text("Newton fractal - polynomial root-finding visualization [8]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Lyapunov2D, "Lyapunov")
-                                .on_hover_text("Lyapunov fractal - stability diagram patterns [9]");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Nova2D, "Nova")
-                                .on_hover_text("Nova fractal - Newton-Mandelbrot hybrid [0]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Magnet2D, "Magnet")
-                                .on_hover_text("Magnet Type 1 - physics-inspired fractal");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Collatz2D, "Collatz")
-                                .on_hover_text("Collatz fractal - based on Collatz conjecture");
-                        });
+                }
+                if ui
+                    .button("🎲 Randomize")
+                    .on_hover_text("Generate random fractal settings for creative exploration")
+                    .clicked()
+                {
+                    randomize_requested = true;
+                }
+                ui.menu_button("🔒", |ui| {
+                    ui.label("Keep when randomizing:");
+                    let locks = &mut self.randomize_locks;
+                    ui.checkbox(&mut locks.fractal_type, "Fractal type");
+                    ui.checkbox(&mut locks.palette, "Palette");
+                    ui.checkbox(&mut locks.color_mode, "Color mode");
+                    ui.checkbox(&mut locks.camera, "Camera")
+                        .on_hover_text("2D view center and zoom");
+                    ui.checkbox(&mut locks.lighting, "Lighting")
+                        .on_hover_text("Light intensity, ambient light and material (3D)");
+                    ui.checkbox(&mut locks.effects, "Effects")
+                        .on_hover_text("Ambient occlusion, shadows, fog and floor (3D)");
+                })
+                .response
+                .on_hover_text("Choose what Randomize leaves unchanged");
+            });
+            if let Some(restored) = self.randomize_gallery_ui(ui) {
+                self.save_to_history(params);
+                *params = restored;
+                changed = true;
+            }
+
+            // UI Control Actions
+            ui.horizontal(|ui| {
+                if ui
+                    .button("⏏ Hide UI")
+                    .on_hover_text("Hide the control panel [H]")
+                    .clicked()
+                {
+                    self.show_ui = false;
+                }
 
-                        ui.separator();
-                        ui.label("2D Density Fractals:");
-                        // Buddhabrot needs accumulation mode enabled
-                        {
-                            let selected = params.fractal_type == FractalType::Buddhabrot2D;
-                            if ui.selectable_label(selected, "Buddhabrot").on_hover_text("Buddhabrot - Mandelbrot escape trajectory density visualization (discovered by Melinda Green, 1993)").clicked() {
-                                params.fractal_type = FractalType::Buddhabrot2D;
-                                params.attractor_accumulation_enabled = true;
-                                params.attractor_pending_clear = true;
-                                params.attractor_total_iterations = 0;
-                                changed = true;
-                            }
-                        }
+                let theme_icon = if self.dark_theme { "☀" } else { "🌙" };
+                let theme_text = format!("{} Theme", theme_icon);
+                if ui
+                    .button(theme_text)
+                    .on_hover_text("Toggle between dark and light themes")
+                    .clicked()
+                {
+                    self.dark_theme = !self.dark_theme;
+                }
 
-                        ui.separator();
-                        ui.label("2D Strange Attractors:");
-                        // Helper macro-like closure to create attractor buttons that auto-enable accumulation
-                        let mut attractor_button = |ui: &mut egui::Ui, fractal: FractalType, label: &str, hover: &str| {
-                            let selected = params.fractal_type == fractal;
-                            if ui.selectable_label(selected, label).on_hover_text(hover).clicked() {
-                                params.fractal_type = fractal;
-                                params.attractor_accumulation_enabled = true;
-                                params.attractor_pending_clear = true;
-                                params.attractor_total_iterations = 0;
-                                changed = true;
-                            }
-                        };
-                        ui.horizontal(|ui| {
-                            attractor_button(ui, FractalType::Hopalong2D, "Hopalong", "Hopalong attractor - intricate web patterns");
-                            attractor_button(ui, FractalType::Martin2D, "Martin", "Martin attractor - spiral/flower patterns");
-                        });
-                        ui.horizontal(|ui| {
-                            attractor_button(ui, FractalType::Gingerbreadman2D, "Gingerbread", "Gingerbreadman - simple formula, complex output");
-                            attractor_button(ui, FractalType::Chip2D, "Chip", "Chip - log/cos/atan hopalong variant");
-                        });
-                        ui.horizontal(|ui| {
-                            attractor_button(ui, FractalType::Quadruptwo2D, "Quadruptwo", "Quadruptwo - log/sin/atan hopalong variant");
-                            attractor_button(ui, FractalType::Threeply2D, "Threeply", "Threeply - trigonometric hopalong variant");
-                        });
+                if ui
+                    .button("📷 Capture")
+                    .on_hover_text("Open capture & recording panel")
+                    .clicked()
+                {
+                    self.ui_state.capture_window_open = !self.ui_state.capture_window_open;
+                }
 
-                        ui.separator();
-                        ui.label("3D Fractals:");
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Mandelbulb3D, "Mandelbulb")
-                                .on_hover_text("3D Mandelbrot with adjustable power [F1]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::MengerSponge3D, "Menger Sponge")
-                                .on_hover_text("Recursive cubic structure with infinite holes [F2]");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::SierpinskiPyramid3D, "Sierpinski Pyramid")
-                                .on_hover_text("3D Sierpinski pyramid - recursive tetrahedron [F3]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::SierpinskiGasket3D, "Sierpinski Gasket")
-                                .on_hover_text("3D Sierpinski gasket - sphere packing structure");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::JuliaSet3D, "Julia 3D")
-                                .on_hover_text("3D Julia set with quaternion math [F4]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Mandelbox3D, "Mandelbox")
-                                .on_hover_text("Cubic folding fractal with sharp edges [F5]");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::OctahedralIFS3D, "Octahedron IFS")
-                                .on_hover_text("Kaleidoscopic IFS with 8-fold symmetry [F6]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::IcosahedralIFS3D, "Icosahedron IFS")
-                                .on_hover_text("Kaleidoscopic IFS with 20-fold symmetry [F7]");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::ApollonianGasket3D, "Apollonian Gasket")
-                                .on_hover_text("Beautiful sphere-packing fractal [F8]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::Kleinian3D, "Kleinian")
-                                .on_hover_text("Kleinian group fractal [F9]");
-                        });
-                        ui.horizontal(|ui| {
-                            ui.selectable_value(&mut params.fractal_type, FractalType::HybridMandelbulbJulia3D, "Hybrid Bulb-Julia")
-                                .on_hover_text("Mandelbulb and Julia set hybrid [F10]");
-                            ui.selectable_value(&mut params.fractal_type, FractalType::QuaternionCubic3D, "Quaternion Cubic")
-                                .on_hover_text("Cubic quaternion Julia set (z³+c)");
-                        });
+                if ui
+                    .button("∑ Math")
+                    .on_hover_text("Show the formula behind the current fractal")
+                    .clicked()
+                {
+                    self.ui_state.math_info_window_open = !self.ui_state.math_info_window_open;
+                }
 
-                        // NOTE: 3D Strange Attractors disabled - ray marching point clouds
-                        // is too expensive (causes GPU timeout). Requires different rendering
-                        // approach (instanced points or volumetric). See todos.md.
+                if ui
+                    .button("ℹ About")
+                    .on_hover_text("About Par Fractal")
+                    .clicked()
+                {
+                    self.ui_state.about_window_open = !self.ui_state.about_window_open;
+                }
+            });
 
-                        if old_type != params.fractal_type {
-                            params.switch_fractal(params.fractal_type);
-                            changed = true;
-                        }
-                    });
-                self.ui_state.fractal_type_open = response.openness > 0.0;
+            // Settings search: opens sections with matching controls and collapses the rest
+            ui.horizontal(|ui| {
+                ui.label("🔍");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.settings_search)
+                        .hint_text("Search settings..."),
+                )
+                .on_hover_text("Filter the control panel by setting name, e.g. \"fog density\"");
+                if !self.settings_search.is_empty()
+                    && ui.small_button("✖").on_hover_text("Clear search").clicked()
+                {
+                    self.settings_search.clear();
+                }
+            });
+            ui.separator();
+
+            let (header, search_matches) =
+                self.section_header("Fractal Type", self.ui_state.fractal_type_open);
+            let response = header.show(ui, |ui| {
+                search::show_matches(ui, &search_matches);
+                let old_type = params.fractal_type;
+                changed |= self.fractal_type_buttons(ui, params);
+
+                if old_type != params.fractal_type {
+                    params.switch_fractal(params.fractal_type);
+                    self.fractal_switched = true;
+                    changed = true;
+                }
+            });
+            self.ui_state.fractal_type_open =
+                self.section_open_state(self.ui_state.fractal_type_open, response.openness);
 
-                // Preset management section
-                let response = egui::CollapsingHeader::new("Presets")
-                    .default_open(self.ui_state.presets_open)
+            // Preset management section
+            let (header, search_matches) =
+                self.section_header("Presets", self.ui_state.presets_open);
+            let response = header
                     .show(ui, |ui| {
+                        search::show_matches(ui, &search_matches);
                         // Category filter buttons
                         ui.horizontal_wrapped(|ui| {
                             ui.label("Category:");
@@ -648,16 +1283,48 @@ impl UI {
                         });
 
                         // Search/filter box
-                        ui.horizontal(|ui| {
+                        let search_focused = ui.horizontal(|ui| {
                             ui.label("🔍 Search:");
-                            ui.text_edit_singleline(&mut self.preset_search)
-                                .on_hover_text("Filter presets by name or description");
+                            let response = ui.text_edit_singleline(&mut self.preset_search)
+                                .on_hover_text("Filter presets by name or description\nUp/Down select a preset, Enter loads it");
                             if ui.small_button("✖")
                                 .on_hover_text("Clear search")
                                 .clicked() {
                                 self.preset_search.clear();
                             }
+                            // Enter ends editing, so count the frame focus is lost too
+                            response.has_focus() || response.lost_focus()
+                        }).inner;
+
+                        // Tour mode: cycle through the built-in presets
+                        ui.horizontal(|ui| {
+                            let label = if self.tour_active { "⏹ Stop Tour" } else { "▶ Start Tour" };
+                            if ui.button(label)
+                                .on_hover_text("Cycle through the built-in presets in order\nAny key, click or scroll on the fractal stops the tour")
+                                .clicked() {
+                                self.tour_toggle_requested = true;
+                            }
+                            let response = ui.add(egui::Slider::new(&mut self.tour_dwell_secs, 5.0..=300.0)
+                                .logarithmic(true)
+                                .suffix(" s")
+                                .text("Dwell"))
+                                .on_hover_text("How long each preset is shown");
+                            if response.drag_stopped() || (response.changed() && !response.dragged()) {
+                                let mut prefs = crate::fractal::AppPreferences::load();
+                                prefs.tour_dwell_secs = self.tour_dwell_secs;
+                                if let Err(e) = prefs.save() {
+                                    log::error!("Failed to save preferences: {}", e);
+                                }
+                            }
                         });
+                        if let Some(name) = &self.loaded_preset_name {
+                            if ui.button(format!("↺ Revert to '{}'", name))
+                                .on_hover_text("Restore the parameters of the last loaded preset
+The camera stays where it is")
+                                .clicked() {
+                                self.revert_to_preset_requested = true;
+                            }
+                        }
                         ui.separator();
 
                         ui.heading("Built-in Presets");
@@ -685,28 +1352,51 @@ impl UI {
                         if filtered_builtin.is_empty() && !search_lower.is_empty() {
                             ui.label("No matching presets found");
                         } else {
-                            egui::ScrollArea::vertical()
+                            let list_id = ui.make_persistent_id("builtin_presets_list");
+                            let names: Vec<&str> = filtered_builtin.iter().map(|p| p.name.as_str()).collect();
+                            let nav = self.preset_list_keys(ui.ctx(), list_id, search_focused, &names);
+                            if let Some(index) = nav.load {
+                                preset_to_load = Some(filtered_builtin[index].clone());
+                            }
+                            let selection_fill = ui.visuals().selection.bg_fill;
+                            let list = egui::ScrollArea::vertical()
                                 .id_salt("builtin_presets_scroll")
                                 .max_height(800.0)
                                 .show(ui, |ui| {
-                                    for preset in filtered_builtin.iter() {
-                                        ui.horizontal(|ui| {
-                                            if ui.button(&preset.name).clicked() {
-                                                preset_to_load = Some((*preset).clone());
-                                            }
-                                            ui.label(format!("- {}", preset.description));
+                                    for (index, preset) in filtered_builtin.iter().enumerate() {
+                                        let selected = nav.active && index == self.preset_selected;
+                                        let row = egui::Frame::new()
+                                            .fill(if selected { selection_fill } else { egui::Color32::TRANSPARENT })
+                                            .corner_radius(2.0)
+                                            .show(ui, |ui| ui.horizontal(|ui| {
+                                                if ui.button(&preset.name).clicked() {
+                                                    preset_to_load = Some((*preset).clone());
+                                                    // Clicking focuses the list for keyboard navigation
+                                                    self.preset_selected = index;
+                                                    ui.memory_mut(|m| m.request_focus(list_id));
+                                                }
+                                                ui.label(format!("- {}", preset.description));
 
-                                            // Add export button
-                                            if ui.small_button("💾").on_hover_text("Export this preset to JSON").clicked() {
-                                                if let Err(e) = PresetGallery::export_preset_to_json(preset) {
-                                                    eprintln!("Failed to export preset: {}", e);
-                                                } else {
-                                                    log::info!("Preset '{}' exported successfully", preset.name);
+                                                if ui.small_button("Δ").on_hover_text("Compare with the current settings").clicked() {
+                                                    self.compare_preset(preset, params, camera_pos, camera_target);
                                                 }
-                                            }
-                                        });
+
+                                                // Add export button
+                                                if ui.small_button("💾").on_hover_text("Export this preset to JSON").clicked() {
+                                                    if let Err(e) = PresetGallery::export_preset_to_json(preset) {
+                                                        log::error!("Failed to export preset: {}", e);
+                                                    } else {
+                                                        log::info!("Preset '{}' exported successfully", preset.name);
+                                                    }
+                                                }
+                                            })).response;
+                                        if selected && nav.moved {
+                                            row.scroll_to_me(None);
+                                        }
                                     }
                                 });
+                            // Register the list as focusable so it keeps keyboard focus between frames
+                            ui.interact(list.inner_rect, list_id, egui::Sense::focusable_noninteractive());
                         }
 
                         ui.separator();
@@ -739,6 +1429,20 @@ impl UI {
                                 });
                         });
 
+                        // Saving under an existing name replaces that preset
+                        let existing_filename = self.preset_name.replace(|c: char| !c.is_alphanumeric() && c != '_' && c != '-', "_");
+                        if !self.preset_name.is_empty() && self.user_presets.contains(&existing_filename) {
+                            ui.horizontal(|ui| {
+                                ui.colored_label(egui::Color32::YELLOW, "⚠ Replaces the saved preset");
+                                if ui.small_button("Δ Show changes").on_hover_text("List what saving would change in this preset").clicked() {
+                                    match PresetGallery::load_preset(&existing_filename) {
+                                        Ok(preset) => self.compare_preset(&preset, params, camera_pos, camera_target),
+                                        Err(e) => log::error!("Failed to load preset '{}': {}", existing_filename, e),
+                                    }
+                                }
+                            });
+                        }
+
                         if ui.button("Save Preset").clicked() && !self.preset_name.is_empty() {
                             let preset = Preset::from_current(
                                 self.preset_name.clone(),
@@ -753,7 +1457,7 @@ impl UI {
                             let filename = self.preset_name.replace(|c: char| !c.is_alphanumeric() && c != '_' && c != '-', "_");
 
                             if let Err(e) = PresetGallery::save_preset(&preset, &filename) {
-                                eprintln!("Failed to save preset: {}", e);
+                                log::error!("Failed to save preset: {}", e);
                             } else {
                                 // Refresh user presets list
                                 self.user_presets = PresetGallery::list_user_presets().unwrap_or_default();
@@ -786,6 +1490,7 @@ impl UI {
                                 ui.label("No matching user presets found");
                             } else {
                                 let mut refresh_presets = false;
+                                let mut preset_to_compare = None;
                                 egui::ScrollArea::vertical()
                                     .id_salt("user_presets_scroll")
                                     .max_height(150.0)
@@ -793,30 +1498,37 @@ impl UI {
                                         for preset_name in filtered_user.iter() {
                                             ui.horizontal(|ui| {
                                                 if ui.button(*preset_name).clicked() {
-                                                    println!("User preset button clicked: {}", preset_name);
+                                                    log::info!("User preset button clicked: {}", preset_name);
                                                     match PresetGallery::load_preset(preset_name) {
                                                         Ok(preset) => {
-                                                            println!("Preset loaded successfully: {}", preset.name);
+                                                            log::info!("Preset loaded successfully: {}", preset.name);
                                                             preset_to_load = Some(preset);
                                                         }
                                                         Err(e) => {
-                                                            eprintln!("Failed to load preset '{}': {}", preset_name, e);
+                                                            log::error!("Failed to load preset '{}': {}", preset_name, e);
                                                         }
                                                     }
                                                 }
 
+                                                if ui.small_button("Δ").on_hover_text("Compare with the current settings").clicked() {
+                                                    match PresetGallery::load_preset(preset_name) {
+                                                        Ok(preset) => preset_to_compare = Some(preset),
+                                                        Err(e) => log::error!("Failed to load preset '{}': {}", preset_name, e),
+                                                    }
+                                                }
+
                                                 // Add export button
                                                 if ui.small_button("💾").on_hover_text("Export this preset to JSON").clicked() {
                                                     match PresetGallery::load_preset(preset_name) {
                                                         Ok(preset) => {
                                                             if let Err(e) = PresetGallery::export_preset_to_json(&preset) {
-                                                                eprintln!("Failed to export preset: {}", e);
+                                                                log::error!("Failed to export preset: {}", e);
                                                             } else {
                                                                 log::info!("Preset '{}' exported successfully", preset.name);
                                                             }
                                                         }
                                                         Err(e) => {
-                                                            eprintln!("Failed to load preset '{}' for export: {}", preset_name, e);
+                                                            log::error!("Failed to load preset '{}' for export: {}", preset_name, e);
                                                         }
                                                     }
                                                 }
@@ -824,7 +1536,7 @@ impl UI {
                                                 // Add delete button
                                                 if ui.small_button("🗑").on_hover_text("Delete preset").clicked() {
                                                     if let Err(e) = PresetGallery::delete_preset(preset_name) {
-                                                        eprintln!("Failed to delete preset: {}", e);
+                                                        log::error!("Failed to delete preset: {}", e);
                                                     } else {
                                                         refresh_presets = true;
                                                     }
@@ -835,6 +1547,9 @@ impl UI {
                                 if refresh_presets {
                                     self.user_presets = PresetGallery::list_user_presets().unwrap_or_default();
                                 }
+                                if let Some(preset) = preset_to_compare {
+                                    self.compare_preset(&preset, params, camera_pos, camera_target);
+                                }
                             }
                         }
 
@@ -847,32 +1562,65 @@ impl UI {
                             {
                                 let settings = params.to_settings();
                                 if let Err(e) = PresetGallery::export_to_json(&settings, camera_pos.to_array(), camera_target.to_array()) {
-                                    eprintln!("Failed to export settings: {}", e);
+                                    log::error!("Failed to export settings: {}", e);
                                 } else {
-                                    println!("Settings exported successfully");
+                                    log::info!("Settings exported successfully");
                                 }
                             }
 
+                            #[cfg(not(target_arch = "wasm32"))]
                             if ui.button("📤 Import from JSON")
                                 .on_hover_text("Import settings from a JSON file")
                                 .clicked()
                             {
                                 match PresetGallery::import_from_json() {
-                                    Ok(preset) => {
-                                        preset_to_load = Some(preset);
+                                    Ok(import) => {
+                                        for warning in import.warnings {
+                                            self.show_toast(format!("⚠ {}", warning));
+                                        }
+                                        preset_to_load = Some(import.preset);
                                     }
+                                    Err(crate::fractal::PresetImportError::Cancelled) => {}
                                     Err(e) => {
-                                        eprintln!("Failed to import settings: {}", e);
+                                        log::error!("Failed to import settings: {}", e);
+                                        self.show_toast(format!("✗ Import failed: {}", e));
                                     }
                                 }
                             }
                         });
+
+                        if ui.button("📋 Copy Recipe Card")
+                            .on_hover_text("Copy a readable summary of the fractal, palette, camera and effects for sharing in a post")
+                            .clicked()
+                        {
+                            let mut settings = params.to_settings();
+                            settings.camera_position = camera_pos.to_array();
+                            settings.camera_target = camera_target.to_array();
+                            ui.ctx().copy_text(settings.recipe_card());
+                            self.show_toast("📋 Recipe card copied to clipboard".to_string());
+                        }
+
+                        ui.menu_button("🧮 Copy Shader Parameters", |ui| {
+                            ui.label("Copy the values fed to the fractal shader as:");
+                            for &format in crate::renderer::shader_export::ShaderExportFormat::ALL {
+                                if ui.button(format.name()).clicked() {
+                                    self.shader_export_requested = Some(format);
+                                    ui.close();
+                                }
+                            }
+                        })
+                        .response
+                        .on_hover_text("Copy the current uniform values as constants for ShaderToy or a custom shader, named as in fractal.wgsl");
                     });
-                self.ui_state.presets_open = response.openness > 0.0;
+            self.ui_state.presets_open =
+                self.section_open_state(self.ui_state.presets_open, response.openness);
 
-                let response = egui::CollapsingHeader::new("Color & Visualization")
-                    .default_open(self.ui_state.color_viz_open)
+            let mut preview_candidate = None;
+            let (header, search_matches) =
+                self.section_header("Color & Visualization", self.ui_state.color_viz_open);
+            let response = header
                     .show(ui, |ui| {
+                        search::show_matches(ui, &search_matches);
                         ui.label("Color Mode:")
                             .on_hover_text("Choose how colors are applied to the fractal");
                         changed |= egui::ComboBox::from_id_salt("color_mode")
@@ -882,6 +1630,7 @@ impl UI {
                                 crate::fractal::ColorMode::Normals => "Normals (3D)",
                                 crate::fractal::ColorMode::OrbitTrapXYZ => "Orbit Trap XYZ",
                                 crate::fractal::ColorMode::OrbitTrapRadial => "Orbit Trap Radial",
+                                crate::fractal::ColorMode::OrbitTrapImage => "Orbit Trap Image",
                                 crate::fractal::ColorMode::WorldPosition => "World Position",
                                 crate::fractal::ColorMode::LocalPosition => "Local Position",
                                 crate::fractal::ColorMode::AmbientOcclusion => "Ambient Occlusion (3D)",
@@ -901,6 +1650,11 @@ impl UI {
                                 changed_local |= ui.selectable_value(&mut params.color_mode, crate::fractal::ColorMode::Normals, "Normals (3D)").changed();
                                 changed_local |= ui.selectable_value(&mut params.color_mode, crate::fractal::ColorMode::OrbitTrapXYZ, "Orbit Trap XYZ").changed();
                                 changed_local |= ui.selectable_value(&mut params.color_mode, crate::fractal::ColorMode::OrbitTrapRadial, "Orbit Trap Radial").changed();
+                                if params.fractal_type.supports_orbit_trap_image() {
+                                    changed_local |= ui.selectable_value(&mut params.color_mode, crate::fractal::ColorMode::OrbitTrapImage, "Orbit Trap Image")
+                                        .on_hover_text("Paint the fractal with an image sampled where orbits land on it")
+                                        .changed();
+                                }
                                 changed_local |= ui.selectable_value(&mut params.color_mode, crate::fractal::ColorMode::WorldPosition, "World Position").changed();
                                 changed_local |= ui.selectable_value(&mut params.color_mode, crate::fractal::ColorMode::LocalPosition, "Local Position").changed();
                                 changed_local |= ui.selectable_value(&mut params.color_mode, crate::fractal::ColorMode::AmbientOcclusion, "Ambient Occlusion (3D)").changed();
@@ -934,6 +1688,52 @@ impl UI {
                             })
                             .inner.unwrap_or(false);
 
+                        if params.fractal_type.has_interior_coloring() {
+                            ui.horizontal(|ui| {
+                                ui.label("Interior:")
+                                    .on_hover_text("Coloring for points that never escape (solid black by default)");
+                                egui::ComboBox::from_id_salt("interior_color_mode")
+                                    .selected_text(params.interior_color_mode.name())
+                                    .show_ui(ui, |ui| {
+                                        for &mode in crate::fractal::InteriorColorMode::ALL {
+                                            if mode.applies_to(params.fractal_type) {
+                                                changed |= ui.selectable_value(&mut params.interior_color_mode, mode, mode.name()).changed();
+                                            }
+                                        }
+                                    })
+                                    .response
+                                    .on_hover_text(
+                                        "Final |z|: magnitude after the last iteration\n\
+                                         Average |z|: mean magnitude over the orbit\n\
+                                         Period: length of the cycle the orbit settles into\n\
+                                         Distance: smooth distance to the boundary (Mandelbrot)",
+                                    );
+                            });
+                            if params.interior_color_mode == crate::fractal::InteriorColorMode::Distance
+                                && params.interior_color_mode.applies_to(params.fractal_type)
+                            {
+                                changed |= ui.checkbox(&mut params.interior_distance_lighting, "Interior Lighting")
+                                    .on_hover_text("Light the distance field like a raised surface for a subtle 3D look\nThree distance estimates per pixel, so slower")
+                                    .changed();
+                            }
+                            changed |= ui.checkbox(&mut params.distance_estimation_2d, "Distance Estimation Edges")
+                                .on_hover_text("Darken the exterior toward the boundary using the orbit's derivative.\nGives crisp, unbroken filaments at one sample per pixel.")
+                                .changed();
+                        }
+
+                        if params.render_mode == crate::fractal::RenderMode::ThreeD {
+                            changed |= ui.checkbox(&mut params.show_distance_contours, "Distance Contours")
+                                .on_hover_text("Draw dark topographic lines where the ray's marched distance crosses\nmultiples of the spacing, multiplied over the normal shading")
+                                .changed();
+                            if params.show_distance_contours {
+                                changed |= ui.add(egui::Slider::new(&mut params.distance_contour_spacing, 0.005..=2.0)
+                                    .logarithmic(true)
+                                    .text("Contour Spacing"))
+                                    .on_hover_text("Ray distance between contour lines, in world units")
+                                    .changed();
+                            }
+                        }
+
                         // Show color key for debug visualization modes
                         match params.color_mode {
                             crate::fractal::ColorMode::DistanceField => {
@@ -1046,11 +1846,24 @@ impl UI {
                             _ => {}
                         }
 
+                        // Live thumbnail of the fractal with the current (or hovered) palette
+                        if let Some(texture) = &self.palette_preview_texture {
+                            ui.separator();
+                            ui.label("Preview:")
+                                .on_hover_text("The current view with this palette and color mode\nHover a palette button or entry to try it before applying");
+                            ui.image(texture);
+                        }
+
                         // Show palette controls for modes that use the palette
                         if params.color_mode == crate::fractal::ColorMode::Palette ||
                            params.color_mode == crate::fractal::ColorMode::OrbitTrapXYZ ||
                            params.color_mode == crate::fractal::ColorMode::OrbitTrapRadial {
                             ui.separator();
+                            if ui.button("🎲 Shuffle Colors")
+                                .on_hover_text("Switch to a random palette without changing the fractal [C]\nShift+C also picks a random color mode")
+                                .clicked() {
+                                shuffle_colors_requested = true;
+                            }
                             // Procedural Palette Selection
                             ui.label("Procedural Palette:")
                                 .on_hover_text("Choose a mathematically-generated palette for smooth gradients");
@@ -1058,17 +1871,31 @@ impl UI {
                                 .selected_text(params.procedural_palette.name())
                                 .show_ui(ui, |ui| {
                                     let mut ch = false;
-                                    ch |= ui.selectable_value(
+                                    let response = ui.selectable_value(
                                         &mut params.procedural_palette,
                                         crate::fractal::ProceduralPalette::None,
                                         "None (Static)"
-                                    ).on_hover_text("Use the static color palette below").changed();
+                                    ).on_hover_text("Use the static color palette below");
+                                    if response.hovered() {
+                                        preview_candidate = Some(PalettePreviewCandidate {
+                                            palette_index: params.palette_index,
+                                            procedural_palette: crate::fractal::ProceduralPalette::None,
+                                        });
+                                    }
+                                    ch |= response.changed();
                                     for palette in crate::fractal::ProceduralPalette::ALL {
-                                        ch |= ui.selectable_value(
+                                        let response = ui.selectable_value(
                                             &mut params.procedural_palette,
                                             *palette,
                                             palette.name()
-                                        ).changed();
+                                        );
+                                        if response.hovered() {
+                                            preview_candidate = Some(PalettePreviewCandidate {
+                                                palette_index: params.palette_index,
+                                                procedural_palette: *palette,
+                                            });
+                                        }
+                                        ch |= response.changed();
                                     }
                                     ch
                                 })
@@ -1134,14 +1961,29 @@ impl UI {
                                 ui.separator();
                                 ui.label("Static Palette:")
                                     .on_hover_text("Choose from built-in color palettes [P to cycle]");
+                                let palette_count = ColorPalette::ALL.len();
                                 ui.horizontal(|ui| {
-                                    if ui.button("◀ Previous").on_hover_text("Switch to previous palette").clicked() {
+                                    let response = ui.button("◀ Previous").on_hover_text("Switch to previous palette");
+                                    if response.hovered() {
+                                        preview_candidate = Some(PalettePreviewCandidate {
+                                            palette_index: (params.palette_index + palette_count - 1) % palette_count,
+                                            procedural_palette: crate::fractal::ProceduralPalette::None,
+                                        });
+                                    }
+                                    if response.clicked() {
                                         params.prev_palette();
                                         self.show_toast(format!("Palette: {}", params.palette.name));
                                         changed = true;
                                     }
                                     ui.label(params.palette.name);
-                                    if ui.button("Next ▶").on_hover_text("Switch to next palette [P]").clicked() {
+                                    let response = ui.button("Next ▶").on_hover_text("Switch to next palette [P]");
+                                    if response.hovered() {
+                                        preview_candidate = Some(PalettePreviewCandidate {
+                                            palette_index: (params.palette_index + 1) % palette_count,
+                                            procedural_palette: crate::fractal::ProceduralPalette::None,
+                                        });
+                                    }
+                                    if response.clicked() {
                                         params.next_palette();
                                         self.show_toast(format!("Palette: {}", params.palette.name));
                                         changed = true;
@@ -1191,18 +2033,80 @@ impl UI {
 
                             // Show orbit trap scale slider for orbit trap modes
                             if params.color_mode == crate::fractal::ColorMode::OrbitTrapXYZ ||
-                               params.color_mode == crate::fractal::ColorMode::OrbitTrapRadial {
+                               params.color_mode == crate::fractal::ColorMode::OrbitTrapRadial ||
+                               params.color_mode == crate::fractal::ColorMode::OrbitTrapImage {
                                 ui.separator();
                                 changed |= ui.add(egui::Slider::new(&mut params.orbit_trap_scale, 0.1..=5.0)
                                     .text("Orbit Trap Scale"))
                                     .on_hover_text("Scale factor for orbit trap coloring - affects color variation")
                                     .changed();
-                            }
 
-                            // Per-Channel Controls
-                            if params.color_mode == crate::fractal::ColorMode::PerChannel {
-                                ui.separator();
-                                ui.label("Channel Mapping:")
+                                ui.horizontal(|ui| {
+                                    ui.label("Trap Offset:").on_hover_text(
+                                        "Point the orbit trap measures from\nMoving or animating it reshapes the color pattern",
+                                    );
+                                    if ui
+                                        .small_button("Origin")
+                                        .on_hover_text("Move the trap point back to the origin")
+                                        .clicked()
+                                    {
+                                        params.orbit_trap_offset = Vec3::ZERO;
+                                        changed = true;
+                                    }
+                                });
+                                let axes = match params.render_mode {
+                                    crate::fractal::RenderMode::TwoD => 2,
+                                    crate::fractal::RenderMode::ThreeD => 3,
+                                };
+                                for (axis, value) in ["X", "Y", "Z"]
+                                    .into_iter()
+                                    .zip(params.orbit_trap_offset.as_mut().iter_mut())
+                                    .take(axes)
+                                {
+                                    changed |= ui
+                                        .add(
+                                            egui::Slider::new(value, -2.0..=2.0)
+                                                .text(format!("Trap Offset {}", axis)),
+                                        )
+                                        .changed();
+                                }
+                            }
+
+                            // Trap image selection
+                            if params.color_mode == crate::fractal::ColorMode::OrbitTrapImage {
+                                let image_name = params.orbit_trap_image_path.as_deref()
+                                    .and_then(|path| std::path::Path::new(path).file_name())
+                                    .map(|name| name.to_string_lossy().to_string())
+                                    .unwrap_or_else(|| "Built-in (hue wheel)".to_string());
+                                ui.label(format!("Trap Image: {}", image_name));
+                                ui.horizontal(|ui| {
+                                    #[cfg(not(target_arch = "wasm32"))]
+                                    if ui.button("🖼 Load Image")
+                                        .on_hover_text("Pick a PNG image to use as the orbit trap\nTransparent pixels let orbits pass through")
+                                        .clicked()
+                                    {
+                                        let file_dialog = rfd::FileDialog::new()
+                                            .add_filter("PNG image", &["png"])
+                                            .set_title("Load Orbit Trap Image");
+                                        if let Some(path) = file_dialog.pick_file() {
+                                            params.orbit_trap_image_path = Some(path.to_string_lossy().to_string());
+                                            changed = true;
+                                        }
+                                    }
+                                    if params.orbit_trap_image_path.is_some() && ui.button("Use Default")
+                                        .on_hover_text("Switch back to the built-in trap image")
+                                        .clicked()
+                                    {
+                                        params.orbit_trap_image_path = None;
+                                        changed = true;
+                                    }
+                                });
+                            }
+
+                            // Per-Channel Controls
+                            if params.color_mode == crate::fractal::ColorMode::PerChannel {
+                                ui.separator();
+                                ui.label("Channel Mapping:")
                                     .on_hover_text("Map different data sources to R, G, and B channels");
 
                                 // Red channel
@@ -1391,6 +2295,55 @@ impl UI {
                                     }
                                 });
 
+                                #[cfg(not(target_arch = "wasm32"))]
+                                if ui.button("🖼 Extract from Image")
+                                    .on_hover_text("Pick a PNG or JPEG image and extract its 8 most representative colors")
+                                    .clicked()
+                                {
+                                    let file_dialog = rfd::FileDialog::new()
+                                        .add_filter("Image", &["png", "jpg", "jpeg"])
+                                        .set_title("Extract Palette from Image");
+                                    if let Some(path) = file_dialog.pick_file() {
+                                        match CustomPalette::from_image_file(&path) {
+                                            Ok(extracted) => {
+                                                self.custom_palette_colors = extracted.colors;
+                                                if self.custom_palette_name.is_empty() {
+                                                    self.custom_palette_name = extracted.name.clone();
+                                                }
+                                                self.palette_import_message = Some(format!("✓ Extracted palette from '{}'", extracted.name));
+                                            }
+                                            Err(e) => {
+                                                self.palette_import_message = Some(format!("✗ Error: {}", e));
+                                            }
+                                        }
+                                    }
+                                }
+
+                                #[cfg(not(target_arch = "wasm32"))]
+                                if ui.button("📋 Paste Palette from Clipboard")
+                                    .on_hover_text("Load hex colors (#RRGGBB, comma or newline separated) copied from a palette website.\nMore than 8 colors are resampled to 8, fewer are blended into a gradient.")
+                                    .clicked()
+                                {
+                                    match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.get_text()) {
+                                        Ok(text) => match CustomPalette::from_hex_list(&text, "Pasted".to_string()) {
+                                            Ok((pasted, count)) => {
+                                                self.custom_palette_colors = pasted.colors;
+                                                self.palette_import_message = Some(if count > 8 {
+                                                    format!("✓ Pasted {} colors (resampled to 8)", count)
+                                                } else {
+                                                    format!("✓ Pasted {} colors", count)
+                                                });
+                                            }
+                                            Err(e) => {
+                                                self.palette_import_message = Some(format!("✗ Error: {}", e));
+                                            }
+                                        },
+                                        Err(e) => {
+                                            self.palette_import_message = Some(format!("✗ Could not read clipboard: {}", e));
+                                        }
+                                    }
+                                }
+
                                 // Show import status message
                                 if let Some(ref msg) = self.palette_import_message {
                                     ui.label(msg);
@@ -1402,6 +2355,8 @@ impl UI {
                                     .on_hover_text("First line: JASC-PAL, Second: 0100, Third: color count, then RGB values (0-255)");
                                 ui.label("  • Plain text: One RGB per line")
                                     .on_hover_text("Format: R G B (space or comma separated, 0-255 or 0.0-1.0)");
+                                ui.label("  • Clipboard: Hex colors")
+                                    .on_hover_text("Format: #RRGGBB or RRGGBB, comma, semicolon or newline separated");
 
                                 // Refresh custom palette list periodically
                                 if self.last_custom_palette_list_update.elapsed().as_secs() > 2 {
@@ -1452,22 +2407,24 @@ impl UI {
                             });
                         }
                     });
-                self.ui_state.color_viz_open = response.openness > 0.0;
-
-                match params.render_mode {
-                    crate::fractal::RenderMode::TwoD => {
-                        let response = egui::CollapsingHeader::new("2D Parameters")
-                            .default_open(self.ui_state.params_2d_open)
+            self.ui_state.color_viz_open =
+                self.section_open_state(self.ui_state.color_viz_open, response.openness);
+            if self.section_reset_button(ui, &response.header_response, params, ParamSection::Color)
+            {
+                changed = true;
+            }
+            self.palette_preview_candidate = preview_candidate;
+
+            match params.render_mode {
+                crate::fractal::RenderMode::TwoD => {
+                    let (header, search_matches) =
+                        self.section_header("2D Parameters", self.ui_state.params_2d_open);
+                    let response = header
                             .show(ui, |ui| {
-                                // Hide iterations slider for Collatz (doesn't affect it)
-                                // Hide max iterations for strange attractors (they use accumulation mode)
-                                // Buddhabrot needs higher range for max iterations
-                                if params.fractal_type != FractalType::Collatz2D && !params.fractal_type.is_2d_attractor() {
-                                    let max_iter_range = if params.fractal_type.is_buddhabrot() {
-                                        1..=10000 // Buddhabrot needs higher iterations for detail
-                                    } else {
-                                        1..=1024
-                                    };
+                                search::show_matches(ui, &search_matches);
+                                // No iterations slider for strange attractors (they use
+                                // accumulation mode); the range is per type
+                                if let Some(max_iter_range) = params.fractal_type.max_iterations_range() {
                                     changed |= ui.add(egui::Slider::new(&mut params.max_iterations, max_iter_range)
                                         .text("Max Iterations")
                                         .logarithmic(true))
@@ -1491,21 +2448,93 @@ impl UI {
                                         .changed();
                                 }
 
+                                if params.fractal_type == FractalType::Newton2D {
+                                    ui.label("Polynomial Roots:")
+                                        .on_hover_text("Newton's method finds the roots of p(z) = (z - r1)(z - r2)...\nEach basin is colored by the root it converges to");
+                                    let mut degree = params.newton_roots.len();
+                                    if ui.add(egui::Slider::new(&mut degree, 2..=crate::fractal::NEWTON_MAX_ROOTS)
+                                        .text("Degree"))
+                                        .on_hover_text("Polynomial degree - resets roots to z^n - 1")
+                                        .changed() {
+                                        params.newton_roots = crate::fractal::newton_unity_roots(degree);
+                                        changed = true;
+                                    }
+                                    changed |= newton_root_editor(ui, &mut params.newton_roots);
+                                    ui.horizontal(|ui| {
+                                        ui.label("Drag roots to reshape");
+                                        if ui.small_button("Reset Roots")
+                                            .on_hover_text("Place the roots back on the unit circle (z^n - 1)")
+                                            .clicked() {
+                                            params.newton_roots = crate::fractal::newton_unity_roots(params.newton_roots.len());
+                                            changed = true;
+                                        }
+                                    });
+                                }
+
                                 if params.fractal_type == FractalType::Julia2D {
                                     ui.label("Julia Constant (C):")
                                         .on_hover_text("The complex constant used in Julia set formula");
-                                    changed |= ui.add(egui::Slider::new(&mut params.julia_c[0], -2.0..=2.0)
-                                        .text("Real"))
-                                        .on_hover_text("Real component of Julia constant")
+                                    changed |= julia_constant_editor(
+                                        ui,
+                                        &mut params.julia_c,
+                                        &mut self.julia_polar_input,
+                                        "Julia constant",
+                                    );
+                                }
+
+                                if params.fractal_type == FractalType::Phoenix2D {
+                                    ui.label("Phoenix Distortion (p):")
+                                        .on_hover_text("Complex coefficient on the previous iterate:\nz(n+1) = z(n)^power + c + p·z(n-1)");
+                                    changed |= ui.add(egui::Slider::new(&mut params.phoenix_p[0], -2.0..=2.0)
+                                        .text("p Real"))
+                                        .on_hover_text("Real part of p - 0.5667 gives the classic Phoenix")
                                         .changed();
-                                    changed |= ui.add(egui::Slider::new(&mut params.julia_c[1], -2.0..=2.0)
-                                        .text("Imaginary"))
-                                        .on_hover_text("Imaginary component of Julia constant")
+                                    changed |= ui.add(egui::Slider::new(&mut params.phoenix_p[1], -2.0..=2.0)
+                                        .text("p Imaginary"))
+                                        .on_hover_text("Imaginary part of p - twists the feathers into spirals")
                                         .changed();
+                                    if ui.small_button("Reset p")
+                                        .on_hover_text("Back to the classic p = 0.5667")
+                                        .clicked() {
+                                        params.phoenix_p = crate::fractal::DEFAULT_PHOENIX_P;
+                                        changed = true;
+                                    }
                                 }
 
-                                ui.label(format!("Center: ({:.6}, {:.6})", params.center_2d[0], params.center_2d[1]))
-                                    .on_hover_text("Current view center (drag to pan)");
+                                if params.fractal_type == FractalType::Collatz2D {
+                                    ui.label("Collatz Map (odd branch m·n + d):")
+                                        .on_hover_text("The continuous Collatz map blends n/2 for even n with m·n + d for odd n\nm = 3, d = 1 is the classic 3n + 1 problem");
+                                    changed |= ui.add(egui::Slider::new(&mut params.collatz_params[0], 1.0..=7.0)
+                                        .text("Multiplier (m)"))
+                                        .on_hover_text("Odd-branch multiplier, 1.0 to 7.0 (classic 3)\nOdd integers keep integer orbits; in-between values morph the bulbs and filaments")
+                                        .changed();
+                                    changed |= ui.add(egui::Slider::new(&mut params.collatz_params[1], -2.0..=2.0)
+                                        .text("Offset (d)"))
+                                        .on_hover_text("Odd-branch offset, -2.0 to 2.0 (classic 1)\nShifts the fixed points; 0 makes the origin a fixed point")
+                                        .changed();
+                                    if ui.small_button("Reset Map")
+                                        .on_hover_text("Back to the classic 3n + 1")
+                                        .clicked() {
+                                        params.collatz_params = crate::fractal::DEFAULT_COLLATZ_PARAMS;
+                                        changed = true;
+                                    }
+                                }
+
+                                ui.horizontal(|ui| {
+                                    ui.label("Center:")
+                                        .on_hover_text("Current view center (drag the view to pan, or click a value to type it)");
+                                    // Enough places to move by a fraction of a pixel at the current zoom
+                                    let decimals = (params.zoom_2d.max(1.0).log10().ceil() as usize + 6).min(15);
+                                    let speed = 0.005 / params.zoom_2d.max(f32::MIN_POSITIVE) as f64;
+                                    for (axis, prefix) in [(0, "x: "), (1, "y: ")] {
+                                        changed |= ui.add(egui::DragValue::new(&mut params.center_2d[axis])
+                                            .prefix(prefix)
+                                            .speed(speed)
+                                            .min_decimals(decimals)
+                                            .max_decimals(decimals))
+                                            .changed();
+                                    }
+                                });
                                 ui.label(format!("Zoom: {:.4}", params.zoom_2d))
                                     .on_hover_text("Current zoom level (scroll to zoom)");
                                 if ui.button("Reset View").on_hover_text("Reset center and zoom [R]").clicked() {
@@ -1519,60 +2548,7 @@ impl UI {
                                     // Ensure accumulation is always enabled for these fractal types
                                     params.attractor_accumulation_enabled = true;
 
-                                    ui.separator();
-                                    ui.label("🎯 Accumulation Settings");
-
-                                    changed |= ui.add(egui::Slider::new(&mut params.attractor_iterations_per_frame, 1_000..=500_000)
-                                        .text("Iterations/Frame")
-                                        .logarithmic(true))
-                                        .on_hover_text("Number of orbit iterations per frame\nLower = better FPS, Higher = faster accumulation")
-                                        .changed();
-
-                                    changed |= ui.add(egui::Slider::new(&mut params.attractor_log_scale, 0.5..=6.0)
-                                        .text("Density Scale"))
-                                        .on_hover_text("Controls saturation point (hits needed for white)\n0.5 = ~30 hits, 1.0 = ~100, 2.0 = ~1000, 3.0 = ~10k, 4.0 = ~100k")
-                                        .changed();
-
-                                    // Format numbers with commas
-                                    let format_with_commas = |n: u64| -> String {
-                                        let s = n.to_string();
-                                        let mut result = String::new();
-                                        for (i, c) in s.chars().rev().enumerate() {
-                                            if i > 0 && i % 3 == 0 {
-                                                result.push(',');
-                                            }
-                                            result.push(c);
-                                        }
-                                        result.chars().rev().collect()
-                                    };
-
-                                    ui.label(format!("Total: {} / {}",
-                                        format_with_commas(params.attractor_total_iterations),
-                                        format_with_commas(params.attractor_max_iterations)));
-
-                                    ui.horizontal(|ui| {
-                                        ui.label("Max:");
-                                        let mut max_millions = (params.attractor_max_iterations / 1_000_000) as u32;
-                                        if ui.add(egui::DragValue::new(&mut max_millions)
-                                            .range(1..=100)
-                                            .suffix("M"))
-                                            .on_hover_text("Maximum iterations before auto-pause (in millions)")
-                                            .changed() {
-                                            params.attractor_max_iterations = max_millions as u64 * 1_000_000;
-                                        }
-                                    });
-
-                                    ui.horizontal(|ui| {
-                                        let pause_text = if params.attractor_paused { "▶ Resume" } else { "⏸ Pause" };
-                                        if ui.button(pause_text).on_hover_text("Pause/resume accumulation").clicked() {
-                                            params.attractor_paused = !params.attractor_paused;
-                                        }
-                                        if ui.button("Clear").on_hover_text("Reset accumulated density").clicked() {
-                                            params.attractor_pending_clear = true;
-                                            params.attractor_total_iterations = 0;
-                                            changed = true;
-                                        }
-                                    });
+                                    changed |= accumulation::accumulation_settings(ui, params);
 
                                     // Attractor-specific parameter controls
                                     ui.separator();
@@ -1660,127 +2636,280 @@ impl UI {
                                         changed = true;
                                     }
                                 }
-                            });
-                        self.ui_state.params_2d_open = response.openness > 0.0;
-                    }
-                    crate::fractal::RenderMode::ThreeD => {
-                        let response = egui::CollapsingHeader::new("3D Parameters")
-                            .default_open(self.ui_state.params_3d_open)
-                            .show(ui, |ui| {
-                                // Scale control for all 3D fractals
-                                ui.label("Fractal Shape:")
-                                    .on_hover_text("Control the size and proportions of the fractal");
-                                changed |= ui.add(egui::Slider::new(&mut params.fractal_scale, 0.5..=5.0)
-                                    .text("Scale"))
-                                    .on_hover_text("Overall size of the fractal structure")
-                                    .changed();
 
-                                // Mandelbulb-specific parameters
-                                if params.fractal_type == FractalType::Mandelbulb3D {
-                                    changed |= ui.add(egui::Slider::new(&mut params.power, 2.0..=16.0)
-                                        .text("Power"))
-                                        .on_hover_text("Mandelbulb power (8 is classic, higher = more detail)")
+                                if !params.fractal_type.uses_accumulation() {
+                                    ui.separator();
+                                    if ui.checkbox(&mut params.relief_3d, "3D Relief")
+                                        .on_hover_text("Render the fractal as a lit 3D landscape, with the iteration count as elevation
+Uses the 3D camera, lighting, shadows and shading; the relief covers the current 2D view")
+                                        .changed()
+                                    {
+                                        params.set_relief(params.relief_3d);
+                                        self.relief_view_requested = params.relief_3d;
+                                        changed = true;
+                                    }
+                                    let periodic = params.fractal_type.tile_cell().is_some();
+                                    changed |= ui.add_enabled(periodic, egui::Checkbox::new(&mut params.seamless_tile, "Seamless Tile"))
+                                        .on_hover_text("Render whole periodic cells so the image tiles without seams (e.g. as a tiled wallpaper)\nReplaces the pan/zoom view; cells stretch slightly unless the output's aspect ratio is a whole multiple of the cell's")
+                                        .on_disabled_hover_text("Only periodic fractals tile seamlessly (Sierpinski Carpet and Triangle)")
                                         .changed();
-                                }
-
-                                // Julia 3D-specific parameters
-                                if params.fractal_type == FractalType::JuliaSet3D {
-                                    ui.label("Julia Constant (C):")
-                                        .on_hover_text("Quaternion constant for 3D Julia set");
-                                    changed |= ui.add(egui::Slider::new(&mut params.julia_c[0], -2.0..=2.0)
-                                        .text("Real"))
-                                        .on_hover_text("Real component of quaternion constant")
+                                    changed |= ui.checkbox(&mut params.high_precision_2d, "High-Precision Coordinates")
+                                        .on_hover_text("Compute each pixel's coordinate in double precision on the CPU\nRemoves jitter when panning at moderate zoom; Mandelbrot, Julia, Sierpinski and Burning Ship also iterate in double-float (slower)")
                                         .changed();
-                                    changed |= ui.add(egui::Slider::new(&mut params.julia_c[1], -2.0..=2.0)
-                                        .text("Imaginary"))
-                                        .on_hover_text("Imaginary component of quaternion constant")
+                                    changed |= ui.checkbox(&mut params.edge_aa_2d, "Boundary Supersampling")
+                                        .on_hover_text("Re-render pixels on the fractal boundary with extra samples in a second pass\nSmooths the jagged edge of the set for a fraction of the cost of supersampling the whole image")
                                         .changed();
+                                    if params.edge_aa_2d {
+                                        ui.indent("edge_aa_2d", |ui| {
+                                            changed |= ui.add(egui::Slider::new(&mut params.edge_aa_grid, 2..=4)
+                                                .text("Samples")
+                                                .custom_formatter(|n, _| format!("{0}×{0}", n)))
+                                                .on_hover_text("Sample grid per boundary pixel")
+                                                .changed();
+                                            changed |= ui.add(egui::Slider::new(&mut params.edge_aa_threshold, 0.01..=0.5)
+                                                .text("Threshold"))
+                                                .on_hover_text("Color difference from a neighbouring pixel that marks a boundary pixel\nLower values refine more pixels")
+                                                .changed();
+                                        });
+                                    }
+                                    if ui.checkbox(&mut self.show_iteration_readout, "Show Iteration Readout on Hover")
+                                        .on_hover_text("Show the coordinate and escape iteration count of the point under the cursor")
+                                        .changed() {
+                                        self.ui_state.show_iteration_readout = self.show_iteration_readout;
+                                    }
                                 }
 
-                                // Iterations control for specific 3D fractals
-                                if matches!(params.fractal_type,
-                                    FractalType::MengerSponge3D |
-                                    FractalType::SierpinskiPyramid3D) {
-                                    changed |= ui.add(egui::Slider::new(&mut params.max_iterations, 1..=20)
-                                        .text("Iterations"))
-                                        .on_hover_text("Recursion depth (higher = more detail and smaller features)")
-                                        .changed();
+                                ui.separator();
+                                ui.label("Bookmarks:")
+                                    .on_hover_text("Save and restore 2D views (center and zoom)");
+                                if let Some(bookmark) = self.bookmark_controls(ui, params, camera_pos, camera_target) {
+                                    bookmark_to_load = Some(bookmark);
                                 }
+                            });
+                    self.ui_state.params_2d_open =
+                        self.section_open_state(self.ui_state.params_2d_open, response.openness);
+                    if self.section_reset_button(
+                        ui,
+                        &response.header_response,
+                        params,
+                        ParamSection::Params2D,
+                    ) {
+                        changed = true;
+                    }
+                }
+                crate::fractal::RenderMode::ThreeD => {
+                    let (header, search_matches) =
+                        self.section_header("3D Parameters", self.ui_state.params_3d_open);
+                    let response = header.show(ui, |ui| {
+                        search::show_matches(ui, &search_matches);
+                        if params.fractal_type.is_3d_attractor() {
+                            changed |= accumulation::attractor_3d_controls(ui, params);
+                            return;
+                        }
+                        if params.relief_active() {
+                            changed |= self.relief_controls(ui, params);
+                            return;
+                        }
+                        // Scale control for all 3D fractals
+                        ui.label("Fractal Shape:")
+                            .on_hover_text("Control the size and proportions of the fractal");
+                        changed |= ui
+                            .add(
+                                egui::Slider::new(&mut params.fractal_scale, 0.5..=5.0)
+                                    .text("Scale"),
+                            )
+                            .on_hover_text("Overall size of the fractal structure")
+                            .changed();
 
-                                if params.fractal_type == FractalType::QuaternionCubic3D {
-                                    changed |= ui.add(egui::Slider::new(&mut params.max_iterations, 1..=64)
-                                        .text("Iterations"))
-                                        .on_hover_text("Number of quaternion iterations (higher = more detail, slower)")
-                                        .changed();
-                                }
+                        // Mandelbulb-specific parameters
+                        if params.fractal_type == FractalType::Mandelbulb3D {
+                            changed |= ui
+                                .add(egui::Slider::new(&mut params.power, 2.0..=16.0).text("Power"))
+                                .on_hover_text(
+                                    "Mandelbulb power (8 is classic, higher = more detail)",
+                                )
+                                .changed();
+                        }
 
-                                // Advanced fractal shape controls
-                                if matches!(params.fractal_type,
-                                    FractalType::Mandelbox3D |
-                                    FractalType::OctahedralIFS3D |
-                                    FractalType::IcosahedralIFS3D |
-                                    FractalType::ApollonianGasket3D) {
-                                    ui.separator();
-                                    ui.label("Advanced Shape:")
-                                        .on_hover_text("Fine-tune fractal geometry with folding parameters");
-                                    changed |= ui.add(egui::Slider::new(&mut params.fractal_fold, 0.1..=3.0)
-                                        .text("Fold"))
-                                        .on_hover_text("Fold strength - affects how space is bent")
-                                        .changed();
+                        // Julia 3D-specific parameters
+                        if params.fractal_type == FractalType::JuliaSet3D {
+                            ui.label("Julia Constant (C):")
+                                .on_hover_text("Quaternion constant for 3D Julia set");
+                            changed |= julia_constant_editor(
+                                ui,
+                                &mut params.julia_c,
+                                &mut self.julia_polar_input,
+                                "quaternion constant",
+                            );
+                        }
 
-                                    // Min Radius for fractals with sphere folding
-                                    if matches!(params.fractal_type,
-                                        FractalType::Mandelbox3D |
-                                        FractalType::ApollonianGasket3D) {
-                                        changed |= ui.add(egui::Slider::new(&mut params.fractal_min_radius, 0.1..=2.0)
-                                            .text("Min Radius"))
-                                            .on_hover_text("Minimum sphere folding radius - affects inner details")
-                                            .changed();
-                                    }
+                        // Iterations control for specific 3D fractals
+                        if let Some(iteration_range) = params.fractal_type.max_iterations_range() {
+                            let hint = if params.fractal_type == FractalType::QuaternionCubic3D {
+                                "Number of quaternion iterations (higher = more detail, slower)"
+                            } else {
+                                "Recursion depth (higher = more detail and smaller features)"
+                            };
+                            changed |= ui
+                                .add(
+                                    egui::Slider::new(&mut params.max_iterations, iteration_range)
+                                        .text("Iterations"),
+                                )
+                                .on_hover_text(hint)
+                                .changed();
+                        }
+
+                        // Advanced fractal shape controls
+                        if matches!(
+                            params.fractal_type,
+                            FractalType::Mandelbox3D
+                                | FractalType::OctahedralIFS3D
+                                | FractalType::IcosahedralIFS3D
+                                | FractalType::ApollonianGasket3D
+                        ) {
+                            ui.separator();
+                            ui.label("Advanced Shape:").on_hover_text(
+                                "Fine-tune fractal geometry with folding parameters",
+                            );
+                            changed |= ui
+                                .add(
+                                    egui::Slider::new(&mut params.fractal_fold, 0.1..=3.0)
+                                        .text("Fold"),
+                                )
+                                .on_hover_text("Fold strength - affects how space is bent")
+                                .changed();
+
+                            // Min Radius for fractals with sphere folding
+                            if matches!(
+                                params.fractal_type,
+                                FractalType::Mandelbox3D | FractalType::ApollonianGasket3D
+                            ) {
+                                changed |= ui
+                                    .add(
+                                        egui::Slider::new(
+                                            &mut params.fractal_min_radius,
+                                            0.1..=2.0,
+                                        )
+                                        .text("Min Radius"),
+                                    )
+                                    .on_hover_text(
+                                        "Minimum sphere folding radius - affects inner details",
+                                    )
+                                    .changed();
+                            }
+                        }
+
+                        // Offset point for the scale step of the kaleidoscopic IFS
+                        if params.fractal_type.uses_ifs_offset() {
+                            ui.separator();
+                            ui.horizontal(|ui| {
+                                ui.label("IFS Offset:").on_hover_text(
+                                    "Point the fractal scales around after each fold\n\
+                                     (1, 1, 1) gives the classic symmetric shape",
+                                );
+                                if ui
+                                    .small_button("Symmetric")
+                                    .on_hover_text("Reset the offset to (1, 1, 1)")
+                                    .clicked()
+                                {
+                                    params.ifs_offset = Vec3::ONE;
+                                    changed = true;
                                 }
                             });
-                        self.ui_state.params_3d_open = response.openness > 0.0;
+                            for (axis, value) in ["X", "Y", "Z"]
+                                .into_iter()
+                                .zip(params.ifs_offset.as_mut().iter_mut())
+                            {
+                                changed |= ui
+                                    .add(
+                                        egui::Slider::new(value, -0.5..=2.5)
+                                            .text(format!("Offset {}", axis)),
+                                    )
+                                    .changed();
+                            }
+                        }
 
-                        let response = egui::CollapsingHeader::new("Ray Marching")
-                            .default_open(self.ui_state.ray_marching_open)
+                        changed |= composite::secondary_fractal_controls(ui, params);
+                    });
+                    self.ui_state.params_3d_open =
+                        self.section_open_state(self.ui_state.params_3d_open, response.openness);
+                    if self.section_reset_button(
+                        ui,
+                        &response.header_response,
+                        params,
+                        ParamSection::Params3D,
+                    ) {
+                        changed = true;
+                    }
+
+                    let (header, search_matches) =
+                        self.section_header("Ray Marching", self.ui_state.ray_marching_open);
+                    let response = header
                             .show(ui, |ui| {
+                                search::show_matches(ui, &search_matches);
                                 changed |= ui.checkbox(&mut params.use_adaptive_step, "Adaptive Step Size")
                                     .on_hover_text("Use distance field for step size (recommended)\nDisable for fixed steps")
                                     .changed();
 
                                 if params.use_adaptive_step {
-                                    changed |= ui.add(egui::Slider::new(&mut params.step_multiplier, 0.1..=2.0)
-                                        .text("Step Multiplier"))
+                                    changed |= precise_slider(ui, &mut params.step_multiplier, 0.1..=2.0, "Step Multiplier", 3, false)
                                         .on_hover_text("Adaptive step multiplier - lower = more accurate but slower")
                                         .changed();
                                 } else {
-                                    changed |= ui.add(egui::Slider::new(&mut params.fixed_step_size, 0.01..=0.5)
-                                        .text("Fixed Step Size"))
+                                    changed |= precise_slider(ui, &mut params.fixed_step_size, 0.01..=0.5, "Fixed Step Size", 4, false)
                                         .on_hover_text("Fixed step size in world units - smaller = more accurate")
                                         .changed();
                                 }
 
-                                changed |= ui.add(egui::Slider::new(&mut params.max_steps, 32..=512)
+                                changed |= ui.add(egui::Slider::new(&mut params.max_steps, crate::fractal::MAX_STEPS_RANGE)
                                     .text("Max Steps"))
                                     .on_hover_text("Maximum ray marching steps - higher = better quality but slower")
                                     .changed();
 
-                                changed |= ui.add(egui::Slider::new(&mut params.min_distance, 0.0001..=0.01)
-                                    .text("Min Distance")
-                                    .logarithmic(true))
+                                changed |= precise_slider(ui, &mut params.min_distance, 0.0001..=0.01, "Min Distance", 5, true)
                                     .on_hover_text("Distance threshold for surface hit detection\nSmaller = finer details")
                                     .changed();
 
+                                changed |= ui.add(egui::Slider::new(&mut params.detail_falloff, 0.0..=4.0)
+                                    .text("Detail Falloff"))
+                                    .on_hover_text("Grow the hit threshold with the size of a pixel at that distance\nKeeps near detail sharp and marches distant surfaces faster\n1 = stop within about a pixel, 0 = fixed Min Distance")
+                                    .changed();
+
                                 changed |= ui.add(egui::Slider::new(&mut params.max_distance, 10.0..=200.0)
                                     .text("Max Distance"))
                                     .on_hover_text("Maximum ray marching distance before giving up")
                                     .changed();
+
+                                ui.add_space(5.0);
+                                let f64_hint = if self.gpu_supports_f64 {
+                                    "Ray march in double precision for high-resolution captures\nPreserves detail in deep close-ups but is much slower\nMandelbulb uses integer powers in this mode"
+                                } else {
+                                    "Not available: this GPU does not support f64 shaders"
+                                };
+                                changed |= ui.add_enabled(
+                                    self.gpu_supports_f64,
+                                    egui::Checkbox::new(&mut params.double_precision_3d, "Double Precision Captures (f64)"),
+                                )
+                                .on_hover_text(f64_hint)
+                                .on_disabled_hover_text(f64_hint)
+                                .changed();
                             });
-                        self.ui_state.ray_marching_open = response.openness > 0.0;
+                    self.ui_state.ray_marching_open =
+                        self.section_open_state(self.ui_state.ray_marching_open, response.openness);
+                    if self.section_reset_button(
+                        ui,
+                        &response.header_response,
+                        params,
+                        ParamSection::RayMarching,
+                    ) {
+                        changed = true;
+                    }
 
-                        let response = egui::CollapsingHeader::new("Camera")
-                            .default_open(self.ui_state.camera_open)
+                    let (header, search_matches) =
+                        self.section_header("Camera", self.ui_state.camera_open);
+                    let response = header
                             .show(ui, |ui| {
+                                search::show_matches(ui, &search_matches);
                                 changed |= ui.add(egui::Slider::new(&mut params.camera_speed, 0.1..=10.0)
                                     .text("Movement Speed"))
                                     .on_hover_text("Camera movement speed for WASD controls")
@@ -1809,6 +2938,22 @@ impl UI {
                                     }
                                 });
 
+                                changed |= ui.checkbox(&mut params.camera_inertia, "Smooth Movement")
+                                    .on_hover_text("Ease into movement and coast to a stop (off = instant start/stop)")
+                                    .changed();
+                                if params.camera_inertia {
+                                    changed |= ui.add(egui::Slider::new(&mut params.camera_acceleration, 0.5..=30.0)
+                                        .logarithmic(true)
+                                        .text("Acceleration"))
+                                        .on_hover_text("How quickly the camera reaches full speed")
+                                        .changed();
+                                    changed |= ui.add(egui::Slider::new(&mut params.camera_damping, 0.5..=30.0)
+                                        .logarithmic(true)
+                                        .text("Damping"))
+                                        .on_hover_text("How quickly the camera coasts to a stop after releasing the keys")
+                                        .changed();
+                                }
+
                                 ui.add_space(5.0);
                                 changed |= ui.add(egui::Slider::new(&mut params.camera_fov, 20.0..=120.0)
                                     .text("Field of View (FOV)"))
@@ -1838,6 +2983,15 @@ impl UI {
                                     }
                                 });
 
+                                ui.separator();
+                                ui.horizontal(|ui| {
+                                    ui.label("Up Axis:")
+                                        .on_hover_text("Which world axis points up for the camera, the floor and the light elevation");
+                                    for axis in [UpAxis::Y, UpAxis::Z] {
+                                        changed |= ui.radio_value(&mut params.up_axis, axis, axis.name()).changed();
+                                    }
+                                });
+
                                 ui.separator();
                                 ui.label("Auto Orbit:")
                                     .on_hover_text("Automatically rotate camera around the fractal [O]");
@@ -1851,6 +3005,27 @@ impl UI {
                                         .changed();
                                 }
 
+                                ui.separator();
+                                ui.label("Stereo 3D:")
+                                    .on_hover_text("Render separate left/right eye views (roughly doubles render cost)");
+                                egui::ComboBox::from_id_salt("stereo_mode")
+                                    .selected_text(params.stereo_mode.name())
+                                    .show_ui(ui, |ui| {
+                                        for mode in [
+                                            crate::fractal::StereoMode::Off,
+                                            crate::fractal::StereoMode::SideBySide,
+                                            crate::fractal::StereoMode::Anaglyph,
+                                        ] {
+                                            changed |= ui.selectable_value(&mut params.stereo_mode, mode, mode.name()).changed();
+                                        }
+                                    });
+                                if params.stereo_mode != crate::fractal::StereoMode::Off {
+                                    changed |= ui.add(egui::Slider::new(&mut params.stereo_eye_separation, 0.0..=0.5)
+                                        .text("Eye Separation"))
+                                        .on_hover_text("Distance between the left and right eye cameras\nLarger = stronger depth effect")
+                                        .changed();
+                                }
+
                                 ui.separator();
                                 if ui.checkbox(&mut self.show_camera_info, "Show Camera Info Overlay")
                                     .on_hover_text("Display camera position and direction on screen")
@@ -1877,109 +3052,169 @@ impl UI {
                                     .on_hover_text("Save and restore camera viewpoints");
 
                                 ui.horizontal(|ui| {
-                                    ui.label("Name:");
-                                    ui.text_edit_singleline(&mut self.bookmark_name);
-                                });
-
-                                if ui.button("📌 Save Bookmark")
-                                    .on_hover_text("Save current camera position")
-                                    .clicked()
-                                    && !self.bookmark_name.is_empty()
-                                {
-                                    let bookmark = CameraBookmark::new(
-                                        self.bookmark_name.clone(),
-                                        camera_pos,
-                                        camera_target,
-                                        params.camera_fov,
-                                    );
-
-                                    // Sanitize filename
-                                    let filename = self.bookmark_name.replace(|c: char| !c.is_alphanumeric() && c != '_' && c != '-', "_");
-
-                                    if let Err(e) = BookmarkGallery::save_bookmark(&bookmark, &filename) {
-                                        eprintln!("Failed to save bookmark: {}", e);
-                                    } else {
-                                        // Refresh bookmark list
-                                        self.bookmarks = BookmarkGallery::list_bookmarks().unwrap_or_default();
-                                        self.bookmark_name.clear();
+                                    ui.label("Transition:");
+                                    let mut save_prefs = false;
+                                    egui::ComboBox::from_id_salt("camera_transition_easing")
+                                        .selected_text(self.camera_transition_easing.name())
+                                        .show_ui(ui, |ui| {
+                                            for easing in crate::fractal::CameraEasing::ALL {
+                                                save_prefs |= ui
+                                                    .selectable_value(&mut self.camera_transition_easing, easing, easing.name())
+                                                    .changed();
+                                            }
+                                        })
+                                        .response
+                                        .on_hover_text("Easing curve of the camera move when loading a bookmark or touring presets\nEase In-Out is smooth for demos, Linear or Ease Out is snappier for tuning");
+                                    let response = ui.add(egui::Slider::new(&mut self.camera_transition_secs, 0.1..=5.0)
+                                        .suffix(" s"))
+                                        .on_hover_text("How long the camera takes to reach a loaded bookmark");
+                                    save_prefs |= response.drag_stopped() || (response.changed() && !response.dragged());
+                                    if save_prefs {
+                                        let mut prefs = crate::fractal::AppPreferences::load();
+                                        prefs.camera_transition_easing = self.camera_transition_easing;
+                                        prefs.camera_transition_secs = self.camera_transition_secs;
+                                        if let Err(e) = prefs.save() {
+                                            log::error!("Failed to save preferences: {}", e);
+                                        }
                                     }
-                                }
+                                });
 
-                                // Refresh bookmark list periodically
-                                if self.last_bookmark_list_update.elapsed().as_secs() > 2 {
-                                    self.bookmarks = BookmarkGallery::list_bookmarks().unwrap_or_default();
-                                    self.last_bookmark_list_update = web_time::Instant::now();
+                                if let Some(bookmark) = self.bookmark_controls(ui, params, camera_pos, camera_target) {
+                                    bookmark_to_load = Some(bookmark);
                                 }
 
-                                if !self.bookmarks.is_empty() {
-                                    ui.separator();
-                                    ui.label("Saved Bookmarks:")
-                                        .on_hover_text("Click to load, right-click to delete");
-
-                                    egui::ScrollArea::vertical()
-                                        .id_salt("bookmarks_scroll")
-                                        .max_height(120.0)
-                                        .show(ui, |ui| {
-                                            let bookmarks_clone = self.bookmarks.clone();
-                                            for bookmark_name in bookmarks_clone.iter() {
-                                                ui.horizontal(|ui| {
-                                                    if ui.button(bookmark_name)
-                                                        .on_hover_text("Click to restore this camera position")
-                                                        .clicked() {
-                                                        if let Ok(bookmark) = BookmarkGallery::load_bookmark(bookmark_name) {
-                                                            bookmark_to_load = Some(bookmark);
-                                                        }
-                                                    }
-                                                    if ui.small_button("🗑")
-                                                        .on_hover_text("Delete this bookmark")
-                                                        .clicked() {
-                                                        self.bookmark_to_delete = Some(bookmark_name.clone());
-                                                    }
-                                                });
-                                            }
+                                ui.separator();
+                                ui.label("Camera Path:")
+                                    .on_hover_text("Keyframe a fly-through and scrub along it to review the motion");
+                                ui.horizontal(|ui| {
+                                    if ui.button("➕ Add Keyframe")
+                                        .on_hover_text("Append the current camera to the path")
+                                        .clicked()
+                                    {
+                                        self.camera_path.push(crate::camera_path::CameraKeyframe {
+                                            position: camera_pos,
+                                            target: camera_target,
+                                            fov: params.camera_fov,
+                                            duration: self.camera_path_segment_secs,
+                                            easing: crate::fractal::CameraEasing::Linear,
                                         });
-                                }
-
-                                // Handle bookmark deletion
-                                if let Some(ref bookmark_name) = self.bookmark_to_delete {
-                                    if let Err(e) = BookmarkGallery::delete_bookmark(bookmark_name) {
-                                        eprintln!("Failed to delete bookmark: {}", e);
                                     }
-                                    self.bookmarks = BookmarkGallery::list_bookmarks().unwrap_or_default();
-                                    self.bookmark_to_delete = None;
+                                    if ui.add_enabled(!self.camera_path.is_empty(), egui::Button::new("🗑 Clear"))
+                                        .on_hover_text("Remove all keyframes")
+                                        .clicked()
+                                    {
+                                        self.camera_path.clear();
+                                        self.camera_path_time = 0.0;
+                                    }
+                                });
+                                ui.add(
+                                    egui::Slider::new(
+                                        &mut self.camera_path_segment_secs,
+                                        crate::camera_path::MIN_SEGMENT_SECS..=30.0,
+                                    )
+                                    .text("Segment (s)"),
+                                )
+                                .on_hover_text("Travel time from a new keyframe to the next one");
+                                #[cfg(not(target_arch = "wasm32"))]
+                                self.camera_path_file_buttons(ui);
+                                ui.label(format!(
+                                    "{} keyframe(s), {:.1}s",
+                                    self.camera_path.len(),
+                                    self.camera_path.total_duration()
+                                ));
+                                if self.camera_path.len() >= 2 {
+                                    let duration = self.camera_path.total_duration();
+                                    if ui.add(
+                                        egui::Slider::new(&mut self.camera_path_time, 0.0..=1.0)
+                                            .text("Scrub")
+                                            .custom_formatter(|t, _| format!("{:.2}s", t as f32 * duration)),
+                                    )
+                                    .on_hover_text("Move the camera along the path")
+                                    .changed()
+                                    {
+                                        self.camera_path_preview = Some(self.camera_path_time);
+                                    }
                                 }
                             });
-                        self.ui_state.camera_open = response.openness > 0.0;
-
-                        let response = egui::CollapsingHeader::new("Shading")
-                            .default_open(self.ui_state.shading_open)
-                            .show(ui, |ui| {
-                                changed |= ui.radio_value(&mut params.shading_model, ShadingModel::BlinnPhong, "Blinn-Phong")
-                                    .on_hover_text("Classic Blinn-Phong shading - fast and simple")
-                                    .changed();
-                                changed |= ui.radio_value(&mut params.shading_model, ShadingModel::PBR, "PBR")
-                                    .on_hover_text("Physically Based Rendering - more realistic materials")
-                                    .changed();
+                    self.ui_state.camera_open =
+                        self.section_open_state(self.ui_state.camera_open, response.openness);
+
+                    let (header, search_matches) =
+                        self.section_header("Shading", self.ui_state.shading_open);
+                    let response = header.show(ui, |ui| {
+                        search::show_matches(ui, &search_matches);
+                        changed |= ui
+                            .radio_value(
+                                &mut params.shading_model,
+                                ShadingModel::BlinnPhong,
+                                "Blinn-Phong",
+                            )
+                            .on_hover_text("Classic Blinn-Phong shading - fast and simple")
+                            .changed();
+                        changed |= ui
+                            .radio_value(&mut params.shading_model, ShadingModel::PBR, "PBR")
+                            .on_hover_text("Physically Based Rendering - more realistic materials")
+                            .changed();
+
+                        if params.shading_model == ShadingModel::PBR {
+                            ui.separator();
+                            ui.label("Material Properties:")
+                                .on_hover_text("Control surface appearance with PBR");
+                            changed |= ui
+                                .add(
+                                    egui::Slider::new(&mut params.roughness, 0.0..=1.0)
+                                        .text("Roughness"),
+                                )
+                                .on_hover_text(
+                                    "Surface roughness: 0 = smooth/shiny, 1 = rough/matte",
+                                )
+                                .changed();
+                            changed |= ui
+                                .add(
+                                    egui::Slider::new(&mut params.metallic, 0.0..=1.0)
+                                        .text("Metallic"),
+                                )
+                                .on_hover_text("Metalness: 0 = dielectric, 1 = metal")
+                                .changed();
+                        }
 
-                                if params.shading_model == ShadingModel::PBR {
-                                    ui.separator();
-                                    ui.label("Material Properties:")
-                                        .on_hover_text("Control surface appearance with PBR");
-                                    changed |= ui.add(egui::Slider::new(&mut params.roughness, 0.0..=1.0)
-                                        .text("Roughness"))
-                                        .on_hover_text("Surface roughness: 0 = smooth/shiny, 1 = rough/matte")
-                                        .changed();
-                                    changed |= ui.add(egui::Slider::new(&mut params.metallic, 0.0..=1.0)
-                                        .text("Metallic"))
-                                        .on_hover_text("Metalness: 0 = dielectric, 1 = metal")
-                                        .changed();
-                                }
-                            });
-                        self.ui_state.shading_open = response.openness > 0.0;
+                        ui.separator();
+                        ui.horizontal(|ui| {
+                            ui.label("Specular Color:");
+                            let mut color = params.specular_color.to_array();
+                            if ui
+                                .color_edit_button_rgb(&mut color)
+                                .on_hover_text("Tint of the highlights, e.g. gold on a blue surface\nWhite leaves them uncolored")
+                                .changed()
+                            {
+                                params.specular_color = Vec3::from_array(color);
+                                changed = true;
+                            }
+                        });
+                        changed |= ui
+                            .add(
+                                egui::Slider::new(&mut params.specular_intensity, 0.0..=4.0)
+                                    .text("Specular Intensity"),
+                            )
+                            .on_hover_text("Strength of the highlights: 0 = none, 1 = physically based")
+                            .changed();
+                    });
+                    self.ui_state.shading_open =
+                        self.section_open_state(self.ui_state.shading_open, response.openness);
+                    if self.section_reset_button(
+                        ui,
+                        &response.header_response,
+                        params,
+                        ParamSection::Shading,
+                    ) {
+                        changed = true;
+                    }
 
-                        let response = egui::CollapsingHeader::new("Lighting")
-                            .default_open(self.ui_state.lighting_open)
+                    let (header, search_matches) =
+                        self.section_header("Lighting", self.ui_state.lighting_open);
+                    let response = header
                             .show(ui, |ui| {
+                                search::show_matches(ui, &search_matches);
                                 ui.label("Light Settings:")
                                     .on_hover_text("Configure lighting intensity and ambience");
                                 changed |= ui.add(egui::Slider::new(&mut params.light_intensity, 0.5..=10.0)
@@ -1993,7 +3228,7 @@ impl UI {
 
                                 ui.separator();
                                 ui.label("Light Direction:")
-                                    .on_hover_text("Control the direction of the main light");
+                                    .on_hover_text("Control the direction of the main light. Ctrl+drag (Cmd+drag on macOS) in the view to rotate it directly");
                                 changed |= ui.add(egui::Slider::new(&mut params.light_azimuth, 0.0..=360.0)
                                     .text("Azimuth"))
                                     .on_hover_text("Horizontal angle of the light (0-360°)")
@@ -2034,9 +3269,16 @@ impl UI {
                                         .changed();
                                 }
                                 if params.shadow_mode == 2 {
+                                    changed |= ui.add(egui::Slider::new(&mut params.light_size, 0.5..=30.0)
+                                        .text("Light Size")
+                                        .suffix("°")
+                                        .logarithmic(true))
+                                        .on_hover_text("Angular diameter of the light as seen from the surface
+Shadows stay sharp where they touch the occluder and soften with distance from it; larger lights give wider penumbrae (the sun is about 0.5°)")
+                                        .changed();
                                     changed |= ui.add(egui::Slider::new(&mut params.shadow_softness, 1.0..=32.0)
                                         .text("Shadow Softness"))
-                                        .on_hover_text("Shadow penumbra softness - higher = softer edges")
+                                        .on_hover_text("Artistic penumbra multiplier on top of the light size - higher = softer edges (8 = physically based)")
                                         .changed();
                                 }
 
@@ -2054,11 +3296,22 @@ impl UI {
                                         .changed();
                                 }
                             });
-                        self.ui_state.lighting_open = response.openness > 0.0;
+                    self.ui_state.lighting_open =
+                        self.section_open_state(self.ui_state.lighting_open, response.openness);
+                    if self.section_reset_button(
+                        ui,
+                        &response.header_response,
+                        params,
+                        ParamSection::Lighting,
+                    ) {
+                        changed = true;
+                    }
 
-                        let response = egui::CollapsingHeader::new("Effects")
-                            .default_open(self.ui_state.effects_open)
+                    let (header, search_matches) =
+                        self.section_header("Effects", self.ui_state.effects_open);
+                    let response = header
                             .show(ui, |ui| {
+                                search::show_matches(ui, &search_matches);
                                 changed |= ui.checkbox(&mut params.depth_of_field, "Depth of Field")
                                     .on_hover_text("Blur based on distance from focus [T]")
                                     .changed();
@@ -2076,6 +3329,19 @@ impl UI {
                                         .text("Samples (quality vs speed)"))
                                         .on_hover_text("Number of samples per pixel - higher = smoother but slower")
                                         .changed();
+                                    ui.horizontal(|ui| {
+                                        ui.label("Bokeh Shape:");
+                                        egui::ComboBox::from_id_salt("bokeh_blades")
+                                            .selected_text(bokeh_shape_name(params.bokeh_blades))
+                                            .show_ui(ui, |ui| {
+                                                for blades in [0, 3, 4, 5, 6, 7, 8] {
+                                                    changed |= ui.selectable_value(&mut params.bokeh_blades, blades, bokeh_shape_name(blades))
+                                                        .changed();
+                                                }
+                                            });
+                                    })
+                                    .response
+                                    .on_hover_text("Aperture shape: out-of-focus highlights take this shape.\nPolygons show best with more samples and bloom.");
                                 }
 
                                 ui.separator();
@@ -2105,20 +3371,41 @@ impl UI {
                                         })
                                         .inner.unwrap_or(false);
 
-                                    changed |= ui.add(egui::Slider::new(&mut params.fog_density, 0.0..=0.2)
-                                        .text("Fog Density"))
-                                        .on_hover_text("How thick the fog is - higher = denser")
+                                    changed |= ui.add(egui::Slider::new(&mut params.fog_start, 0.0..=500.0).logarithmic(true).text("Fog Start"))
+                                        .on_hover_text("Distance from the camera where the fog begins\nNearer surfaces stay clear")
                                         .changed();
+                                    if params.fog_mode == crate::fractal::FogMode::Linear {
+                                        changed |= ui.add(egui::Slider::new(&mut params.fog_end, 0.0..=1000.0).logarithmic(true).text("Fog End"))
+                                            .on_hover_text("Distance where linear fog hides surfaces completely")
+                                            .changed();
+                                        params.fog_end = params.fog_end.max(params.fog_start + 0.01);
+                                    } else {
+                                        changed |= precise_slider(ui, &mut params.fog_density, 0.0..=0.2, "Fog Density", 4, false)
+                                            .on_hover_text("How thick the fog is past the start distance - higher = denser")
+                                            .changed();
+                                    }
 
                                     ui.label("Fog Color:")
                                         .on_hover_text("Color of the fog");
-                                    let mut fog_color = [params.fog_color.x, params.fog_color.y, params.fog_color.z];
-                                    if ui.color_edit_button_rgb(&mut fog_color)
-                                        .on_hover_text("Click to change fog color")
-                                        .changed() {
-                                        params.fog_color = glam::Vec3::from_array(fog_color);
-                                        changed = true;
-                                    }
+                                    ui.horizontal(|ui| {
+                                        let mut fog_color = [params.fog_color.x, params.fog_color.y, params.fog_color.z];
+                                        if ui.color_edit_button_rgb(&mut fog_color)
+                                            .on_hover_text("Click to change fog color")
+                                            .changed() {
+                                            params.fog_color = glam::Vec3::from_array(fog_color);
+                                            changed = true;
+                                        }
+                                        let fog_color = params.fog_color;
+                                        let texture = self.fog_swatch.texture(
+                                            ui.ctx(),
+                                            "fog_swatch",
+                                            [fog_color; 2],
+                                            material_swatch::SwatchLighting::from_params(params),
+                                            || material_swatch::fog_swatch(fog_color),
+                                        );
+                                        ui.image((texture.id(), egui::vec2(24.0, 24.0)))
+                                            .on_hover_text("The fog color fading in over a checkerboard, as distant surfaces fade into fog");
+                                    });
                                 }
 
                                 // Post-Processing Section
@@ -2145,6 +3432,16 @@ impl UI {
                                     .text("Hue Shift"))
                                     .on_hover_text("Shift colors around the color wheel (-1.0 to 1.0)")
                                     .changed();
+                                changed |= ui.checkbox(&mut params.auto_exposure, "Auto Exposure")
+                                    .on_hover_text("Adapt exposure to the scene's measured brightness, like a camera\nBrightness then acts as exposure compensation")
+                                    .changed();
+                                if params.auto_exposure {
+                                    changed |= ui.add(egui::Slider::new(&mut params.auto_exposure_speed, 0.1..=10.0)
+                                        .logarithmic(true)
+                                        .text("Adaptation Speed"))
+                                        .on_hover_text("How quickly exposure follows brightness changes (higher = faster)")
+                                        .changed();
+                                }
 
                                 ui.separator();
 
@@ -2163,11 +3460,61 @@ impl UI {
                                         .changed();
                                 }
 
+                                // Porthole
+                                changed |= ui.checkbox(&mut params.porthole_enabled, "Porthole")
+                                    .on_hover_text("Clip the image to a circle, like looking through a lens\nWith a transparent background capture, the outside is transparent")
+                                    .changed();
+                                if params.porthole_enabled {
+                                    changed |= ui.add(egui::Slider::new(&mut params.porthole_radius, 0.1..=1.5)
+                                        .text("Porthole Radius"))
+                                        .on_hover_text("Size of the opening (1.0 = touches the nearest edges)")
+                                        .changed();
+                                    changed |= ui.add(egui::Slider::new(&mut params.porthole_feather, 0.0..=0.5)
+                                        .text("Porthole Feather"))
+                                        .on_hover_text("Width of the soft edge (0.0 = hard edge)")
+                                        .changed();
+                                    changed |= ui.checkbox(&mut params.porthole_oval, "Oval")
+                                        .on_hover_text("Stretch the opening to the frame's shape instead of a circle")
+                                        .changed();
+                                    ui.horizontal(|ui| {
+                                        ui.label("Outside Color:");
+                                        let mut color = params.porthole_color.to_array();
+                                        if ui.color_edit_button_rgb(&mut color)
+                                            .on_hover_text("Color shown outside the opening")
+                                            .changed() {
+                                            params.porthole_color = glam::Vec3::from_array(color);
+                                            changed = true;
+                                        }
+                                    });
+                                }
+
+                                ui.separator();
+
+                                // Kaleidoscope symmetry
+                                ui.horizontal(|ui| {
+                                    ui.label("Symmetry:");
+                                    egui::ComboBox::from_id_salt("symmetry_mode")
+                                        .selected_text(params.symmetry_mode.name())
+                                        .show_ui(ui, |ui| {
+                                            for &mode in crate::fractal::SymmetryMode::ALL {
+                                                changed |= ui.selectable_value(&mut params.symmetry_mode, mode, mode.name()).changed();
+                                            }
+                                        });
+                                })
+                                .response
+                                .on_hover_text("Mirror the image across the center for kaleidoscopic, wallpaper-friendly symmetry\nRadial folds it into mirrored wedges like a mandala");
+                                if params.symmetry_mode == crate::fractal::SymmetryMode::Radial {
+                                    changed |= ui.add(egui::Slider::new(&mut params.symmetry_segments, 2..=24)
+                                        .text("Segments"))
+                                        .on_hover_text("Number of mirrored wedges around the center")
+                                        .changed();
+                                }
+
                                 ui.separator();
 
                                 // Bloom
                                 changed |= ui.checkbox(&mut params.bloom_enabled, "Bloom")
-                                    .on_hover_text("Glow effect around bright areas - extracts and blurs bright pixels using multi-pass rendering")
+                                    .on_hover_text("Glow effect around bright areas - extracts bright pixels, then downsamples and recombines them through a mip chain")
                                     .changed();
                                 if params.bloom_enabled {
                                     changed |= ui.add(egui::Slider::new(&mut params.bloom_threshold, 0.0..=1.0)
@@ -2178,6 +3525,22 @@ impl UI {
                                         .text("Intensity"))
                                         .on_hover_text("Strength of the bloom glow (0.3-0.8 recommended)")
                                         .changed();
+                                    changed |= ui.add(egui::Slider::new(&mut params.bloom_radius, 0.001..=0.02)
+                                        .text("Spread"))
+                                        .on_hover_text("Filter radius used when combining the bloom levels (larger = softer, wider glow)")
+                                        .changed();
+                                    ui.horizontal(|ui| {
+                                        ui.label("Quality:");
+                                        egui::ComboBox::from_id_salt("bloom_quality")
+                                            .selected_text(params.bloom_quality.name())
+                                            .show_ui(ui, |ui| {
+                                                for &quality in crate::fractal::BloomQuality::ALL {
+                                                    changed |= ui.selectable_value(&mut params.bloom_quality, quality, quality.name()).changed();
+                                                }
+                                            })
+                                            .response
+                                            .on_hover_text("Number of downsampled levels in the bloom chain (Low 3, Medium 5, High 7). More levels give a wider, smoother glow");
+                                    });
                                 }
 
                                 ui.separator();
@@ -2186,12 +3549,47 @@ impl UI {
                                 changed |= ui.checkbox(&mut params.fxaa_enabled, "FXAA Anti-aliasing")
                                     .on_hover_text("Fast approximate anti-aliasing to smooth jagged edges")
                                     .changed();
+
+                                ui.separator();
+
+                                // Color vision deficiency preview (not a fractal setting)
+                                ui.horizontal(|ui| {
+                                    ui.label("Color Blindness:");
+                                    egui::ComboBox::from_id_salt("cvd_simulation")
+                                        .selected_text(self.cvd_simulation.name())
+                                        .show_ui(ui, |ui| {
+                                            for &simulation in crate::fractal::CvdSimulation::ALL {
+                                                ui.selectable_value(&mut self.cvd_simulation, simulation, simulation.name());
+                                            }
+                                        });
+                                })
+                                .response
+                                .on_hover_text("Preview how the image looks to color-blind viewers, to pick palettes that stay legible\nA simulation overlay only: it isn't saved with presets");
+                                if self.cvd_simulation != crate::fractal::CvdSimulation::None {
+                                    ui.colored_label(
+                                        egui::Color32::from_rgb(255, 200, 100),
+                                        "👁 Simulation overlay - colors are not changed",
+                                    );
+                                    ui.checkbox(&mut self.cvd_in_captures, "Include in captures")
+                                        .on_hover_text("Apply the simulation to screenshots, renders and recordings too");
+                                }
                             });
-                        self.ui_state.effects_open = response.openness > 0.0;
+                    self.ui_state.effects_open =
+                        self.section_open_state(self.ui_state.effects_open, response.openness);
+                    if self.section_reset_button(
+                        ui,
+                        &response.header_response,
+                        params,
+                        ParamSection::Effects,
+                    ) {
+                        changed = true;
+                    }
 
-                        let response = egui::CollapsingHeader::new("Floor")
-                            .default_open(self.ui_state.floor_open)
+                    let (header, search_matches) =
+                        self.section_header("Floor", self.ui_state.floor_open);
+                    let response = header
                             .show(ui, |ui| {
+                                search::show_matches(ui, &search_matches);
                                 changed |= ui.checkbox(&mut params.show_floor, "Show Floor")
                                     .on_hover_text("Display checkered floor plane [G]")
                                     .changed();
@@ -2201,26 +3599,54 @@ impl UI {
                                         .text("Floor Height"))
                                         .on_hover_text("Vertical position of the floor plane")
                                         .changed();
+                                    changed |= ui.add(egui::Slider::new(&mut params.floor_checker_scale, 0.05..=20.0)
+                                        .text("Checker Scale")
+                                        .logarithmic(true))
+                                        .on_hover_text("Size of each checkerboard tile in world units")
+                                        .changed();
+                                    changed |= ui.add(egui::Slider::new(&mut params.floor_checker_rotation, 0.0..=90.0)
+                                        .text("Checker Rotation")
+                                        .suffix("°"))
+                                        .on_hover_text("Rotate the checkerboard around the up axis - 45° gives a diagonal pattern")
+                                        .changed();
 
                                     ui.separator();
                                     ui.label("Floor Colors:")
                                         .on_hover_text("Checkerboard pattern colors");
 
-                                    let mut color1 = [params.floor_color1.x, params.floor_color1.y, params.floor_color1.z];
-                                    if ui.color_edit_button_rgb(&mut color1)
-                                        .on_hover_text("First checkerboard color")
-                                        .changed() {
-                                        params.floor_color1 = glam::Vec3::from_array(color1);
-                                        changed = true;
-                                    }
+                                    ui.horizontal(|ui| {
+                                        ui.vertical(|ui| {
+                                            let mut color1 = [params.floor_color1.x, params.floor_color1.y, params.floor_color1.z];
+                                            if ui.color_edit_button_rgb(&mut color1)
+                                                .on_hover_text("First checkerboard color")
+                                                .changed() {
+                                                params.floor_color1 = glam::Vec3::from_array(color1);
+                                                changed = true;
+                                            }
 
-                                    let mut color2 = [params.floor_color2.x, params.floor_color2.y, params.floor_color2.z];
-                                    if ui.color_edit_button_rgb(&mut color2)
-                                        .on_hover_text("Second checkerboard color")
-                                        .changed() {
-                                        params.floor_color2 = glam::Vec3::from_array(color2);
-                                        changed = true;
-                                    }
+                                            let mut color2 = [params.floor_color2.x, params.floor_color2.y, params.floor_color2.z];
+                                            if ui.color_edit_button_rgb(&mut color2)
+                                                .on_hover_text("Second checkerboard color")
+                                                .changed() {
+                                                params.floor_color2 = glam::Vec3::from_array(color2);
+                                                changed = true;
+                                            }
+                                        });
+
+                                        // Both colors on a sphere under the current lights and material
+                                        let colors = [params.floor_color1, params.floor_color2];
+                                        let lighting = material_swatch::SwatchLighting::from_params(params);
+                                        let texture = self.floor_swatch.texture(
+                                            ui.ctx(),
+                                            "floor_swatch",
+                                            colors,
+                                            lighting,
+                                            || material_swatch::sphere_swatch(colors, &lighting),
+                                        );
+                                        let size = material_swatch::SWATCH_SIZE as f32;
+                                        ui.image((texture.id(), egui::vec2(size, size)))
+                                            .on_hover_text("The floor colors as they'll look shaded with the current lighting, shading model and material");
+                                    });
 
                                     ui.separator();
                                     changed |= ui.checkbox(&mut params.floor_reflections, "Floor Reflections")
@@ -2235,12 +3661,23 @@ impl UI {
                                     }
                                 }
                             });
-                        self.ui_state.floor_open = response.openness > 0.0;
+                    self.ui_state.floor_open =
+                        self.section_open_state(self.ui_state.floor_open, response.openness);
+                    if self.section_reset_button(
+                        ui,
+                        &response.header_response,
+                        params,
+                        ParamSection::Floor,
+                    ) {
+                        changed = true;
+                    }
 
-                        // LOD System
-                        let response = egui::CollapsingHeader::new("LOD System")
-                            .default_open(self.ui_state.lod_open)
+                    // LOD System
+                    let (header, search_matches) =
+                        self.section_header("LOD System", self.ui_state.lod_open);
+                    let response = header
                             .show(ui, |ui| {
+                                search::show_matches(ui, &search_matches);
                                 ui.label("Adaptive quality system for smooth performance")
                                     .on_hover_text("Automatically adjusts rendering quality based on distance, motion, and performance");
 
@@ -2332,6 +3769,23 @@ impl UI {
                                         .on_hover_text("Show current LOD level and performance metrics")
                                         .changed();
 
+                                    // Freeze the level (always, or only while recording)
+                                    ui.horizontal(|ui| {
+                                        changed |= ui.checkbox(&mut params.lod_config.freeze_enabled, "Freeze at")
+                                            .on_hover_text("Hold quality at the chosen level instead of adapting it")
+                                            .changed();
+                                        egui::ComboBox::from_id_salt("lod_freeze_level")
+                                            .selected_text(crate::lod::LOD_LEVEL_NAMES[params.lod_config.freeze_level.min(3)])
+                                            .show_ui(ui, |ui| {
+                                                for (level, name) in crate::lod::LOD_LEVEL_NAMES.iter().enumerate() {
+                                                    changed |= ui.selectable_value(&mut params.lod_config.freeze_level, level, *name).changed();
+                                                }
+                                            });
+                                    });
+                                    changed |= ui.checkbox(&mut params.lod_config.freeze_during_recording, "Freeze while recording")
+                                        .on_hover_text("Hold the chosen level while a video records, so quality doesn't pop mid-capture")
+                                        .changed();
+
                                     ui.separator();
 
                                     // Distance-based Controls
@@ -2477,9 +3931,7 @@ impl UI {
                                                     .on_hover_text("Ray marching iterations")
                                                     .changed();
 
-                                                changed |= ui.add(egui::Slider::new(&mut preset.min_distance, 0.0001..=0.01)
-                                                    .text("Min Distance")
-                                                    .logarithmic(true))
+                                                changed |= precise_slider(ui, &mut preset.min_distance, 0.0001..=0.01, "Min Distance", 5, true)
                                                     .on_hover_text("Surface precision threshold")
                                                     .changed();
 
@@ -2571,6 +4023,9 @@ impl UI {
                                         ui.horizontal(|ui| {
                                             ui.label("Current Level:");
                                             ui.colored_label(level_name.1, level_name.0);
+                                            if params.lod_state.frozen {
+                                                ui.label("❄ frozen");
+                                            }
                                         });
 
                                         // FPS Display
@@ -2597,41 +4052,295 @@ impl UI {
                                             });
                                         }
 
-                                        // Active Quality Parameters (showing what's currently being used)
-                                        ui.collapsing("Active Parameters", |ui| {
-                                            let quality = &params.lod_state.active_quality;
-                                            ui.label(format!("Max Steps: {}", quality.max_steps));
-                                            ui.label(format!("Min Distance: {:.6}", quality.min_distance));
-                                            ui.label(format!("Shadow Samples: {}", quality.shadow_samples));
-                                            ui.label(format!("Shadow Step: {:.2}", quality.shadow_step_factor));
-                                            ui.label(format!("AO Step: {:.2}", quality.ao_step_size));
-                                            ui.label(format!("DOF Samples: {}", quality.dof_samples));
-                                            ui.label(format!("Render Scale: {:.2}", quality.render_scale));
-                                        });
-                                    });
+                                        // Active Quality Parameters (showing what's currently being used)
+                                        ui.collapsing("Active Parameters", |ui| {
+                                            let quality = &params.lod_state.active_quality;
+                                            ui.label(format!("Max Steps: {}", quality.max_steps));
+                                            ui.label(format!("Min Distance: {:.6}", quality.min_distance));
+                                            ui.label(format!("Shadow Samples: {}", quality.shadow_samples));
+                                            ui.label(format!("Shadow Step: {:.2}", quality.shadow_step_factor));
+                                            ui.label(format!("AO Step: {:.2}", quality.ao_step_size));
+                                            ui.label(format!("DOF Samples: {}", quality.dof_samples));
+                                            ui.label(format!("Render Scale: {:.2}", quality.render_scale));
+                                        });
+                                    });
+                                }
+                            });
+                    self.ui_state.lod_open =
+                        self.section_open_state(self.ui_state.lod_open, response.openness);
+                }
+            }
+
+            let (header, search_matches) =
+                self.section_header("Settings", self.ui_state.settings_open);
+            let response = header
+                    .show(ui, |ui| {
+                        search::show_matches(ui, &search_matches);
+                        ui.horizontal(|ui| {
+                            if ui.button("💾 Save Settings")
+                                .on_hover_text("Manually save current settings and camera to disk")
+                                .clicked() {
+                                self.save_settings_requested = true;
+                            }
+                            if ui.button("↩ Revert to Last Saved")
+                                .on_hover_text("Discard unsaved changes and reload settings from disk")
+                                .clicked() {
+                                self.revert_settings_requested = true;
+                            }
+                            if ui.button("🔄 Reset to Defaults")
+                                .on_hover_text("Reset all settings to default values")
+                                .clicked() {
+                                reset_requested = true;
+                            }
+                        });
+
+                        ui.separator();
+                        ui.heading("Layout");
+                        ui.horizontal(|ui| {
+                            ui.label("Control Panel:");
+                            egui::ComboBox::from_id_salt("panel_dock")
+                                .selected_text(self.ui_state.panel_dock.name())
+                                .show_ui(ui, |ui| {
+                                    for &dock in PanelDock::ALL {
+                                        ui.selectable_value(&mut self.ui_state.panel_dock, dock, dock.name());
+                                    }
+                                });
+                        })
+                        .response
+                        .on_hover_text("Floating: a movable window over the fractal\nDocked: a fixed side panel, with the fractal rendered in the remaining area");
+
+                        let mut save_ui_scale = false;
+                        let mut auto_scale = self.ui_scale == 0.0;
+                        if ui.checkbox(&mut auto_scale, "Automatic UI scale")
+                            .on_hover_text(format!("Follow the scale the system reports for this display ({:.2})", ui.ctx().native_pixels_per_point().unwrap_or(1.0)))
+                            .changed() {
+                            self.ui_scale = if auto_scale { 0.0 } else { ui.ctx().pixels_per_point() };
+                            self.ui_scale_edit = self.ui_scale;
+                            if auto_scale {
+                                ui.ctx().set_zoom_factor(1.0);
+                            }
+                            save_ui_scale = true;
+                        }
+                        if !auto_scale {
+                            let response = ui.add(egui::Slider::new(&mut self.ui_scale_edit, crate::fractal::UI_SCALE_RANGE)
+                                .text("UI scale")
+                                .step_by(0.05))
+                                .on_hover_text("Pixels per point for the controls, independent of the render resolution\nApplied when you release the slider");
+                            if response.drag_stopped() || (response.changed() && !response.dragged()) {
+                                self.ui_scale = self.ui_scale_edit;
+                                save_ui_scale = true;
+                            }
+                        }
+                        if save_ui_scale {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.ui_scale = self.ui_scale;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
+                            }
+                        }
+
+                        ui.separator();
+                        ui.heading("Diagnostics");
+                        let problems = console::problem_count();
+                        let console_label = if problems > 0 {
+                            format!("🖥 Console ({} ⚠)", problems)
+                        } else {
+                            "🖥 Console".to_string()
+                        };
+                        if ui.button(console_label)
+                            .on_hover_text("Show log messages (preset loading, monitor scanning, errors)\nUseful when reporting an issue")
+                            .clicked() {
+                            self.ui_state.console_window_open = !self.ui_state.console_window_open;
+                        }
+                        let mut blank_frame_changed = ui.checkbox(&mut self.blank_frame_warning, "Warn on blank frames")
+                            .on_hover_text("Show a warning when a parameter change leaves the view entirely black or NaN")
+                            .changed();
+                        ui.add_enabled_ui(self.blank_frame_warning, |ui| {
+                            blank_frame_changed |= ui.checkbox(&mut self.blank_frame_auto_revert, "Auto-revert blank changes")
+                                .on_hover_text("Undo the change automatically instead of only warning")
+                                .changed();
+                        });
+                        if blank_frame_changed {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.blank_frame_warning = self.blank_frame_warning;
+                            prefs.blank_frame_auto_revert = self.blank_frame_auto_revert;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
+                            }
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            ui.separator();
+                            ui.heading("Screenshots");
+                            if ui.checkbox(&mut self.screenshot_supersample, "Render at 2x and downscale")
+                                .on_hover_text("Render F12 screenshots at twice the window resolution and downscale them to the window size\nAnti-aliases the saved file without changing the view; strange attractors and Buddhabrot are saved as shown")
+                                .changed() {
+                                let mut prefs = crate::fractal::AppPreferences::load();
+                                prefs.screenshot_supersample = self.screenshot_supersample;
+                                if let Err(e) = prefs.save() {
+                                    eprintln!("Failed to save preferences: {}", e);
+                                }
+                            }
+                        }
+
+                        ui.separator();
+                        ui.heading("Navigation");
+                        if ui.checkbox(&mut self.zoom_to_cursor, "Zoom toward cursor (2D)")
+                            .on_hover_text("Keep the point under the cursor fixed when zooming with the wheel, Shift+drag or pinch\nWhen off, zoom toward the view center")
+                            .changed() {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.zoom_to_cursor = self.zoom_to_cursor;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
+                            }
+                        }
+                        if ui.checkbox(&mut self.navigation_momentum, "Pan and zoom momentum (2D)")
+                            .on_hover_text("A flicked pan keeps gliding and slows to a stop, and wheel zoom eases out\nClicking or touching the view stops the glide")
+                            .changed() {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.navigation_momentum = self.navigation_momentum;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
+                            }
+                        }
+                        if ui.checkbox(&mut self.remember_fractal_coloring, "Remember coloring per fractal")
+                            .on_hover_text("Switching back to a fractal type restores the palette, color mode, iterations and power last used with it\nWhen off, switching applies the type's defaults")
+                            .changed() {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.remember_fractal_coloring = self.remember_fractal_coloring;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
+                            }
+                        }
+
+                        ui.separator();
+                        ui.heading("Auto-Save");
+                        let mut autosave_changed = ui.checkbox(&mut self.autosave_enabled, "Auto-save settings")
+                            .on_hover_text("Persist settings and camera automatically after changes\nWhen off, only Save Settings writes to disk")
+                            .changed();
+                        ui.add_enabled_ui(self.autosave_enabled, |ui| {
+                            let response = ui.add(egui::Slider::new(&mut self.autosave_interval_secs, 0.5..=60.0)
+                                .text("Delay (s)")
+                                .logarithmic(true))
+                                .on_hover_text("Seconds of inactivity before changes are saved");
+                            // Only write preferences once the drag is released
+                            autosave_changed |= response.drag_stopped() || (response.changed() && !response.dragged());
+                        });
+                        if autosave_changed {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.autosave_enabled = self.autosave_enabled;
+                            prefs.autosave_interval_secs = self.autosave_interval_secs;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
+                            }
+                        }
+
+                        ui.separator();
+                        ui.heading("Frame Rate");
+                        let mut pacing_changed = false;
+                        egui::ComboBox::from_label("Present Mode")
+                            .selected_text(self.present_mode.name())
+                            .show_ui(ui, |ui| {
+                                for mode in self.supported_present_modes.clone() {
+                                    pacing_changed |= ui
+                                        .selectable_value(&mut self.present_mode, mode, mode.name())
+                                        .changed();
                                 }
-                            });
-                        self.ui_state.lod_open = response.openness > 0.0;
-                    }
-                }
+                            })
+                            .response
+                            .on_hover_text("VSync behavior - only modes supported by this GPU are listed");
+                        let response = ui.add(egui::Slider::new(&mut self.max_fps, 0..=240)
+                            .text("Max FPS")
+                            .custom_formatter(|v, _| if v == 0.0 { "Unlimited".to_string() } else { format!("{}", v) }))
+                            .on_hover_text("Frame rate cap (0 = unlimited)");
+                        pacing_changed |= response.drag_stopped() || (response.changed() && !response.dragged());
+                        pacing_changed |= ui.checkbox(&mut self.idle_throttle_enabled, "Throttle when idle")
+                            .on_hover_text("Drop to a low frame rate while the scene is static and nothing is animating")
+                            .changed();
+                        ui.add_enabled_ui(self.idle_throttle_enabled, |ui| {
+                            let response = ui.add(egui::Slider::new(&mut self.idle_fps, 1..=30)
+                                .text("Idle FPS"))
+                                .on_hover_text("Frame rate used after a few seconds without input or changes");
+                            pacing_changed |= response.drag_stopped() || (response.changed() && !response.dragged());
+                        });
+                        if pacing_changed {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.present_mode = self.present_mode;
+                            prefs.max_fps = self.max_fps;
+                            prefs.idle_throttle_enabled = self.idle_throttle_enabled;
+                            prefs.idle_fps = self.idle_fps;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
+                            }
+                        }
 
-                let response = egui::CollapsingHeader::new("Settings")
-                    .default_open(self.ui_state.settings_open)
-                    .show(ui, |ui| {
-                        ui.horizontal(|ui| {
-                            if ui.button("💾 Save Settings")
-                                .on_hover_text("Manually save current settings to disk")
-                                .clicked() {
-                                if let Err(e) = params.save_to_file() {
-                                    eprintln!("Failed to save settings: {}", e);
+                        ui.separator();
+                        ui.heading("Attract Mode");
+                        let response = ui.add(egui::Slider::new(&mut self.idle_timeout_secs, 0.0..=600.0)
+                            .text("Idle timeout")
+                            .suffix(" s")
+                            .custom_formatter(|v, _| if v == 0.0 { "Off".to_string() } else { format!("{:.0}", v) }))
+                            .on_hover_text("After this long without input, auto-orbit the camera and animate the palette\n\
+                                            on the current fractal. Any input stops it and restores your settings (0 = off)");
+                        if response.drag_stopped() || (response.changed() && !response.dragged()) {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.idle_timeout_secs = self.idle_timeout_secs;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
+                            }
+                        }
+
+                        ui.separator();
+                        ui.heading("Display Format");
+                        let mut format_changed = false;
+                        egui::ComboBox::from_label("Surface Format")
+                            .selected_text(self.surface_format.name())
+                            .show_ui(ui, |ui| {
+                                for format in self.supported_surface_formats.clone() {
+                                    format_changed |= ui
+                                        .selectable_value(&mut self.surface_format, format, format.name())
+                                        .changed();
                                 }
+                            })
+                            .response
+                            .on_hover_text(
+                                "Color format of the window - only formats supported by this GPU are listed.\n\
+                                 10-bit reduces banding in smooth gradients; 16-bit float also lets HDR\n\
+                                 displays show highlights brighter than white. Applied on restart.",
+                            );
+                        if format_changed {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.surface_format = self.surface_format;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
                             }
-                            if ui.button("🔄 Reset to Defaults")
-                                .on_hover_text("Reset all settings to default values")
-                                .clicked() {
-                                reset_requested = true;
+                            self.surface_format_changed = true;
+                        }
+                        if self.surface_format_changed {
+                            ui.colored_label(egui::Color32::YELLOW, "Restart the application to apply the new format");
+                        }
+
+                        ui.separator();
+                        ui.heading("Safe Mode");
+                        if ui.checkbox(&mut self.safe_mode, "🛡 Safe Mode")
+                            .on_hover_text(format!(
+                                "Limit max steps to {}, shadow samples to {} and captures to {}px to avoid GPU hangs and driver resets",
+                                crate::fractal::SAFE_MODE_MAX_STEPS,
+                                crate::fractal::SAFE_MODE_MAX_SHADOW_SAMPLES,
+                                crate::fractal::SAFE_MODE_MAX_CAPTURE_DIMENSION,
+                            ))
+                            .changed()
+                        {
+                            let mut prefs = crate::fractal::AppPreferences::load();
+                            prefs.safe_mode = self.safe_mode;
+                            if let Err(e) = prefs.save() {
+                                eprintln!("Failed to save preferences: {}", e);
                             }
-                        });
+                        }
+                        if !self.safe_mode {
+                            ui.colored_label(egui::Color32::YELLOW, "⚠ Quality limits disabled - extreme settings may hang the GPU");
+                        }
 
                         ui.separator();
                         ui.heading("GPU Selection");
@@ -2688,94 +4397,179 @@ impl UI {
                             }
                         }
 
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            ui.separator();
+                            ui.heading("Profile");
+                            ui.horizontal(|ui| {
+                                if ui.button("📤 Export Profile...")
+                                    .on_hover_text("Save preferences and panel layout to one file for use on another machine
+The GPU choice, window size and capture folder are left out")
+                                    .clicked()
+                                {
+                                    let prefs = crate::fractal::AppPreferences::load();
+                                    let profile = crate::fractal::PreferenceProfile::new(&prefs, &self.ui_state);
+                                    match profile.export_to_file() {
+                                        Ok(Some(path)) => self.show_toast(format!("📤 Profile exported to {}", path.display())),
+                                        Ok(None) => {}
+                                        Err(e) => {
+                                            log::error!("Failed to export profile: {}", e);
+                                            self.show_toast(format!("✗ Export failed: {}", e));
+                                        }
+                                    }
+                                }
+                                if ui.button("📥 Import Profile...")
+                                    .on_hover_text("Apply preferences and panel layout from an exported profile
+This machine's GPU choice, window size and capture folder are kept")
+                                    .clicked()
+                                {
+                                    match crate::fractal::PreferenceProfile::import_from_file() {
+                                        Ok(Some(profile)) => {
+                                            let prefs = profile.merged_preferences(&crate::fractal::AppPreferences::load());
+                                            if let Err(e) = prefs.save() {
+                                                eprintln!("Failed to save preferences: {}", e);
+                                            }
+                                            self.apply_preferences(&prefs);
+                                            self.load_ui_state(profile.ui_state);
+                                            self.show_toast("📥 Profile imported".to_string());
+                                        }
+                                        Ok(None) => {}
+                                        Err(e) => {
+                                            log::error!("Failed to import profile: {}", e);
+                                            self.show_toast(format!("✗ Import failed: {}", e));
+                                        }
+                                    }
+                                }
+                            });
+                        }
+
                         ui.separator();
                         ui.label("Settings: ~/.config/par-fractal/settings.yaml")
                             .on_hover_text("Configuration file location");
                     });
-                self.ui_state.settings_open = response.openness > 0.0;
-
-                let response = egui::CollapsingHeader::new("Controls")
-                    .default_open(self.ui_state.controls_open)
-                    .show(ui, |ui| {
-                        ui.label("General:");
-                        ui.label("• H: Toggle UI");
-                        ui.label("• F: Toggle FPS counter");
-                        ui.label("• V: Toggle performance overlay");
-                        ui.label("• F12: Save screenshot");
-                        ui.label("• R: Reset view");
-                        ui.label("• P: Next color palette");
-                        ui.separator();
+            self.ui_state.settings_open =
+                self.section_open_state(self.ui_state.settings_open, response.openness);
+
+            let (header, search_matches) =
+                self.section_header("Controls", self.ui_state.controls_open);
+            let response = header.show(ui, |ui| {
+                search::show_matches(ui, &search_matches);
+                ui.label("General:");
+                ui.label("• H: Toggle UI");
+                ui.label("• F: Toggle FPS counter");
+                ui.label("• V: Toggle performance overlay");
+                ui.label("• F12: Save screenshot");
+                ui.label("• Shift+F12: Copy screenshot to clipboard");
+                ui.label("• R: Reset view");
+                ui.label("• P: Next color palette");
+                ui.separator();
 
-                        ui.label("2D Fractals (Number Keys):");
-                        ui.label("• 1: Mandelbrot");
-                        ui.label("• 2: Julia");
-                        ui.label("• 3: Sierpinski Carpet");
-                        ui.label("• 4: Burning Ship");
-                        ui.label("• 5: Tricorn");
-                        ui.label("• 6: Phoenix");
-                        ui.label("• 7: Celtic");
-                        ui.label("• 8: Newton");
-                        ui.label("• 9: Lyapunov");
-                        ui.label("• 0: Nova");
-                        ui.label("• (Magnet, Collatz: use UI buttons)");
-                        ui.separator();
+                ui.label("2D Fractals (Number Keys):");
+                ui.label("• 1: Mandelbrot");
+                ui.label("• 2: Julia");
+                ui.label("• 3: Sierpinski Carpet");
+                ui.label("• 4: Burning Ship");
+                ui.label("• 5: Tricorn");
+                ui.label("• 6: Phoenix");
+                ui.label("• 7: Celtic");
+                ui.label("• 8: Newton");
+                ui.label("• 9: Lyapunov");
+                ui.label("• 0: Nova");
+                ui.label("• (Magnet, Collatz: use UI buttons)");
+                ui.separator();
 
-                        ui.label("3D Fractals (Function Keys):");
-                        ui.label("• F1: Mandelbulb");
-                        ui.label("• F2: Menger Sponge");
-                        ui.label("• F3: Sierpinski Pyramid");
-                        ui.label("• F4: Julia Set 3D");
-                        ui.label("• F5: Mandelbox");
-                        ui.label("• F6: Tglad Formula");
-                        ui.label("• F7: Octahedral IFS");
-                        ui.label("• F8: Icosahedral IFS");
-                        ui.label("• F9: Apollonian Gasket");
-                        ui.label("• F10: Kleinian");
-                        ui.label("• F11: Hybrid Bulb-Julia");
-                        ui.label("• (Others: use UI buttons)");
-                        ui.separator();
+                ui.label("3D Fractals (Function Keys):");
+                ui.label("• F1: Mandelbulb");
+                ui.label("• F2: Menger Sponge");
+                ui.label("• F3: Sierpinski Pyramid");
+                ui.label("• F4: Julia Set 3D");
+                ui.label("• F5: Mandelbox");
+                ui.label("• F6: Tglad Formula");
+                ui.label("• F7: Octahedral IFS");
+                ui.label("• F8: Icosahedral IFS");
+                ui.label("• F9: Apollonian Gasket");
+                ui.label("• F10: Kleinian");
+                ui.label("• F11: Hybrid Bulb-Julia");
+                ui.label("• (Others: use UI buttons)");
+                ui.separator();
 
-                        ui.label("Parameters:");
-                        ui.label("• -/=: Decrease/increase iterations/steps");
-                        ui.label("• ,/.: Decrease/increase fractal power");
-                        ui.separator();
+                ui.label("Parameters:");
+                ui.label("• -/=: Decrease/increase iterations/steps");
+                ui.label("• ,/.: Decrease/increase fractal power");
+                ui.separator();
 
-                        ui.label("Effects (3D):");
-                        ui.label("• L: Toggle ambient occlusion");
-                        ui.label("• T: Toggle depth of field");
-                        ui.label("• G: Toggle floor");
-                        ui.label("• B: Cycle shadow mode (Off/Hard/Soft)");
-                        ui.separator();
+                ui.label("Effects (3D):");
+                ui.label("• L: Toggle ambient occlusion");
+                ui.label("• T: Toggle depth of field");
+                ui.label("• G: Toggle floor");
+                ui.label("• B: Cycle shadow mode (Off/Hard/Soft)");
+                ui.separator();
 
-                        ui.label("Camera (3D):");
-                        ui.label("• WASD: Move forward/left/back/right");
-                        ui.label("• Q/E: Move down/up");
-                        ui.label("• Mouse Drag: Look around");
-                        ui.label("• O: Toggle auto-orbit");
-                        ui.label("• [/]: Decrease/increase orbit speed");
-                        ui.separator();
+                ui.label("Camera (3D):");
+                ui.label("• WASD: Move forward/left/back/right");
+                ui.label("• Q/E: Move down/up");
+                ui.label("• Mouse Drag: Look around");
+                ui.label("• O: Toggle auto-orbit");
+                ui.label("• [/]: Decrease/increase orbit speed");
+                ui.separator();
 
-                        match params.render_mode {
-                            crate::fractal::RenderMode::TwoD => {
-                                ui.label("Mouse (2D Mode):");
-                                ui.label("• Drag: Pan view");
-                                ui.label("• Wheel: Zoom in/out");
-                            }
-                            crate::fractal::RenderMode::ThreeD => {
-                                ui.label("Mouse (3D Mode):");
-                                ui.label("• Drag: Rotate camera view");
-                                ui.label("• Wheel: Adjust move speed");
-                            }
-                        }
-                    });
-                self.ui_state.controls_open = response.openness > 0.0;
+                match params.render_mode {
+                    crate::fractal::RenderMode::TwoD => {
+                        ui.label("Mouse (2D Mode):");
+                        ui.label("• Drag: Pan view");
+                        ui.label("• Wheel: Zoom in/out");
+                        ui.label("• Ctrl+Drag: Box zoom (Cmd on macOS)");
+                    }
+                    crate::fractal::RenderMode::ThreeD => {
+                        ui.label("Mouse (3D Mode):");
+                        ui.label("• Drag: Rotate camera view");
+                        ui.label("• Wheel: Adjust move speed");
+                    }
+                }
             });
+            self.ui_state.controls_open =
+                self.section_open_state(self.ui_state.controls_open, response.openness);
+        };
+        match panel_dock {
+            PanelDock::Floating => {
+                egui::Window::new("Fractal Controls")
+                    .default_width(320.0)
+                    .default_height(600.0)
+                    .resizable(true)
+                    .vscroll(true)
+                    .show(ctx, panel_contents);
+            }
+            PanelDock::Left | PanelDock::Right => {
+                // The fractal renders in the area the side panel leaves free
+                // (see `App::update_render_area`)
+                let side = if panel_dock == PanelDock::Left {
+                    egui::panel::Side::Left
+                } else {
+                    egui::panel::Side::Right
+                };
+                egui::SidePanel::new(side, "fractal_controls_panel")
+                    .default_width(320.0)
+                    .resizable(true)
+                    .show(ctx, |ui| {
+                        egui::ScrollArea::vertical().show(ui, panel_contents);
+                    });
+            }
+        }
+        self.settings_search_was_active = !self.settings_search.trim().is_empty();
 
         // Handle randomization request
         if randomize_requested {
             self.save_to_history(params);
-            params.randomize();
+            params.randomize(&self.randomize_locks);
+            self.randomize_gallery.push(params);
+            changed = true;
+        }
+
+        if shuffle_colors_requested {
+            let message = self.shuffle_colors(params, false);
+            self.show_toast(message);
             changed = true;
+            from_history = true; // shuffle_colors already saved its history entry
         }
 
         // Save to history when parameters change (but not if change came from undo/redo)
@@ -2793,16 +4587,26 @@ impl UI {
                 .show(ctx, |ui| {
                     ui.heading("Screenshot");
 
-                    if ui
-                        .button("📷 Screen Resolution")
-                        .on_hover_text("Capture current view at screen resolution [F12]")
-                        .clicked()
-                    {
-                        screenshot_requested = true;
-                    }
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button("📷 Screen Resolution")
+                            .on_hover_text("Capture current view at screen resolution [F12]")
+                            .clicked()
+                        {
+                            screenshot_requested = true;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        if ui
+                            .button("📋 Copy to Clipboard")
+                            .on_hover_text("Copy current view to the clipboard instead of saving a file [Shift+F12]")
+                            .clicked()
+                        {
+                            self.clipboard_screenshot_requested = true;
+                        }
+                    });
 
-                    ui.label("Output: {fractal}_YYYYMMDD_HHMMSS.png")
-                        .on_hover_text("Saved to current directory. {fractal} = fractal type name");
+                    ui.label(format!("Output: {}.png", self.filename_template))
+                        .on_hover_text("Saved to the capture folder, named from the filename template");
 
                     ui.separator();
                     ui.heading("🖥 Desktop Wallpaper")
@@ -2862,6 +4666,63 @@ impl UI {
                             {
                                 hires_render_resolution = Some((monitor.width, monitor.height));
                             }
+
+                            #[cfg(not(target_arch = "wasm32"))]
+                            if ui
+                                .button("🖥 Render & Set as Wallpaper")
+                                .on_hover_text(format!(
+                                    "Render at {}'s native resolution and apply it as the desktop wallpaper",
+                                    monitor.name
+                                ))
+                                .clicked()
+                            {
+                                hires_render_resolution = Some((monitor.width, monitor.height));
+                                self.wallpaper_monitor_request = Some(self.selected_monitor_index);
+                            }
+                            safe_mode_wallpaper_note(ui, self.safe_mode, (monitor.width, monitor.height));
+                        }
+
+                        if self.available_monitors.len() > 1 {
+                            ui.add_space(4.0);
+                            ui.label("Span monitors:")
+                                .on_hover_text("Render one image covering several monitors as they're arranged on the desktop");
+                            self.span_monitors.resize(self.available_monitors.len(), true);
+                            ui.horizontal_wrapped(|ui| {
+                                for (monitor, included) in
+                                    self.available_monitors.iter().zip(self.span_monitors.iter_mut())
+                                {
+                                    ui.checkbox(included, &monitor.name).on_hover_text(format!(
+                                        "{}x{} at ({}, {})",
+                                        monitor.width, monitor.height, monitor.x, monitor.y
+                                    ));
+                                }
+                            });
+                            let spanned: Vec<&MonitorInfo> = self
+                                .available_monitors
+                                .iter()
+                                .zip(&self.span_monitors)
+                                .filter(|(_, included)| **included)
+                                .map(|(monitor, _)| monitor)
+                                .collect();
+                            if let Some((size, rects)) = spanned_layout(&spanned) {
+                                if ui
+                                    .button(format!(
+                                        "📐 Render {}x{} Spanned Wallpaper",
+                                        size.0, size.1
+                                    ))
+                                    .on_hover_text("One image covering the selected monitors\nSet it with your desktop's span wallpaper mode\nMonitors of different sizes leave padding that no monitor shows")
+                                    .clicked()
+                                {
+                                    hires_render_resolution = Some(size);
+                                    if self.span_crop_guides {
+                                        self.spanned_guides_request = Some((size, rects));
+                                    }
+                                }
+                                safe_mode_wallpaper_note(ui, self.safe_mode, size);
+                            }
+                            #[cfg(not(target_arch = "wasm32"))]
+                            ui.checkbox(&mut self.span_crop_guides, "Save crop guides")
+                                .on_hover_text("Also save a copy outlining each monitor's area, with the padding no monitor shows darkened");
                         }
 
                         ui.horizontal(|ui| {
@@ -2879,8 +4740,139 @@ impl UI {
                         });
                     }
 
-                    ui.label("Output: {fractal}_WxH_YYYYMMDD_HHMMSS.png")
-                        .on_hover_text("Saved to current directory. {fractal} = fractal type name");
+                    #[cfg(not(target_arch = "wasm32"))]
+                    ui.horizontal(|ui| {
+                        ui.label("Anti-aliasing:");
+                        let prev_aa = self.capture_antialiasing;
+                        egui::ComboBox::from_id_salt("capture_antialiasing")
+                            .selected_text(self.capture_antialiasing.name())
+                            .show_ui(ui, |ui| {
+                                for &aa in crate::fractal::CaptureAntialiasing::ALL {
+                                    ui.selectable_value(&mut self.capture_antialiasing, aa, aa.name());
+                                }
+                            })
+                            .response
+                            .on_hover_text(
+                                "Supersample high-res renders by averaging sub-pixel jittered frames.\n\
+                                 Render time grows with the sample count (4, 16 or 8 frames).",
+                            );
+                        if self.capture_antialiasing != prev_aa {
+                            changed = true;
+                        }
+                    });
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    ui.horizontal(|ui| {
+                        ui.label("Accumulation samples:");
+                        changed |= ui
+                            .add(
+                                egui::DragValue::new(&mut self.accumulation_samples)
+                                    .range(1..=crate::fractal::MAX_ACCUMULATION_SAMPLES),
+                            )
+                            .on_hover_text(
+                                "Average this many frames with Halton-jittered pixel and depth of field\n\
+                                 lens samples for a noise-free still (replaces the anti-aliasing pattern).\n\
+                                 1 = off. 64 converges well; render time grows with the count.",
+                            )
+                            .changed();
+                        for count in [16, 64] {
+                            if ui.small_button(count.to_string()).clicked() {
+                                self.accumulation_samples = count;
+                                changed = true;
+                            }
+                        }
+                    });
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    if ui
+                        .checkbox(&mut self.transparent_background, "Transparent background")
+                        .on_hover_text(
+                            "Save high-res renders as RGBA PNGs where the background is transparent:\n\
+                             rays that miss the fractal in 3D, escaped points in 2D.\n\
+                             Anti-aliasing gives edges partial alpha.",
+                        )
+                        .changed()
+                    {
+                        changed = true;
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    if ui
+                        .checkbox(&mut self.capture_16bit, "16-bit PNG")
+                        .on_hover_text(
+                            "Save high-res and final renders with 16 bits per channel.\n\
+                             Preserves smooth gradients that banding would show in 8 bits,\n\
+                             at about twice the file size.",
+                        )
+                        .changed()
+                    {
+                        changed = true;
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    ui.horizontal(|ui| {
+                        ui.label("Capture gamma:");
+                        changed |= ui
+                            .add(
+                                egui::Slider::new(
+                                    &mut self.capture_gamma,
+                                    crate::fractal::CAPTURE_GAMMA_RANGE,
+                                )
+                                .step_by(0.05),
+                            )
+                            .on_hover_text(
+                                "Gamma applied to saved screenshots and renders on top of sRGB.\n\
+                                 1.0 saves exactly the colors the view shows; raise it only if\n\
+                                 captures look darker in your image viewer than in the app.",
+                            )
+                            .changed();
+                        if self.capture_gamma != 1.0 && ui.small_button("Reset").clicked() {
+                            self.capture_gamma = 1.0;
+                            changed = true;
+                        }
+                    });
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button("🏆 Final Render")
+                            .on_hover_text(format!(
+                                "Render the current view at maximum quality: LOD off, {} supersampling,\n\
+                                 maximum ray march steps and shadow/DoF samples, FXAA off.\n\
+                                 Your interactive settings are left unchanged.",
+                                crate::fractal::FINAL_RENDER_ANTIALIASING.name()
+                            ))
+                            .clicked()
+                        {
+                            self.final_render_requested = true;
+                        }
+                        ui.label("at");
+                        egui::ComboBox::from_id_salt("final_render_size")
+                            .selected_text(if self.final_render_custom_size {
+                                "Custom resolution"
+                            } else {
+                                "Native resolution"
+                            })
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.final_render_custom_size, false, "Native resolution")
+                                    .on_hover_text("The size of the fractal view");
+                                ui.selectable_value(&mut self.final_render_custom_size, true, "Custom resolution")
+                                    .on_hover_text("The Width and Height below");
+                            });
+                    });
+
+                    ui.label(format!("Output: {}_WxH.png", self.filename_template))
+                        .on_hover_text("Saved to the capture folder below, named from the filename template");
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        Self::capture_directory_ui(ui, &mut self.capture_directory);
+                        filename_template::filename_template_controls(
+                            ui,
+                            &mut self.filename_template,
+                            &mut self.filename_template_edit,
+                        );
+                    }
 
                     ui.separator();
                     ui.heading("High-Resolution Render")
@@ -3022,6 +5014,10 @@ impl UI {
                     ui.add_space(4.0);
                     ui.label("Custom resolution:");
 
+                    let window_aspect = {
+                        let rect = ctx.content_rect();
+                        rect.width() / rect.height().max(1.0)
+                    };
                     ui.horizontal(|ui| {
                         ui.label("Width:");
                         let width_resp = ui.add(
@@ -3038,6 +5034,24 @@ impl UI {
                         if width_resp.changed() || height_resp.changed() {
                             changed = true;
                         }
+                        // Fill in the other dimension as the user types
+                        if self.lock_capture_aspect {
+                            if width_resp.changed() {
+                                if let Ok(width) = self.custom_width.trim().parse::<u32>() {
+                                    let height =
+                                        capture_size::matching_dimension(width, self.capture_aspect);
+                                    self.custom_height = height.to_string();
+                                }
+                            } else if height_resp.changed() {
+                                if let Ok(height) = self.custom_height.trim().parse::<u32>() {
+                                    let width = capture_size::matching_dimension(
+                                        height,
+                                        1.0 / self.capture_aspect,
+                                    );
+                                    self.custom_width = width.to_string();
+                                }
+                            }
+                        }
                         if ui
                             .button("Render")
                             .on_hover_text("Render at custom resolution")
@@ -3048,7 +5062,11 @@ impl UI {
                                 self.custom_width.trim().parse::<u32>(),
                                 self.custom_height.trim().parse::<u32>(),
                             ) {
-                                if width > 0 && height > 0 && width <= 16384 && height <= 16384 {
+                                if width > 0
+                                    && height > 0
+                                    && width <= capture_size::MAX_CAPTURE_DIMENSION
+                                    && height <= capture_size::MAX_CAPTURE_DIMENSION
+                                {
                                     hires_render_resolution = Some((width, height));
                                 } else {
                                     // Show error toast for invalid dimensions
@@ -3063,8 +5081,57 @@ impl UI {
                         }
                     });
 
-                    ui.label("Output: {fractal}_WxH_YYYYMMDD_HHMMSS.png")
-                        .on_hover_text("Saved to current directory. {fractal} = fractal type name");
+                    ui.horizontal(|ui| {
+                        if ui
+                            .checkbox(&mut self.lock_capture_aspect, "🔒 Lock Aspect Ratio")
+                            .on_hover_text("Update the other dimension as you type to keep the aspect ratio")
+                            .changed()
+                        {
+                            changed = true;
+                            // Lock the ratio of the current fields, or the window's if they're invalid
+                            if self.lock_capture_aspect {
+                                self.capture_aspect = capture_size::parse_custom_size(
+                                    &self.custom_width,
+                                    &self.custom_height,
+                                )
+                                .map_or(window_aspect, |(w, h)| w as f32 / h as f32);
+                            }
+                        }
+                        if self.lock_capture_aspect {
+                            ui.label(format!("{:.3}:1", self.capture_aspect));
+                            if ui
+                                .button("Window")
+                                .on_hover_text("Lock to the window's aspect ratio and update the height")
+                                .clicked()
+                            {
+                                self.capture_aspect = window_aspect;
+                                if let Ok(width) = self.custom_width.trim().parse::<u32>() {
+                                    let height =
+                                        capture_size::matching_dimension(width, window_aspect);
+                                    self.custom_height = height.to_string();
+                                }
+                                changed = true;
+                            }
+                        }
+                    });
+
+                    ui.label(format!("Output: {}_WxH.png", self.filename_template))
+                        .on_hover_text("Saved to the capture folder below, named from the filename template");
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        Self::capture_directory_ui(ui, &mut self.capture_directory);
+                        filename_template::filename_template_controls(
+                            ui,
+                            &mut self.filename_template,
+                            &mut self.filename_template_edit,
+                        );
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    if sweep::parameter_sweep_controls(ui, &mut self.parameter_sweep, params) {
+                        self.sweep_requested = true;
+                    }
 
                     ui.add_space(4.0);
                     let prev_auto_open = self.auto_open_captures;
@@ -3126,6 +5193,99 @@ impl UI {
                             self.video_fps = 24;
                         }
 
+                        ui.add_enabled_ui(!is_recording, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.label("Resolution:");
+                                let selected = if self.video_width == 0 || self.video_height == 0 {
+                                    "Window size".to_string()
+                                } else {
+                                    format!("{}x{}", self.video_width, self.video_height)
+                                };
+                                egui::ComboBox::from_id_salt("video_resolution")
+                                    .selected_text(selected)
+                                    .show_ui(ui, |ui| {
+                                        for &(label, width, height) in VIDEO_RESOLUTIONS {
+                                            let current = (self.video_width, self.video_height)
+                                                == (width, height);
+                                            if ui.selectable_label(current, label).clicked() {
+                                                self.video_width = width;
+                                                self.video_height = height;
+                                            }
+                                        }
+                                    })
+                                    .response
+                                    .on_hover_text(
+                                        "Record at a fixed size regardless of the window.\n\
+                                         Frames are rendered offscreen, without the UI.",
+                                    );
+                            });
+                            if self.video_width > 0 && self.video_height > 0 {
+                                ui.horizontal(|ui| {
+                                    ui.label("Custom:");
+                                    ui.add(
+                                        egui::DragValue::new(&mut self.video_width)
+                                            .range(2..=7680)
+                                            .suffix(" px"),
+                                    );
+                                    ui.label("x");
+                                    ui.add(
+                                        egui::DragValue::new(&mut self.video_height)
+                                            .range(2..=4320)
+                                            .suffix(" px"),
+                                    );
+                                });
+                                // yuv420p encoding needs even dimensions
+                                self.video_width &= !1;
+                                self.video_height &= !1;
+                            }
+                        });
+
+                        ui.horizontal(|ui| {
+                            ui.label("Burn in:").on_hover_text(
+                                "Draw text into the recorded frames (not shown in the window)",
+                            );
+                            let mut burn_in_changed = false;
+                            burn_in_changed |= ui.checkbox(&mut self.burn_in_frame_number, "Frame #")
+                                .on_hover_text("Frame number within the recording")
+                                .changed();
+                            burn_in_changed |= ui.checkbox(&mut self.burn_in_timecode, "Timecode")
+                                .on_hover_text("Elapsed video time as HH:MM:SS:FF")
+                                .changed();
+                            burn_in_changed |= ui.checkbox(&mut self.burn_in_parameters, "Parameters")
+                                .on_hover_text(
+                                    "Fractal type and view (2D center and zoom, or 3D camera position and FOV),\n\
+                                     and the values of parameters that changed since the recording started",
+                                )
+                                .changed();
+                            if burn_in_changed {
+                                let mut prefs = crate::fractal::AppPreferences::load();
+                                prefs.burn_in_frame_number = self.burn_in_frame_number;
+                                prefs.burn_in_timecode = self.burn_in_timecode;
+                                prefs.burn_in_parameters = self.burn_in_parameters;
+                                if let Err(e) = prefs.save() {
+                                    eprintln!("Failed to save preferences: {}", e);
+                                }
+                            }
+                        });
+
+                        ui.add_enabled_ui(!is_recording, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.checkbox(&mut self.video_auto_stop, "Stop after")
+                                    .on_hover_text(
+                                        "Stop and save the recording by itself once it has this many seconds of video or frames",
+                                    );
+                                ui.add_enabled(
+                                    self.video_auto_stop,
+                                    egui::DragValue::new(&mut self.video_stop_after).range(1..=36000),
+                                );
+                                ui.add_enabled_ui(self.video_auto_stop, |ui| {
+                                    ui.radio_value(&mut self.video_stop_in_frames, false, "seconds")
+                                        .on_hover_text("Seconds of video at the chosen FPS, not wall-clock time");
+                                    ui.radio_value(&mut self.video_stop_in_frames, true, "frames");
+                                });
+                            });
+                        });
+
                         ui.horizontal(|ui| {
                             if !is_recording {
                                 if ui
@@ -3144,9 +5304,9 @@ impl UI {
                             }
                         });
 
-                        ui.label("Output: {fractal}_YYYYMMDD_HHMMSS.{mp4,webm,gif}")
+                        ui.label(format!("Output: {}.{{mp4,webm,gif}}", self.filename_template))
                             .on_hover_text(
-                                "Saved to current directory. {fractal} = fractal type name",
+                                "Saved to the capture folder, named from the filename template",
                             );
 
                         if self.video_format == VideoFormat::GIF {
@@ -3268,6 +5428,21 @@ impl UI {
     }
 }
 
+/// Warn under the wallpaper buttons when safe mode will render below the
+/// desktop's size, so a wallpaper isn't silently downscaled
+fn safe_mode_wallpaper_note(ui: &mut egui::Ui, safe_mode: bool, (width, height): (u32, u32)) {
+    let (clamped, was_clamped) = crate::fractal::clamp_capture_resolution(width, height);
+    if safe_mode && was_clamped {
+        ui.colored_label(
+            egui::Color32::YELLOW,
+            format!(
+                "🛡 Safe mode renders this at {}x{} - turn it off in Settings for the full {}x{}",
+                clamped.0, clamped.1, width, height
+            ),
+        );
+    }
+}
+
 impl Default for UI {
     fn default() -> Self {
         Self::new()
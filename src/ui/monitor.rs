@@ -3,9 +3,51 @@ pub struct MonitorInfo {
     pub name: String,
     pub width: u32,
     pub height: u32,
+    /// Top-left corner on the desktop, in physical pixels
+    pub x: i32,
+    pub y: i32,
     pub is_primary: bool,
 }
 
+/// A monitor's area within a spanned wallpaper, in pixels from its top-left corner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpanRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Lay out one wallpaper spanning `monitors` as they are arranged on the
+/// desktop. Returns the size of their bounding box and each monitor's area
+/// within it; where monitors differ in size or are offset, the box includes
+/// padding that no monitor shows. `None` if no monitor has a size.
+pub fn spanned_layout(monitors: &[&MonitorInfo]) -> Option<((u32, u32), Vec<SpanRect>)> {
+    let monitors: Vec<&MonitorInfo> = monitors
+        .iter()
+        .copied()
+        .filter(|m| m.width > 0 && m.height > 0)
+        .collect();
+    let left = monitors.iter().map(|m| m.x as i64).min()?;
+    let top = monitors.iter().map(|m| m.y as i64).min()?;
+    let right = monitors.iter().map(|m| m.x as i64 + m.width as i64).max()?;
+    let bottom = monitors
+        .iter()
+        .map(|m| m.y as i64 + m.height as i64)
+        .max()?;
+
+    let rects = monitors
+        .iter()
+        .map(|m| SpanRect {
+            x: (m.x as i64 - left) as u32,
+            y: (m.y as i64 - top) as u32,
+            width: m.width,
+            height: m.height,
+        })
+        .collect();
+    Some((((right - left) as u32, (bottom - top) as u32), rects))
+}
+
 /// Helper function to compare two monitors for equality
 pub(super) fn monitors_equal(
     a: &winit::monitor::MonitorHandle,
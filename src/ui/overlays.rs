@@ -1,5 +1,5 @@
 use super::UI;
-use crate::fractal::FractalParams;
+use crate::fractal::{FractalParams, RenderMode};
 use egui::Context;
 use glam::Vec3;
 
@@ -98,9 +98,15 @@ impl UI {
     }
 
     /// Update and get the current palette animation offset using delta time
-    /// This accumulates the offset incrementally to avoid jumps when changing speed
+    /// This accumulates the offset incrementally to avoid jumps when changing speed.
+    /// Speed and direction changes ease the animation rate toward its new target
+    /// instead of snapping, while the offset itself stays continuous.
     pub fn update_palette_animation(&mut self, delta_time: f32) -> f32 {
+        /// Time constant (seconds) for easing the animation rate toward its target
+        const RATE_EASING_TIME: f32 = 0.25;
+
         if !self.palette_animation_enabled {
+            self.palette_animation_rate = 0.0;
             return 0.0;
         }
 
@@ -110,8 +116,13 @@ impl UI {
             1.0
         };
 
+        // Exponentially approach the target rate (frame-rate independent)
+        let target_rate = self.palette_animation_speed * direction;
+        let blend = 1.0 - (-delta_time / RATE_EASING_TIME).exp();
+        self.palette_animation_rate += (target_rate - self.palette_animation_rate) * blend;
+
         // Accumulate offset using delta time (avoids jumps when speed changes)
-        self.palette_animation_offset += delta_time * self.palette_animation_speed * direction;
+        self.palette_animation_offset += delta_time * self.palette_animation_rate;
 
         // Keep offset in [0, 1) range
         self.palette_animation_offset = self.palette_animation_offset.rem_euclid(1.0);
@@ -127,6 +138,21 @@ impl UI {
         }
     }
 
+    /// Frame time budget in milliseconds: the frame cap when one is set, else 60 FPS
+    pub fn frame_budget_ms(&self) -> f32 {
+        if self.max_fps > 0 {
+            1000.0 / self.max_fps as f32
+        } else {
+            1000.0 / 60.0
+        }
+    }
+
+    /// Number of frames in the history that took longer than the budget
+    pub fn frames_over_budget(&self) -> usize {
+        let budget = self.frame_budget_ms();
+        self.frame_times.iter().filter(|&&ft| ft > budget).count()
+    }
+
     /// Render performance overlay with FPS, frame time, and graph
     pub fn render_performance_overlay(&self, ctx: &Context, fps: f32) {
         if !self.show_performance_overlay {
@@ -173,6 +199,8 @@ impl UI {
                             );
                         });
 
+                        let budget = self.frame_budget_ms();
+
                         // Frame time display
                         if let Some(&last_frame_time) = self.frame_times.last() {
                             ui.horizontal(|ui| {
@@ -181,9 +209,9 @@ impl UI {
                                         .color(egui::Color32::from_rgb(180, 180, 180))
                                         .size(14.0),
                                 );
-                                let ft_color = if last_frame_time <= 16.67 {
+                                let ft_color = if last_frame_time <= budget {
                                     egui::Color32::from_rgb(0, 255, 0)
-                                } else if last_frame_time <= 33.33 {
+                                } else if last_frame_time <= budget * 2.0 {
                                     egui::Color32::from_rgb(255, 255, 0)
                                 } else {
                                     egui::Color32::from_rgb(255, 100, 100)
@@ -199,7 +227,7 @@ impl UI {
 
                         ui.add_space(8.0);
 
-                        // Frame time graph (similar to three.js stats)
+                        // Scrolling frame time graph (similar to three.js stats)
                         if !self.frame_times.is_empty() {
                             let graph_height = 60.0;
                             let graph_width = 200.0;
@@ -223,41 +251,42 @@ impl UI {
                                 .cloned()
                                 .fold(0.0f32, f32::max)
                                 .min(50.0);
-                            let max_display = max_ft.max(16.67); // At least show 60fps line
+                            // Always leave headroom above the budget line
+                            let max_display = max_ft.max(budget * 1.25);
 
-                            // Draw target frame time lines
-                            // 60 FPS line (16.67ms)
-                            let y_60fps = rect.max.y - (16.67 / max_display) * graph_height;
+                            // Frame budget line (60 FPS or the frame cap)
+                            let y_budget = rect.max.y - (budget / max_display) * graph_height;
                             ui.painter().hline(
                                 rect.min.x..=rect.max.x,
-                                y_60fps,
+                                y_budget,
                                 (1.0, egui::Color32::from_rgb(0, 180, 0)),
                             );
 
-                            // 30 FPS line (33.33ms)
-                            if max_display >= 33.33 {
-                                let y_30fps = rect.max.y - (33.33 / max_display) * graph_height;
+                            // Twice the budget (a dropped frame)
+                            if max_display >= budget * 2.0 {
+                                let y_double =
+                                    rect.max.y - (budget * 2.0 / max_display) * graph_height;
                                 ui.painter().hline(
                                     rect.min.x..=rect.max.x,
-                                    y_30fps,
+                                    y_double,
                                     (1.0, egui::Color32::from_rgb(180, 180, 0)),
                                 );
                             }
 
-                            // Draw frame time graph
+                            // Draw frame time bars with a fixed width, newest at the right,
+                            // so the history scrolls left as frames arrive
                             let num_samples = self.frame_times.len();
-                            let bar_width = graph_width / num_samples as f32;
+                            let bar_width = graph_width / self.max_frame_history.max(1) as f32;
+                            let start_x = rect.max.x - num_samples as f32 * bar_width;
 
                             for (i, &ft) in self.frame_times.iter().enumerate() {
                                 let normalized_height = (ft / max_display).min(1.0) * graph_height;
-                                let x = rect.min.x + i as f32 * bar_width;
+                                let x = start_x + i as f32 * bar_width;
                                 let y = rect.max.y - normalized_height;
 
-                                // Color based on performance
-                                let bar_color = if ft <= 16.67 {
-                                    egui::Color32::from_rgb(0, 255, 0)
-                                } else if ft <= 33.33 {
-                                    egui::Color32::from_rgb(255, 255, 0)
+                                // Spikes over the budget stand out in red
+                                let bar_color = if ft <= budget {
+                                    egui::Color32::from_rgb(0, 200, 0)
                                 } else {
                                     egui::Color32::from_rgb(255, 80, 80)
                                 };
@@ -289,21 +318,206 @@ impl UI {
                                         .size(11.0),
                                 );
                                 ui.label(
-                                    egui::RichText::new(format!("{} frames", num_samples))
+                                    egui::RichText::new(format!("Budget: {:.1} ms", budget))
                                         .color(egui::Color32::from_rgb(150, 150, 150))
                                         .size(11.0),
                                 );
+                                let over_budget = self.frames_over_budget();
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "{}/{} over",
+                                        over_budget, num_samples
+                                    ))
+                                    .color(if over_budget > 0 {
+                                        egui::Color32::from_rgb(255, 120, 120)
+                                    } else {
+                                        egui::Color32::from_rgb(150, 150, 150)
+                                    })
+                                    .size(11.0),
+                                );
                             });
                         }
                     });
             });
     }
 
+    /// Render the 2D hover readout next to the cursor: the complex coordinate and
+    /// escape iteration count read back from the GPU probe
+    pub fn render_iteration_readout(&self, ctx: &Context, params: &FractalParams) {
+        if !self.show_iteration_readout
+            || params.render_mode != RenderMode::TwoD
+            || params.fractal_type.uses_accumulation()
+        {
+            return;
+        }
+        let Some(sample) = self.hover_probe else {
+            return;
+        };
+        // Hide while the pointer is outside the window or over UI panels
+        let Some(pointer) = ctx.pointer_hover_pos() else {
+            return;
+        };
+        if ctx.is_pointer_over_area() {
+            return;
+        }
+
+        let iterations = if sample.t > 0.0 {
+            format!(
+                "Iterations: {:.1} / {}",
+                sample.t * params.max_iterations as f32,
+                params.max_iterations
+            )
+        } else {
+            format!("Iterations: {} (did not escape)", params.max_iterations)
+        };
+
+        egui::Area::new(egui::Id::new("iteration_readout"))
+            .order(egui::Order::Tooltip)
+            .interactable(false)
+            .fixed_pos(pointer + egui::vec2(16.0, 16.0))
+            .show(ctx, |ui| {
+                egui::Frame::NONE
+                    .fill(egui::Color32::from_black_alpha(220))
+                    .inner_margin(6.0)
+                    .corner_radius(4.0)
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{:.6} {:+.6}i",
+                                sample.coord[0], sample.coord[1]
+                            ))
+                            .color(egui::Color32::from_rgb(200, 200, 255))
+                            .size(12.0)
+                            .monospace(),
+                        );
+                        ui.label(
+                            egui::RichText::new(iterations)
+                                .color(egui::Color32::from_rgb(200, 255, 200))
+                                .size(12.0),
+                        );
+                    });
+            });
+    }
+
+    /// Render a small light-direction compass while the light is being dragged:
+    /// the dot's angle is the azimuth and its distance from the rim the elevation
+    /// (center = straight overhead)
+    pub fn render_light_indicator(&self, ctx: &Context, params: &FractalParams, active: bool) {
+        if !active {
+            return;
+        }
+
+        egui::Area::new(egui::Id::new("light_indicator"))
+            .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -40.0))
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::NONE
+                    .fill(egui::Color32::from_black_alpha(200))
+                    .inner_margin(8.0)
+                    .corner_radius(4.0)
+                    .show(ui, |ui| {
+                        ui.vertical_centered(|ui| {
+                            let radius = 32.0;
+                            let (rect, _) = ui.allocate_exact_size(
+                                egui::vec2(radius * 2.0 + 4.0, radius * 2.0 + 4.0),
+                                egui::Sense::hover(),
+                            );
+                            let painter = ui.painter();
+                            let center = rect.center();
+                            painter.circle_stroke(
+                                center,
+                                radius,
+                                egui::Stroke::new(1.0, egui::Color32::GRAY),
+                            );
+                            let azimuth = params.light_azimuth.to_radians();
+                            let distance = radius * (1.0 - params.light_elevation / 90.0);
+                            let light =
+                                center + egui::vec2(azimuth.cos(), -azimuth.sin()) * distance;
+                            painter.line_segment(
+                                [center, light],
+                                egui::Stroke::new(1.0, egui::Color32::from_gray(160)),
+                            );
+                            painter.circle_filled(
+                                light,
+                                5.0,
+                                egui::Color32::from_rgb(255, 220, 120),
+                            );
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "Light  az {:.0}°  el {:.0}°",
+                                    params.light_azimuth, params.light_elevation
+                                ))
+                                .color(egui::Color32::WHITE)
+                                .size(12.0),
+                            );
+                        });
+                    });
+            });
+    }
+
+    /// Outline the box being dragged for a 2D box zoom, plus the area that will
+    /// actually be framed once the box is expanded to the view's aspect ratio.
+    /// Positions are in physical window pixels; `view` is the render area's
+    /// origin and size.
+    pub fn render_box_zoom(
+        &self,
+        ctx: &Context,
+        selection: Option<((f32, f32), (f32, f32))>,
+        view: ((f32, f32), (f32, f32)),
+    ) {
+        let Some((start, end)) = selection else {
+            return;
+        };
+        let scale = 1.0 / ctx.pixels_per_point();
+        let to_points = |p: (f32, f32)| egui::pos2(p.0 * scale, p.1 * scale);
+        let drawn = egui::Rect::from_two_pos(to_points(start), to_points(end));
+        let (origin, size) = view;
+        let view_rect = egui::Rect::from_min_size(
+            to_points(origin),
+            egui::vec2(size.0 * scale, size.1 * scale),
+        );
+        if drawn.width() <= 0.0 && drawn.height() <= 0.0 {
+            return;
+        }
+
+        let fraction = (drawn.width() / view_rect.width()).max(drawn.height() / view_rect.height());
+        let framed = egui::Rect::from_center_size(drawn.center(), view_rect.size() * fraction);
+
+        let painter = ctx.layer_painter(egui::LayerId::new(
+            egui::Order::Foreground,
+            egui::Id::new("box_zoom"),
+        ));
+        painter.rect_filled(drawn, 0.0, egui::Color32::from_white_alpha(24));
+        painter.rect_stroke(
+            framed,
+            0.0,
+            egui::Stroke::new(1.0, egui::Color32::from_white_alpha(90)),
+            egui::StrokeKind::Middle,
+        );
+        painter.rect_stroke(
+            drawn,
+            0.0,
+            egui::Stroke::new(1.5, egui::Color32::WHITE),
+            egui::StrokeKind::Middle,
+        );
+        if fraction > 0.0 {
+            painter.text(
+                drawn.left_top() - egui::vec2(0.0, 4.0),
+                egui::Align2::LEFT_BOTTOM,
+                format!("×{:.1}", 1.0 / fraction),
+                egui::FontId::proportional(12.0),
+                egui::Color32::WHITE,
+            );
+        }
+    }
+
     pub fn render_recording_indicator(
         &self,
         ctx: &Context,
         is_recording: bool,
         frame_count: u32,
+        frame_limit: Option<u32>,
+        fps: u32,
         filename: &str,
     ) {
         if !is_recording {
@@ -332,11 +546,26 @@ impl UI {
 
                             ui.colored_label(
                                 egui::Color32::WHITE,
-                                egui::RichText::new(format!("REC  {} frames", frame_count))
-                                    .size(16.0)
-                                    .strong(),
+                                egui::RichText::new(match frame_limit {
+                                    Some(limit) => {
+                                        format!("REC  {} / {} frames", frame_count, limit)
+                                    }
+                                    None => format!("REC  {} frames", frame_count),
+                                })
+                                .size(16.0)
+                                .strong(),
                             );
                         });
+                        // Countdown to the automatic stop
+                        if let Some(limit) = frame_limit {
+                            let remaining =
+                                limit.saturating_sub(frame_count) as f32 / fps.max(1) as f32;
+                            ui.add(
+                                egui::ProgressBar::new(frame_count as f32 / limit.max(1) as f32)
+                                    .desired_width(200.0)
+                                    .text(format!("{:.1} s left", remaining)),
+                            );
+                        }
                         ui.colored_label(
                             egui::Color32::from_rgb(255, 255, 200),
                             egui::RichText::new(filename).size(12.0),
@@ -387,6 +616,13 @@ impl UI {
                                 .size(20.0)
                                 .strong(),
                         );
+                        if params.lod_state.frozen {
+                            ui.label(
+                                egui::RichText::new("❄ Frozen")
+                                    .color(egui::Color32::from_rgb(150, 200, 255))
+                                    .size(12.0),
+                            );
+                        }
 
                         ui.add_space(4.0);
 
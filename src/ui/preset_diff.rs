@@ -0,0 +1,75 @@
+use super::UI;
+use crate::fractal::{FractalParams, Preset, SettingsChange};
+use egui::Context;
+use glam::Vec3;
+
+/// A preset compared with the current settings, shown in its own window
+pub struct PresetDiff {
+    name: String,
+    changes: Vec<SettingsChange>,
+}
+
+/// Preset comparison window methods
+impl UI {
+    /// Open the comparison window for `preset` against the current settings
+    pub(super) fn compare_preset(
+        &mut self,
+        preset: &Preset,
+        params: &FractalParams,
+        camera_pos: Vec3,
+        camera_target: Vec3,
+    ) {
+        let current = Preset::from_current(
+            String::new(),
+            String::new(),
+            preset.category,
+            params,
+            camera_pos,
+            camera_target,
+        );
+        self.preset_diff = Some(PresetDiff {
+            name: preset.name.clone(),
+            changes: preset.settings.diff(&current.settings),
+        });
+    }
+
+    pub fn render_preset_diff(&mut self, ctx: &Context) {
+        let Some(diff) = &self.preset_diff else {
+            return;
+        };
+
+        let mut open = true;
+        egui::Window::new(format!("Δ Changes from '{}'", diff.name))
+            .id(egui::Id::new("preset_diff_window"))
+            .open(&mut open)
+            .default_size([460.0, 320.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                if diff.changes.is_empty() {
+                    ui.label("The current settings match this preset.");
+                    return;
+                }
+                ui.label(format!(
+                    "{} field(s) differ (preset → current):",
+                    diff.changes.len()
+                ));
+                ui.separator();
+                egui::ScrollArea::both().show(ui, |ui| {
+                    egui::Grid::new("preset_diff_grid")
+                        .num_columns(2)
+                        .striped(true)
+                        .spacing([12.0, 4.0])
+                        .show(ui, |ui| {
+                            for change in &diff.changes {
+                                ui.monospace(&change.field);
+                                ui.label(format!("{} → {}", change.old, change.new));
+                                ui.end_row();
+                            }
+                        });
+                });
+            });
+        if !open {
+            self.preset_diff = None;
+        }
+    }
+}
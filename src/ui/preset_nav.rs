@@ -0,0 +1,126 @@
+//! Keyboard navigation for the built-in preset list
+//!
+//! Clicking a preset (or focusing the search box) lets Up/Down move a highlighted
+//! selection and Enter load it. While the list itself has focus, typing a name
+//! jumps to the first matching preset, so the gallery works without a mouse.
+
+use super::UI;
+
+/// Typed characters older than this start a new type-to-search query
+const TYPEAHEAD_TIMEOUT_SECS: f32 = 1.0;
+
+/// What the keyboard did to the preset list this frame
+#[derive(Debug, Default)]
+pub(super) struct PresetListNav {
+    /// Whether the list or the search box has keyboard focus
+    pub(super) active: bool,
+    /// The selection changed and should be scrolled into view
+    pub(super) moved: bool,
+    /// Index of the preset to load
+    pub(super) load: Option<usize>,
+}
+
+/// Preset list navigation methods
+impl UI {
+    /// Handle preset list keys. `names` are the presets currently listed, in order.
+    pub(super) fn preset_list_keys(
+        &mut self,
+        ctx: &egui::Context,
+        list_id: egui::Id,
+        search_focused: bool,
+        names: &[&str],
+    ) -> PresetListNav {
+        let list_focused = ctx.memory(|m| m.has_focus(list_id));
+        let mut nav = PresetListNav {
+            active: list_focused || search_focused,
+            ..Default::default()
+        };
+        if names.is_empty() {
+            return nav;
+        }
+        let last = names.len() - 1;
+        self.preset_selected = self.preset_selected.min(last);
+        if !nav.active {
+            return nav;
+        }
+
+        if list_focused {
+            // Keep arrow keys for the list instead of moving focus between widgets
+            ctx.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    list_id,
+                    egui::EventFilter {
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                )
+            });
+        }
+
+        let (up, down, home, end, enter, typed) = ctx.input(|i| {
+            let typed: String = i
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    egui::Event::Text(text) => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect();
+            (
+                i.key_pressed(egui::Key::ArrowUp),
+                i.key_pressed(egui::Key::ArrowDown),
+                i.key_pressed(egui::Key::Home),
+                i.key_pressed(egui::Key::End),
+                i.key_pressed(egui::Key::Enter),
+                typed,
+            )
+        });
+
+        let previous = self.preset_selected;
+        if up {
+            self.preset_selected = self.preset_selected.saturating_sub(1);
+        }
+        if down {
+            self.preset_selected = (self.preset_selected + 1).min(last);
+        }
+        // Home/End would move the cursor in the search box, so only the list uses them
+        if list_focused && home {
+            self.preset_selected = 0;
+        }
+        if list_focused && end {
+            self.preset_selected = last;
+        }
+
+        // Typing in the search box filters instead
+        if list_focused && !typed.is_empty() {
+            if self.preset_typeahead_time.elapsed().as_secs_f32() > TYPEAHEAD_TIMEOUT_SECS {
+                self.preset_typeahead.clear();
+            }
+            self.preset_typeahead.push_str(&typed);
+            self.preset_typeahead_time = web_time::Instant::now();
+            if let Some(index) = find_preset_by_name(names, &self.preset_typeahead) {
+                self.preset_selected = index;
+            }
+        }
+
+        nav.moved = self.preset_selected != previous;
+        if enter {
+            nav.load = Some(self.preset_selected);
+        }
+        nav
+    }
+}
+
+/// First preset whose name starts with `query` (case-insensitive), falling back
+/// to the first one containing it
+pub(super) fn find_preset_by_name(names: &[&str], query: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    if query.trim().is_empty() {
+        return None;
+    }
+    let lower: Vec<String> = names.iter().map(|name| name.to_lowercase()).collect();
+    lower
+        .iter()
+        .position(|name| name.starts_with(&query))
+        .or_else(|| lower.iter().position(|name| name.contains(&query)))
+}
@@ -0,0 +1,125 @@
+//! Gallery of recent Randomize results
+//!
+//! Each roll of the Randomize button is kept with a thumbnail (rendered
+//! offscreen by the app) so a good result a few rolls back can be brought
+//! back with a click. Unlike undo/redo this only tracks Randomize outputs.
+
+use super::UI;
+use crate::fractal::FractalParams;
+use std::collections::VecDeque;
+
+/// Number of recent rolls kept
+const GALLERY_SIZE: usize = 10;
+/// Size of the gallery buttons, in points
+const THUMBNAIL_SIZE: egui::Vec2 = egui::vec2(72.0, 48.0);
+
+/// One Randomize result and its thumbnail, once the app has rendered it
+pub struct RandomizeResult {
+    pub params: FractalParams,
+    pub thumbnail: Option<egui::TextureHandle>,
+    /// Rendering the thumbnail failed, so it isn't retried
+    pub thumbnail_failed: bool,
+}
+
+/// Recent Randomize results, newest first
+#[derive(Default)]
+pub struct RandomizeGallery {
+    results: VecDeque<RandomizeResult>,
+    /// Result last rolled or restored, highlighted in the gallery
+    current: Option<usize>,
+}
+
+impl RandomizeGallery {
+    /// Keep a new roll, dropping the oldest past the gallery size
+    pub fn push(&mut self, params: &FractalParams) {
+        self.results.push_front(RandomizeResult {
+            params: params.clone(),
+            thumbnail: None,
+            thumbnail_failed: false,
+        });
+        self.results.truncate(GALLERY_SIZE);
+        self.current = Some(0);
+    }
+
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&RandomizeResult> {
+        self.results.get(index)
+    }
+
+    /// The oldest-requested result still waiting for a thumbnail, for the app
+    /// to render
+    pub fn next_without_thumbnail(&self) -> Option<(usize, &FractalParams)> {
+        self.results
+            .iter()
+            .enumerate()
+            .find(|(_, result)| result.thumbnail.is_none() && !result.thumbnail_failed)
+            .map(|(index, result)| (index, &result.params))
+    }
+
+    pub fn set_thumbnail(&mut self, index: usize, texture: egui::TextureHandle) {
+        if let Some(result) = self.results.get_mut(index) {
+            result.thumbnail = Some(texture);
+        }
+    }
+
+    /// Stop asking for a thumbnail that couldn't be rendered
+    pub fn set_thumbnail_failed(&mut self, index: usize) {
+        if let Some(result) = self.results.get_mut(index) {
+            result.thumbnail_failed = true;
+        }
+    }
+}
+
+impl UI {
+    /// Show the recent rolls under the Randomize button. Returns the
+    /// parameters of a clicked result to restore.
+    pub(super) fn randomize_gallery_ui(&mut self, ui: &mut egui::Ui) -> Option<FractalParams> {
+        if self.randomize_gallery.is_empty() {
+            return None;
+        }
+        let mut restore = None;
+        egui::CollapsingHeader::new(format!(
+            "🎲 Recent Rolls ({})",
+            self.randomize_gallery.len()
+        ))
+        .id_salt("randomize_gallery")
+        .show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                let gallery = &mut self.randomize_gallery;
+                for (index, result) in gallery.results.iter().enumerate() {
+                    let button = match &result.thumbnail {
+                        Some(texture) => egui::Button::image(
+                            egui::Image::new(texture).fit_to_exact_size(THUMBNAIL_SIZE),
+                        ),
+                        None if result.thumbnail_failed => {
+                            egui::Button::new("✗").min_size(THUMBNAIL_SIZE)
+                        }
+                        None => egui::Button::new("…").min_size(THUMBNAIL_SIZE),
+                    }
+                    .selected(gallery.current == Some(index));
+                    if ui
+                        .add(button)
+                        .on_hover_text(format!(
+                            "{:?}\nClick to restore this roll",
+                            result.params.fractal_type
+                        ))
+                        .clicked()
+                    {
+                        restore = Some(index);
+                    }
+                }
+                if let Some(index) = restore {
+                    gallery.current = Some(index);
+                }
+            });
+        });
+        restore.and_then(|index| self.randomize_gallery.get(index).map(|r| r.params.clone()))
+    }
+}
@@ -0,0 +1,348 @@
+//! Control panel search
+//!
+//! The Fractal Controls window has far more settings than fit on screen. This
+//! registry lists the controls in each collapsible section so the search box at
+//! the top of the panel can open the sections with matches, collapse the rest,
+//! and list the matching controls at the top of each open section.
+
+/// Searchable control labels for each section, keyed by section title.
+/// Keep in sync with the labels used in `UI::render` (checked by a test).
+pub(super) const SECTION_CONTROLS: &[(&str, &[&str])] = &[
+    (
+        "Fractal Type",
+        &[
+            "2D Fractals",
+            "2D Density Fractals",
+            "2D Strange Attractors",
+            "3D Fractals",
+            "3D Strange Attractors",
+            "Shown Types",
+        ],
+    ),
+    (
+        "Presets",
+        &[
+            "Category",
+            "Search",
+            "Revert to",
+            "Built-in Presets",
+            "User Presets",
+            "Compare with the current settings",
+            "Save Current as Preset",
+            "Import / Export",
+            "Export to JSON",
+            "Import from JSON",
+            "Copy Recipe Card",
+            "Copy Shader Parameters",
+        ],
+    ),
+    (
+        "Color & Visualization",
+        &[
+            "Color Mode",
+            "Interior",
+            "Interior Lighting",
+            "Distance Estimation Edges",
+            "Distance Contours",
+            "Contour Spacing",
+            "Debug Modes",
+            "Preview",
+            "Shuffle Colors",
+            "Procedural Palette",
+            "Custom Palette Parameters",
+            "Static Palette",
+            "Animate Palette",
+            "Speed",
+            "Reverse Direction",
+            "Orbit Trap Scale",
+            "Trap Offset",
+            "Orbit Trap Image",
+            "Channel Mapping",
+            "Custom Palettes",
+            "Import Palette",
+            "Extract from Image",
+            "Paste Palette from Clipboard",
+        ],
+    ),
+    (
+        "2D Parameters",
+        &[
+            "Max Iterations",
+            "Power",
+            "Polynomial Roots",
+            "Julia Constant",
+            "Polar",
+            "Phoenix Distortion",
+            "Collatz Map",
+            "Multiplier",
+            "Offset",
+            "Reset View",
+            "Accumulation Settings",
+            "Iterations/Frame",
+            "Density Scale",
+            "Hold While Recording",
+            "Re-clear Every",
+            "Color by",
+            "Attractor Parameters",
+            "High-Precision Coordinates",
+            "Boundary Supersampling",
+            "Iteration Readout",
+            "Seamless Tile",
+            "3D Relief",
+            "Bookmarks",
+            "Save Bookmark",
+            "Quick Bookmark",
+        ],
+    ),
+    (
+        "3D Parameters",
+        &[
+            "Fractal Shape",
+            "Scale",
+            "Power",
+            "Julia Constant",
+            "Polar",
+            "Iterations",
+            "Fold",
+            "Min Radius",
+            "IFS Offset",
+            "Symmetric",
+            "Height",
+            "Composite Second Fractal",
+            "Attractor Parameters",
+            "Accumulation Settings",
+            "Iterations/Frame",
+            "Density Scale",
+            "Hold While Recording",
+            "Re-clear Every",
+        ],
+    ),
+    (
+        "Ray Marching",
+        &[
+            "Adaptive Step Size",
+            "Step Multiplier",
+            "Fixed Step Size",
+            "Max Steps",
+            "Min Distance",
+            "Detail Falloff",
+            "Max Distance",
+        ],
+    ),
+    (
+        "Camera",
+        &[
+            "Movement Speed",
+            "Smooth Movement",
+            "Acceleration",
+            "Damping",
+            "Field of View (FOV)",
+            "Up Axis",
+            "Auto Orbit",
+            "Orbit Speed",
+            "Stereo 3D",
+            "Eye Separation",
+            "Camera Info Overlay",
+            "Reset Camera",
+            "Point at Fractal",
+            "Camera Bookmarks",
+            "Quick Bookmark",
+            "Transition",
+            "Camera Path",
+            "Add Keyframe",
+            "Scrub",
+            "Export Path",
+            "Import Path",
+        ],
+    ),
+    (
+        "Shading",
+        &[
+            "Material Properties",
+            "Roughness",
+            "Metallic",
+            "Specular Color",
+            "Specular Intensity",
+        ],
+    ),
+    (
+        "Lighting",
+        &[
+            "Light Intensity",
+            "Ambient Light",
+            "Light Direction",
+            "Azimuth",
+            "Elevation",
+            "Shadows",
+            "Shadow Distance",
+            "Shadow Samples",
+            "Shadow Accuracy",
+            "Shadow Softness",
+            "Light Size",
+            "Ambient Occlusion",
+            "AO Intensity",
+            "AO Step Size",
+        ],
+    ),
+    (
+        "Effects",
+        &[
+            "Depth of Field",
+            "Focal Length",
+            "Aperture",
+            "DOF Samples",
+            "Bokeh Shape",
+            "Fog",
+            "Fog Density",
+            "Fog Start",
+            "Fog End",
+            "Fog Color",
+            "Color Grading",
+            "Brightness",
+            "Contrast",
+            "Saturation",
+            "Hue Shift",
+            "Auto Exposure",
+            "Adaptation Speed",
+            "Vignette",
+            "Vignette Intensity",
+            "Vignette Radius",
+            "Porthole",
+            "Porthole Radius",
+            "Porthole Feather",
+            "Symmetry",
+            "Segments",
+            "Bloom",
+            "Threshold",
+            "Intensity",
+            "Spread",
+            "Quality",
+            "FXAA Anti-aliasing",
+            "Color Blindness",
+        ],
+    ),
+    (
+        "Floor",
+        &[
+            "Show Floor",
+            "Floor Height",
+            "Checker Scale",
+            "Checker Rotation",
+            "Floor Colors",
+            "Floor Reflections",
+            "Reflection Strength",
+        ],
+    ),
+    (
+        "LOD System",
+        &[
+            "Enable LOD System",
+            "Profile",
+            "Strategy",
+            "Target FPS",
+            "Debug Visualization",
+            "Freeze at",
+            "Freeze while recording",
+            "Near -> Mid",
+            "Mid -> Far",
+            "Far -> Distant",
+            "Motion Sensitivity",
+            "Motion Threshold",
+            "Restore Delay",
+            "Quality Levels",
+            "Render Scale",
+            "Smooth Transitions",
+            "Transition Duration",
+            "Aggressive Mode",
+            "Minimum Quality Level",
+        ],
+    ),
+    (
+        "Settings",
+        &[
+            "Save Settings",
+            "Revert to Last Saved",
+            "Reset to Defaults",
+            "Layout",
+            "Control Panel",
+            "Automatic UI scale",
+            "UI scale",
+            "Zoom toward cursor",
+            "Pan and zoom momentum",
+            "Remember coloring per fractal",
+            "Auto-save settings",
+            "Present Mode",
+            "Max FPS",
+            "Throttle when idle",
+            "Idle FPS",
+            "Attract Mode",
+            "Idle timeout",
+            "Display Format",
+            "Surface Format",
+            "Safe Mode",
+            "GPU Selection",
+            "Profile",
+            "Export Profile",
+            "Import Profile",
+            "Console",
+            "Diagnostics",
+            "Warn on blank frames",
+            "Auto-revert blank changes",
+            "Screenshots",
+            "Render at 2x and downscale",
+        ],
+    ),
+    ("Controls", &["General", "Number Keys", "Mouse"]),
+];
+
+/// What a search query matched in one section
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SectionMatch {
+    /// The section title itself contains the query
+    pub title: bool,
+    /// Labels of the section's controls that contain the query
+    pub controls: Vec<&'static str>,
+}
+
+impl SectionMatch {
+    pub fn is_match(&self) -> bool {
+        self.title || !self.controls.is_empty()
+    }
+}
+
+/// Match a query against a section title and its registered controls,
+/// case-insensitively. Every whitespace-separated word must appear in the label.
+pub fn search_section(section: &str, query: &str) -> SectionMatch {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if words.is_empty() {
+        return SectionMatch::default();
+    }
+    let matches = |label: &str| {
+        let label = label.to_lowercase();
+        words.iter().all(|word| label.contains(word.as_str()))
+    };
+
+    let controls = SECTION_CONTROLS
+        .iter()
+        .find(|(title, _)| *title == section)
+        .map(|(_, controls)| controls.iter().copied().filter(|c| matches(c)).collect())
+        .unwrap_or_default();
+    SectionMatch {
+        title: matches(section),
+        controls,
+    }
+}
+
+/// Show the matching control labels at the top of a section body
+pub fn show_matches(ui: &mut egui::Ui, controls: &[&str]) {
+    if controls.is_empty() {
+        return;
+    }
+    let highlight = ui.visuals().warn_fg_color;
+    ui.label(
+        egui::RichText::new(format!("🔍 {}", controls.join(" · ")))
+            .color(highlight)
+            .strong(),
+    );
+    ui.separator();
+}
@@ -0,0 +1,122 @@
+//! Batch parameter sweep controls
+//!
+//! A sweep renders the current view once per value of one parameter, spaced
+//! evenly over a range, as a contact sheet or a folder of numbered images.
+
+use crate::fractal::SweepParam;
+
+/// Settings for a batch parameter sweep, rendered by the app
+#[derive(Debug, Clone)]
+pub struct ParameterSweep {
+    pub param: SweepParam,
+    pub min: f32,
+    pub max: f32,
+    /// Number of images, including both ends of the range
+    pub count: u32,
+    /// Width of each image; the height follows the view's aspect ratio
+    pub tile_width: u32,
+    /// One grid image, or a folder of numbered images
+    pub contact_sheet: bool,
+}
+
+impl Default for ParameterSweep {
+    fn default() -> Self {
+        let param = SweepParam::default();
+        let (min, max) = param.default_range();
+        Self {
+            param,
+            min,
+            max,
+            count: 9,
+            tile_width: 320,
+            contact_sheet: true,
+        }
+    }
+}
+
+/// Show the parameter sweep controls in the capture window. Returns true when
+/// the sweep should be rendered.
+#[cfg(not(target_arch = "wasm32"))]
+pub(super) fn parameter_sweep_controls(
+    ui: &mut egui::Ui,
+    sweep: &mut ParameterSweep,
+    params: &crate::fractal::FractalParams,
+) -> bool {
+    ui.separator();
+    ui.heading("📊 Parameter Sweep")
+        .on_hover_text("Render a fractal across a range of one parameter, for comparison charts");
+
+    ui.horizontal(|ui| {
+        ui.label("Vary:");
+        egui::ComboBox::from_id_salt("sweep_param")
+            .selected_text(sweep.param.name())
+            .show_ui(ui, |ui| {
+                for &param in SweepParam::ALL {
+                    if param.applies_to(params.render_mode)
+                        && ui
+                            .selectable_value(&mut sweep.param, param, param.name())
+                            .changed()
+                    {
+                        (sweep.min, sweep.max) = param.default_range();
+                    }
+                }
+            });
+        if ui
+            .small_button("Current")
+            .on_hover_text("Center the range on the current value")
+            .clicked()
+        {
+            let value = sweep.param.get(params);
+            let half = (sweep.max - sweep.min).abs() / 2.0;
+            (sweep.min, sweep.max) = (value - half, value + half);
+        }
+    });
+    ui.horizontal(|ui| {
+        ui.label("From");
+        ui.add(egui::DragValue::new(&mut sweep.min).speed(0.01));
+        ui.label("to");
+        ui.add(egui::DragValue::new(&mut sweep.max).speed(0.01));
+    });
+    ui.add(egui::Slider::new(&mut sweep.count, 2..=64).text("Images"))
+        .on_hover_text("Number of images, spaced evenly from the start to the end of the range");
+    ui.add(
+        egui::Slider::new(&mut sweep.tile_width, 64..=1024)
+            .text("Image width")
+            .suffix(" px"),
+    )
+    .on_hover_text("Width of each image; the height follows the view's aspect ratio");
+    ui.horizontal(|ui| {
+        ui.radio_value(&mut sweep.contact_sheet, true, "Contact sheet")
+            .on_hover_text(
+                "Arrange the images in a grid in one PNG, left to right and top to bottom",
+            );
+        ui.radio_value(&mut sweep.contact_sheet, false, "Numbered images")
+            .on_hover_text(
+                "Save each image to a new folder, numbered in order with its value in the name",
+            );
+    });
+
+    let applies = sweep.param.applies_to(params.render_mode);
+    let accumulates = params.fractal_type.uses_accumulation();
+    let (columns, rows) = crate::fractal::contact_sheet_grid(sweep.count);
+    let label = if sweep.contact_sheet {
+        format!("▶ Render {}x{} Sheet", columns, rows)
+    } else {
+        format!("▶ Render {} Images", sweep.count)
+    };
+    let response = ui.add_enabled(applies && !accumulates, egui::Button::new(label));
+    let response = if accumulates {
+        response.on_disabled_hover_text(
+            "Accumulating fractals build their image over many frames, so they can't be swept",
+        )
+    } else if !applies {
+        response.on_disabled_hover_text(format!(
+            "{} has no effect on this fractal",
+            sweep.param.name()
+        ))
+    } else {
+        response
+            .on_hover_text("Render the current view once per value; the app pauses until it's done")
+    };
+    response.clicked()
+}
@@ -29,3 +29,326 @@ fn test_ui_initial_state() {
     let ui = UI::new();
     assert!(ui.show_ui);
 }
+
+#[test]
+fn test_palette_animation_reverse_eases_without_jump() {
+    let mut ui = UI::new();
+    ui.palette_animation_enabled = true;
+    ui.palette_animation_speed = 0.5;
+
+    // Let the rate settle at full forward speed
+    let mut offset = 0.0;
+    for _ in 0..120 {
+        offset = ui.update_palette_animation(1.0 / 60.0);
+    }
+    assert!((ui.palette_animation_rate - 0.5).abs() < 0.01);
+
+    // Reversing must not snap the rate or the offset
+    ui.palette_animation_reverse = true;
+    let next = ui.update_palette_animation(1.0 / 60.0);
+    assert!(ui.palette_animation_rate > 0.0);
+    assert!((next - offset).abs() < 0.01);
+
+    for _ in 0..120 {
+        ui.update_palette_animation(1.0 / 60.0);
+    }
+    assert!((ui.palette_animation_rate + 0.5).abs() < 0.01);
+}
+
+#[test]
+fn test_toggle_iteration_readout_command() {
+    let mut ui = UI::new();
+    let mut params = FractalParams::default();
+    assert!(!ui.show_iteration_readout);
+
+    ui.execute_command(
+        crate::command_palette::CommandAction::ToggleIterationReadout,
+        &mut params,
+    );
+    assert!(ui.show_iteration_readout);
+    assert!(ui.get_ui_state().show_iteration_readout);
+}
+
+#[test]
+fn test_palette_preview_candidate_applies_palette() {
+    let mut params = FractalParams {
+        procedural_palette: ProceduralPalette::Firestrm,
+        ..Default::default()
+    };
+    let candidate = PalettePreviewCandidate {
+        palette_index: 2,
+        procedural_palette: ProceduralPalette::None,
+    };
+    candidate.apply(&mut params);
+    assert_eq!(params.palette_index, 2);
+    assert_eq!(params.palette.name, ColorPalette::ALL[2].name);
+    assert_eq!(params.procedural_palette, ProceduralPalette::None);
+}
+
+#[test]
+fn test_frame_budget_follows_frame_cap() {
+    let mut ui = UI::new();
+    ui.max_fps = 0;
+    assert!((ui.frame_budget_ms() - 1000.0 / 60.0).abs() < 1e-4);
+
+    ui.max_fps = 30;
+    assert!((ui.frame_budget_ms() - 1000.0 / 30.0).abs() < 1e-4);
+    for ft in [10.0, 40.0, 20.0, 35.0] {
+        ui.update_frame_time(ft);
+    }
+    assert_eq!(ui.frames_over_budget(), 2);
+}
+
+#[test]
+fn test_settings_search_matches_controls() {
+    let found = search::search_section("Effects", "fog DENSITY");
+    assert!(found.is_match());
+    assert!(!found.title);
+    assert_eq!(found.controls, vec!["Fog Density"]);
+
+    let found = search::search_section("Lighting", "light");
+    assert!(found.title);
+    assert!(found.controls.contains(&"Light Intensity"));
+
+    assert!(!search::search_section("Floor", "fog").is_match());
+    assert!(!search::search_section("Effects", "   ").is_match());
+}
+
+#[test]
+fn test_find_preset_by_name() {
+    let names = ["Classic Mandelbrot", "Julia Spiral", "Mandelbulb Power 8"];
+    assert_eq!(preset_nav::find_preset_by_name(&names, "jul"), Some(1));
+    // Prefix matches win over earlier names that only contain the query
+    assert_eq!(preset_nav::find_preset_by_name(&names, "MANDEL"), Some(2));
+    assert_eq!(preset_nav::find_preset_by_name(&names, "spiral"), Some(1));
+    assert_eq!(preset_nav::find_preset_by_name(&names, "xyz"), None);
+    assert_eq!(preset_nav::find_preset_by_name(&names, " "), None);
+}
+
+#[test]
+fn test_console_entry_format() {
+    let entry = console::LogEntry {
+        level: log::Level::Warn,
+        target: "par_fractal::fractal::presets".to_string(),
+        message: "Preset 'x' not found".to_string(),
+        time_secs: 1.5,
+    };
+    assert_eq!(
+        console::format_entry(&entry),
+        "[    1.50] WARN  fractal::presets: Preset 'x' not found"
+    );
+
+    let entry = console::LogEntry {
+        target: "wgpu_core::device".to_string(),
+        ..entry
+    };
+    assert!(console::format_entry(&entry).contains(" wgpu_core::device: "));
+}
+
+#[test]
+fn test_capture_size_matching_dimension() {
+    // 16:9 width -> height and back
+    assert_eq!(capture_size::matching_dimension(3840, 16.0 / 9.0), 2160);
+    assert_eq!(capture_size::matching_dimension(2160, 9.0 / 16.0), 3840);
+    // Capped at the capture limit and never zero
+    assert_eq!(capture_size::matching_dimension(16384, 0.5), 16384);
+    assert_eq!(capture_size::matching_dimension(0, 1.0), 1);
+    assert_eq!(capture_size::matching_dimension(100, 0.0), 1);
+
+    assert_eq!(
+        capture_size::parse_custom_size(" 1920", "1080 "),
+        Some((1920, 1080))
+    );
+    assert_eq!(capture_size::parse_custom_size("1920", "0"), None);
+    assert_eq!(capture_size::parse_custom_size("wide", "1080"), None);
+}
+
+#[test]
+fn test_material_swatch_shading() {
+    // Elevation 90 points straight up, azimuth 90 along +X
+    assert!((material_swatch::light_direction(0.0, 90.0) - glam::Vec3::Y).length() < 1e-5);
+    assert!((material_swatch::light_direction(90.0, 0.0) - glam::Vec3::X).length() < 1e-5);
+
+    let params = FractalParams::default();
+    let mut lighting = material_swatch::SwatchLighting::from_params(&params);
+    lighting.light_dir = glam::Vec3::Z;
+    lighting.light_intensity = 0.1;
+    lighting.ambient_light = 0.1;
+    let colors = [glam::Vec3::splat(0.2); 2];
+    let center = material_swatch::SWATCH_SIZE / 2 * (material_swatch::SWATCH_SIZE + 1);
+    let dim = material_swatch::sphere_swatch(colors, &lighting).pixels[center];
+    lighting.light_intensity *= 2.0;
+    let bright = material_swatch::sphere_swatch(colors, &lighting).pixels[center];
+    assert!(bright.r() > dim.r());
+
+    // Fog fades in from the checkerboard on the left to the fog color on the right
+    let fog = material_swatch::fog_swatch(glam::Vec3::new(1.0, 0.0, 0.0));
+    let right = fog.pixels[material_swatch::SWATCH_SIZE - 1];
+    assert_eq!(right, egui::Color32::from_rgb(255, 0, 0));
+    assert_eq!(fog.pixels[0].r(), fog.pixels[0].g());
+}
+
+#[test]
+fn test_julia_constant_polar_conversion() {
+    let (radius, angle) = julia_c_to_polar([0.0, 0.8]);
+    assert!((radius - 0.8).abs() < 1e-6);
+    assert!((angle - 90.0).abs() < 1e-4);
+
+    let c = [-0.7269, 0.1889];
+    let (radius, angle) = julia_c_to_polar(c);
+    let back = julia_c_from_polar(radius, angle);
+    assert!((back[0] - c[0]).abs() < 1e-5 && (back[1] - c[1]).abs() < 1e-5);
+}
+
+#[test]
+fn test_spanned_layout() {
+    let monitor = |name: &str, x, y, width, height| MonitorInfo {
+        name: name.to_string(),
+        width,
+        height,
+        x,
+        y,
+        is_primary: false,
+    };
+    // Three 1080p monitors side by side, the primary in the middle at the origin
+    let left = monitor("left", -1920, 0, 1920, 1080);
+    let middle = monitor("middle", 0, 0, 1920, 1080);
+    let right = monitor("right", 1920, 0, 1920, 1080);
+    let (size, rects) = spanned_layout(&[&middle, &left, &right]).unwrap();
+    assert_eq!(size, (5760, 1080));
+    assert_eq!(rects[0].x, 1920);
+    assert_eq!(rects[1].x, 0);
+    assert_eq!(rects[2].x, 3840);
+
+    // A taller monitor lower down pads the others to the bounding box
+    let tall = monitor("tall", 1920, -200, 1440, 2560);
+    let (size, rects) = spanned_layout(&[&middle, &tall]).unwrap();
+    assert_eq!(size, (3360, 2560));
+    assert_eq!(
+        rects[0],
+        SpanRect {
+            x: 0,
+            y: 200,
+            width: 1920,
+            height: 1080
+        }
+    );
+    assert_eq!((rects[1].x, rects[1].y), (1920, 0));
+
+    assert!(spanned_layout(&[]).is_none());
+}
+
+#[test]
+fn test_video_frame_limit() {
+    let mut ui = UI::new();
+    assert_eq!(ui.video_frame_limit(), None);
+
+    ui.video_auto_stop = true;
+    ui.video_fps = 30;
+    ui.video_stop_after = 10;
+    assert_eq!(ui.video_frame_limit(), Some(300));
+
+    ui.video_stop_in_frames = true;
+    assert_eq!(ui.video_frame_limit(), Some(10));
+}
+
+#[test]
+fn test_cvd_simulation_left_out_of_captures() {
+    use crate::fractal::CvdSimulation;
+
+    let mut ui = UI::new();
+    ui.cvd_simulation = CvdSimulation::Deuteranopia;
+    assert_eq!(ui.cvd_simulation_for(false), CvdSimulation::Deuteranopia);
+    assert_eq!(ui.cvd_simulation_for(true), CvdSimulation::None);
+
+    ui.cvd_in_captures = true;
+    assert_eq!(ui.cvd_simulation_for(true), CvdSimulation::Deuteranopia);
+}
+
+#[test]
+fn test_randomize_gallery_keeps_recent_rolls() {
+    let mut gallery = RandomizeGallery::default();
+    assert!(gallery.is_empty());
+    assert!(gallery.next_without_thumbnail().is_none());
+
+    let mut params = FractalParams::default();
+    for iterations in 1..=12 {
+        params.max_iterations = iterations;
+        gallery.push(&params);
+    }
+
+    // Only the last ten are kept, newest first
+    assert_eq!(gallery.len(), 10);
+    assert_eq!(gallery.get(0).unwrap().params.max_iterations, 12);
+    assert_eq!(gallery.get(9).unwrap().params.max_iterations, 3);
+
+    let (index, waiting) = gallery.next_without_thumbnail().unwrap();
+    assert_eq!(index, 0);
+    assert_eq!(waiting.max_iterations, 12);
+
+    // A thumbnail that failed to render isn't requested again
+    gallery.set_thumbnail_failed(0);
+    let (index, _) = gallery.next_without_thumbnail().unwrap();
+    assert_eq!(index, 1);
+}
+
+#[test]
+fn test_fractal_type_visibility() {
+    use super::fractal_types::fractal_type_visible;
+
+    // No visible set lists every type
+    assert!(fractal_type_visible(None, FractalType::Mandelbulb3D));
+
+    let visible = [FractalType::Mandelbrot2D, FractalType::Julia2D];
+    assert!(fractal_type_visible(Some(&visible), FractalType::Julia2D));
+    assert!(!fractal_type_visible(
+        Some(&visible),
+        FractalType::Mandelbulb3D
+    ));
+}
+
+#[test]
+fn test_search_labels_exist_in_panel() {
+    // Every registered label must still be a string literal in the panel code,
+    // so renaming a control without updating the search index fails here
+    let sources = [
+        include_str!("mod.rs"),
+        include_str!("accumulation.rs"),
+        include_str!("bookmarks.rs"),
+        include_str!("camera_path_io.rs"),
+        include_str!("capture_size.rs"),
+        include_str!("command.rs"),
+        include_str!("composite.rs"),
+        include_str!("console.rs"),
+        include_str!("console_ui.rs"),
+        include_str!("filename_template.rs"),
+        include_str!("fractal_types.rs"),
+        include_str!("history.rs"),
+        include_str!("material_swatch.rs"),
+        include_str!("math_info.rs"),
+        include_str!("monitor.rs"),
+        include_str!("overlays.rs"),
+        include_str!("preset_diff.rs"),
+        include_str!("preset_nav.rs"),
+        include_str!("randomize_gallery.rs"),
+        include_str!("sweep.rs"),
+        include_str!("toast.rs"),
+        include_str!("toast_ui.rs"),
+    ]
+    .concat();
+    for (section, controls) in search::SECTION_CONTROLS {
+        assert!(
+            sources.contains(&format!("\"{}\"", section)),
+            "section '{}' not found in the panel",
+            section
+        );
+        for control in *controls {
+            assert!(
+                sources.contains(control),
+                "'{}' in section '{}' not found in the panel",
+                control,
+                section
+            );
+        }
+    }
+}
@@ -53,6 +53,8 @@ pub struct VideoRecorder {
     encoder_thread: Option<thread::JoinHandle<()>>,
     is_recording: bool,
     frame_count: u32,
+    /// Captured frames after which the recording should stop
+    frame_limit: Option<u32>,
     filename: String,
 }
 
@@ -67,6 +69,7 @@ impl VideoRecorder {
             encoder_thread: None,
             is_recording: false,
             frame_count: 0,
+            frame_limit: None,
             filename: String::new(),
         }
     }
@@ -141,6 +144,11 @@ impl VideoRecorder {
             return Err("Not recording".to_string());
         }
 
+        // Frames past the limit arrive before the app stops the recording
+        if self.limit_reached() {
+            return Ok(());
+        }
+
         if let Some(sender) = &self.frame_sender {
             // Try to send the frame, drop if channel is full (skip frame)
             if sender.try_send(frame_data).is_ok() {
@@ -157,6 +165,15 @@ impl VideoRecorder {
         self.is_recording
     }
 
+    /// Frame size the encoder expects (width, height)
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    pub fn fps(&self) -> u32 {
+        self.fps
+    }
+
     pub fn frame_count(&self) -> u32 {
         self.frame_count
     }
@@ -165,6 +182,23 @@ impl VideoRecorder {
         &self.filename
     }
 
+    /// Stop after this many captured frames (None = until stopped by hand)
+    pub fn set_frame_limit(&mut self, limit: Option<u32>) {
+        self.frame_limit = limit;
+    }
+
+    pub fn frame_limit(&self) -> Option<u32> {
+        self.frame_limit
+    }
+
+    /// Whether a recording has captured all the frames it was limited to
+    pub fn limit_reached(&self) -> bool {
+        self.is_recording
+            && self
+                .frame_limit
+                .is_some_and(|limit| self.frame_count >= limit)
+    }
+
     fn check_ffmpeg_available(&self) -> bool {
         Command::new("ffmpeg")
             .arg("-version")
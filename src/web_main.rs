@@ -6,7 +6,7 @@ use winit::event::*;
 use winit::event_loop::EventLoop;
 use winit::platform::web::{EventLoopExtWebSys, WindowAttributesExtWebSys};
 
-use crate::app::App;
+use crate::app::{App, RenderStatus};
 
 /// Hide the loading indicator and show the canvas
 fn hide_loading() {
@@ -19,6 +19,21 @@ fn hide_loading() {
     }
 }
 
+/// Forwards log records at info and above to the browser console
+struct BrowserConsoleLogger;
+
+impl log::Log for BrowserConsoleLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Info
+    }
+
+    fn log(&self, record: &log::Record) {
+        console_log::log(record);
+    }
+
+    fn flush(&self) {}
+}
+
 /// Show an error message
 fn show_error(message: &str) {
     if let Some(window) = web_sys::window() {
@@ -43,8 +58,12 @@ pub async fn main_web() {
     // Set up better panic messages in browser console
     console_error_panic_hook::set_once();
 
-    // Initialize logging to browser console
-    console_log::init_with_level(log::Level::Info).ok();
+    // Log to the browser console and to the in-app console
+    crate::ui::console::ConsoleLogger::init(
+        Some(Box::new(BrowserConsoleLogger)),
+        log::LevelFilter::Info,
+    )
+    .ok();
 
     log::info!("Par Fractal WASM starting...");
 
@@ -216,6 +235,8 @@ pub async fn main_web() {
         closure.forget();
     }
 
+    // Handle for finishing an async renderer rebuild after the event returns
+    let app_handle = app.clone();
     event_loop.spawn(move |event, target| {
         let mut app = app.borrow_mut();
 
@@ -232,14 +253,21 @@ pub async fn main_web() {
                         }
                         WindowEvent::RedrawRequested => {
                             app.update();
-                            match app.render() {
-                                Ok(_) => {}
-                                Err(wgpu::SurfaceError::Lost) => {
-                                    let size = app.size();
-                                    app.resize(size);
+                            match app.render_frame() {
+                                RenderStatus::Ok => {}
+                                RenderStatus::Rebuild => {
+                                    // Renderer creation is async, so finish it outside the event
+                                    let rebuild = app.renderer_rebuild();
+                                    let app = app_handle.clone();
+                                    wasm_bindgen_futures::spawn_local(async move {
+                                        let renderer = rebuild.await;
+                                        app.borrow_mut().install_renderer(renderer);
+                                    });
+                                }
+                                RenderStatus::Fatal(message) => {
+                                    show_error(&message);
+                                    target.exit();
                                 }
-                                Err(wgpu::SurfaceError::OutOfMemory) => target.exit(),
-                                Err(e) => log::error!("Render error: {:?}", e),
                             }
                         }
                         _ => {}
@@ -1,3 +1,5 @@
+use par_fractal::fractal::BloomQuality;
+use par_fractal::renderer::bloom::bloom_level_count;
 use par_fractal::{
     Camera, CameraController, ColorPalette, FractalParams, FractalType, RenderMode, ShadingModel,
     UI,
@@ -379,3 +381,208 @@ fn test_render_mode_consistency() {
     params.switch_fractal(FractalType::MengerSponge3D);
     assert_eq!(params.render_mode, RenderMode::ThreeD);
 }
+
+#[test]
+fn test_camera_stereo_eyes_parallel() {
+    let camera = Camera::new(1280, 720);
+    let left = camera.stereo_eye(-0.05);
+    let right = camera.stereo_eye(0.05);
+
+    // Eyes are separated sideways by the requested distance
+    assert!((left.position.distance(right.position) - 0.1).abs() < 1e-5);
+    assert!(left.position.x < right.position.x);
+
+    // Both eyes look in the same direction
+    let left_dir = (left.target - left.position).normalize();
+    let right_dir = (right.target - right.position).normalize();
+    assert!(left_dir.abs_diff_eq(right_dir, 1e-5));
+}
+
+#[test]
+fn test_default_orbit_trap_image() {
+    let (width, height, pixels) = par_fractal::renderer::orbit_trap::default_orbit_trap_image();
+    assert_eq!(pixels.len(), (width * height * 4) as usize);
+
+    // Transparent outside the disk, opaque at the center
+    assert_eq!(pixels[3], 0);
+    let center = (((height / 2) * width + width / 2) * 4) as usize;
+    assert_eq!(pixels[center + 3], 255);
+}
+
+#[test]
+fn test_auto_exposure_target() {
+    use par_fractal::renderer::exposure::AutoExposure;
+
+    // A dark scene is brightened, a bright one darkened, within a bounded range
+    assert!(AutoExposure::exposure_for_luminance(-4.0) > 0.0);
+    assert!(AutoExposure::exposure_for_luminance(1.0) < 0.0);
+    assert_eq!(AutoExposure::exposure_for_luminance(-20.0), 3.0);
+    assert_eq!(AutoExposure::exposure_for_luminance(20.0), -3.0);
+}
+
+#[test]
+fn test_precise_2d_coord_table() {
+    use par_fractal::renderer::precise_2d::build_coord_table;
+
+    let center = [-0.743643887037151, 0.131825904205330];
+    let zoom = 1.0e7;
+    let table = build_coord_table(center, zoom, 2.0, 4, 2);
+    assert_eq!(table.len(), 4 + 2);
+
+    // Columns straddle the center symmetrically and keep f64 precision in (hi, lo)
+    let value = |entry: [f32; 2]| entry[0] as f64 + entry[1] as f64;
+    let half_width = 2.0 / zoom * 2.0; // NDC 1.0 at aspect 2
+    assert!((value(table[1]) - (center[0] - 0.25 * half_width)).abs() < 1e-13);
+    assert!((value(table[2]) - (center[0] + 0.25 * half_width)).abs() < 1e-13);
+    assert!(((value(table[4]) + value(table[5])) / 2.0 - center[1]).abs() < 1e-13);
+    // Neighboring columns stay distinct even though f32 alone can't resolve them
+    assert!(value(table[2]) > value(table[1]));
+}
+
+#[test]
+fn test_bloom_level_count_follows_quality_and_size() {
+    assert_eq!(bloom_level_count(1920, 1080, BloomQuality::Low), 3);
+    assert_eq!(bloom_level_count(1920, 1080, BloomQuality::Medium), 5);
+    assert_eq!(bloom_level_count(1920, 1080, BloomQuality::High), 7);
+    // Small targets stop before the levels shrink below 2 pixels
+    assert_eq!(bloom_level_count(64, 16, BloomQuality::High), 3);
+    assert_eq!(bloom_level_count(1, 1, BloomQuality::High), 1);
+}
+
+#[test]
+fn test_surface_format_selection() {
+    use par_fractal::fractal::SurfaceFormatSetting;
+    use par_fractal::renderer::surface_format::{
+        needs_shader_srgb_encode, supported_surface_formats, surface_format_for,
+    };
+    use wgpu::TextureFormat as F;
+
+    let available = [F::Bgra8UnormSrgb, F::Bgra8Unorm, F::Rgba16Float];
+    assert_eq!(
+        surface_format_for(SurfaceFormatSetting::Auto, &available),
+        Some(F::Bgra8UnormSrgb)
+    );
+    assert_eq!(
+        surface_format_for(SurfaceFormatSetting::Unorm8, &available),
+        Some(F::Bgra8Unorm)
+    );
+    // Unsupported choices report None so the renderer can fall back to Auto
+    assert_eq!(
+        surface_format_for(SurfaceFormatSetting::Rgb10a2, &available),
+        None
+    );
+    assert_eq!(
+        supported_surface_formats(&available),
+        vec![
+            SurfaceFormatSetting::Auto,
+            SurfaceFormatSetting::Unorm8,
+            SurfaceFormatSetting::Float16
+        ]
+    );
+
+    // Only non-sRGB unorm surfaces need the shader to encode
+    assert!(!needs_shader_srgb_encode(F::Bgra8UnormSrgb));
+    assert!(needs_shader_srgb_encode(F::Rgb10a2Unorm));
+    assert!(!needs_shader_srgb_encode(F::Rgba16Float));
+}
+
+#[test]
+fn test_surface_pixels_to_rgba8() {
+    use par_fractal::renderer::surface_format::{f16_to_f32, surface_pixels_to_rgba8};
+    use wgpu::TextureFormat as F;
+
+    assert_eq!(f16_to_f32(0x3c00), 1.0);
+    assert_eq!(f16_to_f32(0xc000), -2.0);
+    assert_eq!(f16_to_f32(0x3800), 0.5);
+
+    assert_eq!(
+        surface_pixels_to_rgba8(F::Bgra8UnormSrgb, &[1, 2, 3, 4]),
+        vec![3, 2, 1, 4]
+    );
+    // 10-bit red at full scale, blue at zero, opaque alpha
+    let bits: u32 = 1023 | (512 << 10) | (3 << 30);
+    assert_eq!(
+        surface_pixels_to_rgba8(F::Rgb10a2Unorm, &bits.to_le_bytes()),
+        vec![255, 128, 0, 255]
+    );
+    // Linear float 1.0 stays white, HDR values clip
+    let half_one = 0x3c00u16.to_le_bytes();
+    let half_four = 0x4400u16.to_le_bytes();
+    let pixel = [half_one, half_four, [0, 0], half_one].concat();
+    assert_eq!(
+        surface_pixels_to_rgba8(F::Rgba16Float, &pixel),
+        vec![255, 255, 0, 255]
+    );
+}
+
+#[test]
+fn test_capture_mid_gray_matches_display() {
+    use par_fractal::renderer::surface_format::{
+        apply_capture_gamma_rgba8, linear_to_srgb, surface_pixels_to_rgba8,
+    };
+    use wgpu::TextureFormat as F;
+
+    // sRGB mid-gray 128 is linear ~0.2159; every capture path must save 128
+    let mid_gray = 0.2158605f32;
+    let expected = vec![128, 128, 128, 255];
+
+    // High-res renders encode the linear composite directly
+    assert_eq!((linear_to_srgb(mid_gray) * 255.0).round() as u8, 128);
+    // Screenshots read back what each surface format stores for it
+    assert_eq!(
+        surface_pixels_to_rgba8(F::Rgba8UnormSrgb, &expected),
+        expected
+    );
+    assert_eq!(
+        surface_pixels_to_rgba8(F::Bgra8UnormSrgb, &expected),
+        expected
+    );
+    let ten_bit: u32 = 514 | (514 << 10) | (514 << 20) | (3 << 30);
+    assert_eq!(
+        surface_pixels_to_rgba8(F::Rgb10a2Unorm, &ten_bit.to_le_bytes()),
+        expected
+    );
+    let half_gray = 0x32e8u16.to_le_bytes();
+    let half_one = 0x3c00u16.to_le_bytes();
+    let pixel = [half_gray, half_gray, half_gray, half_one].concat();
+    assert_eq!(surface_pixels_to_rgba8(F::Rgba16Float, &pixel), expected);
+
+    // The default capture gamma leaves pixels alone; a higher one brightens
+    // color but never alpha
+    let mut pixels = expected.clone();
+    apply_capture_gamma_rgba8(&mut pixels, 1.0);
+    assert_eq!(pixels, expected);
+    apply_capture_gamma_rgba8(&mut pixels, 2.2);
+    assert_eq!(pixels, vec![186, 186, 186, 255]);
+}
+
+#[test]
+fn test_shader_parameter_export() {
+    use par_fractal::renderer::shader_export::ShaderExportFormat;
+    use par_fractal::renderer::uniforms::Uniforms;
+
+    let mut params = FractalParams::default();
+    params.switch_fractal(FractalType::Julia2D);
+    params.max_iterations = 250;
+    params.julia_c = [-0.8, 0.156];
+    let camera = Camera::new(1280, 720);
+    let mut uniforms = Uniforms::new();
+    uniforms.update(&camera, &params, 0.0);
+    let fields = uniforms.shader_params();
+
+    let wgsl = ShaderExportFormat::Wgsl.format(&fields);
+    assert!(wgsl.contains("const max_iterations: u32 = 250u;"));
+    assert!(wgsl.contains("const julia_c: vec2<f32> = vec2<f32>(-0.8, 0.156);"));
+    assert!(wgsl.contains("const palette: array<vec4<f32>, 8> = array<vec4<f32>, 8>("));
+
+    let glsl = ShaderExportFormat::Glsl.format(&fields);
+    assert!(glsl.contains("const uint max_iterations = 250u;"));
+    assert!(glsl.contains("const vec4 palette[8] = vec4[8]("));
+
+    let list = ShaderExportFormat::List.format(&fields);
+    assert!(list.contains("max_iterations = 250 //"));
+
+    // Padding and camera matrices are left out
+    assert!(!wgsl.contains("_padding"));
+    assert!(!wgsl.contains("view_proj"));
+}